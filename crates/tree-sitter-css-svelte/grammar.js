@@ -2,7 +2,9 @@
  * CSS grammar for tree-sitter with Svelte-oriented recovery.
  *
  * Based on tree-sitter-css 0.25.0 with looser at-rule prelude parsing so
- * modern media/container queries keep their nested rule blocks intact.
+ * modern media/container queries keep their nested rule blocks intact, and
+ * with Svelte's `:global(...)`, `:global .x`, and `:global { ... }` as
+ * `global_selector` and `global_block` nodes.
  */
 
 /// <reference types="tree-sitter-cli/dsl" />
@@ -39,6 +41,7 @@ module.exports = grammar({
 
     _top_level_item: ($) => choice(
       $.declaration,
+      $.global_block,
       $.rule_set,
       $.import_statement,
       $.custom_media_statement,
@@ -219,6 +222,40 @@ module.exports = grammar({
 
     selectors: ($) => sep1(",", $._selector),
 
+    // Svelte: a rule whose selector ends in a bare `:global`, as in
+    // `:global { ... }` or `.x :global { ... }`. Every rule in its block is
+    // global.
+    global_block: ($) => seq(
+      field("prelude", alias($._global_block_selectors, $.selectors)),
+      field("block", $.block),
+    ),
+
+    _global_block_selectors: ($) => choice(
+      alias($._global_block_selector, $.global_selector),
+      alias($._global_block_descendant_selector, $.descendant_selector),
+      alias($._global_block_child_selector, $.child_selector),
+    ),
+
+    _global_block_descendant_selector: ($) => seq(
+      $._selector,
+      $._descendant_operator,
+      alias($._global_block_selector, $.global_selector),
+    ),
+
+    _global_block_child_selector: ($) => seq(
+      optional($._selector),
+      ">",
+      alias($._global_block_selector, $.global_selector),
+    ),
+
+    // Before `{`, a bare `:global` opens a block rather than ending a
+    // `global_selector`.
+    _global_block_selector: ($) => prec(1, seq(
+      optional($._selector),
+      alias($._pseudo_class_selector_colon, ":"),
+      "global",
+    )),
+
     block: ($) => seq(
       "{",
       repeat($._block_item),
@@ -228,6 +265,7 @@ module.exports = grammar({
 
     _block_item: ($) => choice(
       $.declaration,
+      $.global_block,
       $.rule_set,
       $.import_statement,
       $.custom_media_statement,
@@ -249,6 +287,7 @@ module.exports = grammar({
       $.class_selector,
       $.nesting_selector,
       $.pseudo_class_selector,
+      $.global_selector,
       $.pseudo_element_selector,
       $.id_selector,
       $.attribute_selector,
@@ -261,37 +300,40 @@ module.exports = grammar({
       $.namespace_selector,
     ),
 
-    _selector_no_pseudo_element: ($) => selectorVariant($, $._selector_no_pseudo_element, {
+    ...selectorVariant("_selector_no_pseudo_element", {
       allowPseudoElements: false,
       allowHas: true,
+    }, ($) => ({
       selectorOnlyArguments: $.pseudo_class_selector_arguments,
       forgivingSelectorArguments: $.pseudo_class_forgiving_selector_arguments,
       selectorOrValueArguments: $.pseudo_class_arguments_no_pseudo_element,
       nthArguments: $.pseudo_class_nth_child_arguments_no_pseudo_element,
-    }),
+    })),
 
     _selectors_no_pseudo_element: ($) => sep1(",", $._selector_no_pseudo_element),
 
-    _selector_no_pseudo_element_no_nesting: ($) => selectorVariant($, $._selector_no_pseudo_element_no_nesting, {
+    ...selectorVariant("_selector_no_pseudo_element_no_nesting", {
       allowPseudoElements: false,
       allowHas: true,
       allowNesting: false,
+    }, ($) => ({
       selectorOnlyArguments: $.pseudo_class_selector_arguments,
       forgivingSelectorArguments: $.pseudo_class_forgiving_selector_arguments,
       selectorOrValueArguments: $.pseudo_class_arguments_no_pseudo_element,
       nthArguments: $.pseudo_class_nth_child_arguments_no_pseudo_element,
-    }),
+    })),
 
     _selectors_no_pseudo_element_no_nesting: ($) => sep1(",", $._selector_no_pseudo_element_no_nesting),
 
-    _selector_in_has: ($) => selectorVariant($, $._selector_in_has, {
+    ...selectorVariant("_selector_in_has", {
       allowPseudoElements: false,
       allowHas: false,
+    }, ($) => ({
       selectorOnlyArguments: $.pseudo_class_selector_arguments_in_has,
       forgivingSelectorArguments: $.pseudo_class_forgiving_selector_arguments_in_has,
       selectorOrValueArguments: $.pseudo_class_value_arguments,
       nthArguments: $.pseudo_class_nth_child_arguments_in_has,
-    }),
+    })),
 
     _selectors_in_has: ($) => sep1(",", $._selector_in_has),
 
@@ -305,6 +347,23 @@ module.exports = grammar({
       field("name", $.class_name),
     )),
 
+    // Svelte: `:global(...)` makes its argument selectors global, and a bare
+    // `:global .x` makes everything after it global.
+    global_selector: ($) => seq(
+      optional($._selector),
+      alias($._pseudo_class_selector_colon, ":"),
+      "global",
+      optional(alias($._global_arguments, $.arguments)),
+    ),
+
+    // Numbers parse so that `:global(1px)` can be reported as a non-selector
+    // argument instead of an error.
+    _global_arguments: ($) => seq(
+      token.immediate("("),
+      sep(",", choice($._selector, $.integer_value, $.float_value)),
+      ")",
+    ),
+
     pseudo_class_selector: ($) => seq(
       optional($._selector),
       alias($._pseudo_class_selector_colon, ":"),
@@ -970,26 +1029,24 @@ function declarationFeatureBody($) {
   );
 }
 
-function selectorVariant($, self, options) {
-  const {
-    allowPseudoElements,
-    allowHas,
-    allowNesting = true,
-    selectorOnlyArguments,
-    forgivingSelectorArguments,
-    selectorOrValueArguments,
-    nthArguments,
-  } = options;
-
-  const variants = [
-    $.universal_selector,
-    prec(1, seq(optional(self), alias($.identifier, $.tag_name))),
-    alias(prec(1, seq(optional(self), ".", field("name", $.class_name))), $.class_selector),
-    alias(seq(
+// The rules of the selector variant `name`: `name` itself, a choice of the
+// selector forms nested inside it, and a hidden rule per form that is
+// aliased back to the form's node. An alias on an inline `seq` would instead
+// name each of its members, so each form needs a rule of its own.
+function selectorVariant(name, flags, options) {
+  const { allowPseudoElements, allowHas, allowNesting = true } = flags;
+  const forms = {
+    class_selector: ($, self) => prec(1, seq(optional(self), ".", field("name", $.class_name))),
+    pseudo_class_selector: ($, self, {
+      selectorOnlyArguments,
+      forgivingSelectorArguments,
+      selectorOrValueArguments,
+      nthArguments,
+    }) => seq(
       optional(self),
       alias($._pseudo_class_selector_colon, ":"),
-        choice(
-          seq(
+      choice(
+        seq(
           alias(choice("is", "where", "matches"), $.class_name),
           alias(forgivingSelectorArguments, $.arguments),
         ),
@@ -1017,9 +1074,15 @@ function selectorVariant($, self, options) {
         $.class_name,
         alias("host", $.class_name),
       ),
-    ), $.pseudo_class_selector),
-    alias(seq(optional(self), "#", field("name", alias($.class_name, $.id_name))), $.id_selector),
-    alias(seq(
+    ),
+    global_selector: ($, self) => seq(
+      optional(self),
+      alias($._pseudo_class_selector_colon, ":"),
+      "global",
+      optional(alias($._global_arguments, $.arguments)),
+    ),
+    id_selector: ($, self) => seq(optional(self), "#", field("name", alias($.class_name, $.id_name))),
+    attribute_selector: ($, self) => seq(
       optional(self),
       token(prec(1, "[")),
       field("name", alias(choice($.identifier, $.namespace_selector), $.attribute_name)),
@@ -1029,36 +1092,46 @@ function selectorVariant($, self, options) {
         optional(field("flags", $.attribute_flags)),
       )),
       "]",
-    ), $.attribute_selector),
-    $.string_value,
-    alias(prec.left(seq(optional(self), ">", self)), $.child_selector),
-    alias(prec.left(seq(self, $._descendant_operator, self)), $.descendant_selector),
-    alias(prec.left(seq(optional(self), "~", self)), $.sibling_selector),
-    alias(prec.left(seq(optional(self), "+", self)), $.adjacent_sibling_selector),
-    alias(prec.left(seq(optional(self), "||", self)), $.column_selector),
-    alias(prec.left(seq(optional(self), "|", self)), $.namespace_selector),
-  ];
+    ),
+    child_selector: (_, self) => prec.left(seq(optional(self), ">", self)),
+    descendant_selector: ($, self) => prec.left(seq(self, $._descendant_operator, self)),
+    sibling_selector: (_, self) => prec.left(seq(optional(self), "~", self)),
+    adjacent_sibling_selector: (_, self) => prec.left(seq(optional(self), "+", self)),
+    column_selector: (_, self) => prec.left(seq(optional(self), "||", self)),
+    namespace_selector: (_, self) => prec.left(seq(optional(self), "|", self)),
+  };
 
   if (allowNesting) {
-    variants.splice(3, 0, alias(prec(1, seq(optional(self), "&")), $.nesting_selector));
+    forms.nesting_selector = (_, self) => prec(1, seq(optional(self), "&"));
   }
 
   if (allowPseudoElements) {
-    variants.splice(allowNesting ? 5 : 4, 0,
-      alias(seq(
+    forms.pseudo_element_selector = ($, self) => choice(
+      seq(
         optional(self),
         "::",
         alias($.identifier, $.tag_name),
         optional(alias($.pseudo_element_arguments, $.arguments)),
-      ), $.pseudo_element_selector),
-      alias(seq(
+      ),
+      seq(
         optional(self),
         alias($._pseudo_class_selector_colon, ":"),
         alias(choice("before", "after", "first-line", "first-letter"), $.tag_name),
         optional(alias($.pseudo_element_arguments, $.arguments)),
-      ), $.pseudo_element_selector),
+      ),
     );
   }
 
-  return choice(...variants);
+  const rules = {
+    [name]: ($) => choice(
+      $.universal_selector,
+      prec(1, seq(optional($[name]), alias($.identifier, $.tag_name))),
+      ...Object.keys(forms).map((form) => alias($[`${name}_${form}`], $[form])),
+      $.string_value,
+    ),
+  };
+  for (const [form, rule] of Object.entries(forms)) {
+    rules[`${name}_${form}`] = ($) => rule($, $[name], options($));
+  }
+  return rules;
 }
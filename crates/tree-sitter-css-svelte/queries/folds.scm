@@ -0,0 +1,17 @@
+; CSS folding queries
+
+[
+  (comment)
+  (rule_set)
+  (global_block)
+  (at_rule)
+  (media_statement)
+  (supports_statement)
+  (container_statement)
+  (scope_statement)
+  (keyframes_statement)
+  (keyframe_block)
+] @fold
+
+(layer_statement
+  block: (block)) @fold
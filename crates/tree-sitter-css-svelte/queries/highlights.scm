@@ -35,6 +35,9 @@
 (property_name) @property
 (feature_name) @property
 
+; Svelte's :global(...), :global .x, and :global { ... }
+(global_selector "global" @keyword)
+
 (pseudo_element_selector (tag_name) @attribute)
 (pseudo_class_selector (class_name) @attribute)
 (attribute_name) @attribute
@@ -0,0 +1,19 @@
+; CSS indentation queries
+
+[
+  (block)
+  (keyframe_block_list)
+] @indent.begin
+
+(block
+  "}" @indent.end)
+
+(keyframe_block_list
+  "}" @indent.end)
+
+"}" @indent.branch
+
+[
+  (comment)
+  (js_comment)
+] @indent.ignore
@@ -0,0 +1,7 @@
+; CSS injection queries
+
+([
+  (comment)
+  (js_comment)
+] @injection.content
+  (#set! injection.language "comment"))
@@ -0,0 +1,57 @@
+; CSS locals queries
+;
+; Custom properties, keyframes, and cascade layers are global to a
+; stylesheet, so the stylesheet is the only scope.
+
+(stylesheet) @local.scope
+
+; Custom properties: `--x: ...` defines, `var(--x)` references
+
+((declaration
+  property: (property_name) @local.definition)
+  (#match? @local.definition "^--"))
+
+((call_expression
+  (function_name) @_function
+  (arguments
+    .
+    (plain_value) @local.reference))
+  (#eq? @_function "var")
+  (#match? @local.reference "^--"))
+
+; Keyframes: `@keyframes name` defines, `animation-name` and the
+; `animation` shorthand reference
+
+(keyframes_statement
+  name: (keyframes_name) @local.definition.type)
+
+((declaration
+  property: (property_name) @_property
+  value: (declaration_value
+    (plain_value) @local.reference))
+  (#eq? @_property "animation-name")
+  (#not-any-of? @local.reference "none" "initial" "inherit" "unset" "revert"))
+
+((declaration
+  property: (property_name) @_property
+  value: (declaration_value
+    (plain_value) @local.reference))
+  (#eq? @_property "animation")
+  (#not-any-of? @local.reference
+    "none" "initial" "inherit" "unset" "revert" "linear" "ease" "ease-in"
+    "ease-out" "ease-in-out" "step-start" "step-end" "infinite" "normal"
+    "reverse" "alternate" "alternate-reverse" "forwards" "backwards" "both"
+    "running" "paused"))
+
+; Cascade layers: `@layer a, b;` and `@layer a { ... }` define,
+; `@import ... layer(a)` references
+
+(layer_statement
+  name: (layer_name) @local.definition.namespace)
+
+(layer_statement
+  name: (layer_names
+    (layer_name) @local.definition.namespace))
+
+(import_layer
+  name: (layer_name) @local.reference)
@@ -0,0 +1,292 @@
+//! Cascade layers and `@scope`, following CSS Cascading Level 6.
+//!
+//! [`Cascade`] orders every layer a stylesheet declares, whether by
+//! `@layer a, b;`, an `@layer name {}` block, an anonymous `@layer {}`
+//! block, or `@import url(...) layer(name)`. A layer comes before the layers
+//! declared after it, and its sublayers come before its own styles, so
+//! `@layer a { @layer b {} }` orders `a.b` before `a`. Styles outside any
+//! layer come after every layer.
+//!
+//! Each style rule is listed with its layer and the `@scope` blocks it sits
+//! in, so the cascade position of a declaration can be read off its rule:
+//! [`Cascade::priority`] ranks layers, with `!important` reversing their
+//! order, and scope proximity is decided by the [`Scope`] bounds against a
+//! document.
+//!
+//! Anonymous layers cannot be referred to, and are named `<anonymous N>` in
+//! source order.
+//!
+//! ```rust
+//! use tree_sitter_css_svelte::{cascade::Cascade, LANGUAGE};
+//!
+//! let source = "@layer base, components;\n\
+//!     @layer components { @layer card { .card {} } }\n\
+//!     @scope (.card) to (.content) { img {} }";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let cascade = Cascade::new(tree.root_node(), source);
+//! assert_eq!(cascade.layers, ["base", "components.card", "components"]);
+//! assert_eq!(cascade.rules[0].layer.as_deref(), Some("components.card"));
+//! assert_eq!(cascade.rules[1].scopes[0].start.as_deref(), Some(".card"));
+//! assert_eq!(cascade.rules[1].scopes[0].end.as_deref(), Some(".content"));
+//! ```
+
+use std::collections::HashMap;
+
+use tree_sitter::{Node, Range};
+
+use crate::nesting;
+
+/// The layers and style rules of a stylesheet.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Cascade {
+    /// The full name of every layer, lowest priority first for normal
+    /// declarations.
+    pub layers: Vec<String>,
+    /// Every style rule, in source order.
+    pub rules: Vec<Rule>,
+    /// The full name of each layer block, by the start byte of its
+    /// `@layer` statement.
+    blocks: HashMap<usize, String>,
+}
+
+/// A style rule and where it sits in the cascade.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rule {
+    pub range: Range,
+    /// The rule's selectors with nesting resolved. See [`nesting::resolve`].
+    pub selectors: Vec<String>,
+    /// The full name of the rule's layer, or `None` when it is unlayered.
+    pub layer: Option<String>,
+    /// The `@scope` blocks around the rule, outermost first.
+    pub scopes: Vec<Scope>,
+}
+
+/// The bounds of an `@scope` block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Scope {
+    /// The span of the `@scope` statement.
+    pub range: Range,
+    /// The scoping root selectors, or `None` when the scope is the parent
+    /// element of the stylesheet's owner node, such as a `<style>` element.
+    pub start: Option<String>,
+    /// The scoping limit selectors after `to`.
+    pub end: Option<String>,
+}
+
+/// A layer and its sublayers, in the order they were declared.
+struct Layer {
+    name: String,
+    children: Vec<usize>,
+}
+
+struct Builder<'a> {
+    source: &'a str,
+    /// The layer tree, with the unlayered styles at index 0.
+    layers: Vec<Layer>,
+    anonymous: usize,
+    rules: Vec<Rule>,
+    blocks: HashMap<usize, String>,
+}
+
+impl Cascade {
+    /// Collect the layers and rules of the stylesheet at `root`.
+    pub fn new(root: Node, source: &str) -> Self {
+        let mut builder = Builder {
+            source,
+            layers: vec![Layer {
+                name: String::new(),
+                children: Vec::new(),
+            }],
+            anonymous: 0,
+            rules: Vec::new(),
+            blocks: HashMap::new(),
+        };
+        builder.visit(root, 0, &mut Vec::new());
+
+        let mut layers = Vec::new();
+        builder.order(0, &mut layers);
+        layers.pop();
+        Cascade {
+            layers,
+            rules: builder.rules,
+            blocks: builder.blocks,
+        }
+    }
+
+    /// The rank of declarations in `layer`, where `None` is unlayered: a
+    /// declaration with a higher rank wins over one with a lower rank,
+    /// before specificity is compared. Important declarations rank above
+    /// every normal one, and among them earlier layers win.
+    pub fn priority(&self, layer: Option<&str>, important: bool) -> usize {
+        let count = self.layers.len();
+        let index = layer.and_then(|layer| self.layers.iter().position(|name| name == layer));
+        match (index, important) {
+            (Some(index), false) => index,
+            (None, false) => count,
+            (Some(index), true) => count + 1 + (count - index),
+            (None, true) => count + 1,
+        }
+    }
+
+    /// The layer and `@scope` blocks `node`, such as a declaration, belongs
+    /// to in the tree this cascade was built from.
+    pub fn context(&self, node: Node, source: &str) -> (Option<String>, Vec<Scope>) {
+        let mut layer = None;
+        let mut scopes = Vec::new();
+        let mut current = node.parent();
+        while let Some(ancestor) = current {
+            match ancestor.kind() {
+                "layer_statement" if layer.is_none() => {
+                    layer = self.blocks.get(&ancestor.start_byte()).cloned();
+                }
+                "scope_statement" => scopes.push(scope(ancestor, source)),
+                _ => {}
+            }
+            current = ancestor.parent();
+        }
+        scopes.reverse();
+        (layer, scopes)
+    }
+}
+
+impl Builder<'_> {
+    fn visit(&mut self, node: Node, layer: usize, scopes: &mut Vec<Scope>) {
+        match node.kind() {
+            "import_statement" => {
+                let import = node
+                    .child_by_field_name("prelude")
+                    .and_then(|prelude| prelude.child_by_field_name("layer"));
+                if let Some(import) = import {
+                    match import.child_by_field_name("name") {
+                        Some(name) => {
+                            self.declare(layer, name);
+                        }
+                        None => {
+                            self.declare_anonymous(layer);
+                        }
+                    }
+                }
+                return;
+            }
+            "layer_statement" => {
+                let name = node.child_by_field_name("name");
+                let Some(block) = node.child_by_field_name("block") else {
+                    if let Some(names) = name {
+                        let mut cursor = names.walk();
+                        let names: Vec<_> = match names.kind() {
+                            "layer_names" => names.named_children(&mut cursor).collect(),
+                            _ => vec![names],
+                        };
+                        for name in names.into_iter().filter(|name| name.kind() == "layer_name") {
+                            self.declare(layer, name);
+                        }
+                    }
+                    return;
+                };
+                let inner = match name {
+                    Some(name) => self.declare(layer, name),
+                    None => self.declare_anonymous(layer),
+                };
+                self.blocks
+                    .insert(node.start_byte(), self.layers[inner].name.clone());
+                self.visit(block, inner, scopes);
+                return;
+            }
+            "scope_statement" => {
+                scopes.push(scope(node, self.source));
+                if let Some(block) = node.child_by_field_name("block") {
+                    self.visit(block, layer, scopes);
+                }
+                scopes.pop();
+                return;
+            }
+            "rule_set" | "global_block" => {
+                self.rules.push(Rule {
+                    range: node.range(),
+                    selectors: nesting::resolve(node, self.source),
+                    layer: (layer != 0).then(|| self.layers[layer].name.clone()),
+                    scopes: scopes.clone(),
+                });
+            }
+            _ => {}
+        }
+
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            self.visit(child, layer, scopes);
+        }
+    }
+
+    /// Declare the dotted `layer_name` node `name` within `parent`, and
+    /// return the innermost layer.
+    fn declare(&mut self, parent: usize, name: Node) -> usize {
+        let mut cursor = name.walk();
+        let parts: Vec<_> = name
+            .named_children(&mut cursor)
+            .filter(|part| part.kind() == "layer_name_part")
+            .map(|part| &self.source[part.byte_range()])
+            .collect();
+        parts
+            .into_iter()
+            .fold(parent, |parent, part| self.child(parent, part))
+    }
+
+    fn declare_anonymous(&mut self, parent: usize) -> usize {
+        self.anonymous += 1;
+        let name = format!("<anonymous {}>", self.anonymous);
+        self.child(parent, &name)
+    }
+
+    /// The sublayer `part` of `parent`, declared now if it is new.
+    fn child(&mut self, parent: usize, part: &str) -> usize {
+        let name = match self.layers[parent].name.as_str() {
+            "" => part.to_string(),
+            prefix => format!("{prefix}.{part}"),
+        };
+        if let Some(&existing) = self.layers[parent]
+            .children
+            .iter()
+            .find(|&&child| self.layers[child].name == name)
+        {
+            return existing;
+        }
+        self.layers.push(Layer {
+            name,
+            children: Vec::new(),
+        });
+        let index = self.layers.len() - 1;
+        self.layers[parent].children.push(index);
+        index
+    }
+
+    /// Push the names of `layer`'s sublayers, then its own.
+    fn order(&self, layer: usize, out: &mut Vec<String>) {
+        for &child in &self.layers[layer].children {
+            self.order(child, out);
+        }
+        out.push(self.layers[layer].name.clone());
+    }
+}
+
+fn scope(node: Node, source: &str) -> Scope {
+    let query = node.child_by_field_name("query");
+    let bound = |field: &str| {
+        let bound = query?.child_by_field_name(field)?;
+        let text = source[bound.byte_range()].trim();
+        let text = match field {
+            "end" => text.strip_prefix("to").unwrap_or(text).trim_start(),
+            _ => text,
+        };
+        let text = text.strip_prefix('(').unwrap_or(text);
+        let text = text.strip_suffix(')').unwrap_or(text);
+        Some(text.trim().to_string())
+    };
+    Scope {
+        range: node.range(),
+        start: bound("start"),
+        end: bound("end"),
+    }
+}
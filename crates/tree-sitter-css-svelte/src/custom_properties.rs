@@ -0,0 +1,443 @@
+//! Custom properties and the `var()` references between them.
+//!
+//! [`Graph`] collects every custom property declaration (`--name: ...`) and
+//! every `var(--name)` use, with the rules and at-rules each sits in. A
+//! declaration depends on the custom properties its value refers to, so
+//! the graph can report:
+//!
+//! - cycles, such as `--a: var(--b); --b: var(--a)`, which make every
+//!   property in them invalid at computed-value time
+//! - `var()` uses of properties nothing declares, when they have no fallback
+//! - declarations no `var()` refers to
+//!
+//! A graph can span many stylesheets, such as every `<style>` block of a
+//! project: each is added with its own document index, and references
+//! resolve across all of them.
+//!
+//! ```rust
+//! use tree_sitter_css_svelte::{custom_properties::Graph, LANGUAGE};
+//!
+//! let source = ":root { --gap: 4px; --pad: var(--gap); }\n.card { margin: var(--pad) var(--size); }";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let graph = Graph::new(tree.root_node(), source);
+//! assert_eq!(graph.dependencies("--pad").collect::<Vec<_>>(), ["--gap"]);
+//! assert_eq!(graph.declarations[0].context, [":root"]);
+//!
+//! let messages: Vec<_> = graph.check().iter().map(ToString::to_string).collect();
+//! assert_eq!(messages, ["`--size` is not defined and has no fallback"]);
+//! ```
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+
+use tree_sitter::{Node, Parser, Range};
+
+use crate::LANGUAGE;
+
+/// A `--name: value` declaration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Declaration {
+    pub name: String,
+    /// The span of the property name.
+    pub range: Range,
+    /// The preludes of the enclosing rules and at-rules, outermost first,
+    /// such as `["@media (width > 40em)", ".card"]`.
+    pub context: Vec<String>,
+    /// The custom properties the value refers to, in source order,
+    /// including those in fallbacks.
+    pub dependencies: Vec<String>,
+    /// The stylesheet the declaration was added from.
+    pub document: usize,
+}
+
+/// A `var(--name)` or `var(--name, fallback)` reference.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Use {
+    pub name: String,
+    /// The span of the referenced name.
+    pub range: Range,
+    /// Whether the reference has a fallback, which may be empty.
+    pub has_fallback: bool,
+    /// The property whose value holds the reference.
+    pub property: String,
+    /// The preludes of the enclosing rules and at-rules, outermost first.
+    pub context: Vec<String>,
+    /// The stylesheet the reference was added from.
+    pub document: usize,
+}
+
+/// Custom property declarations and uses, across any number of stylesheets.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Graph {
+    /// Every declaration, in the order added.
+    pub declarations: Vec<Declaration>,
+    /// Every `var()` reference, in the order added.
+    pub uses: Vec<Use>,
+}
+
+/// A problem found in a [`Graph`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    /// The span of the offending name.
+    pub range: Range,
+    /// The stylesheet `range` is in.
+    pub document: usize,
+}
+
+/// The typed payload of a [`Diagnostic`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// A declaration that depends on itself, directly or through others.
+    Cycle {
+        name: String,
+        /// Every property in the cycle, sorted.
+        cycle: Vec<String>,
+    },
+    /// A `var()` without fallback whose property nothing declares.
+    Undefined { name: String },
+    /// A declaration no `var()` refers to.
+    Unused { name: String },
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.kind, f)
+    }
+}
+
+impl fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiagnosticKind::Cycle { name, cycle } => {
+                write!(f, "`{name}` depends on itself through ")?;
+                for (index, member) in cycle.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "`{member}`")?;
+                }
+                Ok(())
+            }
+            DiagnosticKind::Undefined { name } => {
+                write!(f, "`{name}` is not defined and has no fallback")
+            }
+            DiagnosticKind::Unused { name } => write!(f, "`{name}` is never used"),
+        }
+    }
+}
+
+impl Graph {
+    /// The graph of a single stylesheet, as document 0.
+    pub fn new(root: Node, source: &str) -> Graph {
+        let mut graph = Graph::default();
+        graph.add_stylesheet(root, source, 0);
+        graph
+    }
+
+    /// Add the declarations and uses under `root`, recording them as coming
+    /// from `document`.
+    pub fn add_stylesheet(&mut self, root: Node, source: &str, document: usize) {
+        let mut context = Vec::new();
+        self.visit(root, source, document, &mut context);
+    }
+
+    /// Add a declaration written outside a stylesheet, such as a Svelte
+    /// `style:--name="value"` directive. `value` is parsed for `var()`
+    /// references, which are recorded as uses at `range`.
+    pub fn add_inline(
+        &mut self,
+        name: &str,
+        value: &str,
+        range: Range,
+        context: Vec<String>,
+        document: usize,
+    ) {
+        let mut dependencies = Vec::new();
+        let wrapped = format!("x{{{name}:{value}}}");
+        let mut parser = Parser::new();
+        let tree = parser
+            .set_language(&LANGUAGE.into())
+            .ok()
+            .and_then(|()| parser.parse(&wrapped, None));
+        if let Some(tree) = tree {
+            let mut found = Vec::new();
+            references(tree.root_node(), &wrapped, &mut found);
+            for (reference, has_fallback) in found {
+                let reference_name = wrapped[reference.byte_range()].to_string();
+                dependencies.push(reference_name.clone());
+                self.uses.push(Use {
+                    name: reference_name,
+                    range,
+                    has_fallback,
+                    property: name.to_string(),
+                    context: context.clone(),
+                    document,
+                });
+            }
+        }
+        self.declarations.push(Declaration {
+            name: name.to_string(),
+            range,
+            context,
+            dependencies,
+            document,
+        });
+    }
+
+    /// The custom properties `name` depends on, across all its
+    /// declarations, without repeats.
+    pub fn dependencies<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        let mut seen = HashSet::new();
+        self.declarations
+            .iter()
+            .filter(move |declaration| declaration.name == name)
+            .flat_map(|declaration| declaration.dependencies.iter())
+            .filter(move |dependency| seen.insert(dependency.as_str()))
+            .map(String::as_str)
+    }
+
+    /// Each set of custom properties that depend on each other, sorted by
+    /// name. A property that refers to itself is a cycle of one.
+    pub fn cycles(&self) -> Vec<Vec<String>> {
+        let mut edges: HashMap<&str, BTreeSet<&str>> = HashMap::new();
+        for declaration in &self.declarations {
+            edges
+                .entry(&declaration.name)
+                .or_default()
+                .extend(declaration.dependencies.iter().map(String::as_str));
+        }
+        let mut names: Vec<&str> = edges.keys().copied().collect();
+        names.sort_unstable();
+
+        let mut tarjan = Tarjan {
+            edges: &edges,
+            index: HashMap::new(),
+            low: HashMap::new(),
+            stack: Vec::new(),
+            on_stack: HashSet::new(),
+            next: 0,
+            components: Vec::new(),
+        };
+        for name in names {
+            if !tarjan.index.contains_key(name) {
+                tarjan.connect(name);
+            }
+        }
+
+        let mut cycles: Vec<Vec<String>> = tarjan
+            .components
+            .into_iter()
+            .filter(|component| {
+                component.len() > 1
+                    || edges
+                        .get(component[0])
+                        .is_some_and(|targets| targets.contains(component[0]))
+            })
+            .map(|component| {
+                let mut cycle: Vec<String> = component.into_iter().map(str::to_string).collect();
+                cycle.sort();
+                cycle
+            })
+            .collect();
+        cycles.sort();
+        cycles
+    }
+
+    /// Report cycles, undefined references without fallbacks, and unused
+    /// declarations, in the order they were added.
+    pub fn check(&self) -> Vec<Diagnostic> {
+        let declared: HashSet<&str> = self
+            .declarations
+            .iter()
+            .map(|declaration| declaration.name.as_str())
+            .collect();
+        let used: HashSet<&str> = self.uses.iter().map(|found| found.name.as_str()).collect();
+        let cycles = self.cycles();
+        let cycle_of = |name: &str| cycles.iter().find(|cycle| cycle.iter().any(|m| m == name));
+
+        let mut diagnostics = Vec::new();
+        for declaration in &self.declarations {
+            let kind = if let Some(cycle) = cycle_of(&declaration.name) {
+                DiagnosticKind::Cycle {
+                    name: declaration.name.clone(),
+                    cycle: cycle.clone(),
+                }
+            } else if !used.contains(declaration.name.as_str()) {
+                DiagnosticKind::Unused {
+                    name: declaration.name.clone(),
+                }
+            } else {
+                continue;
+            };
+            diagnostics.push(Diagnostic {
+                kind,
+                range: declaration.range,
+                document: declaration.document,
+            });
+        }
+        for found in &self.uses {
+            if !found.has_fallback && !declared.contains(found.name.as_str()) {
+                diagnostics.push(Diagnostic {
+                    kind: DiagnosticKind::Undefined {
+                        name: found.name.clone(),
+                    },
+                    range: found.range,
+                    document: found.document,
+                });
+            }
+        }
+        diagnostics.sort_by_key(|diagnostic| (diagnostic.document, diagnostic.range.start_byte));
+        diagnostics
+    }
+
+    fn visit(&mut self, node: Node, source: &str, document: usize, context: &mut Vec<String>) {
+        if node.kind() == "declaration" {
+            self.declaration(node, source, document, context);
+            return;
+        }
+
+        let prelude = prelude(node, source);
+        if let Some(prelude) = &prelude {
+            context.push(prelude.clone());
+        }
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            self.visit(child, source, document, context);
+        }
+        if prelude.is_some() {
+            context.pop();
+        }
+    }
+
+    fn declaration(&mut self, node: Node, source: &str, document: usize, context: &[String]) {
+        let Some(property) = node.child_by_field_name("property") else {
+            return;
+        };
+        let property_name = &source[property.byte_range()];
+
+        let mut found = Vec::new();
+        if let Some(value) = node.child_by_field_name("value") {
+            references(value, source, &mut found);
+        }
+        for &(reference, has_fallback) in &found {
+            self.uses.push(Use {
+                name: source[reference.byte_range()].to_string(),
+                range: reference.range(),
+                has_fallback,
+                property: property_name.to_string(),
+                context: context.to_vec(),
+                document,
+            });
+        }
+
+        if property_name.starts_with("--") {
+            self.declarations.push(Declaration {
+                name: property_name.to_string(),
+                range: property.range(),
+                context: context.to_vec(),
+                dependencies: found
+                    .iter()
+                    .map(|(reference, _)| source[reference.byte_range()].to_string())
+                    .collect(),
+                document,
+            });
+        }
+    }
+}
+
+/// Every `var()` under `node`, as the referenced name node and whether the
+/// call has a fallback.
+fn references<'tree>(node: Node<'tree>, source: &str, found: &mut Vec<(Node<'tree>, bool)>) {
+    if node.kind() == "call_expression" {
+        let is_var = node
+            .named_child(0)
+            .is_some_and(|name| source[name.byte_range()].eq_ignore_ascii_case("var"));
+        let arguments = node
+            .named_child(1)
+            .filter(|arguments| arguments.kind() == "arguments");
+        if let (true, Some(arguments)) = (is_var, arguments) {
+            let name = arguments
+                .named_child(0)
+                .filter(|name| source[name.byte_range()].starts_with("--"));
+            if let Some(name) = name {
+                let mut cursor = arguments.walk();
+                let has_fallback = arguments
+                    .children(&mut cursor)
+                    .any(|child| child.kind() == ",");
+                found.push((name, has_fallback));
+            }
+        }
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        references(child, source, found);
+    }
+}
+
+/// The prelude of a rule, at-rule, or keyframe block, with whitespace
+/// collapsed.
+fn prelude(node: Node, source: &str) -> Option<String> {
+    let end = match node.kind() {
+        "keyframe_block" => node.named_child(0)?.end_byte(),
+        _ => node.child_by_field_name("block")?.start_byte(),
+    };
+    let text = &source[node.start_byte()..end];
+    Some(text.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+/// Tarjan's strongly connected components over custom property names.
+struct Tarjan<'a> {
+    edges: &'a HashMap<&'a str, BTreeSet<&'a str>>,
+    index: HashMap<&'a str, usize>,
+    low: HashMap<&'a str, usize>,
+    stack: Vec<&'a str>,
+    on_stack: HashSet<&'a str>,
+    next: usize,
+    components: Vec<Vec<&'a str>>,
+}
+
+impl<'a> Tarjan<'a> {
+    fn connect(&mut self, name: &'a str) {
+        self.index.insert(name, self.next);
+        self.low.insert(name, self.next);
+        self.next += 1;
+        self.stack.push(name);
+        self.on_stack.insert(name);
+
+        let edges = self.edges;
+        for &target in edges.get(name).into_iter().flatten() {
+            if !edges.contains_key(target) {
+                continue;
+            }
+            if !self.index.contains_key(target) {
+                self.connect(target);
+                let low = self.low[name].min(self.low[target]);
+                self.low.insert(name, low);
+            } else if self.on_stack.contains(target) {
+                let low = self.low[name].min(self.index[target]);
+                self.low.insert(name, low);
+            }
+        }
+
+        if self.low[name] == self.index[name] {
+            let mut component = Vec::new();
+            while let Some(member) = self.stack.pop() {
+                self.on_stack.remove(member);
+                component.push(member);
+                if member == name {
+                    break;
+                }
+            }
+            self.components.push(component);
+        }
+    }
+}
+
+/// Report the problems in a single stylesheet. See [`Graph::check`].
+pub fn check(root: Node, source: &str) -> Vec<Diagnostic> {
+    Graph::new(root, source).check()
+}
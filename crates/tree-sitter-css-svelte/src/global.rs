@@ -0,0 +1,304 @@
+//! Svelte's `:global` selectors.
+//!
+//! Svelte scopes every selector in a component's `<style>` to that component
+//! unless it is marked global, in one of three forms:
+//!
+//! - functional, `:global(.foo)`: the argument selectors are global
+//! - compound, `:global .foo`: everything after `:global` is global
+//! - block, `:global { ... }` or `.x :global { ... }`: every rule nested in
+//!   the block is global
+//!
+//! The grammar parses each `:global` as a `global_selector`, and a rule whose
+//! selector ends in a bare `:global` as a `global_block`. This module tells
+//! the forms apart, decides whether a selector node is global, and reports
+//! `:global` uses the Svelte compiler rejects.
+//!
+//! ```rust
+//! use tree_sitter_css_svelte::global::{self, GlobalKind};
+//! use tree_sitter_css_svelte::LANGUAGE;
+//!
+//! let source = "a :global(.b) .c {} :global { .d {} }";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let found = global::find(tree.root_node());
+//! assert_eq!(found[0].kind, GlobalKind::Functional);
+//! assert_eq!(found[1].kind, GlobalKind::Block);
+//!
+//! let selectors = found[0].selectors();
+//! assert_eq!(&source[selectors[0].byte_range()], ".b");
+//! assert!(global::is_global(selectors[0]));
+//! ```
+
+use std::fmt;
+
+use tree_sitter::{Node, Range};
+
+/// How a `:global` selector is written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GlobalKind {
+    /// `:global(selector, ...)`.
+    Functional,
+    /// A bare `:global` followed by more selectors, as in `:global .foo`.
+    Compound,
+    /// A bare `:global` ending its selector, so the rule's block is global.
+    Block,
+}
+
+/// A `global_selector`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Global<'tree> {
+    pub kind: GlobalKind,
+    /// The `global_selector` node.
+    pub node: Node<'tree>,
+}
+
+impl<'tree> Global<'tree> {
+    /// Read `node` as a `:global` selector.
+    pub fn from_node(node: Node<'tree>) -> Option<Self> {
+        kind(node).map(|kind| Global { kind, node })
+    }
+
+    /// The selectors inside `:global(...)`. Empty for the other forms.
+    pub fn selectors(&self) -> Vec<Node<'tree>> {
+        let Some(arguments) = arguments(self.node) else {
+            return Vec::new();
+        };
+        let mut cursor = arguments.walk();
+        arguments.named_children(&mut cursor).collect()
+    }
+}
+
+/// A `:global` the Svelte compiler rejects.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    /// The span of the offending node.
+    pub range: Range,
+}
+
+/// The typed payload of a [`Diagnostic`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// `:global()` with nothing inside.
+    EmptyGlobal,
+    /// An argument of `:global(...)` that is not a selector, such as `1px`.
+    NotASelector {
+        /// The argument as written.
+        argument: String,
+    },
+    /// A `:global { ... }` block whose selector list has other selectors.
+    GlobalBlockInList,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.kind, f)
+    }
+}
+
+impl fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiagnosticKind::EmptyGlobal => f.write_str("`:global()` needs a selector"),
+            DiagnosticKind::NotASelector { argument } => {
+                write!(f, "`{argument}` is not a selector")
+            }
+            DiagnosticKind::GlobalBlockInList => {
+                f.write_str("a `:global {...}` block cannot be part of a selector list")
+            }
+        }
+    }
+}
+
+/// How `node` is written if it is a `global_selector`.
+pub fn kind(node: Node) -> Option<GlobalKind> {
+    if node.kind() != "global_selector" {
+        return None;
+    }
+
+    if arguments(node).is_some() {
+        Some(GlobalKind::Functional)
+    } else if ends_selector(node) {
+        Some(GlobalKind::Block)
+    } else {
+        Some(GlobalKind::Compound)
+    }
+}
+
+/// Every `:global` under `root`, in source order.
+pub fn find(root: Node) -> Vec<Global> {
+    let mut found = Vec::new();
+    collect(root, &mut found);
+    found
+}
+
+/// Whether the selector `node` matches unscoped: it is inside
+/// `:global(...)`, follows a compound `:global` in its complex selector, or
+/// belongs to a rule nested in a `global_block`.
+pub fn is_global(node: Node) -> bool {
+    let mut complex = node;
+    let mut current = Some(node);
+    while let Some(ancestor) = current {
+        // Only the arguments are global: `div` in `div:global(.x)` is the
+        // scoped compound the `:global(...)` is attached to.
+        if ancestor.kind() == "arguments"
+            && ancestor
+                .parent()
+                .is_some_and(|parent| kind(parent) == Some(GlobalKind::Functional))
+        {
+            return true;
+        }
+        if ancestor.kind() == "selectors" {
+            break;
+        }
+        complex = ancestor;
+        current = ancestor.parent();
+    }
+
+    let mut globals = Vec::new();
+    collect(complex, &mut globals);
+    if globals.iter().any(|global| {
+        global.kind == GlobalKind::Compound && global.node.end_byte() <= node.start_byte()
+    }) {
+        return true;
+    }
+
+    let mut rule = current.and_then(|selectors| selectors.parent());
+    while let Some(outer) = rule.and_then(|rule| rule.parent()).and_then(enclosing_rule) {
+        if outer.kind() == "global_block" {
+            return true;
+        }
+        rule = Some(outer);
+    }
+    false
+}
+
+/// Report empty `:global()`, non-selector arguments, and `:global` blocks in
+/// selector lists under `root`, in source order.
+pub fn check(root: Node, source: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    for global in find(root) {
+        match global.kind {
+            GlobalKind::Functional => {
+                let selectors = global.selectors();
+                if selectors.is_empty() {
+                    diagnostics.push(Diagnostic {
+                        kind: DiagnosticKind::EmptyGlobal,
+                        range: global.node.range(),
+                    });
+                }
+                for argument in selectors {
+                    if !is_selector(argument) {
+                        diagnostics.push(Diagnostic {
+                            kind: DiagnosticKind::NotASelector {
+                                argument: source[argument.byte_range()].to_string(),
+                            },
+                            range: argument.range(),
+                        });
+                    }
+                }
+            }
+            GlobalKind::Block => {
+                let list = complex_selector(global.node).parent();
+                if list.is_some_and(|list| list.named_child_count() > 1) {
+                    diagnostics.push(Diagnostic {
+                        kind: DiagnosticKind::GlobalBlockInList,
+                        range: global.node.range(),
+                    });
+                }
+            }
+            GlobalKind::Compound => {}
+        }
+    }
+    diagnostics
+}
+
+fn collect<'tree>(node: Node<'tree>, found: &mut Vec<Global<'tree>>) {
+    if let Some(global) = Global::from_node(node) {
+        found.push(global);
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect(child, found);
+    }
+}
+
+fn arguments(node: Node) -> Option<Node> {
+    let mut cursor = node.walk();
+    let arguments = node
+        .children(&mut cursor)
+        .find(|child| child.kind() == "arguments");
+    arguments
+}
+
+/// Whether `node` is the rightmost compound of a selector in a rule's
+/// selector list.
+fn ends_selector(node: Node) -> bool {
+    let mut current = node;
+    while let Some(parent) = current.parent() {
+        if parent.kind() == "selectors" {
+            return true;
+        }
+        if !is_combinator(parent.kind()) {
+            return false;
+        }
+        let last = parent.named_child(parent.named_child_count().saturating_sub(1) as u32);
+        if last != Some(current) {
+            return false;
+        }
+        current = parent;
+    }
+    false
+}
+
+/// The complex selector containing `node`: its ancestor just below
+/// `selectors`.
+fn complex_selector(node: Node) -> Node {
+    let mut current = node;
+    while let Some(parent) = current.parent() {
+        if parent.kind() == "selectors" {
+            break;
+        }
+        current = parent;
+    }
+    current
+}
+
+/// The nearest `rule_set` or `global_block` at or above `node`.
+fn enclosing_rule(node: Node) -> Option<Node> {
+    let mut current = Some(node);
+    while let Some(candidate) = current {
+        if matches!(candidate.kind(), "rule_set" | "global_block") {
+            return Some(candidate);
+        }
+        current = candidate.parent();
+    }
+    None
+}
+
+fn is_combinator(kind: &str) -> bool {
+    matches!(
+        kind,
+        "descendant_selector" | "child_selector" | "sibling_selector" | "adjacent_sibling_selector"
+    )
+}
+
+fn is_selector(node: Node) -> bool {
+    is_combinator(node.kind())
+        || matches!(
+            node.kind(),
+            "attribute_selector"
+                | "class_selector"
+                | "column_selector"
+                | "global_selector"
+                | "id_selector"
+                | "namespace_selector"
+                | "nesting_selector"
+                | "pseudo_class_selector"
+                | "pseudo_element_selector"
+                | "tag_name"
+                | "universal_selector"
+        )
+}
@@ -1,7 +1,26 @@
 //! Tree-sitter grammar for CSS with Svelte-oriented recovery.
+//!
+//! [`global`] reads Svelte's `:global(...)`, `:global .x`, and `:global {}`
+//! forms, which parse as `global_selector` and `global_block` nodes.
+//! [`specificity`] computes Selectors Level 4 specificity.
+//! [`custom_properties`] builds the graph of custom properties and their
+//! `var()` references. [`serialize`] formats and minifies stylesheets.
+//! [`values`] reads math, color, and gradient functions into typed values.
+//! [`nesting`] flattens nested style rules into plain selectors. [`cascade`]
+//! orders cascade layers and lists each rule's layer and `@scope` bounds.
+//! [`preprocessor`] parses SCSS and Less stylesheets permissively.
 
 use tree_sitter_language::LanguageFn;
 
+pub mod cascade;
+pub mod custom_properties;
+pub mod global;
+pub mod nesting;
+pub mod preprocessor;
+pub mod serialize;
+pub mod specificity;
+pub mod values;
+
 extern "C" {
     fn tree_sitter_css() -> *const ();
 }
@@ -60,6 +79,19 @@ pub fn scanner_profile_stats() -> ScannerProfileStats {
 /// The syntax highlighting query for CSS.
 pub const HIGHLIGHTS_QUERY: &str = include_str!("../queries/highlights.scm");
 
+/// The injection query for CSS (comment contents).
+pub const INJECTIONS_QUERY: &str = include_str!("../queries/injections.scm");
+
+/// The folding query for CSS.
+pub const FOLDS_QUERY: &str = include_str!("../queries/folds.scm");
+
+/// The indentation query for CSS.
+pub const INDENTS_QUERY: &str = include_str!("../queries/indents.scm");
+
+/// The locals query for CSS: custom properties, keyframes, and cascade
+/// layers.
+pub const LOCALS_QUERY: &str = include_str!("../queries/locals.scm");
+
 /// The content of the node-types.json file for CSS.
 pub const NODE_TYPES: &str = include_str!("node-types.json");
 
@@ -74,4 +106,24 @@ mod tests {
             .set_language(&LANGUAGE.into())
             .expect("Error loading CSS parser");
     }
+
+    #[test]
+    fn test_editor_queries_compile() {
+        let language = language();
+
+        for (name, source, expected_capture) in [
+            ("highlights", HIGHLIGHTS_QUERY, "tag"),
+            ("injections", INJECTIONS_QUERY, "injection.content"),
+            ("folds", FOLDS_QUERY, "fold"),
+            ("indents", INDENTS_QUERY, "indent.begin"),
+            ("locals", LOCALS_QUERY, "local.scope"),
+        ] {
+            let query = tree_sitter::Query::new(&language, source)
+                .unwrap_or_else(|error| panic!("{name} query should compile: {error}"));
+            assert!(
+                query.capture_names().contains(&expected_capture),
+                "missing @{expected_capture} from CSS {name} query"
+            );
+        }
+    }
 }
@@ -0,0 +1,211 @@
+//! Flattening nested style rules, following CSS Nesting Level 1.
+//!
+//! [`resolve`] expands each selector of a rule's prelude into the selector
+//! it matches with once nesting is taken away:
+//!
+//! - `&` is replaced with `:is(...)` around the parent rule's resolved
+//!   selectors, wherever it appears, including inside `:not(&)`
+//! - a selector without `&` is relative to the parent, so it gets an
+//!   implicit `& ` prefix, or `&` before its leading combinator as in
+//!   `& > .b`
+//! - a rule nested in `@media`, `@supports`, or another conditional at-rule
+//!   keeps the nearest style rule around it as its parent
+//!
+//! Directly inside `@scope`, `&` is `:where(:scope)` and the implicit prefix
+//! is `:scope`, as CSS Cascading Level 6 defines. At the top level, `&` is
+//! `:scope`, and a selector without it is left as is.
+//!
+//! Svelte's `:global` blocks are not style rules of their own. A bare
+//! `:global { ... }` is transparent, so the rules in it keep the enclosing
+//! parent, and `.x :global { ... }` is the parent `.x` for the rules in it.
+//!
+//! Whitespace in the result is collapsed and comments are dropped.
+//!
+//! ```rust
+//! use tree_sitter_css_svelte::{nesting, LANGUAGE};
+//!
+//! let source = ".a, .b { > .c { .d & {} } }";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let rules = nesting::resolve_all(tree.root_node(), source);
+//! let resolved: Vec<_> = rules.iter().map(|(_, selectors)| selectors.join(", ")).collect();
+//! assert_eq!(
+//!     resolved,
+//!     [".a, .b", ":is(.a, .b) > .c", ".d :is(:is(.a, .b) > .c)"]
+//! );
+//! ```
+
+use tree_sitter::{Node, Range};
+
+/// What `&` refers to in a rule.
+enum Parent {
+    /// The rule is at the top level.
+    Root,
+    /// The rule is directly inside `@scope`.
+    Scope,
+    /// The resolved selectors of the enclosing style rule, and the
+    /// combinator a selector without `&` is joined to them with.
+    Rule(Vec<String>, &'static str),
+}
+
+/// The fully expanded selectors of the `rule_set` or `global_block` node
+/// `rule_set`, one per selector of its prelude. The selectors of a
+/// `global_block` stop before their `:global`, so a bare `:global { ... }`
+/// has none.
+pub fn resolve(rule_set: Node, source: &str) -> Vec<String> {
+    global_prefix(rule_set, source).0
+}
+
+/// The expanded selectors of `rule_set` as [`resolve`] reads them, and the
+/// combinator before the `:global` of a `global_block`.
+fn global_prefix(rule_set: Node, source: &str) -> (Vec<String>, &'static str) {
+    let Some(prelude) = rule_set.child_by_field_name("prelude") else {
+        return (Vec::new(), " ");
+    };
+    let parent = parent(rule_set, source);
+    let mut combinator = " ";
+    let mut cursor = prelude.walk();
+    let selectors = prelude
+        .named_children(&mut cursor)
+        .filter(|selector| !matches!(selector.kind(), "comment" | "js_comment"))
+        .filter_map(|selector| {
+            let text = &source[selector.byte_range()];
+            if rule_set.kind() != "global_block" {
+                return Some(expand(text, &parent));
+            }
+            let (prefix, before) = strip_global(text);
+            combinator = before;
+            (!prefix.is_empty()).then(|| expand(prefix, &parent))
+        })
+        .collect();
+    (selectors, combinator)
+}
+
+/// Every `rule_set` and `global_block` under `root` in source order, with
+/// its expanded selectors.
+pub fn resolve_all(root: Node, source: &str) -> Vec<(Range, Vec<String>)> {
+    let mut rules = Vec::new();
+    collect(root, source, &mut rules);
+    rules
+}
+
+fn collect(node: Node, source: &str, rules: &mut Vec<(Range, Vec<String>)>) {
+    if matches!(node.kind(), "rule_set" | "global_block") {
+        rules.push((node.range(), resolve(node, source)));
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect(child, source, rules);
+    }
+}
+
+fn parent(rule_set: Node, source: &str) -> Parent {
+    let mut current = rule_set.parent();
+    while let Some(ancestor) = current {
+        match ancestor.kind() {
+            "rule_set" => return Parent::Rule(resolve(ancestor, source), " "),
+            "global_block" => {
+                let (selectors, combinator) = global_prefix(ancestor, source);
+                if !selectors.is_empty() {
+                    return Parent::Rule(selectors, combinator);
+                }
+                current = ancestor.parent();
+            }
+            "scope_statement" => return Parent::Scope,
+            _ => current = ancestor.parent(),
+        }
+    }
+    Parent::Root
+}
+
+/// `selector` without the bare `:global` it ends in, and the combinator
+/// before that `:global`, such as `" > "` in `.x > :global`.
+fn strip_global(selector: &str) -> (&str, &'static str) {
+    let selector = selector.trim();
+    let Some(prefix) = selector.strip_suffix(":global") else {
+        return (selector, " ");
+    };
+    let prefix = prefix.trim_end();
+    for (symbol, combinator) in [('>', " > "), ('+', " + "), ('~', " ~ ")] {
+        if let Some(rest) = prefix.strip_suffix(symbol) {
+            return (rest.trim_end(), combinator);
+        }
+    }
+    (prefix, " ")
+}
+
+fn expand(selector: &str, parent: &Parent) -> String {
+    let nesting = match parent {
+        Parent::Root => ":scope".to_string(),
+        Parent::Scope => ":where(:scope)".to_string(),
+        Parent::Rule(selectors, _) => format!(":is({})", selectors.join(", ")),
+    };
+    let (expanded, nested) = substitute(selector, &nesting);
+    if nested {
+        return expanded;
+    }
+
+    let (prefix, combinator) = match parent {
+        Parent::Root => return expanded,
+        Parent::Scope => (":scope", " "),
+        Parent::Rule(_, combinator) => (nesting.as_str(), *combinator),
+    };
+    // A leading combinator such as `> .b` reads as `& > .b`.
+    format!("{prefix}{combinator}{expanded}")
+}
+
+/// Replace each `&` in `selector` with `nesting`, collapsing whitespace
+/// and dropping comments. Quoted strings and escapes are copied as written.
+/// Also returns whether there was an `&`.
+fn substitute(selector: &str, nesting: &str) -> (String, bool) {
+    let mut out = String::with_capacity(selector.len());
+    let mut nested = false;
+    let mut space = false;
+    let mut chars = selector.trim().chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '/' && chars.peek() == Some(&'*') {
+            chars.next();
+            let mut previous = ' ';
+            for c in chars.by_ref() {
+                if previous == '*' && c == '/' {
+                    break;
+                }
+                previous = c;
+            }
+            continue;
+        }
+        if c.is_whitespace() {
+            space = true;
+            continue;
+        }
+        if space && !out.is_empty() {
+            out.push(' ');
+        }
+        space = false;
+        match c {
+            '&' => {
+                out.push_str(nesting);
+                nested = true;
+            }
+            '\\' => {
+                out.push(c);
+                out.extend(chars.next());
+            }
+            '"' | '\'' => {
+                out.push(c);
+                while let Some(inner) = chars.next() {
+                    out.push(inner);
+                    if inner == '\\' {
+                        out.extend(chars.next());
+                    } else if inner == c {
+                        break;
+                    }
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+    (out, nested)
+}
@@ -0,0 +1,482 @@
+//! A permissive mode for SCSS and Less stylesheets.
+//!
+//! The CSS grammar has no rules for preprocessor syntax, so [`mask`]
+//! rewrites each construct the grammar rejects into CSS of the same byte
+//! length, and [`parse`] parses the result. Every node of the tree then
+//! spans the same bytes in the original source, where its text can be read
+//! as written:
+//!
+//! - `$name` (SCSS) and `@name` in values (Less) become identifiers
+//! - `#{...}` (SCSS) and `@{...}` (Less) interpolation becomes an
+//!   identifier, so `.icon-#{$name}` reads as a class selector
+//! - `//` comments become block comments
+//! - `%placeholder` selectors (SCSS) become class selectors
+//! - `!default`, `!global`, and `!optional` flags (SCSS) become comments
+//! - a suffix appended to the parent selector, as in `&__item` or `&-x`,
+//!   becomes a pseudo-class of `&`, so the selector stays one compound
+//! - the `.` of a module member such as `math.div(...)` or `math.$pi`
+//!   (SCSS) becomes a `-`, so it reads as a function or value
+//! - a Sass map such as `(key: value)` (SCSS) becomes an identifier
+//! - `~"..."` escapes (Less) lose the `~`
+//! - `.mixin();` calls (Less) become at-rules, like `@include` in SCSS,
+//!   and the parameters of `.mixin(@a) { ... }` become part of its name
+//!
+//! The tree has CSS node kinds, not SCSS or Less ones: `$name` is a
+//! `plain_value` or `property_name`, `&__item` a `pseudo_class_selector`,
+//! and a map a `plain_value`. The [`Construct`]s [`mask`] returns tell these
+//! apart from the CSS they read as. Editors should inject SCSS and Less
+//! stylesheets as their own languages rather than rely on this mode.
+//!
+//! `@mixin`, `@include`, `@use`, `@if`, `@each`, and the other directives
+//! already parse as generic `at_rule` nodes. The indented Sass syntax is
+//! not supported.
+//!
+//! ```rust
+//! use tree_sitter_css_svelte::preprocessor::{self, Dialect};
+//! use tree_sitter_css_svelte::LANGUAGE;
+//!
+//! let source = "$gap: 4px !default;\n.card-#{$size} { margin: $gap * 2; // twice\n}";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = preprocessor::parse(&mut parser, source, Dialect::Scss).unwrap();
+//! assert!(!tree.root_node().has_error());
+//!
+//! let rule = tree.root_node().named_child(1).unwrap();
+//! let prelude = rule.child_by_field_name("prelude").unwrap();
+//! assert_eq!(&source[prelude.byte_range()], ".card-#{$size}");
+//! ```
+
+use std::ops::Range;
+
+use tree_sitter::{Parser, Tree};
+
+/// A preprocessor language.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dialect {
+    Scss,
+    Less,
+}
+
+impl Dialect {
+    /// The dialect of a `lang` or `type` attribute value, such as `scss` or
+    /// `text/less`.
+    pub fn from_lang(lang: &str) -> Option<Dialect> {
+        let lang = lang.trim().to_ascii_lowercase();
+        match lang.strip_prefix("text/").unwrap_or(&lang) {
+            "scss" => Some(Dialect::Scss),
+            "less" => Some(Dialect::Less),
+            _ => None,
+        }
+    }
+}
+
+/// A preprocessor construct [`mask`] rewrote.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Construct {
+    pub kind: ConstructKind,
+    /// The byte range of the construct in the source.
+    pub range: Range<usize>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConstructKind {
+    /// A variable: `$name`, or `@name` in Less.
+    Variable,
+    /// `#{...}`, or `@{...}` in Less.
+    Interpolation,
+    /// A `//` comment.
+    LineComment,
+    /// A `%placeholder` selector.
+    Placeholder,
+    /// A `!default`, `!global`, or `!optional` flag.
+    Flag,
+    /// A suffix appended to the parent selector, such as `__item` in
+    /// `&__item`.
+    ParentSuffix,
+    /// A member of a module loaded with `@use`, such as `math.div` or
+    /// `math.$pi`.
+    ModuleMember,
+    /// A Sass map, such as `(key: value)`.
+    Map,
+    /// A Less `~"..."` escape.
+    Escape,
+    /// A Less `.mixin();` call.
+    MixinCall,
+    /// The parameters of a Less mixin definition, such as `(@width: 1px)`
+    /// in `.bordered(@width: 1px) { ... }`.
+    Parameters,
+}
+
+/// Parse `source` as `dialect` with `parser`, which must be set to the CSS
+/// language. Node ranges are in `source`.
+pub fn parse(parser: &mut Parser, source: &str, dialect: Dialect) -> Option<Tree> {
+    let (masked, _) = mask(source, dialect);
+    parser.parse(masked, None)
+}
+
+/// `source` with every preprocessor construct the CSS grammar rejects
+/// rewritten into CSS of the same length, and the constructs rewritten, in
+/// source order.
+pub fn mask(source: &str, dialect: Dialect) -> (String, Vec<Construct>) {
+    let bytes = source.as_bytes();
+    let mut out = bytes.to_vec();
+    let mut constructs = Vec::new();
+    // The last byte outside whitespace and comments, to tell where a
+    // statement starts.
+    let mut last = b';';
+    // Whether `index` is in the prelude of an at-rule, where parentheses
+    // hold media features rather than maps.
+    let mut at_rule = false;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+        let next = bytes.get(index + 1).copied();
+        let start = index;
+        let statement_start = matches!(last, b';' | b'{' | b'}');
+        if statement_start {
+            at_rule = byte == b'@';
+        }
+        if matches!(byte, b';' | b'{' | b'}') {
+            at_rule = false;
+        }
+
+        match (byte, next) {
+            (b'/', Some(b'*')) => {
+                index = find(bytes, index + 2, b"*/").map_or(bytes.len(), |end| end + 2);
+                continue;
+            }
+            (b'/', Some(b'/')) => {
+                let end = bytes[index..]
+                    .iter()
+                    .position(|&byte| byte == b'\n')
+                    .map_or(bytes.len(), |offset| index + offset);
+                blank_comment(&mut out[index..end]);
+                constructs.push(construct(ConstructKind::LineComment, index..end));
+                index = end;
+                continue;
+            }
+            (b'"' | b'\'', _) => {
+                index = skip_string(bytes, index);
+            }
+            (b'\\', _) => index += 2,
+            (b'u' | b'U', _) if is_url(bytes, index) => {
+                index = bytes[index..]
+                    .iter()
+                    .position(|&byte| byte == b')')
+                    .map_or(bytes.len(), |offset| index + offset + 1);
+            }
+            (b'#', Some(b'{')) if dialect == Dialect::Scss => {
+                index = interpolation(bytes, &mut out, index, &mut constructs);
+            }
+            (b'@', Some(b'{')) if dialect == Dialect::Less => {
+                index = interpolation(bytes, &mut out, index, &mut constructs);
+            }
+            (b'$', Some(next)) if dialect == Dialect::Scss && is_name_start(next) => {
+                out[index] = b'-';
+                index = name_end(bytes, index + 1);
+                constructs.push(construct(ConstructKind::Variable, start..index));
+            }
+            (b'@', Some(next)) if dialect == Dialect::Less && is_name_start(next) => {
+                index = name_end(bytes, index + 1);
+                // `@name: value;` already parses as an at-rule, and other
+                // at-rules start statements too.
+                let declared = bytes.get(skip_whitespace(bytes, index)) == Some(&b':');
+                if !statement_start {
+                    out[start] = b'-';
+                }
+                if !statement_start || declared {
+                    constructs.push(construct(ConstructKind::Variable, start..index));
+                }
+            }
+            // `50%` and `$a%b` are not placeholders.
+            (b'%', Some(next))
+                if dialect == Dialect::Scss
+                    && is_name_start(next)
+                    && index.checked_sub(1).is_none_or(|previous| {
+                        let previous = bytes[previous];
+                        !is_name_byte(previous) && !matches!(previous, b')' | b'.' | b'%')
+                    }) =>
+            {
+                out[index] = b'.';
+                index = name_end(bytes, index + 1);
+                constructs.push(construct(ConstructKind::Placeholder, start..index));
+            }
+            (b'!', Some(_)) if dialect == Dialect::Scss => {
+                let end = name_end(bytes, index + 1);
+                let flag = &source[index + 1..end];
+                if ["default", "global", "optional"]
+                    .iter()
+                    .any(|known| flag.eq_ignore_ascii_case(known))
+                {
+                    blank_comment(&mut out[index..end]);
+                    constructs.push(construct(ConstructKind::Flag, index..end));
+                }
+                index = end.max(index + 1);
+            }
+            // `&__item` and `&-x` read as `&:_item` and `&:x`.
+            (b'&', Some(b'-' | b'_' | b'a'..=b'z' | b'A'..=b'Z'))
+                if bytes
+                    .get(index + 2)
+                    .is_some_and(|&after| after.is_ascii() && is_name_start(after)) =>
+            {
+                out[index + 1] = b':';
+                index = name_end(bytes, index + 1);
+                constructs.push(construct(ConstructKind::ParentSuffix, start + 1..index));
+            }
+            (b'.', Some(next))
+                if dialect == Dialect::Scss
+                    && (next == b'$' || is_name_start(next))
+                    && module_start(bytes, index).is_some() =>
+            {
+                let member = if next == b'$' { index + 2 } else { index + 1 };
+                let end = name_end(bytes, member);
+                if next == b'$' || bytes.get(end) == Some(&b'(') {
+                    out[index..member].fill(b'-');
+                    let module = module_start(bytes, index).unwrap_or(index);
+                    constructs.push(construct(ConstructKind::ModuleMember, module..end));
+                    index = end;
+                } else {
+                    index += 1;
+                }
+            }
+            (b'(', _)
+                if dialect == Dialect::Scss
+                    && !at_rule
+                    && matches!(last, b':' | b',' | b'(')
+                    && index
+                        .checked_sub(1)
+                        .is_none_or(|previous| !is_name_byte(bytes[previous])) =>
+            {
+                match map_end(bytes, index) {
+                    Some(end) => {
+                        out[index..end].fill(b'_');
+                        constructs.push(construct(ConstructKind::Map, start..end));
+                        index = end;
+                    }
+                    None => index += 1,
+                }
+            }
+            (b'~', Some(b'"' | b'\'')) if dialect == Dialect::Less => {
+                out[index] = b' ';
+                index = skip_string(bytes, index + 1);
+                constructs.push(construct(ConstructKind::Escape, start..index));
+            }
+            (b'.', Some(next))
+                if dialect == Dialect::Less && statement_start && is_name_start(next) =>
+            {
+                let name = name_end(bytes, index + 1);
+                let parameters = skip_whitespace(bytes, name);
+                match mixin(bytes, parameters) {
+                    Some((end, b';')) => {
+                        out[index] = b'@';
+                        index = end + 1;
+                        constructs.push(construct(ConstructKind::MixinCall, start..index));
+                    }
+                    Some((end, _)) => {
+                        out[parameters..end].fill(b'_');
+                        constructs.push(construct(ConstructKind::Parameters, parameters..end));
+                        index = end;
+                    }
+                    None => index = name,
+                }
+            }
+            _ => index += 1,
+        }
+
+        if let Some(&byte) = bytes[start..index.min(bytes.len())]
+            .iter()
+            .rev()
+            .find(|byte| !byte.is_ascii_whitespace())
+        {
+            last = byte;
+        }
+    }
+
+    let masked = String::from_utf8(out).unwrap_or_else(|_| source.to_string());
+    (masked, constructs)
+}
+
+fn construct(kind: ConstructKind, range: Range<usize>) -> Construct {
+    Construct { kind, range }
+}
+
+fn find(bytes: &[u8], from: usize, needle: &[u8]) -> Option<usize> {
+    bytes
+        .get(from..)?
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|offset| from + offset)
+}
+
+/// Overwrite `span` with a block comment of the same length, or spaces when
+/// it is too short for one. Every byte is replaced, so the result stays
+/// valid UTF-8.
+fn blank_comment(span: &mut [u8]) {
+    span.fill(b' ');
+    if span.len() >= 4 {
+        let end = span.len();
+        span[..2].copy_from_slice(b"/*");
+        span[end - 2..].copy_from_slice(b"*/");
+    }
+}
+
+/// Replace the `#{...}` or `@{...}` at `start` with an identifier of the
+/// same length, and return the index after it.
+fn interpolation(
+    bytes: &[u8],
+    out: &mut [u8],
+    start: usize,
+    constructs: &mut Vec<Construct>,
+) -> usize {
+    let mut depth = 0;
+    let mut index = start + 1;
+    let end = loop {
+        match bytes.get(index) {
+            None => break bytes.len(),
+            Some(b'{') => depth += 1,
+            Some(b'}') => {
+                depth -= 1;
+                if depth == 0 {
+                    break index + 1;
+                }
+            }
+            Some(b'"' | b'\'') => {
+                index = skip_string(bytes, index);
+                continue;
+            }
+            Some(_) => {}
+        }
+        index += 1;
+    };
+    out[start..end].fill(b'_');
+    constructs.push(construct(ConstructKind::Interpolation, start..end));
+    end
+}
+
+/// The start of the module name before the `.` at `dot`, as in `math` of
+/// `math.div`, or `None` when the name is missing or starts like a number,
+/// class, or id.
+fn module_start(bytes: &[u8], dot: usize) -> Option<usize> {
+    let mut start = dot;
+    while start > 0 && is_name_byte(bytes[start - 1]) {
+        start -= 1;
+    }
+    let first = *bytes.get(start).filter(|_| start < dot)?;
+    let previous = start.checked_sub(1).map(|previous| bytes[previous]);
+    let valid = (first.is_ascii_alphabetic() || first == b'_')
+        && !matches!(
+            previous,
+            Some(b'.' | b'#' | b'$' | b'%' | b'@' | b'&' | b':')
+        );
+    valid.then_some(start)
+}
+
+/// The index after the Sass map whose `(` is at `start`, or `None` when the
+/// parentheses hold no `key: value` pair and so are not a map.
+fn map_end(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut map = false;
+    let mut index = start;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'(' => depth += 1,
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return map.then_some(index + 1);
+                }
+            }
+            b':' if depth == 1 => map = true,
+            b'{' | b'}' | b';' => return None,
+            b'"' | b'\'' => {
+                index = skip_string(bytes, index);
+                continue;
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    None
+}
+
+fn skip_string(bytes: &[u8], start: usize) -> usize {
+    let quote = bytes[start];
+    let mut index = start + 1;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 2,
+            b'\n' => return index,
+            byte if byte == quote => return index + 1,
+            _ => index += 1,
+        }
+    }
+    bytes.len()
+}
+
+fn skip_whitespace(bytes: &[u8], mut index: usize) -> usize {
+    while bytes.get(index).is_some_and(u8::is_ascii_whitespace) {
+        index += 1;
+    }
+    index
+}
+
+fn is_name_start(byte: u8) -> bool {
+    byte.is_ascii_alphabetic() || matches!(byte, b'_' | b'-') || !byte.is_ascii()
+}
+
+fn is_name_byte(byte: u8) -> bool {
+    is_name_start(byte) || byte.is_ascii_digit()
+}
+
+fn name_end(bytes: &[u8], mut index: usize) -> usize {
+    while bytes.get(index).copied().is_some_and(is_name_byte) {
+        index += 1;
+    }
+    index
+}
+
+/// Whether `url(` starts at `index`. Its argument may hold `//` when
+/// unquoted.
+fn is_url(bytes: &[u8], index: usize) -> bool {
+    let preceded = index > 0 && is_name_byte(bytes[index - 1]);
+    !preceded
+        && bytes
+            .get(index..index + 4)
+            .is_some_and(|name| name.eq_ignore_ascii_case(b"url("))
+}
+
+/// The end of the parenthesized arguments or parameters of a Less mixin at
+/// `start`, and the `;` of a call or `{` of a definition after them.
+fn mixin(bytes: &[u8], start: usize) -> Option<(usize, u8)> {
+    if bytes.get(start) != Some(&b'(') {
+        return None;
+    }
+    let mut depth = 0;
+    let mut index = start;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'(' => depth += 1,
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            b'{' | b'}' | b';' => return None,
+            b'"' | b'\'' => {
+                index = skip_string(bytes, index);
+                continue;
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    let end = (index + 1).min(bytes.len());
+    let after = skip_whitespace(bytes, end);
+    match bytes.get(after) {
+        Some(b';') => Some((after, b';')),
+        Some(b'{') => Some((end, b'{')),
+        _ => None,
+    }
+}
@@ -661,16 +661,116 @@ static bool scan_unicode_range_value(TSLexer *lexer) {
     return true;
 }
 
-static bool scan_forgiving_pseudo_element_recovery(TSLexer *lexer) {
+// Scan the rest of a pseudo-class colon whose `:` has been consumed. The
+// colon only counts when a `{` follows, so `a:hover {` is a selector and
+// `color:red;` a declaration.
+static bool scan_pseudo_class_name(TSLexer *lexer) {
+    if (!can_start_pseudo_name(lexer->lookahead)) {
+        return false;
+    }
+    lexer->mark_end(lexer);
+    lexer->result_symbol = PSEUDO_CLASS_SELECTOR_COLON;
+
+    bool in_comment = false;
+    bool in_single = false;
+    bool in_double = false;
+    unsigned depth_paren = 0;
+    unsigned depth_bracket = 0;
+    while (lexer->lookahead != ';' && lexer->lookahead != '}' && !lexer->eof(lexer)) {
+        int32_t c = lexer->lookahead;
+
+        if (c == '\\') {
+            PROFILE_ADVANCE(scan_pseudo_class_colon_bytes, lexer);
+            if (!lexer->eof(lexer)) {
+                PROFILE_ADVANCE(scan_pseudo_class_colon_bytes, lexer);
+            }
+            continue;
+        }
+
+        if (in_single) {
+            PROFILE_ADVANCE(scan_pseudo_class_colon_bytes, lexer);
+            if (c == '\'') {
+                in_single = false;
+            }
+            continue;
+        }
+
+        if (in_double) {
+            PROFILE_ADVANCE(scan_pseudo_class_colon_bytes, lexer);
+            if (c == '"') {
+                in_double = false;
+            }
+            continue;
+        }
+
+        if (in_comment) {
+            PROFILE_ADVANCE(scan_pseudo_class_colon_bytes, lexer);
+            if (c == '*' && lexer->lookahead == '/') {
+                PROFILE_ADVANCE(scan_pseudo_class_colon_bytes, lexer);
+                in_comment = false;
+            }
+            continue;
+        }
+
+        if (c == '/' && !in_comment) {
+            PROFILE_ADVANCE(scan_pseudo_class_colon_bytes, lexer);
+            if (lexer->lookahead == '*') {
+                PROFILE_ADVANCE(scan_pseudo_class_colon_bytes, lexer);
+                in_comment = true;
+            }
+            continue;
+        }
+
+        switch (c) {
+            case '\'':
+                in_single = true;
+                break;
+            case '"':
+                in_double = true;
+                break;
+            case '(':
+                depth_paren++;
+                break;
+            case ')':
+                if (depth_paren > 0) depth_paren--;
+                break;
+            case '[':
+                depth_bracket++;
+                break;
+            case ']':
+                if (depth_bracket > 0) depth_bracket--;
+                break;
+        }
+
+        PROFILE_ADVANCE(scan_pseudo_class_colon_bytes, lexer);
+        if (!in_single && !in_double && !in_comment && lexer->lookahead == '{'
+            && depth_paren == 0 && depth_bracket == 0) {
+            PROFILE_COUNT(scan_pseudo_class_colon_successes);
+            return true;
+        }
+    }
+
+    if (lexer->eof(lexer)) {
+        PROFILE_COUNT(scan_pseudo_class_colon_successes);
+        return true;
+    }
+    return false;
+}
+
+static bool scan_forgiving_pseudo_element_recovery(TSLexer *lexer, bool pseudo_class_valid) {
     PROFILE_COUNT(scan_forgiving_pseudo_element_calls);
 
+    while (is_css_space(lexer->lookahead)) {
+        PROFILE_SKIP(scan_forgiving_pseudo_element_bytes, lexer);
+    }
     if (lexer->lookahead != ':') {
         return false;
     }
     PROFILE_ADVANCE(scan_forgiving_pseudo_element_bytes, lexer);
 
+    // A single colon starts a pseudo-class, as in `:is(:hover)`.
     if (lexer->lookahead != ':') {
-        return false;
+        return pseudo_class_valid && scan_pseudo_class_name(lexer);
     }
     PROFILE_ADVANCE(scan_forgiving_pseudo_element_bytes, lexer);
 
@@ -766,7 +866,7 @@ bool tree_sitter_css_external_scanner_scan(void *payload, TSLexer *lexer, const
     }
 
     if (valid_symbols[FORGIVING_PSEUDO_ELEMENT_RECOVERY]) {
-        return scan_forgiving_pseudo_element_recovery(lexer);
+        return scan_forgiving_pseudo_element_recovery(lexer, valid_symbols[PSEUDO_CLASS_SELECTOR_COLON]);
     }
 
     if (valid_symbols[ERROR_RECOVERY]) {
@@ -820,96 +920,7 @@ bool tree_sitter_css_external_scanner_scan(void *payload, TSLexer *lexer, const
             if (lexer->lookahead == ':') {
                 return false;
             }
-            if (!can_start_pseudo_name(lexer->lookahead)) {
-                return false;
-            }
-            lexer->mark_end(lexer);
-            lexer->result_symbol = PSEUDO_CLASS_SELECTOR_COLON;
-
-            bool in_comment = false;
-            bool in_single = false;
-            bool in_double = false;
-            unsigned depth_paren = 0;
-            unsigned depth_bracket = 0;
-            while (lexer->lookahead != ';' && lexer->lookahead != '}' && !lexer->eof(lexer)) {
-                int32_t c = lexer->lookahead;
-
-                if (c == '\\') {
-                    PROFILE_ADVANCE(scan_pseudo_class_colon_bytes, lexer);
-                    if (!lexer->eof(lexer)) {
-                        PROFILE_ADVANCE(scan_pseudo_class_colon_bytes, lexer);
-                    }
-                    continue;
-                }
-
-                if (in_single) {
-                    PROFILE_ADVANCE(scan_pseudo_class_colon_bytes, lexer);
-                    if (c == '\'') {
-                        in_single = false;
-                    }
-                    continue;
-                }
-
-                if (in_double) {
-                    PROFILE_ADVANCE(scan_pseudo_class_colon_bytes, lexer);
-                    if (c == '"') {
-                        in_double = false;
-                    }
-                    continue;
-                }
-
-                if (in_comment) {
-                    PROFILE_ADVANCE(scan_pseudo_class_colon_bytes, lexer);
-                    if (c == '*' && lexer->lookahead == '/') {
-                        PROFILE_ADVANCE(scan_pseudo_class_colon_bytes, lexer);
-                        in_comment = false;
-                    }
-                    continue;
-                }
-
-                if (c == '/' && !in_comment) {
-                    PROFILE_ADVANCE(scan_pseudo_class_colon_bytes, lexer);
-                    if (lexer->lookahead == '*') {
-                        PROFILE_ADVANCE(scan_pseudo_class_colon_bytes, lexer);
-                        in_comment = true;
-                    }
-                    continue;
-                }
-
-                switch (c) {
-                    case '\'':
-                        in_single = true;
-                        break;
-                    case '"':
-                        in_double = true;
-                        break;
-                    case '(':
-                        depth_paren++;
-                        break;
-                    case ')':
-                        if (depth_paren > 0) depth_paren--;
-                        break;
-                    case '[':
-                        depth_bracket++;
-                        break;
-                    case ']':
-                        if (depth_bracket > 0) depth_bracket--;
-                        break;
-                }
-
-                PROFILE_ADVANCE(scan_pseudo_class_colon_bytes, lexer);
-                if (!in_single && !in_double && !in_comment && lexer->lookahead == '{'
-                    && depth_paren == 0 && depth_bracket == 0) {
-                    PROFILE_COUNT(scan_pseudo_class_colon_successes);
-                    return true;
-                }
-            }
-
-            if (lexer->eof(lexer)) {
-                PROFILE_COUNT(scan_pseudo_class_colon_successes);
-                return true;
-            }
-            return false;
+            return scan_pseudo_class_name(lexer);
         }
     }
 
@@ -0,0 +1,389 @@
+//! Writing a stylesheet back out, formatted or minified.
+//!
+//! [`format`] prints one rule, at-rule, or declaration per line, indents
+//! blocks with a given string, and normalizes spacing inside selectors,
+//! values, and `@media`/`@container`/`@supports` preludes: one space after
+//! commas and around combinators, comparison operators, and `and`/`or`/`not`,
+//! and none inside parentheses. Comments are kept, and a blank line between
+//! two items is kept as one blank line.
+//!
+//! [`minify`] drops comments and every space that does not separate two
+//! tokens. Whitespace that is a descendant combinator is kept, so
+//! `a :global(.b)` and `div :global .c` keep their meaning.
+//!
+//! Both are idempotent, and re-parsing their output gives the same tree up
+//! to whitespace and comments. Subtrees with parse errors are copied as
+//! written.
+//!
+//! ```rust
+//! use tree_sitter_css_svelte::{serialize, LANGUAGE};
+//!
+//! let source = "@media (min-width:40em){a>b,.c{color:red;margin:0 auto}}";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! assert_eq!(
+//!     serialize::format(tree.root_node(), source, "  "),
+//!     "@media (min-width: 40em) {\n  a > b, .c {\n    color: red;\n    margin: 0 auto;\n  }\n}\n"
+//! );
+//! assert_eq!(
+//!     serialize::minify(tree.root_node(), source),
+//!     "@media (min-width:40em){a>b,.c{color:red;margin:0 auto}}"
+//! );
+//! ```
+
+use tree_sitter::Node;
+
+/// The stylesheet at `root` with one item per line, blocks indented by
+/// `indent` per level, and spacing normalized.
+pub fn format(root: Node, source: &str, indent: &str) -> String {
+    let printer = Printer {
+        source,
+        indent,
+        minify: false,
+    };
+    let mut out = String::with_capacity(source.len());
+    printer.items(root, 0, &mut out);
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    out
+}
+
+/// The stylesheet at `root` without comments or optional whitespace.
+pub fn minify(root: Node, source: &str) -> String {
+    let printer = Printer {
+        source,
+        indent: "",
+        minify: true,
+    };
+    let mut out = String::with_capacity(source.len());
+    printer.items(root, 0, &mut out);
+    out
+}
+
+struct Printer<'a> {
+    source: &'a str,
+    indent: &'a str,
+    minify: bool,
+}
+
+/// A token of a prelude or value, with how it must be spaced.
+struct Piece<'a> {
+    text: &'a str,
+    /// Whitespace came before the token in the source.
+    spaced: bool,
+    kind: PieceKind,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PieceKind {
+    Plain,
+    Open,
+    Close,
+    Comma,
+    /// A selector combinator or a query comparison operator.
+    Operator,
+    /// `and`, `or`, `not`, and `only` in at-rule preludes.
+    Keyword,
+    /// The `:` between a query feature's name and value.
+    FeatureColon,
+    Important,
+    Comment,
+    LineComment,
+}
+
+impl Printer<'_> {
+    /// Write the items of a stylesheet, block, or keyframe block list.
+    fn items(&self, container: Node, level: usize, out: &mut String) {
+        let mut cursor = container.walk();
+        let items: Vec<_> = container
+            .named_children(&mut cursor)
+            .filter(|item| !(self.minify && is_comment(item.kind())))
+            .collect();
+
+        let mut previous: Option<Node> = None;
+        for item in items {
+            if let Some(previous) = previous {
+                if self.minify {
+                    if previous.kind() == "declaration" {
+                        out.push(';');
+                    }
+                } else {
+                    out.push('\n');
+                    let gap = &self.source[previous.end_byte()..item.start_byte()];
+                    if gap.matches('\n').count() > 1 {
+                        out.push('\n');
+                    }
+                }
+            }
+            if !self.minify {
+                out.push_str(&self.indent.repeat(level));
+            }
+            self.item(item, level, out);
+            previous = Some(item);
+        }
+    }
+
+    fn item(&self, node: Node, level: usize, out: &mut String) {
+        if node.has_error() || is_comment(node.kind()) {
+            out.push_str(self.source[node.byte_range()].trim_end());
+            return;
+        }
+        if node.kind() == "declaration" {
+            self.declaration(node, out);
+            return;
+        }
+
+        let mut cursor = node.walk();
+        let children: Vec<_> = node.children(&mut cursor).collect();
+        let block = children
+            .iter()
+            .position(|child| matches!(child.kind(), "block" | "keyframe_block_list"));
+        match block {
+            Some(block) => {
+                let header = self.inline(&children[..block]);
+                out.push_str(&header);
+                if !self.minify && !header.is_empty() {
+                    out.push(' ');
+                }
+                self.block(children[block], level, out);
+            }
+            None => {
+                let semicolon = children.last().is_some_and(|last| last.kind() == ";");
+                let body = &children[..children.len() - usize::from(semicolon)];
+                out.push_str(&self.inline(body));
+                if semicolon {
+                    out.push(';');
+                }
+            }
+        }
+    }
+
+    fn block(&self, block: Node, level: usize, out: &mut String) {
+        let mut cursor = block.walk();
+        let empty = !block
+            .named_children(&mut cursor)
+            .any(|item| !(self.minify && is_comment(item.kind())));
+        out.push('{');
+        if empty {
+            out.push('}');
+            return;
+        }
+        if self.minify {
+            self.items(block, level + 1, out);
+        } else {
+            out.push('\n');
+            self.items(block, level + 1, out);
+            out.push('\n');
+            out.push_str(&self.indent.repeat(level));
+        }
+        out.push('}');
+    }
+
+    fn declaration(&self, declaration: Node, out: &mut String) {
+        let mut cursor = declaration.walk();
+        let mut children: Vec<_> = declaration.children(&mut cursor).collect();
+        if children.last().is_some_and(|last| last.kind() == ";") {
+            children.pop();
+        }
+        let Some(colon) = children.iter().position(|child| child.kind() == ":") else {
+            out.push_str(&self.inline(&children));
+            return;
+        };
+
+        out.push_str(&self.inline(&children[..colon]));
+        out.push(':');
+        let value = self.inline(&children[colon + 1..]);
+        if !self.minify && !value.is_empty() {
+            out.push(' ');
+        }
+        out.push_str(&value);
+        if !self.minify {
+            out.push(';');
+        }
+    }
+
+    /// Print `nodes`, which are consecutive siblings, on one line.
+    fn inline(&self, nodes: &[Node]) -> String {
+        let (Some(first), Some(last)) = (nodes.first(), nodes.last()) else {
+            return String::new();
+        };
+        let mut pieces = Vec::new();
+        let mut offset = first.start_byte();
+        for node in nodes {
+            self.pieces(*node, &mut offset, &mut pieces);
+        }
+        self.gap(offset, last.end_byte(), &mut pieces);
+
+        let mut out = String::new();
+        let mut previous: Option<PieceKind> = None;
+        let mut pending_space = false;
+        for piece in pieces {
+            if self.minify && matches!(piece.kind, PieceKind::Comment | PieceKind::LineComment) {
+                pending_space |= piece.spaced;
+                continue;
+            }
+            let spaced = piece.spaced || pending_space;
+            pending_space = false;
+            if let Some(previous) = previous {
+                out.push_str(self.separator(previous, piece.kind, spaced));
+            }
+            out.push_str(piece.text);
+            previous = Some(piece.kind);
+        }
+        out
+    }
+
+    fn separator(&self, previous: PieceKind, current: PieceKind, spaced: bool) -> &'static str {
+        use PieceKind::*;
+
+        if previous == LineComment {
+            return "\n";
+        }
+        if matches!(current, Comma | Close) || previous == Open {
+            return "";
+        }
+        if self.minify {
+            let tight = matches!(previous, Comma | Operator | FeatureColon)
+                || matches!(current, Operator | FeatureColon | Important);
+            return if spaced && !tight { " " } else { "" };
+        }
+
+        if previous == Comma
+            || matches!(previous, Operator | Keyword | FeatureColon)
+            || matches!(current, Operator | Keyword | Important)
+        {
+            " "
+        } else if current == FeatureColon {
+            ""
+        } else if spaced {
+            " "
+        } else {
+            ""
+        }
+    }
+
+    /// Collect the tokens of `node`, and of the source text between them,
+    /// from `offset` on.
+    fn pieces<'a>(&self, node: Node, offset: &mut usize, pieces: &mut Vec<Piece<'a>>)
+    where
+        Self: 'a,
+    {
+        let atomic = node.child_count() == 0
+            || matches!(
+                node.kind(),
+                "string_value"
+                    | "important"
+                    | "comparison_operator"
+                    | "comment"
+                    | "js_comment"
+                    | "url_unquoted_value"
+            );
+        if atomic {
+            let spaced = self.gap(*offset, node.start_byte(), pieces);
+            pieces.push(Piece {
+                text: self.text(node),
+                spaced,
+                kind: piece_kind(node),
+            });
+            *offset = node.end_byte();
+            return;
+        }
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.pieces(child, offset, pieces);
+        }
+        self.gap(*offset, node.end_byte(), pieces);
+        *offset = (*offset).max(node.end_byte());
+    }
+
+    /// Add the words of the source text between `start` and `end`, which
+    /// belong to no child token, such as the digits of `10px`. Returns
+    /// whether whitespace ends the gap.
+    fn gap<'a>(&self, start: usize, end: usize, pieces: &mut Vec<Piece<'a>>) -> bool
+    where
+        Self: 'a,
+    {
+        if start >= end {
+            return false;
+        }
+        let gap: &'a str = self.text_range(start, end);
+        let mut spaced = false;
+        let mut word_start = None;
+        for (index, c) in gap.char_indices() {
+            if c.is_whitespace() {
+                if let Some(word) = word_start.take() {
+                    pieces.push(Piece {
+                        text: &gap[word..index],
+                        spaced,
+                        kind: PieceKind::Plain,
+                    });
+                }
+                spaced = true;
+            } else if word_start.is_none() {
+                word_start = Some(index);
+            }
+        }
+        if let Some(word) = word_start {
+            pieces.push(Piece {
+                text: &gap[word..],
+                spaced,
+                kind: PieceKind::Plain,
+            });
+            false
+        } else {
+            spaced
+        }
+    }
+
+    fn text<'a>(&self, node: Node) -> &'a str
+    where
+        Self: 'a,
+    {
+        self.text_range(node.start_byte(), node.end_byte())
+    }
+
+    fn text_range<'a>(&self, start: usize, end: usize) -> &'a str
+    where
+        Self: 'a,
+    {
+        let source: &'a str = self.source;
+        &source[start..end]
+    }
+}
+
+fn piece_kind(node: Node) -> PieceKind {
+    let parent = node.parent().map_or("", |parent| parent.kind());
+    match node.kind() {
+        "(" => PieceKind::Open,
+        ")" => PieceKind::Close,
+        "," => PieceKind::Comma,
+        ">" | "+" | "~" | "||" if is_combinator(parent) => PieceKind::Operator,
+        "comparison_operator" => PieceKind::Operator,
+        "and" | "or" | "not" | "only" if !node.is_named() => PieceKind::Keyword,
+        ":" if node
+            .prev_sibling()
+            .is_some_and(|previous| previous.kind() == "feature_name") =>
+        {
+            PieceKind::FeatureColon
+        }
+        "important" => PieceKind::Important,
+        "comment" => PieceKind::Comment,
+        "js_comment" => PieceKind::LineComment,
+        _ => PieceKind::Plain,
+    }
+}
+
+fn is_combinator(kind: &str) -> bool {
+    matches!(
+        kind,
+        "child_selector" | "sibling_selector" | "adjacent_sibling_selector" | "column_selector"
+    )
+}
+
+fn is_comment(kind: &str) -> bool {
+    matches!(kind, "comment" | "js_comment" | "html_comment_delimiter")
+}
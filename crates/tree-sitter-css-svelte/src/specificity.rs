@@ -0,0 +1,215 @@
+//! Selector specificity, following Selectors Level 4 §15.
+//!
+//! [`specificity`] counts ids (A), classes, attributes, and pseudo-classes
+//! (B), and type selectors and pseudo-elements (C), with the special cases
+//! of the spec:
+//!
+//! - `:is()`, `:not()`, and `:has()` count as their most specific argument
+//! - `:where()` counts as zero
+//! - `:nth-child(An+B of S)` and `:nth-last-child()` count as one
+//!   pseudo-class plus the most specific selector in `S`
+//! - `&` counts as the most specific selector of the parent rule
+//!
+//! Svelte's `:global(...)` counts as its most specific argument, since the
+//! compiler replaces it with its contents, and a bare `:global` counts as
+//! zero.
+//!
+//! ```rust
+//! use tree_sitter_css_svelte::{specificity::specificity, LANGUAGE};
+//!
+//! let source = ".foo :is(.bar, #baz) {}";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let selectors = tree.root_node().child(0).unwrap().child_by_field_name("prelude").unwrap();
+//! assert_eq!(specificity(selectors.named_child(0).unwrap(), source), (1, 1, 0));
+//! ```
+
+use tree_sitter::Node;
+
+/// The specificity `(A, B, C)` of a selector node: a complex or compound
+/// selector, or a `selectors` list, for which the most specific selector
+/// counts. Counts saturate rather than overflow.
+pub fn specificity(node: Node, source: &str) -> (u32, u32, u32) {
+    let nesting = parent_rule_selectors(node)
+        .map(|selectors| specificity(selectors, source))
+        .unwrap_or_default();
+    compute(node, source, nesting)
+}
+
+fn compute(node: Node, source: &str, nesting: (u32, u32, u32)) -> (u32, u32, u32) {
+    match node.kind() {
+        "selectors" => {
+            let mut cursor = node.walk();
+            let most = node
+                .named_children(&mut cursor)
+                .map(|selector| compute(selector, source, nesting))
+                .max()
+                .unwrap_or_default();
+            most
+        }
+        "descendant_selector"
+        | "child_selector"
+        | "sibling_selector"
+        | "adjacent_sibling_selector"
+        | "column_selector" => {
+            let mut cursor = node.walk();
+            let sum = node
+                .named_children(&mut cursor)
+                .map(|selector| compute(selector, source, nesting))
+                .fold((0, 0, 0), add);
+            sum
+        }
+        "tag_name" => (0, 0, 1),
+        "namespace_selector" => {
+            let name = node.named_child(node.named_child_count().saturating_sub(1) as u32);
+            name.map_or((0, 0, 0), |name| compute(name, source, nesting))
+        }
+        "universal_selector" => (0, 0, 0),
+        "nesting_selector" => nesting,
+        "class_selector" | "attribute_selector" => add(leading(node, source, nesting), (0, 1, 0)),
+        "id_selector" => add(leading(node, source, nesting), (1, 0, 0)),
+        "pseudo_class_selector" => add(
+            leading(node, source, nesting),
+            pseudo_class(node, source, nesting),
+        ),
+        // A bare `:global` is Svelte's marker, not a pseudo-class.
+        "global_selector" => {
+            let own = arguments(node).map_or((0, 0, 0), |arguments| {
+                most_specific(arguments, source, nesting)
+            });
+            add(leading(node, source, nesting), own)
+        }
+        "pseudo_element_selector" => {
+            let mut own = (0, 0, 1);
+            if name(node, source).eq_ignore_ascii_case("slotted") {
+                if let Some(arguments) = arguments(node) {
+                    own = add(own, most_specific(arguments, source, nesting));
+                }
+            }
+            add(leading(node, source, nesting), own)
+        }
+        _ => (0, 0, 0),
+    }
+}
+
+fn add(a: (u32, u32, u32), b: (u32, u32, u32)) -> (u32, u32, u32) {
+    (
+        a.0.saturating_add(b.0),
+        a.1.saturating_add(b.1),
+        a.2.saturating_add(b.2),
+    )
+}
+
+/// The specificity of the selector a suffix node such as `.b` in `a .b`
+/// wraps, or zero when it starts the compound.
+fn leading(node: Node, source: &str, nesting: (u32, u32, u32)) -> (u32, u32, u32) {
+    let Some(first) = node.named_child(0) else {
+        return (0, 0, 0);
+    };
+    let is_name = node.field_name_for_named_child(0) == Some("name")
+        || matches!(first.kind(), "class_name" | "arguments")
+        || (node.kind() == "pseudo_element_selector" && node.named_child_count() < 2)
+        || (node.kind() == "pseudo_element_selector"
+            && node
+                .named_child(1)
+                .is_some_and(|next| next.kind() == "arguments"));
+    if is_name {
+        (0, 0, 0)
+    } else {
+        compute(first, source, nesting)
+    }
+}
+
+fn pseudo_class(node: Node, source: &str, nesting: (u32, u32, u32)) -> (u32, u32, u32) {
+    let name = name(node, source).to_ascii_lowercase();
+    match name.as_str() {
+        "where" => (0, 0, 0),
+        "is" | "matches" | "any" | "-webkit-any" | "-moz-any" | "not" | "has" => {
+            let Some(arguments) = arguments(node) else {
+                return (0, 1, 0);
+            };
+            most_specific(arguments, source, nesting)
+        }
+        "nth-child" | "nth-last-child" => {
+            let of = arguments(node).map_or((0, 0, 0), |arguments| {
+                let mut cursor = arguments.walk();
+                let children: Vec<_> = arguments.children(&mut cursor).collect();
+                // `An+B of S`: the selectors follow the `of` keyword.
+                let selectors = children
+                    .iter()
+                    .position(|child| child.kind() == "of")
+                    .map_or(&[][..], |of| &children[of + 1..]);
+                most_specific_of(selectors, source, nesting)
+            });
+            add((0, 1, 0), of)
+        }
+        "host" | "host-context" => {
+            let own = arguments(node).map_or((0, 0, 0), |arguments| {
+                most_specific(arguments, source, nesting)
+            });
+            add((0, 1, 0), own)
+        }
+        // CSS 2 pseudo-elements written with one colon.
+        "before" | "after" | "first-line" | "first-letter" => (0, 0, 1),
+        _ => (0, 1, 0),
+    }
+}
+
+/// The name after `:` or `::`.
+fn name<'a>(node: Node, source: &'a str) -> &'a str {
+    let mut cursor = node.walk();
+    let children: Vec<_> = node.named_children(&mut cursor).collect();
+    let name = match node.kind() {
+        "pseudo_element_selector" => children
+            .iter()
+            .rev()
+            .find(|child| child.kind() == "tag_name"),
+        _ => children.iter().find(|child| child.kind() == "class_name"),
+    };
+    name.map_or("", |name| &source[name.byte_range()])
+}
+
+/// The `arguments` node of a functional pseudo-class or pseudo-element.
+fn arguments(node: Node) -> Option<Node> {
+    let mut cursor = node.walk();
+    let arguments = node
+        .children(&mut cursor)
+        .find(|child| child.kind() == "arguments");
+    arguments
+}
+
+/// The specificity of the most specific selector in `arguments`.
+fn most_specific(arguments: Node, source: &str, nesting: (u32, u32, u32)) -> (u32, u32, u32) {
+    let mut cursor = arguments.walk();
+    let children: Vec<_> = arguments.children(&mut cursor).collect();
+    most_specific_of(&children, source, nesting)
+}
+
+/// The specificity of the most specific selector among `nodes`, which may
+/// also hold punctuation and values that count as zero.
+fn most_specific_of(nodes: &[Node], source: &str, nesting: (u32, u32, u32)) -> (u32, u32, u32) {
+    nodes
+        .iter()
+        .filter(|node| node.is_named())
+        .map(|node| compute(*node, source, nesting))
+        .max()
+        .unwrap_or_default()
+}
+
+/// The selector list of the rule enclosing the rule `node` belongs to.
+fn parent_rule_selectors(node: Node) -> Option<Node> {
+    let mut current = node.parent();
+    let mut rule = None;
+    while let Some(ancestor) = current {
+        if matches!(ancestor.kind(), "rule_set" | "global_block") {
+            if rule.is_some() {
+                return ancestor.child_by_field_name("prelude");
+            }
+            rule = Some(ancestor);
+        }
+        current = ancestor.parent();
+    }
+    None
+}
@@ -0,0 +1,1139 @@
+//! Typed values for math, color, and gradient functions, following CSS
+//! Values and Units Level 4 and CSS Color Level 5.
+//!
+//! The grammar parses function arguments as generic `call_expression`,
+//! `binary_expression`, and `plain_value` nodes, and gives every
+//! `binary_expression` operator the same precedence, so `1px + 2px * 3`
+//! parses as `(1px + 2px) * 3`. [`value`] reads such a node into a [`Value`]
+//! instead, applying the spec's precedence:
+//!
+//! - [`Calc`] for `calc()`, `min()`, `max()`, `clamp()`, and the other math
+//!   functions, which [`Calc::resolve`] folds to a single [`Numeric`] when
+//!   its units allow
+//! - [`Color`] for hex colors, named colors, `rgb()`, `hsl()`, `hwb()`,
+//!   `lab()`, `lch()`, `oklab()`, `oklch()`, `color()`, relative colors
+//!   (`rgb(from var(--c) r g b / 50%)`), and `color-mix()`
+//! - [`Gradient`] for `linear-gradient()`, `radial-gradient()`,
+//!   `conic-gradient()`, and their `repeating-` forms
+//!
+//! [`values`] finds every such value in a stylesheet.
+//!
+//! ```rust
+//! use tree_sitter_css_svelte::values::{self, Numeric, Value};
+//! use tree_sitter_css_svelte::LANGUAGE;
+//!
+//! let source = "a { width: calc(1in - 2px * 3); color: rgb(255 0 0 / 50%); }";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let found = values::values(tree.root_node(), source);
+//! let Value::Math(width) = &found[0].1 else { panic!() };
+//! assert_eq!(width.resolve(), Some(Numeric::new(90.0, "px")));
+//! let Value::Color(color) = &found[1].1 else { panic!() };
+//! assert_eq!(color.to_string(), "rgb(255 0 0 / 50%)");
+//! ```
+
+use std::fmt;
+
+use tree_sitter::{Node, Range};
+
+/// A typed value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Math(Calc),
+    Color(Color),
+    Gradient(Gradient),
+}
+
+/// A number with an optional unit. Percentages have the unit `%`, and plain
+/// numbers an empty unit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Numeric {
+    pub value: f64,
+    /// The unit, in lowercase.
+    pub unit: String,
+}
+
+/// A math expression.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Calc {
+    Number(Numeric),
+    /// A keyword: a constant such as `pi` or `infinity`, a rounding
+    /// strategy, `none`, or a channel of a relative color such as `r`.
+    Keyword(String),
+    /// A `var()`, `env()`, or `attr()` reference, as written.
+    Reference(String),
+    Operation {
+        operator: Operator,
+        left: Box<Calc>,
+        right: Box<Calc>,
+    },
+    /// A math function, such as `calc()` or `clamp()`.
+    Function {
+        name: String,
+        arguments: Vec<Calc>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+/// A color.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Color {
+    /// A hex color, such as `#f00` or `#ff000080`, as red, green, blue, and
+    /// alpha.
+    Hex([u8; 4]),
+    /// A named color, `transparent`, or `currentcolor`, in lowercase.
+    Named(String),
+    /// A `var()` reference, as written.
+    Reference(String),
+    Function(ColorFunction),
+    Mix(ColorMix),
+}
+
+/// A color function such as `rgb()` or `oklch()`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorFunction {
+    /// The function name, in lowercase.
+    pub name: String,
+    /// The origin color of a relative color, after `from`.
+    pub origin: Option<Box<Color>>,
+    /// The color space of `color()`, such as `display-p3`.
+    pub space: Option<String>,
+    pub channels: Vec<Calc>,
+    pub alpha: Option<Calc>,
+    /// Whether the channels are separated by commas, as in `rgba(0, 0, 0, .5)`.
+    pub legacy: bool,
+}
+
+/// A `color-mix()`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorMix {
+    /// The interpolation method after `in`, such as `oklch` or
+    /// `hsl longer hue`.
+    pub method: String,
+    /// The mixed colors and their optional percentages.
+    pub colors: Vec<(Color, Option<Calc>)>,
+}
+
+/// A gradient function.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient {
+    pub kind: GradientKind,
+    pub repeating: bool,
+    /// The arguments before the first stop, as written, such as `to right`
+    /// or `circle at center`.
+    pub configuration: Option<String>,
+    pub stops: Vec<ColorStop>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GradientKind {
+    Linear,
+    Radial,
+    Conic,
+}
+
+/// An item of a gradient's stop list.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ColorStop {
+    /// A color with zero, one, or two positions.
+    Color { color: Color, positions: Vec<Calc> },
+    /// A transition hint between two stops.
+    Hint(Calc),
+}
+
+const MATH_FUNCTIONS: &[&str] = &[
+    "calc",
+    "-webkit-calc",
+    "-moz-calc",
+    "min",
+    "max",
+    "clamp",
+    "round",
+    "mod",
+    "rem",
+    "sin",
+    "cos",
+    "tan",
+    "asin",
+    "acos",
+    "atan",
+    "atan2",
+    "pow",
+    "sqrt",
+    "hypot",
+    "log",
+    "exp",
+    "abs",
+    "sign",
+];
+
+const COLOR_FUNCTIONS: &[&str] = &[
+    "rgb", "rgba", "hsl", "hsla", "hwb", "lab", "lch", "oklab", "oklch", "color",
+];
+
+/// Every value under `root` that [`value`] recognizes, outermost first, in
+/// source order.
+pub fn values(root: Node, source: &str) -> Vec<(Range, Value)> {
+    let mut found = Vec::new();
+    collect(root, source, &mut found);
+    found
+}
+
+fn collect(node: Node, source: &str, found: &mut Vec<(Range, Value)>) {
+    let candidate = matches!(node.kind(), "call_expression" | "color_value")
+        || (node.kind() == "plain_value"
+            && node
+                .parent()
+                .is_some_and(|parent| parent.kind() == "declaration_value"));
+    if candidate {
+        if let Some(value) = value(node, source) {
+            found.push((node.range(), value));
+            return;
+        }
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect(child, source, found);
+    }
+}
+
+/// The typed value of `node`: a math, color, or gradient function call, a
+/// hex color, or a named color.
+pub fn value(node: Node, source: &str) -> Option<Value> {
+    if node.kind() == "call_expression" {
+        let name = function_name(node, source)?;
+        if MATH_FUNCTIONS.contains(&name.as_str()) {
+            return math_function(node, source).map(Value::Math);
+        }
+        if name.ends_with("-gradient") {
+            return gradient(node, source).map(Value::Gradient);
+        }
+    }
+    color(node, source).map(Value::Color)
+}
+
+impl Numeric {
+    pub fn new(value: f64, unit: &str) -> Self {
+        Numeric {
+            value,
+            unit: unit.to_ascii_lowercase(),
+        }
+    }
+
+    /// The value in the canonical unit of its type (`px`, `deg`, `s`, `hz`,
+    /// or `dppx`), or unchanged when the unit is relative or unknown.
+    pub fn canonical(&self) -> Numeric {
+        match canonical_unit(&self.unit) {
+            Some((unit, factor)) => Numeric::new(self.value * factor, unit),
+            None => self.clone(),
+        }
+    }
+
+    /// The value in `unit`, when both are absolute units of the same type.
+    pub fn to(&self, unit: &str) -> Option<Numeric> {
+        let unit = unit.to_ascii_lowercase();
+        if unit == self.unit {
+            return Some(self.clone());
+        }
+        let (from, factor) = canonical_unit(&self.unit)?;
+        let (to, divisor) = canonical_unit(&unit)?;
+        (from == to).then(|| Numeric::new(self.value * factor / divisor, &unit))
+    }
+}
+
+/// The canonical unit of an absolute unit and the factor to convert to it.
+fn canonical_unit(unit: &str) -> Option<(&'static str, f64)> {
+    Some(match unit {
+        "px" => ("px", 1.0),
+        "cm" => ("px", 96.0 / 2.54),
+        "mm" => ("px", 96.0 / 25.4),
+        "q" => ("px", 96.0 / 101.6),
+        "in" => ("px", 96.0),
+        "pc" => ("px", 16.0),
+        "pt" => ("px", 96.0 / 72.0),
+        "deg" => ("deg", 1.0),
+        "grad" => ("deg", 0.9),
+        "rad" => ("deg", 180.0 / std::f64::consts::PI),
+        "turn" => ("deg", 360.0),
+        "s" => ("s", 1.0),
+        "ms" => ("s", 0.001),
+        "hz" => ("hz", 1.0),
+        "khz" => ("hz", 1000.0),
+        "dppx" | "x" => ("dppx", 1.0),
+        "dpi" => ("dppx", 1.0 / 96.0),
+        "dpcm" => ("dppx", 2.54 / 96.0),
+        _ => return None,
+    })
+}
+
+impl Calc {
+    /// Fold the expression to one value, converting absolute units to their
+    /// canonical unit. `None` when it holds a reference, mixes units that
+    /// cannot be converted, such as `100% - 2px`, or is not valid math.
+    pub fn resolve(&self) -> Option<Numeric> {
+        match self {
+            Calc::Number(number) => Some(number.canonical()),
+            Calc::Keyword(keyword) => constant(keyword).map(|value| Numeric::new(value, "")),
+            Calc::Reference(_) => None,
+            Calc::Operation {
+                operator,
+                left,
+                right,
+            } => {
+                let (left, right) = (left.resolve()?, right.resolve()?);
+                match operator {
+                    Operator::Add | Operator::Subtract => {
+                        let sign = if *operator == Operator::Add {
+                            1.0
+                        } else {
+                            -1.0
+                        };
+                        (left.unit == right.unit)
+                            .then(|| Numeric::new(left.value + sign * right.value, &left.unit))
+                    }
+                    Operator::Multiply if left.unit.is_empty() => {
+                        Some(Numeric::new(left.value * right.value, &right.unit))
+                    }
+                    Operator::Multiply if right.unit.is_empty() => {
+                        Some(Numeric::new(left.value * right.value, &left.unit))
+                    }
+                    Operator::Divide if right.unit.is_empty() => {
+                        Some(Numeric::new(left.value / right.value, &left.unit))
+                    }
+                    Operator::Divide if right.unit == left.unit => {
+                        Some(Numeric::new(left.value / right.value, ""))
+                    }
+                    _ => None,
+                }
+            }
+            Calc::Function { name, arguments } => resolve_function(name, arguments),
+        }
+    }
+}
+
+fn constant(keyword: &str) -> Option<f64> {
+    Some(match keyword.to_ascii_lowercase().as_str() {
+        "e" => std::f64::consts::E,
+        "pi" => std::f64::consts::PI,
+        "infinity" => f64::INFINITY,
+        "-infinity" => f64::NEG_INFINITY,
+        "nan" => f64::NAN,
+        _ => return None,
+    })
+}
+
+fn resolve_function(name: &str, arguments: &[Calc]) -> Option<Numeric> {
+    // A rounding strategy is the only keyword argument that is not a value.
+    let strategy = match arguments.first() {
+        Some(Calc::Keyword(keyword)) if name == "round" && constant(keyword).is_none() => {
+            Some(keyword.to_ascii_lowercase())
+        }
+        _ => None,
+    };
+    let arguments = &arguments[usize::from(strategy.is_some())..];
+    let values = arguments
+        .iter()
+        .map(Calc::resolve)
+        .collect::<Option<Vec<_>>>()?;
+    let same_unit = values.windows(2).all(|pair| pair[0].unit == pair[1].unit);
+    let unit = values.first().map_or("", |first| first.unit.as_str());
+    let number = |value: f64| Some(Numeric::new(value, ""));
+    let in_unit = |value: f64| Some(Numeric::new(value, unit));
+    let radians = |value: &Numeric| match value.unit.as_str() {
+        "" => Some(value.value),
+        "deg" => Some(value.value.to_radians()),
+        _ => None,
+    };
+
+    match (name, values.as_slice()) {
+        ("calc" | "-webkit-calc" | "-moz-calc", [value]) => Some(value.clone()),
+        ("min", [_, ..]) if same_unit => in_unit(
+            values
+                .iter()
+                .map(|value| value.value)
+                .fold(f64::INFINITY, f64::min),
+        ),
+        ("max", [_, ..]) if same_unit => in_unit(
+            values
+                .iter()
+                .map(|value| value.value)
+                .fold(f64::NEG_INFINITY, f64::max),
+        ),
+        ("clamp", [low, value, high]) if same_unit => {
+            in_unit(value.value.min(high.value).max(low.value))
+        }
+        ("round", [value, step]) if same_unit => {
+            let ratio = value.value / step.value;
+            let rounded = match strategy.as_deref() {
+                Some("up") => ratio.ceil(),
+                Some("down") => ratio.floor(),
+                Some("to-zero") => ratio.trunc(),
+                None | Some("nearest") => (ratio + 0.5).floor(),
+                Some(_) => return None,
+            };
+            in_unit(rounded * step.value)
+        }
+        ("round", [value]) if strategy.is_none() && unit.is_empty() => {
+            number((value.value + 0.5).floor())
+        }
+        ("mod", [a, b]) if same_unit => in_unit(a.value - b.value * (a.value / b.value).floor()),
+        ("rem", [a, b]) if same_unit => in_unit(a.value % b.value),
+        ("abs", [value]) => in_unit(value.value.abs()),
+        ("sign", [value]) => number(if value.value == 0.0 {
+            value.value
+        } else {
+            value.value.signum()
+        }),
+        ("sin", [value]) => number(radians(value)?.sin()),
+        ("cos", [value]) => number(radians(value)?.cos()),
+        ("tan", [value]) => number(radians(value)?.tan()),
+        ("asin", [value]) if unit.is_empty() => degrees(value.value.asin()),
+        ("acos", [value]) if unit.is_empty() => degrees(value.value.acos()),
+        ("atan", [value]) if unit.is_empty() => degrees(value.value.atan()),
+        ("atan2", [y, x]) if same_unit => degrees(y.value.atan2(x.value)),
+        ("pow", [base, exponent]) if unit.is_empty() && same_unit => {
+            number(base.value.powf(exponent.value))
+        }
+        ("sqrt", [value]) if unit.is_empty() => number(value.value.sqrt()),
+        ("exp", [value]) if unit.is_empty() => number(value.value.exp()),
+        ("log", [value]) if unit.is_empty() => number(value.value.ln()),
+        ("log", [value, base]) if unit.is_empty() && same_unit => {
+            number(value.value.log(base.value))
+        }
+        ("hypot", [_, ..]) if same_unit => in_unit(
+            values
+                .iter()
+                .map(|value| value.value * value.value)
+                .sum::<f64>()
+                .sqrt(),
+        ),
+        _ => None,
+    }
+}
+
+fn degrees(radians: f64) -> Option<Numeric> {
+    Some(Numeric::new(radians.to_degrees(), "deg"))
+}
+
+impl Color {
+    /// The red, green, blue, and alpha of a hex or named color.
+    pub fn rgba(&self) -> Option<[u8; 4]> {
+        match self {
+            Color::Hex(rgba) => Some(*rgba),
+            Color::Named(name) if name == "transparent" => Some([0, 0, 0, 0]),
+            Color::Named(name) => {
+                let index = NAMED_COLORS.binary_search_by_key(&name.as_str(), |(name, _)| name);
+                let [_, red, green, blue] = NAMED_COLORS[index.ok()?].1.to_be_bytes();
+                Some([red, green, blue, 255])
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A token of a function's arguments, with `binary_expression` nodes taken
+/// apart.
+#[derive(Clone, Copy)]
+enum Token<'t> {
+    Node(Node<'t>),
+    Operator(Operator),
+    Comma,
+}
+
+fn function_name(call: Node, source: &str) -> Option<String> {
+    let name = call
+        .named_child(0)
+        .filter(|name| name.kind() == "function_name")?;
+    Some(source[name.byte_range()].to_ascii_lowercase())
+}
+
+fn arguments(call: Node) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut cursor = call.walk();
+    if let Some(arguments) = call
+        .children(&mut cursor)
+        .find(|child| child.kind() == "arguments")
+    {
+        flatten(arguments, &mut tokens);
+    }
+    tokens
+}
+
+fn flatten<'t>(node: Node<'t>, tokens: &mut Vec<Token<'t>>) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "(" | ")" if node.kind() == "arguments" => {}
+            "binary_expression" => flatten(child, tokens),
+            "," => tokens.push(Token::Comma),
+            "+" if !child.is_named() => tokens.push(Token::Operator(Operator::Add)),
+            "-" if !child.is_named() => tokens.push(Token::Operator(Operator::Subtract)),
+            "*" if !child.is_named() => tokens.push(Token::Operator(Operator::Multiply)),
+            "/" if !child.is_named() => tokens.push(Token::Operator(Operator::Divide)),
+            _ => tokens.push(Token::Node(child)),
+        }
+    }
+}
+
+/// Split `tokens` at commas.
+fn groups<'a, 't>(tokens: &'a [Token<'t>]) -> Vec<&'a [Token<'t>]> {
+    tokens
+        .split(|token| matches!(token, Token::Comma))
+        .collect()
+}
+
+fn math_function(call: Node, source: &str) -> Option<Calc> {
+    let name = function_name(call, source)?;
+    let tokens = arguments(call);
+    let arguments = groups(&tokens)
+        .into_iter()
+        .map(|group| sum(group, source))
+        .collect::<Option<Vec<_>>>()?;
+    Some(Calc::Function { name, arguments })
+}
+
+/// Parse `tokens` as a sum of products.
+fn sum(tokens: &[Token], source: &str) -> Option<Calc> {
+    let split = tokens.iter().rposition(|token| {
+        matches!(
+            token,
+            Token::Operator(Operator::Add) | Token::Operator(Operator::Subtract)
+        )
+    });
+    match split {
+        Some(index) => binary(tokens, index, sum, product, source),
+        None => product(tokens, source),
+    }
+}
+
+fn product(tokens: &[Token], source: &str) -> Option<Calc> {
+    let split = tokens.iter().rposition(|token| {
+        matches!(
+            token,
+            Token::Operator(Operator::Multiply) | Token::Operator(Operator::Divide)
+        )
+    });
+    match split {
+        Some(index) => binary(tokens, index, product, operand_tokens, source),
+        None => operand_tokens(tokens, source),
+    }
+}
+
+fn binary(
+    tokens: &[Token],
+    index: usize,
+    left: fn(&[Token], &str) -> Option<Calc>,
+    right: fn(&[Token], &str) -> Option<Calc>,
+    source: &str,
+) -> Option<Calc> {
+    let Token::Operator(operator) = tokens[index] else {
+        return None;
+    };
+    Some(Calc::Operation {
+        operator,
+        left: Box::new(left(&tokens[..index], source)?),
+        right: Box::new(right(&tokens[index + 1..], source)?),
+    })
+}
+
+fn operand_tokens(tokens: &[Token], source: &str) -> Option<Calc> {
+    match tokens {
+        [Token::Node(node)] => operand(*node, source),
+        _ => None,
+    }
+}
+
+/// A single math operand: a number, keyword, reference, parenthesized sum,
+/// or math function.
+fn operand(node: Node, source: &str) -> Option<Calc> {
+    let text = &source[node.byte_range()];
+    match node.kind() {
+        "integer_value" | "float_value" => numeric(node, source).map(Calc::Number),
+        "plain_value" => Some(Calc::Keyword(text.to_string())),
+        "parenthesized_value" => {
+            let mut tokens = Vec::new();
+            flatten(node, &mut tokens);
+            let inner = match tokens.as_slice() {
+                [Token::Node(open), inner @ .., Token::Node(close)]
+                    if open.kind() == "(" && close.kind() == ")" =>
+                {
+                    inner
+                }
+                _ => return None,
+            };
+            sum(inner, source)
+        }
+        "call_expression" => {
+            let name = function_name(node, source)?;
+            if matches!(name.as_str(), "var" | "env" | "attr") {
+                Some(Calc::Reference(text.to_string()))
+            } else if MATH_FUNCTIONS.contains(&name.as_str()) {
+                math_function(node, source)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+fn numeric(node: Node, source: &str) -> Option<Numeric> {
+    let text = &source[node.byte_range()];
+    let unit = node
+        .named_child(0)
+        .filter(|unit| unit.kind() == "unit")
+        .map_or("", |unit| &source[unit.byte_range()]);
+    let number = &text[..text.len() - unit.len()];
+    Some(Numeric::new(number.parse().ok()?, unit))
+}
+
+fn color(node: Node, source: &str) -> Option<Color> {
+    let text = &source[node.byte_range()];
+    match node.kind() {
+        "color_value" => hex(text.strip_prefix('#')?).map(Color::Hex),
+        "plain_value" => {
+            let name = text.to_ascii_lowercase();
+            let known = matches!(name.as_str(), "transparent" | "currentcolor")
+                || NAMED_COLORS
+                    .binary_search_by_key(&name.as_str(), |(name, _)| name)
+                    .is_ok();
+            known.then_some(Color::Named(name))
+        }
+        "call_expression" => {
+            let name = function_name(node, source)?;
+            if name == "var" {
+                Some(Color::Reference(text.to_string()))
+            } else if name == "color-mix" {
+                color_mix(node, source).map(Color::Mix)
+            } else if COLOR_FUNCTIONS.contains(&name.as_str()) {
+                color_function(node, name, source).map(Color::Function)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+fn hex(digits: &str) -> Option<[u8; 4]> {
+    let nibble = |index: usize| u8::from_str_radix(digits.get(index..index + 1)?, 16).ok();
+    let byte = |index: usize| u8::from_str_radix(digits.get(index..index + 2)?, 16).ok();
+    match digits.len() {
+        3 | 4 => {
+            let mut rgba = [255; 4];
+            for (index, channel) in rgba.iter_mut().enumerate().take(digits.len()) {
+                *channel = nibble(index)? * 17;
+            }
+            Some(rgba)
+        }
+        6 | 8 => {
+            let mut rgba = [255; 4];
+            for (index, channel) in rgba.iter_mut().enumerate().take(digits.len() / 2) {
+                *channel = byte(index * 2)?;
+            }
+            Some(rgba)
+        }
+        _ => None,
+    }
+}
+
+fn keyword<'s>(token: &Token, source: &'s str) -> Option<&'s str> {
+    match token {
+        Token::Node(node) if node.kind() == "plain_value" => Some(&source[node.byte_range()]),
+        _ => None,
+    }
+}
+
+fn color_function(call: Node, name: String, source: &str) -> Option<ColorFunction> {
+    let tokens = arguments(call);
+    let mut rest = tokens.as_slice();
+
+    let mut origin = None;
+    if let [first, Token::Node(from), tail @ ..] = rest {
+        if keyword(first, source).is_some_and(|word| word.eq_ignore_ascii_case("from")) {
+            origin = Some(Box::new(color(*from, source)?));
+            rest = tail;
+        }
+    }
+    let mut space = None;
+    if name == "color" {
+        let (first, tail) = rest.split_first()?;
+        space = Some(keyword(first, source)?.to_ascii_lowercase());
+        rest = tail;
+    }
+
+    let legacy = rest.iter().any(|token| matches!(token, Token::Comma));
+    let (channels, alpha) = if legacy {
+        let mut values = groups(rest)
+            .into_iter()
+            .map(|group| operand_tokens(group, source))
+            .collect::<Option<Vec<_>>>()?;
+        let alpha = (values.len() == 4).then(|| values.pop()).flatten();
+        (values, alpha)
+    } else {
+        let slash = rest
+            .iter()
+            .position(|token| matches!(token, Token::Operator(Operator::Divide)));
+        let (channels, alpha) = match slash {
+            Some(index) => (&rest[..index], Some(&rest[index + 1..])),
+            None => (rest, None),
+        };
+        let channels = channels
+            .iter()
+            .map(|token| operand_tokens(std::slice::from_ref(token), source))
+            .collect::<Option<Vec<_>>>()?;
+        let alpha = match alpha {
+            Some(alpha) => Some(operand_tokens(alpha, source)?),
+            None => None,
+        };
+        (channels, alpha)
+    };
+    if channels.len() != 3 {
+        return None;
+    }
+
+    Some(ColorFunction {
+        name,
+        origin,
+        space,
+        channels,
+        alpha,
+        legacy,
+    })
+}
+
+fn color_mix(call: Node, source: &str) -> Option<ColorMix> {
+    let tokens = arguments(call);
+    let groups = groups(&tokens);
+    let (method, colors) = groups.split_first()?;
+
+    let (first, method) = method.split_first()?;
+    if !keyword(first, source)?.eq_ignore_ascii_case("in") {
+        return None;
+    }
+    let method = method
+        .iter()
+        .map(|token| keyword(token, source))
+        .collect::<Option<Vec<_>>>()?
+        .join(" ");
+
+    let colors = colors
+        .iter()
+        .map(|group| {
+            let mut found = None;
+            let mut percentage = None;
+            for token in *group {
+                let Token::Node(node) = token else {
+                    return None;
+                };
+                if let Some(color) = color(*node, source).filter(|_| found.is_none()) {
+                    found = Some(color);
+                } else if percentage.is_none() {
+                    percentage = Some(operand(*node, source)?);
+                } else {
+                    return None;
+                }
+            }
+            Some((found?, percentage))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    (!method.is_empty() && colors.len() == 2).then_some(ColorMix { method, colors })
+}
+
+fn gradient(call: Node, source: &str) -> Option<Gradient> {
+    let name = function_name(call, source)?;
+    let (repeating, name) = match name.strip_prefix("repeating-") {
+        Some(name) => (true, name),
+        None => (false, name.as_str()),
+    };
+    let kind = match name {
+        "linear-gradient" => GradientKind::Linear,
+        "radial-gradient" => GradientKind::Radial,
+        "conic-gradient" => GradientKind::Conic,
+        _ => return None,
+    };
+
+    let tokens = arguments(call);
+    let mut configuration = None;
+    let mut stops = Vec::new();
+    for (index, group) in groups(&tokens).into_iter().enumerate() {
+        if let Some(stop) = color_stop(group, source) {
+            stops.push(stop);
+            continue;
+        }
+        if let [Token::Node(position)] = group {
+            if index > 0 {
+                stops.push(ColorStop::Hint(operand(*position, source)?));
+                continue;
+            }
+        }
+        if index > 0 {
+            return None;
+        }
+        let (Some(Token::Node(first)), Some(Token::Node(last))) = (group.first(), group.last())
+        else {
+            return None;
+        };
+        configuration = Some(source[first.start_byte()..last.end_byte()].to_string());
+    }
+
+    let colors = stops
+        .iter()
+        .filter(|stop| matches!(stop, ColorStop::Color { .. }))
+        .count();
+    (colors > 0).then_some(Gradient {
+        kind,
+        repeating,
+        configuration,
+        stops,
+    })
+}
+
+fn color_stop(group: &[Token], source: &str) -> Option<ColorStop> {
+    let (Token::Node(first), positions) = group.split_first()? else {
+        return None;
+    };
+    let color = color(*first, source)?;
+    if positions.len() > 2 {
+        return None;
+    }
+    let positions = positions
+        .iter()
+        .map(|token| operand_tokens(std::slice::from_ref(token), source))
+        .collect::<Option<Vec<_>>>()?;
+    Some(ColorStop::Color { color, positions })
+}
+
+impl fmt::Display for Numeric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.value, self.unit)
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Operator::Add => "+",
+            Operator::Subtract => "-",
+            Operator::Multiply => "*",
+            Operator::Divide => "/",
+        })
+    }
+}
+
+impl fmt::Display for Calc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Calc::Number(number) => number.fmt(f),
+            Calc::Keyword(text) | Calc::Reference(text) => f.write_str(text),
+            Calc::Operation {
+                operator,
+                left,
+                right,
+            } => {
+                let grouped = |calc: &Calc, strict: bool| match calc {
+                    Calc::Operation {
+                        operator: inner, ..
+                    } => {
+                        precedence(*inner) < precedence(*operator)
+                            || (strict && precedence(*inner) == precedence(*operator))
+                    }
+                    _ => false,
+                };
+                write_operand(f, left, grouped(left, false))?;
+                write!(f, " {operator} ")?;
+                write_operand(f, right, grouped(right, true))
+            }
+            Calc::Function { name, arguments } => {
+                write!(f, "{name}(")?;
+                write_list(f, arguments, ", ")?;
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+fn precedence(operator: Operator) -> u8 {
+    match operator {
+        Operator::Add | Operator::Subtract => 0,
+        Operator::Multiply | Operator::Divide => 1,
+    }
+}
+
+fn write_operand(f: &mut fmt::Formatter<'_>, calc: &Calc, grouped: bool) -> fmt::Result {
+    if grouped {
+        write!(f, "({calc})")
+    } else {
+        write!(f, "{calc}")
+    }
+}
+
+fn write_list<T: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    items: &[T],
+    separator: &str,
+) -> fmt::Result {
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 {
+            f.write_str(separator)?;
+        }
+        write!(f, "{item}")?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Hex([red, green, blue, 255]) => write!(f, "#{red:02x}{green:02x}{blue:02x}"),
+            Color::Hex([red, green, blue, alpha]) => {
+                write!(f, "#{red:02x}{green:02x}{blue:02x}{alpha:02x}")
+            }
+            Color::Named(text) | Color::Reference(text) => f.write_str(text),
+            Color::Function(function) => function.fmt(f),
+            Color::Mix(mix) => mix.fmt(f),
+        }
+    }
+}
+
+impl fmt::Display for ColorFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}(", self.name)?;
+        if let Some(origin) = &self.origin {
+            write!(f, "from {origin} ")?;
+        }
+        if let Some(space) = &self.space {
+            write!(f, "{space} ")?;
+        }
+        if self.legacy {
+            write_list(f, &self.channels, ", ")?;
+            if let Some(alpha) = &self.alpha {
+                write!(f, ", {alpha}")?;
+            }
+        } else {
+            write_list(f, &self.channels, " ")?;
+            if let Some(alpha) = &self.alpha {
+                write!(f, " / {alpha}")?;
+            }
+        }
+        f.write_str(")")
+    }
+}
+
+impl fmt::Display for ColorMix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "color-mix(in {}", self.method)?;
+        for (color, percentage) in &self.colors {
+            write!(f, ", {color}")?;
+            if let Some(percentage) = percentage {
+                write!(f, " {percentage}")?;
+            }
+        }
+        f.write_str(")")
+    }
+}
+
+impl fmt::Display for Gradient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.repeating {
+            f.write_str("repeating-")?;
+        }
+        let kind = match self.kind {
+            GradientKind::Linear => "linear",
+            GradientKind::Radial => "radial",
+            GradientKind::Conic => "conic",
+        };
+        write!(f, "{kind}-gradient(")?;
+        if let Some(configuration) = &self.configuration {
+            write!(f, "{configuration}, ")?;
+        }
+        write_list(f, &self.stops, ", ")?;
+        f.write_str(")")
+    }
+}
+
+impl fmt::Display for ColorStop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorStop::Color { color, positions } => {
+                color.fmt(f)?;
+                for position in positions {
+                    write!(f, " {position}")?;
+                }
+                Ok(())
+            }
+            ColorStop::Hint(position) => position.fmt(f),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Math(calc) => calc.fmt(f),
+            Value::Color(color) => color.fmt(f),
+            Value::Gradient(gradient) => gradient.fmt(f),
+        }
+    }
+}
+
+/// The named colors of CSS Color Level 4, sorted by name, as `0xRRGGBB`.
+const NAMED_COLORS: &[(&str, u32)] = &[
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
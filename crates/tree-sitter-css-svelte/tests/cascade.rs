@@ -0,0 +1,166 @@
+use tree_sitter::Tree;
+use tree_sitter_css_svelte::cascade::Cascade;
+use tree_sitter_css_svelte::LANGUAGE;
+
+fn parse(source: &str) -> Tree {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&LANGUAGE.into())
+        .expect("failed to load css grammar");
+    let tree = parser.parse(source, None).expect("parse");
+    assert!(!tree.root_node().has_error(), "{source}");
+    tree
+}
+
+fn cascade(source: &str) -> Cascade {
+    Cascade::new(parse(source).root_node(), source)
+}
+
+/// Each rule's selectors and layer.
+fn layered(cascade: &Cascade) -> Vec<(String, Option<&str>)> {
+    cascade
+        .rules
+        .iter()
+        .map(|rule| (rule.selectors.join(", "), rule.layer.as_deref()))
+        .collect()
+}
+
+#[test]
+fn test_layer_order_follows_first_declaration() {
+    let found = cascade(
+        "@layer reset, base;\n\
+         @layer theme { a {} }\n\
+         @layer base { b {} }\n\
+         @layer reset { c {} }",
+    );
+    assert_eq!(found.layers, ["reset", "base", "theme"]);
+    assert_eq!(
+        layered(&found),
+        [
+            ("a".to_string(), Some("theme")),
+            ("b".to_string(), Some("base")),
+            ("c".to_string(), Some("reset")),
+        ]
+    );
+}
+
+#[test]
+fn test_nested_layers_come_before_their_parent() {
+    let found = cascade(
+        "@layer framework { @layer base, layout; p {} }\n\
+         @layer framework.theme { q {} }\n\
+         @layer framework { @layer layout { r {} } }",
+    );
+    assert_eq!(
+        found.layers,
+        [
+            "framework.base",
+            "framework.layout",
+            "framework.theme",
+            "framework"
+        ]
+    );
+    assert_eq!(
+        layered(&found),
+        [
+            ("p".to_string(), Some("framework")),
+            ("q".to_string(), Some("framework.theme")),
+            ("r".to_string(), Some("framework.layout")),
+        ]
+    );
+}
+
+#[test]
+fn test_anonymous_layers_and_imports() {
+    let found = cascade(
+        "@import url(reset.css) layer(reset);\n\
+         @import 'vendor.css' layer;\n\
+         @import 'plain.css';\n\
+         @layer { a {} }\n\
+         @layer { b {} }\n\
+         c {}",
+    );
+    assert_eq!(
+        found.layers,
+        ["reset", "<anonymous 1>", "<anonymous 2>", "<anonymous 3>"]
+    );
+    assert_eq!(
+        layered(&found),
+        [
+            ("a".to_string(), Some("<anonymous 2>")),
+            ("b".to_string(), Some("<anonymous 3>")),
+            ("c".to_string(), None),
+        ]
+    );
+}
+
+#[test]
+fn test_priority_reverses_for_important() {
+    let found = cascade("@layer a, b;");
+    let rank = |layer, important| found.priority(layer, important);
+    assert!(rank(Some("a"), false) < rank(Some("b"), false));
+    assert!(rank(Some("b"), false) < rank(None, false));
+    assert!(rank(None, false) < rank(None, true));
+    assert!(rank(None, true) < rank(Some("b"), true));
+    assert!(rank(Some("b"), true) < rank(Some("a"), true));
+}
+
+#[test]
+fn test_scope_bounds() {
+    let found = cascade(
+        "@scope (.card) to (.content, footer) {\n\
+           img {}\n\
+           @scope (.media) { & > figcaption {} }\n\
+         }\n\
+         @scope { .inline {} }",
+    );
+    let bounds: Vec<Vec<_>> = found
+        .rules
+        .iter()
+        .map(|rule| {
+            rule.scopes
+                .iter()
+                .map(|scope| (scope.start.as_deref(), scope.end.as_deref()))
+                .collect()
+        })
+        .collect();
+    assert_eq!(
+        bounds,
+        [
+            vec![(Some(".card"), Some(".content, footer"))],
+            vec![
+                (Some(".card"), Some(".content, footer")),
+                (Some(".media"), None)
+            ],
+            vec![(None, None)],
+        ]
+    );
+    assert_eq!(found.rules[1].selectors, [":where(:scope) > figcaption"]);
+}
+
+#[test]
+fn test_context_of_a_declaration() {
+    let source =
+        ".x { @layer inner { color: red } }\n@layer outer { @scope (.a) { p { margin: 0 } } }";
+    let tree = parse(source);
+    let found = Cascade::new(tree.root_node(), source);
+    assert_eq!(found.layers, ["inner", "outer"]);
+
+    let mut declarations = Vec::new();
+    let mut stack = vec![tree.root_node()];
+    while let Some(node) = stack.pop() {
+        if node.kind() == "declaration" {
+            declarations.push(node);
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.named_children(&mut cursor));
+    }
+    declarations.sort_by_key(|node| node.start_byte());
+
+    let (layer, scopes) = found.context(declarations[0], source);
+    assert_eq!(layer.as_deref(), Some("inner"));
+    assert!(scopes.is_empty());
+    let (layer, scopes) = found.context(declarations[1], source);
+    assert_eq!(layer.as_deref(), Some("outer"));
+    assert_eq!(scopes[0].start.as_deref(), Some(".a"));
+}
@@ -0,0 +1,143 @@
+use tree_sitter_css_svelte::custom_properties::{self, DiagnosticKind, Graph};
+use tree_sitter_css_svelte::LANGUAGE;
+
+fn parse(source: &str) -> tree_sitter::Tree {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&LANGUAGE.into())
+        .expect("failed to load css grammar");
+    parser.parse(source, None).expect("parse")
+}
+
+fn messages(source: &str) -> Vec<String> {
+    let tree = parse(source);
+    custom_properties::check(tree.root_node(), source)
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[test]
+fn test_collects_declarations_with_context() {
+    let source = ":root { --gap: 4px; }\n@media (width > 40em) {\n  .card,\n  .panel { --gap: 8px; }\n}\n@keyframes pulse { from { --size: 1px; } }";
+    let tree = parse(source);
+    let graph = Graph::new(tree.root_node(), source);
+
+    let found: Vec<_> = graph
+        .declarations
+        .iter()
+        .map(|declaration| (declaration.name.as_str(), declaration.context.clone()))
+        .collect();
+    assert_eq!(
+        found,
+        [
+            ("--gap", vec![":root".to_string()]),
+            (
+                "--gap",
+                vec![
+                    "@media (width > 40em)".to_string(),
+                    ".card, .panel".to_string()
+                ]
+            ),
+            (
+                "--size",
+                vec!["@keyframes pulse".to_string(), "from".to_string()]
+            ),
+        ]
+    );
+    assert_eq!(
+        &source[graph.declarations[1].range.start_byte..graph.declarations[1].range.end_byte],
+        "--gap"
+    );
+}
+
+#[test]
+fn test_collects_uses_and_dependencies() {
+    let source = "a { --a: var(--b, var(--c)); border: 1px solid var(--d,); color: var(--e); }";
+    let tree = parse(source);
+    let graph = Graph::new(tree.root_node(), source);
+
+    let uses: Vec<_> = graph
+        .uses
+        .iter()
+        .map(|found| {
+            (
+                found.name.as_str(),
+                found.has_fallback,
+                found.property.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        uses,
+        [
+            ("--b", true, "--a"),
+            ("--c", false, "--a"),
+            ("--d", true, "border"),
+            ("--e", false, "color"),
+        ]
+    );
+    assert_eq!(
+        graph.dependencies("--a").collect::<Vec<_>>(),
+        ["--b", "--c"]
+    );
+    assert_eq!(graph.dependencies("color").count(), 0);
+}
+
+#[test]
+fn test_reports_cycles() {
+    let source =
+        "a { --a: var(--b); --b: calc(var(--a) + 1px); --self: var(--self, 0); --ok: var(--a); }";
+    let tree = parse(source);
+    let graph = Graph::new(tree.root_node(), source);
+    assert_eq!(
+        graph.cycles(),
+        [
+            vec!["--a".to_string(), "--b".to_string()],
+            vec!["--self".to_string()]
+        ]
+    );
+
+    let found = graph.check();
+    let cycles: Vec<_> = found
+        .iter()
+        .filter(|diagnostic| matches!(diagnostic.kind, DiagnosticKind::Cycle { .. }))
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        cycles,
+        [
+            "`--a` depends on itself through `--a`, `--b`",
+            "`--b` depends on itself through `--a`, `--b`",
+            "`--self` depends on itself through `--self`",
+        ]
+    );
+}
+
+#[test]
+fn test_reports_undefined_and_unused() {
+    assert_eq!(
+        messages(":root { --used: 1px; --spare: 2px; } a { margin: var(--used) var(--missing) var(--soft, 0); }"),
+        [
+            "`--spare` is never used",
+            "`--missing` is not defined and has no fallback",
+        ]
+    );
+}
+
+#[test]
+fn test_resolves_across_stylesheets() {
+    let theme = ":root { --brand: teal; }";
+    let button = "button { color: var(--brand); }";
+    let (theme_tree, button_tree) = (parse(theme), parse(button));
+
+    let mut graph = Graph::default();
+    graph.add_stylesheet(theme_tree.root_node(), theme, 0);
+    graph.add_stylesheet(button_tree.root_node(), button, 1);
+    assert!(graph.check().is_empty());
+    assert_eq!(graph.uses[0].document, 1);
+
+    let alone = Graph::new(button_tree.root_node(), button).check();
+    assert_eq!(alone.len(), 1);
+    assert_eq!(alone[0].document, 0);
+}
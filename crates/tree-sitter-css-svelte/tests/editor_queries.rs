@@ -0,0 +1,191 @@
+//! Coverage tests for editor-facing CSS queries.
+
+use std::collections::BTreeSet;
+use tree_sitter::StreamingIterator;
+use tree_sitter_css_svelte::{
+    FOLDS_QUERY, INDENTS_QUERY, INJECTIONS_QUERY, LANGUAGE, LOCALS_QUERY,
+};
+
+fn parse(source: &str) -> tree_sitter::Tree {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&LANGUAGE.into())
+        .expect("failed to load CSS grammar");
+
+    let tree = parser.parse(source, None).expect("failed to parse source");
+    assert!(!tree.root_node().has_error(), "{source}");
+    tree
+}
+
+fn capture_texts(query_source: &str, capture_name: &str, source: &str) -> BTreeSet<String> {
+    let language = LANGUAGE.into();
+    let query = tree_sitter::Query::new(&language, query_source)
+        .expect("editor query should compile before capture assertions");
+    let tree = parse(source);
+
+    let capture_names = query.capture_names();
+    let mut cursor = tree_sitter::QueryCursor::new();
+    let mut captures = cursor.captures(&query, tree.root_node(), source.as_bytes());
+    let mut texts = BTreeSet::new();
+
+    loop {
+        captures.advance();
+        let Some((query_match, capture_index)) = captures.get() else {
+            break;
+        };
+        let capture = query_match.captures[*capture_index];
+        if capture_names[capture.index as usize] == capture_name {
+            texts.insert(
+                capture
+                    .node
+                    .utf8_text(source.as_bytes())
+                    .expect("capture text should be valid UTF-8")
+                    .to_string(),
+            );
+        }
+    }
+
+    texts
+}
+
+fn assert_captures_include(
+    query_source: &str,
+    capture_name: &str,
+    source: &str,
+    expected: &[&str],
+) {
+    let texts = capture_texts(query_source, capture_name, source);
+
+    for text in expected {
+        assert!(
+            texts.contains(*text),
+            "missing @{capture_name} capture {text:?}\nactual captures:\n{texts:#?}"
+        );
+    }
+}
+
+fn assert_captures_exclude(
+    query_source: &str,
+    capture_name: &str,
+    source: &str,
+    unexpected: &[&str],
+) {
+    let texts = capture_texts(query_source, capture_name, source);
+
+    for text in unexpected {
+        assert!(
+            !texts.contains(*text),
+            "unexpected @{capture_name} capture {text:?}\nactual captures:\n{texts:#?}"
+        );
+    }
+}
+
+#[test]
+fn folds_cover_rule_sets_and_at_rule_blocks() {
+    let source = r#"/* header */
+a { color: red; }
+@media (min-width: 1px) { b {} }
+@supports (display: grid) { c {} }
+@container card (width > 1px) { d {} }
+@layer base { e {} }
+@layer reset, base;
+@font-face { font-family: x; }
+@keyframes spin { from { opacity: 0; } to { opacity: 1; } }
+:global { f {} }
+"#;
+
+    assert_captures_include(
+        FOLDS_QUERY,
+        "fold",
+        source,
+        &[
+            "/* header */",
+            "a { color: red; }",
+            "@media (min-width: 1px) { b {} }",
+            "@supports (display: grid) { c {} }",
+            "@container card (width > 1px) { d {} }",
+            "@layer base { e {} }",
+            "@font-face { font-family: x; }",
+            "@keyframes spin { from { opacity: 0; } to { opacity: 1; } }",
+            "from { opacity: 0; }",
+            "to { opacity: 1; }",
+            ":global { f {} }",
+        ],
+    );
+    assert_captures_exclude(FOLDS_QUERY, "fold", source, &["@layer reset, base;"]);
+}
+
+#[test]
+fn indents_cover_blocks() {
+    let source = "a {\n  color: red;\n}\n@keyframes spin {\n  to { opacity: 1; }\n}\n";
+
+    assert_captures_include(
+        INDENTS_QUERY,
+        "indent.begin",
+        source,
+        &[
+            "{\n  color: red;\n}",
+            "{\n  to { opacity: 1; }\n}",
+            "{ opacity: 1; }",
+        ],
+    );
+    assert_captures_include(INDENTS_QUERY, "indent.end", source, &["}"]);
+    assert_captures_include(INDENTS_QUERY, "indent.branch", source, &["}"]);
+}
+
+#[test]
+fn locals_cover_custom_properties() {
+    let source =
+        ":root { --accent: red; color: var(--accent, blue); }\na { background: var(--missing); }";
+
+    assert_captures_include(LOCALS_QUERY, "local.scope", source, &[source]);
+    assert_captures_include(LOCALS_QUERY, "local.definition", source, &["--accent"]);
+    assert_captures_include(
+        LOCALS_QUERY,
+        "local.reference",
+        source,
+        &["--accent", "--missing"],
+    );
+    assert_captures_exclude(LOCALS_QUERY, "local.definition", source, &["color"]);
+    assert_captures_exclude(LOCALS_QUERY, "local.reference", source, &["blue"]);
+}
+
+#[test]
+fn locals_cover_keyframes() {
+    let source = "@keyframes spin { to { opacity: 1; } }\na { animation-name: spin; }\nb { animation: 1s fade linear infinite; }";
+
+    assert_captures_include(LOCALS_QUERY, "local.definition.type", source, &["spin"]);
+    assert_captures_include(LOCALS_QUERY, "local.reference", source, &["spin", "fade"]);
+    assert_captures_exclude(
+        LOCALS_QUERY,
+        "local.reference",
+        source,
+        &["linear", "infinite"],
+    );
+}
+
+#[test]
+fn locals_cover_layers() {
+    let source =
+        "@import url(x.css) layer(vendor);\n@layer reset, base;\n@layer theme.dark { a {} }";
+
+    assert_captures_include(
+        LOCALS_QUERY,
+        "local.definition.namespace",
+        source,
+        &["reset", "base", "theme.dark"],
+    );
+    assert_captures_include(LOCALS_QUERY, "local.reference", source, &["vendor"]);
+}
+
+#[test]
+fn injections_cover_comments() {
+    let source = "/* TODO: tidy */\na {}\n// note";
+
+    assert_captures_include(
+        INJECTIONS_QUERY,
+        "injection.content",
+        source,
+        &["/* TODO: tidy */", "// note"],
+    );
+}
@@ -0,0 +1,168 @@
+use tree_sitter::StreamingIterator;
+use tree_sitter_css_svelte::global::{self, GlobalKind};
+use tree_sitter_css_svelte::{HIGHLIGHTS_QUERY, LANGUAGE};
+
+fn parse(source: &str) -> tree_sitter::Tree {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&LANGUAGE.into())
+        .expect("failed to load css grammar");
+    parser.parse(source, None).expect("parse")
+}
+
+/// Every class, tag, and id selector in `source`, with whether it is global.
+fn scoping(source: &str) -> Vec<(String, bool)> {
+    fn visit(node: tree_sitter::Node, source: &str, out: &mut Vec<(String, bool)>) {
+        if matches!(node.kind(), "class_name" | "tag_name" | "id_name")
+            && node
+                .parent()
+                .is_some_and(|parent| parent.kind() != "pseudo_class_selector")
+        {
+            let selector = match node.parent() {
+                Some(parent) if parent.kind() != "selectors" && node.kind() != "tag_name" => parent,
+                _ => node,
+            };
+            out.push((
+                source[node.byte_range()].to_string(),
+                global::is_global(selector),
+            ));
+        }
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            visit(child, source, out);
+        }
+    }
+
+    let tree = parse(source);
+    let mut out = Vec::new();
+    visit(tree.root_node(), source, &mut out);
+    out
+}
+
+#[test]
+fn test_classifies_the_three_forms() {
+    let source = ":global(.a) {} div :global .b > p {} .c :global { .d {} } :global {}";
+    let tree = parse(source);
+    let kinds: Vec<_> = global::find(tree.root_node())
+        .iter()
+        .map(|global| global.kind)
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            GlobalKind::Functional,
+            GlobalKind::Compound,
+            GlobalKind::Block,
+            GlobalKind::Block,
+        ]
+    );
+}
+
+#[test]
+fn test_parses_global_blocks_as_their_own_rules() {
+    let source = ".a :global { .b {} } :global .c {}";
+    let tree = parse(source);
+    let root = tree.root_node();
+    let kinds: Vec<_> = (0..root.named_child_count() as u32)
+        .filter_map(|index| root.named_child(index))
+        .map(|node| node.kind())
+        .collect();
+    assert_eq!(kinds, ["global_block", "rule_set"]);
+}
+
+#[test]
+fn test_functional_arguments_are_selectors() {
+    let source = "a :global(.b, .c:hover > span) {}";
+    let tree = parse(source);
+    let found = global::find(tree.root_node());
+    assert_eq!(found.len(), 1);
+    let selectors: Vec<_> = found[0]
+        .selectors()
+        .iter()
+        .map(|node| &source[node.byte_range()])
+        .collect();
+    assert_eq!(selectors, [".b", ".c:hover > span"]);
+}
+
+#[test]
+fn test_tells_scoped_from_global_selectors() {
+    assert_eq!(
+        scoping("a :global(.b) .c {}"),
+        [
+            ("a".to_string(), false),
+            ("b".to_string(), true),
+            ("c".to_string(), false),
+        ]
+    );
+    assert_eq!(
+        scoping("div :global .b > p {}"),
+        [
+            ("div".to_string(), false),
+            ("b".to_string(), true),
+            ("p".to_string(), true),
+        ]
+    );
+    assert_eq!(
+        scoping("div:global(.x) {}"),
+        [("div".to_string(), false), ("x".to_string(), true)]
+    );
+    assert_eq!(
+        scoping("div :global(.x) {}"),
+        [("div".to_string(), false), ("x".to_string(), true)]
+    );
+    assert_eq!(
+        scoping(".x :global { .y { color: red } } .z {}"),
+        [
+            ("x".to_string(), false),
+            ("y".to_string(), true),
+            ("z".to_string(), false),
+        ]
+    );
+}
+
+#[test]
+fn test_other_pseudo_classes_are_not_global() {
+    let source = "a:hover {} :is(.b) {} .global {}";
+    let tree = parse(source);
+    assert!(global::find(tree.root_node()).is_empty());
+    assert!(scoping(source).iter().all(|(_, global)| !global));
+}
+
+#[test]
+fn test_reports_invalid_globals() {
+    let source = ":global() {} :global(1px) {} :global, .a {} :global(.ok) {}";
+    let tree = parse(source);
+    let found = global::check(tree.root_node(), source);
+    let messages: Vec<_> = found.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        messages,
+        [
+            "`:global()` needs a selector",
+            "`1px` is not a selector",
+            "a `:global {...}` block cannot be part of a selector list",
+        ]
+    );
+    assert_eq!(
+        &source[found[1].range.start_byte..found[1].range.end_byte],
+        "1px"
+    );
+}
+
+#[test]
+fn test_highlights_global_as_keyword() {
+    let source = ":global(.a) b:hover {}";
+    let tree = parse(source);
+    let language = LANGUAGE.into();
+    let query = tree_sitter::Query::new(&language, HIGHLIGHTS_QUERY).unwrap();
+    let mut cursor = tree_sitter::QueryCursor::new();
+    let mut captures = cursor.captures(&query, tree.root_node(), source.as_bytes());
+
+    let mut keywords = Vec::new();
+    while let Some((found, index)) = captures.next() {
+        let capture = found.captures[*index];
+        if query.capture_names()[capture.index as usize] == "keyword" {
+            keywords.push(&source[capture.node.byte_range()]);
+        }
+    }
+    assert_eq!(keywords, ["global"]);
+}
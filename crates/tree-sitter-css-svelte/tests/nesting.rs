@@ -0,0 +1,114 @@
+use tree_sitter_css_svelte::{nesting, LANGUAGE};
+
+/// The expanded selector list of every rule in `source`, in source order.
+fn resolved(source: &str) -> Vec<String> {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&LANGUAGE.into())
+        .expect("failed to load css grammar");
+    let tree = parser.parse(source, None).expect("parse");
+    assert!(!tree.root_node().has_error(), "{source}");
+    nesting::resolve_all(tree.root_node(), source)
+        .into_iter()
+        .map(|(_, selectors)| selectors.join(", "))
+        .collect()
+}
+
+#[test]
+fn test_implicit_nesting_prefix() {
+    assert_eq!(
+        resolved(".foo { color: blue; .bar { color: red; } }"),
+        [".foo", ":is(.foo) .bar"]
+    );
+    assert_eq!(
+        resolved(".foo, .bar { > .baz {} + .qux, ~ .quux {} }"),
+        [
+            ".foo, .bar",
+            ":is(.foo, .bar) > .baz",
+            ":is(.foo, .bar) + .qux, :is(.foo, .bar) ~ .quux"
+        ]
+    );
+}
+
+#[test]
+fn test_explicit_nesting_selector() {
+    assert_eq!(
+        resolved(".foo { &.bar {} &:hover {} .bar & {} & + .baz {} }"),
+        [
+            ".foo",
+            ":is(.foo).bar",
+            ":is(.foo):hover",
+            ".bar :is(.foo)",
+            ":is(.foo) + .baz"
+        ]
+    );
+    assert_eq!(
+        resolved(".foo { & .bar & {} :not(&) {} }"),
+        [".foo", ":is(.foo) .bar :is(.foo)", ":not(:is(.foo))"]
+    );
+}
+
+#[test]
+fn test_deep_nesting_wraps_each_level() {
+    assert_eq!(
+        resolved(".a { .b { & > .c { .d {} } } }"),
+        [
+            ".a",
+            ":is(.a) .b",
+            ":is(:is(.a) .b) > .c",
+            ":is(:is(:is(.a) .b) > .c) .d"
+        ]
+    );
+}
+
+#[test]
+fn test_conditional_rules_keep_their_parent() {
+    assert_eq!(
+        resolved(
+            ".card { @media (width > 40em) { .title {} @supports (display: grid) { & {} } } }"
+        ),
+        [".card", ":is(.card) .title", ":is(.card)"]
+    );
+}
+
+#[test]
+fn test_top_level_and_scope() {
+    assert_eq!(
+        resolved("& .x {} .y {} @scope (.card) { .t {} & .u {} }"),
+        [":scope .x", ".y", ":scope .t", ":where(:scope) .u"]
+    );
+}
+
+#[test]
+fn test_whitespace_comments_and_strings() {
+    assert_eq!(
+        resolved(".a {\n  .b\n    >  .c /* note */ {}\n  [title=\"x & y\"] & {}\n}"),
+        [".a", ":is(.a) .b > .c", "[title=\"x & y\"] :is(.a)"]
+    );
+}
+
+#[test]
+fn test_global_blocks() {
+    assert_eq!(
+        resolved(":global { .a { .b {} } }"),
+        ["", ".a", ":is(.a) .b"]
+    );
+    assert_eq!(
+        resolved(".x { :global { .a {} } }"),
+        [".x", "", ":is(.x) .a"]
+    );
+    assert_eq!(
+        resolved(".x :global { .a { & .b {} } } p > :global { span {} }"),
+        [
+            ".x",
+            ":is(.x) .a",
+            ":is(:is(.x) .a) .b",
+            "p",
+            ":is(p) > span"
+        ]
+    );
+    assert_eq!(
+        resolved(".y { .x :global { .a {} } }"),
+        [".y", ":is(.y) .x", ":is(:is(.y) .x) .a"]
+    );
+}
@@ -0,0 +1,227 @@
+use tree_sitter::{Node, Tree};
+use tree_sitter_css_svelte::preprocessor::{self, ConstructKind, Dialect};
+use tree_sitter_css_svelte::LANGUAGE;
+
+fn parse(source: &str, dialect: Dialect) -> Tree {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&LANGUAGE.into())
+        .expect("failed to load css grammar");
+    let tree = preprocessor::parse(&mut parser, source, dialect).expect("parse");
+    assert!(
+        !tree.root_node().has_error(),
+        "{source}\n{}",
+        tree.root_node().to_sexp()
+    );
+    tree
+}
+
+/// The source text of every node of `kind`, in order.
+fn texts<'a>(node: Node, source: &'a str, kind: &str) -> Vec<&'a str> {
+    let mut found = Vec::new();
+    if node.kind() == kind {
+        found.push(&source[node.byte_range()]);
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        found.extend(texts(child, source, kind));
+    }
+    found
+}
+
+fn kinds(source: &str, dialect: Dialect) -> Vec<(ConstructKind, &str)> {
+    preprocessor::mask(source, dialect)
+        .1
+        .into_iter()
+        .map(|construct| (construct.kind, &source[construct.range]))
+        .collect()
+}
+
+#[test]
+fn test_scss_variables_and_flags() {
+    let source = "$primary: #333 !default;\na { color: $primary; margin: $gap * 2; }";
+    let tree = parse(source, Dialect::Scss);
+    assert_eq!(
+        texts(tree.root_node(), source, "property_name"),
+        ["$primary", "color", "margin"]
+    );
+    assert_eq!(
+        texts(tree.root_node(), source, "plain_value"),
+        ["$primary", "$gap"]
+    );
+    assert_eq!(
+        kinds(source, Dialect::Scss),
+        [
+            (ConstructKind::Variable, "$primary"),
+            (ConstructKind::Flag, "!default"),
+            (ConstructKind::Variable, "$primary"),
+            (ConstructKind::Variable, "$gap"),
+        ]
+    );
+}
+
+#[test]
+fn test_scss_directives() {
+    let source = "@use \"sass:math\";\n\
+        @mixin button($size: 1rem) {\n  padding: $size;\n  @content;\n}\n\
+        .primary { @include button(2rem) { color: red; } }\n\
+        @each $name in a, b { .icon-#{$name} { background: url(\"#{$name}.svg\"); } }\n\
+        @if $theme == dark { body { color: white; } } @else { body { color: black; } }\n\
+        @function double($n) { @return $n * 2; }";
+    let tree = parse(source, Dialect::Scss);
+    assert_eq!(
+        texts(tree.root_node(), source, "at_keyword"),
+        [
+            "@use",
+            "@mixin",
+            "@content",
+            "@include",
+            "@each",
+            "@if",
+            "@else",
+            "@function",
+            "@return"
+        ]
+    );
+    assert_eq!(
+        texts(tree.root_node(), source, "selectors"),
+        [".primary", ".icon-#{$name}", "body", "body"]
+    );
+}
+
+#[test]
+fn test_scss_interpolation_placeholders_and_nesting() {
+    let source = "%message { border: 1px solid; width: 50%; }\n\
+        .card {\n  &__title { @extend %message; }\n  #{$prop}-top: 1px;\n  &:hover { color: red }\n}";
+    let tree = parse(source, Dialect::Scss);
+    assert_eq!(
+        texts(tree.root_node(), source, "property_name"),
+        ["border", "width", "#{$prop}-top", "color"]
+    );
+    assert_eq!(
+        kinds(source, Dialect::Scss),
+        [
+            (ConstructKind::Placeholder, "%message"),
+            (ConstructKind::ParentSuffix, "__title"),
+            (ConstructKind::Placeholder, "%message"),
+            (ConstructKind::Interpolation, "#{$prop}"),
+        ]
+    );
+}
+
+#[test]
+fn test_parent_suffixes() {
+    let source = ".a { &__el {} &--big .b, &-x:hover {} &.c {} & + & {} }
+p { &-x {} }";
+    let tree = parse(source, Dialect::Scss);
+    assert_eq!(
+        texts(tree.root_node(), source, "selectors"),
+        [
+            ".a",
+            "&__el",
+            "&--big .b, &-x:hover",
+            "&.c",
+            "& + &",
+            "p",
+            "&-x"
+        ]
+    );
+    assert_eq!(
+        kinds(source, Dialect::Scss),
+        [
+            (ConstructKind::ParentSuffix, "__el"),
+            (ConstructKind::ParentSuffix, "--big"),
+            (ConstructKind::ParentSuffix, "-x"),
+            (ConstructKind::ParentSuffix, "-x"),
+        ]
+    );
+}
+
+#[test]
+fn test_scss_modules_and_maps() {
+    let source = "@use 'sass:math';
+$m: (k: 1, j: (a: 2, b: 3));
+        .a { width: math.div($w, 2); height: math.$pi; margin: map.get((k: 1), k); }
+        div.b { c: 1.5; }";
+    let tree = parse(source, Dialect::Scss);
+    assert_eq!(
+        texts(tree.root_node(), source, "function_name"),
+        ["math.div", "map.get"]
+    );
+    assert_eq!(
+        texts(tree.root_node(), source, "plain_value"),
+        ["(k: 1, j: (a: 2, b: 3))", "$w", "math.$pi", "(k: 1)", "k"]
+    );
+    assert_eq!(
+        kinds(source, Dialect::Scss),
+        [
+            (ConstructKind::Variable, "$m"),
+            (ConstructKind::Map, "(k: 1, j: (a: 2, b: 3))"),
+            (ConstructKind::ModuleMember, "math.div"),
+            (ConstructKind::Variable, "$w"),
+            (ConstructKind::ModuleMember, "math.$pi"),
+            (ConstructKind::ModuleMember, "map.get"),
+            (ConstructKind::Map, "(k: 1)"),
+        ]
+    );
+}
+
+#[test]
+fn test_line_comments_in_values() {
+    let source = "a {\n  margin: 0 // no margin\n  ;\n  // a whole line\n  padding: 0;\n}\n// at the top level\nb { background: url(//cdn.example/x.png); content: \"//\"; }";
+    let tree = parse(source, Dialect::Scss);
+    assert_eq!(
+        texts(tree.root_node(), source, "comment"),
+        ["// no margin", "// a whole line", "// at the top level"]
+    );
+    assert_eq!(
+        texts(tree.root_node(), source, "property_name"),
+        ["margin", "padding", "background", "content"]
+    );
+}
+
+#[test]
+fn test_less() {
+    let source = "@primary: #333;\n@min: ~\"(min-width: 40em)\";\n\
+        .bordered(@width: 1px) { border: @width solid; }\n\
+        .card-@{name} {\n  color: @primary;\n  .bordered(2px);\n  // note\n}";
+    let tree = parse(source, Dialect::Less);
+    assert_eq!(
+        texts(tree.root_node(), source, "selectors"),
+        [".bordered(@width: 1px)", ".card-@{name}"]
+    );
+    assert_eq!(
+        kinds(source, Dialect::Less),
+        [
+            (ConstructKind::Variable, "@primary"),
+            (ConstructKind::Variable, "@min"),
+            (ConstructKind::Escape, "~\"(min-width: 40em)\""),
+            (ConstructKind::Parameters, "(@width: 1px)"),
+            (ConstructKind::Variable, "@width"),
+            (ConstructKind::Interpolation, "@{name}"),
+            (ConstructKind::Variable, "@primary"),
+            (ConstructKind::MixinCall, ".bordered(2px);"),
+            (ConstructKind::LineComment, "// note"),
+        ]
+    );
+}
+
+#[test]
+fn test_masking_keeps_lengths_and_plain_css() {
+    let source = "a { content: \"$x #{y}\"; } /* $z // w */ .é-#{$ü} { b: c }";
+    let (masked, _) = preprocessor::mask(source, Dialect::Scss);
+    assert_eq!(masked.len(), source.len());
+    assert!(masked.starts_with("a { content: \"$x #{y}\"; } /* $z // w */ "));
+
+    let css = "@media (width > 40em) { a:hover > b { width: calc(50% - 1px); } }";
+    assert_eq!(preprocessor::mask(css, Dialect::Scss).0, css);
+    assert_eq!(preprocessor::mask(css, Dialect::Less).0, css);
+}
+
+#[test]
+fn test_dialect_from_lang() {
+    assert_eq!(Dialect::from_lang("scss"), Some(Dialect::Scss));
+    assert_eq!(Dialect::from_lang("text/less"), Some(Dialect::Less));
+    assert_eq!(Dialect::from_lang("sass"), None);
+    assert_eq!(Dialect::from_lang("css"), None);
+}
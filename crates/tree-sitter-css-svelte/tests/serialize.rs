@@ -0,0 +1,328 @@
+use proptest::prelude::*;
+use tree_sitter::{Node, Tree};
+use tree_sitter_css_svelte::serialize::{format, minify};
+use tree_sitter_css_svelte::LANGUAGE;
+
+fn parse(source: &str) -> Tree {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&LANGUAGE.into())
+        .expect("failed to load css grammar");
+    parser.parse(source, None).expect("parse")
+}
+
+fn formatted(source: &str) -> String {
+    format(parse(source).root_node(), source, "  ")
+}
+
+fn minified(source: &str) -> String {
+    minify(parse(source).root_node(), source)
+}
+
+/// The kinds of every node under `node` and the text of its leaves, leaving
+/// out comments and semicolons, which minifying drops before a `}`.
+fn structure(node: Node, source: &str, out: &mut Vec<String>) {
+    if matches!(
+        node.kind(),
+        "comment" | "js_comment" | "html_comment_delimiter" | ";"
+    ) {
+        return;
+    }
+    if node.byte_range().is_empty() {
+        out.push(node.kind().to_string());
+        return;
+    }
+    if node.child_count() == 0 || node.kind() == "string_value" {
+        out.push(format!("{} {:?}", node.kind(), &source[node.byte_range()]));
+        return;
+    }
+    out.push(node.kind().to_string());
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        structure(child, source, out);
+    }
+}
+
+fn structure_of(source: &str) -> Vec<String> {
+    let tree = parse(source);
+    assert!(!tree.root_node().has_error(), "{source}");
+    let mut out = Vec::new();
+    structure(tree.root_node(), source, &mut out);
+    out
+}
+
+#[test]
+fn test_format_indents_one_item_per_line() {
+    let source = "a{color:red;margin:0 auto}.b{}\n\n\n@import 'x.css';";
+    assert_eq!(
+        format(parse(source).root_node(), source, "\t"),
+        "a {\n\tcolor: red;\n\tmargin: 0 auto;\n}\n.b {}\n\n@import 'x.css';\n"
+    );
+}
+
+#[test]
+fn test_format_nests_blocks() {
+    assert_eq!(
+        formatted(".a{&:hover{color:red}@media screen{color:blue}}"),
+        ".a {\n  &:hover {\n    color: red;\n  }\n  @media screen {\n    color: blue;\n  }\n}\n"
+    );
+}
+
+#[test]
+fn test_format_normalizes_query_spacing() {
+    assert_eq!(
+        formatted("@media screen   and (min-width:40em)and ( max-width : 60em ){}"),
+        "@media screen and (min-width: 40em) and (max-width: 60em) {}\n"
+    );
+    assert_eq!(
+        formatted("@container card (400px<=width){}"),
+        "@container card (400px <= width) {}\n"
+    );
+}
+
+#[test]
+fn test_format_normalizes_selectors_and_values() {
+    assert_eq!(
+        formatted("a>b~c+d , e  f{color:rgb( 1,2 ,3 )!important}"),
+        "a > b ~ c + d, e f {\n  color: rgb(1, 2, 3) !important;\n}\n"
+    );
+}
+
+#[test]
+fn test_format_keeps_comments() {
+    assert_eq!(
+        formatted("/* head */a{color:red;/* why */\n// note\n}"),
+        "/* head */\na {\n  color: red;\n  /* why */\n  // note\n}\n"
+    );
+}
+
+#[test]
+fn test_minify_strips_comments_and_whitespace() {
+    assert_eq!(
+        minified("/* x */\na > b ,\n.c {\n  color: red ;\n  // note\n  margin: 0  auto;\n}\n"),
+        "a>b,.c{color:red;margin:0 auto}"
+    );
+    assert_eq!(
+        minified("@media screen and (min-width: 40em) {\n  a { width: calc(100% - 2px) }\n}"),
+        "@media screen and (min-width:40em){a{width:calc(100% - 2px)}}"
+    );
+}
+
+#[test]
+fn test_minify_preserves_global_selectors() {
+    assert_eq!(minified("div :global(.a) {}"), "div :global(.a){}");
+    assert_eq!(minified("div:global(.a) {}"), "div:global(.a){}");
+    assert_eq!(minified("div :global .b {}"), "div :global .b{}");
+    assert_eq!(
+        minified(":global {\n  .a { color: red; }\n}"),
+        ":global{.a{color:red}}"
+    );
+}
+
+#[test]
+fn test_minify_keeps_semicolons_before_nested_rules() {
+    assert_eq!(
+        minified(".a { color: red; .b { color: blue; } }"),
+        ".a{color:red;.b{color:blue}}"
+    );
+}
+
+/// Whether whitespace may, or must, follow a token.
+#[derive(Clone, Copy)]
+enum Space {
+    None,
+    Optional,
+    Required,
+}
+
+fn whitespace() -> impl Strategy<Value = &'static str> {
+    prop_oneof![Just(" "), Just("  "), Just("\n"), Just("\n\n\t")]
+}
+
+/// `tokens` joined by random whitespace where each allows it.
+fn padded(tokens: Vec<(&'static str, Space)>) -> impl Strategy<Value = String> {
+    let count = tokens.len();
+    (
+        prop::collection::vec(whitespace(), count),
+        prop::collection::vec(any::<bool>(), count),
+    )
+        .prop_map(move |(spaces, chosen)| {
+            let mut out = String::new();
+            for (index, (token, space)) in tokens.iter().enumerate() {
+                out.push_str(token);
+                match space {
+                    Space::None => {}
+                    Space::Optional if !chosen[index] => {}
+                    _ => out.push_str(spaces[index]),
+                }
+            }
+            out
+        })
+}
+
+/// A selector list, with optional whitespace where CSS allows it.
+fn selector() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just("a".to_string()),
+        Just(".b".to_string()),
+        Just("#c:hover".to_string()),
+        Just("div :global(.d)".to_string()),
+        Just("p.e[data-x=\"y\"]".to_string()),
+        padded(vec![
+            ("a", Space::Optional),
+            (">", Space::Optional),
+            ("b", Space::None)
+        ]),
+        padded(vec![
+            ("a", Space::Optional),
+            ("+", Space::Optional),
+            ("b", Space::Optional),
+            ("~", Space::Optional),
+            ("c", Space::None)
+        ]),
+        padded(vec![
+            ("ul", Space::None),
+            (",", Space::Optional),
+            ("ol li", Space::None)
+        ]),
+        Just("&:focus".to_string()),
+    ]
+}
+
+fn declaration() -> impl Strategy<Value = String> {
+    prop_oneof![
+        padded(vec![
+            ("color", Space::None),
+            (":", Space::Optional),
+            ("red", Space::Optional),
+            (";", Space::Optional)
+        ]),
+        padded(vec![
+            ("margin", Space::None),
+            (":", Space::Optional),
+            ("0 auto", Space::Optional),
+            (";", Space::Optional)
+        ]),
+        padded(vec![
+            ("width", Space::None),
+            (":", Space::Optional),
+            ("calc(100% - 2px)", Space::Optional),
+            (";", Space::Optional)
+        ]),
+        padded(vec![
+            ("color", Space::None),
+            (":", Space::Optional),
+            ("rgb(", Space::Optional),
+            ("1", Space::Optional),
+            (",", Space::Optional),
+            ("2", Space::Optional),
+            (",", Space::Optional),
+            ("3", Space::Optional),
+            (")", Space::Optional),
+            (";", Space::Optional)
+        ]),
+        padded(vec![
+            ("font-family", Space::None),
+            (":", Space::Optional),
+            ("\"A  B\"", Space::Optional),
+            (",", Space::Optional),
+            ("serif", Space::Optional),
+            (";", Space::Optional)
+        ]),
+        padded(vec![
+            ("--gap", Space::None),
+            (":", Space::Optional),
+            ("var(", Space::Optional),
+            ("--x", Space::Optional),
+            (",", Space::Optional),
+            ("4px", Space::Optional),
+            (")", Space::Optional),
+            (";", Space::Optional)
+        ]),
+        padded(vec![
+            ("display", Space::None),
+            (":", Space::Optional),
+            ("none", Space::Optional),
+            ("!important", Space::Optional),
+            (";", Space::Optional)
+        ]),
+        Just("/* note */".to_string()),
+    ]
+}
+
+fn rule() -> impl Strategy<Value = String> {
+    let plain = (selector(), prop::collection::vec(declaration(), 0..4))
+        .prop_map(|(selector, declarations)| format!("{selector} {{{}}}", declarations.concat()));
+    plain.prop_recursive(2, 8, 3, |inner| {
+        prop_oneof![
+            (selector(), prop::collection::vec(inner.clone(), 1..3)).prop_map(
+                |(selector, rules)| format!("{selector}{{ color: red; {} }}", rules.concat())
+            ),
+            (
+                padded(vec![
+                    ("@media", Space::Required),
+                    ("screen", Space::Required),
+                    ("and", Space::Required),
+                    ("(", Space::Optional),
+                    ("min-width", Space::Optional),
+                    (":", Space::Optional),
+                    ("40em", Space::Optional),
+                    (")", Space::Optional)
+                ]),
+                prop::collection::vec(inner.clone(), 1..3)
+            )
+                .prop_map(|(query, rules)| format!("{query}{{{}}}", rules.concat())),
+            (
+                padded(vec![
+                    ("@container", Space::Required),
+                    ("card", Space::Required),
+                    ("(", Space::Optional),
+                    ("400px", Space::Optional),
+                    ("<=", Space::Optional),
+                    ("width", Space::Optional),
+                    (")", Space::Optional)
+                ]),
+                prop::collection::vec(inner, 1..3)
+            )
+                .prop_map(|(query, rules)| format!("{query}{{{}}}", rules.concat())),
+        ]
+    })
+}
+
+fn stylesheet() -> impl Strategy<Value = String> {
+    let item = prop_oneof![
+        4 => rule(),
+        1 => Just("@import url(\"a.css\") screen;".to_string()),
+        1 => Just("/* comment */".to_string()),
+    ];
+    prop::collection::vec((item, prop::option::of(whitespace())), 0..5).prop_map(|items| {
+        items
+            .into_iter()
+            .map(|(item, space)| format!("{item}{}", space.unwrap_or_default()))
+            .collect()
+    })
+}
+
+proptest! {
+    #[test]
+    fn test_output_reparses_to_the_same_tree(source in stylesheet()) {
+        prop_assume!(!parse(&source).root_node().has_error());
+        let expected = structure_of(&source);
+
+        let pretty = formatted(&source);
+        prop_assert_eq!(&structure_of(&pretty), &expected, "formatted:\n{}", pretty);
+        let small = minified(&source);
+        prop_assert_eq!(&structure_of(&small), &expected, "minified:\n{}", small);
+    }
+
+    #[test]
+    fn test_output_is_idempotent(source in stylesheet()) {
+        prop_assume!(!parse(&source).root_node().has_error());
+
+        let pretty = formatted(&source);
+        prop_assert_eq!(formatted(&pretty), pretty.clone());
+        let small = minified(&source);
+        prop_assert_eq!(minified(&small), small.clone());
+        prop_assert_eq!(minified(&pretty), small);
+    }
+}
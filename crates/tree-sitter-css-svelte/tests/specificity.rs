@@ -0,0 +1,115 @@
+use tree_sitter_css_svelte::specificity::specificity;
+use tree_sitter_css_svelte::LANGUAGE;
+
+/// The specificity of each selector in the first rule of `source`.
+fn specificities(source: &str) -> Vec<(u32, u32, u32)> {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&LANGUAGE.into())
+        .expect("failed to load css grammar");
+    let tree = parser.parse(source, None).expect("parse");
+    assert!(!tree.root_node().has_error(), "{source}");
+
+    let selectors = tree
+        .root_node()
+        .named_child(0)
+        .and_then(|rule| rule.child_by_field_name("prelude"))
+        .expect("a rule");
+    let mut cursor = selectors.walk();
+    let found = selectors
+        .named_children(&mut cursor)
+        .map(|selector| specificity(selector, source))
+        .collect();
+    found
+}
+
+fn single(selector: &str) -> (u32, u32, u32) {
+    let found = specificities(&format!("{selector} {{}}"));
+    assert_eq!(found.len(), 1, "{selector}");
+    found[0]
+}
+
+#[test]
+fn test_spec_examples() {
+    // Selectors Level 4, §15.
+    for (selector, expected) in [
+        ("*", (0, 0, 0)),
+        ("LI", (0, 0, 1)),
+        ("UL LI", (0, 0, 2)),
+        ("UL OL+LI", (0, 0, 3)),
+        ("H1 + *[REL=up]", (0, 1, 1)),
+        ("UL OL LI.red", (0, 1, 3)),
+        ("LI.red.level", (0, 2, 1)),
+        ("#x34y", (1, 0, 0)),
+        ("#s12:not(FOO)", (1, 0, 1)),
+        (".foo :is(.bar, #baz)", (1, 1, 0)),
+    ] {
+        assert_eq!(single(selector), expected, "{selector}");
+    }
+}
+
+#[test]
+fn test_evaluation_context_pseudo_classes() {
+    // Selectors Level 4, §15 and §4.
+    for (selector, expected) in [
+        (":is(em, #foo)", (1, 0, 0)),
+        (".qux:where(em, #foo#bar#baz)", (0, 1, 0)),
+        (":nth-child(even of li, .item)", (0, 2, 0)),
+        (":not(em, strong#foo)", (1, 0, 1)),
+        (":is(#a, .b:hover)", (1, 0, 0)),
+        (":not(.a:is(#b))", (1, 1, 0)),
+        ("a:has(> img.icon)", (0, 1, 2)),
+        ("li:nth-last-child(2n+1)", (0, 1, 1)),
+        ("div:not(span)", (0, 0, 2)),
+    ] {
+        assert_eq!(single(selector), expected, "{selector}");
+    }
+}
+
+#[test]
+fn test_pseudo_elements_and_legacy_forms() {
+    for (selector, expected) in [
+        ("p::first-line", (0, 0, 2)),
+        ("p:first-line", (0, 0, 2)),
+        ("a:hover::before", (0, 1, 2)),
+        ("::slotted(.y)", (0, 1, 1)),
+        ("a[href][target]", (0, 2, 1)),
+        ("#a#a", (2, 0, 0)),
+    ] {
+        assert_eq!(single(selector), expected, "{selector}");
+    }
+}
+
+#[test]
+fn test_selector_lists_are_per_selector() {
+    assert_eq!(
+        specificities("a, .b, #c > d {}"),
+        [(0, 0, 1), (0, 1, 0), (1, 0, 1)]
+    );
+}
+
+#[test]
+fn test_nesting_selector_takes_the_parent_rule() {
+    let source = "#nav, .menu { & a {} }";
+    let mut parser = tree_sitter::Parser::new();
+    parser.set_language(&LANGUAGE.into()).unwrap();
+    let tree = parser.parse(source, None).unwrap();
+
+    let nested = tree
+        .root_node()
+        .named_child(0)
+        .and_then(|rule| rule.child_by_field_name("block"))
+        .and_then(|block| block.named_child(0))
+        .and_then(|rule| rule.child_by_field_name("prelude"))
+        .and_then(|selectors| selectors.named_child(0))
+        .unwrap();
+    assert_eq!(&source[nested.byte_range()], "& a");
+    assert_eq!(specificity(nested, source), (1, 0, 1));
+}
+
+#[test]
+fn test_svelte_global_counts_its_contents() {
+    assert_eq!(single(":global(.a) p"), (0, 1, 1));
+    assert_eq!(single(":global(#x, p)"), (1, 0, 0));
+    assert_eq!(single("div :global .b"), (0, 1, 1));
+}
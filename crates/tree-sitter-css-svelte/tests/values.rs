@@ -0,0 +1,234 @@
+use tree_sitter_css_svelte::values::{
+    self, Calc, Color, ColorStop, GradientKind, Numeric, Operator, Value,
+};
+use tree_sitter_css_svelte::LANGUAGE;
+
+/// The typed values of the declarations in `a { ... }`.
+fn values_of(declarations: &str) -> Vec<Value> {
+    let source = format!("a {{ {declarations} }}");
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&LANGUAGE.into())
+        .expect("failed to load css grammar");
+    let tree = parser.parse(&source, None).expect("parse");
+    assert!(!tree.root_node().has_error(), "{source}");
+    values::values(tree.root_node(), &source)
+        .into_iter()
+        .map(|(_, value)| value)
+        .collect()
+}
+
+fn single(value: &str) -> Option<Value> {
+    let mut found = values_of(&format!("x: {value};"));
+    assert!(found.len() <= 1, "{value}");
+    found.pop()
+}
+
+fn math(value: &str) -> Calc {
+    match single(value) {
+        Some(Value::Math(calc)) => calc,
+        other => panic!("{value}: {other:?}"),
+    }
+}
+
+fn color(value: &str) -> Color {
+    match single(value) {
+        Some(Value::Color(color)) => color,
+        other => panic!("{value}: {other:?}"),
+    }
+}
+
+fn resolved(value: &str) -> Option<String> {
+    math(value).resolve().map(|number| number.to_string())
+}
+
+#[test]
+fn test_math_applies_operator_precedence() {
+    let calc = math("calc(100% - 2 * 3px)");
+    let Calc::Function { name, arguments } = &calc else {
+        panic!("{calc:?}");
+    };
+    assert_eq!(name, "calc");
+    assert!(matches!(
+        &arguments[0],
+        Calc::Operation {
+            operator: Operator::Subtract,
+            ..
+        }
+    ));
+    assert_eq!(calc.to_string(), "calc(100% - 2 * 3px)");
+    assert_eq!(
+        math("calc((1px + 2px) * 3)").to_string(),
+        "calc((1px + 2px) * 3)"
+    );
+    assert_eq!(
+        math("clamp(1rem, 2.5vw + 1rem, 3rem)").to_string(),
+        "clamp(1rem, 2.5vw + 1rem, 3rem)"
+    );
+}
+
+#[test]
+fn test_math_resolves_compatible_units() {
+    assert_eq!(resolved("calc(1in - 2px * 3)").as_deref(), Some("90px"));
+    assert_eq!(resolved("calc((1px + 2px) * 3)").as_deref(), Some("9px"));
+    assert_eq!(
+        resolved("min(100px, 1in, 2cm)").as_deref(),
+        Some("75.59055118110236px")
+    );
+    assert_eq!(resolved("clamp(1rem, 4rem, 3rem)").as_deref(), Some("3rem"));
+    assert_eq!(resolved("round(up, 7px, 5px)").as_deref(), Some("10px"));
+    assert_eq!(resolved("calc(10px / 4px)").as_deref(), Some("2.5"));
+    assert_eq!(resolved("calc(0.5turn + 90deg)").as_deref(), Some("270deg"));
+    assert_eq!(resolved("calc(100% - 2px)"), None);
+    assert_eq!(resolved("calc(var(--x) * 2)"), None);
+}
+
+#[test]
+fn test_invalid_math_has_no_value() {
+    // `+` needs whitespace around it inside calc().
+    assert_eq!(single("calc(1px+2px)"), None);
+    assert_eq!(single("calc()"), None);
+}
+
+#[test]
+fn test_numeric_converts_absolute_units() {
+    let inch = Numeric::new(1.0, "in");
+    assert_eq!(inch.to("px"), Some(Numeric::new(96.0, "px")));
+    assert_eq!(inch.to("pt"), Some(Numeric::new(72.0, "pt")));
+    assert_eq!(
+        Numeric::new(90.0, "deg").to("turn"),
+        Some(Numeric::new(0.25, "turn"))
+    );
+    assert_eq!(
+        Numeric::new(250.0, "ms").canonical(),
+        Numeric::new(0.25, "s")
+    );
+    assert_eq!(inch.to("deg"), None);
+    assert_eq!(Numeric::new(1.0, "em").to("px"), None);
+}
+
+#[test]
+fn test_hex_and_named_colors() {
+    assert_eq!(color("#f00").rgba(), Some([255, 0, 0, 255]));
+    assert_eq!(color("#ff000080").rgba(), Some([255, 0, 0, 128]));
+    assert_eq!(color("#abcd").to_string(), "#aabbccdd");
+    assert_eq!(color("RebeccaPurple").rgba(), Some([102, 51, 153, 255]));
+    assert_eq!(color("transparent").rgba(), Some([0, 0, 0, 0]));
+    assert_eq!(color("currentColor"), Color::Named("currentcolor".into()));
+    assert_eq!(single("auto"), None);
+}
+
+#[test]
+fn test_color_functions() {
+    let Color::Function(rgba) = color("rgba(1, 2, 3, .5)") else {
+        panic!();
+    };
+    assert!(rgba.legacy);
+    assert_eq!(rgba.channels.len(), 3);
+    assert_eq!(rgba.alpha, Some(Calc::Number(Numeric::new(0.5, ""))));
+
+    let Color::Function(oklch) = color("oklch(70% 0.1 200 / 50%)") else {
+        panic!();
+    };
+    assert!(!oklch.legacy);
+    assert_eq!(oklch.channels[0], Calc::Number(Numeric::new(70.0, "%")));
+    assert_eq!(oklch.alpha, Some(Calc::Number(Numeric::new(50.0, "%"))));
+
+    assert_eq!(
+        color("hsl(120deg 50% 50%)").to_string(),
+        "hsl(120deg 50% 50%)"
+    );
+    let Color::Function(p3) = color("color(display-p3 1 0.5 0)") else {
+        panic!();
+    };
+    assert_eq!(p3.space.as_deref(), Some("display-p3"));
+    assert_eq!(single("rgb(1 2)"), None);
+}
+
+#[test]
+fn test_relative_colors() {
+    let Color::Function(relative) = color("rgb(from var(--c) r g calc(b * 2) / 0.5)") else {
+        panic!();
+    };
+    assert_eq!(
+        relative.origin.as_deref(),
+        Some(&Color::Reference("var(--c)".into()))
+    );
+    assert_eq!(relative.channels[0], Calc::Keyword("r".into()));
+    assert_eq!(relative.channels[2].to_string(), "calc(b * 2)");
+    assert_eq!(
+        relative.to_string(),
+        "rgb(from var(--c) r g calc(b * 2) / 0.5)"
+    );
+    assert_eq!(
+        color("hsl(from #0f0 calc(h + 30) s l)").to_string(),
+        "hsl(from #00ff00 calc(h + 30) s l)"
+    );
+}
+
+#[test]
+fn test_color_mix() {
+    let Color::Mix(mix) = color("color-mix(in hsl longer hue, red 40%, #00f)") else {
+        panic!();
+    };
+    assert_eq!(mix.method, "hsl longer hue");
+    assert_eq!(mix.colors[0].0, Color::Named("red".into()));
+    assert_eq!(mix.colors[0].1, Some(Calc::Number(Numeric::new(40.0, "%"))));
+    assert_eq!(mix.colors[1], (Color::Hex([0, 0, 255, 255]), None));
+    assert_eq!(single("color-mix(red, blue)"), None);
+}
+
+#[test]
+fn test_gradient_stop_lists() {
+    let Some(Value::Gradient(linear)) =
+        single("linear-gradient(to right, red 0%, blue 50% 75%, 30%, green)")
+    else {
+        panic!();
+    };
+    assert_eq!(linear.kind, GradientKind::Linear);
+    assert_eq!(linear.configuration.as_deref(), Some("to right"));
+    assert_eq!(linear.stops.len(), 4);
+    assert!(matches!(
+        &linear.stops[1],
+        ColorStop::Color { positions, .. } if positions.len() == 2
+    ));
+    assert_eq!(
+        linear.stops[2],
+        ColorStop::Hint(Calc::Number(Numeric::new(30.0, "%")))
+    );
+
+    let Some(Value::Gradient(radial)) =
+        single("repeating-radial-gradient(circle at center, #fff, rgb(0 0 0) 10px)")
+    else {
+        panic!();
+    };
+    assert!(radial.repeating);
+    assert_eq!(radial.kind, GradientKind::Radial);
+    assert_eq!(
+        radial.to_string(),
+        "repeating-radial-gradient(circle at center, #ffffff, rgb(0 0 0) 10px)"
+    );
+
+    let Some(Value::Gradient(conic)) = single("conic-gradient(red, blue)") else {
+        panic!();
+    };
+    assert_eq!(conic.configuration, None);
+    assert_eq!(single("linear-gradient(to right)"), None);
+}
+
+#[test]
+fn test_values_finds_outermost_values_in_order() {
+    let found = values_of(
+        "width: calc(100% - var(--gap)); border: 1px solid rgb(0 0 0 / 10%); \
+         background: linear-gradient(red, blue), url(x.png); display: block;",
+    );
+    let found: Vec<_> = found.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        found,
+        [
+            "calc(100% - var(--gap))",
+            "rgb(0 0 0 / 10%)",
+            "linear-gradient(red, blue)",
+        ]
+    );
+}
@@ -6,7 +6,7 @@
  *
  * Per §13.1.2, there are six kinds of elements:
  * - Void elements: area, base, br, col, embed, hr, img, input, link, meta, source, track, wbr
- * - The template element
+ * - The template element: contents are a `template_content` fragment
  * - Raw text elements: script, style
  * - Escapable raw text elements: textarea, title
  * - Foreign elements: MathML/SVG
@@ -26,6 +26,9 @@ module.exports = grammar({
 
   extras: $ => [
     $.comment,
+    $.processing_instruction,
+    $.bogus_comment,
+    $.cdata_section,
     WHITESPACE,
   ],
 
@@ -39,25 +42,72 @@ module.exports = grammar({
     $.raw_text,
     $.comment,
     $.text,
+    // §13.2.5.41 - Bogus comments: `<?...>`, other `<!...>` markup, and
+    // `<![CDATA[...>` outside foreign content
+    $.processing_instruction,
+    $.bogus_comment,
+    $.cdata_section,
+    // §13.2.5.42 - `<![CDATA[...]]>` in foreign content
+    $.cdata,
+    $._template_start_tag_name,
+  ],
+
+  conflicts: $ => [
+    // `</` inside template contents starts either the template's end tag or
+    // an erroneous end tag; only the scanner can tell them apart
+    [$.template_content],
   ],
 
   rules: {
     document: $ => repeat($._node),
 
     // §13.1.1 - DOCTYPE
+    // §13.2.5.53 onwards - the name, then an optional PUBLIC or SYSTEM
+    // identifier. Anything else up to `>` is a bogus DOCTYPE remainder.
     doctype: $ => seq(
       '<!',
       alias($._doctype, 'doctype'),
-      /[^>]+/,
+      optional(seq(
+        field('name', $.doctype_name),
+        optional(choice(
+          seq(
+            alias($._doctype_public, 'PUBLIC'),
+            optional(seq(
+              field('public_id', $.doctype_identifier),
+              optional(field('system_id', $.doctype_identifier)),
+            )),
+          ),
+          seq(
+            alias($._doctype_system, 'SYSTEM'),
+            optional(field('system_id', $.doctype_identifier)),
+          ),
+        )),
+        optional(alias($._bogus_doctype, 'bogus')),
+      )),
       '>',
     ),
 
     _doctype: _ => /[Dd][Oo][Cc][Tt][Yy][Pp][Ee]/,
 
+    doctype_name: _ => /[^\s>]+/,
+
+    _doctype_public: _ => token(prec(1, /[Pp][Uu][Bb][Ll][Ii][Cc]/)),
+
+    _doctype_system: _ => token(prec(1, /[Ss][Yy][Ss][Tt][Ee][Mm]/)),
+
+    // A `>` ends the DOCTYPE even inside quotes, leaving the quote unclosed
+    doctype_identifier: _ => token(prec(1, choice(
+      /"[^">]*"?/,
+      /'[^'>]*'?/,
+    ))),
+
+    _bogus_doctype: _ => /[^\s>][^>]*/,
+
     _node: $ => choice(
       $.doctype,
       $.entity,
       $.text,
+      $.cdata,
       $.element,
       $.erroneous_end_tag,
     ),
@@ -76,12 +126,26 @@ module.exports = grammar({
         repeat($._node),
         choice($.end_tag, $._implicit_end_tag),
       ),
+      // Template element (§4.12.3) - content is a document fragment
+      $._template_element,
       // Raw text elements (§13.1.2) - content is unparsed raw_text
       $._raw_text_element,
       // Void elements / self-closing
       $.self_closing_tag,
+      alias($._template_self_closing_tag, $.self_closing_tag),
+    ),
+
+    // §4.12.3 - The template element
+    // Children belong to a separate document fragment, so the parent's
+    // implied end tags do not reach into it
+    _template_element: $ => seq(
+      alias($._template_start_tag, $.start_tag),
+      optional($.template_content),
+      choice($.end_tag, $._implicit_end_tag),
     ),
 
+    template_content: $ => repeat1($._node),
+
     // Raw text elements: script, style, textarea, title
     // Content is raw_text (not parsed as nodes)
     _raw_text_element: $ => seq(
@@ -105,6 +169,13 @@ module.exports = grammar({
       '>',
     ),
 
+    _template_start_tag: $ => seq(
+      '<',
+      field('name', alias($._template_start_tag_name, $.tag_name)),
+      repeat($.attribute),
+      '>',
+    ),
+
     // §13.1.2 - Void elements can use self-closing syntax
     self_closing_tag: $ => seq(
       '<',
@@ -113,6 +184,13 @@ module.exports = grammar({
       '/>',
     ),
 
+    _template_self_closing_tag: $ => seq(
+      '<',
+      field('name', alias($._template_start_tag_name, $.tag_name)),
+      repeat($.attribute),
+      '/>',
+    ),
+
     end_tag: $ => seq(
       '</',
       field('name', alias($._end_tag_name, $.tag_name)),
@@ -0,0 +1,160 @@
+//! Duplicate and malformed attribute names (§13.1.2.3).
+//!
+//! The grammar accepts any run of attributes on a tag, so `<p id=a id=b>`
+//! parses cleanly even though the tokenizer drops the second `id` and reports
+//! a parse error. This module walks every `start_tag` and `self_closing_tag`
+//! and reports repeated names and names containing characters the syntax
+//! does not allow.
+//!
+//! ```rust
+//! use tree_sitter_htmlx_html::{attributes, LANGUAGE};
+//!
+//! let source = r#"<p ID="a" class=x id="b">"#;
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let found = attributes::check(tree.root_node(), source);
+//! assert_eq!(found.len(), 1);
+//! assert_eq!(found[0].to_string(), "duplicate attribute `id`");
+//! assert_eq!(found[0].range.start_byte, 18);
+//! assert_eq!(found[0].first().unwrap().start_byte, 3);
+//! ```
+
+use std::fmt;
+
+use tree_sitter::{Node, Range};
+
+use crate::span::subrange;
+
+/// An attribute a browser would report as a parse error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    /// The span of the offending name, or of the offending character for
+    /// [`DiagnosticKind::InvalidAttributeName`].
+    pub range: Range,
+}
+
+/// The typed payload of a [`Diagnostic`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// A name already used earlier on the same tag. Browsers keep the first
+    /// one and drop this one.
+    DuplicateAttribute {
+        /// The name as written on the repeated attribute.
+        name: String,
+        /// The span of the first attribute's name.
+        first: Range,
+    },
+    /// A name containing a control character, a noncharacter, whitespace,
+    /// or one of `"`, `'`, `<`, `>`, `/`, and `=`.
+    InvalidAttributeName {
+        /// The name as written.
+        name: String,
+        /// The first character that is not allowed.
+        character: char,
+    },
+}
+
+impl Diagnostic {
+    /// The span of the earlier attribute this one repeats.
+    pub fn first(&self) -> Option<Range> {
+        match &self.kind {
+            DiagnosticKind::DuplicateAttribute { first, .. } => Some(*first),
+            DiagnosticKind::InvalidAttributeName { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.kind, f)
+    }
+}
+
+impl fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiagnosticKind::DuplicateAttribute { name, .. } => {
+                write!(f, "duplicate attribute `{name}`")
+            }
+            DiagnosticKind::InvalidAttributeName { name, character } => write!(
+                f,
+                "attribute name `{name}` contains {character:?}, which is not allowed"
+            ),
+        }
+    }
+}
+
+/// Report repeated and malformed attribute names on every tag under `root`,
+/// in source order.
+///
+/// Names are compared ASCII case-insensitively, as the tokenizer lowercases
+/// them.
+pub fn check(root: Node, source: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    visit(root, source, &mut diagnostics);
+    diagnostics
+}
+
+fn visit(node: Node, source: &str, diagnostics: &mut Vec<Diagnostic>) {
+    if matches!(node.kind(), "start_tag" | "self_closing_tag") {
+        check_tag(node, source, diagnostics);
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        visit(child, source, diagnostics);
+    }
+}
+
+fn check_tag(tag: Node, source: &str, diagnostics: &mut Vec<Diagnostic>) {
+    let mut seen: Vec<(&str, Range)> = Vec::new();
+    let mut cursor = tag.walk();
+    for attribute in tag.named_children(&mut cursor) {
+        let mut names = attribute.walk();
+        let Some(node) = attribute
+            .named_children(&mut names)
+            .find(|child| child.kind() == "attribute_name")
+        else {
+            continue;
+        };
+
+        let name = &source[node.byte_range()];
+        if let Some((offset, character)) = name.char_indices().find(|&(_, c)| !is_name_char(c)) {
+            diagnostics.push(Diagnostic {
+                kind: DiagnosticKind::InvalidAttributeName {
+                    name: name.to_string(),
+                    character,
+                },
+                range: subrange(node, name, offset, offset + character.len_utf8()),
+            });
+        }
+
+        match seen
+            .iter()
+            .find(|(other, _)| name.eq_ignore_ascii_case(other))
+        {
+            Some(&(_, first)) => diagnostics.push(Diagnostic {
+                kind: DiagnosticKind::DuplicateAttribute {
+                    name: name.to_string(),
+                    first,
+                },
+                range: node.range(),
+            }),
+            None => seen.push((name, node.range())),
+        }
+    }
+}
+
+/// Whether `c` may appear in an attribute name (§13.1.2.3).
+pub fn is_name_char(c: char) -> bool {
+    !(c.is_control()
+        || matches!(
+            c,
+            ' ' | '"' | '\'' | '<' | '>' | '/' | '=' | '\u{fdd0}'..='\u{fdef}'
+        )
+        || (c as u32 & 0xfffe) == 0xfffe)
+}
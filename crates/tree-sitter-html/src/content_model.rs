@@ -0,0 +1,362 @@
+//! Content-model checks for element nesting (§4, §13.1.2.4).
+//!
+//! The scanner closes elements implicitly when a child they cannot contain
+//! starts, using `tag_can_contain` in `tag.h`. That keeps `<p><div></div></p>`
+//! parseable, but the `<div>` ends up after the `<p>` and the `</p>` is left
+//! without an element to close. Other mistakes, like `<a>` inside `<a>` or
+//! `<input>` inside `<button>`, nest in the tree as written even though the
+//! content model forbids them. This module reports both kinds.
+//!
+//! ```rust
+//! use tree_sitter_htmlx_html::{content_model, LANGUAGE};
+//!
+//! let source = "<div><p><div>x</div></p><button><a href=x>go</a></button></div>";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let found = content_model::check(tree.root_node(), source);
+//! assert_eq!(found[0].to_string(), "`</p>` has no open `<p>` to close: `<div>` closed it implicitly");
+//! assert_eq!(found[1].to_string(), "`<a>` cannot be inside `<button>`");
+//! ```
+
+use std::fmt;
+
+use tree_sitter::{Node, Range};
+
+/// An element placed where the HTML content model does not allow it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    /// The span of the offending start tag, or of the stray end tag for
+    /// [`DiagnosticKind::ClosedImplicitly`].
+    pub range: Range,
+}
+
+/// The typed payload of a [`Diagnostic`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// An end tag for an element that a following sibling already closed,
+    /// as in `<p><div></div></p>`.
+    ClosedImplicitly {
+        /// The lowercased name of the closed element (`p`).
+        element: String,
+        /// The lowercased name of the element that closed it (`div`).
+        closed_by: String,
+        /// The span of the closed element's start tag.
+        start_tag: Range,
+        /// The span of the closing element's start tag.
+        closer: Range,
+    },
+    /// An element inside an ancestor that excludes it: `<a>` in `<a>`,
+    /// `<form>` in `<form>`, or interactive content in `<a>` or `<button>`.
+    InvalidDescendant {
+        /// The lowercased name of the nested element.
+        element: String,
+        /// The lowercased name of the ancestor.
+        ancestor: String,
+        /// The span of the ancestor's start tag.
+        ancestor_start_tag: Range,
+    },
+    /// An `<li>` whose parent is not `<ul>`, `<ol>`, or `<menu>`.
+    ListItemOutsideList {
+        /// The lowercased name of the parent element.
+        parent: String,
+    },
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.kind, f)
+    }
+}
+
+impl fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiagnosticKind::ClosedImplicitly {
+                element, closed_by, ..
+            } => write!(
+                f,
+                "`</{element}>` has no open `<{element}>` to close: `<{closed_by}>` closed it implicitly"
+            ),
+            DiagnosticKind::InvalidDescendant {
+                element, ancestor, ..
+            } => write!(f, "`<{element}>` cannot be inside `<{ancestor}>`"),
+            DiagnosticKind::ListItemOutsideList { parent } => write!(
+                f,
+                "`<li>` must be inside `<ul>`, `<ol>`, or `<menu>`, not `<{parent}>`"
+            ),
+        }
+    }
+}
+
+/// Whether an open `parent` element stays open when a `child` start tag
+/// follows it, compared ASCII case-insensitively. The answer comes from
+/// `tag_can_contain` in `tag.h`, the rule the scanner closes elements by.
+pub fn can_contain(parent: &str, child: &str) -> bool {
+    let (Ok(parent_length), Ok(child_length)) =
+        (u32::try_from(parent.len()), u32::try_from(child.len()))
+    else {
+        return true;
+    };
+    unsafe {
+        crate::tree_sitter_html_tag_can_contain(
+            parent.as_ptr(),
+            parent_length,
+            child.as_ptr(),
+            child_length,
+        )
+    }
+}
+
+/// Report end tags left stray by implicit closing, excluded descendants,
+/// and list items outside lists under `root`, in source order.
+pub fn check(root: Node, source: &str) -> Vec<Diagnostic> {
+    check_with(root, source, |_| false)
+}
+
+/// Like [`check`], for trees where tags whose name satisfies `is_component`
+/// are components. Components are never checked, and their children are
+/// checked as if they had no parent, since a component can render them
+/// anywhere.
+pub fn check_with(
+    root: Node,
+    source: &str,
+    is_component: impl Fn(&str) -> bool,
+) -> Vec<Diagnostic> {
+    let mut checker = Checker {
+        source,
+        is_component: &is_component,
+        ancestors: Vec::new(),
+        foreign: 0,
+        diagnostics: Vec::new(),
+    };
+    checker.visit(root);
+    checker
+        .diagnostics
+        .sort_by_key(|diagnostic| diagnostic.range.start_byte);
+    checker.diagnostics
+}
+
+/// An open element and its start tag, or `None` for a component, which
+/// ancestor checks do not look past.
+type Ancestor<'tree> = Option<(String, Node<'tree>)>;
+
+struct Checker<'a, 'tree> {
+    source: &'a str,
+    is_component: &'a dyn Fn(&str) -> bool,
+    ancestors: Vec<Ancestor<'tree>>,
+    /// How many `<svg>` and `<math>` elements are open. SVG and MathML have
+    /// content models of their own, which are not checked.
+    foreign: usize,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'tree> Checker<'_, 'tree> {
+    fn visit(&mut self, node: Node<'tree>) {
+        if node.kind() == "element" {
+            self.element(node);
+            return;
+        }
+        self.children(node);
+    }
+
+    fn children(&mut self, node: Node<'tree>) {
+        // Elements among the children that a later sibling closed
+        // implicitly: the element, its name, and the sibling that closed it.
+        let mut closed: Vec<(Node<'tree>, String, Node<'tree>)> = Vec::new();
+        let mut open: Option<(Node<'tree>, String)> = None;
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if let Some(name) = self.element_name(child) {
+                if let Some((element, open_name)) = open.take() {
+                    if !can_contain(&open_name, &name) {
+                        closed.push((element, open_name, child));
+                    }
+                }
+                if !has_end_tag(child) {
+                    open = Some((child, name));
+                }
+            } else if let Some((name, range)) = stray_end_tag(child, self.source) {
+                let name = name.to_ascii_lowercase();
+                if let Some(index) = closed.iter().rposition(|(_, closed, _)| *closed == name) {
+                    let (element, element_name, closer) = closed.remove(index);
+                    let closer_name = self.element_name(closer).unwrap_or_default();
+                    self.diagnostics.push(Diagnostic {
+                        kind: DiagnosticKind::ClosedImplicitly {
+                            element: element_name,
+                            closed_by: closer_name,
+                            start_tag: start_tag(element)
+                                .map_or(element.range(), |tag| tag.range()),
+                            closer: start_tag(closer).map_or(closer.range(), |tag| tag.range()),
+                        },
+                        range,
+                    });
+                }
+            }
+            self.visit(child);
+        }
+    }
+
+    fn element(&mut self, element: Node<'tree>) {
+        let Some(tag) = start_tag(element) else {
+            self.children(element);
+            return;
+        };
+        let written = tag
+            .child_by_field_name("name")
+            .map_or("", |name| &self.source[name.byte_range()]);
+
+        if (self.is_component)(written) || self.foreign > 0 {
+            self.ancestors.push(None);
+            self.children(element);
+            self.ancestors.pop();
+            return;
+        }
+
+        let name = written.to_ascii_lowercase();
+        self.check_placement(&name, tag);
+
+        if matches!(name.as_str(), "svg" | "math") {
+            self.foreign += 1;
+            self.children(element);
+            self.foreign -= 1;
+            return;
+        }
+        self.ancestors.push(Some((name, tag)));
+        self.children(element);
+        self.ancestors.pop();
+    }
+
+    fn check_placement(&mut self, name: &str, tag: Node<'tree>) {
+        if name == "li" {
+            if let Some(Some((parent, _))) = self.ancestors.last() {
+                if !matches!(parent.as_str(), "ul" | "ol" | "menu" | "template") {
+                    self.diagnostics.push(Diagnostic {
+                        kind: DiagnosticKind::ListItemOutsideList {
+                            parent: parent.clone(),
+                        },
+                        range: tag.range(),
+                    });
+                }
+            }
+        }
+
+        let interactive = is_interactive(name, tag, self.source);
+        let excluded_by = self
+            .ancestors
+            .iter()
+            .rev()
+            .map_while(|ancestor| ancestor.as_ref())
+            .find(|(ancestor, _)| match ancestor.as_str() {
+                "a" | "button" => interactive,
+                "form" => name == "form",
+                _ => false,
+            });
+        if let Some((ancestor, ancestor_tag)) = excluded_by {
+            self.diagnostics.push(Diagnostic {
+                kind: DiagnosticKind::InvalidDescendant {
+                    element: name.to_string(),
+                    ancestor: ancestor.clone(),
+                    ancestor_start_tag: ancestor_tag.range(),
+                },
+                range: tag.range(),
+            });
+        }
+    }
+
+    /// The lowercased name of `node` if it is an HTML element.
+    fn element_name(&self, node: Node) -> Option<String> {
+        if node.kind() != "element" {
+            return None;
+        }
+        let name = start_tag(node)?.child_by_field_name("name")?;
+        let name = &self.source[name.byte_range()];
+        (!(self.is_component)(name)).then(|| name.to_ascii_lowercase())
+    }
+}
+
+fn start_tag(element: Node) -> Option<Node> {
+    element
+        .child(0)
+        .filter(|tag| matches!(tag.kind(), "start_tag" | "self_closing_tag"))
+}
+
+fn has_end_tag(element: Node) -> bool {
+    let mut cursor = element.walk();
+    let has_end_tag = element.children(&mut cursor).any(|child| {
+        (child.kind() == "end_tag" && !child.is_missing()) || child.kind() == "self_closing_tag"
+    });
+    has_end_tag
+}
+
+/// The name and span of an end tag that closes nothing. The HTML grammar
+/// leaves a stray end tag at the top level as an `ERROR` holding `</`,
+/// followed by text.
+fn stray_end_tag(node: Node, source: &str) -> Option<(String, Range)> {
+    if node.kind() == "erroneous_end_tag" {
+        let mut cursor = node.walk();
+        let name = node
+            .named_children(&mut cursor)
+            .find(|child| child.kind() == "erroneous_end_tag_name")?;
+        return Some((source[name.byte_range()].to_string(), node.range()));
+    }
+
+    if !node.is_error() || !source[node.byte_range()].starts_with("</") {
+        return None;
+    }
+    let rest = &source[node.start_byte() + 2..];
+    let len = rest.find(|c: char| c == '>' || c.is_ascii_whitespace())?;
+    if len == 0 || !rest[len..].starts_with('>') {
+        return None;
+    }
+    let end = node.start_byte() + 2 + len + 1;
+    let mut end_point = node.start_position();
+    end_point.column += end - node.start_byte();
+    Some((
+        rest[..len].to_string(),
+        Range {
+            start_byte: node.start_byte(),
+            end_byte: end,
+            start_point: node.start_position(),
+            end_point,
+        },
+    ))
+}
+
+/// Interactive content (§3.2.5.2.7) that may not be nested in `<a>` or
+/// `<button>`.
+fn is_interactive(name: &str, tag: Node, source: &str) -> bool {
+    let attribute = |wanted: &str| {
+        let mut cursor = tag.walk();
+        let found = tag
+            .named_children(&mut cursor)
+            .filter(|child| child.kind() == "attribute")
+            .find_map(|attribute| {
+                let mut cursor = attribute.walk();
+                let name = attribute
+                    .named_children(&mut cursor)
+                    .find(|child| child.kind() == "attribute_name")?;
+                if !source[name.byte_range()].eq_ignore_ascii_case(wanted) {
+                    return None;
+                }
+                let value = attribute
+                    .named_children(&mut cursor)
+                    .find(|child| child.kind() != "attribute_name");
+                Some(value.map_or("", |value| {
+                    source[value.byte_range()].trim_matches(|c| c == '"' || c == '\'')
+                }))
+            });
+        found
+    };
+
+    match name {
+        "a" | "button" | "details" | "embed" | "iframe" | "label" | "select" | "textarea" => true,
+        "audio" | "video" => attribute("controls").is_some(),
+        "img" => attribute("usemap").is_some(),
+        "input" => !attribute("type").is_some_and(|kind| kind.eq_ignore_ascii_case("hidden")),
+        _ => false,
+    }
+}
@@ -0,0 +1,255 @@
+//! DOCTYPE fields and quirks mode detection.
+//!
+//! The grammar splits `<!DOCTYPE ...>` the way the tokenizer's DOCTYPE
+//! states do (§13.2.5.53 onwards): a `doctype` node has `name`, `public_id`,
+//! and `system_id` fields, and an anonymous `bogus` child for any remainder.
+//! This module reads those fields and computes the document's quirks mode
+//! from them (§13.2.6.4.1).
+//!
+//! ```rust
+//! use tree_sitter_htmlx_html::doctype::{self, QuirksMode};
+//! use tree_sitter_htmlx_html::LANGUAGE;
+//!
+//! let source = r#"<!DOCTYPE HTML PUBLIC "-//W3C//DTD HTML 4.01 Transitional//EN"><p>x</p>"#;
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let legacy = doctype::find(tree.root_node(), source).unwrap();
+//! assert_eq!(legacy.name.as_deref(), Some("html"));
+//! assert_eq!(legacy.system_id, None);
+//! assert_eq!(doctype::quirks_mode(tree.root_node(), source), QuirksMode::Quirks);
+//! ```
+
+use tree_sitter::Node;
+
+/// The rendering mode a DOCTYPE selects.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum QuirksMode {
+    /// Standards mode.
+    NoQuirks,
+    /// Almost-standards mode: only the line height of inline images in table
+    /// cells differs.
+    LimitedQuirks,
+    /// Quirks mode: legacy layout, and a `<table>` no longer closes an open `<p>`.
+    Quirks,
+}
+
+/// The fields of a `<!DOCTYPE ...>` token.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Doctype {
+    /// The name, lowercased (`html` for `<!DOCTYPE HTML>`).
+    pub name: Option<String>,
+    /// The `PUBLIC` identifier, without its quotes.
+    pub public_id: Option<String>,
+    /// The system identifier, without its quotes.
+    pub system_id: Option<String>,
+    /// Set for malformed DOCTYPEs, which always select quirks mode.
+    pub force_quirks: bool,
+}
+
+impl Doctype {
+    /// Read the fields of a `doctype` node.
+    pub fn from_node(node: Node, source: &str) -> Option<Self> {
+        if node.kind() != "doctype" {
+            return None;
+        }
+        let field = |name| node.child_by_field_name(name);
+        let mut doctype = Doctype {
+            name: field("name").map(|name| source[name.byte_range()].to_ascii_lowercase()),
+            ..Doctype::default()
+        };
+        let mut closed = true;
+        let mut identifier = |name| {
+            field(name).map(|id| {
+                let (id, id_closed) = unquote(&source[id.byte_range()]);
+                closed &= id_closed;
+                id.to_string()
+            })
+        };
+        doctype.public_id = identifier("public_id");
+        doctype.system_id = identifier("system_id");
+
+        let mut keyword = None;
+        let mut bogus = false;
+        // A DOCTYPE cut off by the end of the file has no `>`.
+        let mut terminated = false;
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            match child.kind() {
+                "PUBLIC" | "SYSTEM" => keyword = Some(child.kind()),
+                "bogus" => bogus = true,
+                _ => {}
+            }
+            terminated = child.kind() == ">" && !child.is_missing();
+        }
+
+        doctype.force_quirks = doctype.name.is_none()
+            || !terminated
+            || !closed
+            || match keyword {
+                Some("PUBLIC") => doctype.public_id.is_none(),
+                Some(_) => doctype.system_id.is_none(),
+                None => false,
+            }
+            // Junk after the system identifier is an error, but does not
+            // force quirks mode.
+            || (bogus && doctype.system_id.is_none());
+        Some(doctype)
+    }
+
+    /// The quirks mode this DOCTYPE selects, per the table in §13.2.6.4.1.
+    pub fn quirks_mode(&self) -> QuirksMode {
+        if self.force_quirks || self.name.as_deref() != Some("html") {
+            return QuirksMode::Quirks;
+        }
+
+        let public_id = self.public_id.as_deref();
+        let system_id = self.system_id.as_deref();
+        let public_is = |ids: &[&str]| {
+            public_id.is_some_and(|public| ids.iter().any(|id| public.eq_ignore_ascii_case(id)))
+        };
+        let public_starts_with = |prefixes: &[&str]| {
+            public_id.is_some_and(|public| {
+                prefixes
+                    .iter()
+                    .any(|prefix| starts_with_ignore_case(public, prefix))
+            })
+        };
+
+        if public_is(QUIRKS_PUBLIC_IDS)
+            || system_id.is_some_and(|system| system.eq_ignore_ascii_case(QUIRKS_SYSTEM_ID))
+            || public_starts_with(QUIRKS_PUBLIC_ID_PREFIXES)
+            || (system_id.is_none() && public_starts_with(HTML_401_PUBLIC_ID_PREFIXES))
+        {
+            QuirksMode::Quirks
+        } else if public_starts_with(XHTML_10_PUBLIC_ID_PREFIXES)
+            || (system_id.is_some() && public_starts_with(HTML_401_PUBLIC_ID_PREFIXES))
+        {
+            QuirksMode::LimitedQuirks
+        } else {
+            QuirksMode::NoQuirks
+        }
+    }
+}
+
+/// The DOCTYPE that sets the quirks mode of the document at `root`: a
+/// `doctype` preceded only by comments and whitespace.
+pub fn find(root: Node, source: &str) -> Option<Doctype> {
+    let mut cursor = root.walk();
+    for child in root.named_children(&mut cursor) {
+        match child.kind() {
+            "doctype" => return Doctype::from_node(child, source),
+            "comment" | "processing_instruction" | "bogus_comment" | "cdata_section" => {}
+            "text" if is_blank(&source[child.byte_range()]) => {}
+            _ => return None,
+        }
+    }
+    None
+}
+
+/// The quirks mode of the document at `root`. A document without a leading
+/// DOCTYPE is in quirks mode.
+pub fn quirks_mode(root: Node, source: &str) -> QuirksMode {
+    find(root, source).map_or(QuirksMode::Quirks, |doctype| doctype.quirks_mode())
+}
+
+/// Strip the quotes from a `doctype_identifier`, returning whether its
+/// closing quote was found.
+fn unquote(text: &str) -> (&str, bool) {
+    let body = &text[1..];
+    match body.strip_suffix(&text[..1]) {
+        Some(body) => (body, true),
+        None => (body, false),
+    }
+}
+
+fn starts_with_ignore_case(text: &str, prefix: &str) -> bool {
+    text.len() >= prefix.len()
+        && text.as_bytes()[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
+}
+
+fn is_blank(text: &str) -> bool {
+    text.trim_matches(is_html_space).is_empty()
+}
+
+fn is_html_space(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\u{c}' | '\r')
+}
+
+const QUIRKS_PUBLIC_IDS: &[&str] = &[
+    "-//W3O//DTD W3 HTML Strict 3.0//EN//",
+    "-/W3C/DTD HTML 4.0 Transitional/EN",
+    "HTML",
+];
+
+const QUIRKS_SYSTEM_ID: &str = "http://www.ibm.com/data/dtd/v11/ibmxhtml1-transitional.dtd";
+
+const QUIRKS_PUBLIC_ID_PREFIXES: &[&str] = &[
+    "+//Silmaril//dtd html Pro v0r11 19970101//",
+    "-//AS//DTD HTML 3.0 asWedit + extensions//",
+    "-//AdvaSoft Ltd//DTD HTML 3.0 asWedit + extensions//",
+    "-//IETF//DTD HTML 2.0 Level 1//",
+    "-//IETF//DTD HTML 2.0 Level 2//",
+    "-//IETF//DTD HTML 2.0 Strict Level 1//",
+    "-//IETF//DTD HTML 2.0 Strict Level 2//",
+    "-//IETF//DTD HTML 2.0 Strict//",
+    "-//IETF//DTD HTML 2.0//",
+    "-//IETF//DTD HTML 2.1E//",
+    "-//IETF//DTD HTML 3.0//",
+    "-//IETF//DTD HTML 3.2 Final//",
+    "-//IETF//DTD HTML 3.2//",
+    "-//IETF//DTD HTML 3//",
+    "-//IETF//DTD HTML Level 0//",
+    "-//IETF//DTD HTML Level 1//",
+    "-//IETF//DTD HTML Level 2//",
+    "-//IETF//DTD HTML Level 3//",
+    "-//IETF//DTD HTML Strict Level 0//",
+    "-//IETF//DTD HTML Strict Level 1//",
+    "-//IETF//DTD HTML Strict Level 2//",
+    "-//IETF//DTD HTML Strict Level 3//",
+    "-//IETF//DTD HTML Strict//",
+    "-//IETF//DTD HTML//",
+    "-//Metrius//DTD Metrius Presentational//",
+    "-//Microsoft//DTD Internet Explorer 2.0 HTML Strict//",
+    "-//Microsoft//DTD Internet Explorer 2.0 HTML//",
+    "-//Microsoft//DTD Internet Explorer 2.0 Tables//",
+    "-//Microsoft//DTD Internet Explorer 3.0 HTML Strict//",
+    "-//Microsoft//DTD Internet Explorer 3.0 HTML//",
+    "-//Microsoft//DTD Internet Explorer 3.0 Tables//",
+    "-//Netscape Comm. Corp.//DTD HTML//",
+    "-//Netscape Comm. Corp.//DTD Strict HTML//",
+    "-//O'Reilly and Associates//DTD HTML 2.0//",
+    "-//O'Reilly and Associates//DTD HTML Extended 1.0//",
+    "-//O'Reilly and Associates//DTD HTML Extended Relaxed 1.0//",
+    "-//SQ//DTD HTML 2.0 HoTMetaL + extensions//",
+    "-//SoftQuad Software//DTD HoTMetaL PRO 6.0::19990601::extensions to HTML 4.0//",
+    "-//SoftQuad//DTD HoTMetaL PRO 4.0::19971010::extensions to HTML 4.0//",
+    "-//Spyglass//DTD HTML 2.0 Extended//",
+    "-//Sun Microsystems Corp.//DTD HotJava HTML//",
+    "-//Sun Microsystems Corp.//DTD HotJava Strict HTML//",
+    "-//W3C//DTD HTML 3 1995-03-24//",
+    "-//W3C//DTD HTML 3.2 Draft//",
+    "-//W3C//DTD HTML 3.2 Final//",
+    "-//W3C//DTD HTML 3.2//",
+    "-//W3C//DTD HTML 3.2S Draft//",
+    "-//W3C//DTD HTML 4.0 Frameset//",
+    "-//W3C//DTD HTML 4.0 Transitional//",
+    "-//W3C//DTD HTML Experimental 19960712//",
+    "-//W3C//DTD HTML Experimental 970421//",
+    "-//W3C//DTD W3 HTML//",
+    "-//W3O//DTD W3 HTML 3.0//",
+    "-//WebTechs//DTD Mozilla HTML 2.0//",
+    "-//WebTechs//DTD Mozilla HTML//",
+];
+
+/// Quirks without a system identifier, limited quirks with one.
+const HTML_401_PUBLIC_ID_PREFIXES: &[&str] = &[
+    "-//W3C//DTD HTML 4.01 Frameset//",
+    "-//W3C//DTD HTML 4.01 Transitional//",
+];
+
+const XHTML_10_PUBLIC_ID_PREFIXES: &[&str] = &[
+    "-//W3C//DTD XHTML 1.0 Frameset//",
+    "-//W3C//DTD XHTML 1.0 Transitional//",
+];
@@ -0,0 +1,400 @@
+//! Character references (§13.5) decoded with the WHATWG named reference table.
+//!
+//! The grammar's `entity` rule only checks the shape of a reference, so
+//! `&notanentity;` and `&amp` without its `;` parse like any other `entity`
+//! node, and HTMLX keeps a bare `&` inside `text`. This module decodes
+//! references the way the tokenizer's character reference state does and
+//! reports the ones it treats as parse errors.
+//!
+//! ```rust
+//! use tree_sitter_htmlx_html::{entities, LANGUAGE};
+//!
+//! assert_eq!(entities::decode_entity("&amp;").as_deref(), Some("&"));
+//! assert_eq!(entities::decode_entity("&#x1F600;").as_deref(), Some("😀"));
+//! assert_eq!(entities::decode_entity("&notanentity;"), None);
+//!
+//! let source = "<p>&lt;&copy 2024&bogus;</p>";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let found = entities::check(tree.root_node(), source);
+//! assert_eq!(found[0].to_string(), "character reference `&copy` is missing a `;`");
+//! assert_eq!(found[1].to_string(), "unknown named character reference `&bogus;`");
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+
+use tree_sitter::{Node, Range};
+
+use crate::span::subrange;
+
+mod table;
+
+use table::NAMED_REFERENCES;
+
+/// A character reference a browser would report as a parse error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    /// The span of the reference, from `&` through its `;` if any.
+    pub range: Range,
+}
+
+/// The typed payload of a [`Diagnostic`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// A `;`-terminated name that is not in the table, such as `&notanentity;`.
+    UnknownNamedReference {
+        /// The reference as written.
+        reference: String,
+    },
+    /// A reference written without its `;`, such as `&amp` or `&#65`.
+    MissingSemicolon {
+        /// The reference as written.
+        reference: String,
+    },
+    /// A numeric reference to NUL, a surrogate, a noncharacter, a control
+    /// character, or a value past U+10FFFF.
+    InvalidCodePoint {
+        /// The reference as written.
+        reference: String,
+        /// The referenced value, saturated at `u32::MAX`.
+        code_point: u32,
+    },
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.kind, f)
+    }
+}
+
+impl fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiagnosticKind::UnknownNamedReference { reference } => {
+                write!(f, "unknown named character reference `{reference}`")
+            }
+            DiagnosticKind::MissingSemicolon { reference } => {
+                write!(f, "character reference `{reference}` is missing a `;`")
+            }
+            DiagnosticKind::InvalidCodePoint {
+                reference,
+                code_point,
+            } => write!(
+                f,
+                "character reference `{reference}` refers to U+{code_point:04X}, which is not allowed"
+            ),
+        }
+    }
+}
+
+/// Decode a single reference such as `&amp;`, `&copy`, or `&#x41;`.
+///
+/// Returns `None` unless all of `reference` is one character reference:
+/// unknown names, `&ampx`, and `&#;` are not. Numeric references to invalid
+/// code points decode to what a browser inserts, usually U+FFFD.
+pub fn decode_entity(reference: &str) -> Option<Cow<'static, str>> {
+    if !reference.starts_with('&') {
+        return None;
+    }
+    let found = scan_reference(reference, false)?;
+    if found.len != reference.len() {
+        return None;
+    }
+    found.decoded
+}
+
+/// Decode every character reference in `text`, using the rules for text
+/// content.
+pub fn decode_text(text: &str) -> Cow<'_, str> {
+    decode(text, false)
+}
+
+/// Decode every character reference in `text`, using the rules for
+/// attribute values: a reference without its `;` that is followed by `=` or
+/// an alphanumeric stays literal, so `?a=1&copy=2` keeps its `&copy`.
+pub fn decode_attribute(text: &str) -> Cow<'_, str> {
+    decode(text, true)
+}
+
+/// The decoded text of a `text`, `entity`, `attribute_value`, or `raw_text`
+/// node.
+///
+/// `attribute_value` nodes use the attribute rules. `raw_text` is only
+/// decoded inside `<textarea>` and `<title>`, whose content is escapable raw
+/// text; other nodes decode their source with the text rules.
+pub fn decoded_text<'a>(node: Node, source: &'a str) -> Cow<'a, str> {
+    let text = &source[node.byte_range()];
+    match node.kind() {
+        "attribute_value" => decode_attribute(text),
+        "raw_text" if !is_escapable_raw_text(node, source) => Cow::Borrowed(text),
+        _ => decode_text(text),
+    }
+}
+
+/// Report unknown named references, missing `;`s, and numeric references
+/// to invalid code points under `root`, in source order.
+pub fn check(root: Node, source: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    visit(root, source, &mut diagnostics);
+    diagnostics
+}
+
+fn visit(node: Node, source: &str, diagnostics: &mut Vec<Diagnostic>) {
+    let attribute = match node.kind() {
+        "text" | "entity" => Some(false),
+        "raw_text" if is_escapable_raw_text(node, source) => Some(false),
+        "attribute_value" => Some(true),
+        _ => None,
+    };
+
+    if let Some(attribute) = attribute {
+        let text = &source[node.byte_range()];
+        for (offset, reference) in references(text, attribute) {
+            let Some(kind) = reference.problem else {
+                continue;
+            };
+            let range = subrange(node, text, offset, offset + reference.len);
+            diagnostics.push(Diagnostic { kind, range });
+        }
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        visit(child, source, diagnostics);
+    }
+}
+
+fn is_escapable_raw_text(node: Node, source: &str) -> bool {
+    let Some(name) = node
+        .parent()
+        .and_then(|element| element.child(0))
+        .and_then(|start_tag| start_tag.child_by_field_name("name"))
+    else {
+        return false;
+    };
+    let name = &source[name.byte_range()];
+    name.eq_ignore_ascii_case("textarea") || name.eq_ignore_ascii_case("title")
+}
+
+struct Reference {
+    /// Byte length from `&` through the `;`, if one was consumed.
+    len: usize,
+    /// `None` for an unknown `;`-terminated name, which stays literal.
+    decoded: Option<Cow<'static, str>>,
+    problem: Option<DiagnosticKind>,
+}
+
+fn decode(text: &str, attribute: bool) -> Cow<'_, str> {
+    let mut decoded = String::new();
+    let mut copied = 0;
+    for (offset, reference) in references(text, attribute) {
+        let Some(value) = reference.decoded else {
+            continue;
+        };
+        decoded.push_str(&text[copied..offset]);
+        decoded.push_str(&value);
+        copied = offset + reference.len;
+    }
+
+    if copied == 0 {
+        return Cow::Borrowed(text);
+    }
+    decoded.push_str(&text[copied..]);
+    Cow::Owned(decoded)
+}
+
+/// Every reference in `text` with its byte offset. A `&` that does not start
+/// a reference is skipped.
+fn references(text: &str, attribute: bool) -> impl Iterator<Item = (usize, Reference)> + '_ {
+    let mut at = 0;
+    std::iter::from_fn(move || {
+        while let Some(found) = text[at..].find('&') {
+            let start = at + found;
+            match scan_reference(&text[start..], attribute) {
+                Some(reference) => {
+                    at = start + reference.len;
+                    return Some((start, reference));
+                }
+                None => at = start + 1,
+            }
+        }
+        None
+    })
+}
+
+/// Read the reference at the start of `text`, which begins with `&`.
+fn scan_reference(text: &str, attribute: bool) -> Option<Reference> {
+    if text[1..].starts_with('#') {
+        numeric_reference(text)
+    } else {
+        named_reference(text, attribute)
+    }
+}
+
+/// §13.2.5.73 - Named character reference state
+fn named_reference(text: &str, attribute: bool) -> Option<Reference> {
+    let name_len = text[1..]
+        .bytes()
+        .take_while(u8::is_ascii_alphanumeric)
+        .count();
+    if name_len == 0 {
+        return None;
+    }
+
+    let terminated = text[1 + name_len..].starts_with(';');
+    if terminated {
+        if let Some(value) = lookup(&text[1..name_len + 2]) {
+            return Some(Reference {
+                len: name_len + 2,
+                decoded: Some(Cow::Borrowed(value)),
+                problem: None,
+            });
+        }
+    }
+
+    // Legacy references such as `&amp` match as the longest prefix of the
+    // name, so `&notit;` is `¬` followed by `it;`.
+    for len in (1..=name_len).rev() {
+        let Some(value) = lookup(&text[1..1 + len]) else {
+            continue;
+        };
+        let next = text[1 + len..].chars().next();
+        if attribute && next.is_some_and(|c| c == '=' || c.is_ascii_alphanumeric()) {
+            return None;
+        }
+        return Some(Reference {
+            len: 1 + len,
+            decoded: Some(Cow::Borrowed(value)),
+            problem: Some(DiagnosticKind::MissingSemicolon {
+                reference: text[..1 + len].to_string(),
+            }),
+        });
+    }
+
+    terminated.then(|| Reference {
+        len: name_len + 2,
+        decoded: None,
+        problem: Some(DiagnosticKind::UnknownNamedReference {
+            reference: text[..name_len + 2].to_string(),
+        }),
+    })
+}
+
+fn lookup(name: &str) -> Option<&'static str> {
+    NAMED_REFERENCES
+        .binary_search_by(|(entry, _)| (*entry).cmp(name))
+        .ok()
+        .map(|index| NAMED_REFERENCES[index].1)
+}
+
+/// §13.2.5.75 - Numeric character reference state
+fn numeric_reference(text: &str) -> Option<Reference> {
+    let (digits_start, radix) = match text.as_bytes().get(2) {
+        Some(b'x' | b'X') => (3, 16),
+        _ => (2, 10),
+    };
+    let digits: &str = &text[digits_start..];
+    let digits = &digits[..digits
+        .bytes()
+        .take_while(|byte| char::from(*byte).is_digit(radix))
+        .count()];
+    if digits.is_empty() {
+        return None;
+    }
+
+    let code_point = digits.chars().fold(0u32, |value, digit| {
+        value
+            .saturating_mul(radix)
+            .saturating_add(digit.to_digit(radix).unwrap_or(0))
+    });
+    let mut len = digits_start + digits.len();
+    let terminated = text[len..].starts_with(';');
+    if terminated {
+        len += 1;
+    }
+
+    let (decoded, valid) = numeric_value(code_point);
+    let problem = if !valid {
+        Some(DiagnosticKind::InvalidCodePoint {
+            reference: text[..len].to_string(),
+            code_point,
+        })
+    } else if !terminated {
+        Some(DiagnosticKind::MissingSemicolon {
+            reference: text[..len].to_string(),
+        })
+    } else {
+        None
+    };
+
+    Some(Reference {
+        len,
+        decoded: Some(Cow::Owned(decoded.to_string())),
+        problem,
+    })
+}
+
+/// §13.2.5.80 - Numeric character reference end state: the character a
+/// browser inserts, and whether the reference was valid.
+fn numeric_value(code_point: u32) -> (char, bool) {
+    match code_point {
+        0 | 0xD800..=0xDFFF | 0x110000.. => (char::REPLACEMENT_CHARACTER, false),
+        0x80..=0x9F => {
+            let mapped = C1_REPLACEMENTS
+                .iter()
+                .find(|(from, _)| *from == code_point)
+                .map_or(code_point, |(_, to)| *to);
+            (
+                char::from_u32(mapped).unwrap_or(char::REPLACEMENT_CHARACTER),
+                false,
+            )
+        }
+        _ => {
+            let is_noncharacter =
+                (0xFDD0..=0xFDEF).contains(&code_point) || code_point & 0xFFFE == 0xFFFE;
+            let is_control = code_point == 0x0D
+                || code_point == 0x7F
+                || (code_point < 0x20 && !matches!(code_point, 0x09 | 0x0A | 0x0C));
+            (
+                char::from_u32(code_point).unwrap_or(char::REPLACEMENT_CHARACTER),
+                !is_noncharacter && !is_control,
+            )
+        }
+    }
+}
+
+/// Windows-1252 characters that C1 control references are replaced with.
+const C1_REPLACEMENTS: [(u32, u32); 27] = [
+    (0x80, 0x20AC),
+    (0x82, 0x201A),
+    (0x83, 0x0192),
+    (0x84, 0x201E),
+    (0x85, 0x2026),
+    (0x86, 0x2020),
+    (0x87, 0x2021),
+    (0x88, 0x02C6),
+    (0x89, 0x2030),
+    (0x8A, 0x0160),
+    (0x8B, 0x2039),
+    (0x8C, 0x0152),
+    (0x8E, 0x017D),
+    (0x91, 0x2018),
+    (0x92, 0x2019),
+    (0x93, 0x201C),
+    (0x94, 0x201D),
+    (0x95, 0x2022),
+    (0x96, 0x2013),
+    (0x97, 0x2014),
+    (0x98, 0x02DC),
+    (0x99, 0x2122),
+    (0x9A, 0x0161),
+    (0x9B, 0x203A),
+    (0x9C, 0x0153),
+    (0x9E, 0x017E),
+    (0x9F, 0x0178),
+];
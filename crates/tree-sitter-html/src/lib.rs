@@ -3,10 +3,22 @@
 //! This grammar provides spec-compliant HTML parsing including:
 //!
 //! - **Void elements** (§13.1.2): area, base, br, col, embed, hr, img, input, link, meta, source, track, wbr
-//! - **Raw text elements** (§13.1.2.1): script, style
+//! - **Raw text elements** (§13.1.2.1): script, style, iframe, noembed, noframes, xmp,
+//!   and noscript when [scripting is enabled](LANGUAGE_SCRIPTING)
 //! - **Escapable raw text elements** (§13.1.2.2): textarea, title
-//! - **Optional end tags** (§13.1.2.4): Proper implicit closing
-//! - **Character references** (§13.5): Named, decimal, and hex entities
+//! - **Plaintext** (§13.2.5.7): everything after `<plaintext>` is text
+//! - **Optional end tags** (§13.1.2.4): Proper implicit closing; nesting that the implicit
+//!   closing hides, and other content-model violations, are reported by [`content_model`]
+//! - **Template contents** (§4.12.3): a `<template>`'s children sit in a `template_content`
+//!   fragment, and end tags inside it never close elements outside it
+//! - **Character references** (§13.5): Named, decimal, and hex entities, decoded and
+//!   checked against the full WHATWG table by [`entities`]
+//! - **DOCTYPE** (§13.2.5.53): name, public and system identifiers, and the quirks mode
+//!   they select, read by [`doctype`]
+//! - **Attributes** (§13.1.2.3): duplicate and malformed names, reported by [`attributes`]
+//! - **Serialization**: lossless printing and a normalizing printer, in [`serialize`]
+//! - **Foreign content** (§13.2.6.5): SVG/MathML follow XML-like rules up to an
+//!   [integration point](is_html_integration_point); CDATA sections are `cdata` nodes
 //!
 //! ## Example
 //!
@@ -31,10 +43,27 @@
 //! assert!(!tree.root_node().has_error());
 //! ```
 
+use std::sync::OnceLock;
+
 use tree_sitter_language::LanguageFn;
 
+pub mod attributes;
+pub mod content_model;
+pub mod doctype;
+pub mod entities;
+pub mod serialize;
+pub mod span;
+
 extern "C" {
     fn tree_sitter_html() -> *const ();
+    fn tree_sitter_html_scripting() -> *const ();
+    fn tree_sitter_html_is_html_integration_point(name: *const u8, length: u32) -> bool;
+    fn tree_sitter_html_tag_can_contain(
+        parent: *const u8,
+        parent_length: u32,
+        child: *const u8,
+        child_length: u32,
+    ) -> bool;
 }
 
 /// The tree-sitter [`LanguageFn`] for HTML.
@@ -45,6 +74,35 @@ pub fn language() -> tree_sitter::Language {
     LANGUAGE.into()
 }
 
+/// The tree-sitter [`LanguageFn`] for HTML parsed the way a browser with
+/// scripting enabled does: `<noscript>` is a raw text element (§13.2.5.3).
+/// [`LANGUAGE`] parses `<noscript>` content as markup.
+///
+/// The flag belongs to each parser's scanner, so parsers for both languages
+/// can be used side by side on any thread.
+pub const LANGUAGE_SCRIPTING: LanguageFn = unsafe { LanguageFn::from_raw(scripting_language) };
+
+/// The language with scripting enabled. The C side fills in a static copy of
+/// the generated language, so it is only called once.
+extern "C" fn scripting_language() -> *const () {
+    static LANGUAGE: OnceLock<usize> = OnceLock::new();
+    *LANGUAGE.get_or_init(|| unsafe { tree_sitter_html_scripting() } as usize) as *const ()
+}
+
+/// Whether the children of the SVG or MathML element `name` follow HTML rules
+/// again (§13.2.6.5), compared ASCII case-insensitively.
+///
+/// These are the HTML integration points (`foreignObject`, `desc`, `title`,
+/// `annotation-xml`) and the MathML text integration points (`mi`, `mo`,
+/// `mn`, `ms`, `mtext`). The answer comes from the scanner's own table in
+/// `tag.h`, so it always matches how the tree was built.
+pub fn is_html_integration_point(name: &str) -> bool {
+    let Ok(length) = u32::try_from(name.len()) else {
+        return false;
+    };
+    unsafe { tree_sitter_html_is_html_integration_point(name.as_ptr(), length) }
+}
+
 /// The syntax highlighting query for HTML.
 pub const HIGHLIGHTS_QUERY: &str = include_str!("../queries/highlights.scm");
 
@@ -109,6 +167,40 @@ mod tests {
         assert!(!tree.root_node().has_error());
     }
 
+    #[test]
+    fn test_parse_svg_foreign_content() {
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&LANGUAGE.into()).unwrap();
+
+        // Inside <svg>, <image> is not void, <title> is not raw text, and
+        // CDATA is character data; <foreignObject> switches back to HTML rules.
+        let source = r#"<svg><image href="a"><title>Tip</title></image><text><![CDATA[a<b]]></text><foreignObject><p>x<br></foreignObject></svg>"#;
+        let tree = parser.parse(source, None).unwrap();
+
+        assert_eq!(
+            tree.root_node().to_sexp(),
+            "(document (element (start_tag name: (tag_name)) (element (start_tag name: (tag_name) (attribute (attribute_name) (quoted_attribute_value (attribute_value)))) (element (start_tag name: (tag_name)) (text) (end_tag name: (tag_name))) (end_tag name: (tag_name))) (element (start_tag name: (tag_name)) (cdata) (end_tag name: (tag_name))) (element (start_tag name: (tag_name)) (element (start_tag name: (tag_name)) (text) (element (start_tag name: (tag_name)))) (end_tag name: (tag_name))) (end_tag name: (tag_name))))"
+        );
+    }
+
+    #[test]
+    fn test_parse_mathml_text_integration_point() {
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&LANGUAGE.into()).unwrap();
+
+        // <mi> content follows HTML rules, so the second <p> closes the first.
+        let source = "<math><mi><p>a<p>b</mi></math>";
+        let tree = parser.parse(source, None).unwrap();
+
+        assert_eq!(
+            tree.root_node().to_sexp(),
+            "(document (element (start_tag name: (tag_name)) (element (start_tag name: (tag_name)) (element (start_tag name: (tag_name)) (text)) (element (start_tag name: (tag_name)) (text)) (end_tag name: (tag_name))) (end_tag name: (tag_name))))"
+        );
+        assert!(is_html_integration_point("foreignObject"));
+        assert!(is_html_integration_point("MTEXT"));
+        assert!(!is_html_integration_point("text"));
+    }
+
     #[test]
     fn test_parse_script_element() {
         let mut parser = tree_sitter::Parser::new();
@@ -229,6 +321,22 @@ mod tests {
         assert!(!tree.root_node().has_error());
     }
 
+    #[test]
+    fn test_parse_bogus_comments() {
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&LANGUAGE.into()).unwrap();
+
+        // Processing instructions, CDATA in HTML content, and other `<!`
+        // markup are bogus comments (§13.2.5.41).
+        let source = r#"<?xml version="1.0"?><!ELEMENT br EMPTY><p><![CDATA[x]]></p>"#;
+        let tree = parser.parse(source, None).unwrap();
+
+        assert_eq!(
+            tree.root_node().to_sexp(),
+            "(document (processing_instruction) (bogus_comment) (element (start_tag name: (tag_name)) (cdata_section) (end_tag name: (tag_name))))"
+        );
+    }
+
     #[test]
     fn test_parse_attributes() {
         let mut parser = tree_sitter::Parser::new();
@@ -254,10 +362,7 @@ mod tests {
             let query = tree_sitter::Query::new(&language, source)
                 .unwrap_or_else(|error| panic!("{name} query should compile: {error}"));
             assert!(
-                query
-                    .capture_names()
-                    .iter()
-                    .any(|capture| *capture == expected_capture),
+                query.capture_names().contains(&expected_capture),
                 "missing @{expected_capture} from HTML {name} query"
             );
         }
@@ -7,64 +7,81 @@
 #endif
 
 #define LANGUAGE_VERSION 15
-#define STATE_COUNT 82
+#define STATE_COUNT 145
 #define LARGE_STATE_COUNT 2
-#define SYMBOL_COUNT 38
+#define SYMBOL_COUNT 51
 #define ALIAS_COUNT 0
-#define TOKEN_COUNT 24
-#define EXTERNAL_TOKEN_COUNT 9
-#define FIELD_COUNT 1
-#define MAX_ALIAS_SEQUENCE_LENGTH 4
+#define TOKEN_COUNT 33
+#define EXTERNAL_TOKEN_COUNT 14
+#define FIELD_COUNT 3
+#define MAX_ALIAS_SEQUENCE_LENGTH 8
 #define MAX_RESERVED_WORD_SET_SIZE 0
-#define PRODUCTION_ID_COUNT 3
+#define PRODUCTION_ID_COUNT 7
 #define SUPERTYPE_COUNT 0
 
 enum ts_symbol_identifiers {
   anon_sym_LT_BANG = 1,
-  aux_sym_doctype_token1 = 2,
-  anon_sym_GT = 3,
-  sym__doctype = 4,
-  anon_sym_LT = 5,
-  anon_sym_SLASH_GT = 6,
-  anon_sym_LT_SLASH = 7,
-  anon_sym_EQ = 8,
-  sym_attribute_name = 9,
-  sym_attribute_value = 10,
-  anon_sym_SQUOTE = 11,
-  aux_sym_quoted_attribute_value_token1 = 12,
-  anon_sym_DQUOTE = 13,
-  aux_sym_quoted_attribute_value_token2 = 14,
-  sym_entity = 15,
-  sym__start_tag_name = 16,
-  sym__raw_text_start_tag_name = 17,
-  sym__end_tag_name = 18,
-  sym_erroneous_end_tag_name = 19,
-  sym__implicit_end_tag = 20,
-  sym_raw_text = 21,
-  sym_comment = 22,
-  sym_text = 23,
-  sym_document = 24,
-  sym_doctype = 25,
-  sym__node = 26,
-  sym_element = 27,
-  sym__raw_text_element = 28,
-  sym_start_tag = 29,
-  sym__raw_text_start_tag = 30,
-  sym_self_closing_tag = 31,
-  sym_end_tag = 32,
-  sym_erroneous_end_tag = 33,
-  sym_attribute = 34,
-  sym_quoted_attribute_value = 35,
-  aux_sym_document_repeat1 = 36,
-  aux_sym_start_tag_repeat1 = 37,
+  anon_sym_GT = 2,
+  sym__doctype = 3,
+  sym_doctype_name = 4,
+  sym__doctype_public = 5,
+  sym__doctype_system = 6,
+  sym_doctype_identifier = 7,
+  sym__bogus_doctype = 8,
+  anon_sym_LT = 9,
+  anon_sym_SLASH_GT = 10,
+  anon_sym_LT_SLASH = 11,
+  anon_sym_EQ = 12,
+  sym_attribute_name = 13,
+  sym_attribute_value = 14,
+  anon_sym_SQUOTE = 15,
+  aux_sym_quoted_attribute_value_token1 = 16,
+  anon_sym_DQUOTE = 17,
+  aux_sym_quoted_attribute_value_token2 = 18,
+  sym_entity = 19,
+  sym__start_tag_name = 20,
+  sym__raw_text_start_tag_name = 21,
+  sym__end_tag_name = 22,
+  sym_erroneous_end_tag_name = 23,
+  sym__implicit_end_tag = 24,
+  sym_raw_text = 25,
+  sym_comment = 26,
+  sym_text = 27,
+  sym_processing_instruction = 28,
+  sym_bogus_comment = 29,
+  sym_cdata_section = 30,
+  sym_cdata = 31,
+  sym__template_start_tag_name = 32,
+  sym_document = 33,
+  sym_doctype = 34,
+  sym__node = 35,
+  sym_element = 36,
+  sym__template_element = 37,
+  sym_template_content = 38,
+  sym__raw_text_element = 39,
+  sym_start_tag = 40,
+  sym__raw_text_start_tag = 41,
+  sym__template_start_tag = 42,
+  sym_self_closing_tag = 43,
+  sym__template_self_closing_tag = 44,
+  sym_end_tag = 45,
+  sym_erroneous_end_tag = 46,
+  sym_attribute = 47,
+  sym_quoted_attribute_value = 48,
+  aux_sym_document_repeat1 = 49,
+  aux_sym_start_tag_repeat1 = 50,
 };
 
 static const char * const ts_symbol_names[] = {
   [ts_builtin_sym_end] = "end",
   [anon_sym_LT_BANG] = "<!",
-  [aux_sym_doctype_token1] = "doctype_token1",
   [anon_sym_GT] = ">",
   [sym__doctype] = "doctype",
+  [sym_doctype_name] = "doctype_name",
+  [sym__doctype_public] = "PUBLIC",
+  [sym__doctype_system] = "SYSTEM",
+  [sym_doctype_identifier] = "doctype_identifier",
+  [sym__bogus_doctype] = "bogus",
   [anon_sym_LT] = "<",
   [anon_sym_SLASH_GT] = "/>",
   [anon_sym_LT_SLASH] = "</",
@@ -84,14 +101,23 @@ static const char * const ts_symbol_names[] = {
   [sym_raw_text] = "raw_text",
   [sym_comment] = "comment",
   [sym_text] = "text",
+  [sym_processing_instruction] = "processing_instruction",
+  [sym_bogus_comment] = "bogus_comment",
+  [sym_cdata_section] = "cdata_section",
+  [sym_cdata] = "cdata",
+  [sym__template_start_tag_name] = "tag_name",
   [sym_document] = "document",
   [sym_doctype] = "doctype",
   [sym__node] = "_node",
   [sym_element] = "element",
+  [sym__template_element] = "_template_element",
+  [sym_template_content] = "template_content",
   [sym__raw_text_element] = "_raw_text_element",
   [sym_start_tag] = "start_tag",
   [sym__raw_text_start_tag] = "start_tag",
+  [sym__template_start_tag] = "start_tag",
   [sym_self_closing_tag] = "self_closing_tag",
+  [sym__template_self_closing_tag] = "self_closing_tag",
   [sym_end_tag] = "end_tag",
   [sym_erroneous_end_tag] = "erroneous_end_tag",
   [sym_attribute] = "attribute",
@@ -103,9 +129,13 @@ static const char * const ts_symbol_names[] = {
 static const TSSymbol ts_symbol_map[] = {
   [ts_builtin_sym_end] = ts_builtin_sym_end,
   [anon_sym_LT_BANG] = anon_sym_LT_BANG,
-  [aux_sym_doctype_token1] = aux_sym_doctype_token1,
   [anon_sym_GT] = anon_sym_GT,
   [sym__doctype] = sym__doctype,
+  [sym_doctype_name] = sym_doctype_name,
+  [sym__doctype_public] = sym__doctype_public,
+  [sym__doctype_system] = sym__doctype_system,
+  [sym_doctype_identifier] = sym_doctype_identifier,
+  [sym__bogus_doctype] = sym__bogus_doctype,
   [anon_sym_LT] = anon_sym_LT,
   [anon_sym_SLASH_GT] = anon_sym_SLASH_GT,
   [anon_sym_LT_SLASH] = anon_sym_LT_SLASH,
@@ -125,14 +155,23 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_raw_text] = sym_raw_text,
   [sym_comment] = sym_comment,
   [sym_text] = sym_text,
+  [sym_processing_instruction] = sym_processing_instruction,
+  [sym_bogus_comment] = sym_bogus_comment,
+  [sym_cdata_section] = sym_cdata_section,
+  [sym_cdata] = sym_cdata,
+  [sym__template_start_tag_name] = sym__start_tag_name,
   [sym_document] = sym_document,
   [sym_doctype] = sym_doctype,
   [sym__node] = sym__node,
   [sym_element] = sym_element,
+  [sym__template_element] = sym__template_element,
+  [sym_template_content] = sym_template_content,
   [sym__raw_text_element] = sym__raw_text_element,
   [sym_start_tag] = sym_start_tag,
   [sym__raw_text_start_tag] = sym_start_tag,
+  [sym__template_start_tag] = sym_start_tag,
   [sym_self_closing_tag] = sym_self_closing_tag,
+  [sym__template_self_closing_tag] = sym_self_closing_tag,
   [sym_end_tag] = sym_end_tag,
   [sym_erroneous_end_tag] = sym_erroneous_end_tag,
   [sym_attribute] = sym_attribute,
@@ -150,10 +189,6 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
-  [aux_sym_doctype_token1] = {
-    .visible = false,
-    .named = false,
-  },
   [anon_sym_GT] = {
     .visible = true,
     .named = false,
@@ -162,6 +197,26 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [sym_doctype_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__doctype_public] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym__doctype_system] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_doctype_identifier] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__bogus_doctype] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_LT] = {
     .visible = true,
     .named = false,
@@ -238,6 +293,26 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_processing_instruction] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_bogus_comment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_cdata_section] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_cdata] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__template_start_tag_name] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_document] = {
     .visible = true,
     .named = true,
@@ -254,6 +329,14 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym__template_element] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_template_content] = {
+    .visible = true,
+    .named = true,
+  },
   [sym__raw_text_element] = {
     .visible = false,
     .named = true,
@@ -266,10 +349,18 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym__template_start_tag] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_self_closing_tag] = {
     .visible = true,
     .named = true,
   },
+  [sym__template_self_closing_tag] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_end_tag] = {
     .visible = true,
     .named = true,
@@ -298,16 +389,24 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
 
 enum ts_field_identifiers {
   field_name = 1,
+  field_public_id = 2,
+  field_system_id = 3,
 };
 
 static const char * const ts_field_names[] = {
   [0] = NULL,
   [field_name] = "name",
+  [field_public_id] = "public_id",
+  [field_system_id] = "system_id",
 };
 
 static const TSMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
   [1] = {.index = 0, .length = 1},
   [2] = {.index = 1, .length = 1},
+  [3] = {.index = 2, .length = 1},
+  [4] = {.index = 3, .length = 2},
+  [5] = {.index = 5, .length = 2},
+  [6] = {.index = 7, .length = 3},
 };
 
 static const TSFieldMapEntry ts_field_map_entries[] = {
@@ -315,6 +414,18 @@ static const TSFieldMapEntry ts_field_map_entries[] = {
     {field_name, 0, .inherited = true},
   [1] =
     {field_name, 1},
+  [2] =
+    {field_name, 2},
+  [3] =
+    {field_name, 2},
+    {field_public_id, 4},
+  [5] =
+    {field_name, 2},
+    {field_system_id, 4},
+  [7] =
+    {field_name, 2},
+    {field_public_id, 4},
+    {field_system_id, 5},
 };
 
 static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
@@ -329,15 +440,15 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [0] = 0,
   [1] = 1,
   [2] = 2,
-  [3] = 3,
-  [4] = 2,
-  [5] = 3,
-  [6] = 6,
-  [7] = 7,
-  [8] = 7,
+  [3] = 2,
+  [4] = 4,
+  [5] = 5,
+  [6] = 4,
+  [7] = 5,
+  [8] = 8,
   [9] = 9,
   [10] = 10,
-  [11] = 11,
+  [11] = 10,
   [12] = 12,
   [13] = 13,
   [14] = 14,
@@ -345,69 +456,132 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [16] = 16,
   [17] = 17,
   [18] = 18,
-  [19] = 9,
+  [19] = 19,
   [20] = 20,
   [21] = 21,
-  [22] = 11,
-  [23] = 12,
-  [24] = 13,
-  [25] = 14,
-  [26] = 15,
-  [27] = 17,
-  [28] = 18,
-  [29] = 21,
-  [30] = 20,
+  [22] = 22,
+  [23] = 23,
+  [24] = 24,
+  [25] = 25,
+  [26] = 26,
+  [27] = 27,
+  [28] = 28,
+  [29] = 29,
+  [30] = 30,
   [31] = 31,
   [32] = 32,
-  [33] = 31,
+  [33] = 33,
   [34] = 34,
-  [35] = 34,
+  [35] = 35,
   [36] = 36,
   [37] = 37,
   [38] = 38,
-  [39] = 32,
+  [39] = 39,
   [40] = 40,
-  [41] = 40,
-  [42] = 42,
-  [43] = 38,
-  [44] = 44,
-  [45] = 45,
-  [46] = 46,
-  [47] = 46,
-  [48] = 48,
-  [49] = 49,
-  [50] = 50,
-  [51] = 51,
-  [52] = 52,
-  [53] = 53,
-  [54] = 54,
-  [55] = 42,
-  [56] = 44,
-  [57] = 45,
-  [58] = 54,
-  [59] = 48,
-  [60] = 53,
-  [61] = 49,
-  [62] = 51,
-  [63] = 63,
-  [64] = 64,
-  [65] = 65,
-  [66] = 66,
-  [67] = 67,
-  [68] = 65,
+  [41] = 41,
+  [42] = 12,
+  [43] = 13,
+  [44] = 14,
+  [45] = 15,
+  [46] = 16,
+  [47] = 17,
+  [48] = 18,
+  [49] = 20,
+  [50] = 22,
+  [51] = 23,
+  [52] = 24,
+  [53] = 25,
+  [54] = 26,
+  [55] = 27,
+  [56] = 28,
+  [57] = 30,
+  [58] = 32,
+  [59] = 33,
+  [60] = 34,
+  [61] = 35,
+  [62] = 36,
+  [63] = 37,
+  [64] = 38,
+  [65] = 39,
+  [66] = 40,
+  [67] = 41,
+  [68] = 68,
   [69] = 69,
-  [70] = 64,
+  [70] = 70,
   [71] = 71,
   [72] = 72,
-  [73] = 63,
-  [74] = 74,
-  [75] = 75,
-  [76] = 74,
-  [77] = 72,
-  [78] = 69,
-  [79] = 75,
-  [80] = 66,
-  [81] = 71,
+  [73] = 68,
+  [74] = 69,
+  [75] = 70,
+  [76] = 71,
+  [77] = 77,
+  [78] = 78,
+  [79] = 79,
+  [80] = 80,
+  [81] = 81,
+  [82] = 72,
+  [83] = 78,
+  [84] = 81,
+  [85] = 85,
+  [86] = 86,
+  [87] = 87,
+  [88] = 88,
+  [89] = 89,
+  [90] = 90,
+  [91] = 91,
+  [92] = 92,
+  [93] = 93,
+  [94] = 79,
+  [95] = 86,
+  [96] = 87,
+  [97] = 88,
+  [98] = 89,
+  [99] = 90,
+  [100] = 85,
+  [101] = 101,
+  [102] = 102,
+  [103] = 103,
+  [104] = 104,
+  [105] = 105,
+  [106] = 106,
+  [107] = 107,
+  [108] = 108,
+  [109] = 109,
+  [110] = 91,
+  [111] = 92,
+  [112] = 93,
+  [113] = 101,
+  [114] = 103,
+  [115] = 106,
+  [116] = 107,
+  [117] = 108,
+  [118] = 109,
+  [119] = 102,
+  [120] = 120,
+  [121] = 121,
+  [122] = 122,
+  [123] = 123,
+  [124] = 124,
+  [125] = 125,
+  [126] = 126,
+  [127] = 127,
+  [128] = 128,
+  [129] = 129,
+  [130] = 130,
+  [131] = 131,
+  [132] = 132,
+  [133] = 123,
+  [134] = 125,
+  [135] = 126,
+  [136] = 127,
+  [137] = 128,
+  [138] = 129,
+  [139] = 130,
+  [140] = 131,
+  [141] = 132,
+  [142] = 120,
+  [143] = 121,
+  [144] = 124,
 };
 
 static const TSCharacterRange extras_character_set_1[] = {
@@ -430,246 +604,451 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
   eof = lexer->eof(lexer);
   switch (state) {
     case 0:
-      if (eof) ADVANCE(16);
+      if (eof) ADVANCE(29);
       ADVANCE_MAP(
-        '"', 31,
-        '&', 3,
-        '\'', 28,
-        '/', 6,
-        '<', 22,
-        '=', 25,
-        '>', 20,
-        'D', 9,
-        'd', 9,
+        '"', 38,
+        '&', 5,
+        '\'', 39,
+        '/', 10,
+        '<', 54,
+        '=', 57,
+        '>', 31,
+        'D', 19,
+        'd', 19,
+        'P', 24,
+        'p', 24,
+        'S', 26,
+        's', 26,
       );
       if (set_contains(extras_character_set_1, 11, lookahead)) SKIP(0);
       END_STATE();
     case 1:
-      if (lookahead == '"') ADVANCE(31);
-      if (lookahead == '\'') ADVANCE(28);
-      if (set_contains(extras_character_set_1, 11, lookahead)) SKIP(1);
-      if (lookahead != 0 &&
-          (lookahead < '<' || '>' < lookahead) &&
-          lookahead != '`') ADVANCE(27);
+      if (lookahead == '"') ADVANCE(38);
+      if (lookahead == '\'') ADVANCE(39);
+      if (lookahead == '>') ADVANCE(31);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(1);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(40);
+      if (lookahead != 0) ADVANCE(53);
       END_STATE();
     case 2:
-      if (lookahead == '"') ADVANCE(31);
-      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(32);
-      if (lookahead != 0) ADVANCE(33);
+      if (lookahead == '"') ADVANCE(63);
+      if (lookahead == '\'') ADVANCE(60);
+      if (lookahead == '/') ADVANCE(10);
+      if (lookahead == '=') ADVANCE(57);
+      if (lookahead == '>') ADVANCE(31);
+      if (set_contains(extras_character_set_1, 11, lookahead)) SKIP(2);
+      if (lookahead != 0 &&
+          (lookahead < '<' || '>' < lookahead)) ADVANCE(58);
       END_STATE();
     case 3:
-      if (lookahead == '#') ADVANCE(12);
-      if (('A' <= lookahead && lookahead <= 'Z') ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(47);
+      if (lookahead == '"') ADVANCE(63);
+      if (lookahead == '\'') ADVANCE(60);
+      if (set_contains(extras_character_set_1, 11, lookahead)) SKIP(3);
+      if (lookahead != 0 &&
+          (lookahead < '<' || '>' < lookahead) &&
+          lookahead != '`') ADVANCE(59);
       END_STATE();
     case 4:
-      if (lookahead == '\'') ADVANCE(28);
-      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(29);
-      if (lookahead != 0) ADVANCE(30);
+      if (lookahead == '"') ADVANCE(63);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(64);
+      if (lookahead != 0) ADVANCE(65);
       END_STATE();
     case 5:
-      if (lookahead == '/') ADVANCE(6);
-      if (lookahead == '=') ADVANCE(25);
-      if (lookahead == '>') ADVANCE(20);
-      if (set_contains(extras_character_set_1, 11, lookahead)) SKIP(5);
-      if (lookahead != 0 &&
-          lookahead != '"' &&
-          lookahead != '\'' &&
-          (lookahead < '<' || '>' < lookahead)) ADVANCE(26);
+      if (lookahead == '#') ADVANCE(25);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(79);
       END_STATE();
     case 6:
-      if (lookahead == '>') ADVANCE(23);
+      if (lookahead == '\'') ADVANCE(60);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(61);
+      if (lookahead != 0) ADVANCE(62);
       END_STATE();
     case 7:
-      if (lookahead == 'C' ||
-          lookahead == 'c') ADVANCE(11);
+      if (lookahead == '>') ADVANCE(31);
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(50);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(51);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(7);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(47);
+      if (lookahead != 0) ADVANCE(53);
       END_STATE();
     case 8:
-      if (lookahead == 'E' ||
-          lookahead == 'e') ADVANCE(21);
+      if (lookahead == '>') ADVANCE(31);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(8);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(33);
+      if (lookahead != 0) ADVANCE(34);
       END_STATE();
     case 9:
-      if (lookahead == 'O' ||
-          lookahead == 'o') ADVANCE(7);
+      if (lookahead == '>') ADVANCE(31);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(9);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(52);
+      if (lookahead != 0) ADVANCE(53);
       END_STATE();
     case 10:
-      if (lookahead == 'P' ||
-          lookahead == 'p') ADVANCE(8);
+      if (lookahead == '>') ADVANCE(55);
       END_STATE();
     case 11:
-      if (lookahead == 'T' ||
-          lookahead == 't') ADVANCE(13);
+      if (lookahead == 'B' ||
+          lookahead == 'b') ADVANCE(17);
       END_STATE();
     case 12:
-      if (lookahead == 'X' ||
-          lookahead == 'x') ADVANCE(14);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(41);
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(23);
       END_STATE();
     case 13:
-      if (lookahead == 'Y' ||
-          lookahead == 'y') ADVANCE(10);
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(35);
       END_STATE();
     case 14:
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'F') ||
-          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(46);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(18);
       END_STATE();
     case 15:
-      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(18);
-      if (lookahead != 0 &&
-          lookahead != '>') ADVANCE(19);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(32);
       END_STATE();
     case 16:
-      ACCEPT_TOKEN(ts_builtin_sym_end);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(13);
       END_STATE();
     case 17:
-      ACCEPT_TOKEN(anon_sym_LT_BANG);
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(16);
       END_STATE();
     case 18:
-      ACCEPT_TOKEN(aux_sym_doctype_token1);
-      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(18);
-      if (lookahead != 0 &&
-          lookahead != '>') ADVANCE(19);
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(36);
       END_STATE();
     case 19:
-      ACCEPT_TOKEN(aux_sym_doctype_token1);
-      if (lookahead != 0 &&
-          lookahead != '>') ADVANCE(19);
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(12);
       END_STATE();
     case 20:
-      ACCEPT_TOKEN(anon_sym_GT);
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(15);
       END_STATE();
     case 21:
-      ACCEPT_TOKEN(sym__doctype);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(22);
       END_STATE();
     case 22:
-      ACCEPT_TOKEN(anon_sym_LT);
-      if (lookahead == '!') ADVANCE(17);
-      if (lookahead == '/') ADVANCE(24);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(14);
       END_STATE();
     case 23:
-      ACCEPT_TOKEN(anon_sym_SLASH_GT);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(27);
       END_STATE();
     case 24:
-      ACCEPT_TOKEN(anon_sym_LT_SLASH);
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(11);
       END_STATE();
     case 25:
-      ACCEPT_TOKEN(anon_sym_EQ);
+      if (lookahead == 'X' ||
+          lookahead == 'x') ADVANCE(28);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(73);
       END_STATE();
     case 26:
-      ACCEPT_TOKEN(sym_attribute_name);
-      if ((!eof && set_contains(sym_attribute_name_character_set_1, 16, lookahead))) ADVANCE(26);
+      if (lookahead == 'Y' ||
+          lookahead == 'y') ADVANCE(21);
       END_STATE();
     case 27:
-      ACCEPT_TOKEN(sym_attribute_value);
-      if ((!eof && set_contains(sym_attribute_value_character_set_1, 16, lookahead))) ADVANCE(27);
+      if (lookahead == 'Y' ||
+          lookahead == 'y') ADVANCE(20);
       END_STATE();
     case 28:
-      ACCEPT_TOKEN(anon_sym_SQUOTE);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(78);
       END_STATE();
     case 29:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 30:
+      ACCEPT_TOKEN(anon_sym_LT_BANG);
+      END_STATE();
+    case 31:
+      ACCEPT_TOKEN(anon_sym_GT);
+      END_STATE();
+    case 32:
+      ACCEPT_TOKEN(sym__doctype);
+      END_STATE();
+    case 33:
+      ACCEPT_TOKEN(sym_doctype_name);
+      if ((set_contains(extras_character_set_1, 11, lookahead)) &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ') ADVANCE(33);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '>') ADVANCE(34);
+      END_STATE();
+    case 34:
+      ACCEPT_TOKEN(sym_doctype_name);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '>') ADVANCE(34);
+      END_STATE();
+    case 35:
+      ACCEPT_TOKEN(sym__doctype_public);
+      END_STATE();
+    case 36:
+      ACCEPT_TOKEN(sym__doctype_system);
+      END_STATE();
+    case 37:
+      ACCEPT_TOKEN(sym_doctype_identifier);
+      END_STATE();
+    case 38:
+      ACCEPT_TOKEN(sym_doctype_identifier);
+      if (lookahead == '"') ADVANCE(37);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(38);
+      END_STATE();
+    case 39:
+      ACCEPT_TOKEN(sym_doctype_identifier);
+      if (lookahead == '\'') ADVANCE(37);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(39);
+      END_STATE();
+    case 40:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == '"') ADVANCE(38);
+      if (lookahead == '\'') ADVANCE(39);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(40);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(40);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(53);
+      END_STATE();
+    case 41:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'B' ||
+          lookahead == 'b') ADVANCE(45);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(53);
+      END_STATE();
+    case 42:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(35);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(53);
+      END_STATE();
+    case 43:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(46);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(53);
+      END_STATE();
+    case 44:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(42);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(53);
+      END_STATE();
+    case 45:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(44);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(53);
+      END_STATE();
+    case 46:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(36);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(53);
+      END_STATE();
+    case 47:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(50);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(51);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(47);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(47);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(53);
+      END_STATE();
+    case 48:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(49);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(53);
+      END_STATE();
+    case 49:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(43);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(53);
+      END_STATE();
+    case 50:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(41);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(53);
+      END_STATE();
+    case 51:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'Y' ||
+          lookahead == 'y') ADVANCE(48);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(53);
+      END_STATE();
+    case 52:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(52);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(52);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(53);
+      END_STATE();
+    case 53:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(53);
+      END_STATE();
+    case 54:
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '!') ADVANCE(30);
+      if (lookahead == '/') ADVANCE(56);
+      END_STATE();
+    case 55:
+      ACCEPT_TOKEN(anon_sym_SLASH_GT);
+      END_STATE();
+    case 56:
+      ACCEPT_TOKEN(anon_sym_LT_SLASH);
+      END_STATE();
+    case 57:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      END_STATE();
+    case 58:
+      ACCEPT_TOKEN(sym_attribute_name);
+      if ((!eof && set_contains(sym_attribute_name_character_set_1, 16, lookahead))) ADVANCE(58);
+      END_STATE();
+    case 59:
+      ACCEPT_TOKEN(sym_attribute_value);
+      if ((!eof && set_contains(sym_attribute_value_character_set_1, 16, lookahead))) ADVANCE(59);
+      END_STATE();
+    case 60:
+      ACCEPT_TOKEN(anon_sym_SQUOTE);
+      END_STATE();
+    case 61:
       ACCEPT_TOKEN(aux_sym_quoted_attribute_value_token1);
-      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(29);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(61);
       if (lookahead != 0 &&
-          lookahead != '\'') ADVANCE(30);
+          lookahead != '\'') ADVANCE(62);
       END_STATE();
-    case 30:
+    case 62:
       ACCEPT_TOKEN(aux_sym_quoted_attribute_value_token1);
       if (lookahead != 0 &&
-          lookahead != '\'') ADVANCE(30);
+          lookahead != '\'') ADVANCE(62);
       END_STATE();
-    case 31:
+    case 63:
       ACCEPT_TOKEN(anon_sym_DQUOTE);
       END_STATE();
-    case 32:
+    case 64:
       ACCEPT_TOKEN(aux_sym_quoted_attribute_value_token2);
-      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(32);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(64);
       if (lookahead != 0 &&
-          lookahead != '"') ADVANCE(33);
+          lookahead != '"') ADVANCE(65);
       END_STATE();
-    case 33:
+    case 65:
       ACCEPT_TOKEN(aux_sym_quoted_attribute_value_token2);
       if (lookahead != 0 &&
-          lookahead != '"') ADVANCE(33);
+          lookahead != '"') ADVANCE(65);
       END_STATE();
-    case 34:
+    case 66:
       ACCEPT_TOKEN(sym_entity);
       END_STATE();
-    case 35:
+    case 67:
       ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(34);
+      if (lookahead == ';') ADVANCE(66);
       END_STATE();
-    case 36:
+    case 68:
       ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(34);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(35);
+      if (lookahead == ';') ADVANCE(66);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(67);
       END_STATE();
-    case 37:
+    case 69:
       ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(34);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(36);
+      if (lookahead == ';') ADVANCE(66);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(68);
       END_STATE();
-    case 38:
+    case 70:
       ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(34);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(37);
+      if (lookahead == ';') ADVANCE(66);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(69);
       END_STATE();
-    case 39:
+    case 71:
       ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(34);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(38);
+      if (lookahead == ';') ADVANCE(66);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(70);
       END_STATE();
-    case 40:
+    case 72:
       ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(34);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(39);
+      if (lookahead == ';') ADVANCE(66);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(71);
       END_STATE();
-    case 41:
+    case 73:
       ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(34);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(40);
+      if (lookahead == ';') ADVANCE(66);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(72);
       END_STATE();
-    case 42:
+    case 74:
       ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(34);
+      if (lookahead == ';') ADVANCE(66);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'F') ||
-          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(35);
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(67);
       END_STATE();
-    case 43:
+    case 75:
       ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(34);
+      if (lookahead == ';') ADVANCE(66);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'F') ||
-          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(42);
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(74);
       END_STATE();
-    case 44:
+    case 76:
       ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(34);
+      if (lookahead == ';') ADVANCE(66);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'F') ||
-          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(43);
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(75);
       END_STATE();
-    case 45:
+    case 77:
       ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(34);
+      if (lookahead == ';') ADVANCE(66);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'F') ||
-          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(44);
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(76);
       END_STATE();
-    case 46:
+    case 78:
       ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(34);
+      if (lookahead == ';') ADVANCE(66);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'F') ||
-          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(45);
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(77);
       END_STATE();
-    case 47:
+    case 79:
       ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(34);
+      if (lookahead == ';') ADVANCE(66);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(47);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(79);
       END_STATE();
     default:
       return false;
@@ -683,82 +1062,145 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [3] = {.lex_state = 0, .external_lex_state = 3},
   [4] = {.lex_state = 0, .external_lex_state = 3},
   [5] = {.lex_state = 0, .external_lex_state = 3},
-  [6] = {.lex_state = 0, .external_lex_state = 2},
-  [7] = {.lex_state = 0, .external_lex_state = 2},
-  [8] = {.lex_state = 0, .external_lex_state = 3},
-  [9] = {.lex_state = 0, .external_lex_state = 2},
-  [10] = {.lex_state = 0, .external_lex_state = 3},
-  [11] = {.lex_state = 0, .external_lex_state = 2},
+  [6] = {.lex_state = 0, .external_lex_state = 3},
+  [7] = {.lex_state = 0, .external_lex_state = 3},
+  [8] = {.lex_state = 0, .external_lex_state = 2},
+  [9] = {.lex_state = 0, .external_lex_state = 3},
+  [10] = {.lex_state = 0, .external_lex_state = 2},
+  [11] = {.lex_state = 0, .external_lex_state = 3},
   [12] = {.lex_state = 0, .external_lex_state = 2},
   [13] = {.lex_state = 0, .external_lex_state = 2},
   [14] = {.lex_state = 0, .external_lex_state = 2},
   [15] = {.lex_state = 0, .external_lex_state = 2},
-  [16] = {.lex_state = 0, .external_lex_state = 3},
+  [16] = {.lex_state = 0, .external_lex_state = 2},
   [17] = {.lex_state = 0, .external_lex_state = 2},
   [18] = {.lex_state = 0, .external_lex_state = 2},
   [19] = {.lex_state = 0, .external_lex_state = 3},
-  [20] = {.lex_state = 0, .external_lex_state = 3},
+  [20] = {.lex_state = 0, .external_lex_state = 2},
   [21] = {.lex_state = 0, .external_lex_state = 3},
-  [22] = {.lex_state = 0, .external_lex_state = 3},
-  [23] = {.lex_state = 0, .external_lex_state = 3},
-  [24] = {.lex_state = 0, .external_lex_state = 3},
-  [25] = {.lex_state = 0, .external_lex_state = 3},
-  [26] = {.lex_state = 0, .external_lex_state = 3},
-  [27] = {.lex_state = 0, .external_lex_state = 3},
-  [28] = {.lex_state = 0, .external_lex_state = 3},
-  [29] = {.lex_state = 0, .external_lex_state = 2},
+  [22] = {.lex_state = 0, .external_lex_state = 2},
+  [23] = {.lex_state = 0, .external_lex_state = 2},
+  [24] = {.lex_state = 0, .external_lex_state = 2},
+  [25] = {.lex_state = 0, .external_lex_state = 2},
+  [26] = {.lex_state = 0, .external_lex_state = 2},
+  [27] = {.lex_state = 0, .external_lex_state = 2},
+  [28] = {.lex_state = 0, .external_lex_state = 2},
+  [29] = {.lex_state = 0, .external_lex_state = 3},
   [30] = {.lex_state = 0, .external_lex_state = 2},
-  [31] = {.lex_state = 5, .external_lex_state = 4},
-  [32] = {.lex_state = 5, .external_lex_state = 4},
-  [33] = {.lex_state = 5, .external_lex_state = 4},
-  [34] = {.lex_state = 5, .external_lex_state = 4},
-  [35] = {.lex_state = 5, .external_lex_state = 4},
-  [36] = {.lex_state = 5, .external_lex_state = 5},
-  [37] = {.lex_state = 5, .external_lex_state = 5},
-  [38] = {.lex_state = 5, .external_lex_state = 4},
-  [39] = {.lex_state = 5, .external_lex_state = 5},
-  [40] = {.lex_state = 1, .external_lex_state = 5},
-  [41] = {.lex_state = 1, .external_lex_state = 5},
-  [42] = {.lex_state = 5, .external_lex_state = 4},
-  [43] = {.lex_state = 5, .external_lex_state = 5},
-  [44] = {.lex_state = 5, .external_lex_state = 4},
-  [45] = {.lex_state = 5, .external_lex_state = 4},
-  [46] = {.lex_state = 0, .external_lex_state = 6},
-  [47] = {.lex_state = 0, .external_lex_state = 6},
-  [48] = {.lex_state = 4, .external_lex_state = 5},
-  [49] = {.lex_state = 0, .external_lex_state = 7},
-  [50] = {.lex_state = 0, .external_lex_state = 6},
-  [51] = {.lex_state = 0, .external_lex_state = 8},
-  [52] = {.lex_state = 0, .external_lex_state = 6},
-  [53] = {.lex_state = 2, .external_lex_state = 5},
-  [54] = {.lex_state = 0, .external_lex_state = 5},
-  [55] = {.lex_state = 5, .external_lex_state = 5},
-  [56] = {.lex_state = 5, .external_lex_state = 5},
-  [57] = {.lex_state = 5, .external_lex_state = 5},
-  [58] = {.lex_state = 0, .external_lex_state = 5},
-  [59] = {.lex_state = 4, .external_lex_state = 5},
-  [60] = {.lex_state = 2, .external_lex_state = 5},
-  [61] = {.lex_state = 0, .external_lex_state = 7},
-  [62] = {.lex_state = 0, .external_lex_state = 8},
-  [63] = {.lex_state = 0, .external_lex_state = 5},
-  [64] = {.lex_state = 0, .external_lex_state = 5},
-  [65] = {.lex_state = 0, .external_lex_state = 5},
-  [66] = {.lex_state = 0, .external_lex_state = 9},
-  [67] = {.lex_state = 0, .external_lex_state = 5},
-  [68] = {.lex_state = 0, .external_lex_state = 5},
-  [69] = {.lex_state = 15, .external_lex_state = 5},
-  [70] = {.lex_state = 0, .external_lex_state = 5},
-  [71] = {.lex_state = 0, .external_lex_state = 5},
-  [72] = {.lex_state = 0, .external_lex_state = 10},
-  [73] = {.lex_state = 0, .external_lex_state = 5},
-  [74] = {.lex_state = 0, .external_lex_state = 5},
-  [75] = {.lex_state = 0, .external_lex_state = 5},
-  [76] = {.lex_state = 0, .external_lex_state = 5},
-  [77] = {.lex_state = 0, .external_lex_state = 10},
-  [78] = {.lex_state = 15, .external_lex_state = 5},
-  [79] = {.lex_state = 0, .external_lex_state = 5},
-  [80] = {.lex_state = 0, .external_lex_state = 9},
-  [81] = {.lex_state = 0, .external_lex_state = 5},
+  [31] = {.lex_state = 0, .external_lex_state = 3},
+  [32] = {.lex_state = 0, .external_lex_state = 2},
+  [33] = {.lex_state = 0, .external_lex_state = 2},
+  [34] = {.lex_state = 0, .external_lex_state = 2},
+  [35] = {.lex_state = 0, .external_lex_state = 2},
+  [36] = {.lex_state = 0, .external_lex_state = 2},
+  [37] = {.lex_state = 0, .external_lex_state = 2},
+  [38] = {.lex_state = 0, .external_lex_state = 2},
+  [39] = {.lex_state = 0, .external_lex_state = 2},
+  [40] = {.lex_state = 0, .external_lex_state = 2},
+  [41] = {.lex_state = 0, .external_lex_state = 2},
+  [42] = {.lex_state = 0, .external_lex_state = 3},
+  [43] = {.lex_state = 0, .external_lex_state = 3},
+  [44] = {.lex_state = 0, .external_lex_state = 3},
+  [45] = {.lex_state = 0, .external_lex_state = 3},
+  [46] = {.lex_state = 0, .external_lex_state = 3},
+  [47] = {.lex_state = 0, .external_lex_state = 3},
+  [48] = {.lex_state = 0, .external_lex_state = 3},
+  [49] = {.lex_state = 0, .external_lex_state = 3},
+  [50] = {.lex_state = 0, .external_lex_state = 3},
+  [51] = {.lex_state = 0, .external_lex_state = 3},
+  [52] = {.lex_state = 0, .external_lex_state = 3},
+  [53] = {.lex_state = 0, .external_lex_state = 3},
+  [54] = {.lex_state = 0, .external_lex_state = 3},
+  [55] = {.lex_state = 0, .external_lex_state = 3},
+  [56] = {.lex_state = 0, .external_lex_state = 3},
+  [57] = {.lex_state = 0, .external_lex_state = 3},
+  [58] = {.lex_state = 0, .external_lex_state = 3},
+  [59] = {.lex_state = 0, .external_lex_state = 3},
+  [60] = {.lex_state = 0, .external_lex_state = 3},
+  [61] = {.lex_state = 0, .external_lex_state = 3},
+  [62] = {.lex_state = 0, .external_lex_state = 3},
+  [63] = {.lex_state = 0, .external_lex_state = 3},
+  [64] = {.lex_state = 0, .external_lex_state = 3},
+  [65] = {.lex_state = 0, .external_lex_state = 3},
+  [66] = {.lex_state = 0, .external_lex_state = 3},
+  [67] = {.lex_state = 0, .external_lex_state = 3},
+  [68] = {.lex_state = 2, .external_lex_state = 4},
+  [69] = {.lex_state = 2, .external_lex_state = 4},
+  [70] = {.lex_state = 2, .external_lex_state = 4},
+  [71] = {.lex_state = 2, .external_lex_state = 4},
+  [72] = {.lex_state = 2, .external_lex_state = 4},
+  [73] = {.lex_state = 2, .external_lex_state = 4},
+  [74] = {.lex_state = 2, .external_lex_state = 4},
+  [75] = {.lex_state = 2, .external_lex_state = 4},
+  [76] = {.lex_state = 2, .external_lex_state = 4},
+  [77] = {.lex_state = 2, .external_lex_state = 5},
+  [78] = {.lex_state = 7, .external_lex_state = 5},
+  [79] = {.lex_state = 2, .external_lex_state = 4},
+  [80] = {.lex_state = 2, .external_lex_state = 5},
+  [81] = {.lex_state = 3, .external_lex_state = 5},
+  [82] = {.lex_state = 2, .external_lex_state = 5},
+  [83] = {.lex_state = 7, .external_lex_state = 5},
+  [84] = {.lex_state = 3, .external_lex_state = 5},
+  [85] = {.lex_state = 0, .external_lex_state = 6},
+  [86] = {.lex_state = 0, .external_lex_state = 7},
+  [87] = {.lex_state = 0, .external_lex_state = 8},
+  [88] = {.lex_state = 1, .external_lex_state = 5},
+  [89] = {.lex_state = 1, .external_lex_state = 5},
+  [90] = {.lex_state = 1, .external_lex_state = 5},
+  [91] = {.lex_state = 2, .external_lex_state = 4},
+  [92] = {.lex_state = 2, .external_lex_state = 4},
+  [93] = {.lex_state = 2, .external_lex_state = 4},
+  [94] = {.lex_state = 2, .external_lex_state = 5},
+  [95] = {.lex_state = 0, .external_lex_state = 7},
+  [96] = {.lex_state = 0, .external_lex_state = 8},
+  [97] = {.lex_state = 1, .external_lex_state = 5},
+  [98] = {.lex_state = 1, .external_lex_state = 5},
+  [99] = {.lex_state = 1, .external_lex_state = 5},
+  [100] = {.lex_state = 0, .external_lex_state = 6},
+  [101] = {.lex_state = 8, .external_lex_state = 5},
+  [102] = {.lex_state = 0, .external_lex_state = 9},
+  [103] = {.lex_state = 0, .external_lex_state = 5},
+  [104] = {.lex_state = 0, .external_lex_state = 7},
+  [105] = {.lex_state = 0, .external_lex_state = 7},
+  [106] = {.lex_state = 9, .external_lex_state = 5},
+  [107] = {.lex_state = 6, .external_lex_state = 5},
+  [108] = {.lex_state = 4, .external_lex_state = 5},
+  [109] = {.lex_state = 9, .external_lex_state = 5},
+  [110] = {.lex_state = 2, .external_lex_state = 5},
+  [111] = {.lex_state = 2, .external_lex_state = 5},
+  [112] = {.lex_state = 2, .external_lex_state = 5},
+  [113] = {.lex_state = 8, .external_lex_state = 5},
+  [114] = {.lex_state = 0, .external_lex_state = 5},
+  [115] = {.lex_state = 9, .external_lex_state = 5},
+  [116] = {.lex_state = 6, .external_lex_state = 5},
+  [117] = {.lex_state = 4, .external_lex_state = 5},
+  [118] = {.lex_state = 9, .external_lex_state = 5},
+  [119] = {.lex_state = 0, .external_lex_state = 9},
+  [120] = {.lex_state = 0, .external_lex_state = 5},
+  [121] = {.lex_state = 0, .external_lex_state = 10},
+  [122] = {.lex_state = 0, .external_lex_state = 5},
+  [123] = {.lex_state = 0, .external_lex_state = 5},
+  [124] = {.lex_state = 0, .external_lex_state = 11},
+  [125] = {.lex_state = 0, .external_lex_state = 5},
+  [126] = {.lex_state = 0, .external_lex_state = 5},
+  [127] = {.lex_state = 0, .external_lex_state = 5},
+  [128] = {.lex_state = 0, .external_lex_state = 5},
+  [129] = {.lex_state = 0, .external_lex_state = 5},
+  [130] = {.lex_state = 2, .external_lex_state = 5},
+  [131] = {.lex_state = 2, .external_lex_state = 5},
+  [132] = {.lex_state = 0, .external_lex_state = 5},
+  [133] = {.lex_state = 0, .external_lex_state = 5},
+  [134] = {.lex_state = 0, .external_lex_state = 5},
+  [135] = {.lex_state = 0, .external_lex_state = 5},
+  [136] = {.lex_state = 0, .external_lex_state = 5},
+  [137] = {.lex_state = 0, .external_lex_state = 5},
+  [138] = {.lex_state = 0, .external_lex_state = 5},
+  [139] = {.lex_state = 2, .external_lex_state = 5},
+  [140] = {.lex_state = 2, .external_lex_state = 5},
+  [141] = {.lex_state = 0, .external_lex_state = 5},
+  [142] = {.lex_state = 0, .external_lex_state = 5},
+  [143] = {.lex_state = 0, .external_lex_state = 10},
+  [144] = {.lex_state = 0, .external_lex_state = 11},
 };
 
 static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
@@ -767,6 +1209,9 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_LT_BANG] = ACTIONS(1),
     [anon_sym_GT] = ACTIONS(1),
     [sym__doctype] = ACTIONS(1),
+    [sym__doctype_public] = ACTIONS(1),
+    [sym__doctype_system] = ACTIONS(1),
+    [sym_doctype_identifier] = ACTIONS(1),
     [anon_sym_LT] = ACTIONS(1),
     [anon_sym_SLASH_GT] = ACTIONS(1),
     [anon_sym_LT_SLASH] = ACTIONS(1),
@@ -782,18 +1227,26 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_raw_text] = ACTIONS(1),
     [sym_comment] = ACTIONS(3),
     [sym_text] = ACTIONS(1),
+    [sym_processing_instruction] = ACTIONS(3),
+    [sym_bogus_comment] = ACTIONS(3),
+    [sym_cdata_section] = ACTIONS(3),
+    [sym_cdata] = ACTIONS(1),
+    [sym__template_start_tag_name] = ACTIONS(1),
   },
   [STATE(1)] = {
-    [sym_document] = STATE(67),
-    [sym_doctype] = STATE(6),
-    [sym__node] = STATE(6),
-    [sym_element] = STATE(6),
-    [sym__raw_text_element] = STATE(9),
+    [sym_document] = STATE(122),
+    [sym_doctype] = STATE(8),
+    [sym__node] = STATE(8),
+    [sym_element] = STATE(8),
+    [sym__template_element] = STATE(12),
+    [sym__raw_text_element] = STATE(12),
     [sym_start_tag] = STATE(4),
-    [sym__raw_text_start_tag] = STATE(46),
-    [sym_self_closing_tag] = STATE(9),
-    [sym_erroneous_end_tag] = STATE(6),
-    [aux_sym_document_repeat1] = STATE(6),
+    [sym__raw_text_start_tag] = STATE(86),
+    [sym__template_start_tag] = STATE(2),
+    [sym_self_closing_tag] = STATE(12),
+    [sym__template_self_closing_tag] = STATE(13),
+    [sym_erroneous_end_tag] = STATE(8),
+    [aux_sym_document_repeat1] = STATE(8),
     [ts_builtin_sym_end] = ACTIONS(5),
     [anon_sym_LT_BANG] = ACTIONS(7),
     [anon_sym_LT] = ACTIONS(9),
@@ -801,13 +1254,15 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_entity] = ACTIONS(13),
     [sym_comment] = ACTIONS(3),
     [sym_text] = ACTIONS(13),
+    [sym_processing_instruction] = ACTIONS(3),
+    [sym_bogus_comment] = ACTIONS(3),
+    [sym_cdata_section] = ACTIONS(3),
+    [sym_cdata] = ACTIONS(13),
   },
 };
 
 static const uint16_t ts_small_parse_table[] = {
-  [0] = 11,
-    ACTIONS(3), 1,
-      sym_comment,
+  [0] = 14,
     ACTIONS(15), 1,
       anon_sym_LT_BANG,
     ACTIONS(17), 1,
@@ -816,85 +1271,116 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_SLASH,
     ACTIONS(23), 1,
       sym__implicit_end_tag,
-    STATE(2), 1,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(6), 1,
       sym_start_tag,
-    STATE(20), 1,
+    STATE(17), 1,
       sym_end_tag,
-    STATE(47), 1,
+    STATE(43), 1,
+      sym__template_self_closing_tag,
+    STATE(87), 1,
+      sym_template_content,
+    STATE(95), 1,
       sym__raw_text_start_tag,
-    ACTIONS(21), 2,
+    ACTIONS(21), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(19), 2,
+    STATE(42), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    STATE(5), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(9), 5,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       aux_sym_document_repeat1,
-  [40] = 11,
-    ACTIONS(3), 1,
-      sym_comment,
+  [54] = 14,
     ACTIONS(15), 1,
       anon_sym_LT_BANG,
     ACTIONS(17), 1,
       anon_sym_LT,
     ACTIONS(25), 1,
       anon_sym_LT_SLASH,
-    ACTIONS(29), 1,
+    ACTIONS(27), 1,
       sym__implicit_end_tag,
-    STATE(2), 1,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(6), 1,
       sym_start_tag,
-    STATE(13), 1,
-      sym_end_tag,
+    STATE(43), 1,
+      sym__template_self_closing_tag,
     STATE(47), 1,
+      sym_end_tag,
+    STATE(95), 1,
       sym__raw_text_start_tag,
-    ACTIONS(27), 2,
+    STATE(96), 1,
+      sym_template_content,
+    ACTIONS(21), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(19), 2,
+    STATE(42), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    STATE(8), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(9), 5,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       aux_sym_document_repeat1,
-  [80] = 11,
-    ACTIONS(3), 1,
-      sym_comment,
+  [108] = 13,
     ACTIONS(15), 1,
       anon_sym_LT_BANG,
     ACTIONS(17), 1,
       anon_sym_LT,
-    ACTIONS(25), 1,
+    ACTIONS(19), 1,
       anon_sym_LT_SLASH,
-    ACTIONS(33), 1,
+    ACTIONS(31), 1,
       sym__implicit_end_tag,
-    STATE(2), 1,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(6), 1,
       sym_start_tag,
-    STATE(30), 1,
+    STATE(14), 1,
       sym_end_tag,
-    STATE(47), 1,
+    STATE(43), 1,
+      sym__template_self_closing_tag,
+    STATE(95), 1,
       sym__raw_text_start_tag,
-    ACTIONS(31), 2,
+    ACTIONS(29), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(19), 2,
+    STATE(42), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    STATE(3), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(5), 5,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       aux_sym_document_repeat1,
-  [120] = 11,
-    ACTIONS(3), 1,
-      sym_comment,
+  [159] = 13,
     ACTIONS(15), 1,
       anon_sym_LT_BANG,
     ACTIONS(17), 1,
@@ -903,792 +1389,2052 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_SLASH,
     ACTIONS(35), 1,
       sym__implicit_end_tag,
-    STATE(2), 1,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(6), 1,
       sym_start_tag,
     STATE(24), 1,
       sym_end_tag,
-    STATE(47), 1,
+    STATE(43), 1,
+      sym__template_self_closing_tag,
+    STATE(95), 1,
       sym__raw_text_start_tag,
-    ACTIONS(27), 2,
+    ACTIONS(33), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(19), 2,
+    STATE(42), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    STATE(8), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(11), 5,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       aux_sym_document_repeat1,
-  [160] = 10,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(7), 1,
+  [210] = 13,
+    ACTIONS(15), 1,
       anon_sym_LT_BANG,
-    ACTIONS(9), 1,
+    ACTIONS(17), 1,
       anon_sym_LT,
-    ACTIONS(11), 1,
+    ACTIONS(25), 1,
       anon_sym_LT_SLASH,
-    ACTIONS(37), 1,
-      ts_builtin_sym_end,
-    STATE(4), 1,
+    ACTIONS(39), 1,
+      sym__implicit_end_tag,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(6), 1,
       sym_start_tag,
-    STATE(46), 1,
+    STATE(43), 1,
+      sym__template_self_closing_tag,
+    STATE(44), 1,
+      sym_end_tag,
+    STATE(95), 1,
       sym__raw_text_start_tag,
-    ACTIONS(39), 2,
+    ACTIONS(37), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(9), 2,
+    STATE(42), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
     STATE(7), 5,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       aux_sym_document_repeat1,
-  [197] = 10,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(41), 1,
-      ts_builtin_sym_end,
-    ACTIONS(43), 1,
+  [261] = 13,
+    ACTIONS(15), 1,
       anon_sym_LT_BANG,
-    ACTIONS(46), 1,
+    ACTIONS(17), 1,
       anon_sym_LT,
-    ACTIONS(49), 1,
+    ACTIONS(25), 1,
       anon_sym_LT_SLASH,
-    STATE(4), 1,
+    ACTIONS(41), 1,
+      sym__implicit_end_tag,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(6), 1,
       sym_start_tag,
-    STATE(46), 1,
+    STATE(43), 1,
+      sym__template_self_closing_tag,
+    STATE(52), 1,
+      sym_end_tag,
+    STATE(95), 1,
       sym__raw_text_start_tag,
-    ACTIONS(52), 2,
+    ACTIONS(33), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(9), 2,
+    STATE(42), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    STATE(7), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(11), 5,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       aux_sym_document_repeat1,
-  [234] = 10,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(41), 1,
-      sym__implicit_end_tag,
-    ACTIONS(55), 1,
+  [312] = 12,
+    ACTIONS(7), 1,
       anon_sym_LT_BANG,
-    ACTIONS(58), 1,
+    ACTIONS(9), 1,
       anon_sym_LT,
-    ACTIONS(61), 1,
+    ACTIONS(11), 1,
       anon_sym_LT_SLASH,
+    ACTIONS(43), 1,
+      ts_builtin_sym_end,
     STATE(2), 1,
+      sym__template_start_tag,
+    STATE(4), 1,
       sym_start_tag,
-    STATE(47), 1,
+    STATE(13), 1,
+      sym__template_self_closing_tag,
+    STATE(86), 1,
       sym__raw_text_start_tag,
-    ACTIONS(64), 2,
+    ACTIONS(45), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(19), 2,
+    STATE(12), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    STATE(8), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(10), 5,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       aux_sym_document_repeat1,
-  [271] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(69), 1,
-      anon_sym_LT,
-    ACTIONS(67), 5,
-      sym_text,
-      ts_builtin_sym_end,
+  [360] = 12,
+    ACTIONS(15), 1,
       anon_sym_LT_BANG,
+    ACTIONS(17), 1,
+      anon_sym_LT,
+    ACTIONS(47), 1,
       anon_sym_LT_SLASH,
+    ACTIONS(50), 1,
+      sym__implicit_end_tag,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(6), 1,
+      sym_start_tag,
+    STATE(43), 1,
+      sym__template_self_closing_tag,
+    STATE(95), 1,
+      sym__raw_text_start_tag,
+    ACTIONS(33), 3,
+      sym_text,
+      sym_cdata,
       sym_entity,
-  [285] = 3,
-    ACTIONS(3), 1,
+    STATE(42), 3,
+      sym__template_element,
+      sym__raw_text_element,
+      sym_self_closing_tag,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(73), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(11), 5,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_erroneous_end_tag,
+      aux_sym_document_repeat1,
+  [408] = 12,
+    ACTIONS(52), 1,
+      ts_builtin_sym_end,
+    ACTIONS(54), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(57), 1,
       anon_sym_LT,
-    ACTIONS(71), 5,
+    ACTIONS(60), 1,
+      anon_sym_LT_SLASH,
+    STATE(2), 1,
+      sym__template_start_tag,
+    STATE(4), 1,
+      sym_start_tag,
+    STATE(13), 1,
+      sym__template_self_closing_tag,
+    STATE(86), 1,
+      sym__raw_text_start_tag,
+    ACTIONS(63), 3,
+      sym_text,
+      sym_cdata,
+      sym_entity,
+    STATE(12), 3,
+      sym__template_element,
+      sym__raw_text_element,
+      sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(10), 5,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_erroneous_end_tag,
+      aux_sym_document_repeat1,
+  [456] = 12,
+    ACTIONS(52), 1,
       sym__implicit_end_tag,
+    ACTIONS(66), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(69), 1,
+      anon_sym_LT,
+    ACTIONS(72), 1,
+      anon_sym_LT_SLASH,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(6), 1,
+      sym_start_tag,
+    STATE(43), 1,
+      sym__template_self_closing_tag,
+    STATE(95), 1,
+      sym__raw_text_start_tag,
+    ACTIONS(75), 3,
       sym_text,
+      sym_cdata,
+      sym_entity,
+    STATE(42), 3,
+      sym__template_element,
+      sym__raw_text_element,
+      sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(11), 5,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_erroneous_end_tag,
+      aux_sym_document_repeat1,
+  [504] = 3,
+    ACTIONS(80), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(78), 6,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
-  [299] = 3,
-    ACTIONS(3), 1,
+  [522] = 3,
+    ACTIONS(84), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(77), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(82), 6,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [540] = 3,
+    ACTIONS(88), 1,
       anon_sym_LT,
-    ACTIONS(75), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(86), 6,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
-  [313] = 3,
-    ACTIONS(3), 1,
+  [558] = 3,
+    ACTIONS(92), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(90), 6,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [576] = 3,
+    ACTIONS(96), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(94), 6,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [594] = 3,
+    ACTIONS(96), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(94), 6,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [612] = 3,
+    ACTIONS(100), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(98), 6,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [630] = 3,
+    ACTIONS(104), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(81), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(102), 6,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [648] = 3,
+    ACTIONS(108), 1,
       anon_sym_LT,
-    ACTIONS(79), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(106), 6,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
-  [327] = 3,
-    ACTIONS(3), 1,
+  [666] = 3,
+    ACTIONS(112), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(85), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(110), 6,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [684] = 3,
+    ACTIONS(116), 1,
       anon_sym_LT,
-    ACTIONS(83), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(114), 6,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
-  [341] = 3,
-    ACTIONS(3), 1,
+  [702] = 3,
+    ACTIONS(120), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(118), 6,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [720] = 3,
+    ACTIONS(124), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(122), 6,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [738] = 3,
+    ACTIONS(128), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(89), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(126), 6,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [756] = 3,
+    ACTIONS(132), 1,
       anon_sym_LT,
-    ACTIONS(87), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(130), 6,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
-  [355] = 3,
-    ACTIONS(3), 1,
+  [774] = 3,
+    ACTIONS(132), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(93), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(130), 6,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [792] = 3,
+    ACTIONS(136), 1,
       anon_sym_LT,
-    ACTIONS(91), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(134), 6,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
-  [369] = 3,
-    ACTIONS(3), 1,
+  [810] = 3,
+    ACTIONS(140), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(97), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(138), 6,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [828] = 3,
+    ACTIONS(144), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(142), 6,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [846] = 3,
+    ACTIONS(148), 1,
       anon_sym_LT,
-    ACTIONS(95), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(146), 6,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
-  [383] = 3,
-    ACTIONS(3), 1,
+  [864] = 3,
+    ACTIONS(152), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(101), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(150), 6,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [882] = 3,
+    ACTIONS(156), 1,
       anon_sym_LT,
-    ACTIONS(99), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(154), 6,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
-  [397] = 3,
-    ACTIONS(3), 1,
+  [900] = 3,
+    ACTIONS(160), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(105), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(158), 6,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [918] = 3,
+    ACTIONS(164), 1,
       anon_sym_LT,
-    ACTIONS(103), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(162), 6,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
-  [411] = 3,
-    ACTIONS(3), 1,
+  [936] = 3,
+    ACTIONS(168), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(69), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(166), 6,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [954] = 3,
+    ACTIONS(172), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(170), 6,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [972] = 3,
+    ACTIONS(176), 1,
       anon_sym_LT,
-    ACTIONS(67), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(174), 6,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [990] = 3,
+    ACTIONS(180), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(178), 6,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [1008] = 3,
+    ACTIONS(184), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(182), 6,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [1026] = 3,
+    ACTIONS(188), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(186), 6,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [1044] = 3,
+    ACTIONS(80), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(78), 6,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
-  [425] = 3,
-    ACTIONS(3), 1,
+  [1062] = 3,
+    ACTIONS(84), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(109), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(82), 6,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [1080] = 3,
+    ACTIONS(88), 1,
       anon_sym_LT,
-    ACTIONS(107), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(86), 6,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
-  [439] = 3,
-    ACTIONS(3), 1,
+  [1098] = 3,
+    ACTIONS(92), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(113), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(90), 6,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [1116] = 3,
+    ACTIONS(96), 1,
       anon_sym_LT,
-    ACTIONS(111), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(94), 6,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
-  [453] = 3,
-    ACTIONS(3), 1,
+  [1134] = 3,
+    ACTIONS(96), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(77), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(94), 6,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [1152] = 3,
+    ACTIONS(100), 1,
       anon_sym_LT,
-    ACTIONS(75), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(98), 6,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
-  [467] = 3,
-    ACTIONS(3), 1,
+  [1170] = 3,
+    ACTIONS(108), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(81), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(106), 6,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [1188] = 3,
+    ACTIONS(116), 1,
       anon_sym_LT,
-    ACTIONS(79), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(114), 6,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
-  [481] = 3,
-    ACTIONS(3), 1,
+  [1206] = 3,
+    ACTIONS(120), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(85), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(118), 6,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [1224] = 3,
+    ACTIONS(124), 1,
       anon_sym_LT,
-    ACTIONS(83), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(122), 6,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
-  [495] = 3,
-    ACTIONS(3), 1,
+  [1242] = 3,
+    ACTIONS(128), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(89), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(126), 6,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [1260] = 3,
+    ACTIONS(132), 1,
       anon_sym_LT,
-    ACTIONS(87), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(130), 6,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
-  [509] = 3,
-    ACTIONS(3), 1,
+  [1278] = 3,
+    ACTIONS(132), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(93), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(130), 6,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [1296] = 3,
+    ACTIONS(136), 1,
       anon_sym_LT,
-    ACTIONS(91), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(134), 6,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
-  [523] = 3,
-    ACTIONS(3), 1,
+  [1314] = 3,
+    ACTIONS(144), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(101), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(142), 6,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [1332] = 3,
+    ACTIONS(152), 1,
       anon_sym_LT,
-    ACTIONS(99), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(150), 6,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
-  [537] = 3,
-    ACTIONS(3), 1,
+  [1350] = 3,
+    ACTIONS(156), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(105), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(154), 6,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [1368] = 3,
+    ACTIONS(160), 1,
       anon_sym_LT,
-    ACTIONS(103), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(158), 6,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
-  [551] = 3,
-    ACTIONS(3), 1,
+  [1386] = 3,
+    ACTIONS(164), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(113), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(162), 6,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [1404] = 3,
+    ACTIONS(168), 1,
       anon_sym_LT,
-    ACTIONS(111), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(166), 6,
+      sym__implicit_end_tag,
       sym_text,
-      ts_builtin_sym_end,
+      sym_cdata,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
-  [565] = 3,
-    ACTIONS(3), 1,
+  [1422] = 3,
+    ACTIONS(172), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(109), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(170), 6,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [1440] = 3,
+    ACTIONS(176), 1,
       anon_sym_LT,
-    ACTIONS(107), 5,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(174), 6,
+      sym__implicit_end_tag,
       sym_text,
-      ts_builtin_sym_end,
+      sym_cdata,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
-  [579] = 5,
-    ACTIONS(3), 1,
+  [1458] = 3,
+    ACTIONS(180), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(115), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(178), 6,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [1476] = 3,
+    ACTIONS(184), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(182), 6,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [1494] = 3,
+    ACTIONS(188), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(186), 6,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+  [1512] = 5,
+    ACTIONS(190), 1,
       anon_sym_GT,
-    ACTIONS(117), 1,
+    ACTIONS(192), 1,
       anon_sym_SLASH_GT,
-    ACTIONS(119), 1,
+    ACTIONS(194), 1,
       sym_attribute_name,
-    STATE(34), 2,
+    STATE(70), 2,
       sym_attribute,
       aux_sym_start_tag_repeat1,
-  [596] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(123), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1532] = 5,
+    ACTIONS(194), 1,
       sym_attribute_name,
-    ACTIONS(121), 2,
+    ACTIONS(196), 1,
       anon_sym_GT,
+    ACTIONS(198), 1,
       anon_sym_SLASH_GT,
-    STATE(32), 2,
+    STATE(71), 2,
       sym_attribute,
       aux_sym_start_tag_repeat1,
-  [611] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(115), 1,
-      anon_sym_GT,
-    ACTIONS(119), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1552] = 5,
+    ACTIONS(194), 1,
       sym_attribute_name,
-    ACTIONS(126), 1,
+    ACTIONS(200), 1,
+      anon_sym_GT,
+    ACTIONS(202), 1,
       anon_sym_SLASH_GT,
-    STATE(35), 2,
+    STATE(72), 2,
       sym_attribute,
       aux_sym_start_tag_repeat1,
-  [628] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(119), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1572] = 5,
+    ACTIONS(194), 1,
       sym_attribute_name,
-    ACTIONS(128), 1,
+    ACTIONS(204), 1,
       anon_sym_GT,
-    ACTIONS(130), 1,
+    ACTIONS(206), 1,
       anon_sym_SLASH_GT,
-    STATE(32), 2,
+    STATE(72), 2,
       sym_attribute,
       aux_sym_start_tag_repeat1,
-  [645] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(119), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1592] = 4,
+    ACTIONS(210), 1,
       sym_attribute_name,
-    ACTIONS(128), 1,
+    ACTIONS(208), 2,
       anon_sym_GT,
-    ACTIONS(132), 1,
       anon_sym_SLASH_GT,
-    STATE(32), 2,
+    STATE(72), 2,
       sym_attribute,
       aux_sym_start_tag_repeat1,
-  [662] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(134), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1610] = 5,
+    ACTIONS(190), 1,
       anon_sym_GT,
-    ACTIONS(136), 1,
+    ACTIONS(194), 1,
       sym_attribute_name,
-    STATE(39), 2,
+    ACTIONS(213), 1,
+      anon_sym_SLASH_GT,
+    STATE(75), 2,
       sym_attribute,
       aux_sym_start_tag_repeat1,
-  [676] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(136), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1630] = 5,
+    ACTIONS(194), 1,
+      sym_attribute_name,
+    ACTIONS(196), 1,
+      anon_sym_GT,
+    ACTIONS(215), 1,
+      anon_sym_SLASH_GT,
+    STATE(76), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1650] = 5,
+    ACTIONS(194), 1,
       sym_attribute_name,
-    ACTIONS(138), 1,
+    ACTIONS(200), 1,
       anon_sym_GT,
-    STATE(36), 2,
+    ACTIONS(217), 1,
+      anon_sym_SLASH_GT,
+    STATE(72), 2,
       sym_attribute,
       aux_sym_start_tag_repeat1,
-  [690] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(142), 1,
-      anon_sym_EQ,
-    ACTIONS(140), 3,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1670] = 5,
+    ACTIONS(194), 1,
+      sym_attribute_name,
+    ACTIONS(204), 1,
       anon_sym_GT,
+    ACTIONS(219), 1,
       anon_sym_SLASH_GT,
+    STATE(72), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1690] = 4,
+    ACTIONS(221), 1,
+      anon_sym_GT,
+    ACTIONS(223), 1,
       sym_attribute_name,
-  [702] = 4,
-    ACTIONS(3), 1,
+    STATE(80), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(121), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1707] = 5,
+    ACTIONS(225), 1,
       anon_sym_GT,
-    ACTIONS(144), 1,
+    ACTIONS(227), 1,
+      sym__doctype_public,
+    ACTIONS(229), 1,
+      sym__doctype_system,
+    ACTIONS(231), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1726] = 3,
+    ACTIONS(235), 1,
+      anon_sym_EQ,
+    ACTIONS(233), 3,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
       sym_attribute_name,
-    STATE(39), 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1741] = 4,
+    ACTIONS(223), 1,
+      sym_attribute_name,
+    ACTIONS(237), 1,
+      anon_sym_GT,
+    STATE(82), 2,
       sym_attribute,
       aux_sym_start_tag_repeat1,
-  [716] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(147), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1758] = 5,
+    ACTIONS(239), 1,
       sym_attribute_value,
-    ACTIONS(149), 1,
+    ACTIONS(241), 1,
       anon_sym_SQUOTE,
-    ACTIONS(151), 1,
+    ACTIONS(243), 1,
       anon_sym_DQUOTE,
-    STATE(42), 1,
+    STATE(91), 1,
       sym_quoted_attribute_value,
-  [732] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(153), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1777] = 4,
+    ACTIONS(208), 1,
+      anon_sym_GT,
+    ACTIONS(245), 1,
+      sym_attribute_name,
+    STATE(82), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1794] = 5,
+    ACTIONS(248), 1,
+      anon_sym_GT,
+    ACTIONS(250), 1,
+      sym__doctype_public,
+    ACTIONS(252), 1,
+      sym__doctype_system,
+    ACTIONS(254), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1813] = 5,
+    ACTIONS(256), 1,
       sym_attribute_value,
-    ACTIONS(155), 1,
+    ACTIONS(258), 1,
       anon_sym_SQUOTE,
-    ACTIONS(157), 1,
+    ACTIONS(260), 1,
       anon_sym_DQUOTE,
-    STATE(55), 1,
+    STATE(110), 1,
       sym_quoted_attribute_value,
-  [748] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(159), 3,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1832] = 4,
+    ACTIONS(262), 1,
+      sym__start_tag_name,
+    ACTIONS(264), 1,
+      sym__raw_text_start_tag_name,
+    ACTIONS(266), 1,
+      sym__template_start_tag_name,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1848] = 4,
+    ACTIONS(268), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(270), 1,
+      sym_raw_text,
+    STATE(15), 1,
+      sym_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1864] = 4,
+    ACTIONS(268), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(272), 1,
+      sym__implicit_end_tag,
+    STATE(27), 1,
+      sym_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1880] = 4,
+    ACTIONS(274), 1,
+      anon_sym_GT,
+    ACTIONS(276), 1,
+      sym_doctype_identifier,
+    ACTIONS(278), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1896] = 4,
+    ACTIONS(274), 1,
+      anon_sym_GT,
+    ACTIONS(278), 1,
+      sym__bogus_doctype,
+    ACTIONS(280), 1,
+      sym_doctype_identifier,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1912] = 4,
+    ACTIONS(282), 1,
+      anon_sym_GT,
+    ACTIONS(284), 1,
+      sym_doctype_identifier,
+    ACTIONS(286), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1928] = 2,
+    ACTIONS(288), 3,
       anon_sym_GT,
       anon_sym_SLASH_GT,
       sym_attribute_name,
-  [757] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(161), 1,
-      anon_sym_EQ,
-    ACTIONS(140), 2,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1940] = 2,
+    ACTIONS(290), 3,
       anon_sym_GT,
+      anon_sym_SLASH_GT,
       sym_attribute_name,
-  [768] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(163), 3,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1952] = 2,
+    ACTIONS(292), 3,
       anon_sym_GT,
       anon_sym_SLASH_GT,
       sym_attribute_name,
-  [777] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(165), 3,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1964] = 3,
+    ACTIONS(294), 1,
+      anon_sym_EQ,
+    ACTIONS(233), 2,
       anon_sym_GT,
-      anon_sym_SLASH_GT,
       sym_attribute_name,
-  [786] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(167), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1978] = 4,
+    ACTIONS(296), 1,
       anon_sym_LT_SLASH,
-    ACTIONS(169), 1,
+    ACTIONS(298), 1,
       sym_raw_text,
-    STATE(29), 1,
+    STATE(45), 1,
       sym_end_tag,
-  [799] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(171), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [1994] = 4,
+    ACTIONS(296), 1,
       anon_sym_LT_SLASH,
-    ACTIONS(173), 1,
-      sym_raw_text,
-    STATE(21), 1,
+    ACTIONS(300), 1,
+      sym__implicit_end_tag,
+    STATE(55), 1,
       sym_end_tag,
-  [812] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(175), 1,
-      anon_sym_SQUOTE,
-    ACTIONS(177), 1,
-      aux_sym_quoted_attribute_value_token1,
-  [822] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(179), 1,
-      sym__start_tag_name,
-    ACTIONS(181), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2010] = 4,
+    ACTIONS(302), 1,
+      anon_sym_GT,
+    ACTIONS(304), 1,
+      sym_doctype_identifier,
+    ACTIONS(306), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2026] = 4,
+    ACTIONS(302), 1,
+      anon_sym_GT,
+    ACTIONS(306), 1,
+      sym__bogus_doctype,
+    ACTIONS(308), 1,
+      sym_doctype_identifier,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2042] = 4,
+    ACTIONS(310), 1,
+      anon_sym_GT,
+    ACTIONS(312), 1,
+      sym_doctype_identifier,
+    ACTIONS(314), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2058] = 4,
+    ACTIONS(264), 1,
       sym__raw_text_start_tag_name,
-  [832] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(316), 1,
+      sym__start_tag_name,
+    ACTIONS(318), 1,
+      sym__template_start_tag_name,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2074] = 3,
+    ACTIONS(320), 1,
+      anon_sym_GT,
+    ACTIONS(322), 1,
+      sym_doctype_name,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2087] = 3,
+    ACTIONS(324), 1,
+      sym__end_tag_name,
+    ACTIONS(326), 1,
+      sym_erroneous_end_tag_name,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(183), 2,
-      sym_raw_text,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2100] = 3,
+    ACTIONS(268), 1,
       anon_sym_LT_SLASH,
-  [840] = 3,
-    ACTIONS(3), 1,
+    STATE(25), 1,
+      sym_end_tag,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(185), 1,
-      sym__end_tag_name,
-    ACTIONS(187), 1,
-      sym_erroneous_end_tag_name,
-  [850] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2113] = 2,
+    ACTIONS(328), 2,
+      sym_raw_text,
+      anon_sym_LT_SLASH,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(189), 2,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2124] = 2,
+    ACTIONS(330), 2,
       sym_raw_text,
       anon_sym_LT_SLASH,
-  [858] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(175), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2135] = 3,
+    ACTIONS(332), 1,
+      anon_sym_GT,
+    ACTIONS(334), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2148] = 3,
+    ACTIONS(336), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(338), 1,
+      aux_sym_quoted_attribute_value_token1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2161] = 3,
+    ACTIONS(336), 1,
       anon_sym_DQUOTE,
-    ACTIONS(191), 1,
+    ACTIONS(340), 1,
       aux_sym_quoted_attribute_value_token2,
-  [868] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(167), 1,
-      anon_sym_LT_SLASH,
-    STATE(14), 1,
-      sym_end_tag,
-  [878] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(159), 2,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2174] = 3,
+    ACTIONS(342), 1,
+      anon_sym_GT,
+    ACTIONS(344), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2187] = 2,
+    ACTIONS(288), 2,
       anon_sym_GT,
       sym_attribute_name,
-  [886] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(163), 2,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2198] = 2,
+    ACTIONS(290), 2,
       anon_sym_GT,
       sym_attribute_name,
-  [894] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(165), 2,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2209] = 2,
+    ACTIONS(292), 2,
       anon_sym_GT,
       sym_attribute_name,
-  [902] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(171), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2220] = 3,
+    ACTIONS(346), 1,
+      anon_sym_GT,
+    ACTIONS(348), 1,
+      sym_doctype_name,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2233] = 3,
+    ACTIONS(296), 1,
       anon_sym_LT_SLASH,
-    STATE(25), 1,
+    STATE(53), 1,
       sym_end_tag,
-  [912] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(193), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2246] = 3,
+    ACTIONS(350), 1,
+      anon_sym_GT,
+    ACTIONS(352), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2259] = 3,
+    ACTIONS(354), 1,
       anon_sym_SQUOTE,
-    ACTIONS(195), 1,
+    ACTIONS(356), 1,
       aux_sym_quoted_attribute_value_token1,
-  [922] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(193), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2272] = 3,
+    ACTIONS(354), 1,
       anon_sym_DQUOTE,
-    ACTIONS(197), 1,
+    ACTIONS(358), 1,
       aux_sym_quoted_attribute_value_token2,
-  [932] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(181), 1,
-      sym__raw_text_start_tag_name,
-    ACTIONS(199), 1,
-      sym__start_tag_name,
-  [942] = 3,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2285] = 3,
+    ACTIONS(360), 1,
+      anon_sym_GT,
+    ACTIONS(362), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2298] = 3,
+    ACTIONS(326), 1,
+      sym_erroneous_end_tag_name,
+    ACTIONS(364), 1,
+      sym__end_tag_name,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(187), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2311] = 2,
+    ACTIONS(366), 1,
+      sym__doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2321] = 2,
+    ACTIONS(368), 1,
       sym_erroneous_end_tag_name,
-    ACTIONS(201), 1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2331] = 2,
+    ACTIONS(370), 1,
+      ts_builtin_sym_end,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2341] = 2,
+    ACTIONS(372), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2351] = 2,
+    ACTIONS(324), 1,
       sym__end_tag_name,
-  [952] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(203), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2361] = 2,
+    ACTIONS(374), 1,
       anon_sym_GT,
-  [959] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(205), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2371] = 2,
+    ACTIONS(376), 1,
       anon_sym_GT,
-  [966] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(207), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2381] = 2,
+    ACTIONS(378), 1,
       anon_sym_GT,
-  [973] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(185), 1,
-      sym__end_tag_name,
-  [980] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2391] = 2,
+    ACTIONS(380), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(209), 1,
-      ts_builtin_sym_end,
-  [987] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2401] = 2,
+    ACTIONS(382), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(211), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2411] = 2,
+    ACTIONS(384), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2421] = 2,
+    ACTIONS(384), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2431] = 2,
+    ACTIONS(386), 1,
       anon_sym_GT,
-  [994] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(213), 1,
-      aux_sym_doctype_token1,
-  [1001] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2441] = 2,
+    ACTIONS(388), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(215), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2451] = 2,
+    ACTIONS(390), 1,
       anon_sym_GT,
-  [1008] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(217), 1,
-      sym__doctype,
-  [1015] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2461] = 2,
+    ACTIONS(392), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(219), 1,
-      sym_erroneous_end_tag_name,
-  [1022] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2471] = 2,
+    ACTIONS(394), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(221), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2481] = 2,
+    ACTIONS(396), 1,
       anon_sym_GT,
-  [1029] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(223), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2491] = 2,
+    ACTIONS(398), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2501] = 2,
+    ACTIONS(400), 1,
       anon_sym_SQUOTE,
-  [1036] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(223), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2511] = 2,
+    ACTIONS(400), 1,
       anon_sym_DQUOTE,
-  [1043] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(225), 1,
-      anon_sym_SQUOTE,
-  [1050] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(187), 1,
-      sym_erroneous_end_tag_name,
-  [1057] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2521] = 2,
+    ACTIONS(402), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(227), 1,
-      aux_sym_doctype_token1,
-  [1064] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2531] = 2,
+    ACTIONS(404), 1,
+      sym__doctype,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(225), 1,
-      anon_sym_DQUOTE,
-  [1071] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2541] = 2,
+    ACTIONS(326), 1,
+      sym_erroneous_end_tag_name,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(201), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [2551] = 2,
+    ACTIONS(364), 1,
       sym__end_tag_name,
-  [1078] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(229), 1,
-      sym__doctype,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
 };
 
 static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(2)] = 0,
-  [SMALL_STATE(3)] = 40,
-  [SMALL_STATE(4)] = 80,
-  [SMALL_STATE(5)] = 120,
-  [SMALL_STATE(6)] = 160,
-  [SMALL_STATE(7)] = 197,
-  [SMALL_STATE(8)] = 234,
-  [SMALL_STATE(9)] = 271,
-  [SMALL_STATE(10)] = 285,
-  [SMALL_STATE(11)] = 299,
-  [SMALL_STATE(12)] = 313,
-  [SMALL_STATE(13)] = 327,
-  [SMALL_STATE(14)] = 341,
-  [SMALL_STATE(15)] = 355,
-  [SMALL_STATE(16)] = 369,
-  [SMALL_STATE(17)] = 383,
-  [SMALL_STATE(18)] = 397,
-  [SMALL_STATE(19)] = 411,
-  [SMALL_STATE(20)] = 425,
-  [SMALL_STATE(21)] = 439,
-  [SMALL_STATE(22)] = 453,
-  [SMALL_STATE(23)] = 467,
-  [SMALL_STATE(24)] = 481,
-  [SMALL_STATE(25)] = 495,
-  [SMALL_STATE(26)] = 509,
-  [SMALL_STATE(27)] = 523,
-  [SMALL_STATE(28)] = 537,
-  [SMALL_STATE(29)] = 551,
-  [SMALL_STATE(30)] = 565,
-  [SMALL_STATE(31)] = 579,
-  [SMALL_STATE(32)] = 596,
-  [SMALL_STATE(33)] = 611,
-  [SMALL_STATE(34)] = 628,
-  [SMALL_STATE(35)] = 645,
-  [SMALL_STATE(36)] = 662,
-  [SMALL_STATE(37)] = 676,
-  [SMALL_STATE(38)] = 690,
-  [SMALL_STATE(39)] = 702,
-  [SMALL_STATE(40)] = 716,
-  [SMALL_STATE(41)] = 732,
-  [SMALL_STATE(42)] = 748,
-  [SMALL_STATE(43)] = 757,
-  [SMALL_STATE(44)] = 768,
-  [SMALL_STATE(45)] = 777,
-  [SMALL_STATE(46)] = 786,
-  [SMALL_STATE(47)] = 799,
-  [SMALL_STATE(48)] = 812,
-  [SMALL_STATE(49)] = 822,
-  [SMALL_STATE(50)] = 832,
-  [SMALL_STATE(51)] = 840,
-  [SMALL_STATE(52)] = 850,
-  [SMALL_STATE(53)] = 858,
-  [SMALL_STATE(54)] = 868,
-  [SMALL_STATE(55)] = 878,
-  [SMALL_STATE(56)] = 886,
-  [SMALL_STATE(57)] = 894,
-  [SMALL_STATE(58)] = 902,
-  [SMALL_STATE(59)] = 912,
-  [SMALL_STATE(60)] = 922,
-  [SMALL_STATE(61)] = 932,
-  [SMALL_STATE(62)] = 942,
-  [SMALL_STATE(63)] = 952,
-  [SMALL_STATE(64)] = 959,
-  [SMALL_STATE(65)] = 966,
-  [SMALL_STATE(66)] = 973,
-  [SMALL_STATE(67)] = 980,
-  [SMALL_STATE(68)] = 987,
-  [SMALL_STATE(69)] = 994,
-  [SMALL_STATE(70)] = 1001,
-  [SMALL_STATE(71)] = 1008,
-  [SMALL_STATE(72)] = 1015,
-  [SMALL_STATE(73)] = 1022,
-  [SMALL_STATE(74)] = 1029,
-  [SMALL_STATE(75)] = 1036,
-  [SMALL_STATE(76)] = 1043,
-  [SMALL_STATE(77)] = 1050,
-  [SMALL_STATE(78)] = 1057,
-  [SMALL_STATE(79)] = 1064,
-  [SMALL_STATE(80)] = 1071,
-  [SMALL_STATE(81)] = 1078,
+  [SMALL_STATE(3)] = 54,
+  [SMALL_STATE(4)] = 108,
+  [SMALL_STATE(5)] = 159,
+  [SMALL_STATE(6)] = 210,
+  [SMALL_STATE(7)] = 261,
+  [SMALL_STATE(8)] = 312,
+  [SMALL_STATE(9)] = 360,
+  [SMALL_STATE(10)] = 408,
+  [SMALL_STATE(11)] = 456,
+  [SMALL_STATE(12)] = 504,
+  [SMALL_STATE(13)] = 522,
+  [SMALL_STATE(14)] = 540,
+  [SMALL_STATE(15)] = 558,
+  [SMALL_STATE(16)] = 576,
+  [SMALL_STATE(17)] = 594,
+  [SMALL_STATE(18)] = 612,
+  [SMALL_STATE(19)] = 630,
+  [SMALL_STATE(20)] = 648,
+  [SMALL_STATE(21)] = 666,
+  [SMALL_STATE(22)] = 684,
+  [SMALL_STATE(23)] = 702,
+  [SMALL_STATE(24)] = 720,
+  [SMALL_STATE(25)] = 738,
+  [SMALL_STATE(26)] = 756,
+  [SMALL_STATE(27)] = 774,
+  [SMALL_STATE(28)] = 792,
+  [SMALL_STATE(29)] = 810,
+  [SMALL_STATE(30)] = 828,
+  [SMALL_STATE(31)] = 846,
+  [SMALL_STATE(32)] = 864,
+  [SMALL_STATE(33)] = 882,
+  [SMALL_STATE(34)] = 900,
+  [SMALL_STATE(35)] = 918,
+  [SMALL_STATE(36)] = 936,
+  [SMALL_STATE(37)] = 954,
+  [SMALL_STATE(38)] = 972,
+  [SMALL_STATE(39)] = 990,
+  [SMALL_STATE(40)] = 1008,
+  [SMALL_STATE(41)] = 1026,
+  [SMALL_STATE(42)] = 1044,
+  [SMALL_STATE(43)] = 1062,
+  [SMALL_STATE(44)] = 1080,
+  [SMALL_STATE(45)] = 1098,
+  [SMALL_STATE(46)] = 1116,
+  [SMALL_STATE(47)] = 1134,
+  [SMALL_STATE(48)] = 1152,
+  [SMALL_STATE(49)] = 1170,
+  [SMALL_STATE(50)] = 1188,
+  [SMALL_STATE(51)] = 1206,
+  [SMALL_STATE(52)] = 1224,
+  [SMALL_STATE(53)] = 1242,
+  [SMALL_STATE(54)] = 1260,
+  [SMALL_STATE(55)] = 1278,
+  [SMALL_STATE(56)] = 1296,
+  [SMALL_STATE(57)] = 1314,
+  [SMALL_STATE(58)] = 1332,
+  [SMALL_STATE(59)] = 1350,
+  [SMALL_STATE(60)] = 1368,
+  [SMALL_STATE(61)] = 1386,
+  [SMALL_STATE(62)] = 1404,
+  [SMALL_STATE(63)] = 1422,
+  [SMALL_STATE(64)] = 1440,
+  [SMALL_STATE(65)] = 1458,
+  [SMALL_STATE(66)] = 1476,
+  [SMALL_STATE(67)] = 1494,
+  [SMALL_STATE(68)] = 1512,
+  [SMALL_STATE(69)] = 1532,
+  [SMALL_STATE(70)] = 1552,
+  [SMALL_STATE(71)] = 1572,
+  [SMALL_STATE(72)] = 1592,
+  [SMALL_STATE(73)] = 1610,
+  [SMALL_STATE(74)] = 1630,
+  [SMALL_STATE(75)] = 1650,
+  [SMALL_STATE(76)] = 1670,
+  [SMALL_STATE(77)] = 1690,
+  [SMALL_STATE(78)] = 1707,
+  [SMALL_STATE(79)] = 1726,
+  [SMALL_STATE(80)] = 1741,
+  [SMALL_STATE(81)] = 1758,
+  [SMALL_STATE(82)] = 1777,
+  [SMALL_STATE(83)] = 1794,
+  [SMALL_STATE(84)] = 1813,
+  [SMALL_STATE(85)] = 1832,
+  [SMALL_STATE(86)] = 1848,
+  [SMALL_STATE(87)] = 1864,
+  [SMALL_STATE(88)] = 1880,
+  [SMALL_STATE(89)] = 1896,
+  [SMALL_STATE(90)] = 1912,
+  [SMALL_STATE(91)] = 1928,
+  [SMALL_STATE(92)] = 1940,
+  [SMALL_STATE(93)] = 1952,
+  [SMALL_STATE(94)] = 1964,
+  [SMALL_STATE(95)] = 1978,
+  [SMALL_STATE(96)] = 1994,
+  [SMALL_STATE(97)] = 2010,
+  [SMALL_STATE(98)] = 2026,
+  [SMALL_STATE(99)] = 2042,
+  [SMALL_STATE(100)] = 2058,
+  [SMALL_STATE(101)] = 2074,
+  [SMALL_STATE(102)] = 2087,
+  [SMALL_STATE(103)] = 2100,
+  [SMALL_STATE(104)] = 2113,
+  [SMALL_STATE(105)] = 2124,
+  [SMALL_STATE(106)] = 2135,
+  [SMALL_STATE(107)] = 2148,
+  [SMALL_STATE(108)] = 2161,
+  [SMALL_STATE(109)] = 2174,
+  [SMALL_STATE(110)] = 2187,
+  [SMALL_STATE(111)] = 2198,
+  [SMALL_STATE(112)] = 2209,
+  [SMALL_STATE(113)] = 2220,
+  [SMALL_STATE(114)] = 2233,
+  [SMALL_STATE(115)] = 2246,
+  [SMALL_STATE(116)] = 2259,
+  [SMALL_STATE(117)] = 2272,
+  [SMALL_STATE(118)] = 2285,
+  [SMALL_STATE(119)] = 2298,
+  [SMALL_STATE(120)] = 2311,
+  [SMALL_STATE(121)] = 2321,
+  [SMALL_STATE(122)] = 2331,
+  [SMALL_STATE(123)] = 2341,
+  [SMALL_STATE(124)] = 2351,
+  [SMALL_STATE(125)] = 2361,
+  [SMALL_STATE(126)] = 2371,
+  [SMALL_STATE(127)] = 2381,
+  [SMALL_STATE(128)] = 2391,
+  [SMALL_STATE(129)] = 2401,
+  [SMALL_STATE(130)] = 2411,
+  [SMALL_STATE(131)] = 2421,
+  [SMALL_STATE(132)] = 2431,
+  [SMALL_STATE(133)] = 2441,
+  [SMALL_STATE(134)] = 2451,
+  [SMALL_STATE(135)] = 2461,
+  [SMALL_STATE(136)] = 2471,
+  [SMALL_STATE(137)] = 2481,
+  [SMALL_STATE(138)] = 2491,
+  [SMALL_STATE(139)] = 2501,
+  [SMALL_STATE(140)] = 2511,
+  [SMALL_STATE(141)] = 2521,
+  [SMALL_STATE(142)] = 2531,
+  [SMALL_STATE(143)] = 2541,
+  [SMALL_STATE(144)] = 2551,
 };
 
 static const TSParseActionEntry ts_parse_actions[] = {
@@ -1696,113 +3442,200 @@ static const TSParseActionEntry ts_parse_actions[] = {
   [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
   [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
   [5] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_document, 0, 0, 0),
-  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
-  [9] = {.entry = {.count = 1, .reusable = false}}, SHIFT(49),
-  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
-  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
-  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
-  [17] = {.entry = {.count = 1, .reusable = false}}, SHIFT(61),
-  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
-  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
-  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
-  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
-  [27] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
-  [29] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
-  [31] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
-  [33] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
+  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [9] = {.entry = {.count = 1, .reusable = false}}, SHIFT(85),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
+  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(142),
+  [17] = {.entry = {.count = 1, .reusable = false}}, SHIFT(100),
+  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
+  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
+  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
+  [27] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
+  [29] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
+  [31] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
+  [33] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
   [35] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
-  [37] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_document, 1, 0, 0),
-  [39] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
-  [41] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0),
-  [43] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(71),
-  [46] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(49),
-  [49] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(72),
-  [52] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(7),
-  [55] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(81),
-  [58] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(61),
-  [61] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(77),
-  [64] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(8),
-  [67] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 1, 0, 0),
-  [69] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 1, 0, 0),
-  [71] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_start_tag, 3, 0, 2),
-  [73] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_start_tag, 3, 0, 2),
-  [75] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_self_closing_tag, 3, 0, 2),
-  [77] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_self_closing_tag, 3, 0, 2),
-  [79] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_erroneous_end_tag, 3, 0, 0),
-  [81] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_erroneous_end_tag, 3, 0, 0),
-  [83] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 3, 0, 0),
-  [85] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 3, 0, 0),
-  [87] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__raw_text_element, 3, 0, 1),
-  [89] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__raw_text_element, 3, 0, 1),
-  [91] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 4, 0, 0),
-  [93] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 4, 0, 0),
-  [95] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_start_tag, 4, 0, 2),
-  [97] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_start_tag, 4, 0, 2),
-  [99] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_self_closing_tag, 4, 0, 2),
-  [101] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_self_closing_tag, 4, 0, 2),
-  [103] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_end_tag, 3, 0, 2),
-  [105] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_end_tag, 3, 0, 2),
-  [107] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 2, 0, 0),
-  [109] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 2, 0, 0),
-  [111] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__raw_text_element, 2, 0, 1),
-  [113] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__raw_text_element, 2, 0, 1),
-  [115] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
-  [117] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
-  [119] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
-  [121] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0),
-  [123] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(38),
-  [126] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
-  [128] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
-  [130] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
-  [132] = {.entry = {.count = 1, .reusable = true}}, SHIFT(27),
-  [134] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
-  [136] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
-  [138] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
-  [140] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 1, 0, 0),
-  [142] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
-  [144] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(43),
-  [147] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
-  [149] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
-  [151] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
-  [153] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
-  [155] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
-  [157] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
-  [159] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 3, 0, 0),
-  [161] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
-  [163] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_quoted_attribute_value, 2, 0, 0),
-  [165] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_quoted_attribute_value, 3, 0, 0),
-  [167] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
-  [169] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
-  [171] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
-  [173] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
-  [175] = {.entry = {.count = 1, .reusable = false}}, SHIFT(44),
-  [177] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
-  [179] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
-  [181] = {.entry = {.count = 1, .reusable = true}}, SHIFT(37),
-  [183] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__raw_text_start_tag, 4, 0, 2),
-  [185] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
-  [187] = {.entry = {.count = 1, .reusable = true}}, SHIFT(63),
-  [189] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__raw_text_start_tag, 3, 0, 2),
-  [191] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
-  [193] = {.entry = {.count = 1, .reusable = false}}, SHIFT(56),
-  [195] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
-  [197] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
-  [199] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
-  [201] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
-  [203] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
-  [205] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
-  [207] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
-  [209] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
-  [211] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
-  [213] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
-  [215] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
-  [217] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
-  [219] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
-  [221] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
-  [223] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
-  [225] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
-  [227] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
-  [229] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
+  [37] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
+  [39] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
+  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
+  [43] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_document, 1, 0, 0),
+  [45] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
+  [47] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_template_content, 1, 0, 0), SHIFT(143),
+  [50] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_content, 1, 0, 0),
+  [52] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0),
+  [54] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(120),
+  [57] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(85),
+  [60] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(121),
+  [63] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(10),
+  [66] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(142),
+  [69] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(100),
+  [72] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(143),
+  [75] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(11),
+  [78] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 1, 0, 0),
+  [80] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 1, 0, 0),
+  [82] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 1, 0, 1),
+  [84] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 1, 0, 1),
+  [86] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 2, 0, 0),
+  [88] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 2, 0, 0),
+  [90] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__raw_text_element, 2, 0, 1),
+  [92] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__raw_text_element, 2, 0, 1),
+  [94] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__template_element, 2, 0, 1),
+  [96] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__template_element, 2, 0, 1),
+  [98] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 3, 0, 0),
+  [100] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 3, 0, 0),
+  [102] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_start_tag, 3, 0, 2),
+  [104] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_start_tag, 3, 0, 2),
+  [106] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_self_closing_tag, 3, 0, 2),
+  [108] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_self_closing_tag, 3, 0, 2),
+  [110] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__template_start_tag, 3, 0, 2),
+  [112] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__template_start_tag, 3, 0, 2),
+  [114] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__template_self_closing_tag, 3, 0, 2),
+  [116] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__template_self_closing_tag, 3, 0, 2),
+  [118] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_erroneous_end_tag, 3, 0, 0),
+  [120] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_erroneous_end_tag, 3, 0, 0),
+  [122] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 3, 0, 0),
+  [124] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 3, 0, 0),
+  [126] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__raw_text_element, 3, 0, 1),
+  [128] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__raw_text_element, 3, 0, 1),
+  [130] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__template_element, 3, 0, 1),
+  [132] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__template_element, 3, 0, 1),
+  [134] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 4, 0, 3),
+  [136] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 4, 0, 3),
+  [138] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_start_tag, 4, 0, 2),
+  [140] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_start_tag, 4, 0, 2),
+  [142] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_self_closing_tag, 4, 0, 2),
+  [144] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_self_closing_tag, 4, 0, 2),
+  [146] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__template_start_tag, 4, 0, 2),
+  [148] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__template_start_tag, 4, 0, 2),
+  [150] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__template_self_closing_tag, 4, 0, 2),
+  [152] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__template_self_closing_tag, 4, 0, 2),
+  [154] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_end_tag, 3, 0, 2),
+  [156] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_end_tag, 3, 0, 2),
+  [158] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 5, 0, 3),
+  [160] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 5, 0, 3),
+  [162] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 6, 0, 4),
+  [164] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 6, 0, 4),
+  [166] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 6, 0, 3),
+  [168] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 6, 0, 3),
+  [170] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 6, 0, 5),
+  [172] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 6, 0, 5),
+  [174] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 7, 0, 6),
+  [176] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 7, 0, 6),
+  [178] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 7, 0, 4),
+  [180] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 7, 0, 4),
+  [182] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 7, 0, 5),
+  [184] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 7, 0, 5),
+  [186] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 8, 0, 6),
+  [188] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 8, 0, 6),
+  [190] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
+  [192] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
+  [194] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
+  [196] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
+  [198] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
+  [200] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
+  [202] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
+  [204] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
+  [206] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
+  [208] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0),
+  [210] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(79),
+  [213] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
+  [215] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
+  [217] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
+  [219] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
+  [221] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
+  [223] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [225] = {.entry = {.count = 1, .reusable = false}}, SHIFT(28),
+  [227] = {.entry = {.count = 1, .reusable = false}}, SHIFT(88),
+  [229] = {.entry = {.count = 1, .reusable = false}}, SHIFT(89),
+  [231] = {.entry = {.count = 1, .reusable = false}}, SHIFT(126),
+  [233] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 1, 0, 0),
+  [235] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
+  [237] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
+  [239] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
+  [241] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
+  [243] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [245] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(94),
+  [248] = {.entry = {.count = 1, .reusable = false}}, SHIFT(56),
+  [250] = {.entry = {.count = 1, .reusable = false}}, SHIFT(97),
+  [252] = {.entry = {.count = 1, .reusable = false}}, SHIFT(98),
+  [254] = {.entry = {.count = 1, .reusable = false}}, SHIFT(135),
+  [256] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+  [258] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [260] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [262] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
+  [264] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
+  [266] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
+  [268] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
+  [270] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [272] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
+  [274] = {.entry = {.count = 1, .reusable = false}}, SHIFT(34),
+  [276] = {.entry = {.count = 1, .reusable = false}}, SHIFT(90),
+  [278] = {.entry = {.count = 1, .reusable = false}}, SHIFT(127),
+  [280] = {.entry = {.count = 1, .reusable = false}}, SHIFT(106),
+  [282] = {.entry = {.count = 1, .reusable = false}}, SHIFT(35),
+  [284] = {.entry = {.count = 1, .reusable = false}}, SHIFT(109),
+  [286] = {.entry = {.count = 1, .reusable = false}}, SHIFT(128),
+  [288] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 3, 0, 0),
+  [290] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_quoted_attribute_value, 2, 0, 0),
+  [292] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_quoted_attribute_value, 3, 0, 0),
+  [294] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
+  [296] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
+  [298] = {.entry = {.count = 1, .reusable = true}}, SHIFT(114),
+  [300] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
+  [302] = {.entry = {.count = 1, .reusable = false}}, SHIFT(60),
+  [304] = {.entry = {.count = 1, .reusable = false}}, SHIFT(99),
+  [306] = {.entry = {.count = 1, .reusable = false}}, SHIFT(136),
+  [308] = {.entry = {.count = 1, .reusable = false}}, SHIFT(115),
+  [310] = {.entry = {.count = 1, .reusable = false}}, SHIFT(61),
+  [312] = {.entry = {.count = 1, .reusable = false}}, SHIFT(118),
+  [314] = {.entry = {.count = 1, .reusable = false}}, SHIFT(137),
+  [316] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
+  [318] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
+  [320] = {.entry = {.count = 1, .reusable = false}}, SHIFT(18),
+  [322] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
+  [324] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
+  [326] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
+  [328] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__raw_text_start_tag, 3, 0, 2),
+  [330] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__raw_text_start_tag, 4, 0, 2),
+  [332] = {.entry = {.count = 1, .reusable = false}}, SHIFT(37),
+  [334] = {.entry = {.count = 1, .reusable = true}}, SHIFT(129),
+  [336] = {.entry = {.count = 1, .reusable = false}}, SHIFT(92),
+  [338] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
+  [340] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
+  [342] = {.entry = {.count = 1, .reusable = false}}, SHIFT(38),
+  [344] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
+  [346] = {.entry = {.count = 1, .reusable = false}}, SHIFT(48),
+  [348] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [350] = {.entry = {.count = 1, .reusable = false}}, SHIFT(63),
+  [352] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
+  [354] = {.entry = {.count = 1, .reusable = false}}, SHIFT(111),
+  [356] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [358] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
+  [360] = {.entry = {.count = 1, .reusable = false}}, SHIFT(64),
+  [362] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
+  [364] = {.entry = {.count = 1, .reusable = true}}, SHIFT(134),
+  [366] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
+  [368] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
+  [370] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [372] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
+  [374] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
+  [376] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
+  [378] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
+  [380] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
+  [382] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
+  [384] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
+  [386] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
+  [388] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
+  [390] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
+  [392] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
+  [394] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
+  [396] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
+  [398] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
+  [400] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
+  [402] = {.entry = {.count = 1, .reusable = true}}, SHIFT(67),
+  [404] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
 };
 
 enum ts_external_scanner_symbol_identifiers {
@@ -1815,6 +3648,11 @@ enum ts_external_scanner_symbol_identifiers {
   ts_external_token_raw_text = 6,
   ts_external_token_comment = 7,
   ts_external_token_text = 8,
+  ts_external_token_processing_instruction = 9,
+  ts_external_token_bogus_comment = 10,
+  ts_external_token_cdata_section = 11,
+  ts_external_token_cdata = 12,
+  ts_external_token__template_start_tag_name = 13,
 };
 
 static const TSSymbol ts_external_scanner_symbol_map[EXTERNAL_TOKEN_COUNT] = {
@@ -1827,9 +3665,14 @@ static const TSSymbol ts_external_scanner_symbol_map[EXTERNAL_TOKEN_COUNT] = {
   [ts_external_token_raw_text] = sym_raw_text,
   [ts_external_token_comment] = sym_comment,
   [ts_external_token_text] = sym_text,
+  [ts_external_token_processing_instruction] = sym_processing_instruction,
+  [ts_external_token_bogus_comment] = sym_bogus_comment,
+  [ts_external_token_cdata_section] = sym_cdata_section,
+  [ts_external_token_cdata] = sym_cdata,
+  [ts_external_token__template_start_tag_name] = sym__template_start_tag_name,
 };
 
-static const bool ts_external_scanner_states[11][EXTERNAL_TOKEN_COUNT] = {
+static const bool ts_external_scanner_states[12][EXTERNAL_TOKEN_COUNT] = {
   [1] = {
     [ts_external_token__start_tag_name] = true,
     [ts_external_token__raw_text_start_tag_name] = true,
@@ -1840,44 +3683,86 @@ static const bool ts_external_scanner_states[11][EXTERNAL_TOKEN_COUNT] = {
     [ts_external_token_raw_text] = true,
     [ts_external_token_comment] = true,
     [ts_external_token_text] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
+    [ts_external_token_cdata] = true,
+    [ts_external_token__template_start_tag_name] = true,
   },
   [2] = {
     [ts_external_token_comment] = true,
     [ts_external_token_text] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
+    [ts_external_token_cdata] = true,
   },
   [3] = {
     [ts_external_token__implicit_end_tag] = true,
     [ts_external_token_comment] = true,
     [ts_external_token_text] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
+    [ts_external_token_cdata] = true,
   },
   [4] = {
     [ts_external_token_SLASH_GT] = true,
     [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
   },
   [5] = {
     [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
   },
   [6] = {
-    [ts_external_token_raw_text] = true,
+    [ts_external_token__start_tag_name] = true,
+    [ts_external_token__raw_text_start_tag_name] = true,
     [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
+    [ts_external_token__template_start_tag_name] = true,
   },
   [7] = {
-    [ts_external_token__start_tag_name] = true,
-    [ts_external_token__raw_text_start_tag_name] = true,
+    [ts_external_token_raw_text] = true,
     [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
   },
   [8] = {
-    [ts_external_token__end_tag_name] = true,
-    [ts_external_token_erroneous_end_tag_name] = true,
+    [ts_external_token__implicit_end_tag] = true,
     [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
   },
   [9] = {
     [ts_external_token__end_tag_name] = true,
+    [ts_external_token_erroneous_end_tag_name] = true,
     [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
   },
   [10] = {
     [ts_external_token_erroneous_end_tag_name] = true,
     [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
+  },
+  [11] = {
+    [ts_external_token__end_tag_name] = true,
+    [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
   },
 };
 
@@ -1940,7 +3825,7 @@ TS_PUBLIC const TSLanguage *tree_sitter_html(void) {
     .metadata = {
       .major_version = 0,
       .minor_version = 1,
-      .patch_version = 12,
+      .patch_version = 14,
     },
   };
   return &language;
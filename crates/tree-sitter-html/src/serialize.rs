@@ -0,0 +1,272 @@
+//! Writing a tree back out as markup.
+//!
+//! [`print`] rebuilds the source from the tree's tokens and the text between
+//! them, so an unchanged tree reproduces its input byte for byte. That makes
+//! it a safe base for codemods that splice in edited subtrees.
+//!
+//! [`normalize`] prints the same document in a canonical spelling:
+//!
+//! - implied end tags are written out (`<li>a` becomes `<li>a</li>`), except
+//!   on void elements and `<plaintext>`
+//! - HTML tag names are lowercased; SVG and MathML names keep their case
+//! - unquoted attribute values are quoted
+//!
+//! Grammars built on HTML add their own rules through an [`Extension`] passed
+//! to [`normalize_with`].
+//!
+//! ```rust
+//! use tree_sitter_htmlx_html::{serialize, LANGUAGE};
+//!
+//! let source = "<UL class=nav><li>One\n<LI>Two</UL>";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! assert_eq!(serialize::print(tree.root_node(), source), source);
+//! assert_eq!(
+//!     serialize::normalize(tree.root_node(), source),
+//!     "<ul class=\"nav\"><li>One\n</li><li>Two</li></ul>"
+//! );
+//! ```
+
+use tree_sitter::Node;
+
+use crate::is_html_integration_point;
+
+/// The markup of the tree at `root`, exactly as parsed.
+///
+/// Text outside `root`'s own span, such as whitespace before the document,
+/// is included, so printing a root node returns all of `source`.
+pub fn print(root: Node, source: &str) -> String {
+    Printer {
+        source,
+        normalize: false,
+        extension: &Html,
+    }
+    .document(root)
+}
+
+/// The markup of the tree at `root`, with implied end tags written out,
+/// HTML tag names lowercased, and attribute values quoted.
+pub fn normalize(root: Node, source: &str) -> String {
+    normalize_with(root, source, &Html)
+}
+
+/// Like [`normalize`], with the rules `extension` adds for a grammar built
+/// on HTML.
+pub fn normalize_with(root: Node, source: &str, extension: &dyn Extension) -> String {
+    Printer {
+        source,
+        normalize: true,
+        extension,
+    }
+    .document(root)
+}
+
+/// The normalizations a grammar built on HTML adds to [`normalize_with`].
+pub trait Extension {
+    /// Whether the tag `name` is a component, whose name is written as is
+    /// and whose content is never void or foreign.
+    fn is_component(&self, _name: &str) -> bool {
+        false
+    }
+
+    /// Write the canonical form of a node HTML doesn't know and return
+    /// `true`, or return `false` to print it from its children.
+    fn node(&self, _node: Node, _source: &str, _out: &mut String) -> bool {
+        false
+    }
+}
+
+/// Plain HTML, which adds nothing.
+struct Html;
+
+impl Extension for Html {}
+
+struct Printer<'a> {
+    source: &'a str,
+    normalize: bool,
+    extension: &'a dyn Extension,
+}
+
+impl Printer<'_> {
+    fn document(&self, root: Node) -> String {
+        let mut out = String::with_capacity(self.source.len());
+        out.push_str(&self.source[..root.start_byte()]);
+        self.node(root, false, &mut out);
+        out.push_str(&self.source[root.end_byte()..]);
+        out
+    }
+
+    /// Write `node`, where `foreign` is set inside SVG and MathML.
+    fn node(&self, node: Node, foreign: bool, out: &mut String) {
+        if !self.normalize {
+            self.children(node, foreign, out);
+            return;
+        }
+
+        match node.kind() {
+            "element" => self.element(node, foreign, out),
+            "tag_name" => {
+                let name = &self.source[node.byte_range()];
+                if foreign || self.extension.is_component(name) {
+                    out.push_str(name);
+                } else {
+                    out.push_str(&name.to_ascii_lowercase());
+                }
+            }
+            "attribute_value" | "unquoted_attribute_value"
+                if node
+                    .parent()
+                    .is_some_and(|parent| parent.kind() == "attribute") =>
+            {
+                self.quoted(node, foreign, out);
+            }
+            _ if self.extension.node(node, self.source, out) => {}
+            _ => self.children(node, foreign, out),
+        }
+    }
+
+    /// Write `node` from its children and the source text between them.
+    fn children(&self, node: Node, foreign: bool, out: &mut String) {
+        let mut offset = node.start_byte();
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            out.push_str(&self.source[offset..child.start_byte()]);
+            self.node(child, foreign, out);
+            offset = child.end_byte();
+        }
+        out.push_str(&self.source[offset..node.end_byte()]);
+    }
+
+    fn element(&self, element: Node, foreign: bool, out: &mut String) {
+        let Some(start_tag) = element.child(0) else {
+            self.children(element, foreign, out);
+            return;
+        };
+        let name = start_tag
+            .child_by_field_name("name")
+            .map_or("", |name| &self.source[name.byte_range()]);
+        let component = self.extension.is_component(name);
+        let lowercase = name.to_ascii_lowercase();
+
+        let content_foreign = if component {
+            foreign
+        } else if foreign {
+            !is_html_integration_point(name)
+        } else {
+            matches!(lowercase.as_str(), "svg" | "math")
+        };
+        // The `<svg>` and `<math>` tags themselves are read as HTML.
+        let tag_foreign = foreign && !matches!(lowercase.as_str(), "svg" | "math");
+
+        let mut offset = element.start_byte();
+        let mut cursor = element.walk();
+        for child in element.children(&mut cursor) {
+            out.push_str(&self.source[offset..child.start_byte()]);
+            let foreign = match child.kind() {
+                "start_tag" | "end_tag" | "self_closing_tag" => tag_foreign,
+                _ => content_foreign,
+            };
+            self.node(child, foreign, out);
+            offset = child.end_byte();
+        }
+        out.push_str(&self.source[offset..element.end_byte()]);
+
+        let closed = element
+            .children(&mut element.walk())
+            .skip(1)
+            .last()
+            .is_some_and(|last| last.kind() == "end_tag" && !last.is_missing());
+        let implied = start_tag.kind() == "start_tag"
+            && !closed
+            && !name.is_empty()
+            && (component || !is_void(&lowercase))
+            && lowercase != "plaintext";
+        if implied {
+            out.push_str("</");
+            out.push_str(if tag_foreign || component {
+                name
+            } else {
+                &lowercase
+            });
+            out.push('>');
+        }
+    }
+
+    /// Write an unquoted attribute value inside quotes.
+    fn quoted(&self, value: Node, foreign: bool, out: &mut String) {
+        let text = &self.source[value.byte_range()];
+        // An HTMLX value that runs across whitespace (`a=x{b} {c}`) is a
+        // recovery span the browser would split; quoting it would merge them.
+        if text.contains(|c: char| c.is_ascii_whitespace()) {
+            self.children(value, foreign, out);
+            return;
+        }
+
+        // Only the text pieces can be escaped; an HTMLX expression inside the
+        // value has to be written as is, so it decides which quote is free.
+        let mut pieces = Vec::new();
+        let mut offset = value.start_byte();
+        let mut cursor = value.walk();
+        for child in value.children(&mut cursor) {
+            pieces.push((true, self.source[offset..child.start_byte()].to_string()));
+            let mut piece = String::new();
+            self.node(child, foreign, &mut piece);
+            pieces.push((child.kind() == "attribute_value", piece));
+            offset = child.end_byte();
+        }
+        pieces.push((true, self.source[offset..value.end_byte()].to_string()));
+
+        let has = |text: bool, quote: char| {
+            pieces
+                .iter()
+                .any(|(is_text, piece)| *is_text == text && piece.contains(quote))
+        };
+        let quote = if has(false, '"') {
+            if has(false, '\'') {
+                // Neither quote can enclose the value, so leave it unquoted.
+                self.children(value, foreign, out);
+                return;
+            }
+            '\''
+        } else if has(true, '"') && !has(true, '\'') && !has(false, '\'') {
+            '\''
+        } else {
+            '"'
+        };
+        let escaped = if quote == '"' { "&quot;" } else { "&#39;" };
+
+        out.push(quote);
+        for (is_text, piece) in &pieces {
+            if *is_text {
+                out.push_str(&piece.replace(quote, escaped));
+            } else {
+                out.push_str(piece);
+            }
+        }
+        out.push(quote);
+    }
+}
+
+/// Void elements, which never have an end tag (§13.1.2).
+fn is_void(name: &str) -> bool {
+    matches!(
+        name,
+        "area"
+            | "base"
+            | "br"
+            | "col"
+            | "embed"
+            | "hr"
+            | "img"
+            | "input"
+            | "keygen"
+            | "link"
+            | "meta"
+            | "param"
+            | "source"
+            | "track"
+            | "wbr"
+    )
+}
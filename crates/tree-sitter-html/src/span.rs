@@ -0,0 +1,32 @@
+//! Ranges inside a node's text.
+//!
+//! Diagnostics often point at part of a token, such as one character of an
+//! attribute name or the name inside a `&name;` reference. Tree-sitter only
+//! gives ranges for whole nodes, so [`subrange`] works out the byte offsets
+//! and row/column points of a slice of one.
+
+use tree_sitter::{Node, Point, Range};
+
+/// The range of `text[start..end]`, where `text` is the source text of
+/// `node`.
+pub fn subrange(node: Node, text: &str, start: usize, end: usize) -> Range {
+    Range {
+        start_byte: node.start_byte() + start,
+        end_byte: node.start_byte() + end,
+        start_point: advance_point(node.start_position(), &text[..start]),
+        end_point: advance_point(node.start_position(), &text[..end]),
+    }
+}
+
+fn advance_point(start: Point, text: &str) -> Point {
+    match text.rfind('\n') {
+        Some(newline) => Point {
+            row: start.row + text.matches('\n').count(),
+            column: text.len() - newline - 1,
+        },
+        None => Point {
+            row: start.row,
+            column: start.column + text.len(),
+        },
+    }
+}
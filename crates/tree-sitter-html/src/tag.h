@@ -55,6 +55,10 @@ typedef enum {
   // =========================================================================
   SCRIPT,
   STYLE,
+  IFRAME,
+  NOEMBED,
+  NOFRAMES,
+  XMP,
   END_OF_RAW_TEXT_TAGS, // Sentinel for raw text element detection
 
   // =========================================================================
@@ -119,7 +123,6 @@ typedef enum {
   HGROUP,
   HTML,
   I,
-  IFRAME,
   INS,
   KBD,
   LABEL,
@@ -132,7 +135,7 @@ typedef enum {
   MENU,
   METER,
   NAV,
-  NOSCRIPT,
+  NOSCRIPT, // Raw text only when scripting is enabled
   OBJECT,
   OL,
   OPTGROUP,
@@ -140,6 +143,7 @@ typedef enum {
   OUTPUT,
   P,
   PICTURE,
+  PLAINTEXT, // Content is text up to end of input (§13.2.5.7)
   PRE,
   PROGRESS,
   Q,
@@ -282,6 +286,8 @@ static const TagMapEntry TAG_TABLE[] = {
     {"METER", 5, METER},
     // N
     {"NAV", 3, NAV},
+    {"NOEMBED", 7, NOEMBED},
+    {"NOFRAMES", 8, NOFRAMES},
     {"NOSCRIPT", 8, NOSCRIPT},
     // O
     {"OBJECT", 6, OBJECT},
@@ -293,6 +299,7 @@ static const TagMapEntry TAG_TABLE[] = {
     {"P", 1, P},
     {"PARAM", 5, PARAM},
     {"PICTURE", 7, PICTURE},
+    {"PLAINTEXT", 9, PLAINTEXT},
     {"PRE", 3, PRE},
     {"PROGRESS", 8, PROGRESS},
     // Q
@@ -341,6 +348,8 @@ static const TagMapEntry TAG_TABLE[] = {
     {"VIDEO", 5, VIDEO},
     // W
     {"WBR", 3, WBR},
+    // X
+    {"XMP", 3, XMP},
 };
 
 #define TAG_TABLE_SIZE (sizeof(TAG_TABLE) / sizeof(TagMapEntry))
@@ -371,19 +380,19 @@ static const CharBucket CHAR_INDEX[26] = {
     {54, 56},   // K: KBD, KEYGEN (2)
     {56, 60},   // L: LABEL..LINK (4)
     {60, 67},   // M: MAIN..METER (7)
-    {67, 69},   // N: NAV, NOSCRIPT (2)
-    {69, 74},   // O: OBJECT..OUTPUT (5)
-    {74, 79},   // P: P..PROGRESS (5)
-    {79, 80},   // Q: Q (1)
-    {80, 85},   // R: RB..RUBY (5)
-    {85, 101},  // S: S..SVG (16)
-    {101, 113}, // T: TABLE..TRACK (12)
-    {113, 115}, // U: U, UL (2)
-    {115, 117}, // V: VAR, VIDEO (2)
-    {117, 118}, // W: WBR (1)
-    {118, 118}, // X: (none)
-    {118, 118}, // Y: (none)
-    {118, 118}, // Z: (none)
+    {67, 71},   // N: NAV, NOEMBED, NOFRAMES, NOSCRIPT (4)
+    {71, 76},   // O: OBJECT..OUTPUT (5)
+    {76, 82},   // P: P..PROGRESS (6)
+    {82, 83},   // Q: Q (1)
+    {83, 88},   // R: RB..RUBY (5)
+    {88, 104},  // S: S..SVG (16)
+    {104, 116}, // T: TABLE..TRACK (12)
+    {116, 118}, // U: U, UL (2)
+    {118, 120}, // V: VAR, VIDEO (2)
+    {120, 121}, // W: WBR (1)
+    {121, 122}, // X: XMP (1)
+    {122, 122}, // Y: (none)
+    {122, 122}, // Z: (none)
 };
 
 /**
@@ -435,10 +444,11 @@ static inline TagType tag_type_for_name(const String *tag_name) {
  * Per §13.1.2.4 - Optional tags
  */
 static const TagType TAG_TYPES_NOT_ALLOWED_IN_PARAGRAPHS[] = {
-    ADDRESS,    ARTICLE, ASIDE,  BLOCKQUOTE, DETAILS, DIV,   DL,   FIELDSET,
-    FIGCAPTION, FIGURE,  FOOTER, FORM,       H1,      H2,    H3,   H4,
-    H5,         H6,      HEADER, HGROUP,     HR,      MAIN,  MENU, NAV,
-    OL,         P,       PRE,    SEARCH,     SECTION, TABLE, UL,
+    ADDRESS,  ARTICLE,    ASIDE,  BLOCKQUOTE, DETAILS, DIV,       DL,
+    FIELDSET, FIGCAPTION, FIGURE, FOOTER,     FORM,    H1,        H2,
+    H3,       H4,         H5,     H6,         HEADER,  HGROUP,    HR,
+    MAIN,     MENU,       NAV,    OL,         P,       PLAINTEXT, PRE,
+    SEARCH,   SECTION,    TABLE,  UL,         XMP,
 };
 
 #define P_CLOSING_TAGS_SIZE                                                    \
@@ -496,9 +506,114 @@ static inline bool tag_eq(const Tag *self, const Tag *other) {
   return true;
 }
 
+static inline bool name_is(const char *name, uint32_t name_length,
+                           const char *upper, uint32_t length) {
+  if (name_length != length) {
+    return false;
+  }
+  for (uint32_t i = 0; i < length; i++) {
+    char c = name[i];
+    if (c >= 'a' && c <= 'z') {
+      c = (char)(c - 32);
+    }
+    if (c != upper[i]) {
+      return false;
+    }
+  }
+  return true;
+}
+
+static inline bool tag_custom_name_is(const Tag *self, const char *upper,
+                                      uint32_t length) {
+  return self->type == CUSTOM &&
+         name_is(self->custom_tag_name.contents, self->custom_tag_name.size,
+                 upper, length);
+}
+
+/**
+ * Re-tag an element opened in foreign content
+ * Per §13.2.6.5 - SVG and MathML elements have no void, raw text, or
+ * optional-end-tag rules. Tracking them as custom tags turns those HTML rules
+ * off; <svg> and <math> keep their type so the content can be found again.
+ */
+static inline Tag tag_for_foreign_content(Tag tag) {
+  if (tag.type == CUSTOM || tag.type == SVG || tag.type == MATH) {
+    return tag;
+  }
+
+  Tag foreign = tag_new();
+  foreign.type = CUSTOM;
+  for (unsigned i = 0; i < sizeof(TAG_TABLE) / sizeof(TAG_TABLE[0]); i++) {
+    if (TAG_TABLE[i].tag_type == tag.type) {
+      array_extend(&foreign.custom_tag_name, TAG_TABLE[i].length,
+                   TAG_TABLE[i].tag_name);
+      break;
+    }
+  }
+  return foreign;
+}
+
+/**
+ * Foreign elements whose children follow HTML rules again
+ * Per §13.2.6.5 - the HTML integration points (SVG foreignObject, desc, and
+ * title, and MathML annotation-xml) and the MathML text integration points
+ * (mi, mo, mn, ms, and mtext). annotation-xml is only an integration point
+ * with an HTML `encoding`, which the scanner does not see, so it always is.
+ *
+ * This is the only copy of the list: the Rust crate reads it through
+ * `tree_sitter_html_is_html_integration_point`.
+ */
+static const struct {
+  const char *name;
+  uint8_t length;
+} HTML_INTEGRATION_POINTS[] = {
+    {"FOREIGNOBJECT", 13}, {"DESC", 4}, {"TITLE", 5},
+    {"ANNOTATION-XML", 14}, {"MI", 2},  {"MO", 2},
+    {"MN", 2},             {"MS", 2},   {"MTEXT", 5},
+};
+
+static inline bool name_is_html_integration_point(const char *name,
+                                                  uint32_t length) {
+  for (unsigned i = 0; i < sizeof(HTML_INTEGRATION_POINTS) /
+                               sizeof(HTML_INTEGRATION_POINTS[0]);
+       i++) {
+    if (name_is(name, length, HTML_INTEGRATION_POINTS[i].name,
+                HTML_INTEGRATION_POINTS[i].length)) {
+      return true;
+    }
+  }
+  return false;
+}
+
+static inline bool tag_is_html_integration_point(const Tag *self) {
+  return self->type == CUSTOM &&
+         name_is_html_integration_point(self->custom_tag_name.contents,
+                                        self->custom_tag_name.size);
+}
+
+/**
+ * The tag type of `name`, compared ASCII case-insensitively. Names longer
+ * than any built-in tag are custom.
+ */
+static inline TagType name_tag_type(const char *name, uint32_t length) {
+  char upper[10];
+  if (length > sizeof(upper)) {
+    return CUSTOM;
+  }
+  for (uint32_t i = 0; i < length; i++) {
+    char c = name[i];
+    upper[i] = c >= 'a' && c <= 'z' ? c - ('a' - 'A') : c;
+  }
+  String string = {.contents = upper, .size = length, .capacity = length};
+  return tag_type_for_name(&string);
+}
+
 /**
  * Determines if a parent element can contain a child element
  * Based on HTML content model rules (§4)
+ *
+ * The Rust crate's `content_model::can_contain` reads these rules through
+ * `tree_sitter_html_tag_can_contain`.
  */
 static inline bool tag_can_contain(Tag *self, const Tag *other) {
   TagType child = other->type;
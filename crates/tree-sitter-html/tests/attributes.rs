@@ -0,0 +1,66 @@
+use tree_sitter_htmlx_html::attributes::{self, Diagnostic, DiagnosticKind};
+use tree_sitter_htmlx_html::LANGUAGE;
+
+fn check(source: &str) -> Vec<Diagnostic> {
+    let mut parser = tree_sitter::Parser::new();
+    parser.set_language(&LANGUAGE.into()).unwrap();
+    let tree = parser.parse(source, None).unwrap();
+    attributes::check(tree.root_node(), source)
+}
+
+fn text(source: &str, range: tree_sitter::Range) -> &str {
+    &source[range.start_byte..range.end_byte]
+}
+
+#[test]
+fn test_duplicates_are_case_insensitive() {
+    let source = "<div id=a ID=b Id=c class=x></div>";
+    let found = check(source);
+    assert_eq!(found.len(), 2);
+    for diagnostic in &found {
+        let DiagnosticKind::DuplicateAttribute { first, .. } = diagnostic.kind else {
+            panic!("expected a duplicate, got {diagnostic:?}");
+        };
+        assert_eq!(first.start_byte, 5);
+    }
+    assert_eq!(text(source, found[0].range), "ID");
+    assert_eq!(text(source, found[1].range), "Id");
+    assert_eq!(found[1].to_string(), "duplicate attribute `Id`");
+}
+
+#[test]
+fn test_duplicates_on_self_closing_and_foreign_tags() {
+    let source = "<svg viewBox=1 viewbox=2><path d=a d=b /></svg>";
+    let found = check(source);
+    let names: Vec<_> = found.iter().map(|d| text(source, d.range)).collect();
+    assert_eq!(names, ["viewbox", "d"]);
+}
+
+#[test]
+fn test_distinct_names_are_not_reported() {
+    for source in [
+        "<p id=a class=b data-id=c>x</p>",
+        "<input type=checkbox checked disabled>",
+        "<a href=x>a</a><a href=y>b</a>",
+    ] {
+        assert!(check(source).is_empty(), "{source}: {:#?}", check(source));
+    }
+}
+
+#[test]
+fn test_invalid_name_points_at_the_character() {
+    let source = "<p\n a\u{1}b=1 c\u{fdd0}=2>x</p>";
+    let found = check(source);
+    assert_eq!(found.len(), 2);
+    assert_eq!(
+        found[0].kind,
+        DiagnosticKind::InvalidAttributeName {
+            name: "a\u{1}b".to_string(),
+            character: '\u{1}',
+        }
+    );
+    assert_eq!(text(source, found[0].range), "\u{1}");
+    assert_eq!(found[0].range.start_point.row, 1);
+    assert_eq!(found[0].range.start_point.column, 2);
+    assert_eq!(text(source, found[1].range), "\u{fdd0}");
+}
@@ -0,0 +1,151 @@
+use std::fs;
+
+use tree_sitter_htmlx_html::content_model::{self, can_contain, Diagnostic, DiagnosticKind};
+use tree_sitter_htmlx_html::LANGUAGE;
+
+fn check(source: &str) -> Vec<Diagnostic> {
+    let mut parser = tree_sitter::Parser::new();
+    parser.set_language(&LANGUAGE.into()).unwrap();
+    let tree = parser.parse(source, None).unwrap();
+    content_model::check(tree.root_node(), source)
+}
+
+fn text(source: &str, range: tree_sitter::Range) -> &str {
+    &source[range.start_byte..range.end_byte]
+}
+
+#[test]
+fn test_paragraph_table_matches_tag_h() {
+    let tag_h = fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/src/tag.h")).unwrap();
+    let table = tag_h
+        .split_once("TAG_TYPES_NOT_ALLOWED_IN_PARAGRAPHS[] = {")
+        .and_then(|(_, rest)| rest.split_once("};"))
+        .unwrap()
+        .0;
+    let closes: Vec<String> = table
+        .split(',')
+        .map(|name| name.trim().to_ascii_lowercase())
+        .filter(|name| !name.is_empty())
+        .collect();
+    assert!(closes.contains(&"div".to_string()));
+
+    let names = tag_h
+        .split_once("TAG_TABLE[] = {")
+        .and_then(|(_, rest)| rest.split_once("};"))
+        .unwrap()
+        .0
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("{\""))
+        .filter_map(|line| line.split_once('"'))
+        .map(|(name, _)| name.to_ascii_lowercase());
+    for name in names {
+        assert_eq!(
+            can_contain("p", &name),
+            !closes.contains(&name),
+            "<p> followed by <{name}>"
+        );
+    }
+}
+
+#[test]
+fn test_can_contain_follows_optional_end_tags() {
+    assert!(!can_contain("li", "LI"));
+    assert!(!can_contain("dd", "dt"));
+    assert!(!can_contain("td", "tr"));
+    assert!(!can_contain("colgroup", "tr"));
+    assert!(can_contain("p", "span"));
+    assert!(can_contain("div", "div"));
+}
+
+#[test]
+fn test_stray_end_tags_after_implicit_close() {
+    let source = "<p>a<div>b</div></p>\n<ul><li>x<ul><li>y</ul></li></ul>";
+    let found = check(source);
+    assert_eq!(found.len(), 1, "{found:#?}");
+    let DiagnosticKind::ClosedImplicitly {
+        element,
+        closed_by,
+        start_tag,
+        closer,
+    } = &found[0].kind
+    else {
+        panic!("expected an implicit close, got {:?}", found[0]);
+    };
+    assert_eq!((element.as_str(), closed_by.as_str()), ("p", "div"));
+    assert_eq!(text(source, found[0].range), "</p>");
+    assert_eq!(text(source, *start_tag), "<p>");
+    assert_eq!(text(source, *closer), "<div>");
+
+    let nested = "<section><P>a<table></table></p></section>";
+    let found = check(nested);
+    assert_eq!(found.len(), 1, "{found:#?}");
+    assert_eq!(text(nested, found[0].range), "</p>");
+    assert_eq!(
+        found[0].to_string(),
+        "`</p>` has no open `<p>` to close: `<table>` closed it implicitly"
+    );
+}
+
+#[test]
+fn test_optional_end_tags_alone_are_not_reported() {
+    for source in [
+        "<p>a<div>b</div>",
+        "<ul><li>a<li>b</ul>",
+        "<dl><dt>a<dd>b</dl>",
+        "<table><tr><td>a<td>b</table>",
+        "<nav><ul><li><a href=x>x</a></li></ul></nav>",
+        "<a href=x><span>go</span></a><button><img src=x></button>",
+        "<button><input type=hidden name=x>go</button>",
+        "<svg><a href=x><a href=y></a></a></svg>",
+    ] {
+        assert!(check(source).is_empty(), "{source}: {:#?}", check(source));
+    }
+}
+
+#[test]
+fn test_excluded_descendants() {
+    let source = "<a href=x><div><a href=y>in</a></div></a><button><label>l</label><input></button><form><form></form></form><a href=z><video controls></video></a>";
+    let found = check(source);
+    let reported: Vec<_> = found
+        .iter()
+        .map(|diagnostic| (text(source, diagnostic.range), diagnostic.to_string()))
+        .collect();
+    assert_eq!(
+        reported,
+        [
+            ("<a href=y>", "`<a>` cannot be inside `<a>`".to_string()),
+            (
+                "<label>",
+                "`<label>` cannot be inside `<button>`".to_string()
+            ),
+            (
+                "<input>",
+                "`<input>` cannot be inside `<button>`".to_string()
+            ),
+            ("<form>", "`<form>` cannot be inside `<form>`".to_string()),
+            (
+                "<video controls>",
+                "`<video>` cannot be inside `<a>`".to_string()
+            ),
+        ]
+    );
+    let DiagnosticKind::InvalidDescendant {
+        ancestor_start_tag, ..
+    } = found[0].kind
+    else {
+        panic!("expected an invalid descendant");
+    };
+    assert_eq!(text(source, ancestor_start_tag), "<a href=x>");
+}
+
+#[test]
+fn test_list_item_outside_list() {
+    let source = "<div><li>a</li></div><ol><li>b</li></ol><menu><li>c</menu><li>top</li>";
+    let found = check(source);
+    assert_eq!(found.len(), 1, "{found:#?}");
+    assert_eq!(text(source, found[0].range), "<li>");
+    assert_eq!(
+        found[0].to_string(),
+        "`<li>` must be inside `<ul>`, `<ol>`, or `<menu>`, not `<div>`"
+    );
+}
@@ -0,0 +1,114 @@
+use tree_sitter_htmlx_html::doctype::{self, Doctype, QuirksMode};
+use tree_sitter_htmlx_html::LANGUAGE;
+
+fn parse(source: &str) -> tree_sitter::Tree {
+    let mut parser = tree_sitter::Parser::new();
+    parser.set_language(&LANGUAGE.into()).unwrap();
+    parser.parse(source, None).unwrap()
+}
+
+fn read(text: &str) -> Doctype {
+    let tree = parse(text);
+    let node = tree.root_node().named_child(0).unwrap();
+    Doctype::from_node(node, text).unwrap()
+}
+
+fn document_mode(source: &str) -> QuirksMode {
+    doctype::quirks_mode(parse(source).root_node(), source)
+}
+
+#[test]
+fn test_parse_fields() {
+    let doctype = read(
+        r#"<!DOCTYPE html PUBLIC "-//W3C//DTD XHTML 1.0 Strict//EN" 'http://www.w3.org/TR/xhtml1/DTD/xhtml1-strict.dtd'>"#,
+    );
+    assert_eq!(doctype.name.as_deref(), Some("html"));
+    assert_eq!(
+        doctype.public_id.as_deref(),
+        Some("-//W3C//DTD XHTML 1.0 Strict//EN")
+    );
+    assert_eq!(
+        doctype.system_id.as_deref(),
+        Some("http://www.w3.org/TR/xhtml1/DTD/xhtml1-strict.dtd")
+    );
+    assert!(!doctype.force_quirks);
+
+    let system = read(r#"<!doctype HTML system "about:legacy-compat">"#);
+    assert_eq!(system.name.as_deref(), Some("html"));
+    assert_eq!(system.public_id, None);
+    assert_eq!(system.system_id.as_deref(), Some("about:legacy-compat"));
+}
+
+#[test]
+fn test_malformed_doctypes_force_quirks() {
+    for text in [
+        "<!DOCTYPE>",
+        "<!DOCTYPE html bogus>",
+        "<!DOCTYPE html PUBLIC>",
+        r#"<!DOCTYPE html PUBLIC "unterminated>"#,
+        r#"<!DOCTYPE html PUBLIC "a" b>"#,
+        "<!DOCTYPE html",
+    ] {
+        let doctype = read(text);
+        assert!(doctype.force_quirks, "{text}");
+        assert_eq!(doctype.quirks_mode(), QuirksMode::Quirks, "{text}");
+    }
+
+    // Trailing junk after the system identifier is an error, but does not
+    // force quirks mode.
+    assert!(!read(r#"<!DOCTYPE html SYSTEM "a" junk>"#).force_quirks);
+}
+
+#[test]
+fn test_quirks_mode_table() {
+    let mode = |text: &str| read(text).quirks_mode();
+
+    assert_eq!(mode("<!DOCTYPE html>"), QuirksMode::NoQuirks);
+    assert_eq!(mode("<!DOCTYPE svg>"), QuirksMode::Quirks);
+    assert_eq!(
+        mode(
+            r#"<!DOCTYPE html PUBLIC "-//W3C//DTD HTML 4.01//EN" "http://www.w3.org/TR/html4/strict.dtd">"#
+        ),
+        QuirksMode::NoQuirks
+    );
+    assert_eq!(
+        mode(r#"<!DOCTYPE html PUBLIC "-//IETF//DTD HTML 2.0//EN">"#),
+        QuirksMode::Quirks
+    );
+    assert_eq!(mode(r#"<!DOCTYPE html PUBLIC "html">"#), QuirksMode::Quirks);
+    assert_eq!(
+        mode(
+            r#"<!DOCTYPE html SYSTEM "http://www.ibm.com/data/dtd/v11/ibmxhtml1-transitional.dtd">"#
+        ),
+        QuirksMode::Quirks
+    );
+
+    // HTML 4.01 Transitional depends on the system identifier.
+    assert_eq!(
+        mode(r#"<!DOCTYPE html PUBLIC "-//W3C//DTD HTML 4.01 Transitional//EN">"#),
+        QuirksMode::Quirks
+    );
+    assert_eq!(
+        mode(
+            r#"<!DOCTYPE html PUBLIC "-//W3C//DTD HTML 4.01 Transitional//EN" "http://www.w3.org/TR/html4/loose.dtd">"#
+        ),
+        QuirksMode::LimitedQuirks
+    );
+    assert_eq!(
+        mode(
+            r#"<!DOCTYPE html PUBLIC "-//W3C//DTD XHTML 1.0 Transitional//EN" "http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd">"#
+        ),
+        QuirksMode::LimitedQuirks
+    );
+}
+
+#[test]
+fn test_document_quirks_mode() {
+    assert_eq!(
+        document_mode("<!-- generated -->\n<!DOCTYPE html>\n<p>x</p>"),
+        QuirksMode::NoQuirks
+    );
+    assert_eq!(document_mode("<p>x</p>"), QuirksMode::Quirks);
+    // A DOCTYPE after content is ignored.
+    assert_eq!(document_mode("<p>x</p><!DOCTYPE html>"), QuirksMode::Quirks);
+}
@@ -0,0 +1,132 @@
+use tree_sitter_htmlx_html::entities::{
+    check, decode_attribute, decode_entity, decode_text, decoded_text, DiagnosticKind,
+};
+use tree_sitter_htmlx_html::LANGUAGE;
+
+fn parse(source: &str) -> tree_sitter::Tree {
+    let mut parser = tree_sitter::Parser::new();
+    parser.set_language(&LANGUAGE.into()).unwrap();
+    parser.parse(source, None).unwrap()
+}
+
+fn find<'tree>(node: tree_sitter::Node<'tree>, kind: &str) -> Option<tree_sitter::Node<'tree>> {
+    if node.kind() == kind {
+        return Some(node);
+    }
+    let mut cursor = node.walk();
+    let children: Vec<_> = node.children(&mut cursor).collect();
+    children.into_iter().find_map(|child| find(child, kind))
+}
+
+#[test]
+fn test_decode_entity_named_references() {
+    assert_eq!(decode_entity("&amp;").as_deref(), Some("&"));
+    assert_eq!(decode_entity("&AMP;").as_deref(), Some("&"));
+    assert_eq!(decode_entity("&hearts;").as_deref(), Some("\u{2665}"));
+    // Some references expand to two code points.
+    assert_eq!(
+        decode_entity("&NotEqualTilde;").as_deref(),
+        Some("\u{2242}\u{338}")
+    );
+    // Legacy references may omit the `;`.
+    assert_eq!(decode_entity("&copy").as_deref(), Some("\u{a9}"));
+    assert_eq!(decode_entity("&hearts"), None);
+    assert_eq!(decode_entity("&notanentity;"), None);
+    assert_eq!(decode_entity("&ampx"), None);
+    assert_eq!(decode_entity("amp;"), None);
+}
+
+#[test]
+fn test_decode_entity_numeric_references() {
+    assert_eq!(decode_entity("&#65;").as_deref(), Some("A"));
+    assert_eq!(decode_entity("&#x1F600;").as_deref(), Some("\u{1F600}"));
+    assert_eq!(decode_entity("&#X41").as_deref(), Some("A"));
+    // NUL, surrogates, and out-of-range values become U+FFFD.
+    assert_eq!(decode_entity("&#0;").as_deref(), Some("\u{FFFD}"));
+    assert_eq!(decode_entity("&#xD800;").as_deref(), Some("\u{FFFD}"));
+    assert_eq!(decode_entity("&#x110000;").as_deref(), Some("\u{FFFD}"));
+    // C1 controls map to their Windows-1252 characters.
+    assert_eq!(decode_entity("&#x80;").as_deref(), Some("\u{20AC}"));
+    assert_eq!(decode_entity("&#;"), None);
+}
+
+#[test]
+fn test_decode_text_uses_longest_legacy_prefix() {
+    assert_eq!(decode_text("&notit; &lt;&gt"), "\u{ac}it; <>");
+    assert_eq!(decode_text("a & b &bogus;"), "a & b &bogus;");
+}
+
+#[test]
+fn test_decode_attribute_keeps_ambiguous_references() {
+    assert_eq!(decode_attribute("?a=1&copy=2&amp;b"), "?a=1&copy=2&b");
+    assert_eq!(decode_attribute("&copy 2024"), "\u{a9} 2024");
+}
+
+#[test]
+fn test_decoded_text_for_nodes() {
+    let source =
+        r#"<a title="x&ampy=1&lt;">&copy;</a><title>&lt;b&gt;</title><script>&lt;</script>"#;
+    let tree = parse(source);
+    let root = tree.root_node();
+
+    let value = find(root, "attribute_value").unwrap();
+    assert_eq!(decoded_text(value, source), "x&ampy=1<");
+
+    let entity = find(root, "entity").unwrap();
+    assert_eq!(decoded_text(entity, source), "\u{a9}");
+
+    let title = root
+        .child(1)
+        .and_then(|title| find(title, "raw_text"))
+        .unwrap();
+    assert_eq!(decoded_text(title, source), "<b>");
+
+    let script = root
+        .child(2)
+        .and_then(|script| find(script, "raw_text"))
+        .unwrap();
+    assert_eq!(decoded_text(script, source), "&lt;");
+}
+
+#[test]
+fn test_check_reports_unknown_and_unterminated_references() {
+    let source = "<p class=\"&bogus;\">&amp &notanentity;\n&#0;</p>";
+    let tree = parse(source);
+    let found = check(tree.root_node(), source);
+
+    assert_eq!(found.len(), 4);
+    assert_eq!(
+        found[0].kind,
+        DiagnosticKind::UnknownNamedReference {
+            reference: "&bogus;".to_string()
+        }
+    );
+    assert_eq!(
+        found[1].kind,
+        DiagnosticKind::MissingSemicolon {
+            reference: "&amp".to_string()
+        }
+    );
+    // `&not` is a legacy reference, so `&notanentity;` is `¬anentity;`.
+    assert_eq!(
+        found[2].to_string(),
+        "character reference `&not` is missing a `;`"
+    );
+    assert_eq!(
+        found[3].to_string(),
+        "character reference `&#0;` refers to U+0000, which is not allowed"
+    );
+    assert_eq!(found[3].range.start_point.row, 1);
+    assert_eq!(found[3].range.start_point.column, 0);
+    assert_eq!(
+        &source[found[3].range.start_byte..found[3].range.end_byte],
+        "&#0;"
+    );
+}
+
+#[test]
+fn test_check_accepts_valid_references() {
+    let source = "<a href=\"?a=1&copy=2\">&lt;&#x41;&hearts;</a>";
+    let tree = parse(source);
+    assert!(check(tree.root_node(), source).is_empty());
+}
@@ -0,0 +1,47 @@
+//! `<noscript>` parsing with scripting enabled and disabled.
+
+use tree_sitter_htmlx_html::{LANGUAGE, LANGUAGE_SCRIPTING};
+use tree_sitter_language::LanguageFn;
+
+fn parse(language: LanguageFn, source: &str) -> String {
+    let mut parser = tree_sitter::Parser::new();
+    parser.set_language(&language.into()).unwrap();
+    parser.parse(source, None).unwrap().root_node().to_sexp()
+}
+
+#[test]
+fn test_noscript_follows_scripting_flag() {
+    let source = "<noscript><p>x</p></noscript>";
+
+    assert_eq!(
+        parse(LANGUAGE, source),
+        "(document (element (start_tag name: (tag_name)) (element (start_tag name: (tag_name)) (text) (end_tag name: (tag_name))) (end_tag name: (tag_name))))"
+    );
+    assert_eq!(
+        parse(LANGUAGE_SCRIPTING, source),
+        "(document (element (start_tag name: (tag_name)) (raw_text) (end_tag name: (tag_name))))"
+    );
+}
+
+#[test]
+fn test_scripting_flag_is_per_parser() {
+    let source = "<noscript><p>x</p></noscript>";
+    let threads: Vec<_> = (0..8)
+        .map(|index| {
+            std::thread::spawn(move || {
+                let scripting = index % 2 == 0;
+                let language = if scripting {
+                    LANGUAGE_SCRIPTING
+                } else {
+                    LANGUAGE
+                };
+                (scripting, parse(language, source).contains("(raw_text)"))
+            })
+        })
+        .collect();
+
+    for thread in threads {
+        let (scripting, raw_text) = thread.join().unwrap();
+        assert_eq!(scripting, raw_text);
+    }
+}
@@ -0,0 +1,35 @@
+//! Tests for the HTML serializer, run over the tree-construction cases.
+
+mod utils;
+
+use tree_sitter_htmlx_html::serialize;
+use utils::{dump, parse, read_cases, read_divergences};
+
+#[test]
+fn test_serialize_round_trip() {
+    let divergences = read_divergences();
+    let mut failures = Vec::new();
+    for case in read_cases() {
+        let tree = parse(&case.data);
+        let printed = serialize::print(tree.root_node(), &case.data);
+        if printed != case.data {
+            failures.push(format!("{}: print changed\n{printed}", case.id));
+            continue;
+        }
+
+        let normalized = serialize::normalize(tree.root_node(), &case.data);
+        if divergences.contains(&case.id) || dump(&case.data).as_deref() != Ok(&case.document) {
+            continue;
+        }
+        let rebuilt = dump(&normalized);
+        if rebuilt.as_deref() != Ok(case.document.as_str()) {
+            failures.push(format!(
+                "{}: normalizing changed the document\n#data\n{}\n#normalized\n{normalized}\n#actual\n{}",
+                case.id,
+                case.data,
+                rebuilt.unwrap_or_else(|error| error)
+            ));
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+}
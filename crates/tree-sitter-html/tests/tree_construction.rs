@@ -0,0 +1,121 @@
+//! Tree-construction runner over the cases in `tests/tree-construction`.
+//!
+//! Reads every `tests/tree-construction/cases/*.dat` file, parses each
+//! `#data` section, converts the tree-sitter tree into the `#document` dump
+//! format of html5lib-tests with [`utils::dump`], and compares it with the
+//! expected dump. The cases are written for this crate, not taken from
+//! html5lib-tests, so passing them says nothing about html5lib conformance.
+//!
+//! Cases that are known to diverge are listed by `file.dat:index` (1-based)
+//! in `tests/tree-construction/known_divergences.txt`. The test fails when a case
+//! outside the list fails, and when a listed case starts passing, so the list
+//! always matches reality. Fragment (`#document-fragment`) and `#script-on`
+//! cases are skipped.
+//!
+//! With `TREE_CONSTRUCTION_UPDATE_DIVERGENCES=1`, the test instead rewrites the list:
+//! cases that now pass or no longer exist are removed, and new failures are
+//! appended under a heading to be sorted into reasons by hand.
+//!
+//! See `tests/tree-construction/README.md` for how the cases are written.
+
+mod utils;
+
+use std::collections::BTreeSet;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use utils::{dump, read_cases, read_divergences, CASES_DIR, DIVERGENCES};
+
+#[test]
+fn test_tree_construction() {
+    assert!(Path::new(CASES_DIR).is_dir(), "missing {CASES_DIR}");
+    let cases = read_cases();
+    let divergences = read_divergences();
+    assert!(!cases.is_empty(), "no tree-construction cases found");
+
+    let mut passed = 0;
+    let mut unexpected = Vec::new();
+    let mut unexpected_ids = Vec::new();
+    let mut fixed = Vec::new();
+    for case in &cases {
+        let result = dump(&case.data);
+        let ok = result.as_deref() == Ok(case.document.as_str());
+        let known = divergences.contains(&case.id);
+        println!(
+            "{} {}",
+            match (ok, known) {
+                (true, _) => "PASS",
+                (false, true) => "KNOWN",
+                (false, false) => "FAIL",
+            },
+            case.id
+        );
+
+        if ok {
+            passed += 1;
+            if known {
+                fixed.push(case.id.clone());
+            }
+        } else if !known {
+            unexpected_ids.push(case.id.clone());
+            let actual = result.unwrap_or_else(|error| error);
+            unexpected.push(format!(
+                "{}\n#data\n{}\n#expected\n{}\n#actual\n{}",
+                case.id, case.data, case.document, actual
+            ));
+        }
+    }
+
+    let unknown: Vec<_> = divergences
+        .iter()
+        .filter(|id| !cases.iter().any(|case| case.id == **id))
+        .collect();
+    println!("tree construction: {passed}/{} passed", cases.len());
+
+    if env::var_os("TREE_CONSTRUCTION_UPDATE_DIVERGENCES").is_some() {
+        let stale: BTreeSet<_> = fixed.iter().chain(unknown.iter().copied()).collect();
+        update_divergences(&stale, &unexpected_ids);
+        return;
+    }
+
+    assert!(
+        unexpected.is_empty(),
+        "unexpected tree-construction failures:\n\n{}",
+        unexpected.join("\n\n")
+    );
+    assert!(
+        fixed.is_empty(),
+        "known divergences now pass, remove them from {DIVERGENCES}: {fixed:?}"
+    );
+    assert!(
+        unknown.is_empty(),
+        "{DIVERGENCES} lists cases that do not exist: {unknown:?}"
+    );
+}
+
+/// Rewrite the divergence list without the `stale` ids and with `failing`
+/// appended, keeping the comments and grouping of the rest.
+fn update_divergences(stale: &BTreeSet<&String>, failing: &[String]) {
+    let contents = fs::read_to_string(DIVERGENCES).unwrap_or_default();
+    let mut lines: Vec<_> = contents
+        .lines()
+        .filter(|line| {
+            let id = line.split('#').next().unwrap_or_default().trim();
+            !stale.iter().any(|stale| stale.as_str() == id)
+        })
+        .map(str::to_string)
+        .collect();
+    if !failing.is_empty() {
+        lines.push(String::new());
+        lines.push("# Not yet classified.".to_string());
+        lines.extend(failing.iter().cloned());
+    }
+    fs::write(DIVERGENCES, lines.join("\n") + "\n")
+        .unwrap_or_else(|error| panic!("failed to write {DIVERGENCES}: {error}"));
+    println!(
+        "updated {DIVERGENCES}: removed {}, added {}",
+        stale.len(),
+        failing.len()
+    );
+}
@@ -0,0 +1,465 @@
+//! Tree-construction harness shared by the tree-construction and serializer
+//! tests. Cases use the html5lib-tests `.dat` format.
+//!
+//! tree-sitter-html builds the tree the markup spells out: it closes elements
+//! through optional end tags (the hidden `_implicit_end_tag`), but it does not
+//! run the full tree builder. [`dump`] therefore synthesizes the implied
+//! `<html>`, `<head>`, and `<body>` elements the way the "before html" through
+//! "in body" insertion modes would, and decodes character references, so the
+//! remaining differences are real parsing differences.
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::fs;
+
+use tree_sitter_htmlx_html::doctype::Doctype;
+use tree_sitter_htmlx_html::entities::{decode_attribute, decode_text};
+use tree_sitter_htmlx_html::{is_html_integration_point, LANGUAGE};
+
+pub const CASES_DIR: &str = "tests/tree-construction/cases";
+pub const DIVERGENCES: &str = "tests/tree-construction/known_divergences.txt";
+
+/// Elements the "in head" insertion mode keeps in `<head>`.
+const HEAD_ELEMENTS: &[&str] = &[
+    "base", "basefont", "bgsound", "link", "meta", "noframes", "script", "style", "template",
+    "title",
+];
+
+/// Elements whose raw text still decodes character references.
+const ESCAPABLE_RAW_TEXT: &[&str] = &["textarea", "title"];
+
+pub struct Case {
+    /// `file.dat:index`, 1-based.
+    pub id: String,
+    pub data: String,
+    pub document: String,
+}
+
+#[derive(Debug)]
+enum Dom {
+    Doctype(String),
+    Comment(String),
+    Text(String),
+    Element {
+        name: String,
+        attributes: Vec<(String, String)>,
+        children: Vec<Dom>,
+    },
+    /// The document fragment holding a `<template>`'s contents.
+    Content(Vec<Dom>),
+}
+
+pub fn read_cases() -> Vec<Case> {
+    let mut paths: Vec<_> = fs::read_dir(CASES_DIR)
+        .expect("tree-construction cases directory should exist")
+        .map(|entry| entry.expect("directory entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "dat"))
+        .collect();
+    paths.sort();
+
+    let mut cases = Vec::new();
+    for path in paths {
+        let file = path.file_name().unwrap().to_string_lossy().into_owned();
+        let contents = fs::read_to_string(&path)
+            .unwrap_or_else(|error| panic!("failed to read {}: {error}", path.display()));
+        let contents = format!("\n{}", contents.replace("\r\n", "\n"));
+
+        for (index, chunk) in contents.split("\n#data\n").skip(1).enumerate() {
+            let id = format!("{file}:{}", index + 1);
+            if chunk.contains("\n#document-fragment\n") || chunk.contains("\n#script-on\n") {
+                continue;
+            }
+            let (data, rest) = chunk
+                .split_once("\n#errors\n")
+                .unwrap_or_else(|| panic!("{id}: missing #errors section"));
+            let (_, document) = rest
+                .split_once("#document\n")
+                .unwrap_or_else(|| panic!("{id}: missing #document section"));
+            cases.push(Case {
+                id,
+                data: data.to_string(),
+                document: document.trim_end_matches('\n').to_string(),
+            });
+        }
+    }
+    cases
+}
+
+pub fn read_divergences() -> BTreeSet<String> {
+    let contents = fs::read_to_string(DIVERGENCES).unwrap_or_default();
+    contents
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+pub fn parse(source: &str) -> tree_sitter::Tree {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&LANGUAGE.into())
+        .expect("Failed to load HTML grammar");
+    parser.parse(source, None).expect("Failed to parse")
+}
+
+/// Convert a parsed document into its html5lib dump.
+pub fn dump(source: &str) -> Result<String, String> {
+    let tree = parse(source);
+    let root = tree.root_node();
+    if root.has_error() {
+        return Err(format!("parse error: {}", root.to_sexp()));
+    }
+
+    let nodes = children(root, source, None);
+    let mut out = String::new();
+    for node in synthesize(nodes) {
+        write_node(&node, 0, &mut out);
+    }
+    Ok(out.trim_end_matches('\n').to_string())
+}
+
+fn children(node: tree_sitter::Node, source: &str, namespace: Option<&'static str>) -> Vec<Dom> {
+    let mut nodes: Vec<Dom> = Vec::new();
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        let converted = match child.kind() {
+            "doctype" => Dom::Doctype(doctype(child, source)),
+            "comment" => {
+                let text = &source[child.byte_range()];
+                let data = text.strip_prefix("<!--").unwrap_or(text);
+                Dom::Comment(data.strip_suffix("-->").unwrap_or(data).to_string())
+            }
+            // Bogus comments keep everything between `<!` (or `<` before
+            // `?`) and `>`.
+            "processing_instruction" | "bogus_comment" | "cdata_section" => {
+                let text = &source[child.byte_range()];
+                let data = text.strip_prefix("<!").unwrap_or(&text[1..]);
+                Dom::Comment(data.strip_suffix('>').unwrap_or(data).to_string())
+            }
+            "text" => Dom::Text(decode_text(&source[child.byte_range()]).into_owned()),
+            "cdata" => {
+                let text = &source[child.byte_range()];
+                let data = text.strip_prefix("<![CDATA[").unwrap_or(text);
+                Dom::Text(data.strip_suffix("]]>").unwrap_or(data).to_string())
+            }
+            "entity" => Dom::Text(decode_text(&source[child.byte_range()]).into_owned()),
+            "element" => element(child, source, namespace),
+            "template_content" => Dom::Content(children(child, source, namespace)),
+            _ => continue,
+        };
+        // Adjacent text and entity pieces form one text node.
+        if let (Some(Dom::Text(previous)), Dom::Text(text)) = (nodes.last_mut(), &converted) {
+            previous.push_str(text);
+        } else {
+            nodes.push(converted);
+        }
+    }
+    nodes
+}
+
+/// Convert an element; SVG and MathML elements are named `svg x` and
+/// `math x`, as html5lib prints them.
+fn element(node: tree_sitter::Node, source: &str, namespace: Option<&'static str>) -> Dom {
+    let tag = node
+        .named_child(0)
+        .expect("element should start with a tag");
+    let raw_name = tag
+        .child_by_field_name("name")
+        .map(|name| &source[name.byte_range()])
+        .unwrap_or_default();
+    let lowercase = raw_name.to_ascii_lowercase();
+
+    let namespace = match (namespace, lowercase.as_str()) {
+        (None, "svg") => Some("svg"),
+        (None, "math") => Some("math"),
+        (namespace, _) => namespace,
+    };
+    let name = match namespace {
+        Some(namespace) => format!("{namespace} {raw_name}"),
+        None => lowercase.clone(),
+    };
+    // Integration points switch their children back to HTML.
+    let child_namespace = namespace.filter(|_| !is_html_integration_point(raw_name));
+
+    let mut attributes: Vec<(String, String)> = Vec::new();
+    let mut cursor = tag.walk();
+    for attribute in tag.named_children(&mut cursor) {
+        if attribute.kind() != "attribute" {
+            continue;
+        }
+        let mut name = String::new();
+        let mut value = String::new();
+        let mut parts = attribute.walk();
+        for part in attribute.named_children(&mut parts) {
+            match part.kind() {
+                "attribute_name" => name = source[part.byte_range()].to_ascii_lowercase(),
+                "attribute_value" => {
+                    value = decode_attribute(&source[part.byte_range()]).into_owned()
+                }
+                "quoted_attribute_value" => {
+                    value = part
+                        .named_child(0)
+                        .map(|inner| decode_attribute(&source[inner.byte_range()]).into_owned())
+                        .unwrap_or_default();
+                }
+                _ => {}
+            }
+        }
+        // The first occurrence of a duplicated attribute wins.
+        if !attributes.iter().any(|(existing, _)| *existing == name) {
+            attributes.push((name, value));
+        }
+    }
+
+    let mut children = Vec::new();
+    let mut cursor = node.walk();
+    let raw_text = node
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "raw_text");
+    if let Some(raw_text) = raw_text {
+        let text = &source[raw_text.byte_range()];
+        let text = if ESCAPABLE_RAW_TEXT.contains(&name.as_str()) {
+            decode_text(text).into_owned()
+        } else {
+            text.to_string()
+        };
+        children.push(Dom::Text(text));
+    } else if name == "plaintext" {
+        // Everything after `<plaintext>` is literal text.
+        children = node
+            .named_children(&mut cursor)
+            .filter(|child| child.kind() == "text")
+            .map(|child| Dom::Text(source[child.byte_range()].to_string()))
+            .collect();
+    } else {
+        children = self::children(node, source, child_namespace);
+    }
+
+    // A newline right after `<pre>`, `<listing>`, or `<textarea>` is dropped.
+    if matches!(name.as_str(), "pre" | "listing" | "textarea") {
+        if let Some(Dom::Text(text)) = children.first_mut() {
+            if let Some(rest) = text.strip_prefix('\n') {
+                *text = rest.to_string();
+            }
+            if text.is_empty() {
+                children.remove(0);
+            }
+        }
+    }
+
+    // An empty `<template>` has no `template_content` node but still owns
+    // an empty fragment.
+    if name == "template" && children.is_empty() {
+        children.push(Dom::Content(Vec::new()));
+    }
+
+    Dom::Element {
+        name,
+        attributes,
+        children,
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Mode {
+    BeforeHtml,
+    InHead,
+    AfterHead,
+    InBody,
+}
+
+/// Place top-level nodes into implied `<html>`, `<head>`, and `<body>`.
+fn synthesize(nodes: Vec<Dom>) -> Vec<Dom> {
+    struct Builder {
+        document: Vec<Dom>,
+        html: Vec<(String, String)>,
+        head_attributes: Vec<(String, String)>,
+        head: Vec<Dom>,
+        between: Vec<Dom>,
+        body_attributes: Vec<(String, String)>,
+        body: Vec<Dom>,
+        mode: Mode,
+    }
+
+    impl Builder {
+        fn place(&mut self, node: Dom) {
+            match node {
+                Dom::Doctype(_) | Dom::Comment(_) if self.mode == Mode::BeforeHtml => {
+                    self.document.push(node)
+                }
+                Dom::Doctype(_) => {}
+                Dom::Content(_) => unreachable!("template contents only appear in <template>"),
+                Dom::Comment(_) => self.current().push(node),
+                Dom::Text(text) if text.trim_matches(is_html_space).is_empty() => match self.mode {
+                    Mode::BeforeHtml => {}
+                    Mode::InHead => self.head.push(Dom::Text(text)),
+                    Mode::AfterHead => self.between.push(Dom::Text(text)),
+                    Mode::InBody => self.body.push(Dom::Text(text)),
+                },
+                Dom::Text(text) => {
+                    // Leading whitespace stays in the current mode; the rest
+                    // implies `<body>`.
+                    let content = text.trim_start_matches(is_html_space);
+                    let space = &text[..text.len() - content.len()];
+                    if !space.is_empty() && self.mode != Mode::InBody {
+                        self.place(Dom::Text(space.to_string()));
+                    }
+                    self.mode = Mode::InBody;
+                    let text = if space.is_empty() || self.body.is_empty() {
+                        content
+                    } else {
+                        text.as_str()
+                    };
+                    match self.body.last_mut() {
+                        Some(Dom::Text(previous)) => previous.push_str(text),
+                        _ => self.body.push(Dom::Text(text.to_string())),
+                    }
+                }
+                Dom::Element {
+                    name,
+                    attributes,
+                    children,
+                } => match name.as_str() {
+                    "html" => {
+                        merge(&mut self.html, attributes);
+                        self.mode = Mode::InHead;
+                        for child in children {
+                            self.place(child);
+                        }
+                    }
+                    "head" if self.mode < Mode::AfterHead => {
+                        merge(&mut self.head_attributes, attributes);
+                        self.mode = Mode::InHead;
+                        for child in children {
+                            self.place(child);
+                        }
+                        self.mode = Mode::AfterHead;
+                    }
+                    "body" => {
+                        merge(&mut self.body_attributes, attributes);
+                        self.mode = Mode::InBody;
+                        for child in children {
+                            self.place(child);
+                        }
+                    }
+                    _ if self.mode < Mode::InBody && HEAD_ELEMENTS.contains(&name.as_str()) => {
+                        self.mode = self.mode.max(Mode::InHead);
+                        self.head.push(Dom::Element {
+                            name,
+                            attributes,
+                            children,
+                        });
+                    }
+                    _ => {
+                        self.mode = Mode::InBody;
+                        self.body.push(Dom::Element {
+                            name,
+                            attributes,
+                            children,
+                        });
+                    }
+                },
+            }
+        }
+
+        fn current(&mut self) -> &mut Vec<Dom> {
+            match self.mode {
+                Mode::BeforeHtml | Mode::InHead => &mut self.head,
+                Mode::AfterHead => &mut self.between,
+                Mode::InBody => &mut self.body,
+            }
+        }
+    }
+
+    let mut builder = Builder {
+        document: Vec::new(),
+        html: Vec::new(),
+        head_attributes: Vec::new(),
+        head: Vec::new(),
+        between: Vec::new(),
+        body_attributes: Vec::new(),
+        body: Vec::new(),
+        mode: Mode::BeforeHtml,
+    };
+    for node in nodes {
+        builder.place(node);
+    }
+
+    let mut html = vec![Dom::Element {
+        name: "head".to_string(),
+        attributes: builder.head_attributes,
+        children: builder.head,
+    }];
+    html.extend(builder.between);
+    html.push(Dom::Element {
+        name: "body".to_string(),
+        attributes: builder.body_attributes,
+        children: builder.body,
+    });
+
+    let mut document = builder.document;
+    document.push(Dom::Element {
+        name: "html".to_string(),
+        attributes: builder.html,
+        children: html,
+    });
+    document
+}
+
+/// Attributes on a repeated `<html>`/`<body>` tag are added when missing.
+fn merge(target: &mut Vec<(String, String)>, attributes: Vec<(String, String)>) {
+    for (name, value) in attributes {
+        if !target.iter().any(|(existing, _)| *existing == name) {
+            target.push((name, value));
+        }
+    }
+}
+
+fn write_node(node: &Dom, depth: usize, out: &mut String) {
+    let indent = "  ".repeat(depth);
+    match node {
+        Dom::Doctype(text) => writeln!(out, "| {indent}<!DOCTYPE {text}>").unwrap(),
+        Dom::Comment(text) => writeln!(out, "| {indent}<!-- {text} -->").unwrap(),
+        Dom::Text(text) => writeln!(out, "| {indent}\"{text}\"").unwrap(),
+        Dom::Element {
+            name,
+            attributes,
+            children,
+        } => {
+            writeln!(out, "| {indent}<{name}>").unwrap();
+            let mut sorted: Vec<_> = attributes.iter().collect();
+            sorted.sort();
+            for (name, value) in sorted {
+                writeln!(out, "| {indent}  {name}=\"{value}\"").unwrap();
+            }
+            for child in children {
+                write_node(child, depth + 1, out);
+            }
+        }
+        Dom::Content(children) => {
+            writeln!(out, "| {indent}content").unwrap();
+            for child in children {
+                write_node(child, depth + 1, out);
+            }
+        }
+    }
+}
+
+/// `<!DOCTYPE name "public" "system">` as html5lib prints it.
+fn doctype(node: tree_sitter::Node, source: &str) -> String {
+    let doctype = Doctype::from_node(node, source).expect("node should be a doctype");
+    let name = doctype.name.unwrap_or_default();
+    if doctype.public_id.is_none() && doctype.system_id.is_none() {
+        return name;
+    }
+    format!(
+        "{name} \"{}\" \"{}\"",
+        doctype.public_id.unwrap_or_default(),
+        doctype.system_id.unwrap_or_default()
+    )
+}
+
+fn is_html_space(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\u{c}' | '\r')
+}
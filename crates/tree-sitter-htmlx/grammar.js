@@ -13,12 +13,12 @@ const HTML = require("../tree-sitter-html/grammar");
 module.exports = grammar(HTML, {
   name: "htmlx",
 
-  conflicts: ($) => [
+  conflicts: ($, original) => original.concat([
     // Conflict between attribute ending with plain _attribute_value
     // vs continuing with unquoted_attribute_value (text{expr} pattern)
     // We prefer the longer unquoted_attribute_value match
     [$.attribute, $.unquoted_attribute_value],
-  ],
+  ]),
 
   externals: ($, original) =>
     original.concat([
@@ -61,6 +61,12 @@ module.exports = grammar(HTML, {
         seq(alias($._namespaced_unterminated_start_tag, $.start_tag)),
         seq(alias($._member_unterminated_start_tag, $.start_tag)),
         seq(alias($._raw_text_unterminated_start_tag, $.start_tag)),
+        seq(alias($._template_unterminated_start_tag, $.start_tag)),
+        seq(
+          alias($._template_unterminated_start_tag_with_close, $.start_tag),
+          optional($.template_content),
+          $.end_tag,
+        ),
         prec(
           1,
           seq(
@@ -80,6 +86,8 @@ module.exports = grammar(HTML, {
             $._implicit_end_tag,
           ),
         ),
+        // Template element - content is a document fragment
+        $._template_element,
         // Namespaced elements (svelte:head)
         seq(
           alias($._namespaced_start_tag, $.start_tag),
@@ -96,6 +104,7 @@ module.exports = grammar(HTML, {
         $._raw_text_element,
         // Self-closing tags
         $.self_closing_tag,
+        alias($._template_self_closing_tag, $.self_closing_tag),
         alias($._namespaced_self_closing_tag, $.self_closing_tag),
         alias($._member_self_closing_tag, $.self_closing_tag),
       ),
@@ -123,6 +132,30 @@ module.exports = grammar(HTML, {
     end_tag: ($) =>
       endTag($, $._end_tag_name),
 
+    // Override template element to use HTMLX-aware attribute handling
+    _template_element: ($) =>
+      seq(
+        alias($._template_start_tag, $.start_tag),
+        optional($.template_content),
+        choice(
+          prec(1, $.end_tag),
+          prec(10, $._unterminated_tag_end),
+          $._implicit_end_tag,
+        ),
+      ),
+
+    _template_start_tag: ($) =>
+      tag($, $._template_start_tag_name, ">"),
+
+    _template_unterminated_start_tag: ($) =>
+      tag($, $._template_start_tag_name, $._unterminated_tag_end),
+
+    _template_unterminated_start_tag_with_close: ($) =>
+      tag($, $._template_start_tag_name, $._unterminated_tag_end_open),
+
+    _template_self_closing_tag: ($) =>
+      tag($, $._template_start_tag_name, "/>"),
+
     // Override raw text element to use HTMLX-aware attribute handling
     _raw_text_element: ($) =>
       seq(
@@ -0,0 +1,324 @@
+//! Duplicate, malformed, and conflicting attribute names on HTMLX tags.
+//!
+//! Like [`tree_sitter_html::attributes`], this reports repeated names and
+//! names containing characters the syntax does not allow (§13.1.2.3), and adds
+//! HTMLX's rules on top:
+//!
+//! - attributes on HTML elements are compared ASCII case-insensitively, while
+//!   components (`<Button>`, `<ui.Card>`) pass their props through as
+//!   written, so `value` and `Value` are different;
+//! - `{name}` shorthand sets `name`, and `{...spread}` sets nothing knowable;
+//! - directives that set the same thing twice, or fight an attribute over it,
+//!   are reported.
+//!
+//! ```rust
+//! use tree_sitter_htmlx::{attributes, LANGUAGE};
+//!
+//! let source = "<input value={a} bind:value><Card Title=a title=b />";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let found = attributes::check(tree.root_node(), source);
+//! assert_eq!(found.len(), 1);
+//! assert_eq!(found[0].to_string(), "`bind:value` conflicts with the `value` attribute");
+//! ```
+
+use std::fmt;
+
+use tree_sitter::{Node, Range};
+
+use tree_sitter_html::attributes::is_name_char;
+use tree_sitter_html::span::subrange;
+
+use crate::is_component_name;
+
+/// An attribute a browser would report as a parse error, or an HTMLX
+/// directive that conflicts with another one on the same tag.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    /// The span of the offending name, or of the offending character for
+    /// [`DiagnosticKind::InvalidAttributeName`].
+    pub range: Range,
+}
+
+/// The typed payload of a [`Diagnostic`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// A name already used earlier on the same tag. Browsers keep the first
+    /// one and drop this one.
+    DuplicateAttribute {
+        /// The name as written on the repeated attribute.
+        name: String,
+        /// The span of the first attribute's name.
+        first: Range,
+    },
+    /// A name containing a control character, a noncharacter, whitespace,
+    /// or one of `"`, `'`, `<`, `>`, `/`, and `=`.
+    InvalidAttributeName {
+        /// The name as written.
+        name: String,
+        /// The first character that is not allowed.
+        character: char,
+    },
+    /// An HTMLX `bind:name` directive on a tag that also sets `name` as an
+    /// attribute, so the two fight over the same property.
+    ConflictingBinding {
+        /// The bound name (`value` for `bind:value`).
+        name: String,
+        /// The span of the other half of the pair, which comes first.
+        first: Range,
+    },
+    /// An HTMLX `class:name` or `style:name` directive on a tag whose static
+    /// `class` or `style` attribute already sets `name`, so the directive
+    /// and the attribute fight over it.
+    ConflictingDirective {
+        /// `class` or `style`.
+        directive: String,
+        /// The class or style property (`active` for `class:active`).
+        name: String,
+        /// The span of the other half of the pair, which comes first.
+        first: Range,
+    },
+}
+
+impl Diagnostic {
+    /// The span of the earlier attribute this one repeats or conflicts with.
+    pub fn first(&self) -> Option<Range> {
+        match &self.kind {
+            DiagnosticKind::DuplicateAttribute { first, .. }
+            | DiagnosticKind::ConflictingBinding { first, .. }
+            | DiagnosticKind::ConflictingDirective { first, .. } => Some(*first),
+            DiagnosticKind::InvalidAttributeName { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.kind, f)
+    }
+}
+
+impl fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiagnosticKind::DuplicateAttribute { name, .. } => {
+                write!(f, "duplicate attribute `{name}`")
+            }
+            DiagnosticKind::InvalidAttributeName { name, character } => write!(
+                f,
+                "attribute name `{name}` contains {character:?}, which is not allowed"
+            ),
+            DiagnosticKind::ConflictingBinding { name, .. } => {
+                write!(f, "`bind:{name}` conflicts with the `{name}` attribute")
+            }
+            DiagnosticKind::ConflictingDirective {
+                directive, name, ..
+            } => write!(
+                f,
+                "`{directive}:{name}` conflicts with `{name}` in the `{directive}` attribute"
+            ),
+        }
+    }
+}
+
+/// Report repeated, malformed, and conflicting attribute names on every tag
+/// under `root`, in source order.
+///
+/// Names are compared ASCII case-insensitively, as the tokenizer lowercases
+/// them, except on components. Directives are compared case-sensitively
+/// everywhere. Repeating `class:name`, `style:name`, or `bind:name` is
+/// reported as a duplicate; other directives, like `on:click`, may repeat.
+/// `class:name` and `style:name` also conflict with a static `class` or
+/// `style` attribute that already sets `name`.
+pub fn check(root: Node, source: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    visit(root, source, &mut diagnostics);
+    diagnostics
+}
+
+fn visit(node: Node, source: &str, diagnostics: &mut Vec<Diagnostic>) {
+    if matches!(node.kind(), "start_tag" | "self_closing_tag") {
+        let case_sensitive = node
+            .child_by_field_name("name")
+            .is_some_and(|name| is_component_name(&source[name.byte_range()]));
+        check_tag(node, source, case_sensitive, diagnostics);
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        visit(child, source, diagnostics);
+    }
+}
+
+/// One attribute, reduced to what it sets.
+enum Key<'a, 'tree> {
+    /// A plain attribute or `{name}` shorthand, with its value if written.
+    Attribute(&'a str, Option<Node<'tree>>),
+    /// A directive such as `class:name`, without its modifiers.
+    Directive { directive: &'a str, name: &'a str },
+}
+
+fn check_tag(tag: Node, source: &str, case_sensitive: bool, diagnostics: &mut Vec<Diagnostic>) {
+    let same = |a: &str, b: &str| {
+        if case_sensitive {
+            a == b
+        } else {
+            a.eq_ignore_ascii_case(b)
+        }
+    };
+
+    let mut seen: Vec<(Key, Range)> = Vec::new();
+    let mut cursor = tag.walk();
+    for attribute in tag.named_children(&mut cursor) {
+        if attribute.kind() != "attribute" {
+            continue;
+        }
+        let Some((key, node)) = attribute_key(attribute, source) else {
+            continue;
+        };
+
+        let text = &source[node.byte_range()];
+        if let Some((offset, character)) = text.char_indices().find(|&(_, c)| !is_name_char(c)) {
+            diagnostics.push(Diagnostic {
+                kind: DiagnosticKind::InvalidAttributeName {
+                    name: text.to_string(),
+                    character,
+                },
+                range: subrange(node, text, offset, offset + character.len_utf8()),
+            });
+        }
+
+        let earlier = seen.iter().find_map(|(other, range)| {
+            let kind = match (&key, other) {
+                (Key::Attribute(name, _), Key::Attribute(other, _)) if same(name, other) => {
+                    DiagnosticKind::DuplicateAttribute {
+                        name: name.to_string(),
+                        first: *range,
+                    }
+                }
+                (
+                    Key::Directive { directive, name },
+                    Key::Directive {
+                        directive: other_directive,
+                        name: other_name,
+                    },
+                ) if directive == other_directive
+                    && name == other_name
+                    && matches!(*directive, "class" | "style" | "bind") =>
+                {
+                    DiagnosticKind::DuplicateAttribute {
+                        name: text.to_string(),
+                        first: *range,
+                    }
+                }
+                (Key::Directive { directive, name }, Key::Attribute(other, _))
+                | (Key::Attribute(other, _), Key::Directive { directive, name })
+                    if *directive == "bind" && same(name, other) =>
+                {
+                    DiagnosticKind::ConflictingBinding {
+                        name: name.to_string(),
+                        first: *range,
+                    }
+                }
+                (Key::Directive { directive, name }, Key::Attribute(other, Some(value)))
+                | (Key::Attribute(other, Some(value)), Key::Directive { directive, name })
+                    if matches!(*directive, "class" | "style")
+                        && other.eq_ignore_ascii_case(directive)
+                        && sets(directive, &static_value(*value, source), name) =>
+                {
+                    DiagnosticKind::ConflictingDirective {
+                        directive: directive.to_string(),
+                        name: name.to_string(),
+                        first: *range,
+                    }
+                }
+                _ => return None,
+            };
+            Some(kind)
+        });
+
+        match earlier {
+            Some(kind) => diagnostics.push(Diagnostic {
+                kind,
+                range: node.range(),
+            }),
+            None => seen.push((key, node.range())),
+        }
+    }
+}
+
+/// What `attribute` sets, and the node spanning its name. Spreads such as
+/// `{...rest}` set nothing knowable and return `None`.
+fn attribute_key<'a, 'tree>(
+    attribute: Node<'tree>,
+    source: &'a str,
+) -> Option<(Key<'a, 'tree>, Node<'tree>)> {
+    let mut cursor = attribute.walk();
+    let node = attribute
+        .named_children(&mut cursor)
+        .find(|child| matches!(child.kind(), "attribute_name" | "shorthand_attribute"))?;
+
+    if node.kind() == "shorthand_attribute" {
+        let content = node.child_by_field_name("content")?;
+        let name = source[content.byte_range()].trim();
+        if name.is_empty() || name.starts_with("...") {
+            return None;
+        }
+        return Some((Key::Attribute(name, None), node));
+    }
+
+    let directive = node
+        .named_child(0)
+        .filter(|child| child.kind() == "attribute_directive");
+    let key = match directive {
+        Some(directive) => {
+            let mut cursor = node.walk();
+            let name = node
+                .named_children(&mut cursor)
+                .find(|child| child.kind() == "attribute_identifier")
+                .map_or("", |name| &source[name.byte_range()]);
+            Key::Directive {
+                directive: &source[directive.byte_range()],
+                name,
+            }
+        }
+        None => Key::Attribute(
+            &source[node.byte_range()],
+            attribute.child_by_field_name("value"),
+        ),
+    };
+    Some((key, node))
+}
+
+/// The text of an attribute value with each expression replaced by a NUL,
+/// so nothing next to an expression reads as a static name.
+fn static_value(value: Node, source: &str) -> String {
+    match value.kind() {
+        "attribute_value" => source[value.byte_range()].to_string(),
+        "quoted_attribute_value" | "unquoted_attribute_value" => {
+            let mut cursor = value.walk();
+            value
+                .named_children(&mut cursor)
+                .map(|part| static_value(part, source))
+                .collect()
+        }
+        _ => "\0".to_string(),
+    }
+}
+
+/// Whether a static `class` or `style` attribute `value` sets `name`: a
+/// class in the list, or a property of a declaration.
+fn sets(directive: &str, value: &str, name: &str) -> bool {
+    if directive == "class" {
+        value.split_ascii_whitespace().any(|class| class == name)
+    } else {
+        value
+            .split(';')
+            .filter_map(|declaration| declaration.split_once(':'))
+            .any(|(property, _)| property.trim() == name)
+    }
+}
@@ -0,0 +1,30 @@
+//! Content-model checks for element nesting in HTMLX trees.
+//!
+//! This applies [`tree_sitter_html::content_model`] with HTMLX's component
+//! rule: components are not checked, and their children may go anywhere, so
+//! `<Menu><li>` is fine while `<div><li>` is not.
+//!
+//! ```rust
+//! use tree_sitter_htmlx::{content_model, LANGUAGE};
+//!
+//! let source = "<Menu><li>a</li></Menu><button><a href={url}>go</a></button>";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let found = content_model::check(tree.root_node(), source);
+//! assert_eq!(found.len(), 1);
+//! assert_eq!(found[0].to_string(), "`<a>` cannot be inside `<button>`");
+//! ```
+
+use tree_sitter::Node;
+
+pub use tree_sitter_html::content_model::{can_contain, Diagnostic, DiagnosticKind};
+
+use crate::is_component_name;
+
+/// Report content-model violations under `root`, in source order. See
+/// [`tree_sitter_html::content_model::check_with`].
+pub fn check(root: Node, source: &str) -> Vec<Diagnostic> {
+    tree_sitter_html::content_model::check_with(root, source, is_component_name)
+}
@@ -12,11 +12,16 @@
  *
  * Handles:
  * - Tag names (start, end, special elements)
- * - Raw text content (script, style)
+ * - Raw text content (script, style, iframe, noembed, noframes, xmp, and
+ *   noscript when scripting is enabled)
  * - Escapable raw text content (textarea, title)
- * - Implicit end tags (§13.1.2.4)
- * - Comments (§13.6)
+ * - PLAINTEXT content, which runs to the end of input
+ * - Implicit end tags (§13.1.2.4), scoped to <template> contents
+ * - Comments (§13.6), and the bogus comments the tokenizer makes of
+ *   processing instructions, CDATA outside foreign content, and other `<!`
+ *   markup (§13.2.5.41)
  * - Self-closing tag delimiter
+ * - Foreign content (§13.2.6.5): SVG/MathML elements and CDATA sections
  *
  * Performance optimizations:
  * - ASCII-only normalization for built-in tag matching
@@ -45,6 +50,15 @@
 #define ALWAYS_INLINE inline
 #endif
 
+// A grammar that vendors this scanner includes it into its own scanner.c.
+// Its copy of the entry points stays private to that file, so it links
+// alongside the HTML grammar and other grammars that vendor it.
+#ifdef TREE_SITTER_HTML_VENDORED
+#define HTML_EXPORT static
+#else
+#define HTML_EXPORT
+#endif
+
 // ============================================================================
 // Token types - must match grammar.js externals order
 // ============================================================================
@@ -59,6 +73,11 @@ enum TokenType {
   RAW_TEXT,                   // 6
   COMMENT,                    // 7
   TEXT,                       // 8 - Text content including whitespace (§13.1.3)
+  PROCESSING_INSTRUCTION,     // 9 - `<?...>` bogus comment
+  BOGUS_COMMENT,              // 10 - Other `<!...>` bogus comment
+  CDATA_SECTION,              // 11 - `<![CDATA[...>` bogus comment in HTML content
+  CDATA,                      // 12 - `<![CDATA[...]]>` in foreign content
+  TEMPLATE_START_TAG_NAME,    // 13 - `<template>`, whose contents are a fragment
 };
 
 // ============================================================================
@@ -67,8 +86,26 @@ enum TokenType {
 
 typedef struct {
   Array(Tag) tags;
+  bool scripting; // <noscript> content is raw text (§13.2.5.3)
 } Scanner;
 
+HTML_EXPORT bool tree_sitter_html_is_html_integration_point(
+    const char *name, uint32_t length) {
+  return name_is_html_integration_point(name, length);
+}
+
+HTML_EXPORT bool tree_sitter_html_tag_can_contain(
+    const char *parent,
+    uint32_t parent_length,
+    const char *child,
+    uint32_t child_length) {
+  Tag parent_tag = tag_new();
+  parent_tag.type = name_tag_type(parent, parent_length);
+  Tag child_tag = tag_new();
+  child_tag.type = name_tag_type(child, child_length);
+  return tag_can_contain(&parent_tag, &child_tag);
+}
+
 static ALWAYS_INLINE bool has_open_tag(Scanner *scanner) {
   return scanner->tags.size > 0;
 }
@@ -274,19 +311,67 @@ static String scan_tag_name(TSLexer *lexer) {
 // Comment scanning
 // ============================================================================
 
+/**
+ * Scan a bogus comment per §13.2.5.41 - everything up to the next '>'
+ *
+ * The tokenizer turns `<?...>`, `<!...>` that is neither a comment nor a
+ * DOCTYPE, and `<![CDATA[...]]>` outside foreign content into comments;
+ * `symbol` says which of the three this is.
+ */
+static bool scan_bogus_comment(TSLexer *lexer, enum TokenType symbol) {
+  while (lexer->lookahead != 0 && lexer->lookahead != '>') {
+    advance(lexer);
+  }
+  if (lexer->lookahead == '>') {
+    advance(lexer);
+  }
+  lexer->mark_end(lexer);
+  lexer->result_symbol = symbol;
+  return true;
+}
+
+/**
+ * Consume the `[CDATA[` that follows `<!` in a CDATA section
+ */
+static bool scan_cdata_open(TSLexer *lexer) {
+  static const char open[] = "[CDATA[";
+  for (unsigned i = 0; i < sizeof(open) - 1; i++) {
+    if (lexer->lookahead != open[i]) {
+      return false;
+    }
+    advance(lexer);
+  }
+  return true;
+}
+
 /**
  * Scan HTML comment
  * Per §13.6 - Comments start with <!-- and end with -->
  */
 static bool scan_comment(TSLexer *lexer) {
   // Already consumed '<!'
+  if (lexer->lookahead == '[') {
+    // Outside foreign content a CDATA section ends at the first '>' like any
+    // other bogus comment (§13.2.5.42)
+    return scan_bogus_comment(lexer, scan_cdata_open(lexer) ? CDATA_SECTION
+                                                            : BOGUS_COMMENT);
+  }
+
   if (UNLIKELY(lexer->lookahead != '-')) {
+    // Leave DOCTYPE to the grammar; anything else is a bogus comment
+    static const char DOCTYPE[] = "DOCTYPE";
+    for (unsigned i = 0; i < sizeof(DOCTYPE) - 1; i++) {
+      if (to_ascii_upper(lexer->lookahead) != DOCTYPE[i]) {
+        return scan_bogus_comment(lexer, BOGUS_COMMENT);
+      }
+      advance(lexer);
+    }
     return false;
   }
   advance(lexer);
 
   if (UNLIKELY(lexer->lookahead != '-')) {
-    return false;
+    return scan_bogus_comment(lexer, BOGUS_COMMENT);
   }
   advance(lexer);
 
@@ -310,6 +395,36 @@ static bool scan_comment(TSLexer *lexer) {
   return false;
 }
 
+/**
+ * Scan a CDATA section in foreign content
+ * Per §13.2.5.42 - <![CDATA[...]]> is character data inside <svg>/<math>
+ */
+static bool scan_cdata(TSLexer *lexer) {
+  // Already consumed '<!'
+  if (!scan_cdata_open(lexer)) {
+    return scan_bogus_comment(lexer, BOGUS_COMMENT);
+  }
+
+  unsigned brackets = 0;
+  while (lexer->lookahead != 0) {
+    int32_t c = lexer->lookahead;
+    advance(lexer);
+
+    if (c == ']') {
+      brackets++;
+    } else if (c == '>' && brackets >= 2) {
+      break;
+    } else {
+      brackets = 0;
+    }
+  }
+
+  // An unterminated section runs to the end of input
+  lexer->mark_end(lexer);
+  lexer->result_symbol = CDATA;
+  return true;
+}
+
 // ============================================================================
 // Raw text content scanning
 // ============================================================================
@@ -323,14 +438,20 @@ static const struct {
 } RAW_TEXT_DELIMITERS[] = {
     {SCRIPT, "</SCRIPT", 8},
     {STYLE, "</STYLE", 7},
+    {IFRAME, "</IFRAME", 8},
+    {NOEMBED, "</NOEMBED", 9},
+    {NOFRAMES, "</NOFRAMES", 10},
+    {NOSCRIPT, "</NOSCRIPT", 10},
+    {XMP, "</XMP", 5},
     {TEXTAREA, "</TEXTAREA", 10},
     {TITLE, "</TITLE", 7},
 };
 
-#define RAW_TEXT_DELIMITER_COUNT 4
+#define RAW_TEXT_DELIMITER_COUNT                                               \
+  (sizeof(RAW_TEXT_DELIMITERS) / sizeof(RAW_TEXT_DELIMITERS[0]))
 
 /**
- * Scan raw text content for script, style, textarea, title elements
+ * Scan raw text content for raw text and escapable raw text elements
  * Per §13.1.2.1 and §13.1.2.2
  *
  * Optimized: uses pre-computed delimiter info, avoids strlen in hot path
@@ -346,7 +467,7 @@ static bool scan_raw_text(Scanner *scanner, TSLexer *lexer) {
   const char *delimiter = NULL;
   unsigned delimiter_len = 0;
 
-  for (int i = 0; i < RAW_TEXT_DELIMITER_COUNT; i++) {
+  for (unsigned i = 0; i < RAW_TEXT_DELIMITER_COUNT; i++) {
     if (RAW_TEXT_DELIMITERS[i].type == tag_type) {
       delimiter = RAW_TEXT_DELIMITERS[i].delimiter;
       delimiter_len = RAW_TEXT_DELIMITERS[i].length;
@@ -393,6 +514,27 @@ static ALWAYS_INLINE void pop_tag(Scanner *scanner) {
   tag_free(&popped_tag);
 }
 
+/**
+ * Whether elements opened at `depth` of the stack are in foreign content
+ * Per §13.2.6.5 - inside <svg>/<math>, up to an HTML integration point
+ */
+static bool in_foreign_content(Scanner *scanner, unsigned depth) {
+  for (unsigned i = depth; i > 0; i--) {
+    Tag *tag = &scanner->tags.contents[i - 1];
+    if (tag->type == SVG || tag->type == MATH) {
+      return true;
+    }
+    if (tag_is_html_integration_point(tag)) {
+      return false;
+    }
+  }
+  return false;
+}
+
+static ALWAYS_INLINE unsigned parent_depth(Scanner *scanner) {
+  return scanner->tags.size > 0 ? scanner->tags.size - 1 : 0;
+}
+
 // ============================================================================
 // Implicit end tag scanning
 // ============================================================================
@@ -425,6 +567,10 @@ static bool scan_implicit_end_tag(Scanner *scanner, TSLexer *lexer) {
   }
 
   Tag next_tag = tag_for_htmlx_name(tag_name);
+  if (in_foreign_content(scanner, is_closing_tag ? parent_depth(scanner)
+                                                 : scanner->tags.size)) {
+    next_tag = tag_for_foreign_content(next_tag);
+  }
 
   if (is_closing_tag) {
     // Check if tag correctly closes the topmost element
@@ -433,7 +579,9 @@ static bool scan_implicit_end_tag(Scanner *scanner, TSLexer *lexer) {
       return false;
     }
 
-    // Search stack for matching tag - emit implicit end tags
+    // Search stack for matching tag - emit implicit end tags. Template
+    // contents are a separate fragment (§4.12.3), so the search stops at the
+    // innermost <template> and end tags inside it never close outer elements.
     for (unsigned i = scanner->tags.size; i > 0; i--) {
       if (tag_eq(&scanner->tags.contents[i - 1], &next_tag)) {
         pop_tag(scanner);
@@ -441,6 +589,9 @@ static bool scan_implicit_end_tag(Scanner *scanner, TSLexer *lexer) {
         tag_free(&next_tag);
         return true;
       }
+      if (scanner->tags.contents[i - 1].type == TEMPLATE) {
+        break;
+      }
     }
   } else if (parent != NULL) {
     // Check content model - does parent allow this child?
@@ -468,7 +619,8 @@ static bool scan_implicit_end_tag(Scanner *scanner, TSLexer *lexer) {
 // Start tag scanning
 // ============================================================================
 
-static bool scan_start_tag_name(Scanner *scanner, TSLexer *lexer) {
+static bool scan_start_tag_name(Scanner *scanner, TSLexer *lexer,
+                                const bool *valid_symbols) {
   String tag_name = scan_tag_name(lexer);
 
   if (UNLIKELY(tag_name.size == 0)) {
@@ -477,16 +629,32 @@ static bool scan_start_tag_name(Scanner *scanner, TSLexer *lexer) {
   }
 
   Tag tag = tag_for_htmlx_name(tag_name);
+  if (in_foreign_content(scanner, scanner->tags.size)) {
+    tag = tag_for_foreign_content(tag);
+  }
   array_push(&scanner->tags, tag);
 
   // Determine token type: raw text elements vs normal elements
   switch (tag.type) {
   case SCRIPT:
   case STYLE:
+  case IFRAME:
+  case NOEMBED:
+  case NOFRAMES:
+  case XMP:
   case TEXTAREA:
   case TITLE:
     lexer->result_symbol = RAW_TEXT_START_TAG_NAME;
     break;
+  case NOSCRIPT:
+    lexer->result_symbol =
+        scanner->scripting ? RAW_TEXT_START_TAG_NAME : START_TAG_NAME;
+    break;
+  case TEMPLATE:
+    lexer->result_symbol = valid_symbols[TEMPLATE_START_TAG_NAME]
+                               ? TEMPLATE_START_TAG_NAME
+                               : START_TAG_NAME;
+    break;
   default:
     lexer->result_symbol = START_TAG_NAME;
     break;
@@ -508,6 +676,9 @@ static bool scan_end_tag_name(Scanner *scanner, TSLexer *lexer) {
   }
 
   Tag tag = tag_for_htmlx_name(tag_name);
+  if (in_foreign_content(scanner, parent_depth(scanner))) {
+    tag = tag_for_foreign_content(tag);
+  }
 
   // Check if this closes the current element
   if (has_open_tag(scanner) && tag_eq(current_tag(scanner), &tag)) {
@@ -577,6 +748,39 @@ static bool scan_text(TSLexer *lexer) {
   return false;
 }
 
+/**
+ * Scan <plaintext> content per §13.2.5.7
+ *
+ * Once a <plaintext> start tag is seen, the rest of the input is text and
+ * the element is only closed by the end of input.
+ */
+static bool scan_plaintext(Scanner *scanner, TSLexer *lexer,
+                           const bool *valid_symbols) {
+  if (!has_open_tag(scanner) || current_tag(scanner)->type != PLAINTEXT) {
+    return false;
+  }
+
+  if (lexer->lookahead == 0) {
+    if (!valid_symbols[IMPLICIT_END_TAG]) {
+      return false;
+    }
+    pop_tag(scanner);
+    lexer->result_symbol = IMPLICIT_END_TAG;
+    return true;
+  }
+
+  if (!valid_symbols[TEXT]) {
+    return false;
+  }
+
+  while (lexer->lookahead != 0) {
+    advance(lexer);
+  }
+  lexer->mark_end(lexer);
+  lexer->result_symbol = TEXT;
+  return true;
+}
+
 static bool scan_void_implicit_end_tag(Scanner *scanner, TSLexer *lexer,
                                        const bool *valid_symbols) {
   if (!valid_symbols[IMPLICIT_END_TAG] || !has_open_tag(scanner)) {
@@ -599,7 +803,7 @@ static bool scan_void_implicit_end_tag(Scanner *scanner, TSLexer *lexer,
 // ============================================================================
 
 static bool scan(Scanner *scanner, TSLexer *lexer, const bool *valid_symbols) {
-  // Priority 1: Raw text mode - for script, style, textarea, title content
+  // Priority 1: Raw text mode - for raw text and escapable raw text content
   if (valid_symbols[RAW_TEXT] && !valid_symbols[START_TAG_NAME] &&
       !valid_symbols[END_TAG_NAME]) {
     return scan_raw_text(scanner, lexer);
@@ -609,6 +813,11 @@ static bool scan(Scanner *scanner, TSLexer *lexer, const bool *valid_symbols) {
     return true;
   }
 
+  if ((valid_symbols[TEXT] || valid_symbols[IMPLICIT_END_TAG]) &&
+      scan_plaintext(scanner, lexer, valid_symbols)) {
+    return true;
+  }
+
   // Priority 2: Text content - capture before whitespace is skipped
   // Text includes whitespace per §13.1.3
   if (valid_symbols[TEXT]) {
@@ -631,9 +840,18 @@ static bool scan(Scanner *scanner, TSLexer *lexer, const bool *valid_symbols) {
 
     if (lexer->lookahead == '!') {
       advance(lexer);
+      if (lexer->lookahead == '[' && valid_symbols[CDATA] &&
+          in_foreign_content(scanner, scanner->tags.size)) {
+        return scan_cdata(lexer);
+      }
       return scan_comment(lexer);
     }
 
+    // Processing instructions are bogus comments in HTML
+    if (lexer->lookahead == '?') {
+      return scan_bogus_comment(lexer, PROCESSING_INSTRUCTION);
+    }
+
     if (valid_symbols[IMPLICIT_END_TAG]) {
       return scan_implicit_end_tag(scanner, lexer);
     }
@@ -658,7 +876,7 @@ static bool scan(Scanner *scanner, TSLexer *lexer, const bool *valid_symbols) {
   if ((valid_symbols[START_TAG_NAME] || valid_symbols[END_TAG_NAME]) &&
       !valid_symbols[RAW_TEXT]) {
     if (valid_symbols[START_TAG_NAME]) {
-      return scan_start_tag_name(scanner, lexer);
+      return scan_start_tag_name(scanner, lexer, valid_symbols);
     } else {
       return scan_end_tag_name(scanner, lexer);
     }
@@ -671,31 +889,43 @@ static bool scan(Scanner *scanner, TSLexer *lexer, const bool *valid_symbols) {
 // Tree-sitter external scanner interface
 // ============================================================================
 
-void *tree_sitter_html_external_scanner_create(void) {
+static Scanner *scanner_create(bool scripting) {
   Scanner *scanner = (Scanner *)ts_calloc(1, sizeof(Scanner));
+  scanner->scripting = scripting;
   return scanner;
 }
 
-bool tree_sitter_html_external_scanner_scan(void *payload, TSLexer *lexer,
-                                            const bool *valid_symbols) {
+HTML_EXPORT void *tree_sitter_html_external_scanner_create(void) {
+  return scanner_create(false);
+}
+
+/**
+ * Browsers parse <noscript> as raw text when scripting is enabled; the
+ * default scanner matches a document parsed with scripting disabled.
+ */
+HTML_EXPORT void *tree_sitter_html_external_scanner_create_scripting(void) {
+  return scanner_create(true);
+}
+
+HTML_EXPORT bool tree_sitter_html_external_scanner_scan(
+    void *payload, TSLexer *lexer, const bool *valid_symbols) {
   Scanner *scanner = (Scanner *)payload;
   return scan(scanner, lexer, valid_symbols);
 }
 
-unsigned tree_sitter_html_external_scanner_serialize(void *payload,
-                                                     char *buffer) {
+HTML_EXPORT unsigned tree_sitter_html_external_scanner_serialize(
+    void *payload, char *buffer) {
   Scanner *scanner = (Scanner *)payload;
   return serialize(scanner, buffer);
 }
 
-void tree_sitter_html_external_scanner_deserialize(void *payload,
-                                                   const char *buffer,
-                                                   unsigned length) {
+HTML_EXPORT void tree_sitter_html_external_scanner_deserialize(
+    void *payload, const char *buffer, unsigned length) {
   Scanner *scanner = (Scanner *)payload;
   deserialize(scanner, buffer, length);
 }
 
-void tree_sitter_html_external_scanner_destroy(void *payload) {
+HTML_EXPORT void tree_sitter_html_external_scanner_destroy(void *payload) {
   Scanner *scanner = (Scanner *)payload;
   for (unsigned i = 0; i < scanner->tags.size; i++) {
     tag_free(&scanner->tags.contents[i]);
@@ -703,3 +933,28 @@ void tree_sitter_html_external_scanner_destroy(void *payload) {
   array_delete(&scanner->tags);
   ts_free(scanner);
 }
+
+// ============================================================================
+// Scripting-enabled language
+// ============================================================================
+
+// Grammars that vendor this scanner build their own scripting language.
+#ifndef TREE_SITTER_HTML_VENDORED
+
+const TSLanguage *tree_sitter_html(void);
+
+static TSLanguage scripting_language;
+
+/**
+ * The HTML language with scripting enabled: a copy of the generated language
+ * whose scanners are created with the scripting flag set. Not thread-safe;
+ * the Rust crate calls it once behind a lock.
+ */
+const TSLanguage *tree_sitter_html_scripting(void) {
+  scripting_language = *tree_sitter_html();
+  scripting_language.external_scanner.create =
+      tree_sitter_html_external_scanner_create_scripting;
+  return &scripting_language;
+}
+
+#endif
@@ -10,6 +10,13 @@
 //! - Spread attributes: `{...props}`
 //! - Directive attributes: `bind:value`, `on:click`, `class:active`, etc.
 //!
+//! Character references inside `text` and attribute values are decoded and
+//! checked by [`entities`], shared with the HTML grammar. Repeated attribute
+//! names, malformed names, and directives that repeat or clash with an
+//! attribute are reported by [`attributes`], nesting the HTML content model
+//! forbids by [`content_model`], and [`serialize`] prints trees
+//! back out losslessly or in a normalized form.
+//!
 //! ## Example
 //!
 //! ```rust
@@ -27,10 +34,19 @@
 //! assert!(!tree.root_node().has_error());
 //! ```
 
+use std::sync::OnceLock;
+
 use tree_sitter_language::LanguageFn;
 
+pub mod attributes;
+pub mod content_model;
+pub mod serialize;
+
+pub use tree_sitter_html::entities;
+
 extern "C" {
     fn tree_sitter_htmlx() -> *const ();
+    fn tree_sitter_htmlx_scripting() -> *const ();
 }
 
 /// The tree-sitter [`LanguageFn`] for HTMLX.
@@ -41,6 +57,23 @@ pub fn language() -> tree_sitter::Language {
     LANGUAGE.into()
 }
 
+/// The tree-sitter [`LanguageFn`] for HTMLX with scripting enabled, so
+/// `<noscript>` content is raw text. See
+/// [`tree_sitter_html::LANGUAGE_SCRIPTING`].
+pub const LANGUAGE_SCRIPTING: LanguageFn = unsafe { LanguageFn::from_raw(scripting_language) };
+
+extern "C" fn scripting_language() -> *const () {
+    static LANGUAGE: OnceLock<usize> = OnceLock::new();
+    *LANGUAGE.get_or_init(|| unsafe { tree_sitter_htmlx_scripting() } as usize) as *const ()
+}
+
+/// Whether the tag `name` is an HTMLX component rather than an element: it
+/// starts with an uppercase letter or is a member expression such as
+/// `ui.Card`. Components keep the case of their name and props.
+pub fn is_component_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase()) || name.contains('.')
+}
+
 /// The syntax highlighting query for HTMLX.
 pub const HIGHLIGHTS_QUERY: &str = include_str!("../queries/highlights.scm");
 
@@ -158,7 +191,7 @@ mod tests {
 
         for capture in ["tag", "attribute", "string", "punctuation.bracket"] {
             assert!(
-                captures.iter().any(|name| *name == capture),
+                captures.contains(&capture),
                 "missing @{capture} from HTMLX highlights query"
             );
         }
@@ -193,10 +226,7 @@ mod tests {
             let query = tree_sitter::Query::new(&language, source)
                 .unwrap_or_else(|error| panic!("{name} query should compile: {error}"));
             assert!(
-                query
-                    .capture_names()
-                    .iter()
-                    .any(|capture| *capture == expected_capture),
+                query.capture_names().contains(&expected_capture),
                 "missing @{expected_capture} from HTMLX {name} query"
             );
         }
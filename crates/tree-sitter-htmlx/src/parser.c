@@ -7,118 +7,137 @@
 #endif
 
 #define LANGUAGE_VERSION 15
-#define STATE_COUNT 344
+#define STATE_COUNT 553
 #define LARGE_STATE_COUNT 2
-#define SYMBOL_COUNT 92
+#define SYMBOL_COUNT 107
 #define ALIAS_COUNT 0
-#define TOKEN_COUNT 48
-#define EXTERNAL_TOKEN_COUNT 26
-#define FIELD_COUNT 8
-#define MAX_ALIAS_SEQUENCE_LENGTH 4
+#define TOKEN_COUNT 57
+#define EXTERNAL_TOKEN_COUNT 31
+#define FIELD_COUNT 10
+#define MAX_ALIAS_SEQUENCE_LENGTH 8
 #define MAX_RESERVED_WORD_SET_SIZE 0
-#define PRODUCTION_ID_COUNT 17
+#define PRODUCTION_ID_COUNT 21
 #define SUPERTYPE_COUNT 0
 
 enum ts_symbol_identifiers {
   anon_sym_LT_BANG = 1,
-  aux_sym_doctype_token1 = 2,
-  anon_sym_GT = 3,
-  sym__doctype = 4,
-  anon_sym_LT = 5,
-  anon_sym_SLASH_GT = 6,
-  anon_sym_LT_SLASH = 7,
-  anon_sym_EQ = 8,
-  aux_sym_attribute_name_token1 = 9,
-  anon_sym_SQUOTE = 10,
-  anon_sym_DQUOTE = 11,
-  sym_entity = 12,
-  anon_sym_DOT = 13,
-  anon_sym_COLON = 14,
-  sym_attribute_expected_equals_tail = 15,
-  anon_sym_LBRACE = 16,
-  anon_sym_RBRACE = 17,
-  sym_attribute_identifier = 18,
-  anon_sym_PIPE = 19,
-  sym_attribute_modifier = 20,
-  aux_sym__quoted_attribute_content_single_token1 = 21,
-  aux_sym__quoted_attribute_content_double_token1 = 22,
-  sym__start_tag_name = 23,
-  sym__raw_text_start_tag_name = 24,
-  sym__end_tag_name = 25,
-  sym_erroneous_end_tag_name = 26,
-  sym__implicit_end_tag = 27,
-  sym_raw_text = 28,
-  sym_comment = 29,
-  sym_text = 30,
-  sym__tag_namespace = 31,
-  sym__tag_local_name = 32,
-  sym__ts_lang_marker = 33,
-  sym__expression_js = 34,
-  sym__expression_ts = 35,
-  sym__attribute_expression_js = 36,
-  sym__attribute_expression_ts = 37,
-  sym__directive_marker = 38,
-  sym__member_tag_object = 39,
-  sym__member_tag_property = 40,
-  sym__attribute_value = 41,
-  sym__pipe_attribute_name = 42,
-  sym__line_tag_comment = 43,
-  sym__block_tag_comment = 44,
-  sym__unterminated_tag_end = 45,
-  sym__textarea_end_boundary = 46,
-  sym__unterminated_tag_end_open = 47,
-  sym_document = 48,
-  sym_doctype = 49,
-  sym__node = 50,
-  sym_element = 51,
-  sym__raw_text_element = 52,
-  sym_start_tag = 53,
-  sym__raw_text_start_tag = 54,
-  sym_self_closing_tag = 55,
-  sym_end_tag = 56,
-  sym_erroneous_end_tag = 57,
-  sym_attribute = 58,
-  sym_attribute_name = 59,
-  sym_quoted_attribute_value = 60,
-  sym__unterminated_start_tag = 61,
-  sym__unterminated_start_tag_with_close = 62,
-  sym__broken_member_unterminated_start_tag = 63,
-  sym__raw_text_unterminated_start_tag = 64,
-  sym__namespaced_start_tag = 65,
-  sym__namespaced_unterminated_start_tag = 66,
-  sym__namespaced_self_closing_tag = 67,
-  sym__namespaced_end_tag = 68,
-  sym__namespaced_tag_name = 69,
-  sym__member_start_tag = 70,
-  sym__member_unterminated_start_tag = 71,
-  sym__member_self_closing_tag = 72,
-  sym__tag_attribute_item = 73,
-  sym_tag_comment = 74,
-  sym__member_end_tag = 75,
-  sym__member_tag_name = 76,
-  sym_expression = 77,
-  sym_attribute_expression = 78,
-  sym_shorthand_attribute = 79,
-  sym___attribute_directive = 80,
-  sym_attribute_modifiers = 81,
-  sym_unquoted_attribute_value = 82,
-  sym__quoted_attribute_content_single = 83,
-  sym__quoted_attribute_content_double = 84,
-  aux_sym_document_repeat1 = 85,
-  aux_sym_start_tag_repeat1 = 86,
-  aux_sym_quoted_attribute_value_repeat1 = 87,
-  aux_sym_quoted_attribute_value_repeat2 = 88,
-  aux_sym__member_tag_name_repeat1 = 89,
-  aux_sym_attribute_modifiers_repeat1 = 90,
-  aux_sym_unquoted_attribute_value_repeat1 = 91,
+  anon_sym_GT = 2,
+  sym__doctype = 3,
+  sym_doctype_name = 4,
+  sym__doctype_public = 5,
+  sym__doctype_system = 6,
+  sym_doctype_identifier = 7,
+  sym__bogus_doctype = 8,
+  anon_sym_LT = 9,
+  anon_sym_SLASH_GT = 10,
+  anon_sym_LT_SLASH = 11,
+  anon_sym_EQ = 12,
+  aux_sym_attribute_name_token1 = 13,
+  anon_sym_SQUOTE = 14,
+  anon_sym_DQUOTE = 15,
+  sym_entity = 16,
+  anon_sym_DOT = 17,
+  anon_sym_COLON = 18,
+  sym_attribute_expected_equals_tail = 19,
+  anon_sym_LBRACE = 20,
+  anon_sym_RBRACE = 21,
+  sym_attribute_identifier = 22,
+  anon_sym_PIPE = 23,
+  sym_attribute_modifier = 24,
+  aux_sym__quoted_attribute_content_single_token1 = 25,
+  aux_sym__quoted_attribute_content_double_token1 = 26,
+  sym__start_tag_name = 27,
+  sym__raw_text_start_tag_name = 28,
+  sym__end_tag_name = 29,
+  sym_erroneous_end_tag_name = 30,
+  sym__implicit_end_tag = 31,
+  sym_raw_text = 32,
+  sym_comment = 33,
+  sym_text = 34,
+  sym_processing_instruction = 35,
+  sym_bogus_comment = 36,
+  sym_cdata_section = 37,
+  sym_cdata = 38,
+  sym__template_start_tag_name = 39,
+  sym__tag_namespace = 40,
+  sym__tag_local_name = 41,
+  sym__ts_lang_marker = 42,
+  sym__expression_js = 43,
+  sym__expression_ts = 44,
+  sym__attribute_expression_js = 45,
+  sym__attribute_expression_ts = 46,
+  sym__directive_marker = 47,
+  sym__member_tag_object = 48,
+  sym__member_tag_property = 49,
+  sym__attribute_value = 50,
+  sym__pipe_attribute_name = 51,
+  sym__line_tag_comment = 52,
+  sym__block_tag_comment = 53,
+  sym__unterminated_tag_end = 54,
+  sym__textarea_end_boundary = 55,
+  sym__unterminated_tag_end_open = 56,
+  sym_document = 57,
+  sym_doctype = 58,
+  sym__node = 59,
+  sym_element = 60,
+  sym__template_element = 61,
+  sym_template_content = 62,
+  sym__raw_text_element = 63,
+  sym_start_tag = 64,
+  sym__raw_text_start_tag = 65,
+  sym__template_start_tag = 66,
+  sym_self_closing_tag = 67,
+  sym__template_self_closing_tag = 68,
+  sym_end_tag = 69,
+  sym_erroneous_end_tag = 70,
+  sym_attribute = 71,
+  sym_attribute_name = 72,
+  sym_quoted_attribute_value = 73,
+  sym__unterminated_start_tag = 74,
+  sym__unterminated_start_tag_with_close = 75,
+  sym__broken_member_unterminated_start_tag = 76,
+  sym__template_unterminated_start_tag = 77,
+  sym__template_unterminated_start_tag_with_close = 78,
+  sym__raw_text_unterminated_start_tag = 79,
+  sym__namespaced_start_tag = 80,
+  sym__namespaced_unterminated_start_tag = 81,
+  sym__namespaced_self_closing_tag = 82,
+  sym__namespaced_end_tag = 83,
+  sym__namespaced_tag_name = 84,
+  sym__member_start_tag = 85,
+  sym__member_unterminated_start_tag = 86,
+  sym__member_self_closing_tag = 87,
+  sym__tag_attribute_item = 88,
+  sym_tag_comment = 89,
+  sym__member_end_tag = 90,
+  sym__member_tag_name = 91,
+  sym_expression = 92,
+  sym_attribute_expression = 93,
+  sym_shorthand_attribute = 94,
+  sym___attribute_directive = 95,
+  sym_attribute_modifiers = 96,
+  sym_unquoted_attribute_value = 97,
+  sym__quoted_attribute_content_single = 98,
+  sym__quoted_attribute_content_double = 99,
+  aux_sym_document_repeat1 = 100,
+  aux_sym_start_tag_repeat1 = 101,
+  aux_sym_quoted_attribute_value_repeat1 = 102,
+  aux_sym_quoted_attribute_value_repeat2 = 103,
+  aux_sym__member_tag_name_repeat1 = 104,
+  aux_sym_attribute_modifiers_repeat1 = 105,
+  aux_sym_unquoted_attribute_value_repeat1 = 106,
 };
 
 static const char * const ts_symbol_names[] = {
   [ts_builtin_sym_end] = "end",
   [anon_sym_LT_BANG] = "<!",
-  [aux_sym_doctype_token1] = "doctype_token1",
   [anon_sym_GT] = ">",
   [sym__doctype] = "doctype",
+  [sym_doctype_name] = "doctype_name",
+  [sym__doctype_public] = "PUBLIC",
+  [sym__doctype_system] = "SYSTEM",
+  [sym_doctype_identifier] = "doctype_identifier",
+  [sym__bogus_doctype] = "bogus",
   [anon_sym_LT] = "<",
   [anon_sym_SLASH_GT] = "/>",
   [anon_sym_LT_SLASH] = "</",
@@ -145,6 +164,11 @@ static const char * const ts_symbol_names[] = {
   [sym_raw_text] = "raw_text",
   [sym_comment] = "comment",
   [sym_text] = "text",
+  [sym_processing_instruction] = "processing_instruction",
+  [sym_bogus_comment] = "bogus_comment",
+  [sym_cdata_section] = "cdata_section",
+  [sym_cdata] = "cdata",
+  [sym__template_start_tag_name] = "tag_name",
   [sym__tag_namespace] = "tag_namespace",
   [sym__tag_local_name] = "tag_local_name",
   [sym__ts_lang_marker] = "_ts_lang_marker",
@@ -166,10 +190,14 @@ static const char * const ts_symbol_names[] = {
   [sym_doctype] = "doctype",
   [sym__node] = "_node",
   [sym_element] = "element",
+  [sym__template_element] = "_template_element",
+  [sym_template_content] = "template_content",
   [sym__raw_text_element] = "_raw_text_element",
   [sym_start_tag] = "start_tag",
   [sym__raw_text_start_tag] = "start_tag",
+  [sym__template_start_tag] = "start_tag",
   [sym_self_closing_tag] = "self_closing_tag",
+  [sym__template_self_closing_tag] = "self_closing_tag",
   [sym_end_tag] = "end_tag",
   [sym_erroneous_end_tag] = "erroneous_end_tag",
   [sym_attribute] = "attribute",
@@ -178,6 +206,8 @@ static const char * const ts_symbol_names[] = {
   [sym__unterminated_start_tag] = "start_tag",
   [sym__unterminated_start_tag_with_close] = "start_tag",
   [sym__broken_member_unterminated_start_tag] = "start_tag",
+  [sym__template_unterminated_start_tag] = "start_tag",
+  [sym__template_unterminated_start_tag_with_close] = "start_tag",
   [sym__raw_text_unterminated_start_tag] = "start_tag",
   [sym__namespaced_start_tag] = "start_tag",
   [sym__namespaced_unterminated_start_tag] = "start_tag",
@@ -211,9 +241,13 @@ static const char * const ts_symbol_names[] = {
 static const TSSymbol ts_symbol_map[] = {
   [ts_builtin_sym_end] = ts_builtin_sym_end,
   [anon_sym_LT_BANG] = anon_sym_LT_BANG,
-  [aux_sym_doctype_token1] = aux_sym_doctype_token1,
   [anon_sym_GT] = anon_sym_GT,
   [sym__doctype] = sym__doctype,
+  [sym_doctype_name] = sym_doctype_name,
+  [sym__doctype_public] = sym__doctype_public,
+  [sym__doctype_system] = sym__doctype_system,
+  [sym_doctype_identifier] = sym_doctype_identifier,
+  [sym__bogus_doctype] = sym__bogus_doctype,
   [anon_sym_LT] = anon_sym_LT,
   [anon_sym_SLASH_GT] = anon_sym_SLASH_GT,
   [anon_sym_LT_SLASH] = anon_sym_LT_SLASH,
@@ -240,6 +274,11 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_raw_text] = sym_raw_text,
   [sym_comment] = sym_comment,
   [sym_text] = sym_text,
+  [sym_processing_instruction] = sym_processing_instruction,
+  [sym_bogus_comment] = sym_bogus_comment,
+  [sym_cdata_section] = sym_cdata_section,
+  [sym_cdata] = sym_cdata,
+  [sym__template_start_tag_name] = sym__start_tag_name,
   [sym__tag_namespace] = sym__tag_namespace,
   [sym__tag_local_name] = sym__tag_local_name,
   [sym__ts_lang_marker] = sym__ts_lang_marker,
@@ -261,10 +300,14 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_doctype] = sym_doctype,
   [sym__node] = sym__node,
   [sym_element] = sym_element,
+  [sym__template_element] = sym__template_element,
+  [sym_template_content] = sym_template_content,
   [sym__raw_text_element] = sym__raw_text_element,
   [sym_start_tag] = sym_start_tag,
   [sym__raw_text_start_tag] = sym_start_tag,
+  [sym__template_start_tag] = sym_start_tag,
   [sym_self_closing_tag] = sym_self_closing_tag,
+  [sym__template_self_closing_tag] = sym_self_closing_tag,
   [sym_end_tag] = sym_end_tag,
   [sym_erroneous_end_tag] = sym_erroneous_end_tag,
   [sym_attribute] = sym_attribute,
@@ -273,6 +316,8 @@ static const TSSymbol ts_symbol_map[] = {
   [sym__unterminated_start_tag] = sym_start_tag,
   [sym__unterminated_start_tag_with_close] = sym_start_tag,
   [sym__broken_member_unterminated_start_tag] = sym_start_tag,
+  [sym__template_unterminated_start_tag] = sym_start_tag,
+  [sym__template_unterminated_start_tag_with_close] = sym_start_tag,
   [sym__raw_text_unterminated_start_tag] = sym_start_tag,
   [sym__namespaced_start_tag] = sym_start_tag,
   [sym__namespaced_unterminated_start_tag] = sym_start_tag,
@@ -312,10 +357,6 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
-  [aux_sym_doctype_token1] = {
-    .visible = false,
-    .named = false,
-  },
   [anon_sym_GT] = {
     .visible = true,
     .named = false,
@@ -324,6 +365,26 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [sym_doctype_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__doctype_public] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym__doctype_system] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_doctype_identifier] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__bogus_doctype] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_LT] = {
     .visible = true,
     .named = false,
@@ -428,6 +489,26 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_processing_instruction] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_bogus_comment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_cdata_section] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_cdata] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__template_start_tag_name] = {
+    .visible = true,
+    .named = true,
+  },
   [sym__tag_namespace] = {
     .visible = true,
     .named = true,
@@ -512,6 +593,14 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym__template_element] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_template_content] = {
+    .visible = true,
+    .named = true,
+  },
   [sym__raw_text_element] = {
     .visible = false,
     .named = true,
@@ -524,10 +613,18 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym__template_start_tag] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_self_closing_tag] = {
     .visible = true,
     .named = true,
   },
+  [sym__template_self_closing_tag] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_end_tag] = {
     .visible = true,
     .named = true,
@@ -560,6 +657,14 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym__template_unterminated_start_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__template_unterminated_start_tag_with_close] = {
+    .visible = true,
+    .named = true,
+  },
   [sym__raw_text_unterminated_start_tag] = {
     .visible = true,
     .named = true,
@@ -681,8 +786,10 @@ enum ts_field_identifiers {
   field_namespace = 4,
   field_object = 5,
   field_property = 6,
-  field_tail = 7,
-  field_value = 8,
+  field_public_id = 7,
+  field_system_id = 8,
+  field_tail = 9,
+  field_value = 10,
 };
 
 static const char * const ts_field_names[] = {
@@ -693,6 +800,8 @@ static const char * const ts_field_names[] = {
   [field_namespace] = "namespace",
   [field_object] = "object",
   [field_property] = "property",
+  [field_public_id] = "public_id",
+  [field_system_id] = "system_id",
   [field_tail] = "tail",
   [field_value] = "value",
 };
@@ -708,12 +817,16 @@ static const TSMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
   [8] = {.index = 11, .length = 3},
   [9] = {.index = 14, .length = 1},
   [10] = {.index = 15, .length = 2},
-  [11] = {.index = 17, .length = 2},
-  [12] = {.index = 19, .length = 2},
-  [13] = {.index = 21, .length = 1},
-  [14] = {.index = 22, .length = 2},
-  [15] = {.index = 24, .length = 2},
-  [16] = {.index = 26, .length = 2},
+  [11] = {.index = 17, .length = 1},
+  [12] = {.index = 18, .length = 2},
+  [13] = {.index = 20, .length = 2},
+  [14] = {.index = 22, .length = 1},
+  [15] = {.index = 23, .length = 2},
+  [16] = {.index = 25, .length = 2},
+  [17] = {.index = 27, .length = 2},
+  [18] = {.index = 29, .length = 2},
+  [19] = {.index = 31, .length = 2},
+  [20] = {.index = 33, .length = 3},
 };
 
 static const TSFieldMapEntry ts_field_map_entries[] = {
@@ -745,22 +858,34 @@ static const TSFieldMapEntry ts_field_map_entries[] = {
     {field_name, 0, .inherited = true},
     {field_name, 2, .inherited = true},
   [17] =
+    {field_name, 2},
+  [18] =
     {field_name, 0},
     {field_tail, 1},
-  [19] =
+  [20] =
     {field_name, 2},
     {field_namespace, 0},
-  [21] =
-    {field_property, 1},
   [22] =
+    {field_property, 1},
+  [23] =
     {field_property, 0, .inherited = true},
     {field_property, 1, .inherited = true},
-  [24] =
+  [25] =
     {field_name, 0},
     {field_value, 2},
-  [26] =
+  [27] =
+    {field_name, 2},
+    {field_public_id, 4},
+  [29] =
+    {field_name, 2},
+    {field_system_id, 4},
+  [31] =
     {field_name, 1},
     {field_value, 3},
+  [33] =
+    {field_name, 2},
+    {field_public_id, 4},
+    {field_system_id, 5},
 };
 
 static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
@@ -776,130 +901,130 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [1] = 1,
   [2] = 2,
   [3] = 3,
-  [4] = 3,
+  [4] = 4,
   [5] = 2,
-  [6] = 6,
-  [7] = 7,
-  [8] = 8,
-  [9] = 9,
-  [10] = 10,
+  [6] = 3,
+  [7] = 4,
+  [8] = 2,
+  [9] = 3,
+  [10] = 4,
   [11] = 11,
   [12] = 12,
   [13] = 13,
-  [14] = 6,
-  [15] = 7,
-  [16] = 8,
-  [17] = 10,
-  [18] = 11,
-  [19] = 12,
-  [20] = 13,
+  [14] = 11,
+  [15] = 12,
+  [16] = 12,
+  [17] = 17,
+  [18] = 18,
+  [19] = 19,
+  [20] = 20,
   [21] = 21,
-  [22] = 21,
+  [22] = 22,
   [23] = 23,
-  [24] = 24,
-  [25] = 24,
-  [26] = 23,
-  [27] = 27,
-  [28] = 28,
-  [29] = 29,
-  [30] = 30,
-  [31] = 30,
-  [32] = 29,
-  [33] = 28,
-  [34] = 27,
-  [35] = 35,
-  [36] = 36,
-  [37] = 35,
-  [38] = 23,
-  [39] = 39,
-  [40] = 36,
-  [41] = 39,
+  [24] = 11,
+  [25] = 17,
+  [26] = 18,
+  [27] = 19,
+  [28] = 21,
+  [29] = 22,
+  [30] = 23,
+  [31] = 17,
+  [32] = 18,
+  [33] = 19,
+  [34] = 21,
+  [35] = 22,
+  [36] = 23,
+  [37] = 13,
+  [38] = 38,
+  [39] = 38,
+  [40] = 38,
+  [41] = 41,
   [42] = 42,
   [43] = 43,
   [44] = 44,
-  [45] = 39,
-  [46] = 43,
-  [47] = 42,
-  [48] = 48,
-  [49] = 49,
-  [50] = 42,
-  [51] = 44,
-  [52] = 43,
+  [45] = 41,
+  [46] = 42,
+  [47] = 43,
+  [48] = 41,
+  [49] = 42,
+  [50] = 43,
+  [51] = 51,
+  [52] = 52,
   [53] = 53,
   [54] = 54,
-  [55] = 55,
-  [56] = 56,
-  [57] = 56,
-  [58] = 44,
-  [59] = 49,
-  [60] = 48,
-  [61] = 55,
-  [62] = 62,
-  [63] = 63,
+  [55] = 44,
+  [56] = 51,
+  [57] = 52,
+  [58] = 53,
+  [59] = 54,
+  [60] = 51,
+  [61] = 52,
+  [62] = 53,
+  [63] = 54,
   [64] = 64,
   [65] = 65,
-  [66] = 53,
-  [67] = 67,
-  [68] = 54,
+  [66] = 66,
+  [67] = 44,
+  [68] = 64,
   [69] = 65,
-  [70] = 70,
-  [71] = 71,
-  [72] = 48,
-  [73] = 56,
-  [74] = 74,
-  [75] = 49,
-  [76] = 64,
-  [77] = 62,
-  [78] = 78,
-  [79] = 62,
-  [80] = 64,
+  [70] = 64,
+  [71] = 65,
+  [72] = 72,
+  [73] = 73,
+  [74] = 66,
+  [75] = 75,
+  [76] = 66,
+  [77] = 72,
+  [78] = 73,
+  [79] = 79,
+  [80] = 80,
   [81] = 81,
-  [82] = 54,
+  [82] = 82,
   [83] = 83,
-  [84] = 53,
-  [85] = 85,
-  [86] = 86,
-  [87] = 87,
+  [84] = 84,
+  [85] = 72,
+  [86] = 73,
+  [87] = 75,
   [88] = 88,
   [89] = 89,
-  [90] = 55,
-  [91] = 88,
-  [92] = 71,
-  [93] = 74,
-  [94] = 64,
-  [95] = 83,
-  [96] = 62,
-  [97] = 86,
-  [98] = 65,
-  [99] = 62,
-  [100] = 87,
-  [101] = 89,
-  [102] = 64,
-  [103] = 70,
-  [104] = 78,
+  [90] = 90,
+  [91] = 75,
+  [92] = 79,
+  [93] = 80,
+  [94] = 81,
+  [95] = 82,
+  [96] = 83,
+  [97] = 84,
+  [98] = 98,
+  [99] = 99,
+  [100] = 100,
+  [101] = 101,
+  [102] = 102,
+  [103] = 103,
+  [104] = 104,
   [105] = 105,
-  [106] = 85,
+  [106] = 106,
   [107] = 107,
   [108] = 108,
-  [109] = 109,
+  [109] = 98,
   [110] = 110,
   [111] = 111,
-  [112] = 112,
-  [113] = 113,
-  [114] = 114,
-  [115] = 115,
-  [116] = 116,
-  [117] = 117,
-  [118] = 118,
-  [119] = 119,
-  [120] = 120,
-  [121] = 121,
+  [112] = 99,
+  [113] = 79,
+  [114] = 80,
+  [115] = 81,
+  [116] = 82,
+  [117] = 83,
+  [118] = 84,
+  [119] = 90,
+  [120] = 98,
+  [121] = 99,
   [122] = 122,
   [123] = 123,
-  [124] = 83,
+  [124] = 124,
   [125] = 125,
   [126] = 126,
-  [127] = 86,
+  [127] = 127,
   [128] = 128,
   [129] = 129,
   [130] = 130,
@@ -915,8 +1040,8 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [140] = 140,
   [141] = 141,
   [142] = 142,
-  [143] = 87,
-  [144] = 89,
+  [143] = 143,
+  [144] = 144,
   [145] = 145,
   [146] = 146,
   [147] = 147,
@@ -928,194 +1053,403 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [153] = 153,
   [154] = 154,
   [155] = 155,
-  [156] = 85,
-  [157] = 88,
-  [158] = 70,
+  [156] = 156,
+  [157] = 157,
+  [158] = 158,
   [159] = 159,
-  [160] = 71,
-  [161] = 62,
-  [162] = 78,
-  [163] = 74,
-  [164] = 64,
+  [160] = 160,
+  [161] = 161,
+  [162] = 162,
+  [163] = 163,
+  [164] = 164,
   [165] = 165,
-  [166] = 142,
-  [167] = 123,
-  [168] = 151,
-  [169] = 152,
-  [170] = 112,
-  [171] = 153,
-  [172] = 154,
-  [173] = 155,
-  [174] = 125,
-  [175] = 117,
-  [176] = 118,
-  [177] = 119,
-  [178] = 126,
-  [179] = 120,
-  [180] = 121,
-  [181] = 114,
-  [182] = 113,
-  [183] = 115,
-  [184] = 110,
-  [185] = 122,
-  [186] = 150,
-  [187] = 108,
-  [188] = 129,
-  [189] = 130,
-  [190] = 107,
-  [191] = 131,
-  [192] = 132,
-  [193] = 133,
-  [194] = 134,
-  [195] = 165,
-  [196] = 136,
-  [197] = 137,
-  [198] = 138,
-  [199] = 139,
-  [200] = 140,
-  [201] = 141,
-  [202] = 149,
-  [203] = 203,
-  [204] = 145,
-  [205] = 111,
-  [206] = 146,
-  [207] = 109,
-  [208] = 147,
-  [209] = 116,
-  [210] = 203,
-  [211] = 203,
-  [212] = 148,
-  [213] = 128,
-  [214] = 214,
-  [215] = 215,
-  [216] = 216,
-  [217] = 217,
-  [218] = 218,
-  [219] = 219,
-  [220] = 220,
-  [221] = 221,
-  [222] = 222,
-  [223] = 223,
-  [224] = 224,
-  [225] = 225,
-  [226] = 217,
-  [227] = 215,
-  [228] = 216,
-  [229] = 225,
-  [230] = 217,
-  [231] = 215,
-  [232] = 216,
-  [233] = 219,
-  [234] = 225,
-  [235] = 235,
-  [236] = 236,
-  [237] = 236,
-  [238] = 236,
-  [239] = 39,
-  [240] = 240,
-  [241] = 42,
-  [242] = 242,
-  [243] = 243,
-  [244] = 62,
-  [245] = 240,
-  [246] = 64,
-  [247] = 62,
-  [248] = 248,
-  [249] = 64,
-  [250] = 248,
-  [251] = 242,
-  [252] = 242,
-  [253] = 240,
-  [254] = 63,
-  [255] = 255,
-  [256] = 248,
-  [257] = 257,
-  [258] = 67,
-  [259] = 248,
-  [260] = 248,
-  [261] = 248,
-  [262] = 248,
-  [263] = 248,
-  [264] = 264,
-  [265] = 257,
-  [266] = 243,
-  [267] = 264,
-  [268] = 255,
-  [269] = 43,
-  [270] = 270,
-  [271] = 271,
-  [272] = 270,
-  [273] = 273,
-  [274] = 274,
-  [275] = 275,
-  [276] = 276,
-  [277] = 274,
-  [278] = 44,
-  [279] = 270,
-  [280] = 273,
-  [281] = 270,
-  [282] = 81,
-  [283] = 283,
-  [284] = 271,
-  [285] = 283,
-  [286] = 286,
-  [287] = 287,
-  [288] = 288,
-  [289] = 289,
-  [290] = 290,
-  [291] = 291,
-  [292] = 292,
-  [293] = 56,
-  [294] = 294,
-  [295] = 289,
-  [296] = 296,
-  [297] = 297,
-  [298] = 298,
-  [299] = 299,
-  [300] = 54,
-  [301] = 301,
-  [302] = 298,
-  [303] = 299,
-  [304] = 291,
-  [305] = 292,
-  [306] = 296,
-  [307] = 286,
-  [308] = 290,
-  [309] = 301,
-  [310] = 310,
-  [311] = 298,
-  [312] = 312,
-  [313] = 105,
-  [314] = 314,
-  [315] = 291,
-  [316] = 291,
-  [317] = 298,
-  [318] = 301,
-  [319] = 319,
-  [320] = 301,
-  [321] = 321,
-  [322] = 301,
-  [323] = 292,
-  [324] = 301,
-  [325] = 294,
-  [326] = 301,
-  [327] = 327,
-  [328] = 328,
-  [329] = 314,
-  [330] = 319,
-  [331] = 297,
-  [332] = 327,
-  [333] = 333,
-  [334] = 287,
-  [335] = 310,
-  [336] = 312,
-  [337] = 310,
-  [338] = 312,
-  [339] = 310,
-  [340] = 333,
-  [341] = 328,
-  [342] = 321,
-  [343] = 301,
+  [166] = 166,
+  [167] = 167,
+  [168] = 168,
+  [169] = 169,
+  [170] = 170,
+  [171] = 171,
+  [172] = 172,
+  [173] = 173,
+  [174] = 174,
+  [175] = 175,
+  [176] = 176,
+  [177] = 177,
+  [178] = 178,
+  [179] = 179,
+  [180] = 180,
+  [181] = 181,
+  [182] = 182,
+  [183] = 183,
+  [184] = 184,
+  [185] = 185,
+  [186] = 186,
+  [187] = 187,
+  [188] = 188,
+  [189] = 90,
+  [190] = 190,
+  [191] = 191,
+  [192] = 192,
+  [193] = 193,
+  [194] = 100,
+  [195] = 101,
+  [196] = 102,
+  [197] = 103,
+  [198] = 105,
+  [199] = 106,
+  [200] = 107,
+  [201] = 108,
+  [202] = 98,
+  [203] = 110,
+  [204] = 111,
+  [205] = 99,
+  [206] = 98,
+  [207] = 99,
+  [208] = 208,
+  [209] = 209,
+  [210] = 100,
+  [211] = 101,
+  [212] = 102,
+  [213] = 103,
+  [214] = 105,
+  [215] = 106,
+  [216] = 107,
+  [217] = 108,
+  [218] = 98,
+  [219] = 110,
+  [220] = 111,
+  [221] = 99,
+  [222] = 125,
+  [223] = 126,
+  [224] = 127,
+  [225] = 128,
+  [226] = 129,
+  [227] = 130,
+  [228] = 131,
+  [229] = 132,
+  [230] = 133,
+  [231] = 134,
+  [232] = 135,
+  [233] = 136,
+  [234] = 137,
+  [235] = 138,
+  [236] = 139,
+  [237] = 140,
+  [238] = 141,
+  [239] = 142,
+  [240] = 143,
+  [241] = 144,
+  [242] = 145,
+  [243] = 146,
+  [244] = 147,
+  [245] = 148,
+  [246] = 149,
+  [247] = 150,
+  [248] = 151,
+  [249] = 152,
+  [250] = 153,
+  [251] = 154,
+  [252] = 155,
+  [253] = 156,
+  [254] = 157,
+  [255] = 158,
+  [256] = 159,
+  [257] = 160,
+  [258] = 161,
+  [259] = 162,
+  [260] = 163,
+  [261] = 164,
+  [262] = 165,
+  [263] = 166,
+  [264] = 167,
+  [265] = 168,
+  [266] = 169,
+  [267] = 170,
+  [268] = 171,
+  [269] = 172,
+  [270] = 173,
+  [271] = 174,
+  [272] = 175,
+  [273] = 176,
+  [274] = 177,
+  [275] = 178,
+  [276] = 179,
+  [277] = 180,
+  [278] = 181,
+  [279] = 182,
+  [280] = 183,
+  [281] = 184,
+  [282] = 185,
+  [283] = 186,
+  [284] = 187,
+  [285] = 188,
+  [286] = 190,
+  [287] = 191,
+  [288] = 192,
+  [289] = 193,
+  [290] = 125,
+  [291] = 126,
+  [292] = 127,
+  [293] = 128,
+  [294] = 129,
+  [295] = 130,
+  [296] = 131,
+  [297] = 132,
+  [298] = 133,
+  [299] = 134,
+  [300] = 135,
+  [301] = 136,
+  [302] = 137,
+  [303] = 138,
+  [304] = 139,
+  [305] = 140,
+  [306] = 141,
+  [307] = 142,
+  [308] = 143,
+  [309] = 144,
+  [310] = 145,
+  [311] = 146,
+  [312] = 147,
+  [313] = 148,
+  [314] = 149,
+  [315] = 150,
+  [316] = 151,
+  [317] = 152,
+  [318] = 153,
+  [319] = 154,
+  [320] = 155,
+  [321] = 156,
+  [322] = 157,
+  [323] = 158,
+  [324] = 159,
+  [325] = 160,
+  [326] = 161,
+  [327] = 162,
+  [328] = 163,
+  [329] = 164,
+  [330] = 165,
+  [331] = 166,
+  [332] = 167,
+  [333] = 168,
+  [334] = 169,
+  [335] = 170,
+  [336] = 171,
+  [337] = 172,
+  [338] = 173,
+  [339] = 174,
+  [340] = 175,
+  [341] = 176,
+  [342] = 177,
+  [343] = 178,
+  [344] = 179,
+  [345] = 180,
+  [346] = 181,
+  [347] = 182,
+  [348] = 183,
+  [349] = 184,
+  [350] = 185,
+  [351] = 186,
+  [352] = 187,
+  [353] = 188,
+  [354] = 190,
+  [355] = 191,
+  [356] = 192,
+  [357] = 193,
+  [358] = 358,
+  [359] = 359,
+  [360] = 360,
+  [361] = 361,
+  [362] = 362,
+  [363] = 363,
+  [364] = 364,
+  [365] = 365,
+  [366] = 366,
+  [367] = 367,
+  [368] = 363,
+  [369] = 363,
+  [370] = 358,
+  [371] = 358,
+  [372] = 372,
+  [373] = 373,
+  [374] = 374,
+  [375] = 375,
+  [376] = 376,
+  [377] = 377,
+  [378] = 372,
+  [379] = 373,
+  [380] = 374,
+  [381] = 375,
+  [382] = 372,
+  [383] = 373,
+  [384] = 374,
+  [385] = 375,
+  [386] = 386,
+  [387] = 386,
+  [388] = 386,
+  [389] = 389,
+  [390] = 390,
+  [391] = 66,
+  [392] = 389,
+  [393] = 390,
+  [394] = 389,
+  [395] = 390,
+  [396] = 396,
+  [397] = 397,
+  [398] = 398,
+  [399] = 399,
+  [400] = 400,
+  [401] = 401,
+  [402] = 402,
+  [403] = 403,
+  [404] = 404,
+  [405] = 405,
+  [406] = 88,
+  [407] = 89,
+  [408] = 72,
+  [409] = 73,
+  [410] = 98,
+  [411] = 99,
+  [412] = 98,
+  [413] = 99,
+  [414] = 396,
+  [415] = 397,
+  [416] = 400,
+  [417] = 401,
+  [418] = 402,
+  [419] = 403,
+  [420] = 404,
+  [421] = 405,
+  [422] = 396,
+  [423] = 397,
+  [424] = 400,
+  [425] = 401,
+  [426] = 402,
+  [427] = 403,
+  [428] = 404,
+  [429] = 405,
+  [430] = 405,
+  [431] = 405,
+  [432] = 405,
+  [433] = 405,
+  [434] = 405,
+  [435] = 398,
+  [436] = 399,
+  [437] = 398,
+  [438] = 399,
+  [439] = 439,
+  [440] = 440,
+  [441] = 441,
+  [442] = 442,
+  [443] = 443,
+  [444] = 444,
+  [445] = 445,
+  [446] = 446,
+  [447] = 447,
+  [448] = 448,
+  [449] = 449,
+  [450] = 104,
+  [451] = 75,
+  [452] = 439,
+  [453] = 440,
+  [454] = 441,
+  [455] = 442,
+  [456] = 443,
+  [457] = 444,
+  [458] = 446,
+  [459] = 448,
+  [460] = 449,
+  [461] = 439,
+  [462] = 441,
+  [463] = 442,
+  [464] = 443,
+  [465] = 444,
+  [466] = 446,
+  [467] = 448,
+  [468] = 449,
+  [469] = 442,
+  [470] = 442,
+  [471] = 442,
+  [472] = 442,
+  [473] = 442,
+  [474] = 442,
+  [475] = 475,
+  [476] = 476,
+  [477] = 477,
+  [478] = 478,
+  [479] = 479,
+  [480] = 480,
+  [481] = 481,
+  [482] = 482,
+  [483] = 483,
+  [484] = 484,
+  [485] = 485,
+  [486] = 486,
+  [487] = 487,
+  [488] = 488,
+  [489] = 489,
+  [490] = 490,
+  [491] = 491,
+  [492] = 492,
+  [493] = 493,
+  [494] = 494,
+  [495] = 495,
+  [496] = 496,
+  [497] = 497,
+  [498] = 498,
+  [499] = 124,
+  [500] = 79,
+  [501] = 82,
+  [502] = 479,
+  [503] = 480,
+  [504] = 483,
+  [505] = 484,
+  [506] = 485,
+  [507] = 486,
+  [508] = 487,
+  [509] = 488,
+  [510] = 489,
+  [511] = 491,
+  [512] = 492,
+  [513] = 493,
+  [514] = 494,
+  [515] = 495,
+  [516] = 496,
+  [517] = 497,
+  [518] = 498,
+  [519] = 479,
+  [520] = 480,
+  [521] = 483,
+  [522] = 486,
+  [523] = 487,
+  [524] = 488,
+  [525] = 489,
+  [526] = 491,
+  [527] = 492,
+  [528] = 493,
+  [529] = 494,
+  [530] = 495,
+  [531] = 496,
+  [532] = 497,
+  [533] = 498,
+  [534] = 491,
+  [535] = 496,
+  [536] = 497,
+  [537] = 497,
+  [538] = 497,
+  [539] = 497,
+  [540] = 497,
+  [541] = 475,
+  [542] = 476,
+  [543] = 478,
+  [544] = 481,
+  [545] = 482,
+  [546] = 490,
+  [547] = 475,
+  [548] = 476,
+  [549] = 481,
+  [550] = 482,
+  [551] = 490,
+  [552] = 482,
 };
 
 static const TSCharacterRange extras_character_set_1[] = {
@@ -1145,318 +1479,524 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
   eof = lexer->eof(lexer);
   switch (state) {
     case 0:
-      if (eof) ADVANCE(18);
+      if (eof) ADVANCE(31);
       ADVANCE_MAP(
-        '"', 30,
-        '&', 2,
-        '\'', 29,
-        '.', 45,
-        '/', 6,
-        ':', 46,
-        '<', 24,
-        '=', 27,
-        '>', 22,
-        '{', 48,
-        '|', 51,
-        '}', 49,
-        'D', 9,
-        'd', 9,
+        '"', 40,
+        '&', 4,
+        '\'', 41,
+        '.', 77,
+        '/', 11,
+        ':', 78,
+        '<', 56,
+        '=', 59,
+        '>', 33,
+        '{', 80,
+        '|', 83,
+        '}', 81,
+        'D', 20,
+        'd', 20,
+        'P', 25,
+        'p', 25,
+        'S', 27,
+        's', 27,
       );
       if (set_contains(extras_character_set_1, 11, lookahead)) SKIP(0);
       END_STATE();
     case 1:
-      if (lookahead == '"') ADVANCE(30);
-      if (lookahead == '{') ADVANCE(48);
-      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(55);
-      if (lookahead != 0) ADVANCE(56);
+      if (lookahead == '"') ADVANCE(40);
+      if (lookahead == '\'') ADVANCE(41);
+      if (lookahead == '>') ADVANCE(33);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(1);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(42);
+      if (lookahead != 0) ADVANCE(55);
       END_STATE();
     case 2:
-      if (lookahead == '#') ADVANCE(12);
-      if (('A' <= lookahead && lookahead <= 'Z') ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(44);
+      if (lookahead == '"') ADVANCE(62);
+      if (lookahead == '\'') ADVANCE(61);
+      if (lookahead == '{') ADVANCE(80);
+      if (set_contains(extras_character_set_1, 11, lookahead)) SKIP(2);
+      if (lookahead != 0 &&
+          lookahead != '/' &&
+          (lookahead < '<' || '>' < lookahead) &&
+          lookahead != '\\' &&
+          (lookahead < '{' || '}' < lookahead)) ADVANCE(82);
       END_STATE();
     case 3:
-      if (lookahead == '\'') ADVANCE(29);
-      if (lookahead == '{') ADVANCE(48);
-      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(53);
-      if (lookahead != 0) ADVANCE(54);
+      if (lookahead == '"') ADVANCE(62);
+      if (lookahead == '{') ADVANCE(80);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(87);
+      if (lookahead != 0) ADVANCE(88);
       END_STATE();
     case 4:
+      if (lookahead == '#') ADVANCE(26);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(76);
+      END_STATE();
+    case 5:
+      if (lookahead == '\'') ADVANCE(61);
+      if (lookahead == '{') ADVANCE(80);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(85);
+      if (lookahead != 0) ADVANCE(86);
+      END_STATE();
+    case 6:
       ADVANCE_MAP(
-        '.', 45,
-        '/', 6,
-        '=', 27,
-        '>', 22,
-        '{', 48,
-        '|', 51,
-        '"', 47,
-        '\'', 47,
+        '.', 77,
+        '/', 11,
+        '=', 59,
+        '>', 33,
+        '{', 80,
+        '|', 83,
+        '"', 79,
+        '\'', 79,
       );
-      if (set_contains(extras_character_set_1, 11, lookahead)) SKIP(5);
+      if (set_contains(extras_character_set_1, 11, lookahead)) SKIP(7);
       if (lookahead != 0 &&
           (lookahead < '\'' || ')' < lookahead) &&
           lookahead != ':' &&
           (lookahead < '<' || '>' < lookahead) &&
           lookahead != '\\' &&
-          (lookahead < '{' || '}' < lookahead)) ADVANCE(28);
+          (lookahead < '{' || '}' < lookahead)) ADVANCE(60);
       END_STATE();
-    case 5:
-      if (lookahead == '.') ADVANCE(45);
-      if (lookahead == '/') ADVANCE(6);
-      if (lookahead == '=') ADVANCE(27);
-      if (lookahead == '>') ADVANCE(22);
-      if (lookahead == '{') ADVANCE(48);
-      if (lookahead == '|') ADVANCE(51);
-      if (set_contains(extras_character_set_1, 11, lookahead)) SKIP(5);
+    case 7:
+      if (lookahead == '.') ADVANCE(77);
+      if (lookahead == '/') ADVANCE(11);
+      if (lookahead == '=') ADVANCE(59);
+      if (lookahead == '>') ADVANCE(33);
+      if (lookahead == '{') ADVANCE(80);
+      if (lookahead == '|') ADVANCE(83);
+      if (set_contains(extras_character_set_1, 11, lookahead)) SKIP(7);
       if (lookahead != 0 &&
           lookahead != '"' &&
           (lookahead < '\'' || ')' < lookahead) &&
           lookahead != ':' &&
           (lookahead < '<' || '>' < lookahead) &&
           lookahead != '\\' &&
-          (lookahead < '{' || '}' < lookahead)) ADVANCE(28);
-      END_STATE();
-    case 6:
-      if (lookahead == '>') ADVANCE(25);
-      END_STATE();
-    case 7:
-      if (lookahead == 'C' ||
-          lookahead == 'c') ADVANCE(11);
+          (lookahead < '{' || '}' < lookahead)) ADVANCE(60);
       END_STATE();
     case 8:
-      if (lookahead == 'E' ||
-          lookahead == 'e') ADVANCE(23);
+      if (lookahead == '>') ADVANCE(33);
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(52);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(53);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(8);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(49);
+      if (lookahead != 0) ADVANCE(55);
       END_STATE();
     case 9:
-      if (lookahead == 'O' ||
-          lookahead == 'o') ADVANCE(7);
+      if (lookahead == '>') ADVANCE(33);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(9);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(35);
+      if (lookahead != 0) ADVANCE(36);
       END_STATE();
     case 10:
-      if (lookahead == 'P' ||
-          lookahead == 'p') ADVANCE(8);
+      if (lookahead == '>') ADVANCE(33);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(10);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(54);
+      if (lookahead != 0) ADVANCE(55);
       END_STATE();
     case 11:
-      if (lookahead == 'T' ||
-          lookahead == 't') ADVANCE(13);
+      if (lookahead == '>') ADVANCE(57);
       END_STATE();
     case 12:
-      if (lookahead == 'X' ||
-          lookahead == 'x') ADVANCE(14);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(38);
+      if (lookahead == 'B' ||
+          lookahead == 'b') ADVANCE(18);
       END_STATE();
     case 13:
-      if (lookahead == 'Y' ||
-          lookahead == 'y') ADVANCE(10);
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(24);
       END_STATE();
     case 14:
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'F') ||
-          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(43);
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(37);
       END_STATE();
     case 15:
-      if (set_contains(extras_character_set_1, 11, lookahead)) SKIP(15);
-      if (lookahead != 0 &&
-          lookahead != '"' &&
-          lookahead != '\'' &&
-          lookahead != '/' &&
-          (lookahead < '<' || '>' < lookahead) &&
-          lookahead != '\\' &&
-          (lookahead < '{' || '}' < lookahead)) ADVANCE(50);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(19);
       END_STATE();
     case 16:
-      if (set_contains(extras_character_set_1, 11, lookahead)) SKIP(16);
-      if (lookahead == '$' ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(52);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(34);
       END_STATE();
     case 17:
-      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(20);
-      if (lookahead != 0 &&
-          lookahead != '>') ADVANCE(21);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(14);
       END_STATE();
     case 18:
-      ACCEPT_TOKEN(ts_builtin_sym_end);
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(17);
       END_STATE();
     case 19:
-      ACCEPT_TOKEN(anon_sym_LT_BANG);
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(38);
       END_STATE();
     case 20:
-      ACCEPT_TOKEN(aux_sym_doctype_token1);
-      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(20);
-      if (lookahead != 0 &&
-          lookahead != '>') ADVANCE(21);
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(13);
       END_STATE();
     case 21:
-      ACCEPT_TOKEN(aux_sym_doctype_token1);
-      if (lookahead != 0 &&
-          lookahead != '>') ADVANCE(21);
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(16);
       END_STATE();
     case 22:
-      ACCEPT_TOKEN(anon_sym_GT);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(23);
       END_STATE();
     case 23:
-      ACCEPT_TOKEN(sym__doctype);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(15);
       END_STATE();
     case 24:
-      ACCEPT_TOKEN(anon_sym_LT);
-      if (lookahead == '!') ADVANCE(19);
-      if (lookahead == '/') ADVANCE(26);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(28);
       END_STATE();
     case 25:
-      ACCEPT_TOKEN(anon_sym_SLASH_GT);
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(12);
       END_STATE();
     case 26:
-      ACCEPT_TOKEN(anon_sym_LT_SLASH);
+      if (lookahead == 'X' ||
+          lookahead == 'x') ADVANCE(29);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(70);
       END_STATE();
     case 27:
-      ACCEPT_TOKEN(anon_sym_EQ);
+      if (lookahead == 'Y' ||
+          lookahead == 'y') ADVANCE(22);
       END_STATE();
     case 28:
-      ACCEPT_TOKEN(aux_sym_attribute_name_token1);
-      if ((!eof && set_contains(aux_sym_attribute_name_token1_character_set_2, 19, lookahead))) ADVANCE(28);
+      if (lookahead == 'Y' ||
+          lookahead == 'y') ADVANCE(21);
       END_STATE();
     case 29:
-      ACCEPT_TOKEN(anon_sym_SQUOTE);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(75);
       END_STATE();
     case 30:
-      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      if (set_contains(extras_character_set_1, 11, lookahead)) SKIP(30);
+      if (lookahead == '$' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
       END_STATE();
     case 31:
-      ACCEPT_TOKEN(sym_entity);
+      ACCEPT_TOKEN(ts_builtin_sym_end);
       END_STATE();
     case 32:
-      ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(31);
+      ACCEPT_TOKEN(anon_sym_LT_BANG);
       END_STATE();
     case 33:
-      ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(31);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(32);
+      ACCEPT_TOKEN(anon_sym_GT);
       END_STATE();
     case 34:
-      ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(31);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(33);
+      ACCEPT_TOKEN(sym__doctype);
       END_STATE();
     case 35:
-      ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(31);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(34);
+      ACCEPT_TOKEN(sym_doctype_name);
+      if ((set_contains(extras_character_set_1, 11, lookahead)) &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ') ADVANCE(35);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '>') ADVANCE(36);
       END_STATE();
     case 36:
-      ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(31);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(35);
+      ACCEPT_TOKEN(sym_doctype_name);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '>') ADVANCE(36);
       END_STATE();
     case 37:
-      ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(31);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(36);
+      ACCEPT_TOKEN(sym__doctype_public);
       END_STATE();
     case 38:
-      ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(31);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(37);
+      ACCEPT_TOKEN(sym__doctype_system);
       END_STATE();
     case 39:
+      ACCEPT_TOKEN(sym_doctype_identifier);
+      END_STATE();
+    case 40:
+      ACCEPT_TOKEN(sym_doctype_identifier);
+      if (lookahead == '"') ADVANCE(39);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(40);
+      END_STATE();
+    case 41:
+      ACCEPT_TOKEN(sym_doctype_identifier);
+      if (lookahead == '\'') ADVANCE(39);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(41);
+      END_STATE();
+    case 42:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == '"') ADVANCE(40);
+      if (lookahead == '\'') ADVANCE(41);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(42);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(42);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(55);
+      END_STATE();
+    case 43:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'B' ||
+          lookahead == 'b') ADVANCE(47);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(55);
+      END_STATE();
+    case 44:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(37);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(55);
+      END_STATE();
+    case 45:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(48);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(55);
+      END_STATE();
+    case 46:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(44);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(55);
+      END_STATE();
+    case 47:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(46);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(55);
+      END_STATE();
+    case 48:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(38);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(55);
+      END_STATE();
+    case 49:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(52);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(53);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(49);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(49);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(55);
+      END_STATE();
+    case 50:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(51);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(55);
+      END_STATE();
+    case 51:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(45);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(55);
+      END_STATE();
+    case 52:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(43);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(55);
+      END_STATE();
+    case 53:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead == 'Y' ||
+          lookahead == 'y') ADVANCE(50);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(55);
+      END_STATE();
+    case 54:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(54);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(54);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(55);
+      END_STATE();
+    case 55:
+      ACCEPT_TOKEN(sym__bogus_doctype);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(55);
+      END_STATE();
+    case 56:
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '!') ADVANCE(32);
+      if (lookahead == '/') ADVANCE(58);
+      END_STATE();
+    case 57:
+      ACCEPT_TOKEN(anon_sym_SLASH_GT);
+      END_STATE();
+    case 58:
+      ACCEPT_TOKEN(anon_sym_LT_SLASH);
+      END_STATE();
+    case 59:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      END_STATE();
+    case 60:
+      ACCEPT_TOKEN(aux_sym_attribute_name_token1);
+      if ((!eof && set_contains(aux_sym_attribute_name_token1_character_set_2, 19, lookahead))) ADVANCE(60);
+      END_STATE();
+    case 61:
+      ACCEPT_TOKEN(anon_sym_SQUOTE);
+      END_STATE();
+    case 62:
+      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      END_STATE();
+    case 63:
+      ACCEPT_TOKEN(sym_entity);
+      END_STATE();
+    case 64:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(63);
+      END_STATE();
+    case 65:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(63);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(64);
+      END_STATE();
+    case 66:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(63);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(65);
+      END_STATE();
+    case 67:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(63);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(66);
+      END_STATE();
+    case 68:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(63);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(67);
+      END_STATE();
+    case 69:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(63);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(68);
+      END_STATE();
+    case 70:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(63);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(69);
+      END_STATE();
+    case 71:
       ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(31);
+      if (lookahead == ';') ADVANCE(63);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'F') ||
-          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(32);
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(64);
       END_STATE();
-    case 40:
+    case 72:
       ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(31);
+      if (lookahead == ';') ADVANCE(63);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'F') ||
-          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(39);
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(71);
       END_STATE();
-    case 41:
+    case 73:
       ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(31);
+      if (lookahead == ';') ADVANCE(63);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'F') ||
-          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(40);
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(72);
       END_STATE();
-    case 42:
+    case 74:
       ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(31);
+      if (lookahead == ';') ADVANCE(63);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'F') ||
-          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(41);
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(73);
       END_STATE();
-    case 43:
+    case 75:
       ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(31);
+      if (lookahead == ';') ADVANCE(63);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'F') ||
-          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(42);
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(74);
       END_STATE();
-    case 44:
+    case 76:
       ACCEPT_TOKEN(sym_entity);
-      if (lookahead == ';') ADVANCE(31);
+      if (lookahead == ';') ADVANCE(63);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(44);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(76);
       END_STATE();
-    case 45:
+    case 77:
       ACCEPT_TOKEN(anon_sym_DOT);
       END_STATE();
-    case 46:
+    case 78:
       ACCEPT_TOKEN(anon_sym_COLON);
       END_STATE();
-    case 47:
+    case 79:
       ACCEPT_TOKEN(sym_attribute_expected_equals_tail);
-      if ((!eof && set_contains(sym_attribute_expected_equals_tail_character_set_1, 13, lookahead))) ADVANCE(47);
+      if ((!eof && set_contains(sym_attribute_expected_equals_tail_character_set_1, 13, lookahead))) ADVANCE(79);
       END_STATE();
-    case 48:
+    case 80:
       ACCEPT_TOKEN(anon_sym_LBRACE);
       END_STATE();
-    case 49:
+    case 81:
       ACCEPT_TOKEN(anon_sym_RBRACE);
       END_STATE();
-    case 50:
+    case 82:
       ACCEPT_TOKEN(sym_attribute_identifier);
-      if ((!eof && set_contains(sym_attribute_identifier_character_set_1, 18, lookahead))) ADVANCE(50);
+      if ((!eof && set_contains(sym_attribute_identifier_character_set_1, 18, lookahead))) ADVANCE(82);
       END_STATE();
-    case 51:
+    case 83:
       ACCEPT_TOKEN(anon_sym_PIPE);
       END_STATE();
-    case 52:
+    case 84:
       ACCEPT_TOKEN(sym_attribute_modifier);
       if (lookahead == '$' ||
           ('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(52);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
       END_STATE();
-    case 53:
+    case 85:
       ACCEPT_TOKEN(aux_sym__quoted_attribute_content_single_token1);
-      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(53);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(85);
       if (lookahead != 0 &&
           lookahead != '\'' &&
-          lookahead != '{') ADVANCE(54);
+          lookahead != '{') ADVANCE(86);
       END_STATE();
-    case 54:
+    case 86:
       ACCEPT_TOKEN(aux_sym__quoted_attribute_content_single_token1);
       if (lookahead != 0 &&
           lookahead != '\'' &&
-          lookahead != '{') ADVANCE(54);
+          lookahead != '{') ADVANCE(86);
       END_STATE();
-    case 55:
+    case 87:
       ACCEPT_TOKEN(aux_sym__quoted_attribute_content_double_token1);
-      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(55);
+      if (set_contains(extras_character_set_1, 11, lookahead)) ADVANCE(87);
       if (lookahead != 0 &&
           lookahead != '"' &&
-          lookahead != '{') ADVANCE(56);
+          lookahead != '{') ADVANCE(88);
       END_STATE();
-    case 56:
+    case 88:
       ACCEPT_TOKEN(aux_sym__quoted_attribute_content_double_token1);
       if (lookahead != 0 &&
           lookahead != '"' &&
-          lookahead != '{') ADVANCE(56);
+          lookahead != '{') ADVANCE(88);
       END_STATE();
     default:
       return false;
@@ -1467,131 +2007,131 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [0] = {.lex_state = 0, .external_lex_state = 1},
   [1] = {.lex_state = 0, .external_lex_state = 2},
   [2] = {.lex_state = 0, .external_lex_state = 3},
-  [3] = {.lex_state = 0, .external_lex_state = 3},
+  [3] = {.lex_state = 0, .external_lex_state = 4},
   [4] = {.lex_state = 0, .external_lex_state = 3},
   [5] = {.lex_state = 0, .external_lex_state = 3},
-  [6] = {.lex_state = 0, .external_lex_state = 3},
-  [7] = {.lex_state = 0, .external_lex_state = 2},
-  [8] = {.lex_state = 0, .external_lex_state = 2},
-  [9] = {.lex_state = 0, .external_lex_state = 2},
-  [10] = {.lex_state = 0, .external_lex_state = 2},
-  [11] = {.lex_state = 0, .external_lex_state = 2},
+  [6] = {.lex_state = 0, .external_lex_state = 4},
+  [7] = {.lex_state = 0, .external_lex_state = 3},
+  [8] = {.lex_state = 0, .external_lex_state = 3},
+  [9] = {.lex_state = 0, .external_lex_state = 4},
+  [10] = {.lex_state = 0, .external_lex_state = 3},
+  [11] = {.lex_state = 0, .external_lex_state = 3},
   [12] = {.lex_state = 0, .external_lex_state = 2},
-  [13] = {.lex_state = 0, .external_lex_state = 2},
-  [14] = {.lex_state = 0, .external_lex_state = 2},
+  [13] = {.lex_state = 0, .external_lex_state = 4},
+  [14] = {.lex_state = 0, .external_lex_state = 4},
   [15] = {.lex_state = 0, .external_lex_state = 2},
   [16] = {.lex_state = 0, .external_lex_state = 2},
   [17] = {.lex_state = 0, .external_lex_state = 2},
   [18] = {.lex_state = 0, .external_lex_state = 2},
   [19] = {.lex_state = 0, .external_lex_state = 2},
   [20] = {.lex_state = 0, .external_lex_state = 2},
-  [21] = {.lex_state = 4, .external_lex_state = 4},
-  [22] = {.lex_state = 4, .external_lex_state = 4},
-  [23] = {.lex_state = 4, .external_lex_state = 4},
-  [24] = {.lex_state = 4, .external_lex_state = 4},
-  [25] = {.lex_state = 4, .external_lex_state = 4},
-  [26] = {.lex_state = 4, .external_lex_state = 5},
-  [27] = {.lex_state = 4, .external_lex_state = 5},
-  [28] = {.lex_state = 4, .external_lex_state = 5},
-  [29] = {.lex_state = 4, .external_lex_state = 5},
-  [30] = {.lex_state = 4, .external_lex_state = 5},
-  [31] = {.lex_state = 4, .external_lex_state = 5},
-  [32] = {.lex_state = 4, .external_lex_state = 5},
-  [33] = {.lex_state = 4, .external_lex_state = 5},
-  [34] = {.lex_state = 4, .external_lex_state = 5},
-  [35] = {.lex_state = 4, .external_lex_state = 6},
-  [36] = {.lex_state = 4, .external_lex_state = 6},
-  [37] = {.lex_state = 4, .external_lex_state = 6},
-  [38] = {.lex_state = 4, .external_lex_state = 6},
-  [39] = {.lex_state = 4, .external_lex_state = 4},
-  [40] = {.lex_state = 4, .external_lex_state = 6},
-  [41] = {.lex_state = 4, .external_lex_state = 5},
-  [42] = {.lex_state = 4, .external_lex_state = 4},
-  [43] = {.lex_state = 4, .external_lex_state = 4},
-  [44] = {.lex_state = 4, .external_lex_state = 4},
-  [45] = {.lex_state = 4, .external_lex_state = 6},
-  [46] = {.lex_state = 4, .external_lex_state = 5},
-  [47] = {.lex_state = 4, .external_lex_state = 5},
-  [48] = {.lex_state = 4, .external_lex_state = 4},
-  [49] = {.lex_state = 4, .external_lex_state = 4},
-  [50] = {.lex_state = 4, .external_lex_state = 6},
-  [51] = {.lex_state = 4, .external_lex_state = 5},
-  [52] = {.lex_state = 4, .external_lex_state = 6},
-  [53] = {.lex_state = 4, .external_lex_state = 4},
-  [54] = {.lex_state = 4, .external_lex_state = 4},
-  [55] = {.lex_state = 4, .external_lex_state = 4},
-  [56] = {.lex_state = 4, .external_lex_state = 4},
-  [57] = {.lex_state = 4, .external_lex_state = 5},
-  [58] = {.lex_state = 4, .external_lex_state = 6},
-  [59] = {.lex_state = 4, .external_lex_state = 5},
-  [60] = {.lex_state = 4, .external_lex_state = 5},
-  [61] = {.lex_state = 4, .external_lex_state = 5},
-  [62] = {.lex_state = 4, .external_lex_state = 7},
-  [63] = {.lex_state = 4, .external_lex_state = 5},
-  [64] = {.lex_state = 4, .external_lex_state = 7},
-  [65] = {.lex_state = 4, .external_lex_state = 7},
-  [66] = {.lex_state = 4, .external_lex_state = 5},
-  [67] = {.lex_state = 4, .external_lex_state = 5},
-  [68] = {.lex_state = 4, .external_lex_state = 5},
-  [69] = {.lex_state = 4, .external_lex_state = 8},
-  [70] = {.lex_state = 4, .external_lex_state = 4},
-  [71] = {.lex_state = 4, .external_lex_state = 4},
-  [72] = {.lex_state = 4, .external_lex_state = 6},
-  [73] = {.lex_state = 4, .external_lex_state = 6},
-  [74] = {.lex_state = 4, .external_lex_state = 4},
-  [75] = {.lex_state = 4, .external_lex_state = 6},
-  [76] = {.lex_state = 4, .external_lex_state = 4},
-  [77] = {.lex_state = 4, .external_lex_state = 4},
-  [78] = {.lex_state = 4, .external_lex_state = 4},
-  [79] = {.lex_state = 4, .external_lex_state = 8},
-  [80] = {.lex_state = 4, .external_lex_state = 8},
-  [81] = {.lex_state = 4, .external_lex_state = 5},
-  [82] = {.lex_state = 4, .external_lex_state = 6},
-  [83] = {.lex_state = 4, .external_lex_state = 4},
-  [84] = {.lex_state = 4, .external_lex_state = 6},
-  [85] = {.lex_state = 4, .external_lex_state = 4},
-  [86] = {.lex_state = 4, .external_lex_state = 4},
-  [87] = {.lex_state = 4, .external_lex_state = 4},
-  [88] = {.lex_state = 4, .external_lex_state = 4},
-  [89] = {.lex_state = 4, .external_lex_state = 4},
-  [90] = {.lex_state = 4, .external_lex_state = 6},
-  [91] = {.lex_state = 4, .external_lex_state = 5},
-  [92] = {.lex_state = 4, .external_lex_state = 5},
-  [93] = {.lex_state = 4, .external_lex_state = 5},
-  [94] = {.lex_state = 4, .external_lex_state = 5},
-  [95] = {.lex_state = 4, .external_lex_state = 5},
-  [96] = {.lex_state = 4, .external_lex_state = 5},
-  [97] = {.lex_state = 4, .external_lex_state = 5},
-  [98] = {.lex_state = 4, .external_lex_state = 9},
-  [99] = {.lex_state = 4, .external_lex_state = 9},
-  [100] = {.lex_state = 4, .external_lex_state = 5},
-  [101] = {.lex_state = 4, .external_lex_state = 5},
-  [102] = {.lex_state = 4, .external_lex_state = 9},
-  [103] = {.lex_state = 4, .external_lex_state = 5},
-  [104] = {.lex_state = 4, .external_lex_state = 5},
-  [105] = {.lex_state = 4, .external_lex_state = 5},
-  [106] = {.lex_state = 4, .external_lex_state = 5},
-  [107] = {.lex_state = 0, .external_lex_state = 3},
-  [108] = {.lex_state = 0, .external_lex_state = 3},
-  [109] = {.lex_state = 0, .external_lex_state = 3},
-  [110] = {.lex_state = 0, .external_lex_state = 3},
-  [111] = {.lex_state = 0, .external_lex_state = 3},
-  [112] = {.lex_state = 0, .external_lex_state = 3},
-  [113] = {.lex_state = 0, .external_lex_state = 3},
-  [114] = {.lex_state = 0, .external_lex_state = 3},
-  [115] = {.lex_state = 0, .external_lex_state = 3},
-  [116] = {.lex_state = 0, .external_lex_state = 3},
-  [117] = {.lex_state = 0, .external_lex_state = 3},
-  [118] = {.lex_state = 0, .external_lex_state = 3},
-  [119] = {.lex_state = 0, .external_lex_state = 3},
-  [120] = {.lex_state = 0, .external_lex_state = 3},
-  [121] = {.lex_state = 0, .external_lex_state = 3},
+  [21] = {.lex_state = 0, .external_lex_state = 2},
+  [22] = {.lex_state = 0, .external_lex_state = 2},
+  [23] = {.lex_state = 0, .external_lex_state = 2},
+  [24] = {.lex_state = 0, .external_lex_state = 2},
+  [25] = {.lex_state = 0, .external_lex_state = 2},
+  [26] = {.lex_state = 0, .external_lex_state = 2},
+  [27] = {.lex_state = 0, .external_lex_state = 2},
+  [28] = {.lex_state = 0, .external_lex_state = 2},
+  [29] = {.lex_state = 0, .external_lex_state = 2},
+  [30] = {.lex_state = 0, .external_lex_state = 2},
+  [31] = {.lex_state = 0, .external_lex_state = 2},
+  [32] = {.lex_state = 0, .external_lex_state = 2},
+  [33] = {.lex_state = 0, .external_lex_state = 2},
+  [34] = {.lex_state = 0, .external_lex_state = 2},
+  [35] = {.lex_state = 0, .external_lex_state = 2},
+  [36] = {.lex_state = 0, .external_lex_state = 2},
+  [37] = {.lex_state = 0, .external_lex_state = 2},
+  [38] = {.lex_state = 6, .external_lex_state = 5},
+  [39] = {.lex_state = 6, .external_lex_state = 5},
+  [40] = {.lex_state = 6, .external_lex_state = 5},
+  [41] = {.lex_state = 6, .external_lex_state = 5},
+  [42] = {.lex_state = 6, .external_lex_state = 5},
+  [43] = {.lex_state = 6, .external_lex_state = 5},
+  [44] = {.lex_state = 6, .external_lex_state = 5},
+  [45] = {.lex_state = 6, .external_lex_state = 5},
+  [46] = {.lex_state = 6, .external_lex_state = 5},
+  [47] = {.lex_state = 6, .external_lex_state = 5},
+  [48] = {.lex_state = 6, .external_lex_state = 5},
+  [49] = {.lex_state = 6, .external_lex_state = 5},
+  [50] = {.lex_state = 6, .external_lex_state = 5},
+  [51] = {.lex_state = 6, .external_lex_state = 6},
+  [52] = {.lex_state = 6, .external_lex_state = 6},
+  [53] = {.lex_state = 6, .external_lex_state = 6},
+  [54] = {.lex_state = 6, .external_lex_state = 6},
+  [55] = {.lex_state = 6, .external_lex_state = 6},
+  [56] = {.lex_state = 6, .external_lex_state = 6},
+  [57] = {.lex_state = 6, .external_lex_state = 6},
+  [58] = {.lex_state = 6, .external_lex_state = 6},
+  [59] = {.lex_state = 6, .external_lex_state = 6},
+  [60] = {.lex_state = 6, .external_lex_state = 6},
+  [61] = {.lex_state = 6, .external_lex_state = 6},
+  [62] = {.lex_state = 6, .external_lex_state = 6},
+  [63] = {.lex_state = 6, .external_lex_state = 6},
+  [64] = {.lex_state = 6, .external_lex_state = 7},
+  [65] = {.lex_state = 6, .external_lex_state = 7},
+  [66] = {.lex_state = 6, .external_lex_state = 5},
+  [67] = {.lex_state = 6, .external_lex_state = 7},
+  [68] = {.lex_state = 6, .external_lex_state = 7},
+  [69] = {.lex_state = 6, .external_lex_state = 7},
+  [70] = {.lex_state = 6, .external_lex_state = 7},
+  [71] = {.lex_state = 6, .external_lex_state = 7},
+  [72] = {.lex_state = 6, .external_lex_state = 5},
+  [73] = {.lex_state = 6, .external_lex_state = 5},
+  [74] = {.lex_state = 6, .external_lex_state = 6},
+  [75] = {.lex_state = 6, .external_lex_state = 5},
+  [76] = {.lex_state = 6, .external_lex_state = 7},
+  [77] = {.lex_state = 6, .external_lex_state = 6},
+  [78] = {.lex_state = 6, .external_lex_state = 6},
+  [79] = {.lex_state = 6, .external_lex_state = 5},
+  [80] = {.lex_state = 6, .external_lex_state = 5},
+  [81] = {.lex_state = 6, .external_lex_state = 5},
+  [82] = {.lex_state = 6, .external_lex_state = 5},
+  [83] = {.lex_state = 6, .external_lex_state = 5},
+  [84] = {.lex_state = 6, .external_lex_state = 5},
+  [85] = {.lex_state = 6, .external_lex_state = 7},
+  [86] = {.lex_state = 6, .external_lex_state = 7},
+  [87] = {.lex_state = 6, .external_lex_state = 6},
+  [88] = {.lex_state = 6, .external_lex_state = 6},
+  [89] = {.lex_state = 6, .external_lex_state = 6},
+  [90] = {.lex_state = 6, .external_lex_state = 8},
+  [91] = {.lex_state = 6, .external_lex_state = 7},
+  [92] = {.lex_state = 6, .external_lex_state = 6},
+  [93] = {.lex_state = 6, .external_lex_state = 6},
+  [94] = {.lex_state = 6, .external_lex_state = 6},
+  [95] = {.lex_state = 6, .external_lex_state = 6},
+  [96] = {.lex_state = 6, .external_lex_state = 6},
+  [97] = {.lex_state = 6, .external_lex_state = 6},
+  [98] = {.lex_state = 6, .external_lex_state = 8},
+  [99] = {.lex_state = 6, .external_lex_state = 8},
+  [100] = {.lex_state = 6, .external_lex_state = 5},
+  [101] = {.lex_state = 6, .external_lex_state = 5},
+  [102] = {.lex_state = 6, .external_lex_state = 5},
+  [103] = {.lex_state = 6, .external_lex_state = 5},
+  [104] = {.lex_state = 6, .external_lex_state = 6},
+  [105] = {.lex_state = 6, .external_lex_state = 5},
+  [106] = {.lex_state = 6, .external_lex_state = 5},
+  [107] = {.lex_state = 6, .external_lex_state = 5},
+  [108] = {.lex_state = 6, .external_lex_state = 5},
+  [109] = {.lex_state = 6, .external_lex_state = 5},
+  [110] = {.lex_state = 6, .external_lex_state = 5},
+  [111] = {.lex_state = 6, .external_lex_state = 5},
+  [112] = {.lex_state = 6, .external_lex_state = 5},
+  [113] = {.lex_state = 6, .external_lex_state = 7},
+  [114] = {.lex_state = 6, .external_lex_state = 7},
+  [115] = {.lex_state = 6, .external_lex_state = 7},
+  [116] = {.lex_state = 6, .external_lex_state = 7},
+  [117] = {.lex_state = 6, .external_lex_state = 7},
+  [118] = {.lex_state = 6, .external_lex_state = 7},
+  [119] = {.lex_state = 6, .external_lex_state = 9},
+  [120] = {.lex_state = 6, .external_lex_state = 9},
+  [121] = {.lex_state = 6, .external_lex_state = 9},
   [122] = {.lex_state = 0, .external_lex_state = 3},
   [123] = {.lex_state = 0, .external_lex_state = 3},
-  [124] = {.lex_state = 4, .external_lex_state = 6},
+  [124] = {.lex_state = 6, .external_lex_state = 6},
   [125] = {.lex_state = 0, .external_lex_state = 3},
   [126] = {.lex_state = 0, .external_lex_state = 3},
-  [127] = {.lex_state = 4, .external_lex_state = 6},
+  [127] = {.lex_state = 0, .external_lex_state = 3},
   [128] = {.lex_state = 0, .external_lex_state = 3},
   [129] = {.lex_state = 0, .external_lex_state = 3},
   [130] = {.lex_state = 0, .external_lex_state = 3},
@@ -1607,8 +2147,8 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [140] = {.lex_state = 0, .external_lex_state = 3},
   [141] = {.lex_state = 0, .external_lex_state = 3},
   [142] = {.lex_state = 0, .external_lex_state = 3},
-  [143] = {.lex_state = 4, .external_lex_state = 6},
-  [144] = {.lex_state = 4, .external_lex_state = 6},
+  [143] = {.lex_state = 0, .external_lex_state = 3},
+  [144] = {.lex_state = 0, .external_lex_state = 3},
   [145] = {.lex_state = 0, .external_lex_state = 3},
   [146] = {.lex_state = 0, .external_lex_state = 3},
   [147] = {.lex_state = 0, .external_lex_state = 3},
@@ -1620,194 +2160,403 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [153] = {.lex_state = 0, .external_lex_state = 3},
   [154] = {.lex_state = 0, .external_lex_state = 3},
   [155] = {.lex_state = 0, .external_lex_state = 3},
-  [156] = {.lex_state = 4, .external_lex_state = 6},
-  [157] = {.lex_state = 4, .external_lex_state = 6},
-  [158] = {.lex_state = 4, .external_lex_state = 6},
+  [156] = {.lex_state = 0, .external_lex_state = 3},
+  [157] = {.lex_state = 0, .external_lex_state = 3},
+  [158] = {.lex_state = 0, .external_lex_state = 3},
   [159] = {.lex_state = 0, .external_lex_state = 3},
-  [160] = {.lex_state = 4, .external_lex_state = 6},
-  [161] = {.lex_state = 4, .external_lex_state = 6},
-  [162] = {.lex_state = 4, .external_lex_state = 6},
-  [163] = {.lex_state = 4, .external_lex_state = 6},
-  [164] = {.lex_state = 4, .external_lex_state = 6},
+  [160] = {.lex_state = 0, .external_lex_state = 3},
+  [161] = {.lex_state = 0, .external_lex_state = 3},
+  [162] = {.lex_state = 0, .external_lex_state = 3},
+  [163] = {.lex_state = 0, .external_lex_state = 3},
+  [164] = {.lex_state = 0, .external_lex_state = 3},
   [165] = {.lex_state = 0, .external_lex_state = 3},
-  [166] = {.lex_state = 0, .external_lex_state = 2},
-  [167] = {.lex_state = 0, .external_lex_state = 2},
-  [168] = {.lex_state = 0, .external_lex_state = 2},
-  [169] = {.lex_state = 0, .external_lex_state = 2},
-  [170] = {.lex_state = 0, .external_lex_state = 2},
-  [171] = {.lex_state = 0, .external_lex_state = 2},
-  [172] = {.lex_state = 0, .external_lex_state = 2},
-  [173] = {.lex_state = 0, .external_lex_state = 2},
-  [174] = {.lex_state = 0, .external_lex_state = 2},
-  [175] = {.lex_state = 0, .external_lex_state = 2},
-  [176] = {.lex_state = 0, .external_lex_state = 2},
-  [177] = {.lex_state = 0, .external_lex_state = 2},
-  [178] = {.lex_state = 0, .external_lex_state = 2},
-  [179] = {.lex_state = 0, .external_lex_state = 2},
-  [180] = {.lex_state = 0, .external_lex_state = 2},
-  [181] = {.lex_state = 0, .external_lex_state = 2},
-  [182] = {.lex_state = 0, .external_lex_state = 2},
-  [183] = {.lex_state = 0, .external_lex_state = 2},
-  [184] = {.lex_state = 0, .external_lex_state = 2},
-  [185] = {.lex_state = 0, .external_lex_state = 2},
-  [186] = {.lex_state = 0, .external_lex_state = 2},
-  [187] = {.lex_state = 0, .external_lex_state = 2},
-  [188] = {.lex_state = 0, .external_lex_state = 2},
-  [189] = {.lex_state = 0, .external_lex_state = 2},
-  [190] = {.lex_state = 0, .external_lex_state = 2},
-  [191] = {.lex_state = 0, .external_lex_state = 2},
-  [192] = {.lex_state = 0, .external_lex_state = 2},
-  [193] = {.lex_state = 0, .external_lex_state = 2},
-  [194] = {.lex_state = 0, .external_lex_state = 2},
-  [195] = {.lex_state = 0, .external_lex_state = 2},
-  [196] = {.lex_state = 0, .external_lex_state = 2},
-  [197] = {.lex_state = 0, .external_lex_state = 2},
-  [198] = {.lex_state = 0, .external_lex_state = 2},
-  [199] = {.lex_state = 0, .external_lex_state = 2},
-  [200] = {.lex_state = 0, .external_lex_state = 2},
-  [201] = {.lex_state = 0, .external_lex_state = 2},
-  [202] = {.lex_state = 0, .external_lex_state = 2},
-  [203] = {.lex_state = 0, .external_lex_state = 10},
-  [204] = {.lex_state = 0, .external_lex_state = 2},
-  [205] = {.lex_state = 0, .external_lex_state = 2},
-  [206] = {.lex_state = 0, .external_lex_state = 2},
-  [207] = {.lex_state = 0, .external_lex_state = 2},
-  [208] = {.lex_state = 0, .external_lex_state = 2},
-  [209] = {.lex_state = 0, .external_lex_state = 2},
-  [210] = {.lex_state = 0, .external_lex_state = 10},
-  [211] = {.lex_state = 0, .external_lex_state = 10},
-  [212] = {.lex_state = 0, .external_lex_state = 2},
-  [213] = {.lex_state = 0, .external_lex_state = 2},
-  [214] = {.lex_state = 0, .external_lex_state = 2},
-  [215] = {.lex_state = 3, .external_lex_state = 11},
-  [216] = {.lex_state = 1, .external_lex_state = 11},
-  [217] = {.lex_state = 1, .external_lex_state = 11},
-  [218] = {.lex_state = 0, .external_lex_state = 2},
-  [219] = {.lex_state = 0, .external_lex_state = 12},
-  [220] = {.lex_state = 0, .external_lex_state = 2},
-  [221] = {.lex_state = 0, .external_lex_state = 2},
-  [222] = {.lex_state = 3, .external_lex_state = 11},
-  [223] = {.lex_state = 1, .external_lex_state = 11},
-  [224] = {.lex_state = 0, .external_lex_state = 2},
-  [225] = {.lex_state = 3, .external_lex_state = 11},
-  [226] = {.lex_state = 1, .external_lex_state = 11},
-  [227] = {.lex_state = 3, .external_lex_state = 11},
-  [228] = {.lex_state = 1, .external_lex_state = 11},
-  [229] = {.lex_state = 3, .external_lex_state = 11},
-  [230] = {.lex_state = 1, .external_lex_state = 11},
-  [231] = {.lex_state = 3, .external_lex_state = 11},
-  [232] = {.lex_state = 1, .external_lex_state = 11},
-  [233] = {.lex_state = 0, .external_lex_state = 12},
-  [234] = {.lex_state = 3, .external_lex_state = 11},
-  [235] = {.lex_state = 0, .external_lex_state = 2},
-  [236] = {.lex_state = 4, .external_lex_state = 13},
-  [237] = {.lex_state = 4, .external_lex_state = 13},
-  [238] = {.lex_state = 4, .external_lex_state = 13},
-  [239] = {.lex_state = 0, .external_lex_state = 11},
-  [240] = {.lex_state = 0, .external_lex_state = 11},
-  [241] = {.lex_state = 0, .external_lex_state = 11},
-  [242] = {.lex_state = 0, .external_lex_state = 14},
-  [243] = {.lex_state = 0, .external_lex_state = 15},
-  [244] = {.lex_state = 3, .external_lex_state = 11},
-  [245] = {.lex_state = 0, .external_lex_state = 11},
-  [246] = {.lex_state = 3, .external_lex_state = 11},
-  [247] = {.lex_state = 1, .external_lex_state = 11},
-  [248] = {.lex_state = 0, .external_lex_state = 16},
-  [249] = {.lex_state = 1, .external_lex_state = 11},
-  [250] = {.lex_state = 0, .external_lex_state = 16},
-  [251] = {.lex_state = 0, .external_lex_state = 14},
-  [252] = {.lex_state = 0, .external_lex_state = 14},
-  [253] = {.lex_state = 0, .external_lex_state = 11},
-  [254] = {.lex_state = 0, .external_lex_state = 11},
-  [255] = {.lex_state = 0, .external_lex_state = 14},
-  [256] = {.lex_state = 0, .external_lex_state = 16},
-  [257] = {.lex_state = 0, .external_lex_state = 17},
-  [258] = {.lex_state = 0, .external_lex_state = 11},
-  [259] = {.lex_state = 0, .external_lex_state = 16},
-  [260] = {.lex_state = 0, .external_lex_state = 16},
-  [261] = {.lex_state = 0, .external_lex_state = 16},
-  [262] = {.lex_state = 0, .external_lex_state = 16},
-  [263] = {.lex_state = 0, .external_lex_state = 16},
-  [264] = {.lex_state = 0, .external_lex_state = 18},
-  [265] = {.lex_state = 0, .external_lex_state = 17},
-  [266] = {.lex_state = 0, .external_lex_state = 15},
-  [267] = {.lex_state = 0, .external_lex_state = 18},
-  [268] = {.lex_state = 0, .external_lex_state = 14},
-  [269] = {.lex_state = 0, .external_lex_state = 11},
-  [270] = {.lex_state = 0, .external_lex_state = 19},
-  [271] = {.lex_state = 0, .external_lex_state = 11},
-  [272] = {.lex_state = 0, .external_lex_state = 19},
-  [273] = {.lex_state = 0, .external_lex_state = 11},
-  [274] = {.lex_state = 0, .external_lex_state = 11},
-  [275] = {.lex_state = 0, .external_lex_state = 17},
-  [276] = {.lex_state = 0, .external_lex_state = 17},
-  [277] = {.lex_state = 0, .external_lex_state = 11},
-  [278] = {.lex_state = 0, .external_lex_state = 11},
-  [279] = {.lex_state = 0, .external_lex_state = 19},
-  [280] = {.lex_state = 0, .external_lex_state = 11},
-  [281] = {.lex_state = 0, .external_lex_state = 19},
-  [282] = {.lex_state = 0, .external_lex_state = 11},
-  [283] = {.lex_state = 0, .external_lex_state = 11},
-  [284] = {.lex_state = 0, .external_lex_state = 11},
-  [285] = {.lex_state = 0, .external_lex_state = 11},
-  [286] = {.lex_state = 0, .external_lex_state = 11},
-  [287] = {.lex_state = 0, .external_lex_state = 11},
-  [288] = {.lex_state = 0, .external_lex_state = 11},
-  [289] = {.lex_state = 0, .external_lex_state = 11},
-  [290] = {.lex_state = 0, .external_lex_state = 11},
-  [291] = {.lex_state = 15, .external_lex_state = 11},
-  [292] = {.lex_state = 0, .external_lex_state = 11},
-  [293] = {.lex_state = 0, .external_lex_state = 11},
-  [294] = {.lex_state = 0, .external_lex_state = 20},
-  [295] = {.lex_state = 0, .external_lex_state = 11},
-  [296] = {.lex_state = 0, .external_lex_state = 11},
-  [297] = {.lex_state = 0, .external_lex_state = 21},
-  [298] = {.lex_state = 16, .external_lex_state = 11},
-  [299] = {.lex_state = 0, .external_lex_state = 22},
-  [300] = {.lex_state = 0, .external_lex_state = 11},
-  [301] = {.lex_state = 0, .external_lex_state = 11},
-  [302] = {.lex_state = 16, .external_lex_state = 11},
-  [303] = {.lex_state = 0, .external_lex_state = 22},
-  [304] = {.lex_state = 15, .external_lex_state = 11},
-  [305] = {.lex_state = 0, .external_lex_state = 11},
-  [306] = {.lex_state = 0, .external_lex_state = 11},
-  [307] = {.lex_state = 0, .external_lex_state = 11},
-  [308] = {.lex_state = 0, .external_lex_state = 11},
-  [309] = {.lex_state = 0, .external_lex_state = 11},
-  [310] = {.lex_state = 0, .external_lex_state = 11},
-  [311] = {.lex_state = 16, .external_lex_state = 11},
-  [312] = {.lex_state = 0, .external_lex_state = 11},
-  [313] = {.lex_state = 0, .external_lex_state = 11},
-  [314] = {.lex_state = 17, .external_lex_state = 11},
-  [315] = {.lex_state = 15, .external_lex_state = 11},
-  [316] = {.lex_state = 15, .external_lex_state = 11},
-  [317] = {.lex_state = 16, .external_lex_state = 11},
-  [318] = {.lex_state = 0, .external_lex_state = 11},
-  [319] = {.lex_state = 0, .external_lex_state = 11},
-  [320] = {.lex_state = 0, .external_lex_state = 11},
-  [321] = {.lex_state = 0, .external_lex_state = 11},
-  [322] = {.lex_state = 0, .external_lex_state = 11},
-  [323] = {.lex_state = 0, .external_lex_state = 11},
-  [324] = {.lex_state = 0, .external_lex_state = 11},
-  [325] = {.lex_state = 0, .external_lex_state = 20},
-  [326] = {.lex_state = 0, .external_lex_state = 11},
-  [327] = {.lex_state = 0, .external_lex_state = 23},
-  [328] = {.lex_state = 0, .external_lex_state = 24},
-  [329] = {.lex_state = 17, .external_lex_state = 11},
-  [330] = {.lex_state = 0, .external_lex_state = 11},
-  [331] = {.lex_state = 0, .external_lex_state = 21},
-  [332] = {.lex_state = 0, .external_lex_state = 23},
-  [333] = {.lex_state = 0, .external_lex_state = 11},
-  [334] = {.lex_state = 0, .external_lex_state = 11},
-  [335] = {.lex_state = 0, .external_lex_state = 11},
-  [336] = {.lex_state = 0, .external_lex_state = 11},
-  [337] = {.lex_state = 0, .external_lex_state = 11},
-  [338] = {.lex_state = 0, .external_lex_state = 11},
-  [339] = {.lex_state = 0, .external_lex_state = 11},
-  [340] = {.lex_state = 0, .external_lex_state = 11},
-  [341] = {.lex_state = 0, .external_lex_state = 24},
-  [342] = {.lex_state = 0, .external_lex_state = 11},
-  [343] = {.lex_state = 0, .external_lex_state = 11},
+  [166] = {.lex_state = 0, .external_lex_state = 3},
+  [167] = {.lex_state = 0, .external_lex_state = 3},
+  [168] = {.lex_state = 0, .external_lex_state = 3},
+  [169] = {.lex_state = 0, .external_lex_state = 3},
+  [170] = {.lex_state = 0, .external_lex_state = 3},
+  [171] = {.lex_state = 0, .external_lex_state = 3},
+  [172] = {.lex_state = 0, .external_lex_state = 3},
+  [173] = {.lex_state = 0, .external_lex_state = 3},
+  [174] = {.lex_state = 0, .external_lex_state = 3},
+  [175] = {.lex_state = 0, .external_lex_state = 3},
+  [176] = {.lex_state = 0, .external_lex_state = 3},
+  [177] = {.lex_state = 0, .external_lex_state = 3},
+  [178] = {.lex_state = 0, .external_lex_state = 3},
+  [179] = {.lex_state = 0, .external_lex_state = 3},
+  [180] = {.lex_state = 0, .external_lex_state = 3},
+  [181] = {.lex_state = 0, .external_lex_state = 3},
+  [182] = {.lex_state = 0, .external_lex_state = 3},
+  [183] = {.lex_state = 0, .external_lex_state = 3},
+  [184] = {.lex_state = 0, .external_lex_state = 3},
+  [185] = {.lex_state = 0, .external_lex_state = 3},
+  [186] = {.lex_state = 0, .external_lex_state = 3},
+  [187] = {.lex_state = 0, .external_lex_state = 3},
+  [188] = {.lex_state = 0, .external_lex_state = 3},
+  [189] = {.lex_state = 6, .external_lex_state = 10},
+  [190] = {.lex_state = 0, .external_lex_state = 3},
+  [191] = {.lex_state = 0, .external_lex_state = 3},
+  [192] = {.lex_state = 0, .external_lex_state = 3},
+  [193] = {.lex_state = 0, .external_lex_state = 3},
+  [194] = {.lex_state = 6, .external_lex_state = 6},
+  [195] = {.lex_state = 6, .external_lex_state = 6},
+  [196] = {.lex_state = 6, .external_lex_state = 6},
+  [197] = {.lex_state = 6, .external_lex_state = 6},
+  [198] = {.lex_state = 6, .external_lex_state = 6},
+  [199] = {.lex_state = 6, .external_lex_state = 6},
+  [200] = {.lex_state = 6, .external_lex_state = 6},
+  [201] = {.lex_state = 6, .external_lex_state = 6},
+  [202] = {.lex_state = 6, .external_lex_state = 6},
+  [203] = {.lex_state = 6, .external_lex_state = 6},
+  [204] = {.lex_state = 6, .external_lex_state = 6},
+  [205] = {.lex_state = 6, .external_lex_state = 6},
+  [206] = {.lex_state = 6, .external_lex_state = 10},
+  [207] = {.lex_state = 6, .external_lex_state = 10},
+  [208] = {.lex_state = 0, .external_lex_state = 4},
+  [209] = {.lex_state = 0, .external_lex_state = 4},
+  [210] = {.lex_state = 6, .external_lex_state = 7},
+  [211] = {.lex_state = 6, .external_lex_state = 7},
+  [212] = {.lex_state = 6, .external_lex_state = 7},
+  [213] = {.lex_state = 6, .external_lex_state = 7},
+  [214] = {.lex_state = 6, .external_lex_state = 7},
+  [215] = {.lex_state = 6, .external_lex_state = 7},
+  [216] = {.lex_state = 6, .external_lex_state = 7},
+  [217] = {.lex_state = 6, .external_lex_state = 7},
+  [218] = {.lex_state = 6, .external_lex_state = 7},
+  [219] = {.lex_state = 6, .external_lex_state = 7},
+  [220] = {.lex_state = 6, .external_lex_state = 7},
+  [221] = {.lex_state = 6, .external_lex_state = 7},
+  [222] = {.lex_state = 0, .external_lex_state = 4},
+  [223] = {.lex_state = 0, .external_lex_state = 4},
+  [224] = {.lex_state = 0, .external_lex_state = 4},
+  [225] = {.lex_state = 0, .external_lex_state = 4},
+  [226] = {.lex_state = 0, .external_lex_state = 4},
+  [227] = {.lex_state = 0, .external_lex_state = 4},
+  [228] = {.lex_state = 0, .external_lex_state = 4},
+  [229] = {.lex_state = 0, .external_lex_state = 4},
+  [230] = {.lex_state = 0, .external_lex_state = 4},
+  [231] = {.lex_state = 0, .external_lex_state = 4},
+  [232] = {.lex_state = 0, .external_lex_state = 4},
+  [233] = {.lex_state = 0, .external_lex_state = 4},
+  [234] = {.lex_state = 0, .external_lex_state = 4},
+  [235] = {.lex_state = 0, .external_lex_state = 4},
+  [236] = {.lex_state = 0, .external_lex_state = 4},
+  [237] = {.lex_state = 0, .external_lex_state = 4},
+  [238] = {.lex_state = 0, .external_lex_state = 4},
+  [239] = {.lex_state = 0, .external_lex_state = 4},
+  [240] = {.lex_state = 0, .external_lex_state = 4},
+  [241] = {.lex_state = 0, .external_lex_state = 4},
+  [242] = {.lex_state = 0, .external_lex_state = 4},
+  [243] = {.lex_state = 0, .external_lex_state = 4},
+  [244] = {.lex_state = 0, .external_lex_state = 4},
+  [245] = {.lex_state = 0, .external_lex_state = 4},
+  [246] = {.lex_state = 0, .external_lex_state = 4},
+  [247] = {.lex_state = 0, .external_lex_state = 4},
+  [248] = {.lex_state = 0, .external_lex_state = 4},
+  [249] = {.lex_state = 0, .external_lex_state = 4},
+  [250] = {.lex_state = 0, .external_lex_state = 4},
+  [251] = {.lex_state = 0, .external_lex_state = 4},
+  [252] = {.lex_state = 0, .external_lex_state = 4},
+  [253] = {.lex_state = 0, .external_lex_state = 4},
+  [254] = {.lex_state = 0, .external_lex_state = 4},
+  [255] = {.lex_state = 0, .external_lex_state = 4},
+  [256] = {.lex_state = 0, .external_lex_state = 4},
+  [257] = {.lex_state = 0, .external_lex_state = 4},
+  [258] = {.lex_state = 0, .external_lex_state = 4},
+  [259] = {.lex_state = 0, .external_lex_state = 4},
+  [260] = {.lex_state = 0, .external_lex_state = 4},
+  [261] = {.lex_state = 0, .external_lex_state = 4},
+  [262] = {.lex_state = 0, .external_lex_state = 4},
+  [263] = {.lex_state = 0, .external_lex_state = 4},
+  [264] = {.lex_state = 0, .external_lex_state = 4},
+  [265] = {.lex_state = 0, .external_lex_state = 4},
+  [266] = {.lex_state = 0, .external_lex_state = 4},
+  [267] = {.lex_state = 0, .external_lex_state = 4},
+  [268] = {.lex_state = 0, .external_lex_state = 4},
+  [269] = {.lex_state = 0, .external_lex_state = 4},
+  [270] = {.lex_state = 0, .external_lex_state = 4},
+  [271] = {.lex_state = 0, .external_lex_state = 4},
+  [272] = {.lex_state = 0, .external_lex_state = 4},
+  [273] = {.lex_state = 0, .external_lex_state = 4},
+  [274] = {.lex_state = 0, .external_lex_state = 4},
+  [275] = {.lex_state = 0, .external_lex_state = 4},
+  [276] = {.lex_state = 0, .external_lex_state = 4},
+  [277] = {.lex_state = 0, .external_lex_state = 4},
+  [278] = {.lex_state = 0, .external_lex_state = 4},
+  [279] = {.lex_state = 0, .external_lex_state = 4},
+  [280] = {.lex_state = 0, .external_lex_state = 4},
+  [281] = {.lex_state = 0, .external_lex_state = 4},
+  [282] = {.lex_state = 0, .external_lex_state = 4},
+  [283] = {.lex_state = 0, .external_lex_state = 4},
+  [284] = {.lex_state = 0, .external_lex_state = 4},
+  [285] = {.lex_state = 0, .external_lex_state = 4},
+  [286] = {.lex_state = 0, .external_lex_state = 4},
+  [287] = {.lex_state = 0, .external_lex_state = 4},
+  [288] = {.lex_state = 0, .external_lex_state = 4},
+  [289] = {.lex_state = 0, .external_lex_state = 4},
+  [290] = {.lex_state = 0, .external_lex_state = 2},
+  [291] = {.lex_state = 0, .external_lex_state = 2},
+  [292] = {.lex_state = 0, .external_lex_state = 2},
+  [293] = {.lex_state = 0, .external_lex_state = 2},
+  [294] = {.lex_state = 0, .external_lex_state = 2},
+  [295] = {.lex_state = 0, .external_lex_state = 2},
+  [296] = {.lex_state = 0, .external_lex_state = 2},
+  [297] = {.lex_state = 0, .external_lex_state = 2},
+  [298] = {.lex_state = 0, .external_lex_state = 2},
+  [299] = {.lex_state = 0, .external_lex_state = 2},
+  [300] = {.lex_state = 0, .external_lex_state = 2},
+  [301] = {.lex_state = 0, .external_lex_state = 2},
+  [302] = {.lex_state = 0, .external_lex_state = 2},
+  [303] = {.lex_state = 0, .external_lex_state = 2},
+  [304] = {.lex_state = 0, .external_lex_state = 2},
+  [305] = {.lex_state = 0, .external_lex_state = 2},
+  [306] = {.lex_state = 0, .external_lex_state = 2},
+  [307] = {.lex_state = 0, .external_lex_state = 2},
+  [308] = {.lex_state = 0, .external_lex_state = 2},
+  [309] = {.lex_state = 0, .external_lex_state = 2},
+  [310] = {.lex_state = 0, .external_lex_state = 2},
+  [311] = {.lex_state = 0, .external_lex_state = 2},
+  [312] = {.lex_state = 0, .external_lex_state = 2},
+  [313] = {.lex_state = 0, .external_lex_state = 2},
+  [314] = {.lex_state = 0, .external_lex_state = 2},
+  [315] = {.lex_state = 0, .external_lex_state = 2},
+  [316] = {.lex_state = 0, .external_lex_state = 2},
+  [317] = {.lex_state = 0, .external_lex_state = 2},
+  [318] = {.lex_state = 0, .external_lex_state = 2},
+  [319] = {.lex_state = 0, .external_lex_state = 2},
+  [320] = {.lex_state = 0, .external_lex_state = 2},
+  [321] = {.lex_state = 0, .external_lex_state = 2},
+  [322] = {.lex_state = 0, .external_lex_state = 2},
+  [323] = {.lex_state = 0, .external_lex_state = 2},
+  [324] = {.lex_state = 0, .external_lex_state = 2},
+  [325] = {.lex_state = 0, .external_lex_state = 2},
+  [326] = {.lex_state = 0, .external_lex_state = 2},
+  [327] = {.lex_state = 0, .external_lex_state = 2},
+  [328] = {.lex_state = 0, .external_lex_state = 2},
+  [329] = {.lex_state = 0, .external_lex_state = 2},
+  [330] = {.lex_state = 0, .external_lex_state = 2},
+  [331] = {.lex_state = 0, .external_lex_state = 2},
+  [332] = {.lex_state = 0, .external_lex_state = 2},
+  [333] = {.lex_state = 0, .external_lex_state = 2},
+  [334] = {.lex_state = 0, .external_lex_state = 2},
+  [335] = {.lex_state = 0, .external_lex_state = 2},
+  [336] = {.lex_state = 0, .external_lex_state = 2},
+  [337] = {.lex_state = 0, .external_lex_state = 2},
+  [338] = {.lex_state = 0, .external_lex_state = 2},
+  [339] = {.lex_state = 0, .external_lex_state = 2},
+  [340] = {.lex_state = 0, .external_lex_state = 2},
+  [341] = {.lex_state = 0, .external_lex_state = 2},
+  [342] = {.lex_state = 0, .external_lex_state = 2},
+  [343] = {.lex_state = 0, .external_lex_state = 2},
+  [344] = {.lex_state = 0, .external_lex_state = 2},
+  [345] = {.lex_state = 0, .external_lex_state = 2},
+  [346] = {.lex_state = 0, .external_lex_state = 2},
+  [347] = {.lex_state = 0, .external_lex_state = 2},
+  [348] = {.lex_state = 0, .external_lex_state = 2},
+  [349] = {.lex_state = 0, .external_lex_state = 2},
+  [350] = {.lex_state = 0, .external_lex_state = 2},
+  [351] = {.lex_state = 0, .external_lex_state = 2},
+  [352] = {.lex_state = 0, .external_lex_state = 2},
+  [353] = {.lex_state = 0, .external_lex_state = 2},
+  [354] = {.lex_state = 0, .external_lex_state = 2},
+  [355] = {.lex_state = 0, .external_lex_state = 2},
+  [356] = {.lex_state = 0, .external_lex_state = 2},
+  [357] = {.lex_state = 0, .external_lex_state = 2},
+  [358] = {.lex_state = 0, .external_lex_state = 11},
+  [359] = {.lex_state = 0, .external_lex_state = 2},
+  [360] = {.lex_state = 0, .external_lex_state = 2},
+  [361] = {.lex_state = 0, .external_lex_state = 2},
+  [362] = {.lex_state = 0, .external_lex_state = 2},
+  [363] = {.lex_state = 2, .external_lex_state = 12},
+  [364] = {.lex_state = 0, .external_lex_state = 2},
+  [365] = {.lex_state = 0, .external_lex_state = 2},
+  [366] = {.lex_state = 0, .external_lex_state = 2},
+  [367] = {.lex_state = 0, .external_lex_state = 2},
+  [368] = {.lex_state = 2, .external_lex_state = 12},
+  [369] = {.lex_state = 2, .external_lex_state = 12},
+  [370] = {.lex_state = 0, .external_lex_state = 11},
+  [371] = {.lex_state = 0, .external_lex_state = 11},
+  [372] = {.lex_state = 5, .external_lex_state = 13},
+  [373] = {.lex_state = 3, .external_lex_state = 13},
+  [374] = {.lex_state = 5, .external_lex_state = 13},
+  [375] = {.lex_state = 3, .external_lex_state = 13},
+  [376] = {.lex_state = 5, .external_lex_state = 13},
+  [377] = {.lex_state = 3, .external_lex_state = 13},
+  [378] = {.lex_state = 5, .external_lex_state = 13},
+  [379] = {.lex_state = 3, .external_lex_state = 13},
+  [380] = {.lex_state = 5, .external_lex_state = 13},
+  [381] = {.lex_state = 3, .external_lex_state = 13},
+  [382] = {.lex_state = 5, .external_lex_state = 13},
+  [383] = {.lex_state = 3, .external_lex_state = 13},
+  [384] = {.lex_state = 5, .external_lex_state = 13},
+  [385] = {.lex_state = 3, .external_lex_state = 13},
+  [386] = {.lex_state = 6, .external_lex_state = 14},
+  [387] = {.lex_state = 6, .external_lex_state = 14},
+  [388] = {.lex_state = 6, .external_lex_state = 14},
+  [389] = {.lex_state = 0, .external_lex_state = 15},
+  [390] = {.lex_state = 8, .external_lex_state = 13},
+  [391] = {.lex_state = 0, .external_lex_state = 13},
+  [392] = {.lex_state = 0, .external_lex_state = 15},
+  [393] = {.lex_state = 8, .external_lex_state = 13},
+  [394] = {.lex_state = 0, .external_lex_state = 15},
+  [395] = {.lex_state = 8, .external_lex_state = 13},
+  [396] = {.lex_state = 0, .external_lex_state = 16},
+  [397] = {.lex_state = 0, .external_lex_state = 17},
+  [398] = {.lex_state = 0, .external_lex_state = 18},
+  [399] = {.lex_state = 0, .external_lex_state = 19},
+  [400] = {.lex_state = 0, .external_lex_state = 16},
+  [401] = {.lex_state = 1, .external_lex_state = 13},
+  [402] = {.lex_state = 1, .external_lex_state = 13},
+  [403] = {.lex_state = 1, .external_lex_state = 13},
+  [404] = {.lex_state = 2, .external_lex_state = 13},
+  [405] = {.lex_state = 0, .external_lex_state = 20},
+  [406] = {.lex_state = 0, .external_lex_state = 13},
+  [407] = {.lex_state = 0, .external_lex_state = 13},
+  [408] = {.lex_state = 0, .external_lex_state = 13},
+  [409] = {.lex_state = 0, .external_lex_state = 13},
+  [410] = {.lex_state = 5, .external_lex_state = 13},
+  [411] = {.lex_state = 5, .external_lex_state = 13},
+  [412] = {.lex_state = 3, .external_lex_state = 13},
+  [413] = {.lex_state = 3, .external_lex_state = 13},
+  [414] = {.lex_state = 0, .external_lex_state = 16},
+  [415] = {.lex_state = 0, .external_lex_state = 17},
+  [416] = {.lex_state = 0, .external_lex_state = 16},
+  [417] = {.lex_state = 1, .external_lex_state = 13},
+  [418] = {.lex_state = 1, .external_lex_state = 13},
+  [419] = {.lex_state = 1, .external_lex_state = 13},
+  [420] = {.lex_state = 2, .external_lex_state = 13},
+  [421] = {.lex_state = 0, .external_lex_state = 20},
+  [422] = {.lex_state = 0, .external_lex_state = 16},
+  [423] = {.lex_state = 0, .external_lex_state = 17},
+  [424] = {.lex_state = 0, .external_lex_state = 16},
+  [425] = {.lex_state = 1, .external_lex_state = 13},
+  [426] = {.lex_state = 1, .external_lex_state = 13},
+  [427] = {.lex_state = 1, .external_lex_state = 13},
+  [428] = {.lex_state = 2, .external_lex_state = 13},
+  [429] = {.lex_state = 0, .external_lex_state = 20},
+  [430] = {.lex_state = 0, .external_lex_state = 20},
+  [431] = {.lex_state = 0, .external_lex_state = 20},
+  [432] = {.lex_state = 0, .external_lex_state = 20},
+  [433] = {.lex_state = 0, .external_lex_state = 20},
+  [434] = {.lex_state = 0, .external_lex_state = 20},
+  [435] = {.lex_state = 0, .external_lex_state = 18},
+  [436] = {.lex_state = 0, .external_lex_state = 19},
+  [437] = {.lex_state = 0, .external_lex_state = 18},
+  [438] = {.lex_state = 0, .external_lex_state = 19},
+  [439] = {.lex_state = 9, .external_lex_state = 13},
+  [440] = {.lex_state = 0, .external_lex_state = 13},
+  [441] = {.lex_state = 0, .external_lex_state = 13},
+  [442] = {.lex_state = 0, .external_lex_state = 21},
+  [443] = {.lex_state = 0, .external_lex_state = 13},
+  [444] = {.lex_state = 0, .external_lex_state = 13},
+  [445] = {.lex_state = 0, .external_lex_state = 17},
+  [446] = {.lex_state = 0, .external_lex_state = 13},
+  [447] = {.lex_state = 0, .external_lex_state = 17},
+  [448] = {.lex_state = 10, .external_lex_state = 13},
+  [449] = {.lex_state = 10, .external_lex_state = 13},
+  [450] = {.lex_state = 0, .external_lex_state = 13},
+  [451] = {.lex_state = 0, .external_lex_state = 13},
+  [452] = {.lex_state = 9, .external_lex_state = 13},
+  [453] = {.lex_state = 0, .external_lex_state = 13},
+  [454] = {.lex_state = 0, .external_lex_state = 13},
+  [455] = {.lex_state = 0, .external_lex_state = 21},
+  [456] = {.lex_state = 0, .external_lex_state = 13},
+  [457] = {.lex_state = 0, .external_lex_state = 13},
+  [458] = {.lex_state = 0, .external_lex_state = 13},
+  [459] = {.lex_state = 10, .external_lex_state = 13},
+  [460] = {.lex_state = 10, .external_lex_state = 13},
+  [461] = {.lex_state = 9, .external_lex_state = 13},
+  [462] = {.lex_state = 0, .external_lex_state = 13},
+  [463] = {.lex_state = 0, .external_lex_state = 21},
+  [464] = {.lex_state = 0, .external_lex_state = 13},
+  [465] = {.lex_state = 0, .external_lex_state = 13},
+  [466] = {.lex_state = 0, .external_lex_state = 13},
+  [467] = {.lex_state = 10, .external_lex_state = 13},
+  [468] = {.lex_state = 10, .external_lex_state = 13},
+  [469] = {.lex_state = 0, .external_lex_state = 21},
+  [470] = {.lex_state = 0, .external_lex_state = 21},
+  [471] = {.lex_state = 0, .external_lex_state = 21},
+  [472] = {.lex_state = 0, .external_lex_state = 21},
+  [473] = {.lex_state = 0, .external_lex_state = 21},
+  [474] = {.lex_state = 0, .external_lex_state = 21},
+  [475] = {.lex_state = 0, .external_lex_state = 13},
+  [476] = {.lex_state = 0, .external_lex_state = 22},
+  [477] = {.lex_state = 0, .external_lex_state = 13},
+  [478] = {.lex_state = 0, .external_lex_state = 13},
+  [479] = {.lex_state = 0, .external_lex_state = 13},
+  [480] = {.lex_state = 0, .external_lex_state = 13},
+  [481] = {.lex_state = 0, .external_lex_state = 23},
+  [482] = {.lex_state = 0, .external_lex_state = 13},
+  [483] = {.lex_state = 0, .external_lex_state = 24},
+  [484] = {.lex_state = 0, .external_lex_state = 25},
+  [485] = {.lex_state = 0, .external_lex_state = 26},
+  [486] = {.lex_state = 0, .external_lex_state = 13},
+  [487] = {.lex_state = 0, .external_lex_state = 13},
+  [488] = {.lex_state = 0, .external_lex_state = 13},
+  [489] = {.lex_state = 0, .external_lex_state = 13},
+  [490] = {.lex_state = 0, .external_lex_state = 13},
+  [491] = {.lex_state = 2, .external_lex_state = 13},
+  [492] = {.lex_state = 0, .external_lex_state = 13},
+  [493] = {.lex_state = 0, .external_lex_state = 13},
+  [494] = {.lex_state = 0, .external_lex_state = 13},
+  [495] = {.lex_state = 0, .external_lex_state = 13},
+  [496] = {.lex_state = 30, .external_lex_state = 13},
+  [497] = {.lex_state = 0, .external_lex_state = 13},
+  [498] = {.lex_state = 0, .external_lex_state = 13},
+  [499] = {.lex_state = 0, .external_lex_state = 13},
+  [500] = {.lex_state = 0, .external_lex_state = 13},
+  [501] = {.lex_state = 0, .external_lex_state = 13},
+  [502] = {.lex_state = 0, .external_lex_state = 13},
+  [503] = {.lex_state = 0, .external_lex_state = 13},
+  [504] = {.lex_state = 0, .external_lex_state = 24},
+  [505] = {.lex_state = 0, .external_lex_state = 25},
+  [506] = {.lex_state = 0, .external_lex_state = 26},
+  [507] = {.lex_state = 0, .external_lex_state = 13},
+  [508] = {.lex_state = 0, .external_lex_state = 13},
+  [509] = {.lex_state = 0, .external_lex_state = 13},
+  [510] = {.lex_state = 0, .external_lex_state = 13},
+  [511] = {.lex_state = 2, .external_lex_state = 13},
+  [512] = {.lex_state = 0, .external_lex_state = 13},
+  [513] = {.lex_state = 0, .external_lex_state = 13},
+  [514] = {.lex_state = 0, .external_lex_state = 13},
+  [515] = {.lex_state = 0, .external_lex_state = 13},
+  [516] = {.lex_state = 30, .external_lex_state = 13},
+  [517] = {.lex_state = 0, .external_lex_state = 13},
+  [518] = {.lex_state = 0, .external_lex_state = 13},
+  [519] = {.lex_state = 0, .external_lex_state = 13},
+  [520] = {.lex_state = 0, .external_lex_state = 13},
+  [521] = {.lex_state = 0, .external_lex_state = 24},
+  [522] = {.lex_state = 0, .external_lex_state = 13},
+  [523] = {.lex_state = 0, .external_lex_state = 13},
+  [524] = {.lex_state = 0, .external_lex_state = 13},
+  [525] = {.lex_state = 0, .external_lex_state = 13},
+  [526] = {.lex_state = 2, .external_lex_state = 13},
+  [527] = {.lex_state = 0, .external_lex_state = 13},
+  [528] = {.lex_state = 0, .external_lex_state = 13},
+  [529] = {.lex_state = 0, .external_lex_state = 13},
+  [530] = {.lex_state = 0, .external_lex_state = 13},
+  [531] = {.lex_state = 30, .external_lex_state = 13},
+  [532] = {.lex_state = 0, .external_lex_state = 13},
+  [533] = {.lex_state = 0, .external_lex_state = 13},
+  [534] = {.lex_state = 2, .external_lex_state = 13},
+  [535] = {.lex_state = 30, .external_lex_state = 13},
+  [536] = {.lex_state = 0, .external_lex_state = 13},
+  [537] = {.lex_state = 0, .external_lex_state = 13},
+  [538] = {.lex_state = 0, .external_lex_state = 13},
+  [539] = {.lex_state = 0, .external_lex_state = 13},
+  [540] = {.lex_state = 0, .external_lex_state = 13},
+  [541] = {.lex_state = 0, .external_lex_state = 13},
+  [542] = {.lex_state = 0, .external_lex_state = 22},
+  [543] = {.lex_state = 0, .external_lex_state = 13},
+  [544] = {.lex_state = 0, .external_lex_state = 23},
+  [545] = {.lex_state = 0, .external_lex_state = 13},
+  [546] = {.lex_state = 0, .external_lex_state = 13},
+  [547] = {.lex_state = 0, .external_lex_state = 13},
+  [548] = {.lex_state = 0, .external_lex_state = 22},
+  [549] = {.lex_state = 0, .external_lex_state = 23},
+  [550] = {.lex_state = 0, .external_lex_state = 13},
+  [551] = {.lex_state = 0, .external_lex_state = 13},
+  [552] = {.lex_state = 0, .external_lex_state = 13},
 };
 
 static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
@@ -1816,6 +2565,9 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_LT_BANG] = ACTIONS(1),
     [anon_sym_GT] = ACTIONS(1),
     [sym__doctype] = ACTIONS(1),
+    [sym__doctype_public] = ACTIONS(1),
+    [sym__doctype_system] = ACTIONS(1),
+    [sym_doctype_identifier] = ACTIONS(1),
     [anon_sym_LT] = ACTIONS(1),
     [anon_sym_SLASH_GT] = ACTIONS(1),
     [anon_sym_LT_SLASH] = ACTIONS(1),
@@ -1836,6 +2588,11 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_raw_text] = ACTIONS(1),
     [sym_comment] = ACTIONS(3),
     [sym_text] = ACTIONS(1),
+    [sym_processing_instruction] = ACTIONS(3),
+    [sym_bogus_comment] = ACTIONS(3),
+    [sym_cdata_section] = ACTIONS(3),
+    [sym_cdata] = ACTIONS(1),
+    [sym__template_start_tag_name] = ACTIONS(1),
     [sym__tag_namespace] = ACTIONS(1),
     [sym__tag_local_name] = ACTIONS(1),
     [sym__ts_lang_marker] = ACTIONS(1),
@@ -1855,27 +2612,32 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym__unterminated_tag_end_open] = ACTIONS(1),
   },
   [STATE(1)] = {
-    [sym_document] = STATE(288),
-    [sym_doctype] = STATE(9),
-    [sym__node] = STATE(9),
-    [sym_element] = STATE(9),
-    [sym__raw_text_element] = STATE(187),
+    [sym_document] = STATE(477),
+    [sym_doctype] = STATE(20),
+    [sym__node] = STATE(20),
+    [sym_element] = STATE(20),
+    [sym__template_element] = STATE(290),
+    [sym__raw_text_element] = STATE(290),
     [sym_start_tag] = STATE(2),
-    [sym__raw_text_start_tag] = STATE(265),
-    [sym_self_closing_tag] = STATE(187),
-    [sym_erroneous_end_tag] = STATE(9),
-    [sym__unterminated_start_tag] = STATE(207),
-    [sym__unterminated_start_tag_with_close] = STATE(7),
-    [sym__broken_member_unterminated_start_tag] = STATE(184),
-    [sym__raw_text_unterminated_start_tag] = STATE(205),
-    [sym__namespaced_start_tag] = STATE(10),
-    [sym__namespaced_unterminated_start_tag] = STATE(170),
-    [sym__namespaced_self_closing_tag] = STATE(182),
-    [sym__member_start_tag] = STATE(8),
-    [sym__member_unterminated_start_tag] = STATE(181),
-    [sym__member_self_closing_tag] = STATE(183),
-    [sym_expression] = STATE(9),
-    [aux_sym_document_repeat1] = STATE(9),
+    [sym__raw_text_start_tag] = STATE(397),
+    [sym__template_start_tag] = STATE(3),
+    [sym_self_closing_tag] = STATE(290),
+    [sym__template_self_closing_tag] = STATE(291),
+    [sym_erroneous_end_tag] = STATE(20),
+    [sym__unterminated_start_tag] = STATE(292),
+    [sym__unterminated_start_tag_with_close] = STATE(17),
+    [sym__broken_member_unterminated_start_tag] = STATE(293),
+    [sym__template_unterminated_start_tag] = STATE(294),
+    [sym__template_unterminated_start_tag_with_close] = STATE(12),
+    [sym__raw_text_unterminated_start_tag] = STATE(295),
+    [sym__namespaced_start_tag] = STATE(18),
+    [sym__namespaced_unterminated_start_tag] = STATE(296),
+    [sym__namespaced_self_closing_tag] = STATE(297),
+    [sym__member_start_tag] = STATE(19),
+    [sym__member_unterminated_start_tag] = STATE(298),
+    [sym__member_self_closing_tag] = STATE(299),
+    [sym_expression] = STATE(20),
+    [aux_sym_document_repeat1] = STATE(20),
     [ts_builtin_sym_end] = ACTIONS(5),
     [anon_sym_LT_BANG] = ACTIONS(7),
     [anon_sym_LT] = ACTIONS(9),
@@ -1884,13 +2646,15 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_LBRACE] = ACTIONS(15),
     [sym_comment] = ACTIONS(3),
     [sym_text] = ACTIONS(13),
+    [sym_processing_instruction] = ACTIONS(3),
+    [sym_bogus_comment] = ACTIONS(3),
+    [sym_cdata_section] = ACTIONS(3),
+    [sym_cdata] = ACTIONS(13),
   },
 };
 
 static const uint16_t ts_small_parse_table[] = {
-  [0] = 24,
-    ACTIONS(3), 1,
-      sym_comment,
+  [0] = 28,
     ACTIONS(17), 1,
       anon_sym_LT_BANG,
     ACTIONS(19), 1,
@@ -1907,306 +2671,396 @@ static const uint16_t ts_small_parse_table[] = {
       sym__textarea_end_boundary,
     STATE(5), 1,
       sym_start_tag,
+    STATE(6), 1,
+      sym__template_start_tag,
     STATE(15), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(25), 1,
       sym__unterminated_start_tag_with_close,
-    STATE(16), 1,
-      sym__member_start_tag,
-    STATE(17), 1,
+    STATE(26), 1,
       sym__namespaced_start_tag,
-    STATE(109), 1,
+    STATE(27), 1,
+      sym__member_start_tag,
+    STATE(126), 1,
+      sym__template_self_closing_tag,
+    STATE(127), 1,
       sym__unterminated_start_tag,
-    STATE(110), 1,
+    STATE(128), 1,
       sym__broken_member_unterminated_start_tag,
-    STATE(111), 1,
+    STATE(129), 1,
+      sym__template_unterminated_start_tag,
+    STATE(130), 1,
       sym__raw_text_unterminated_start_tag,
-    STATE(112), 1,
+    STATE(131), 1,
       sym__namespaced_unterminated_start_tag,
-    STATE(113), 1,
+    STATE(132), 1,
       sym__namespaced_self_closing_tag,
-    STATE(114), 1,
+    STATE(133), 1,
       sym__member_unterminated_start_tag,
-    STATE(115), 1,
+    STATE(134), 1,
       sym__member_self_closing_tag,
-    STATE(177), 1,
+    STATE(303), 1,
       sym_end_tag,
-    STATE(257), 1,
+    STATE(415), 1,
       sym__raw_text_start_tag,
-    ACTIONS(23), 2,
+    ACTIONS(23), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(108), 2,
+    STATE(125), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    STATE(3), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(4), 6,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       sym_expression,
       aux_sym_document_repeat1,
-  [80] = 24,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(17), 1,
+  [97] = 28,
+    ACTIONS(33), 1,
       anon_sym_LT_BANG,
-    ACTIONS(19), 1,
+    ACTIONS(35), 1,
       anon_sym_LT,
-    ACTIONS(21), 1,
+    ACTIONS(37), 1,
       anon_sym_LT_SLASH,
-    ACTIONS(25), 1,
+    ACTIONS(41), 1,
       anon_sym_LBRACE,
-    ACTIONS(35), 1,
+    ACTIONS(43), 1,
       sym__implicit_end_tag,
-    ACTIONS(37), 1,
+    ACTIONS(45), 1,
       sym__unterminated_tag_end,
-    ACTIONS(39), 1,
-      sym__textarea_end_boundary,
-    STATE(5), 1,
+    STATE(8), 1,
       sym_start_tag,
-    STATE(15), 1,
-      sym__unterminated_start_tag_with_close,
+    STATE(9), 1,
+      sym__template_start_tag,
     STATE(16), 1,
-      sym__member_start_tag,
-    STATE(17), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(31), 1,
+      sym__unterminated_start_tag_with_close,
+    STATE(32), 1,
       sym__namespaced_start_tag,
-    STATE(109), 1,
+    STATE(33), 1,
+      sym__member_start_tag,
+    STATE(223), 1,
+      sym__template_self_closing_tag,
+    STATE(224), 1,
       sym__unterminated_start_tag,
-    STATE(110), 1,
+    STATE(225), 1,
       sym__broken_member_unterminated_start_tag,
-    STATE(111), 1,
+    STATE(226), 1,
+      sym__template_unterminated_start_tag,
+    STATE(227), 1,
       sym__raw_text_unterminated_start_tag,
-    STATE(112), 1,
+    STATE(228), 1,
       sym__namespaced_unterminated_start_tag,
-    STATE(113), 1,
+    STATE(229), 1,
       sym__namespaced_self_closing_tag,
-    STATE(114), 1,
+    STATE(230), 1,
       sym__member_unterminated_start_tag,
-    STATE(115), 1,
+    STATE(231), 1,
       sym__member_self_closing_tag,
-    STATE(194), 1,
+    STATE(307), 1,
       sym_end_tag,
-    STATE(257), 1,
+    STATE(389), 1,
+      sym_template_content,
+    STATE(423), 1,
       sym__raw_text_start_tag,
-    ACTIONS(33), 2,
+    ACTIONS(39), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(108), 2,
+    STATE(222), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    STATE(6), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(13), 6,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       sym_expression,
       aux_sym_document_repeat1,
-  [160] = 24,
-    ACTIONS(3), 1,
-      sym_comment,
+  [194] = 28,
     ACTIONS(17), 1,
       anon_sym_LT_BANG,
     ACTIONS(19), 1,
       anon_sym_LT,
+    ACTIONS(21), 1,
+      anon_sym_LT_SLASH,
     ACTIONS(25), 1,
       anon_sym_LBRACE,
-    ACTIONS(41), 1,
-      anon_sym_LT_SLASH,
-    ACTIONS(43), 1,
+    ACTIONS(49), 1,
       sym__implicit_end_tag,
-    ACTIONS(45), 1,
+    ACTIONS(51), 1,
       sym__unterminated_tag_end,
-    ACTIONS(47), 1,
+    ACTIONS(53), 1,
       sym__textarea_end_boundary,
     STATE(5), 1,
       sym_start_tag,
+    STATE(6), 1,
+      sym__template_start_tag,
     STATE(15), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(25), 1,
       sym__unterminated_start_tag_with_close,
-    STATE(16), 1,
-      sym__member_start_tag,
-    STATE(17), 1,
+    STATE(26), 1,
       sym__namespaced_start_tag,
-    STATE(109), 1,
+    STATE(27), 1,
+      sym__member_start_tag,
+    STATE(126), 1,
+      sym__template_self_closing_tag,
+    STATE(127), 1,
       sym__unterminated_start_tag,
-    STATE(110), 1,
+    STATE(128), 1,
       sym__broken_member_unterminated_start_tag,
-    STATE(111), 1,
+    STATE(129), 1,
+      sym__template_unterminated_start_tag,
+    STATE(130), 1,
       sym__raw_text_unterminated_start_tag,
-    STATE(112), 1,
+    STATE(131), 1,
       sym__namespaced_unterminated_start_tag,
-    STATE(113), 1,
+    STATE(132), 1,
       sym__namespaced_self_closing_tag,
-    STATE(114), 1,
+    STATE(133), 1,
       sym__member_unterminated_start_tag,
-    STATE(115), 1,
-      sym__member_self_closing_tag,
     STATE(134), 1,
+      sym__member_self_closing_tag,
+    STATE(324), 1,
       sym_end_tag,
-    STATE(257), 1,
+    STATE(415), 1,
       sym__raw_text_start_tag,
-    ACTIONS(33), 2,
+    ACTIONS(47), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(108), 2,
+    STATE(125), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    STATE(6), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(11), 6,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       sym_expression,
       aux_sym_document_repeat1,
-  [240] = 24,
-    ACTIONS(3), 1,
-      sym_comment,
+  [291] = 28,
     ACTIONS(17), 1,
       anon_sym_LT_BANG,
     ACTIONS(19), 1,
       anon_sym_LT,
     ACTIONS(25), 1,
       anon_sym_LBRACE,
-    ACTIONS(41), 1,
+    ACTIONS(55), 1,
       anon_sym_LT_SLASH,
-    ACTIONS(51), 1,
+    ACTIONS(59), 1,
       sym__implicit_end_tag,
-    ACTIONS(53), 1,
+    ACTIONS(61), 1,
       sym__unterminated_tag_end,
-    ACTIONS(55), 1,
+    ACTIONS(63), 1,
       sym__textarea_end_boundary,
     STATE(5), 1,
       sym_start_tag,
+    STATE(6), 1,
+      sym__template_start_tag,
     STATE(15), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(25), 1,
       sym__unterminated_start_tag_with_close,
-    STATE(16), 1,
-      sym__member_start_tag,
-    STATE(17), 1,
+    STATE(26), 1,
       sym__namespaced_start_tag,
-    STATE(109), 1,
+    STATE(27), 1,
+      sym__member_start_tag,
+    STATE(126), 1,
+      sym__template_self_closing_tag,
+    STATE(127), 1,
       sym__unterminated_start_tag,
-    STATE(110), 1,
+    STATE(128), 1,
       sym__broken_member_unterminated_start_tag,
-    STATE(111), 1,
+    STATE(129), 1,
+      sym__template_unterminated_start_tag,
+    STATE(130), 1,
       sym__raw_text_unterminated_start_tag,
-    STATE(112), 1,
+    STATE(131), 1,
       sym__namespaced_unterminated_start_tag,
-    STATE(113), 1,
+    STATE(132), 1,
       sym__namespaced_self_closing_tag,
-    STATE(114), 1,
+    STATE(133), 1,
       sym__member_unterminated_start_tag,
-    STATE(115), 1,
+    STATE(134), 1,
       sym__member_self_closing_tag,
-    STATE(119), 1,
+    STATE(138), 1,
       sym_end_tag,
-    STATE(257), 1,
+    STATE(415), 1,
       sym__raw_text_start_tag,
-    ACTIONS(49), 2,
+    ACTIONS(57), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(108), 2,
+    STATE(125), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    STATE(4), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(7), 6,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       sym_expression,
       aux_sym_document_repeat1,
-  [320] = 21,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(57), 1,
+  [388] = 28,
+    ACTIONS(33), 1,
       anon_sym_LT_BANG,
-    ACTIONS(60), 1,
+    ACTIONS(35), 1,
       anon_sym_LT,
-    ACTIONS(63), 1,
+    ACTIONS(41), 1,
+      anon_sym_LBRACE,
+    ACTIONS(65), 1,
       anon_sym_LT_SLASH,
+    ACTIONS(67), 1,
+      sym__implicit_end_tag,
     ACTIONS(69), 1,
-      anon_sym_LBRACE,
-    STATE(5), 1,
+      sym__unterminated_tag_end,
+    STATE(8), 1,
       sym_start_tag,
-    STATE(15), 1,
-      sym__unterminated_start_tag_with_close,
+    STATE(9), 1,
+      sym__template_start_tag,
     STATE(16), 1,
-      sym__member_start_tag,
-    STATE(17), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(31), 1,
+      sym__unterminated_start_tag_with_close,
+    STATE(32), 1,
       sym__namespaced_start_tag,
-    STATE(109), 1,
+    STATE(33), 1,
+      sym__member_start_tag,
+    STATE(142), 1,
+      sym_end_tag,
+    STATE(223), 1,
+      sym__template_self_closing_tag,
+    STATE(224), 1,
       sym__unterminated_start_tag,
-    STATE(110), 1,
+    STATE(225), 1,
       sym__broken_member_unterminated_start_tag,
-    STATE(111), 1,
+    STATE(226), 1,
+      sym__template_unterminated_start_tag,
+    STATE(227), 1,
       sym__raw_text_unterminated_start_tag,
-    STATE(112), 1,
+    STATE(228), 1,
       sym__namespaced_unterminated_start_tag,
-    STATE(113), 1,
+    STATE(229), 1,
       sym__namespaced_self_closing_tag,
-    STATE(114), 1,
+    STATE(230), 1,
       sym__member_unterminated_start_tag,
-    STATE(115), 1,
+    STATE(231), 1,
       sym__member_self_closing_tag,
-    STATE(257), 1,
+    STATE(392), 1,
+      sym_template_content,
+    STATE(423), 1,
       sym__raw_text_start_tag,
-    ACTIONS(66), 2,
+    ACTIONS(39), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(108), 2,
+    STATE(222), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    ACTIONS(72), 3,
-      sym__implicit_end_tag,
-      sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
-    STATE(6), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(13), 6,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       sym_expression,
       aux_sym_document_repeat1,
-  [393] = 21,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(7), 1,
+  [485] = 28,
+    ACTIONS(17), 1,
       anon_sym_LT_BANG,
-    ACTIONS(9), 1,
+    ACTIONS(19), 1,
       anon_sym_LT,
-    ACTIONS(15), 1,
+    ACTIONS(25), 1,
       anon_sym_LBRACE,
-    ACTIONS(74), 1,
+    ACTIONS(55), 1,
       anon_sym_LT_SLASH,
-    STATE(2), 1,
+    ACTIONS(71), 1,
+      sym__implicit_end_tag,
+    ACTIONS(73), 1,
+      sym__unterminated_tag_end,
+    ACTIONS(75), 1,
+      sym__textarea_end_boundary,
+    STATE(5), 1,
       sym_start_tag,
-    STATE(7), 1,
+    STATE(6), 1,
+      sym__template_start_tag,
+    STATE(15), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(25), 1,
       sym__unterminated_start_tag_with_close,
-    STATE(8), 1,
-      sym__member_start_tag,
-    STATE(10), 1,
+    STATE(26), 1,
       sym__namespaced_start_tag,
-    STATE(170), 1,
+    STATE(27), 1,
+      sym__member_start_tag,
+    STATE(126), 1,
+      sym__template_self_closing_tag,
+    STATE(127), 1,
+      sym__unterminated_start_tag,
+    STATE(128), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(129), 1,
+      sym__template_unterminated_start_tag,
+    STATE(130), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(131), 1,
       sym__namespaced_unterminated_start_tag,
-    STATE(180), 1,
-      sym_end_tag,
-    STATE(181), 1,
-      sym__member_unterminated_start_tag,
-    STATE(182), 1,
+    STATE(132), 1,
       sym__namespaced_self_closing_tag,
-    STATE(183), 1,
+    STATE(133), 1,
+      sym__member_unterminated_start_tag,
+    STATE(134), 1,
       sym__member_self_closing_tag,
-    STATE(184), 1,
-      sym__broken_member_unterminated_start_tag,
-    STATE(205), 1,
-      sym__raw_text_unterminated_start_tag,
-    STATE(207), 1,
-      sym__unterminated_start_tag,
-    STATE(265), 1,
+    STATE(159), 1,
+      sym_end_tag,
+    STATE(415), 1,
       sym__raw_text_start_tag,
-    ACTIONS(76), 2,
+    ACTIONS(47), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(187), 2,
+    STATE(125), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
     STATE(11), 6,
       sym_doctype,
       sym__node,
@@ -2214,299 +3068,400 @@ static const uint16_t ts_small_parse_table[] = {
       sym_erroneous_end_tag,
       sym_expression,
       aux_sym_document_repeat1,
-  [464] = 21,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(7), 1,
+  [582] = 28,
+    ACTIONS(17), 1,
       anon_sym_LT_BANG,
-    ACTIONS(9), 1,
+    ACTIONS(19), 1,
       anon_sym_LT,
-    ACTIONS(15), 1,
+    ACTIONS(25), 1,
       anon_sym_LBRACE,
-    ACTIONS(78), 1,
+    ACTIONS(77), 1,
       anon_sym_LT_SLASH,
-    STATE(2), 1,
+    ACTIONS(81), 1,
+      sym__implicit_end_tag,
+    ACTIONS(83), 1,
+      sym__unterminated_tag_end,
+    ACTIONS(85), 1,
+      sym__textarea_end_boundary,
+    STATE(5), 1,
       sym_start_tag,
-    STATE(7), 1,
+    STATE(6), 1,
+      sym__template_start_tag,
+    STATE(15), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(25), 1,
       sym__unterminated_start_tag_with_close,
-    STATE(8), 1,
-      sym__member_start_tag,
-    STATE(10), 1,
+    STATE(26), 1,
       sym__namespaced_start_tag,
-    STATE(167), 1,
-      sym__member_end_tag,
-    STATE(170), 1,
+    STATE(27), 1,
+      sym__member_start_tag,
+    STATE(126), 1,
+      sym__template_self_closing_tag,
+    STATE(127), 1,
+      sym__unterminated_start_tag,
+    STATE(128), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(129), 1,
+      sym__template_unterminated_start_tag,
+    STATE(130), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(131), 1,
       sym__namespaced_unterminated_start_tag,
-    STATE(181), 1,
-      sym__member_unterminated_start_tag,
-    STATE(182), 1,
+    STATE(132), 1,
       sym__namespaced_self_closing_tag,
-    STATE(183), 1,
+    STATE(133), 1,
+      sym__member_unterminated_start_tag,
+    STATE(134), 1,
       sym__member_self_closing_tag,
-    STATE(184), 1,
-      sym__broken_member_unterminated_start_tag,
-    STATE(205), 1,
-      sym__raw_text_unterminated_start_tag,
-    STATE(207), 1,
-      sym__unterminated_start_tag,
-    STATE(265), 1,
+    STATE(235), 1,
+      sym_end_tag,
+    STATE(415), 1,
       sym__raw_text_start_tag,
-    ACTIONS(80), 2,
+    ACTIONS(79), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(187), 2,
+    STATE(125), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    STATE(13), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(10), 6,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       sym_expression,
       aux_sym_document_repeat1,
-  [535] = 21,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(7), 1,
+  [679] = 28,
+    ACTIONS(33), 1,
       anon_sym_LT_BANG,
-    ACTIONS(9), 1,
+    ACTIONS(35), 1,
       anon_sym_LT,
-    ACTIONS(11), 1,
-      anon_sym_LT_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(41), 1,
       anon_sym_LBRACE,
-    ACTIONS(82), 1,
-      ts_builtin_sym_end,
-    STATE(2), 1,
+    ACTIONS(87), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(89), 1,
+      sym__implicit_end_tag,
+    ACTIONS(91), 1,
+      sym__unterminated_tag_end,
+    STATE(8), 1,
       sym_start_tag,
-    STATE(7), 1,
+    STATE(9), 1,
+      sym__template_start_tag,
+    STATE(16), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(31), 1,
       sym__unterminated_start_tag_with_close,
-    STATE(8), 1,
-      sym__member_start_tag,
-    STATE(10), 1,
+    STATE(32), 1,
       sym__namespaced_start_tag,
-    STATE(170), 1,
+    STATE(33), 1,
+      sym__member_start_tag,
+    STATE(223), 1,
+      sym__template_self_closing_tag,
+    STATE(224), 1,
+      sym__unterminated_start_tag,
+    STATE(225), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(226), 1,
+      sym__template_unterminated_start_tag,
+    STATE(227), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(228), 1,
       sym__namespaced_unterminated_start_tag,
-    STATE(181), 1,
-      sym__member_unterminated_start_tag,
-    STATE(182), 1,
+    STATE(229), 1,
       sym__namespaced_self_closing_tag,
-    STATE(183), 1,
+    STATE(230), 1,
+      sym__member_unterminated_start_tag,
+    STATE(231), 1,
       sym__member_self_closing_tag,
-    STATE(184), 1,
-      sym__broken_member_unterminated_start_tag,
-    STATE(205), 1,
-      sym__raw_text_unterminated_start_tag,
-    STATE(207), 1,
-      sym__unterminated_start_tag,
-    STATE(265), 1,
+    STATE(239), 1,
+      sym_end_tag,
+    STATE(394), 1,
+      sym_template_content,
+    STATE(423), 1,
       sym__raw_text_start_tag,
-    ACTIONS(84), 2,
+    ACTIONS(39), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(187), 2,
+    STATE(222), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    STATE(14), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(13), 6,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       sym_expression,
       aux_sym_document_repeat1,
-  [606] = 21,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(7), 1,
+  [776] = 28,
+    ACTIONS(17), 1,
       anon_sym_LT_BANG,
-    ACTIONS(9), 1,
+    ACTIONS(19), 1,
       anon_sym_LT,
-    ACTIONS(15), 1,
+    ACTIONS(25), 1,
       anon_sym_LBRACE,
-    ACTIONS(86), 1,
+    ACTIONS(77), 1,
       anon_sym_LT_SLASH,
-    STATE(2), 1,
+    ACTIONS(93), 1,
+      sym__implicit_end_tag,
+    ACTIONS(95), 1,
+      sym__unterminated_tag_end,
+    ACTIONS(97), 1,
+      sym__textarea_end_boundary,
+    STATE(5), 1,
       sym_start_tag,
-    STATE(7), 1,
+    STATE(6), 1,
+      sym__template_start_tag,
+    STATE(15), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(25), 1,
       sym__unterminated_start_tag_with_close,
-    STATE(8), 1,
-      sym__member_start_tag,
-    STATE(10), 1,
+    STATE(26), 1,
       sym__namespaced_start_tag,
-    STATE(170), 1,
+    STATE(27), 1,
+      sym__member_start_tag,
+    STATE(126), 1,
+      sym__template_self_closing_tag,
+    STATE(127), 1,
+      sym__unterminated_start_tag,
+    STATE(128), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(129), 1,
+      sym__template_unterminated_start_tag,
+    STATE(130), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(131), 1,
       sym__namespaced_unterminated_start_tag,
-    STATE(181), 1,
-      sym__member_unterminated_start_tag,
-    STATE(182), 1,
+    STATE(132), 1,
       sym__namespaced_self_closing_tag,
-    STATE(183), 1,
+    STATE(133), 1,
+      sym__member_unterminated_start_tag,
+    STATE(134), 1,
       sym__member_self_closing_tag,
-    STATE(184), 1,
-      sym__broken_member_unterminated_start_tag,
-    STATE(185), 1,
-      sym__namespaced_end_tag,
-    STATE(205), 1,
-      sym__raw_text_unterminated_start_tag,
-    STATE(207), 1,
-      sym__unterminated_start_tag,
-    STATE(265), 1,
+    STATE(256), 1,
+      sym_end_tag,
+    STATE(415), 1,
       sym__raw_text_start_tag,
-    ACTIONS(88), 2,
+    ACTIONS(47), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(187), 2,
+    STATE(125), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    STATE(12), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(11), 6,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       sym_expression,
       aux_sym_document_repeat1,
-  [677] = 21,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(7), 1,
+  [873] = 25,
+    ACTIONS(99), 1,
       anon_sym_LT_BANG,
-    ACTIONS(9), 1,
+    ACTIONS(102), 1,
       anon_sym_LT,
-    ACTIONS(15), 1,
-      anon_sym_LBRACE,
-    ACTIONS(74), 1,
+    ACTIONS(105), 1,
       anon_sym_LT_SLASH,
-    STATE(2), 1,
+    ACTIONS(111), 1,
+      anon_sym_LBRACE,
+    STATE(5), 1,
       sym_start_tag,
-    STATE(7), 1,
+    STATE(6), 1,
+      sym__template_start_tag,
+    STATE(15), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(25), 1,
       sym__unterminated_start_tag_with_close,
-    STATE(8), 1,
-      sym__member_start_tag,
-    STATE(10), 1,
+    STATE(26), 1,
       sym__namespaced_start_tag,
-    STATE(170), 1,
+    STATE(27), 1,
+      sym__member_start_tag,
+    STATE(126), 1,
+      sym__template_self_closing_tag,
+    STATE(127), 1,
+      sym__unterminated_start_tag,
+    STATE(128), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(129), 1,
+      sym__template_unterminated_start_tag,
+    STATE(130), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(131), 1,
       sym__namespaced_unterminated_start_tag,
-    STATE(181), 1,
-      sym__member_unterminated_start_tag,
-    STATE(182), 1,
+    STATE(132), 1,
       sym__namespaced_self_closing_tag,
-    STATE(183), 1,
+    STATE(133), 1,
+      sym__member_unterminated_start_tag,
+    STATE(134), 1,
       sym__member_self_closing_tag,
-    STATE(184), 1,
-      sym__broken_member_unterminated_start_tag,
-    STATE(198), 1,
-      sym_end_tag,
-    STATE(205), 1,
-      sym__raw_text_unterminated_start_tag,
-    STATE(207), 1,
-      sym__unterminated_start_tag,
-    STATE(265), 1,
+    STATE(415), 1,
       sym__raw_text_start_tag,
-    ACTIONS(84), 2,
+    ACTIONS(108), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(187), 2,
+    ACTIONS(114), 3,
+      sym__implicit_end_tag,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+    STATE(125), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    STATE(14), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(11), 6,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       sym_expression,
       aux_sym_document_repeat1,
-  [748] = 21,
-    ACTIONS(3), 1,
-      sym_comment,
+  [963] = 26,
     ACTIONS(7), 1,
       anon_sym_LT_BANG,
     ACTIONS(9), 1,
       anon_sym_LT,
     ACTIONS(15), 1,
       anon_sym_LBRACE,
-    ACTIONS(86), 1,
+    ACTIONS(116), 1,
       anon_sym_LT_SLASH,
     STATE(2), 1,
       sym_start_tag,
-    STATE(7), 1,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
       sym__unterminated_start_tag_with_close,
-    STATE(8), 1,
-      sym__member_start_tag,
-    STATE(10), 1,
+    STATE(18), 1,
       sym__namespaced_start_tag,
-    STATE(170), 1,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
       sym__namespaced_unterminated_start_tag,
-    STATE(181), 1,
-      sym__member_unterminated_start_tag,
-    STATE(182), 1,
+    STATE(297), 1,
       sym__namespaced_self_closing_tag,
-    STATE(183), 1,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
       sym__member_self_closing_tag,
-    STATE(184), 1,
-      sym__broken_member_unterminated_start_tag,
-    STATE(199), 1,
-      sym__namespaced_end_tag,
-    STATE(205), 1,
-      sym__raw_text_unterminated_start_tag,
-    STATE(207), 1,
-      sym__unterminated_start_tag,
-    STATE(265), 1,
+    STATE(309), 1,
+      sym_end_tag,
+    STATE(397), 1,
       sym__raw_text_start_tag,
-    ACTIONS(84), 2,
+    STATE(444), 1,
+      sym_template_content,
+    ACTIONS(118), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(187), 2,
+    STATE(290), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    STATE(14), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(37), 6,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       sym_expression,
       aux_sym_document_repeat1,
-  [819] = 21,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(7), 1,
+  [1054] = 25,
+    ACTIONS(33), 1,
       anon_sym_LT_BANG,
-    ACTIONS(9), 1,
+    ACTIONS(35), 1,
       anon_sym_LT,
-    ACTIONS(15), 1,
+    ACTIONS(41), 1,
       anon_sym_LBRACE,
-    ACTIONS(78), 1,
+    ACTIONS(120), 1,
       anon_sym_LT_SLASH,
-    STATE(2), 1,
+    STATE(8), 1,
       sym_start_tag,
-    STATE(7), 1,
+    STATE(9), 1,
+      sym__template_start_tag,
+    STATE(16), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(31), 1,
       sym__unterminated_start_tag_with_close,
-    STATE(8), 1,
-      sym__member_start_tag,
-    STATE(10), 1,
+    STATE(32), 1,
       sym__namespaced_start_tag,
-    STATE(170), 1,
+    STATE(33), 1,
+      sym__member_start_tag,
+    STATE(223), 1,
+      sym__template_self_closing_tag,
+    STATE(224), 1,
+      sym__unterminated_start_tag,
+    STATE(225), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(226), 1,
+      sym__template_unterminated_start_tag,
+    STATE(227), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(228), 1,
       sym__namespaced_unterminated_start_tag,
-    STATE(181), 1,
-      sym__member_unterminated_start_tag,
-    STATE(182), 1,
+    STATE(229), 1,
       sym__namespaced_self_closing_tag,
-    STATE(183), 1,
+    STATE(230), 1,
+      sym__member_unterminated_start_tag,
+    STATE(231), 1,
       sym__member_self_closing_tag,
-    STATE(184), 1,
-      sym__broken_member_unterminated_start_tag,
-    STATE(200), 1,
-      sym__member_end_tag,
-    STATE(205), 1,
-      sym__raw_text_unterminated_start_tag,
-    STATE(207), 1,
-      sym__unterminated_start_tag,
-    STATE(265), 1,
+    STATE(423), 1,
       sym__raw_text_start_tag,
-    ACTIONS(84), 2,
+    ACTIONS(125), 2,
+      sym__implicit_end_tag,
+      sym__unterminated_tag_end,
+    ACTIONS(123), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(187), 2,
+    STATE(222), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
     STATE(14), 6,
       sym_doctype,
       sym__node,
@@ -2514,49 +3469,63 @@ static const uint16_t ts_small_parse_table[] = {
       sym_erroneous_end_tag,
       sym_expression,
       aux_sym_document_repeat1,
-  [890] = 21,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(72), 1,
-      ts_builtin_sym_end,
-    ACTIONS(90), 1,
+  [1143] = 25,
+    ACTIONS(127), 1,
       anon_sym_LT_BANG,
-    ACTIONS(93), 1,
+    ACTIONS(130), 1,
       anon_sym_LT,
-    ACTIONS(96), 1,
+    ACTIONS(133), 1,
       anon_sym_LT_SLASH,
-    ACTIONS(102), 1,
+    ACTIONS(139), 1,
       anon_sym_LBRACE,
-    STATE(2), 1,
+    STATE(8), 1,
       sym_start_tag,
-    STATE(7), 1,
+    STATE(9), 1,
+      sym__template_start_tag,
+    STATE(16), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(31), 1,
       sym__unterminated_start_tag_with_close,
-    STATE(8), 1,
-      sym__member_start_tag,
-    STATE(10), 1,
+    STATE(32), 1,
       sym__namespaced_start_tag,
-    STATE(170), 1,
+    STATE(33), 1,
+      sym__member_start_tag,
+    STATE(223), 1,
+      sym__template_self_closing_tag,
+    STATE(224), 1,
+      sym__unterminated_start_tag,
+    STATE(225), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(226), 1,
+      sym__template_unterminated_start_tag,
+    STATE(227), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(228), 1,
       sym__namespaced_unterminated_start_tag,
-    STATE(181), 1,
-      sym__member_unterminated_start_tag,
-    STATE(182), 1,
+    STATE(229), 1,
       sym__namespaced_self_closing_tag,
-    STATE(183), 1,
+    STATE(230), 1,
+      sym__member_unterminated_start_tag,
+    STATE(231), 1,
       sym__member_self_closing_tag,
-    STATE(184), 1,
-      sym__broken_member_unterminated_start_tag,
-    STATE(205), 1,
-      sym__raw_text_unterminated_start_tag,
-    STATE(207), 1,
-      sym__unterminated_start_tag,
-    STATE(265), 1,
+    STATE(423), 1,
       sym__raw_text_start_tag,
-    ACTIONS(99), 2,
+    ACTIONS(114), 2,
+      sym__implicit_end_tag,
+      sym__unterminated_tag_end,
+    ACTIONS(136), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(187), 2,
+    STATE(222), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
     STATE(14), 6,
       sym_doctype,
       sym__node,
@@ -2564,1266 +3533,4875 @@ static const uint16_t ts_small_parse_table[] = {
       sym_erroneous_end_tag,
       sym_expression,
       aux_sym_document_repeat1,
-  [961] = 21,
-    ACTIONS(3), 1,
-      sym_comment,
+  [1232] = 26,
     ACTIONS(7), 1,
       anon_sym_LT_BANG,
     ACTIONS(9), 1,
       anon_sym_LT,
     ACTIONS(15), 1,
       anon_sym_LBRACE,
-    ACTIONS(105), 1,
+    ACTIONS(142), 1,
       anon_sym_LT_SLASH,
     STATE(2), 1,
       sym_start_tag,
-    STATE(7), 1,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
       sym__unterminated_start_tag_with_close,
-    STATE(8), 1,
-      sym__member_start_tag,
-    STATE(10), 1,
+    STATE(18), 1,
       sym__namespaced_start_tag,
-    STATE(121), 1,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(144), 1,
       sym_end_tag,
-    STATE(170), 1,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
       sym__namespaced_unterminated_start_tag,
-    STATE(181), 1,
-      sym__member_unterminated_start_tag,
-    STATE(182), 1,
+    STATE(297), 1,
       sym__namespaced_self_closing_tag,
-    STATE(183), 1,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
       sym__member_self_closing_tag,
-    STATE(184), 1,
-      sym__broken_member_unterminated_start_tag,
-    STATE(205), 1,
-      sym__raw_text_unterminated_start_tag,
-    STATE(207), 1,
-      sym__unterminated_start_tag,
-    STATE(265), 1,
+    STATE(397), 1,
       sym__raw_text_start_tag,
-    ACTIONS(107), 2,
+    STATE(457), 1,
+      sym_template_content,
+    ACTIONS(118), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(187), 2,
+    STATE(290), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    STATE(18), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(37), 6,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       sym_expression,
       aux_sym_document_repeat1,
-  [1032] = 21,
-    ACTIONS(3), 1,
-      sym_comment,
+  [1323] = 26,
     ACTIONS(7), 1,
       anon_sym_LT_BANG,
     ACTIONS(9), 1,
       anon_sym_LT,
     ACTIONS(15), 1,
       anon_sym_LBRACE,
-    ACTIONS(109), 1,
+    ACTIONS(144), 1,
       anon_sym_LT_SLASH,
     STATE(2), 1,
       sym_start_tag,
-    STATE(7), 1,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
       sym__unterminated_start_tag_with_close,
-    STATE(8), 1,
-      sym__member_start_tag,
-    STATE(10), 1,
+    STATE(18), 1,
       sym__namespaced_start_tag,
-    STATE(123), 1,
-      sym__member_end_tag,
-    STATE(170), 1,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(241), 1,
+      sym_end_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
       sym__namespaced_unterminated_start_tag,
-    STATE(181), 1,
-      sym__member_unterminated_start_tag,
-    STATE(182), 1,
+    STATE(297), 1,
       sym__namespaced_self_closing_tag,
-    STATE(183), 1,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
       sym__member_self_closing_tag,
-    STATE(184), 1,
-      sym__broken_member_unterminated_start_tag,
-    STATE(205), 1,
-      sym__raw_text_unterminated_start_tag,
-    STATE(207), 1,
-      sym__unterminated_start_tag,
-    STATE(265), 1,
+    STATE(397), 1,
       sym__raw_text_start_tag,
-    ACTIONS(111), 2,
+    STATE(465), 1,
+      sym_template_content,
+    ACTIONS(118), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(187), 2,
+    STATE(290), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    STATE(20), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(37), 6,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       sym_expression,
       aux_sym_document_repeat1,
-  [1103] = 21,
-    ACTIONS(3), 1,
-      sym_comment,
+  [1414] = 25,
     ACTIONS(7), 1,
       anon_sym_LT_BANG,
     ACTIONS(9), 1,
       anon_sym_LT,
     ACTIONS(15), 1,
       anon_sym_LBRACE,
-    ACTIONS(113), 1,
+    ACTIONS(116), 1,
       anon_sym_LT_SLASH,
     STATE(2), 1,
       sym_start_tag,
-    STATE(7), 1,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
       sym__unterminated_start_tag_with_close,
-    STATE(8), 1,
-      sym__member_start_tag,
-    STATE(10), 1,
+    STATE(18), 1,
       sym__namespaced_start_tag,
-    STATE(122), 1,
-      sym__namespaced_end_tag,
-    STATE(170), 1,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
       sym__namespaced_unterminated_start_tag,
-    STATE(181), 1,
-      sym__member_unterminated_start_tag,
-    STATE(182), 1,
+    STATE(297), 1,
       sym__namespaced_self_closing_tag,
-    STATE(183), 1,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
       sym__member_self_closing_tag,
-    STATE(184), 1,
-      sym__broken_member_unterminated_start_tag,
-    STATE(205), 1,
-      sym__raw_text_unterminated_start_tag,
-    STATE(207), 1,
-      sym__unterminated_start_tag,
-    STATE(265), 1,
+    STATE(308), 1,
+      sym_end_tag,
+    STATE(397), 1,
       sym__raw_text_start_tag,
-    ACTIONS(115), 2,
+    ACTIONS(146), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(187), 2,
+    STATE(290), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    STATE(19), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(21), 6,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       sym_expression,
       aux_sym_document_repeat1,
-  [1174] = 21,
-    ACTIONS(3), 1,
-      sym_comment,
+  [1502] = 25,
     ACTIONS(7), 1,
       anon_sym_LT_BANG,
     ACTIONS(9), 1,
       anon_sym_LT,
     ACTIONS(15), 1,
       anon_sym_LBRACE,
-    ACTIONS(105), 1,
+    ACTIONS(148), 1,
       anon_sym_LT_SLASH,
     STATE(2), 1,
       sym_start_tag,
-    STATE(7), 1,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
       sym__unterminated_start_tag_with_close,
-    STATE(8), 1,
-      sym__member_start_tag,
-    STATE(10), 1,
+    STATE(18), 1,
       sym__namespaced_start_tag,
-    STATE(138), 1,
-      sym_end_tag,
-    STATE(170), 1,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
       sym__namespaced_unterminated_start_tag,
-    STATE(181), 1,
-      sym__member_unterminated_start_tag,
-    STATE(182), 1,
+    STATE(297), 1,
       sym__namespaced_self_closing_tag,
-    STATE(183), 1,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
       sym__member_self_closing_tag,
-    STATE(184), 1,
-      sym__broken_member_unterminated_start_tag,
-    STATE(205), 1,
-      sym__raw_text_unterminated_start_tag,
-    STATE(207), 1,
-      sym__unterminated_start_tag,
-    STATE(265), 1,
+    STATE(310), 1,
+      sym__namespaced_end_tag,
+    STATE(397), 1,
       sym__raw_text_start_tag,
-    ACTIONS(84), 2,
+    ACTIONS(150), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(187), 2,
+    STATE(290), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    STATE(14), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(22), 6,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       sym_expression,
       aux_sym_document_repeat1,
-  [1245] = 21,
-    ACTIONS(3), 1,
-      sym_comment,
+  [1590] = 25,
     ACTIONS(7), 1,
       anon_sym_LT_BANG,
     ACTIONS(9), 1,
       anon_sym_LT,
     ACTIONS(15), 1,
       anon_sym_LBRACE,
-    ACTIONS(113), 1,
+    ACTIONS(152), 1,
       anon_sym_LT_SLASH,
     STATE(2), 1,
       sym_start_tag,
-    STATE(7), 1,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
       sym__unterminated_start_tag_with_close,
-    STATE(8), 1,
-      sym__member_start_tag,
-    STATE(10), 1,
+    STATE(18), 1,
       sym__namespaced_start_tag,
-    STATE(139), 1,
-      sym__namespaced_end_tag,
-    STATE(170), 1,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
       sym__namespaced_unterminated_start_tag,
-    STATE(181), 1,
-      sym__member_unterminated_start_tag,
-    STATE(182), 1,
+    STATE(297), 1,
       sym__namespaced_self_closing_tag,
-    STATE(183), 1,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
       sym__member_self_closing_tag,
-    STATE(184), 1,
+    STATE(311), 1,
+      sym__member_end_tag,
+    STATE(397), 1,
+      sym__raw_text_start_tag,
+    ACTIONS(154), 3,
+      sym_text,
+      sym_cdata,
+      sym_entity,
+    STATE(290), 3,
+      sym__template_element,
+      sym__raw_text_element,
+      sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(23), 6,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_erroneous_end_tag,
+      sym_expression,
+      aux_sym_document_repeat1,
+  [1678] = 25,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(156), 1,
+      ts_builtin_sym_end,
+    STATE(2), 1,
+      sym_start_tag,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
+      sym__unterminated_start_tag_with_close,
+    STATE(18), 1,
+      sym__namespaced_start_tag,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
       sym__broken_member_unterminated_start_tag,
-    STATE(205), 1,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
       sym__raw_text_unterminated_start_tag,
-    STATE(207), 1,
-      sym__unterminated_start_tag,
-    STATE(265), 1,
+    STATE(296), 1,
+      sym__namespaced_unterminated_start_tag,
+    STATE(297), 1,
+      sym__namespaced_self_closing_tag,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
+      sym__member_self_closing_tag,
+    STATE(397), 1,
       sym__raw_text_start_tag,
-    ACTIONS(84), 2,
+    ACTIONS(158), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(187), 2,
+    STATE(290), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    STATE(14), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(24), 6,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       sym_expression,
       aux_sym_document_repeat1,
-  [1316] = 21,
-    ACTIONS(3), 1,
-      sym_comment,
+  [1766] = 25,
     ACTIONS(7), 1,
       anon_sym_LT_BANG,
     ACTIONS(9), 1,
       anon_sym_LT,
     ACTIONS(15), 1,
       anon_sym_LBRACE,
-    ACTIONS(109), 1,
+    ACTIONS(116), 1,
       anon_sym_LT_SLASH,
     STATE(2), 1,
       sym_start_tag,
-    STATE(7), 1,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
       sym__unterminated_start_tag_with_close,
-    STATE(8), 1,
-      sym__member_start_tag,
-    STATE(10), 1,
+    STATE(18), 1,
       sym__namespaced_start_tag,
-    STATE(140), 1,
-      sym__member_end_tag,
-    STATE(170), 1,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
       sym__namespaced_unterminated_start_tag,
-    STATE(181), 1,
-      sym__member_unterminated_start_tag,
-    STATE(182), 1,
+    STATE(297), 1,
       sym__namespaced_self_closing_tag,
-    STATE(183), 1,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
       sym__member_self_closing_tag,
-    STATE(184), 1,
-      sym__broken_member_unterminated_start_tag,
-    STATE(205), 1,
-      sym__raw_text_unterminated_start_tag,
-    STATE(207), 1,
-      sym__unterminated_start_tag,
-    STATE(265), 1,
+    STATE(331), 1,
+      sym_end_tag,
+    STATE(397), 1,
       sym__raw_text_start_tag,
-    ACTIONS(84), 2,
+    ACTIONS(158), 3,
       sym_text,
+      sym_cdata,
       sym_entity,
-    STATE(187), 2,
+    STATE(290), 3,
+      sym__template_element,
       sym__raw_text_element,
       sym_self_closing_tag,
-    STATE(14), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(24), 6,
       sym_doctype,
       sym__node,
       sym_element,
       sym_erroneous_end_tag,
       sym_expression,
       aux_sym_document_repeat1,
-  [1387] = 15,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(117), 1,
-      anon_sym_GT,
-    ACTIONS(119), 1,
-      anon_sym_SLASH_GT,
-    ACTIONS(123), 1,
-      anon_sym_DOT,
-    ACTIONS(125), 1,
+  [1854] = 25,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(15), 1,
       anon_sym_LBRACE,
-    ACTIONS(127), 1,
-      sym__ts_lang_marker,
-    ACTIONS(129), 1,
-      sym__directive_marker,
-    ACTIONS(133), 1,
-      sym__unterminated_tag_end,
-    ACTIONS(135), 1,
-      sym__unterminated_tag_end_open,
-    STATE(53), 1,
-      sym_attribute_name,
-    STATE(54), 1,
-      sym___attribute_directive,
-    STATE(86), 1,
-      sym_shorthand_attribute,
-    ACTIONS(121), 2,
+    ACTIONS(148), 1,
+      anon_sym_LT_SLASH,
+    STATE(2), 1,
+      sym_start_tag,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
+      sym__unterminated_start_tag_with_close,
+    STATE(18), 1,
+      sym__namespaced_start_tag,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
+      sym__namespaced_unterminated_start_tag,
+    STATE(297), 1,
+      sym__namespaced_self_closing_tag,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
+      sym__member_self_closing_tag,
+    STATE(333), 1,
+      sym__namespaced_end_tag,
+    STATE(397), 1,
+      sym__raw_text_start_tag,
+    ACTIONS(158), 3,
+      sym_text,
+      sym_cdata,
+      sym_entity,
+    STATE(290), 3,
+      sym__template_element,
+      sym__raw_text_element,
+      sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(24), 6,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_erroneous_end_tag,
+      sym_expression,
+      aux_sym_document_repeat1,
+  [1942] = 25,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(152), 1,
+      anon_sym_LT_SLASH,
+    STATE(2), 1,
+      sym_start_tag,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
+      sym__unterminated_start_tag_with_close,
+    STATE(18), 1,
+      sym__namespaced_start_tag,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
+      sym__namespaced_unterminated_start_tag,
+    STATE(297), 1,
+      sym__namespaced_self_closing_tag,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
+      sym__member_self_closing_tag,
+    STATE(334), 1,
+      sym__member_end_tag,
+    STATE(397), 1,
+      sym__raw_text_start_tag,
+    ACTIONS(158), 3,
+      sym_text,
+      sym_cdata,
+      sym_entity,
+    STATE(290), 3,
+      sym__template_element,
+      sym__raw_text_element,
+      sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(24), 6,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_erroneous_end_tag,
+      sym_expression,
+      aux_sym_document_repeat1,
+  [2030] = 25,
+    ACTIONS(114), 1,
+      ts_builtin_sym_end,
+    ACTIONS(160), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(163), 1,
+      anon_sym_LT,
+    ACTIONS(166), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(172), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_start_tag,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
+      sym__unterminated_start_tag_with_close,
+    STATE(18), 1,
+      sym__namespaced_start_tag,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
+      sym__namespaced_unterminated_start_tag,
+    STATE(297), 1,
+      sym__namespaced_self_closing_tag,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
+      sym__member_self_closing_tag,
+    STATE(397), 1,
+      sym__raw_text_start_tag,
+    ACTIONS(169), 3,
+      sym_text,
+      sym_cdata,
+      sym_entity,
+    STATE(290), 3,
+      sym__template_element,
+      sym__raw_text_element,
+      sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(24), 6,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_erroneous_end_tag,
+      sym_expression,
+      aux_sym_document_repeat1,
+  [2118] = 25,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(142), 1,
+      anon_sym_LT_SLASH,
+    STATE(2), 1,
+      sym_start_tag,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
+      sym__unterminated_start_tag_with_close,
+    STATE(18), 1,
+      sym__namespaced_start_tag,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(143), 1,
+      sym_end_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
+      sym__namespaced_unterminated_start_tag,
+    STATE(297), 1,
+      sym__namespaced_self_closing_tag,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
+      sym__member_self_closing_tag,
+    STATE(397), 1,
+      sym__raw_text_start_tag,
+    ACTIONS(175), 3,
+      sym_text,
+      sym_cdata,
+      sym_entity,
+    STATE(290), 3,
+      sym__template_element,
+      sym__raw_text_element,
+      sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(28), 6,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_erroneous_end_tag,
+      sym_expression,
+      aux_sym_document_repeat1,
+  [2206] = 25,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(177), 1,
+      anon_sym_LT_SLASH,
+    STATE(2), 1,
+      sym_start_tag,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
+      sym__unterminated_start_tag_with_close,
+    STATE(18), 1,
+      sym__namespaced_start_tag,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(145), 1,
+      sym__namespaced_end_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
+      sym__namespaced_unterminated_start_tag,
+    STATE(297), 1,
+      sym__namespaced_self_closing_tag,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
+      sym__member_self_closing_tag,
+    STATE(397), 1,
+      sym__raw_text_start_tag,
+    ACTIONS(179), 3,
+      sym_text,
+      sym_cdata,
+      sym_entity,
+    STATE(290), 3,
+      sym__template_element,
+      sym__raw_text_element,
+      sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(29), 6,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_erroneous_end_tag,
+      sym_expression,
+      aux_sym_document_repeat1,
+  [2294] = 25,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(181), 1,
+      anon_sym_LT_SLASH,
+    STATE(2), 1,
+      sym_start_tag,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
+      sym__unterminated_start_tag_with_close,
+    STATE(18), 1,
+      sym__namespaced_start_tag,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(146), 1,
+      sym__member_end_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
+      sym__namespaced_unterminated_start_tag,
+    STATE(297), 1,
+      sym__namespaced_self_closing_tag,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
+      sym__member_self_closing_tag,
+    STATE(397), 1,
+      sym__raw_text_start_tag,
+    ACTIONS(183), 3,
+      sym_text,
+      sym_cdata,
+      sym_entity,
+    STATE(290), 3,
+      sym__template_element,
+      sym__raw_text_element,
+      sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(30), 6,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_erroneous_end_tag,
+      sym_expression,
+      aux_sym_document_repeat1,
+  [2382] = 25,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(142), 1,
+      anon_sym_LT_SLASH,
+    STATE(2), 1,
+      sym_start_tag,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
+      sym__unterminated_start_tag_with_close,
+    STATE(18), 1,
+      sym__namespaced_start_tag,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(166), 1,
+      sym_end_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
+      sym__namespaced_unterminated_start_tag,
+    STATE(297), 1,
+      sym__namespaced_self_closing_tag,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
+      sym__member_self_closing_tag,
+    STATE(397), 1,
+      sym__raw_text_start_tag,
+    ACTIONS(158), 3,
+      sym_text,
+      sym_cdata,
+      sym_entity,
+    STATE(290), 3,
+      sym__template_element,
+      sym__raw_text_element,
+      sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(24), 6,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_erroneous_end_tag,
+      sym_expression,
+      aux_sym_document_repeat1,
+  [2470] = 25,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(177), 1,
+      anon_sym_LT_SLASH,
+    STATE(2), 1,
+      sym_start_tag,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
+      sym__unterminated_start_tag_with_close,
+    STATE(18), 1,
+      sym__namespaced_start_tag,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(168), 1,
+      sym__namespaced_end_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
+      sym__namespaced_unterminated_start_tag,
+    STATE(297), 1,
+      sym__namespaced_self_closing_tag,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
+      sym__member_self_closing_tag,
+    STATE(397), 1,
+      sym__raw_text_start_tag,
+    ACTIONS(158), 3,
+      sym_text,
+      sym_cdata,
+      sym_entity,
+    STATE(290), 3,
+      sym__template_element,
+      sym__raw_text_element,
+      sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(24), 6,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_erroneous_end_tag,
+      sym_expression,
+      aux_sym_document_repeat1,
+  [2558] = 25,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(181), 1,
+      anon_sym_LT_SLASH,
+    STATE(2), 1,
+      sym_start_tag,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
+      sym__unterminated_start_tag_with_close,
+    STATE(18), 1,
+      sym__namespaced_start_tag,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(169), 1,
+      sym__member_end_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
+      sym__namespaced_unterminated_start_tag,
+    STATE(297), 1,
+      sym__namespaced_self_closing_tag,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
+      sym__member_self_closing_tag,
+    STATE(397), 1,
+      sym__raw_text_start_tag,
+    ACTIONS(158), 3,
+      sym_text,
+      sym_cdata,
+      sym_entity,
+    STATE(290), 3,
+      sym__template_element,
+      sym__raw_text_element,
+      sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(24), 6,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_erroneous_end_tag,
+      sym_expression,
+      aux_sym_document_repeat1,
+  [2646] = 25,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(144), 1,
+      anon_sym_LT_SLASH,
+    STATE(2), 1,
+      sym_start_tag,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
+      sym__unterminated_start_tag_with_close,
+    STATE(18), 1,
+      sym__namespaced_start_tag,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(240), 1,
+      sym_end_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
+      sym__namespaced_unterminated_start_tag,
+    STATE(297), 1,
+      sym__namespaced_self_closing_tag,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
+      sym__member_self_closing_tag,
+    STATE(397), 1,
+      sym__raw_text_start_tag,
+    ACTIONS(185), 3,
+      sym_text,
+      sym_cdata,
+      sym_entity,
+    STATE(290), 3,
+      sym__template_element,
+      sym__raw_text_element,
+      sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(34), 6,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_erroneous_end_tag,
+      sym_expression,
+      aux_sym_document_repeat1,
+  [2734] = 25,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(187), 1,
+      anon_sym_LT_SLASH,
+    STATE(2), 1,
+      sym_start_tag,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
+      sym__unterminated_start_tag_with_close,
+    STATE(18), 1,
+      sym__namespaced_start_tag,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(242), 1,
+      sym__namespaced_end_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
+      sym__namespaced_unterminated_start_tag,
+    STATE(297), 1,
+      sym__namespaced_self_closing_tag,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
+      sym__member_self_closing_tag,
+    STATE(397), 1,
+      sym__raw_text_start_tag,
+    ACTIONS(189), 3,
+      sym_text,
+      sym_cdata,
+      sym_entity,
+    STATE(290), 3,
+      sym__template_element,
+      sym__raw_text_element,
+      sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(35), 6,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_erroneous_end_tag,
+      sym_expression,
+      aux_sym_document_repeat1,
+  [2822] = 25,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(191), 1,
+      anon_sym_LT_SLASH,
+    STATE(2), 1,
+      sym_start_tag,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
+      sym__unterminated_start_tag_with_close,
+    STATE(18), 1,
+      sym__namespaced_start_tag,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(243), 1,
+      sym__member_end_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
+      sym__namespaced_unterminated_start_tag,
+    STATE(297), 1,
+      sym__namespaced_self_closing_tag,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
+      sym__member_self_closing_tag,
+    STATE(397), 1,
+      sym__raw_text_start_tag,
+    ACTIONS(193), 3,
+      sym_text,
+      sym_cdata,
+      sym_entity,
+    STATE(290), 3,
+      sym__template_element,
+      sym__raw_text_element,
+      sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(36), 6,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_erroneous_end_tag,
+      sym_expression,
+      aux_sym_document_repeat1,
+  [2910] = 25,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(144), 1,
+      anon_sym_LT_SLASH,
+    STATE(2), 1,
+      sym_start_tag,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
+      sym__unterminated_start_tag_with_close,
+    STATE(18), 1,
+      sym__namespaced_start_tag,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(263), 1,
+      sym_end_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
+      sym__namespaced_unterminated_start_tag,
+    STATE(297), 1,
+      sym__namespaced_self_closing_tag,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
+      sym__member_self_closing_tag,
+    STATE(397), 1,
+      sym__raw_text_start_tag,
+    ACTIONS(158), 3,
+      sym_text,
+      sym_cdata,
+      sym_entity,
+    STATE(290), 3,
+      sym__template_element,
+      sym__raw_text_element,
+      sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(24), 6,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_erroneous_end_tag,
+      sym_expression,
+      aux_sym_document_repeat1,
+  [2998] = 25,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(187), 1,
+      anon_sym_LT_SLASH,
+    STATE(2), 1,
+      sym_start_tag,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
+      sym__unterminated_start_tag_with_close,
+    STATE(18), 1,
+      sym__namespaced_start_tag,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(265), 1,
+      sym__namespaced_end_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
+      sym__namespaced_unterminated_start_tag,
+    STATE(297), 1,
+      sym__namespaced_self_closing_tag,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
+      sym__member_self_closing_tag,
+    STATE(397), 1,
+      sym__raw_text_start_tag,
+    ACTIONS(158), 3,
+      sym_text,
+      sym_cdata,
+      sym_entity,
+    STATE(290), 3,
+      sym__template_element,
+      sym__raw_text_element,
+      sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(24), 6,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_erroneous_end_tag,
+      sym_expression,
+      aux_sym_document_repeat1,
+  [3086] = 25,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(191), 1,
+      anon_sym_LT_SLASH,
+    STATE(2), 1,
+      sym_start_tag,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
+      sym__unterminated_start_tag_with_close,
+    STATE(18), 1,
+      sym__namespaced_start_tag,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(266), 1,
+      sym__member_end_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
+      sym__namespaced_unterminated_start_tag,
+    STATE(297), 1,
+      sym__namespaced_self_closing_tag,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
+      sym__member_self_closing_tag,
+    STATE(397), 1,
+      sym__raw_text_start_tag,
+    ACTIONS(158), 3,
+      sym_text,
+      sym_cdata,
+      sym_entity,
+    STATE(290), 3,
+      sym__template_element,
+      sym__raw_text_element,
+      sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(24), 6,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_erroneous_end_tag,
+      sym_expression,
+      aux_sym_document_repeat1,
+  [3174] = 24,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(195), 1,
+      anon_sym_LT_SLASH,
+    STATE(2), 1,
+      sym_start_tag,
+    STATE(3), 1,
+      sym__template_start_tag,
+    STATE(12), 1,
+      sym__template_unterminated_start_tag_with_close,
+    STATE(17), 1,
+      sym__unterminated_start_tag_with_close,
+    STATE(18), 1,
+      sym__namespaced_start_tag,
+    STATE(19), 1,
+      sym__member_start_tag,
+    STATE(291), 1,
+      sym__template_self_closing_tag,
+    STATE(292), 1,
+      sym__unterminated_start_tag,
+    STATE(293), 1,
+      sym__broken_member_unterminated_start_tag,
+    STATE(294), 1,
+      sym__template_unterminated_start_tag,
+    STATE(295), 1,
+      sym__raw_text_unterminated_start_tag,
+    STATE(296), 1,
+      sym__namespaced_unterminated_start_tag,
+    STATE(297), 1,
+      sym__namespaced_self_closing_tag,
+    STATE(298), 1,
+      sym__member_unterminated_start_tag,
+    STATE(299), 1,
+      sym__member_self_closing_tag,
+    STATE(397), 1,
+      sym__raw_text_start_tag,
+    ACTIONS(158), 3,
+      sym_text,
+      sym_cdata,
+      sym_entity,
+    STATE(290), 3,
+      sym__template_element,
+      sym__raw_text_element,
+      sym_self_closing_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(24), 6,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_erroneous_end_tag,
+      sym_expression,
+      aux_sym_document_repeat1,
+  [3259] = 15,
+    ACTIONS(198), 1,
+      anon_sym_GT,
+    ACTIONS(200), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(204), 1,
+      anon_sym_DOT,
+    ACTIONS(206), 1,
+      anon_sym_LBRACE,
+    ACTIONS(208), 1,
+      sym__ts_lang_marker,
+    ACTIONS(210), 1,
+      sym__directive_marker,
+    ACTIONS(214), 1,
+      sym__unterminated_tag_end,
+    ACTIONS(216), 1,
+      sym__unterminated_tag_end_open,
+    STATE(79), 1,
+      sym___attribute_directive,
+    STATE(80), 1,
+      sym_attribute_name,
+    STATE(101), 1,
+      sym_shorthand_attribute,
+    ACTIONS(202), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(212), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(42), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [3313] = 15,
+    ACTIONS(198), 1,
+      anon_sym_GT,
+    ACTIONS(206), 1,
+      anon_sym_LBRACE,
+    ACTIONS(208), 1,
+      sym__ts_lang_marker,
+    ACTIONS(210), 1,
+      sym__directive_marker,
+    ACTIONS(216), 1,
+      sym__unterminated_tag_end_open,
+    ACTIONS(218), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(220), 1,
+      anon_sym_DOT,
+    ACTIONS(222), 1,
+      sym__unterminated_tag_end,
+    STATE(79), 1,
+      sym___attribute_directive,
+    STATE(80), 1,
+      sym_attribute_name,
+    STATE(101), 1,
+      sym_shorthand_attribute,
+    ACTIONS(202), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(212), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(46), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [3367] = 15,
+    ACTIONS(198), 1,
+      anon_sym_GT,
+    ACTIONS(206), 1,
+      anon_sym_LBRACE,
+    ACTIONS(208), 1,
+      sym__ts_lang_marker,
+    ACTIONS(210), 1,
+      sym__directive_marker,
+    ACTIONS(216), 1,
+      sym__unterminated_tag_end_open,
+    ACTIONS(224), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(226), 1,
+      anon_sym_DOT,
+    ACTIONS(228), 1,
+      sym__unterminated_tag_end,
+    STATE(79), 1,
+      sym___attribute_directive,
+    STATE(80), 1,
+      sym_attribute_name,
+    STATE(101), 1,
+      sym_shorthand_attribute,
+    ACTIONS(202), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(212), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(49), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [3421] = 14,
+    ACTIONS(206), 1,
+      anon_sym_LBRACE,
+    ACTIONS(208), 1,
+      sym__ts_lang_marker,
+    ACTIONS(210), 1,
+      sym__directive_marker,
+    ACTIONS(230), 1,
+      anon_sym_GT,
+    ACTIONS(232), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(234), 1,
+      sym__unterminated_tag_end,
+    ACTIONS(236), 1,
+      sym__unterminated_tag_end_open,
+    STATE(79), 1,
+      sym___attribute_directive,
+    STATE(80), 1,
+      sym_attribute_name,
+    STATE(101), 1,
+      sym_shorthand_attribute,
+    ACTIONS(202), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(212), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(43), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [3472] = 14,
+    ACTIONS(206), 1,
+      anon_sym_LBRACE,
+    ACTIONS(208), 1,
+      sym__ts_lang_marker,
+    ACTIONS(210), 1,
+      sym__directive_marker,
+    ACTIONS(238), 1,
+      anon_sym_GT,
+    ACTIONS(240), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(242), 1,
+      sym__unterminated_tag_end,
+    ACTIONS(244), 1,
+      sym__unterminated_tag_end_open,
+    STATE(79), 1,
+      sym___attribute_directive,
+    STATE(80), 1,
+      sym_attribute_name,
+    STATE(101), 1,
+      sym_shorthand_attribute,
+    ACTIONS(202), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(212), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(44), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [3523] = 14,
+    ACTIONS(206), 1,
+      anon_sym_LBRACE,
+    ACTIONS(208), 1,
+      sym__ts_lang_marker,
+    ACTIONS(210), 1,
+      sym__directive_marker,
+    ACTIONS(246), 1,
+      anon_sym_GT,
+    ACTIONS(248), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(250), 1,
+      sym__unterminated_tag_end,
+    ACTIONS(252), 1,
+      sym__unterminated_tag_end_open,
+    STATE(79), 1,
+      sym___attribute_directive,
+    STATE(80), 1,
+      sym_attribute_name,
+    STATE(101), 1,
+      sym_shorthand_attribute,
+    ACTIONS(202), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(212), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(44), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [3574] = 11,
+    ACTIONS(259), 1,
+      anon_sym_LBRACE,
+    ACTIONS(262), 1,
+      sym__ts_lang_marker,
+    ACTIONS(265), 1,
+      sym__directive_marker,
+    STATE(79), 1,
+      sym___attribute_directive,
+    STATE(80), 1,
+      sym_attribute_name,
+    STATE(101), 1,
+      sym_shorthand_attribute,
+    ACTIONS(256), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(268), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(254), 4,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+    STATE(44), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [3619] = 14,
+    ACTIONS(206), 1,
+      anon_sym_LBRACE,
+    ACTIONS(208), 1,
+      sym__ts_lang_marker,
+    ACTIONS(210), 1,
+      sym__directive_marker,
+    ACTIONS(230), 1,
+      anon_sym_GT,
+    ACTIONS(236), 1,
+      sym__unterminated_tag_end_open,
+    ACTIONS(271), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(273), 1,
+      sym__unterminated_tag_end,
+    STATE(79), 1,
+      sym___attribute_directive,
+    STATE(80), 1,
+      sym_attribute_name,
+    STATE(101), 1,
+      sym_shorthand_attribute,
+    ACTIONS(202), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(212), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(47), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [3670] = 14,
+    ACTIONS(206), 1,
+      anon_sym_LBRACE,
+    ACTIONS(208), 1,
+      sym__ts_lang_marker,
+    ACTIONS(210), 1,
+      sym__directive_marker,
+    ACTIONS(238), 1,
+      anon_sym_GT,
+    ACTIONS(244), 1,
+      sym__unterminated_tag_end_open,
+    ACTIONS(275), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(277), 1,
+      sym__unterminated_tag_end,
+    STATE(79), 1,
+      sym___attribute_directive,
+    STATE(80), 1,
+      sym_attribute_name,
+    STATE(101), 1,
+      sym_shorthand_attribute,
+    ACTIONS(202), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(212), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(44), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [3721] = 14,
+    ACTIONS(206), 1,
+      anon_sym_LBRACE,
+    ACTIONS(208), 1,
+      sym__ts_lang_marker,
+    ACTIONS(210), 1,
+      sym__directive_marker,
+    ACTIONS(246), 1,
+      anon_sym_GT,
+    ACTIONS(252), 1,
+      sym__unterminated_tag_end_open,
+    ACTIONS(279), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(281), 1,
+      sym__unterminated_tag_end,
+    STATE(79), 1,
+      sym___attribute_directive,
+    STATE(80), 1,
+      sym_attribute_name,
+    STATE(101), 1,
+      sym_shorthand_attribute,
+    ACTIONS(202), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(212), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(44), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [3772] = 14,
+    ACTIONS(206), 1,
+      anon_sym_LBRACE,
+    ACTIONS(208), 1,
+      sym__ts_lang_marker,
+    ACTIONS(210), 1,
+      sym__directive_marker,
+    ACTIONS(230), 1,
+      anon_sym_GT,
+    ACTIONS(236), 1,
+      sym__unterminated_tag_end_open,
+    ACTIONS(283), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(285), 1,
+      sym__unterminated_tag_end,
+    STATE(79), 1,
+      sym___attribute_directive,
+    STATE(80), 1,
+      sym_attribute_name,
+    STATE(101), 1,
+      sym_shorthand_attribute,
+    ACTIONS(202), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(212), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(50), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [3823] = 14,
+    ACTIONS(206), 1,
+      anon_sym_LBRACE,
+    ACTIONS(208), 1,
+      sym__ts_lang_marker,
+    ACTIONS(210), 1,
+      sym__directive_marker,
+    ACTIONS(238), 1,
+      anon_sym_GT,
+    ACTIONS(244), 1,
+      sym__unterminated_tag_end_open,
+    ACTIONS(287), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(289), 1,
+      sym__unterminated_tag_end,
+    STATE(79), 1,
+      sym___attribute_directive,
+    STATE(80), 1,
+      sym_attribute_name,
+    STATE(101), 1,
+      sym_shorthand_attribute,
+    ACTIONS(202), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(212), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(44), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [3874] = 14,
+    ACTIONS(206), 1,
+      anon_sym_LBRACE,
+    ACTIONS(208), 1,
+      sym__ts_lang_marker,
+    ACTIONS(210), 1,
+      sym__directive_marker,
+    ACTIONS(246), 1,
+      anon_sym_GT,
+    ACTIONS(252), 1,
+      sym__unterminated_tag_end_open,
+    ACTIONS(291), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(293), 1,
+      sym__unterminated_tag_end,
+    STATE(79), 1,
+      sym___attribute_directive,
+    STATE(80), 1,
+      sym_attribute_name,
+    STATE(101), 1,
+      sym_shorthand_attribute,
+    ACTIONS(202), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(212), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(44), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [3925] = 13,
+    ACTIONS(295), 1,
+      anon_sym_GT,
+    ACTIONS(297), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(301), 1,
+      anon_sym_LBRACE,
+    ACTIONS(303), 1,
+      sym__ts_lang_marker,
+    ACTIONS(305), 1,
+      sym__directive_marker,
+    ACTIONS(309), 1,
+      sym__unterminated_tag_end,
+    STATE(92), 1,
+      sym___attribute_directive,
+    STATE(93), 1,
+      sym_attribute_name,
+    STATE(195), 1,
+      sym_shorthand_attribute,
+    ACTIONS(299), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(307), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(53), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [3973] = 13,
+    ACTIONS(301), 1,
+      anon_sym_LBRACE,
+    ACTIONS(303), 1,
+      sym__ts_lang_marker,
+    ACTIONS(305), 1,
+      sym__directive_marker,
+    ACTIONS(311), 1,
+      anon_sym_GT,
+    ACTIONS(313), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(315), 1,
+      sym__unterminated_tag_end,
+    STATE(92), 1,
+      sym___attribute_directive,
+    STATE(93), 1,
+      sym_attribute_name,
+    STATE(195), 1,
+      sym_shorthand_attribute,
+    ACTIONS(299), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(307), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(54), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [4021] = 13,
+    ACTIONS(301), 1,
+      anon_sym_LBRACE,
+    ACTIONS(303), 1,
+      sym__ts_lang_marker,
+    ACTIONS(305), 1,
+      sym__directive_marker,
+    ACTIONS(317), 1,
+      anon_sym_GT,
+    ACTIONS(319), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(321), 1,
+      sym__unterminated_tag_end,
+    STATE(92), 1,
+      sym___attribute_directive,
+    STATE(93), 1,
+      sym_attribute_name,
+    STATE(195), 1,
+      sym_shorthand_attribute,
+    ACTIONS(299), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(307), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(55), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [4069] = 13,
+    ACTIONS(301), 1,
+      anon_sym_LBRACE,
+    ACTIONS(303), 1,
+      sym__ts_lang_marker,
+    ACTIONS(305), 1,
+      sym__directive_marker,
+    ACTIONS(323), 1,
+      anon_sym_GT,
+    ACTIONS(325), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(327), 1,
+      sym__unterminated_tag_end,
+    STATE(92), 1,
+      sym___attribute_directive,
+    STATE(93), 1,
+      sym_attribute_name,
+    STATE(195), 1,
+      sym_shorthand_attribute,
+    ACTIONS(299), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(307), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(55), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [4117] = 11,
+    ACTIONS(332), 1,
+      anon_sym_LBRACE,
+    ACTIONS(335), 1,
+      sym__ts_lang_marker,
+    ACTIONS(338), 1,
+      sym__directive_marker,
+    STATE(92), 1,
+      sym___attribute_directive,
+    STATE(93), 1,
+      sym_attribute_name,
+    STATE(195), 1,
+      sym_shorthand_attribute,
+    ACTIONS(329), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(341), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(254), 3,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(55), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [4161] = 13,
+    ACTIONS(295), 1,
+      anon_sym_GT,
+    ACTIONS(301), 1,
+      anon_sym_LBRACE,
+    ACTIONS(303), 1,
+      sym__ts_lang_marker,
+    ACTIONS(305), 1,
+      sym__directive_marker,
+    ACTIONS(344), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(346), 1,
+      sym__unterminated_tag_end,
+    STATE(92), 1,
+      sym___attribute_directive,
+    STATE(93), 1,
+      sym_attribute_name,
+    STATE(195), 1,
+      sym_shorthand_attribute,
+    ACTIONS(299), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(307), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(58), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [4209] = 13,
+    ACTIONS(301), 1,
+      anon_sym_LBRACE,
+    ACTIONS(303), 1,
+      sym__ts_lang_marker,
+    ACTIONS(305), 1,
+      sym__directive_marker,
+    ACTIONS(311), 1,
+      anon_sym_GT,
+    ACTIONS(348), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(350), 1,
+      sym__unterminated_tag_end,
+    STATE(92), 1,
+      sym___attribute_directive,
+    STATE(93), 1,
+      sym_attribute_name,
+    STATE(195), 1,
+      sym_shorthand_attribute,
+    ACTIONS(299), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(307), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(59), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [4257] = 13,
+    ACTIONS(301), 1,
+      anon_sym_LBRACE,
+    ACTIONS(303), 1,
+      sym__ts_lang_marker,
+    ACTIONS(305), 1,
+      sym__directive_marker,
+    ACTIONS(317), 1,
+      anon_sym_GT,
+    ACTIONS(352), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(354), 1,
+      sym__unterminated_tag_end,
+    STATE(92), 1,
+      sym___attribute_directive,
+    STATE(93), 1,
+      sym_attribute_name,
+    STATE(195), 1,
+      sym_shorthand_attribute,
+    ACTIONS(299), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(307), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(55), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [4305] = 13,
+    ACTIONS(301), 1,
+      anon_sym_LBRACE,
+    ACTIONS(303), 1,
+      sym__ts_lang_marker,
+    ACTIONS(305), 1,
+      sym__directive_marker,
+    ACTIONS(323), 1,
+      anon_sym_GT,
+    ACTIONS(356), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(358), 1,
+      sym__unterminated_tag_end,
+    STATE(92), 1,
+      sym___attribute_directive,
+    STATE(93), 1,
+      sym_attribute_name,
+    STATE(195), 1,
+      sym_shorthand_attribute,
+    ACTIONS(299), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(307), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(55), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [4353] = 13,
+    ACTIONS(295), 1,
+      anon_sym_GT,
+    ACTIONS(301), 1,
+      anon_sym_LBRACE,
+    ACTIONS(303), 1,
+      sym__ts_lang_marker,
+    ACTIONS(305), 1,
+      sym__directive_marker,
+    ACTIONS(360), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(362), 1,
+      sym__unterminated_tag_end,
+    STATE(92), 1,
+      sym___attribute_directive,
+    STATE(93), 1,
+      sym_attribute_name,
+    STATE(195), 1,
+      sym_shorthand_attribute,
+    ACTIONS(299), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(307), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(62), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [4401] = 13,
+    ACTIONS(301), 1,
+      anon_sym_LBRACE,
+    ACTIONS(303), 1,
+      sym__ts_lang_marker,
+    ACTIONS(305), 1,
+      sym__directive_marker,
+    ACTIONS(311), 1,
+      anon_sym_GT,
+    ACTIONS(364), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(366), 1,
+      sym__unterminated_tag_end,
+    STATE(92), 1,
+      sym___attribute_directive,
+    STATE(93), 1,
+      sym_attribute_name,
+    STATE(195), 1,
+      sym_shorthand_attribute,
+    ACTIONS(299), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(307), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(63), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [4449] = 13,
+    ACTIONS(301), 1,
+      anon_sym_LBRACE,
+    ACTIONS(303), 1,
+      sym__ts_lang_marker,
+    ACTIONS(305), 1,
+      sym__directive_marker,
+    ACTIONS(317), 1,
+      anon_sym_GT,
+    ACTIONS(368), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(370), 1,
+      sym__unterminated_tag_end,
+    STATE(92), 1,
+      sym___attribute_directive,
+    STATE(93), 1,
+      sym_attribute_name,
+    STATE(195), 1,
+      sym_shorthand_attribute,
+    ACTIONS(299), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(307), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(55), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [4497] = 13,
+    ACTIONS(301), 1,
+      anon_sym_LBRACE,
+    ACTIONS(303), 1,
+      sym__ts_lang_marker,
+    ACTIONS(305), 1,
+      sym__directive_marker,
+    ACTIONS(323), 1,
+      anon_sym_GT,
+    ACTIONS(372), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(374), 1,
+      sym__unterminated_tag_end,
+    STATE(92), 1,
+      sym___attribute_directive,
+    STATE(93), 1,
+      sym_attribute_name,
+    STATE(195), 1,
+      sym_shorthand_attribute,
+    ACTIONS(299), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(307), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(55), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [4545] = 12,
+    ACTIONS(376), 1,
+      anon_sym_GT,
+    ACTIONS(380), 1,
+      anon_sym_LBRACE,
+    ACTIONS(382), 1,
+      sym__ts_lang_marker,
+    ACTIONS(384), 1,
+      sym__directive_marker,
+    ACTIONS(388), 1,
+      sym__unterminated_tag_end,
+    STATE(113), 1,
+      sym___attribute_directive,
+    STATE(114), 1,
+      sym_attribute_name,
+    STATE(211), 1,
+      sym_shorthand_attribute,
+    ACTIONS(378), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(386), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(65), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [4590] = 12,
+    ACTIONS(380), 1,
+      anon_sym_LBRACE,
+    ACTIONS(382), 1,
+      sym__ts_lang_marker,
+    ACTIONS(384), 1,
+      sym__directive_marker,
+    ACTIONS(390), 1,
+      anon_sym_GT,
+    ACTIONS(392), 1,
+      sym__unterminated_tag_end,
+    STATE(113), 1,
+      sym___attribute_directive,
+    STATE(114), 1,
+      sym_attribute_name,
+    STATE(211), 1,
+      sym_shorthand_attribute,
+    ACTIONS(378), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(386), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(67), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [4635] = 5,
+    ACTIONS(396), 1,
+      anon_sym_PIPE,
+    STATE(72), 1,
+      aux_sym_attribute_modifiers_repeat1,
+    STATE(82), 1,
+      sym_attribute_modifiers,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(394), 13,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_EQ,
+      aux_sym_attribute_name_token1,
+      sym_attribute_expected_equals_tail,
+      anon_sym_LBRACE,
+  [4666] = 11,
+    ACTIONS(401), 1,
+      anon_sym_LBRACE,
+    ACTIONS(404), 1,
+      sym__ts_lang_marker,
+    ACTIONS(407), 1,
+      sym__directive_marker,
+    STATE(113), 1,
+      sym___attribute_directive,
+    STATE(114), 1,
+      sym_attribute_name,
+    STATE(211), 1,
+      sym_shorthand_attribute,
+    ACTIONS(254), 2,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+    ACTIONS(398), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(410), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(67), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [4709] = 12,
+    ACTIONS(376), 1,
+      anon_sym_GT,
+    ACTIONS(380), 1,
+      anon_sym_LBRACE,
+    ACTIONS(382), 1,
+      sym__ts_lang_marker,
+    ACTIONS(384), 1,
+      sym__directive_marker,
+    ACTIONS(413), 1,
+      sym__unterminated_tag_end,
+    STATE(113), 1,
+      sym___attribute_directive,
+    STATE(114), 1,
+      sym_attribute_name,
+    STATE(211), 1,
+      sym_shorthand_attribute,
+    ACTIONS(378), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(386), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(69), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [4754] = 12,
+    ACTIONS(380), 1,
+      anon_sym_LBRACE,
+    ACTIONS(382), 1,
+      sym__ts_lang_marker,
+    ACTIONS(384), 1,
+      sym__directive_marker,
+    ACTIONS(390), 1,
+      anon_sym_GT,
+    ACTIONS(415), 1,
+      sym__unterminated_tag_end,
+    STATE(113), 1,
+      sym___attribute_directive,
+    STATE(114), 1,
+      sym_attribute_name,
+    STATE(211), 1,
+      sym_shorthand_attribute,
+    ACTIONS(378), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(386), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(67), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [4799] = 12,
+    ACTIONS(376), 1,
+      anon_sym_GT,
+    ACTIONS(380), 1,
+      anon_sym_LBRACE,
+    ACTIONS(382), 1,
+      sym__ts_lang_marker,
+    ACTIONS(384), 1,
+      sym__directive_marker,
+    ACTIONS(417), 1,
+      sym__unterminated_tag_end,
+    STATE(113), 1,
+      sym___attribute_directive,
+    STATE(114), 1,
+      sym_attribute_name,
+    STATE(211), 1,
+      sym_shorthand_attribute,
+    ACTIONS(378), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(386), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(71), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [4844] = 12,
+    ACTIONS(380), 1,
+      anon_sym_LBRACE,
+    ACTIONS(382), 1,
+      sym__ts_lang_marker,
+    ACTIONS(384), 1,
+      sym__directive_marker,
+    ACTIONS(390), 1,
+      anon_sym_GT,
+    ACTIONS(419), 1,
+      sym__unterminated_tag_end,
+    STATE(113), 1,
+      sym___attribute_directive,
+    STATE(114), 1,
+      sym_attribute_name,
+    STATE(211), 1,
+      sym_shorthand_attribute,
+    ACTIONS(378), 2,
+      sym__pipe_attribute_name,
+      aux_sym_attribute_name_token1,
+    ACTIONS(386), 2,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    STATE(67), 4,
+      sym_attribute,
+      sym__tag_attribute_item,
+      sym_tag_comment,
+      aux_sym_start_tag_repeat1,
+  [4889] = 4,
+    ACTIONS(396), 1,
+      anon_sym_PIPE,
+    STATE(73), 1,
+      aux_sym_attribute_modifiers_repeat1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(421), 13,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_EQ,
+      aux_sym_attribute_name_token1,
+      sym_attribute_expected_equals_tail,
+      anon_sym_LBRACE,
+  [4917] = 4,
+    ACTIONS(425), 1,
+      anon_sym_PIPE,
+    STATE(73), 1,
+      aux_sym_attribute_modifiers_repeat1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(423), 13,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_EQ,
+      aux_sym_attribute_name_token1,
+      sym_attribute_expected_equals_tail,
+      anon_sym_LBRACE,
+  [4945] = 5,
+    ACTIONS(428), 1,
+      anon_sym_PIPE,
+    STATE(77), 1,
+      aux_sym_attribute_modifiers_repeat1,
+    STATE(95), 1,
+      sym_attribute_modifiers,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(394), 12,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_EQ,
+      aux_sym_attribute_name_token1,
+      sym_attribute_expected_equals_tail,
+      anon_sym_LBRACE,
+  [4975] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(423), 14,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_EQ,
+      aux_sym_attribute_name_token1,
+      sym_attribute_expected_equals_tail,
+      anon_sym_LBRACE,
+      anon_sym_PIPE,
+  [4998] = 5,
+    ACTIONS(430), 1,
+      anon_sym_PIPE,
+    STATE(85), 1,
+      aux_sym_attribute_modifiers_repeat1,
+    STATE(116), 1,
+      sym_attribute_modifiers,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(394), 11,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      anon_sym_EQ,
+      aux_sym_attribute_name_token1,
+      sym_attribute_expected_equals_tail,
+      anon_sym_LBRACE,
+  [5027] = 4,
+    ACTIONS(428), 1,
+      anon_sym_PIPE,
+    STATE(78), 1,
+      aux_sym_attribute_modifiers_repeat1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(421), 12,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_EQ,
+      aux_sym_attribute_name_token1,
+      sym_attribute_expected_equals_tail,
+      anon_sym_LBRACE,
+  [5054] = 4,
+    ACTIONS(432), 1,
+      anon_sym_PIPE,
+    STATE(78), 1,
+      aux_sym_attribute_modifiers_repeat1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(423), 12,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_EQ,
+      aux_sym_attribute_name_token1,
+      sym_attribute_expected_equals_tail,
+      anon_sym_LBRACE,
+  [5081] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(435), 13,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_EQ,
+      aux_sym_attribute_name_token1,
+      sym_attribute_expected_equals_tail,
+      anon_sym_LBRACE,
+  [5103] = 4,
+    ACTIONS(439), 1,
+      anon_sym_EQ,
+    ACTIONS(441), 1,
+      sym_attribute_expected_equals_tail,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(437), 11,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [5129] = 5,
+    ACTIONS(445), 1,
+      anon_sym_LBRACE,
+    STATE(83), 1,
+      aux_sym_unquoted_attribute_value_repeat1,
+    STATE(90), 1,
+      sym_attribute_expression,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(443), 10,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+  [5157] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(448), 13,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_EQ,
+      aux_sym_attribute_name_token1,
+      sym_attribute_expected_equals_tail,
+      anon_sym_LBRACE,
+  [5179] = 5,
+    ACTIONS(452), 1,
+      anon_sym_LBRACE,
+    STATE(84), 1,
+      aux_sym_unquoted_attribute_value_repeat1,
+    STATE(90), 1,
+      sym_attribute_expression,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(450), 10,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+  [5207] = 5,
+    ACTIONS(456), 1,
+      anon_sym_LBRACE,
+    STATE(84), 1,
+      aux_sym_unquoted_attribute_value_repeat1,
+    STATE(90), 1,
+      sym_attribute_expression,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(454), 10,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+  [5235] = 4,
+    ACTIONS(430), 1,
+      anon_sym_PIPE,
+    STATE(86), 1,
+      aux_sym_attribute_modifiers_repeat1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(421), 11,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      anon_sym_EQ,
+      aux_sym_attribute_name_token1,
+      sym_attribute_expected_equals_tail,
+      anon_sym_LBRACE,
+  [5261] = 4,
+    ACTIONS(459), 1,
+      anon_sym_PIPE,
+    STATE(86), 1,
+      aux_sym_attribute_modifiers_repeat1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(423), 11,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      anon_sym_EQ,
+      aux_sym_attribute_name_token1,
+      sym_attribute_expected_equals_tail,
+      anon_sym_LBRACE,
+  [5287] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(423), 13,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_EQ,
+      aux_sym_attribute_name_token1,
+      sym_attribute_expected_equals_tail,
+      anon_sym_LBRACE,
+      anon_sym_PIPE,
+  [5309] = 4,
+    ACTIONS(464), 1,
+      anon_sym_DOT,
+    STATE(89), 1,
+      aux_sym__member_tag_name_repeat1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(462), 10,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [5334] = 4,
+    ACTIONS(468), 1,
+      anon_sym_DOT,
+    STATE(89), 1,
+      aux_sym__member_tag_name_repeat1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(466), 10,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [5359] = 3,
+    ACTIONS(473), 1,
+      sym__attribute_value,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(471), 11,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [5382] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(423), 12,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      anon_sym_EQ,
+      aux_sym_attribute_name_token1,
+      sym_attribute_expected_equals_tail,
+      anon_sym_LBRACE,
+      anon_sym_PIPE,
+  [5403] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(435), 12,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_EQ,
+      aux_sym_attribute_name_token1,
+      sym_attribute_expected_equals_tail,
+      anon_sym_LBRACE,
+  [5424] = 4,
+    ACTIONS(475), 1,
+      anon_sym_EQ,
+    ACTIONS(477), 1,
+      sym_attribute_expected_equals_tail,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(437), 10,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [5449] = 5,
+    ACTIONS(479), 1,
+      anon_sym_LBRACE,
+    STATE(96), 1,
+      aux_sym_unquoted_attribute_value_repeat1,
+    STATE(119), 1,
+      sym_attribute_expression,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(443), 9,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+  [5476] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(448), 12,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_EQ,
+      aux_sym_attribute_name_token1,
+      sym_attribute_expected_equals_tail,
+      anon_sym_LBRACE,
+  [5497] = 5,
+    ACTIONS(482), 1,
+      anon_sym_LBRACE,
+    STATE(97), 1,
+      aux_sym_unquoted_attribute_value_repeat1,
+    STATE(119), 1,
+      sym_attribute_expression,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(450), 9,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+  [5524] = 5,
+    ACTIONS(484), 1,
+      anon_sym_LBRACE,
+    STATE(97), 1,
+      aux_sym_unquoted_attribute_value_repeat1,
+    STATE(119), 1,
+      sym_attribute_expression,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(454), 9,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+  [5551] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(487), 12,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__attribute_value,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [5572] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(489), 12,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__attribute_value,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [5593] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(491), 11,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [5613] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(493), 11,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [5633] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(495), 11,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [5653] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(497), 11,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [5673] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(499), 11,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+      anon_sym_DOT,
+      anon_sym_LBRACE,
+  [5693] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(501), 11,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [5713] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(443), 11,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [5733] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(503), 11,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [5753] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(505), 11,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [5773] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(487), 11,
+      sym__ts_lang_marker,
+      sym__directive_marker,
       sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [5793] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(454), 11,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [5813] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(507), 11,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [5833] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(489), 11,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      sym__unterminated_tag_end_open,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [5853] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(435), 11,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      anon_sym_EQ,
+      aux_sym_attribute_name_token1,
+      sym_attribute_expected_equals_tail,
+      anon_sym_LBRACE,
+  [5873] = 4,
+    ACTIONS(509), 1,
+      anon_sym_EQ,
+    ACTIONS(511), 1,
+      sym_attribute_expected_equals_tail,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(437), 9,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
       aux_sym_attribute_name_token1,
-    ACTIONS(131), 2,
+      anon_sym_LBRACE,
+  [5897] = 5,
+    ACTIONS(513), 1,
+      anon_sym_LBRACE,
+    STATE(117), 1,
+      aux_sym_unquoted_attribute_value_repeat1,
+    STATE(189), 1,
+      sym_attribute_expression,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(443), 8,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
       sym__line_tag_comment,
       sym__block_tag_comment,
-    STATE(25), 4,
-      sym_attribute,
-      sym__tag_attribute_item,
-      sym_tag_comment,
-      aux_sym_start_tag_repeat1,
-  [1438] = 15,
-    ACTIONS(3), 1,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      aux_sym_attribute_name_token1,
+  [5923] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(117), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(448), 11,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
       anon_sym_GT,
-    ACTIONS(125), 1,
+      anon_sym_EQ,
+      aux_sym_attribute_name_token1,
+      sym_attribute_expected_equals_tail,
       anon_sym_LBRACE,
-    ACTIONS(127), 1,
+  [5943] = 5,
+    ACTIONS(516), 1,
+      anon_sym_LBRACE,
+    STATE(118), 1,
+      aux_sym_unquoted_attribute_value_repeat1,
+    STATE(189), 1,
+      sym_attribute_expression,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(450), 8,
       sym__ts_lang_marker,
-    ACTIONS(129), 1,
       sym__directive_marker,
-    ACTIONS(135), 1,
-      sym__unterminated_tag_end_open,
-    ACTIONS(137), 1,
-      anon_sym_SLASH_GT,
-    ACTIONS(139), 1,
-      anon_sym_DOT,
-    ACTIONS(141), 1,
-      sym__unterminated_tag_end,
-    STATE(53), 1,
-      sym_attribute_name,
-    STATE(54), 1,
-      sym___attribute_directive,
-    STATE(86), 1,
-      sym_shorthand_attribute,
-    ACTIONS(121), 2,
       sym__pipe_attribute_name,
-      aux_sym_attribute_name_token1,
-    ACTIONS(131), 2,
       sym__line_tag_comment,
       sym__block_tag_comment,
-    STATE(24), 4,
-      sym_attribute,
-      sym__tag_attribute_item,
-      sym_tag_comment,
-      aux_sym_start_tag_repeat1,
-  [1489] = 11,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(148), 1,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      aux_sym_attribute_name_token1,
+  [5969] = 5,
+    ACTIONS(518), 1,
       anon_sym_LBRACE,
-    ACTIONS(151), 1,
+    STATE(118), 1,
+      aux_sym_unquoted_attribute_value_repeat1,
+    STATE(189), 1,
+      sym_attribute_expression,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(454), 8,
       sym__ts_lang_marker,
-    ACTIONS(154), 1,
       sym__directive_marker,
-    STATE(53), 1,
-      sym_attribute_name,
-    STATE(54), 1,
-      sym___attribute_directive,
-    STATE(86), 1,
-      sym_shorthand_attribute,
-    ACTIONS(145), 2,
       sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
       aux_sym_attribute_name_token1,
-    ACTIONS(157), 2,
+  [5995] = 3,
+    ACTIONS(521), 1,
+      sym__attribute_value,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(471), 10,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__pipe_attribute_name,
       sym__line_tag_comment,
       sym__block_tag_comment,
-    ACTIONS(143), 4,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
       anon_sym_GT,
       anon_sym_SLASH_GT,
-    STATE(23), 4,
-      sym_attribute,
-      sym__tag_attribute_item,
-      sym_tag_comment,
-      aux_sym_start_tag_repeat1,
-  [1531] = 14,
-    ACTIONS(3), 1,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [6017] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(125), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(487), 11,
+      sym__ts_lang_marker,
+      sym__directive_marker,
+      sym__attribute_value,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
       anon_sym_LBRACE,
-    ACTIONS(127), 1,
+  [6037] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(489), 11,
       sym__ts_lang_marker,
-    ACTIONS(129), 1,
       sym__directive_marker,
-    ACTIONS(160), 1,
+      sym__attribute_value,
+      sym__pipe_attribute_name,
+      sym__line_tag_comment,
+      sym__block_tag_comment,
+      sym__unterminated_tag_end,
       anon_sym_GT,
-    ACTIONS(162), 1,
       anon_sym_SLASH_GT,
-    ACTIONS(164), 1,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [6057] = 3,
+    ACTIONS(525), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(523), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-    ACTIONS(166), 1,
-      sym__unterminated_tag_end_open,
-    STATE(53), 1,
-      sym_attribute_name,
-    STATE(54), 1,
-      sym___attribute_directive,
-    STATE(86), 1,
-      sym_shorthand_attribute,
-    ACTIONS(121), 2,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6078] = 3,
+    ACTIONS(529), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(527), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6099] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(531), 10,
+      sym__ts_lang_marker,
+      sym__directive_marker,
       sym__pipe_attribute_name,
-      aux_sym_attribute_name_token1,
-    ACTIONS(131), 2,
       sym__line_tag_comment,
       sym__block_tag_comment,
-    STATE(23), 4,
-      sym_attribute,
-      sym__tag_attribute_item,
-      sym_tag_comment,
-      aux_sym_start_tag_repeat1,
-  [1579] = 14,
-    ACTIONS(3), 1,
+      sym__unterminated_tag_end,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+  [6118] = 3,
+    ACTIONS(535), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(533), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6139] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6160] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6181] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6202] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6223] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6244] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6265] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6286] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6307] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6328] = 3,
+    ACTIONS(543), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(541), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6349] = 3,
+    ACTIONS(547), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(545), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6370] = 3,
+    ACTIONS(547), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(545), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6391] = 3,
+    ACTIONS(547), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(125), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(545), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-    ACTIONS(127), 1,
-      sym__ts_lang_marker,
-    ACTIONS(129), 1,
-      sym__directive_marker,
-    ACTIONS(160), 1,
-      anon_sym_GT,
-    ACTIONS(166), 1,
-      sym__unterminated_tag_end_open,
-    ACTIONS(168), 1,
-      anon_sym_SLASH_GT,
-    ACTIONS(170), 1,
+  [6412] = 3,
+    ACTIONS(551), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(549), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-    STATE(53), 1,
-      sym_attribute_name,
-    STATE(54), 1,
-      sym___attribute_directive,
-    STATE(86), 1,
-      sym_shorthand_attribute,
-    ACTIONS(121), 2,
-      sym__pipe_attribute_name,
-      aux_sym_attribute_name_token1,
-    ACTIONS(131), 2,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-    STATE(23), 4,
-      sym_attribute,
-      sym__tag_attribute_item,
-      sym_tag_comment,
-      aux_sym_start_tag_repeat1,
-  [1627] = 11,
-    ACTIONS(3), 1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6433] = 3,
+    ACTIONS(555), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(175), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(553), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-    ACTIONS(178), 1,
-      sym__ts_lang_marker,
-    ACTIONS(181), 1,
-      sym__directive_marker,
-    STATE(66), 1,
-      sym_attribute_name,
-    STATE(68), 1,
-      sym___attribute_directive,
-    STATE(97), 1,
-      sym_shorthand_attribute,
-    ACTIONS(172), 2,
-      sym__pipe_attribute_name,
-      aux_sym_attribute_name_token1,
-    ACTIONS(184), 2,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-    ACTIONS(143), 3,
+  [6454] = 3,
+    ACTIONS(555), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(553), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-    STATE(26), 4,
-      sym_attribute,
-      sym__tag_attribute_item,
-      sym_tag_comment,
-      aux_sym_start_tag_repeat1,
-  [1668] = 13,
-    ACTIONS(3), 1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6475] = 3,
+    ACTIONS(555), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(187), 1,
-      anon_sym_GT,
-    ACTIONS(189), 1,
-      anon_sym_SLASH_GT,
-    ACTIONS(193), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(553), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-    ACTIONS(195), 1,
-      sym__ts_lang_marker,
-    ACTIONS(197), 1,
-      sym__directive_marker,
-    ACTIONS(201), 1,
+  [6496] = 3,
+    ACTIONS(559), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(557), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-    STATE(66), 1,
-      sym_attribute_name,
-    STATE(68), 1,
-      sym___attribute_directive,
-    STATE(97), 1,
-      sym_shorthand_attribute,
-    ACTIONS(191), 2,
-      sym__pipe_attribute_name,
-      aux_sym_attribute_name_token1,
-    ACTIONS(199), 2,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-    STATE(26), 4,
-      sym_attribute,
-      sym__tag_attribute_item,
-      sym_tag_comment,
-      aux_sym_start_tag_repeat1,
-  [1713] = 13,
-    ACTIONS(3), 1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6517] = 3,
+    ACTIONS(559), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(193), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(557), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-    ACTIONS(195), 1,
-      sym__ts_lang_marker,
-    ACTIONS(197), 1,
-      sym__directive_marker,
-    ACTIONS(203), 1,
-      anon_sym_GT,
-    ACTIONS(205), 1,
-      anon_sym_SLASH_GT,
-    ACTIONS(207), 1,
+  [6538] = 3,
+    ACTIONS(563), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(561), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-    STATE(66), 1,
-      sym_attribute_name,
-    STATE(68), 1,
-      sym___attribute_directive,
-    STATE(97), 1,
-      sym_shorthand_attribute,
-    ACTIONS(191), 2,
-      sym__pipe_attribute_name,
-      aux_sym_attribute_name_token1,
-    ACTIONS(199), 2,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-    STATE(26), 4,
-      sym_attribute,
-      sym__tag_attribute_item,
-      sym_tag_comment,
-      aux_sym_start_tag_repeat1,
-  [1758] = 13,
-    ACTIONS(3), 1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6559] = 3,
+    ACTIONS(563), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(193), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(561), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-    ACTIONS(195), 1,
-      sym__ts_lang_marker,
-    ACTIONS(197), 1,
-      sym__directive_marker,
-    ACTIONS(209), 1,
-      anon_sym_GT,
-    ACTIONS(211), 1,
-      anon_sym_SLASH_GT,
-    ACTIONS(213), 1,
+  [6580] = 3,
+    ACTIONS(567), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(565), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-    STATE(66), 1,
-      sym_attribute_name,
-    STATE(68), 1,
-      sym___attribute_directive,
-    STATE(97), 1,
-      sym_shorthand_attribute,
-    ACTIONS(191), 2,
-      sym__pipe_attribute_name,
-      aux_sym_attribute_name_token1,
-    ACTIONS(199), 2,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-    STATE(27), 4,
-      sym_attribute,
-      sym__tag_attribute_item,
-      sym_tag_comment,
-      aux_sym_start_tag_repeat1,
-  [1803] = 13,
-    ACTIONS(3), 1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6601] = 3,
+    ACTIONS(571), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(193), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(569), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-    ACTIONS(195), 1,
-      sym__ts_lang_marker,
-    ACTIONS(197), 1,
-      sym__directive_marker,
-    ACTIONS(215), 1,
-      anon_sym_GT,
-    ACTIONS(217), 1,
-      anon_sym_SLASH_GT,
-    ACTIONS(219), 1,
+  [6622] = 3,
+    ACTIONS(575), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(573), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-    STATE(66), 1,
-      sym_attribute_name,
-    STATE(68), 1,
-      sym___attribute_directive,
-    STATE(97), 1,
-      sym_shorthand_attribute,
-    ACTIONS(191), 2,
-      sym__pipe_attribute_name,
-      aux_sym_attribute_name_token1,
-    ACTIONS(199), 2,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-    STATE(33), 4,
-      sym_attribute,
-      sym__tag_attribute_item,
-      sym_tag_comment,
-      aux_sym_start_tag_repeat1,
-  [1848] = 13,
-    ACTIONS(3), 1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6643] = 3,
+    ACTIONS(579), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(193), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(577), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-    ACTIONS(195), 1,
-      sym__ts_lang_marker,
-    ACTIONS(197), 1,
-      sym__directive_marker,
-    ACTIONS(215), 1,
-      anon_sym_GT,
-    ACTIONS(221), 1,
-      anon_sym_SLASH_GT,
-    ACTIONS(223), 1,
+  [6664] = 3,
+    ACTIONS(583), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(581), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-    STATE(66), 1,
-      sym_attribute_name,
-    STATE(68), 1,
-      sym___attribute_directive,
-    STATE(97), 1,
-      sym_shorthand_attribute,
-    ACTIONS(191), 2,
-      sym__pipe_attribute_name,
-      aux_sym_attribute_name_token1,
-    ACTIONS(199), 2,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-    STATE(28), 4,
-      sym_attribute,
-      sym__tag_attribute_item,
-      sym_tag_comment,
-      aux_sym_start_tag_repeat1,
-  [1893] = 13,
-    ACTIONS(3), 1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6685] = 3,
+    ACTIONS(587), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(193), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(585), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-    ACTIONS(195), 1,
-      sym__ts_lang_marker,
-    ACTIONS(197), 1,
-      sym__directive_marker,
-    ACTIONS(209), 1,
-      anon_sym_GT,
-    ACTIONS(225), 1,
-      anon_sym_SLASH_GT,
-    ACTIONS(227), 1,
+  [6706] = 3,
+    ACTIONS(591), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(589), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-    STATE(66), 1,
-      sym_attribute_name,
-    STATE(68), 1,
-      sym___attribute_directive,
-    STATE(97), 1,
-      sym_shorthand_attribute,
-    ACTIONS(191), 2,
-      sym__pipe_attribute_name,
-      aux_sym_attribute_name_token1,
-    ACTIONS(199), 2,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-    STATE(34), 4,
-      sym_attribute,
-      sym__tag_attribute_item,
-      sym_tag_comment,
-      aux_sym_start_tag_repeat1,
-  [1938] = 13,
-    ACTIONS(3), 1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6727] = 3,
+    ACTIONS(595), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(193), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(593), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-    ACTIONS(195), 1,
-      sym__ts_lang_marker,
-    ACTIONS(197), 1,
-      sym__directive_marker,
-    ACTIONS(203), 1,
-      anon_sym_GT,
-    ACTIONS(229), 1,
-      anon_sym_SLASH_GT,
-    ACTIONS(231), 1,
+  [6748] = 3,
+    ACTIONS(599), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(597), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-    STATE(66), 1,
-      sym_attribute_name,
-    STATE(68), 1,
-      sym___attribute_directive,
-    STATE(97), 1,
-      sym_shorthand_attribute,
-    ACTIONS(191), 2,
-      sym__pipe_attribute_name,
-      aux_sym_attribute_name_token1,
-    ACTIONS(199), 2,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-    STATE(26), 4,
-      sym_attribute,
-      sym__tag_attribute_item,
-      sym_tag_comment,
-      aux_sym_start_tag_repeat1,
-  [1983] = 13,
-    ACTIONS(3), 1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6769] = 3,
+    ACTIONS(603), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(187), 1,
-      anon_sym_GT,
-    ACTIONS(193), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(601), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-    ACTIONS(195), 1,
-      sym__ts_lang_marker,
-    ACTIONS(197), 1,
-      sym__directive_marker,
-    ACTIONS(233), 1,
-      anon_sym_SLASH_GT,
-    ACTIONS(235), 1,
+  [6790] = 3,
+    ACTIONS(607), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(605), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-    STATE(66), 1,
-      sym_attribute_name,
-    STATE(68), 1,
-      sym___attribute_directive,
-    STATE(97), 1,
-      sym_shorthand_attribute,
-    ACTIONS(191), 2,
-      sym__pipe_attribute_name,
-      aux_sym_attribute_name_token1,
-    ACTIONS(199), 2,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-    STATE(26), 4,
-      sym_attribute,
-      sym__tag_attribute_item,
-      sym_tag_comment,
-      aux_sym_start_tag_repeat1,
-  [2028] = 12,
-    ACTIONS(3), 1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6811] = 3,
+    ACTIONS(611), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(237), 1,
-      anon_sym_GT,
-    ACTIONS(241), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(609), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-    ACTIONS(243), 1,
-      sym__ts_lang_marker,
-    ACTIONS(245), 1,
-      sym__directive_marker,
-    ACTIONS(249), 1,
+  [6832] = 3,
+    ACTIONS(615), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(613), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-    STATE(82), 1,
-      sym___attribute_directive,
-    STATE(84), 1,
-      sym_attribute_name,
-    STATE(127), 1,
-      sym_shorthand_attribute,
-    ACTIONS(239), 2,
-      sym__pipe_attribute_name,
-      aux_sym_attribute_name_token1,
-    ACTIONS(247), 2,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-    STATE(36), 4,
-      sym_attribute,
-      sym__tag_attribute_item,
-      sym_tag_comment,
-      aux_sym_start_tag_repeat1,
-  [2070] = 12,
-    ACTIONS(3), 1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6853] = 3,
+    ACTIONS(615), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(241), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(613), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-    ACTIONS(243), 1,
-      sym__ts_lang_marker,
-    ACTIONS(245), 1,
-      sym__directive_marker,
-    ACTIONS(251), 1,
-      anon_sym_GT,
-    ACTIONS(253), 1,
+  [6874] = 3,
+    ACTIONS(615), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(613), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-    STATE(82), 1,
-      sym___attribute_directive,
-    STATE(84), 1,
-      sym_attribute_name,
-    STATE(127), 1,
-      sym_shorthand_attribute,
-    ACTIONS(239), 2,
-      sym__pipe_attribute_name,
-      aux_sym_attribute_name_token1,
-    ACTIONS(247), 2,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-    STATE(38), 4,
-      sym_attribute,
-      sym__tag_attribute_item,
-      sym_tag_comment,
-      aux_sym_start_tag_repeat1,
-  [2112] = 12,
-    ACTIONS(3), 1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6895] = 3,
+    ACTIONS(619), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(237), 1,
-      anon_sym_GT,
-    ACTIONS(241), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(617), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-    ACTIONS(243), 1,
-      sym__ts_lang_marker,
-    ACTIONS(245), 1,
-      sym__directive_marker,
-    ACTIONS(255), 1,
+  [6916] = 3,
+    ACTIONS(623), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(621), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-    STATE(82), 1,
-      sym___attribute_directive,
-    STATE(84), 1,
-      sym_attribute_name,
-    STATE(127), 1,
-      sym_shorthand_attribute,
-    ACTIONS(239), 2,
-      sym__pipe_attribute_name,
-      aux_sym_attribute_name_token1,
-    ACTIONS(247), 2,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-    STATE(40), 4,
-      sym_attribute,
-      sym__tag_attribute_item,
-      sym_tag_comment,
-      aux_sym_start_tag_repeat1,
-  [2154] = 11,
-    ACTIONS(3), 1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6937] = 3,
+    ACTIONS(623), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(260), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(621), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-    ACTIONS(263), 1,
-      sym__ts_lang_marker,
-    ACTIONS(266), 1,
-      sym__directive_marker,
-    STATE(82), 1,
-      sym___attribute_directive,
-    STATE(84), 1,
-      sym_attribute_name,
-    STATE(127), 1,
-      sym_shorthand_attribute,
-    ACTIONS(143), 2,
+  [6958] = 3,
+    ACTIONS(623), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(621), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-    ACTIONS(257), 2,
-      sym__pipe_attribute_name,
-      aux_sym_attribute_name_token1,
-    ACTIONS(269), 2,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-    STATE(38), 4,
-      sym_attribute,
-      sym__tag_attribute_item,
-      sym_tag_comment,
-      aux_sym_start_tag_repeat1,
-  [2194] = 5,
-    ACTIONS(3), 1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [6979] = 3,
+    ACTIONS(627), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(274), 1,
-      anon_sym_PIPE,
-    STATE(43), 1,
-      aux_sym_attribute_modifiers_repeat1,
-    STATE(56), 1,
-      sym_attribute_modifiers,
-    ACTIONS(272), 13,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(625), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      anon_sym_EQ,
-      aux_sym_attribute_name_token1,
-      sym_attribute_expected_equals_tail,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [2222] = 12,
-    ACTIONS(3), 1,
+  [7000] = 3,
+    ACTIONS(627), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(241), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(625), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-    ACTIONS(243), 1,
-      sym__ts_lang_marker,
-    ACTIONS(245), 1,
-      sym__directive_marker,
-    ACTIONS(251), 1,
-      anon_sym_GT,
-    ACTIONS(276), 1,
+  [7021] = 3,
+    ACTIONS(631), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(629), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-    STATE(82), 1,
-      sym___attribute_directive,
-    STATE(84), 1,
-      sym_attribute_name,
-    STATE(127), 1,
-      sym_shorthand_attribute,
-    ACTIONS(239), 2,
-      sym__pipe_attribute_name,
-      aux_sym_attribute_name_token1,
-    ACTIONS(247), 2,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-    STATE(38), 4,
-      sym_attribute,
-      sym__tag_attribute_item,
-      sym_tag_comment,
-      aux_sym_start_tag_repeat1,
-  [2264] = 5,
-    ACTIONS(3), 1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [7042] = 3,
+    ACTIONS(631), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(278), 1,
-      anon_sym_PIPE,
-    STATE(46), 1,
-      aux_sym_attribute_modifiers_repeat1,
-    STATE(57), 1,
-      sym_attribute_modifiers,
-    ACTIONS(272), 12,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(629), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      anon_sym_EQ,
-      aux_sym_attribute_name_token1,
-      sym_attribute_expected_equals_tail,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [2291] = 4,
-    ACTIONS(3), 1,
+  [7063] = 3,
+    ACTIONS(635), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(282), 1,
-      anon_sym_PIPE,
-    STATE(42), 1,
-      aux_sym_attribute_modifiers_repeat1,
-    ACTIONS(280), 13,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(633), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      anon_sym_EQ,
-      aux_sym_attribute_name_token1,
-      sym_attribute_expected_equals_tail,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [2316] = 4,
-    ACTIONS(3), 1,
+  [7084] = 3,
+    ACTIONS(639), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(274), 1,
-      anon_sym_PIPE,
-    STATE(42), 1,
-      aux_sym_attribute_modifiers_repeat1,
-    ACTIONS(285), 13,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(637), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      anon_sym_EQ,
-      aux_sym_attribute_name_token1,
-      sym_attribute_expected_equals_tail,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [2341] = 2,
-    ACTIONS(3), 1,
+  [7105] = 3,
+    ACTIONS(643), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(280), 14,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(641), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      anon_sym_EQ,
-      aux_sym_attribute_name_token1,
-      sym_attribute_expected_equals_tail,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-      anon_sym_PIPE,
-  [2361] = 5,
-    ACTIONS(3), 1,
+  [7126] = 3,
+    ACTIONS(647), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(287), 1,
-      anon_sym_PIPE,
-    STATE(52), 1,
-      aux_sym_attribute_modifiers_repeat1,
-    STATE(73), 1,
-      sym_attribute_modifiers,
-    ACTIONS(272), 11,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(645), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_EQ,
-      aux_sym_attribute_name_token1,
-      sym_attribute_expected_equals_tail,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [2387] = 4,
-    ACTIONS(3), 1,
+  [7147] = 3,
+    ACTIONS(651), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(278), 1,
-      anon_sym_PIPE,
-    STATE(47), 1,
-      aux_sym_attribute_modifiers_repeat1,
-    ACTIONS(285), 12,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(649), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      anon_sym_EQ,
-      aux_sym_attribute_name_token1,
-      sym_attribute_expected_equals_tail,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [2411] = 4,
-    ACTIONS(3), 1,
+  [7168] = 3,
+    ACTIONS(655), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(289), 1,
-      anon_sym_PIPE,
-    STATE(47), 1,
-      aux_sym_attribute_modifiers_repeat1,
-    ACTIONS(280), 12,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(653), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      anon_sym_EQ,
-      aux_sym_attribute_name_token1,
-      sym_attribute_expected_equals_tail,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [2435] = 5,
-    ACTIONS(3), 1,
+  [7189] = 3,
+    ACTIONS(659), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(294), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(657), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-    STATE(49), 1,
-      aux_sym_unquoted_attribute_value_repeat1,
-    STATE(65), 1,
-      sym_attribute_expression,
-    ACTIONS(292), 10,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+  [7210] = 3,
+    ACTIONS(663), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(661), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
-  [2460] = 5,
-    ACTIONS(3), 1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [7231] = 3,
+    ACTIONS(667), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(299), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(665), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-    STATE(55), 1,
-      aux_sym_unquoted_attribute_value_repeat1,
-    STATE(65), 1,
-      sym_attribute_expression,
-    ACTIONS(297), 10,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+  [7252] = 3,
+    ACTIONS(671), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(669), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
-  [2485] = 4,
-    ACTIONS(3), 1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [7273] = 3,
+    ACTIONS(675), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(301), 1,
-      anon_sym_PIPE,
-    STATE(50), 1,
-      aux_sym_attribute_modifiers_repeat1,
-    ACTIONS(280), 11,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(673), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_EQ,
-      aux_sym_attribute_name_token1,
-      sym_attribute_expected_equals_tail,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [7294] = 3,
+    ACTIONS(679), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(677), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [2508] = 2,
-    ACTIONS(3), 1,
+  [7315] = 3,
+    ACTIONS(683), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(280), 13,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(681), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      anon_sym_EQ,
-      aux_sym_attribute_name_token1,
-      sym_attribute_expected_equals_tail,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-      anon_sym_PIPE,
-  [2527] = 4,
-    ACTIONS(3), 1,
+  [7336] = 3,
+    ACTIONS(687), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(287), 1,
-      anon_sym_PIPE,
-    STATE(50), 1,
-      aux_sym_attribute_modifiers_repeat1,
-    ACTIONS(285), 11,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(685), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_EQ,
-      aux_sym_attribute_name_token1,
-      sym_attribute_expected_equals_tail,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [2550] = 4,
-    ACTIONS(3), 1,
+  [7357] = 3,
+    ACTIONS(691), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(306), 1,
-      anon_sym_EQ,
-    ACTIONS(308), 1,
-      sym_attribute_expected_equals_tail,
-    ACTIONS(304), 11,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(689), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [2573] = 2,
-    ACTIONS(3), 1,
+  [7378] = 3,
+    ACTIONS(695), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(310), 13,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(693), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      anon_sym_EQ,
-      aux_sym_attribute_name_token1,
-      sym_attribute_expected_equals_tail,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [2592] = 5,
-    ACTIONS(3), 1,
+  [7399] = 3,
+    ACTIONS(699), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(314), 1,
-      anon_sym_LBRACE,
-    STATE(55), 1,
-      aux_sym_unquoted_attribute_value_repeat1,
-    STATE(65), 1,
-      sym_attribute_expression,
-    ACTIONS(312), 10,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(697), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
-  [2617] = 2,
-    ACTIONS(3), 1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [7420] = 3,
+    ACTIONS(703), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(317), 13,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(701), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      anon_sym_EQ,
-      aux_sym_attribute_name_token1,
-      sym_attribute_expected_equals_tail,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [2636] = 2,
-    ACTIONS(3), 1,
+  [7441] = 3,
+    ACTIONS(707), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(317), 12,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(705), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      anon_sym_EQ,
-      aux_sym_attribute_name_token1,
-      sym_attribute_expected_equals_tail,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [2654] = 2,
-    ACTIONS(3), 1,
+  [7462] = 3,
+    ACTIONS(709), 1,
+      sym__attribute_value,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(280), 12,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(471), 9,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
@@ -3831,143 +8409,121 @@ static const uint16_t ts_small_parse_table[] = {
       sym__block_tag_comment,
       sym__unterminated_tag_end,
       anon_sym_GT,
-      anon_sym_EQ,
       aux_sym_attribute_name_token1,
-      sym_attribute_expected_equals_tail,
       anon_sym_LBRACE,
-      anon_sym_PIPE,
-  [2672] = 5,
-    ACTIONS(3), 1,
+  [7483] = 3,
+    ACTIONS(713), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(319), 1,
-      anon_sym_LBRACE,
-    STATE(61), 1,
-      aux_sym_unquoted_attribute_value_repeat1,
-    STATE(69), 1,
-      sym_attribute_expression,
-    ACTIONS(297), 9,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(711), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
-  [2696] = 5,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(321), 1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-    STATE(59), 1,
-      aux_sym_unquoted_attribute_value_repeat1,
-    STATE(69), 1,
-      sym_attribute_expression,
-    ACTIONS(292), 9,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-      sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
-  [2720] = 5,
-    ACTIONS(3), 1,
+  [7504] = 3,
+    ACTIONS(717), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(324), 1,
-      anon_sym_LBRACE,
-    STATE(61), 1,
-      aux_sym_unquoted_attribute_value_repeat1,
-    STATE(69), 1,
-      sym_attribute_expression,
-    ACTIONS(312), 9,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(715), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
-  [2744] = 2,
-    ACTIONS(3), 1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [7525] = 3,
+    ACTIONS(721), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(327), 12,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__attribute_value,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(719), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [2762] = 4,
-    ACTIONS(3), 1,
+  [7546] = 3,
+    ACTIONS(725), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(331), 1,
-      anon_sym_DOT,
-    STATE(63), 1,
-      aux_sym__member_tag_name_repeat1,
-    ACTIONS(329), 10,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(723), 9,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
+      sym__textarea_end_boundary,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [2784] = 2,
-    ACTIONS(3), 1,
+  [7567] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(334), 12,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(491), 10,
       sym__ts_lang_marker,
       sym__directive_marker,
-      sym__attribute_value,
       sym__pipe_attribute_name,
       sym__line_tag_comment,
       sym__block_tag_comment,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
       anon_sym_GT,
       anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
       anon_sym_LBRACE,
-  [2802] = 3,
-    ACTIONS(3), 1,
+  [7586] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(338), 1,
-      sym__attribute_value,
-    ACTIONS(336), 11,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(493), 10,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
       sym__line_tag_comment,
       sym__block_tag_comment,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
       anon_sym_GT,
       anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
       anon_sym_LBRACE,
-  [2822] = 4,
-    ACTIONS(3), 1,
+  [7605] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(340), 1,
-      anon_sym_EQ,
-    ACTIONS(342), 1,
-      sym_attribute_expected_equals_tail,
-    ACTIONS(304), 10,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(495), 10,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
@@ -3978,14 +8534,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
       anon_sym_LBRACE,
-  [2844] = 4,
-    ACTIONS(3), 1,
+  [7624] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(346), 1,
-      anon_sym_DOT,
-    STATE(63), 1,
-      aux_sym__member_tag_name_repeat1,
-    ACTIONS(344), 10,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(497), 10,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
@@ -3996,10 +8551,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
       anon_sym_LBRACE,
-  [2866] = 2,
-    ACTIONS(3), 1,
+  [7643] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(310), 12,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(501), 10,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
@@ -4008,16 +8566,15 @@ static const uint16_t ts_small_parse_table[] = {
       sym__unterminated_tag_end,
       anon_sym_GT,
       anon_sym_SLASH_GT,
-      anon_sym_EQ,
       aux_sym_attribute_name_token1,
-      sym_attribute_expected_equals_tail,
       anon_sym_LBRACE,
-  [2884] = 3,
-    ACTIONS(3), 1,
+  [7662] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(348), 1,
-      sym__attribute_value,
-    ACTIONS(336), 10,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(443), 10,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
@@ -4028,46 +8585,47 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
       anon_sym_LBRACE,
-  [2903] = 2,
-    ACTIONS(3), 1,
+  [7681] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(350), 11,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(503), 10,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
       sym__line_tag_comment,
       sym__block_tag_comment,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
       anon_sym_GT,
       anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
       anon_sym_LBRACE,
-  [2920] = 2,
-    ACTIONS(3), 1,
+  [7700] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(352), 11,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(505), 10,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
       sym__line_tag_comment,
       sym__block_tag_comment,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
       anon_sym_GT,
       anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
       anon_sym_LBRACE,
-  [2937] = 5,
-    ACTIONS(3), 1,
+  [7719] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(354), 1,
-      anon_sym_LBRACE,
-    STATE(75), 1,
-      aux_sym_unquoted_attribute_value_repeat1,
-    STATE(98), 1,
-      sym_attribute_expression,
-    ACTIONS(292), 8,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(487), 10,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
@@ -4075,11 +8633,16 @@ static const uint16_t ts_small_parse_table[] = {
       sym__block_tag_comment,
       sym__unterminated_tag_end,
       anon_sym_GT,
+      anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
-  [2960] = 2,
-    ACTIONS(3), 1,
+      anon_sym_LBRACE,
+  [7738] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(317), 11,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(454), 10,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
@@ -4087,35 +8650,33 @@ static const uint16_t ts_small_parse_table[] = {
       sym__block_tag_comment,
       sym__unterminated_tag_end,
       anon_sym_GT,
-      anon_sym_EQ,
+      anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
-      sym_attribute_expected_equals_tail,
       anon_sym_LBRACE,
-  [2977] = 2,
-    ACTIONS(3), 1,
+  [7757] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(357), 11,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(507), 10,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
       sym__line_tag_comment,
       sym__block_tag_comment,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
       anon_sym_GT,
       anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
-      anon_sym_LBRACE,
-  [2994] = 5,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(359), 1,
-      anon_sym_LBRACE,
-    STATE(90), 1,
-      aux_sym_unquoted_attribute_value_repeat1,
-    STATE(98), 1,
-      sym_attribute_expression,
-    ACTIONS(297), 8,
+      anon_sym_LBRACE,
+  [7776] = 2,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(489), 10,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
@@ -4123,86 +8684,132 @@ static const uint16_t ts_small_parse_table[] = {
       sym__block_tag_comment,
       sym__unterminated_tag_end,
       anon_sym_GT,
+      anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
-  [3017] = 2,
-    ACTIONS(3), 1,
+      anon_sym_LBRACE,
+  [7795] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(334), 11,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(487), 10,
       sym__ts_lang_marker,
       sym__directive_marker,
+      sym__attribute_value,
       sym__pipe_attribute_name,
       sym__line_tag_comment,
       sym__block_tag_comment,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
       anon_sym_GT,
-      anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
       anon_sym_LBRACE,
-  [3034] = 2,
-    ACTIONS(3), 1,
+  [7814] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(327), 11,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(489), 10,
       sym__ts_lang_marker,
       sym__directive_marker,
+      sym__attribute_value,
       sym__pipe_attribute_name,
       sym__line_tag_comment,
       sym__block_tag_comment,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
       anon_sym_GT,
-      anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
       anon_sym_LBRACE,
-  [3051] = 2,
-    ACTIONS(3), 1,
+  [7833] = 3,
+    ACTIONS(729), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(727), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [7853] = 3,
+    ACTIONS(733), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(731), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [7873] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(312), 11,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(491), 9,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
       sym__line_tag_comment,
       sym__block_tag_comment,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
       anon_sym_GT,
-      anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
       anon_sym_LBRACE,
-  [3068] = 2,
-    ACTIONS(3), 1,
+  [7891] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(327), 11,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(493), 9,
       sym__ts_lang_marker,
       sym__directive_marker,
-      sym__attribute_value,
       sym__pipe_attribute_name,
       sym__line_tag_comment,
       sym__block_tag_comment,
       sym__unterminated_tag_end,
       anon_sym_GT,
-      anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
       anon_sym_LBRACE,
-  [3085] = 2,
-    ACTIONS(3), 1,
+  [7909] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(334), 11,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(495), 9,
       sym__ts_lang_marker,
       sym__directive_marker,
-      sym__attribute_value,
       sym__pipe_attribute_name,
       sym__line_tag_comment,
       sym__block_tag_comment,
       sym__unterminated_tag_end,
       anon_sym_GT,
-      anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
       anon_sym_LBRACE,
-  [3102] = 2,
-    ACTIONS(3), 1,
+  [7927] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(361), 11,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(497), 9,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
@@ -4210,14 +8817,15 @@ static const uint16_t ts_small_parse_table[] = {
       sym__block_tag_comment,
       sym__unterminated_tag_end,
       anon_sym_GT,
-      anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
-      anon_sym_DOT,
       anon_sym_LBRACE,
-  [3119] = 2,
-    ACTIONS(3), 1,
+  [7945] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(310), 11,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(501), 9,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
@@ -4225,33 +8833,31 @@ static const uint16_t ts_small_parse_table[] = {
       sym__block_tag_comment,
       sym__unterminated_tag_end,
       anon_sym_GT,
-      anon_sym_EQ,
       aux_sym_attribute_name_token1,
-      sym_attribute_expected_equals_tail,
       anon_sym_LBRACE,
-  [3136] = 2,
-    ACTIONS(3), 1,
+  [7963] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(363), 11,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(443), 9,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
       sym__line_tag_comment,
       sym__block_tag_comment,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
       anon_sym_GT,
-      anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
       anon_sym_LBRACE,
-  [3153] = 4,
-    ACTIONS(3), 1,
+  [7981] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(365), 1,
-      anon_sym_EQ,
-    ACTIONS(367), 1,
-      sym_attribute_expected_equals_tail,
-    ACTIONS(304), 9,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(503), 9,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
@@ -4261,3044 +8867,4963 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_GT,
       aux_sym_attribute_name_token1,
       anon_sym_LBRACE,
-  [3174] = 2,
-    ACTIONS(3), 1,
+  [7999] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(369), 11,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(505), 9,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
       sym__line_tag_comment,
       sym__block_tag_comment,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
       anon_sym_GT,
-      anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
       anon_sym_LBRACE,
-  [3191] = 2,
-    ACTIONS(3), 1,
+  [8017] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(371), 11,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(487), 9,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
       sym__line_tag_comment,
       sym__block_tag_comment,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
       anon_sym_GT,
-      anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
       anon_sym_LBRACE,
-  [3208] = 2,
-    ACTIONS(3), 1,
+  [8035] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(373), 11,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(454), 9,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
       sym__line_tag_comment,
       sym__block_tag_comment,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
       anon_sym_GT,
-      anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
       anon_sym_LBRACE,
-  [3225] = 2,
-    ACTIONS(3), 1,
+  [8053] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(292), 11,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(507), 9,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
       sym__line_tag_comment,
       sym__block_tag_comment,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
       anon_sym_GT,
-      anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
       anon_sym_LBRACE,
-  [3242] = 2,
-    ACTIONS(3), 1,
+  [8071] = 2,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(375), 11,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(489), 9,
       sym__ts_lang_marker,
       sym__directive_marker,
       sym__pipe_attribute_name,
       sym__line_tag_comment,
       sym__block_tag_comment,
       sym__unterminated_tag_end,
-      sym__unterminated_tag_end_open,
       anon_sym_GT,
-      anon_sym_SLASH_GT,
       aux_sym_attribute_name_token1,
       anon_sym_LBRACE,
-  [3259] = 5,
-    ACTIONS(3), 1,
+  [8089] = 3,
+    ACTIONS(535), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(533), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8109] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8129] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8149] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8169] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8189] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8209] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8229] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8249] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8269] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8289] = 3,
+    ACTIONS(543), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(541), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8309] = 3,
+    ACTIONS(547), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(545), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8329] = 3,
+    ACTIONS(547), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(545), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8349] = 3,
+    ACTIONS(547), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(545), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8369] = 3,
+    ACTIONS(551), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(549), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8389] = 3,
+    ACTIONS(555), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(553), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8409] = 3,
+    ACTIONS(555), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(553), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8429] = 3,
+    ACTIONS(555), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(553), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8449] = 3,
+    ACTIONS(559), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(557), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8469] = 3,
+    ACTIONS(559), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(557), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8489] = 3,
+    ACTIONS(563), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(561), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8509] = 3,
+    ACTIONS(563), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(561), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8529] = 3,
+    ACTIONS(567), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(565), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8549] = 3,
+    ACTIONS(571), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(377), 1,
-      anon_sym_LBRACE,
-    STATE(90), 1,
-      aux_sym_unquoted_attribute_value_repeat1,
-    STATE(98), 1,
-      sym_attribute_expression,
-    ACTIONS(312), 8,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(569), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      aux_sym_attribute_name_token1,
-  [3282] = 2,
-    ACTIONS(3), 1,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8569] = 3,
+    ACTIONS(575), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(292), 10,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(573), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [3298] = 2,
-    ACTIONS(3), 1,
+  [8589] = 3,
+    ACTIONS(579), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(352), 10,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(577), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [3314] = 2,
-    ACTIONS(3), 1,
+  [8609] = 3,
+    ACTIONS(583), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(357), 10,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(581), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [3330] = 2,
-    ACTIONS(3), 1,
+  [8629] = 3,
+    ACTIONS(587), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(334), 10,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(585), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [3346] = 2,
-    ACTIONS(3), 1,
+  [8649] = 3,
+    ACTIONS(591), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(363), 10,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(589), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [3362] = 2,
-    ACTIONS(3), 1,
+  [8669] = 3,
+    ACTIONS(595), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(327), 10,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(593), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [3378] = 2,
-    ACTIONS(3), 1,
+  [8689] = 3,
+    ACTIONS(599), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(371), 10,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(597), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [3394] = 3,
-    ACTIONS(3), 1,
+  [8709] = 3,
+    ACTIONS(603), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(380), 1,
-      sym__attribute_value,
-    ACTIONS(336), 9,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(601), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      aux_sym_attribute_name_token1,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [3412] = 2,
-    ACTIONS(3), 1,
+  [8729] = 3,
+    ACTIONS(607), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(327), 10,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__attribute_value,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(605), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      aux_sym_attribute_name_token1,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [3428] = 2,
-    ACTIONS(3), 1,
+  [8749] = 3,
+    ACTIONS(611), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(373), 10,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(609), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [3444] = 2,
-    ACTIONS(3), 1,
+  [8769] = 3,
+    ACTIONS(615), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(375), 10,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(613), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [3460] = 2,
-    ACTIONS(3), 1,
+  [8789] = 3,
+    ACTIONS(615), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(334), 10,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__attribute_value,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(613), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      aux_sym_attribute_name_token1,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [3476] = 2,
-    ACTIONS(3), 1,
+  [8809] = 3,
+    ACTIONS(615), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(350), 10,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(613), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [3492] = 2,
-    ACTIONS(3), 1,
+  [8829] = 3,
+    ACTIONS(619), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(312), 10,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(617), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [3508] = 2,
-    ACTIONS(3), 1,
+  [8849] = 3,
+    ACTIONS(623), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(382), 10,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(621), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [3524] = 2,
-    ACTIONS(3), 1,
+  [8869] = 3,
+    ACTIONS(623), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(369), 10,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(621), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      anon_sym_GT,
-      anon_sym_SLASH_GT,
-      aux_sym_attribute_name_token1,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [3540] = 3,
-    ACTIONS(3), 1,
+  [8889] = 3,
+    ACTIONS(623), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(386), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(621), 8,
+      sym__implicit_end_tag,
+      sym_text,
+      sym_cdata,
+      sym__unterminated_tag_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [8909] = 3,
+    ACTIONS(627), 1,
       anon_sym_LT,
-    ACTIONS(384), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(625), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3557] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(390), 1,
+  [8929] = 3,
+    ACTIONS(627), 1,
       anon_sym_LT,
-    ACTIONS(388), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(625), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3574] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(394), 1,
+  [8949] = 3,
+    ACTIONS(631), 1,
       anon_sym_LT,
-    ACTIONS(392), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(629), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3591] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(394), 1,
+  [8969] = 3,
+    ACTIONS(631), 1,
       anon_sym_LT,
-    ACTIONS(392), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(629), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3608] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(394), 1,
+  [8989] = 3,
+    ACTIONS(635), 1,
       anon_sym_LT,
-    ACTIONS(392), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(633), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3625] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(394), 1,
+  [9009] = 3,
+    ACTIONS(639), 1,
       anon_sym_LT,
-    ACTIONS(392), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(637), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3642] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(394), 1,
+  [9029] = 3,
+    ACTIONS(643), 1,
       anon_sym_LT,
-    ACTIONS(392), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(641), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3659] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(394), 1,
+  [9049] = 3,
+    ACTIONS(647), 1,
       anon_sym_LT,
-    ACTIONS(392), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(645), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3676] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(394), 1,
+  [9069] = 3,
+    ACTIONS(651), 1,
       anon_sym_LT,
-    ACTIONS(392), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(649), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3693] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(398), 1,
+  [9089] = 3,
+    ACTIONS(655), 1,
       anon_sym_LT,
-    ACTIONS(396), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(653), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3710] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(402), 1,
+  [9109] = 3,
+    ACTIONS(659), 1,
       anon_sym_LT,
-    ACTIONS(400), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(657), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3727] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(402), 1,
+  [9129] = 3,
+    ACTIONS(663), 1,
       anon_sym_LT,
-    ACTIONS(400), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(661), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3744] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(402), 1,
+  [9149] = 3,
+    ACTIONS(667), 1,
       anon_sym_LT,
-    ACTIONS(400), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(665), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3761] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(406), 1,
+  [9169] = 3,
+    ACTIONS(671), 1,
       anon_sym_LT,
-    ACTIONS(404), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(669), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3778] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(410), 1,
+  [9189] = 3,
+    ACTIONS(675), 1,
       anon_sym_LT,
-    ACTIONS(408), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(673), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3795] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(414), 1,
+  [9209] = 3,
+    ACTIONS(679), 1,
       anon_sym_LT,
-    ACTIONS(412), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(677), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3812] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(414), 1,
+  [9229] = 3,
+    ACTIONS(683), 1,
       anon_sym_LT,
-    ACTIONS(412), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(681), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3829] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(363), 9,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-      sym__unterminated_tag_end,
-      anon_sym_GT,
-      aux_sym_attribute_name_token1,
-      anon_sym_LBRACE,
-  [3844] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(418), 1,
+  [9249] = 3,
+    ACTIONS(687), 1,
       anon_sym_LT,
-    ACTIONS(416), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(685), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3861] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(422), 1,
+  [9269] = 3,
+    ACTIONS(691), 1,
       anon_sym_LT,
-    ACTIONS(420), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(689), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3878] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(371), 9,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-      sym__unterminated_tag_end,
-      anon_sym_GT,
-      aux_sym_attribute_name_token1,
-      anon_sym_LBRACE,
-  [3893] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(426), 1,
+  [9289] = 3,
+    ACTIONS(695), 1,
       anon_sym_LT,
-    ACTIONS(424), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(693), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3910] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(430), 1,
+  [9309] = 3,
+    ACTIONS(699), 1,
       anon_sym_LT,
-    ACTIONS(428), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(697), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3927] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(434), 1,
+  [9329] = 3,
+    ACTIONS(703), 1,
       anon_sym_LT,
-    ACTIONS(432), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(701), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3944] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(438), 1,
+  [9349] = 3,
+    ACTIONS(707), 1,
       anon_sym_LT,
-    ACTIONS(436), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(705), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3961] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(442), 1,
+  [9369] = 3,
+    ACTIONS(713), 1,
       anon_sym_LT,
-    ACTIONS(440), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(711), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3978] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(446), 1,
+  [9389] = 3,
+    ACTIONS(717), 1,
       anon_sym_LT,
-    ACTIONS(444), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(715), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [3995] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(450), 1,
+  [9409] = 3,
+    ACTIONS(721), 1,
       anon_sym_LT,
-    ACTIONS(448), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(719), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4012] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(454), 1,
+  [9429] = 3,
+    ACTIONS(725), 1,
       anon_sym_LT,
-    ACTIONS(452), 8,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(723), 8,
       sym__implicit_end_tag,
       sym_text,
+      sym_cdata,
       sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4029] = 3,
-    ACTIONS(3), 1,
+  [9449] = 3,
+    ACTIONS(535), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(450), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(533), 7,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [9468] = 3,
+    ACTIONS(539), 1,
       anon_sym_LT,
-    ACTIONS(448), 8,
-      sym__implicit_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 7,
       sym_text,
-      sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
+      sym_cdata,
+      ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4046] = 3,
-    ACTIONS(3), 1,
+  [9487] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(458), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 7,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [9506] = 3,
+    ACTIONS(539), 1,
       anon_sym_LT,
-    ACTIONS(456), 8,
-      sym__implicit_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 7,
       sym_text,
-      sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
+      sym_cdata,
+      ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4063] = 3,
-    ACTIONS(3), 1,
+  [9525] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(462), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 7,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [9544] = 3,
+    ACTIONS(539), 1,
       anon_sym_LT,
-    ACTIONS(460), 8,
-      sym__implicit_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 7,
       sym_text,
-      sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
+      sym_cdata,
+      ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4080] = 3,
-    ACTIONS(3), 1,
+  [9563] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(466), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 7,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [9582] = 3,
+    ACTIONS(539), 1,
       anon_sym_LT,
-    ACTIONS(464), 8,
-      sym__implicit_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 7,
       sym_text,
-      sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
+      sym_cdata,
+      ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4097] = 3,
-    ACTIONS(3), 1,
+  [9601] = 3,
+    ACTIONS(539), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(466), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 7,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [9620] = 3,
+    ACTIONS(539), 1,
       anon_sym_LT,
-    ACTIONS(464), 8,
-      sym__implicit_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(537), 7,
       sym_text,
-      sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
+      sym_cdata,
+      ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4114] = 3,
-    ACTIONS(3), 1,
+  [9639] = 3,
+    ACTIONS(543), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(470), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(541), 7,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [9658] = 3,
+    ACTIONS(547), 1,
       anon_sym_LT,
-    ACTIONS(468), 8,
-      sym__implicit_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(545), 7,
       sym_text,
-      sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
+      sym_cdata,
+      ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4131] = 3,
-    ACTIONS(3), 1,
+  [9677] = 3,
+    ACTIONS(547), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(474), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(545), 7,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [9696] = 3,
+    ACTIONS(547), 1,
       anon_sym_LT,
-    ACTIONS(472), 8,
-      sym__implicit_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(545), 7,
       sym_text,
-      sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
+      sym_cdata,
+      ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4148] = 2,
-    ACTIONS(3), 1,
+  [9715] = 3,
+    ACTIONS(551), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(373), 9,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-      sym__unterminated_tag_end,
-      anon_sym_GT,
-      aux_sym_attribute_name_token1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(549), 7,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [4163] = 2,
-    ACTIONS(3), 1,
+  [9734] = 3,
+    ACTIONS(555), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(375), 9,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-      sym__unterminated_tag_end,
-      anon_sym_GT,
-      aux_sym_attribute_name_token1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(553), 7,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-  [4178] = 3,
-    ACTIONS(3), 1,
+  [9753] = 3,
+    ACTIONS(555), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(478), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(553), 7,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [9772] = 3,
+    ACTIONS(555), 1,
       anon_sym_LT,
-    ACTIONS(476), 8,
-      sym__implicit_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(553), 7,
       sym_text,
-      sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
+      sym_cdata,
+      ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4195] = 3,
-    ACTIONS(3), 1,
+  [9791] = 3,
+    ACTIONS(559), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(482), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(557), 7,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [9810] = 3,
+    ACTIONS(559), 1,
       anon_sym_LT,
-    ACTIONS(480), 8,
-      sym__implicit_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(557), 7,
       sym_text,
-      sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
+      sym_cdata,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+  [9829] = 3,
+    ACTIONS(563), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(561), 7,
+      sym_text,
+      sym_cdata,
+      ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4212] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(486), 1,
+  [9848] = 3,
+    ACTIONS(563), 1,
       anon_sym_LT,
-    ACTIONS(484), 8,
-      sym__implicit_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(561), 7,
       sym_text,
-      sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
+      sym_cdata,
+      ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4229] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(490), 1,
+  [9867] = 3,
+    ACTIONS(567), 1,
       anon_sym_LT,
-    ACTIONS(488), 8,
-      sym__implicit_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(565), 7,
       sym_text,
-      sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
+      sym_cdata,
+      ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4246] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(494), 1,
+  [9886] = 3,
+    ACTIONS(571), 1,
       anon_sym_LT,
-    ACTIONS(492), 8,
-      sym__implicit_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(569), 7,
       sym_text,
-      sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
+      sym_cdata,
+      ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4263] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(498), 1,
+  [9905] = 3,
+    ACTIONS(575), 1,
       anon_sym_LT,
-    ACTIONS(496), 8,
-      sym__implicit_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(573), 7,
       sym_text,
-      sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
+      sym_cdata,
+      ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4280] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(502), 1,
+  [9924] = 3,
+    ACTIONS(579), 1,
       anon_sym_LT,
-    ACTIONS(500), 8,
-      sym__implicit_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(577), 7,
       sym_text,
-      sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
+      sym_cdata,
+      ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4297] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(506), 1,
+  [9943] = 3,
+    ACTIONS(583), 1,
       anon_sym_LT,
-    ACTIONS(504), 8,
-      sym__implicit_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(581), 7,
       sym_text,
-      sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
+      sym_cdata,
+      ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4314] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(510), 1,
+  [9962] = 3,
+    ACTIONS(587), 1,
       anon_sym_LT,
-    ACTIONS(508), 8,
-      sym__implicit_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(585), 7,
       sym_text,
-      sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
+      sym_cdata,
+      ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4331] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(514), 1,
+  [9981] = 3,
+    ACTIONS(591), 1,
       anon_sym_LT,
-    ACTIONS(512), 8,
-      sym__implicit_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(589), 7,
       sym_text,
-      sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
+      sym_cdata,
+      ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4348] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(518), 1,
+  [10000] = 3,
+    ACTIONS(595), 1,
       anon_sym_LT,
-    ACTIONS(516), 8,
-      sym__implicit_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(593), 7,
       sym_text,
-      sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
+      sym_cdata,
+      ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4365] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(369), 9,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-      sym__unterminated_tag_end,
-      anon_sym_GT,
-      aux_sym_attribute_name_token1,
-      anon_sym_LBRACE,
-  [4380] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(292), 9,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-      sym__unterminated_tag_end,
-      anon_sym_GT,
-      aux_sym_attribute_name_token1,
-      anon_sym_LBRACE,
-  [4395] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(350), 9,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-      sym__unterminated_tag_end,
-      anon_sym_GT,
-      aux_sym_attribute_name_token1,
-      anon_sym_LBRACE,
-  [4410] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(522), 1,
+  [10019] = 3,
+    ACTIONS(599), 1,
       anon_sym_LT,
-    ACTIONS(520), 8,
-      sym__implicit_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(597), 7,
       sym_text,
-      sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
+      sym_cdata,
+      ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4427] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(352), 9,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-      sym__unterminated_tag_end,
-      anon_sym_GT,
-      aux_sym_attribute_name_token1,
-      anon_sym_LBRACE,
-  [4442] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(327), 9,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-      sym__unterminated_tag_end,
-      anon_sym_GT,
-      aux_sym_attribute_name_token1,
-      anon_sym_LBRACE,
-  [4457] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(312), 9,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-      sym__unterminated_tag_end,
-      anon_sym_GT,
-      aux_sym_attribute_name_token1,
-      anon_sym_LBRACE,
-  [4472] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(357), 9,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-      sym__unterminated_tag_end,
-      anon_sym_GT,
-      aux_sym_attribute_name_token1,
-      anon_sym_LBRACE,
-  [4487] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(334), 9,
-      sym__ts_lang_marker,
-      sym__directive_marker,
-      sym__pipe_attribute_name,
-      sym__line_tag_comment,
-      sym__block_tag_comment,
-      sym__unterminated_tag_end,
-      anon_sym_GT,
-      aux_sym_attribute_name_token1,
-      anon_sym_LBRACE,
-  [4502] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(450), 1,
+  [10038] = 3,
+    ACTIONS(603), 1,
       anon_sym_LT,
-    ACTIONS(448), 8,
-      sym__implicit_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(601), 7,
       sym_text,
-      sym__unterminated_tag_end,
-      sym__textarea_end_boundary,
+      sym_cdata,
+      ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4519] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(474), 1,
+  [10057] = 3,
+    ACTIONS(607), 1,
       anon_sym_LT,
-    ACTIONS(472), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(605), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4534] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(414), 1,
+  [10076] = 3,
+    ACTIONS(611), 1,
       anon_sym_LT,
-    ACTIONS(412), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(609), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4549] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(502), 1,
+  [10095] = 3,
+    ACTIONS(615), 1,
       anon_sym_LT,
-    ACTIONS(500), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(613), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4564] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(506), 1,
+  [10114] = 3,
+    ACTIONS(615), 1,
       anon_sym_LT,
-    ACTIONS(504), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(613), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4579] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(394), 1,
+  [10133] = 3,
+    ACTIONS(615), 1,
       anon_sym_LT,
-    ACTIONS(392), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(613), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4594] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(510), 1,
+  [10152] = 3,
+    ACTIONS(619), 1,
       anon_sym_LT,
-    ACTIONS(508), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(617), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4609] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(514), 1,
+  [10171] = 3,
+    ACTIONS(623), 1,
       anon_sym_LT,
-    ACTIONS(512), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(621), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4624] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(518), 1,
+  [10190] = 3,
+    ACTIONS(623), 1,
       anon_sym_LT,
-    ACTIONS(516), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(621), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4639] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(418), 1,
+  [10209] = 3,
+    ACTIONS(623), 1,
       anon_sym_LT,
-    ACTIONS(416), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(621), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4654] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(402), 1,
+  [10228] = 3,
+    ACTIONS(627), 1,
       anon_sym_LT,
-    ACTIONS(400), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(625), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4669] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(402), 1,
+  [10247] = 3,
+    ACTIONS(627), 1,
       anon_sym_LT,
-    ACTIONS(400), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(625), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4684] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(402), 1,
+  [10266] = 3,
+    ACTIONS(631), 1,
       anon_sym_LT,
-    ACTIONS(400), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(629), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4699] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(422), 1,
+  [10285] = 3,
+    ACTIONS(631), 1,
       anon_sym_LT,
-    ACTIONS(420), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(629), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4714] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(406), 1,
+  [10304] = 3,
+    ACTIONS(635), 1,
       anon_sym_LT,
-    ACTIONS(404), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(633), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4729] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(410), 1,
+  [10323] = 3,
+    ACTIONS(639), 1,
       anon_sym_LT,
-    ACTIONS(408), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(637), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4744] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(394), 1,
+  [10342] = 3,
+    ACTIONS(643), 1,
       anon_sym_LT,
-    ACTIONS(392), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(641), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4759] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(394), 1,
+  [10361] = 3,
+    ACTIONS(647), 1,
       anon_sym_LT,
-    ACTIONS(392), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(645), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4774] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(394), 1,
+  [10380] = 3,
+    ACTIONS(651), 1,
       anon_sym_LT,
-    ACTIONS(392), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(649), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4789] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(394), 1,
+  [10399] = 3,
+    ACTIONS(655), 1,
       anon_sym_LT,
-    ACTIONS(392), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(653), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4804] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(414), 1,
+  [10418] = 3,
+    ACTIONS(659), 1,
       anon_sym_LT,
-    ACTIONS(412), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(657), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4819] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(498), 1,
+  [10437] = 3,
+    ACTIONS(663), 1,
       anon_sym_LT,
-    ACTIONS(496), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(661), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4834] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(390), 1,
+  [10456] = 3,
+    ACTIONS(667), 1,
       anon_sym_LT,
-    ACTIONS(388), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(665), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4849] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(430), 1,
+  [10475] = 3,
+    ACTIONS(671), 1,
       anon_sym_LT,
-    ACTIONS(428), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(669), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4864] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(434), 1,
+  [10494] = 3,
+    ACTIONS(675), 1,
       anon_sym_LT,
-    ACTIONS(432), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(673), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4879] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(386), 1,
+  [10513] = 3,
+    ACTIONS(679), 1,
       anon_sym_LT,
-    ACTIONS(384), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(677), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4894] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(438), 1,
+  [10532] = 3,
+    ACTIONS(683), 1,
       anon_sym_LT,
-    ACTIONS(436), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(681), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4909] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(442), 1,
+  [10551] = 3,
+    ACTIONS(687), 1,
       anon_sym_LT,
-    ACTIONS(440), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(685), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4924] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(446), 1,
+  [10570] = 3,
+    ACTIONS(691), 1,
       anon_sym_LT,
-    ACTIONS(444), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(689), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4939] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(450), 1,
+  [10589] = 3,
+    ACTIONS(695), 1,
       anon_sym_LT,
-    ACTIONS(448), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(693), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4954] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(450), 1,
+  [10608] = 3,
+    ACTIONS(699), 1,
       anon_sym_LT,
-    ACTIONS(448), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(697), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4969] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(450), 1,
+  [10627] = 3,
+    ACTIONS(703), 1,
       anon_sym_LT,
-    ACTIONS(448), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(701), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4984] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(458), 1,
+  [10646] = 3,
+    ACTIONS(707), 1,
       anon_sym_LT,
-    ACTIONS(456), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(705), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [4999] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(462), 1,
+  [10665] = 3,
+    ACTIONS(713), 1,
       anon_sym_LT,
-    ACTIONS(460), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(711), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [5014] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(466), 1,
+  [10684] = 3,
+    ACTIONS(717), 1,
       anon_sym_LT,
-    ACTIONS(464), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(715), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [5029] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(466), 1,
+  [10703] = 3,
+    ACTIONS(721), 1,
       anon_sym_LT,
-    ACTIONS(464), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(719), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [5044] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(470), 1,
+  [10722] = 3,
+    ACTIONS(725), 1,
       anon_sym_LT,
-    ACTIONS(468), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(723), 7,
       sym_text,
+      sym_cdata,
       ts_builtin_sym_end,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [5059] = 3,
-    ACTIONS(3), 1,
+  [10741] = 8,
+    ACTIONS(735), 1,
+      sym__start_tag_name,
+    ACTIONS(737), 1,
+      sym__raw_text_start_tag_name,
+    ACTIONS(739), 1,
+      sym__template_start_tag_name,
+    ACTIONS(741), 1,
+      sym__tag_namespace,
+    ACTIONS(743), 1,
+      sym__member_tag_object,
+    STATE(51), 1,
+      sym__namespaced_tag_name,
+    STATE(52), 1,
+      sym__member_tag_name,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(494), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [10769] = 3,
+    ACTIONS(747), 1,
       anon_sym_LT,
-    ACTIONS(492), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(745), 6,
       sym_text,
-      ts_builtin_sym_end,
+      sym_cdata,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [5074] = 6,
-    ACTIONS(3), 1,
+  [10787] = 3,
+    ACTIONS(751), 1,
+      anon_sym_LT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(524), 1,
-      anon_sym_SQUOTE,
-    ACTIONS(526), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(528), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(749), 6,
+      sym_text,
+      sym_cdata,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
       anon_sym_LBRACE,
-    ACTIONS(530), 1,
-      sym__attribute_value,
-    STATE(88), 3,
-      sym_quoted_attribute_value,
-      sym_attribute_expression,
-      sym_unquoted_attribute_value,
-  [5095] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(478), 1,
+  [10805] = 3,
+    ACTIONS(755), 1,
       anon_sym_LT,
-    ACTIONS(476), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(753), 6,
       sym_text,
-      ts_builtin_sym_end,
+      sym_cdata,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [5110] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(394), 1,
+  [10823] = 3,
+    ACTIONS(759), 1,
       anon_sym_LT,
-    ACTIONS(392), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(757), 6,
       sym_text,
-      ts_builtin_sym_end,
+      sym_cdata,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [5125] = 3,
-    ACTIONS(3), 1,
+  [10841] = 6,
+    ACTIONS(761), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(763), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(765), 1,
+      anon_sym_LBRACE,
+    ACTIONS(767), 1,
+      sym__attribute_value,
+    STATE(106), 3,
+      sym_quoted_attribute_value,
+      sym_attribute_expression,
+      sym_unquoted_attribute_value,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(482), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [10865] = 3,
+    ACTIONS(771), 1,
       anon_sym_LT,
-    ACTIONS(480), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(769), 6,
       sym_text,
-      ts_builtin_sym_end,
+      sym_cdata,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [5140] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(394), 1,
+  [10883] = 3,
+    ACTIONS(775), 1,
       anon_sym_LT,
-    ACTIONS(392), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(773), 6,
       sym_text,
-      ts_builtin_sym_end,
+      sym_cdata,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [5155] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(486), 1,
+  [10901] = 3,
+    ACTIONS(779), 1,
       anon_sym_LT,
-    ACTIONS(484), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(777), 6,
       sym_text,
-      ts_builtin_sym_end,
+      sym_cdata,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [5170] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(398), 1,
+  [10919] = 3,
+    ACTIONS(783), 1,
       anon_sym_LT,
-    ACTIONS(396), 6,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+    ACTIONS(781), 6,
       sym_text,
-      ts_builtin_sym_end,
+      sym_cdata,
       anon_sym_LT_BANG,
       anon_sym_LT_SLASH,
       sym_entity,
       anon_sym_LBRACE,
-  [5185] = 6,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(532), 1,
+  [10937] = 6,
+    ACTIONS(785), 1,
       anon_sym_SQUOTE,
-    ACTIONS(534), 1,
+    ACTIONS(787), 1,
       anon_sym_DQUOTE,
-    ACTIONS(536), 1,
+    ACTIONS(789), 1,
       anon_sym_LBRACE,
-    ACTIONS(538), 1,
+    ACTIONS(791), 1,
       sym__attribute_value,
-    STATE(157), 3,
+    STATE(215), 3,
       sym_quoted_attribute_value,
       sym_attribute_expression,
       sym_unquoted_attribute_value,
-  [5206] = 6,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(540), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [10961] = 6,
+    ACTIONS(793), 1,
       anon_sym_SQUOTE,
-    ACTIONS(542), 1,
+    ACTIONS(795), 1,
       anon_sym_DQUOTE,
-    ACTIONS(544), 1,
+    ACTIONS(797), 1,
       anon_sym_LBRACE,
-    ACTIONS(546), 1,
+    ACTIONS(799), 1,
       sym__attribute_value,
-    STATE(91), 3,
+    STATE(199), 3,
       sym_quoted_attribute_value,
       sym_attribute_expression,
       sym_unquoted_attribute_value,
-  [5227] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(490), 1,
-      anon_sym_LT,
-    ACTIONS(488), 6,
-      sym_text,
-      ts_builtin_sym_end,
-      anon_sym_LT_BANG,
-      anon_sym_LT_SLASH,
-      sym_entity,
-      anon_sym_LBRACE,
-  [5242] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(426), 1,
-      anon_sym_LT,
-    ACTIONS(424), 6,
-      sym_text,
-      ts_builtin_sym_end,
-      anon_sym_LT_BANG,
-      anon_sym_LT_SLASH,
-      sym_entity,
-      anon_sym_LBRACE,
-  [5257] = 3,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [10985] = 8,
+    ACTIONS(741), 1,
+      sym__tag_namespace,
+    ACTIONS(743), 1,
+      sym__member_tag_object,
+    ACTIONS(801), 1,
+      sym__start_tag_name,
+    ACTIONS(803), 1,
+      sym__raw_text_start_tag_name,
+    ACTIONS(805), 1,
+      sym__template_start_tag_name,
+    STATE(56), 1,
+      sym__namespaced_tag_name,
+    STATE(57), 1,
+      sym__member_tag_name,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(550), 1,
-      anon_sym_LT,
-    ACTIONS(548), 5,
-      sym_text,
-      anon_sym_LT_BANG,
-      anon_sym_LT_SLASH,
-      sym_entity,
-      anon_sym_LBRACE,
-  [5271] = 5,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11013] = 8,
+    ACTIONS(741), 1,
+      sym__tag_namespace,
+    ACTIONS(743), 1,
+      sym__member_tag_object,
+    ACTIONS(807), 1,
+      sym__start_tag_name,
+    ACTIONS(809), 1,
+      sym__raw_text_start_tag_name,
+    ACTIONS(811), 1,
+      sym__template_start_tag_name,
+    STATE(60), 1,
+      sym__namespaced_tag_name,
+    STATE(61), 1,
+      sym__member_tag_name,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(552), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11041] = 5,
+    ACTIONS(813), 1,
       anon_sym_SQUOTE,
-    ACTIONS(554), 1,
+    ACTIONS(815), 1,
       anon_sym_LBRACE,
-    ACTIONS(556), 1,
+    ACTIONS(817), 1,
       aux_sym__quoted_attribute_content_single_token1,
-    STATE(222), 3,
+    STATE(374), 3,
       sym_attribute_expression,
       sym__quoted_attribute_content_single,
       aux_sym_quoted_attribute_value_repeat1,
-  [5289] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(552), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11062] = 5,
+    ACTIONS(813), 1,
       anon_sym_DQUOTE,
-    ACTIONS(558), 1,
+    ACTIONS(819), 1,
       anon_sym_LBRACE,
-    ACTIONS(560), 1,
+    ACTIONS(821), 1,
       aux_sym__quoted_attribute_content_double_token1,
-    STATE(223), 3,
+    STATE(375), 3,
       sym_attribute_expression,
       sym__quoted_attribute_content_double,
       aux_sym_quoted_attribute_value_repeat2,
-  [5307] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11083] = 5,
+    ACTIONS(815), 1,
+      anon_sym_LBRACE,
+    ACTIONS(823), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(825), 1,
+      aux_sym__quoted_attribute_content_single_token1,
+    STATE(376), 3,
+      sym_attribute_expression,
+      sym__quoted_attribute_content_single,
+      aux_sym_quoted_attribute_value_repeat1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(558), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11104] = 5,
+    ACTIONS(819), 1,
       anon_sym_LBRACE,
-    ACTIONS(562), 1,
+    ACTIONS(823), 1,
       anon_sym_DQUOTE,
-    ACTIONS(564), 1,
+    ACTIONS(827), 1,
       aux_sym__quoted_attribute_content_double_token1,
-    STATE(216), 3,
+    STATE(377), 3,
       sym_attribute_expression,
       sym__quoted_attribute_content_double,
       aux_sym_quoted_attribute_value_repeat2,
-  [5325] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(568), 1,
-      anon_sym_LT,
-    ACTIONS(566), 5,
-      sym_text,
-      anon_sym_LT_BANG,
-      anon_sym_LT_SLASH,
-      sym_entity,
-      anon_sym_LBRACE,
-  [5339] = 7,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(570), 1,
-      sym__start_tag_name,
-    ACTIONS(572), 1,
-      sym__raw_text_start_tag_name,
-    ACTIONS(574), 1,
-      sym__tag_namespace,
-    ACTIONS(576), 1,
-      sym__member_tag_object,
-    STATE(29), 1,
-      sym__member_tag_name,
-    STATE(31), 1,
-      sym__namespaced_tag_name,
-  [5361] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(580), 1,
-      anon_sym_LT,
-    ACTIONS(578), 5,
-      sym_text,
-      anon_sym_LT_BANG,
-      anon_sym_LT_SLASH,
-      sym_entity,
-      anon_sym_LBRACE,
-  [5375] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(584), 1,
-      anon_sym_LT,
-    ACTIONS(582), 5,
-      sym_text,
-      anon_sym_LT_BANG,
-      anon_sym_LT_SLASH,
-      sym_entity,
-      anon_sym_LBRACE,
-  [5389] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(586), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11125] = 5,
+    ACTIONS(829), 1,
       anon_sym_SQUOTE,
-    ACTIONS(588), 1,
+    ACTIONS(831), 1,
       anon_sym_LBRACE,
-    ACTIONS(591), 1,
+    ACTIONS(834), 1,
       aux_sym__quoted_attribute_content_single_token1,
-    STATE(222), 3,
+    STATE(376), 3,
       sym_attribute_expression,
       sym__quoted_attribute_content_single,
       aux_sym_quoted_attribute_value_repeat1,
-  [5407] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(594), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11146] = 5,
+    ACTIONS(837), 1,
       anon_sym_DQUOTE,
-    ACTIONS(596), 1,
+    ACTIONS(839), 1,
       anon_sym_LBRACE,
-    ACTIONS(599), 1,
+    ACTIONS(842), 1,
       aux_sym__quoted_attribute_content_double_token1,
-    STATE(223), 3,
+    STATE(377), 3,
       sym_attribute_expression,
       sym__quoted_attribute_content_double,
       aux_sym_quoted_attribute_value_repeat2,
-  [5425] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(604), 1,
-      anon_sym_LT,
-    ACTIONS(602), 5,
-      sym_text,
-      anon_sym_LT_BANG,
-      anon_sym_LT_SLASH,
-      sym_entity,
-      anon_sym_LBRACE,
-  [5439] = 5,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(554), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11167] = 5,
+    ACTIONS(815), 1,
       anon_sym_LBRACE,
-    ACTIONS(606), 1,
+    ACTIONS(845), 1,
       anon_sym_SQUOTE,
-    ACTIONS(608), 1,
+    ACTIONS(847), 1,
       aux_sym__quoted_attribute_content_single_token1,
-    STATE(227), 3,
+    STATE(380), 3,
       sym_attribute_expression,
       sym__quoted_attribute_content_single,
       aux_sym_quoted_attribute_value_repeat1,
-  [5457] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(558), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11188] = 5,
+    ACTIONS(819), 1,
       anon_sym_LBRACE,
-    ACTIONS(606), 1,
+    ACTIONS(845), 1,
       anon_sym_DQUOTE,
-    ACTIONS(610), 1,
+    ACTIONS(849), 1,
       aux_sym__quoted_attribute_content_double_token1,
-    STATE(228), 3,
+    STATE(381), 3,
       sym_attribute_expression,
       sym__quoted_attribute_content_double,
       aux_sym_quoted_attribute_value_repeat2,
-  [5475] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(554), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11209] = 5,
+    ACTIONS(815), 1,
       anon_sym_LBRACE,
-    ACTIONS(556), 1,
+    ACTIONS(825), 1,
       aux_sym__quoted_attribute_content_single_token1,
-    ACTIONS(612), 1,
+    ACTIONS(851), 1,
       anon_sym_SQUOTE,
-    STATE(222), 3,
+    STATE(376), 3,
       sym_attribute_expression,
       sym__quoted_attribute_content_single,
       aux_sym_quoted_attribute_value_repeat1,
-  [5493] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(558), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11230] = 5,
+    ACTIONS(819), 1,
       anon_sym_LBRACE,
-    ACTIONS(560), 1,
+    ACTIONS(827), 1,
       aux_sym__quoted_attribute_content_double_token1,
-    ACTIONS(612), 1,
+    ACTIONS(851), 1,
       anon_sym_DQUOTE,
-    STATE(223), 3,
+    STATE(377), 3,
       sym_attribute_expression,
       sym__quoted_attribute_content_double,
       aux_sym_quoted_attribute_value_repeat2,
-  [5511] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(554), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11251] = 5,
+    ACTIONS(815), 1,
       anon_sym_LBRACE,
-    ACTIONS(614), 1,
+    ACTIONS(853), 1,
       anon_sym_SQUOTE,
-    ACTIONS(616), 1,
+    ACTIONS(855), 1,
       aux_sym__quoted_attribute_content_single_token1,
-    STATE(231), 3,
+    STATE(384), 3,
       sym_attribute_expression,
       sym__quoted_attribute_content_single,
       aux_sym_quoted_attribute_value_repeat1,
-  [5529] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(558), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11272] = 5,
+    ACTIONS(819), 1,
       anon_sym_LBRACE,
-    ACTIONS(614), 1,
+    ACTIONS(853), 1,
       anon_sym_DQUOTE,
-    ACTIONS(618), 1,
+    ACTIONS(857), 1,
       aux_sym__quoted_attribute_content_double_token1,
-    STATE(232), 3,
+    STATE(385), 3,
       sym_attribute_expression,
       sym__quoted_attribute_content_double,
       aux_sym_quoted_attribute_value_repeat2,
-  [5547] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(554), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11293] = 5,
+    ACTIONS(815), 1,
       anon_sym_LBRACE,
-    ACTIONS(556), 1,
+    ACTIONS(825), 1,
       aux_sym__quoted_attribute_content_single_token1,
-    ACTIONS(620), 1,
+    ACTIONS(859), 1,
       anon_sym_SQUOTE,
-    STATE(222), 3,
+    STATE(376), 3,
       sym_attribute_expression,
       sym__quoted_attribute_content_single,
       aux_sym_quoted_attribute_value_repeat1,
-  [5565] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(558), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11314] = 5,
+    ACTIONS(819), 1,
       anon_sym_LBRACE,
-    ACTIONS(560), 1,
+    ACTIONS(827), 1,
       aux_sym__quoted_attribute_content_double_token1,
-    ACTIONS(620), 1,
+    ACTIONS(859), 1,
       anon_sym_DQUOTE,
-    STATE(223), 3,
+    STATE(377), 3,
       sym_attribute_expression,
       sym__quoted_attribute_content_double,
       aux_sym_quoted_attribute_value_repeat2,
-  [5583] = 7,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(574), 1,
-      sym__tag_namespace,
-    ACTIONS(576), 1,
-      sym__member_tag_object,
-    ACTIONS(622), 1,
-      sym__start_tag_name,
-    ACTIONS(624), 1,
-      sym__raw_text_start_tag_name,
-    STATE(30), 1,
-      sym__namespaced_tag_name,
-    STATE(32), 1,
-      sym__member_tag_name,
-  [5605] = 5,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(554), 1,
-      anon_sym_LBRACE,
-    ACTIONS(562), 1,
-      anon_sym_SQUOTE,
-    ACTIONS(626), 1,
-      aux_sym__quoted_attribute_content_single_token1,
-    STATE(215), 3,
-      sym_attribute_expression,
-      sym__quoted_attribute_content_single,
-      aux_sym_quoted_attribute_value_repeat1,
-  [5623] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(630), 1,
-      anon_sym_LT,
-    ACTIONS(628), 5,
-      sym_text,
-      anon_sym_LT_BANG,
-      anon_sym_LT_SLASH,
-      sym_entity,
-      anon_sym_LBRACE,
-  [5637] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(634), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11335] = 5,
+    ACTIONS(863), 1,
       sym__directive_marker,
-    STATE(300), 1,
-      sym___attribute_directive,
-    STATE(312), 1,
+    STATE(490), 1,
       sym_attribute_name,
-    ACTIONS(632), 2,
+    STATE(500), 1,
+      sym___attribute_directive,
+    ACTIONS(861), 2,
       sym__pipe_attribute_name,
       aux_sym_attribute_name_token1,
-  [5654] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(634), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11355] = 5,
+    ACTIONS(863), 1,
       sym__directive_marker,
-    STATE(300), 1,
+    STATE(500), 1,
       sym___attribute_directive,
-    STATE(336), 1,
+    STATE(546), 1,
       sym_attribute_name,
-    ACTIONS(632), 2,
+    ACTIONS(861), 2,
       sym__pipe_attribute_name,
       aux_sym_attribute_name_token1,
-  [5671] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(634), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11375] = 5,
+    ACTIONS(863), 1,
       sym__directive_marker,
-    STATE(300), 1,
+    STATE(500), 1,
       sym___attribute_directive,
-    STATE(338), 1,
+    STATE(551), 1,
       sym_attribute_name,
-    ACTIONS(632), 2,
+    ACTIONS(861), 2,
       sym__pipe_attribute_name,
       aux_sym_attribute_name_token1,
-  [5688] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11395] = 5,
+    ACTIONS(865), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(867), 1,
+      sym__implicit_end_tag,
+    ACTIONS(869), 1,
+      sym__unterminated_tag_end,
+    STATE(330), 1,
+      sym_end_tag,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(272), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11414] = 5,
+    ACTIONS(871), 1,
+      anon_sym_GT,
+    ACTIONS(873), 1,
+      sym__doctype_public,
+    ACTIONS(875), 1,
+      sym__doctype_system,
+    ACTIONS(877), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11433] = 5,
+    ACTIONS(394), 1,
       anon_sym_EQ,
-    ACTIONS(636), 1,
+    ACTIONS(879), 1,
       anon_sym_PIPE,
-    STATE(269), 1,
+    STATE(408), 1,
       aux_sym_attribute_modifiers_repeat1,
-    STATE(293), 1,
+    STATE(501), 1,
       sym_attribute_modifiers,
-  [5704] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(524), 1,
-      anon_sym_SQUOTE,
-    ACTIONS(526), 1,
-      anon_sym_DQUOTE,
-    STATE(70), 1,
-      sym_quoted_attribute_value,
-  [5717] = 4,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11452] = 5,
+    ACTIONS(881), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(883), 1,
+      sym__implicit_end_tag,
+    ACTIONS(885), 1,
+      sym__unterminated_tag_end,
+    STATE(165), 1,
+      sym_end_tag,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(280), 1,
-      anon_sym_EQ,
-    ACTIONS(638), 1,
-      anon_sym_PIPE,
-    STATE(241), 1,
-      aux_sym_attribute_modifiers_repeat1,
-  [5730] = 3,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11471] = 5,
+    ACTIONS(887), 1,
+      anon_sym_GT,
+    ACTIONS(889), 1,
+      sym__doctype_public,
+    ACTIONS(891), 1,
+      sym__doctype_system,
+    ACTIONS(893), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11490] = 5,
+    ACTIONS(895), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(897), 1,
+      sym__implicit_end_tag,
+    ACTIONS(899), 1,
+      sym__unterminated_tag_end,
+    STATE(262), 1,
+      sym_end_tag,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(641), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11509] = 5,
+    ACTIONS(901), 1,
+      anon_sym_GT,
+    ACTIONS(903), 1,
+      sym__doctype_public,
+    ACTIONS(905), 1,
+      sym__doctype_system,
+    ACTIONS(907), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11528] = 3,
+    ACTIONS(909), 1,
       anon_sym_RBRACE,
-    ACTIONS(643), 2,
+    ACTIONS(911), 2,
       sym__expression_js,
       sym__expression_ts,
-  [5741] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11542] = 4,
+    ACTIONS(865), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(913), 1,
+      sym_raw_text,
+    STATE(304), 1,
+      sym_end_tag,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(645), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11558] = 4,
+    ACTIONS(915), 1,
       sym_erroneous_end_tag_name,
-    ACTIONS(647), 1,
+    ACTIONS(917), 1,
       sym__tag_namespace,
-    STATE(295), 1,
+    STATE(487), 1,
       sym__namespaced_tag_name,
-  [5754] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(327), 1,
-      aux_sym__quoted_attribute_content_single_token1,
-    ACTIONS(649), 2,
-      anon_sym_SQUOTE,
-      anon_sym_LBRACE,
-  [5765] = 4,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11574] = 4,
+    ACTIONS(915), 1,
+      sym_erroneous_end_tag_name,
+    ACTIONS(919), 1,
+      sym__member_tag_object,
+    STATE(488), 1,
+      sym__member_tag_name,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(532), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11590] = 3,
+    ACTIONS(921), 1,
+      anon_sym_RBRACE,
+    ACTIONS(923), 2,
+      sym__expression_js,
+      sym__expression_ts,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11604] = 4,
+    ACTIONS(925), 1,
+      anon_sym_GT,
+    ACTIONS(927), 1,
+      sym_doctype_identifier,
+    ACTIONS(929), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11620] = 4,
+    ACTIONS(925), 1,
+      anon_sym_GT,
+    ACTIONS(929), 1,
+      sym__bogus_doctype,
+    ACTIONS(931), 1,
+      sym_doctype_identifier,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11636] = 4,
+    ACTIONS(933), 1,
+      anon_sym_GT,
+    ACTIONS(935), 1,
+      sym_doctype_identifier,
+    ACTIONS(937), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11652] = 4,
+    ACTIONS(761), 1,
       anon_sym_SQUOTE,
-    ACTIONS(534), 1,
+    ACTIONS(763), 1,
       anon_sym_DQUOTE,
-    STATE(158), 1,
+    STATE(107), 1,
       sym_quoted_attribute_value,
-  [5778] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11668] = 3,
+    ACTIONS(939), 1,
+      anon_sym_RBRACE,
+    ACTIONS(941), 2,
+      sym__attribute_expression_js,
+      sym__attribute_expression_ts,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11682] = 4,
+    ACTIONS(462), 1,
+      anon_sym_GT,
+    ACTIONS(943), 1,
+      anon_sym_DOT,
+    STATE(407), 1,
+      aux_sym__member_tag_name_repeat1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(334), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11698] = 4,
+    ACTIONS(466), 1,
+      anon_sym_GT,
+    ACTIONS(945), 1,
+      anon_sym_DOT,
+    STATE(407), 1,
+      aux_sym__member_tag_name_repeat1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11714] = 4,
+    ACTIONS(421), 1,
+      anon_sym_EQ,
+    ACTIONS(879), 1,
+      anon_sym_PIPE,
+    STATE(409), 1,
+      aux_sym_attribute_modifiers_repeat1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11730] = 4,
+    ACTIONS(423), 1,
+      anon_sym_EQ,
+    ACTIONS(948), 1,
+      anon_sym_PIPE,
+    STATE(409), 1,
+      aux_sym_attribute_modifiers_repeat1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11746] = 3,
+    ACTIONS(487), 1,
       aux_sym__quoted_attribute_content_single_token1,
-    ACTIONS(651), 2,
+    ACTIONS(951), 2,
       anon_sym_SQUOTE,
       anon_sym_LBRACE,
-  [5789] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(327), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11760] = 3,
+    ACTIONS(489), 1,
+      aux_sym__quoted_attribute_content_single_token1,
+    ACTIONS(953), 2,
+      anon_sym_SQUOTE,
+      anon_sym_LBRACE,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11774] = 3,
+    ACTIONS(487), 1,
       aux_sym__quoted_attribute_content_double_token1,
-    ACTIONS(649), 2,
+    ACTIONS(951), 2,
       anon_sym_DQUOTE,
       anon_sym_LBRACE,
-  [5800] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(653), 1,
-      anon_sym_RBRACE,
-    ACTIONS(655), 2,
-      sym__attribute_expression_js,
-      sym__attribute_expression_ts,
-  [5811] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(334), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11788] = 3,
+    ACTIONS(489), 1,
       aux_sym__quoted_attribute_content_double_token1,
-    ACTIONS(651), 2,
+    ACTIONS(953), 2,
       anon_sym_DQUOTE,
       anon_sym_LBRACE,
-  [5822] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(657), 1,
-      anon_sym_RBRACE,
-    ACTIONS(659), 2,
-      sym__attribute_expression_js,
-      sym__attribute_expression_ts,
-  [5833] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(661), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11802] = 3,
+    ACTIONS(955), 1,
       anon_sym_RBRACE,
-    ACTIONS(663), 2,
+    ACTIONS(957), 2,
       sym__expression_js,
       sym__expression_ts,
-  [5844] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11816] = 4,
+    ACTIONS(881), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(959), 1,
+      sym_raw_text,
+    STATE(139), 1,
+      sym_end_tag,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(665), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11832] = 3,
+    ACTIONS(961), 1,
       anon_sym_RBRACE,
-    ACTIONS(667), 2,
+    ACTIONS(963), 2,
       sym__expression_js,
       sym__expression_ts,
-  [5855] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(540), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11846] = 4,
+    ACTIONS(965), 1,
+      anon_sym_GT,
+    ACTIONS(967), 1,
+      sym_doctype_identifier,
+    ACTIONS(969), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11862] = 4,
+    ACTIONS(965), 1,
+      anon_sym_GT,
+    ACTIONS(969), 1,
+      sym__bogus_doctype,
+    ACTIONS(971), 1,
+      sym_doctype_identifier,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11878] = 4,
+    ACTIONS(973), 1,
+      anon_sym_GT,
+    ACTIONS(975), 1,
+      sym_doctype_identifier,
+    ACTIONS(977), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11894] = 4,
+    ACTIONS(785), 1,
       anon_sym_SQUOTE,
-    ACTIONS(542), 1,
+    ACTIONS(787), 1,
       anon_sym_DQUOTE,
-    STATE(103), 1,
+    STATE(216), 1,
       sym_quoted_attribute_value,
-  [5868] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(329), 1,
-      anon_sym_GT,
-    ACTIONS(669), 1,
-      anon_sym_DOT,
-    STATE(254), 1,
-      aux_sym__member_tag_name_repeat1,
-  [5881] = 3,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11910] = 3,
+    ACTIONS(979), 1,
+      anon_sym_RBRACE,
+    ACTIONS(981), 2,
+      sym__attribute_expression_js,
+      sym__attribute_expression_ts,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(672), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11924] = 3,
+    ACTIONS(983), 1,
       anon_sym_RBRACE,
-    ACTIONS(674), 2,
+    ACTIONS(985), 2,
       sym__expression_js,
       sym__expression_ts,
-  [5892] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(676), 1,
-      anon_sym_RBRACE,
-    ACTIONS(678), 2,
-      sym__attribute_expression_js,
-      sym__attribute_expression_ts,
-  [5903] = 4,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(680), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11938] = 4,
+    ACTIONS(895), 1,
       anon_sym_LT_SLASH,
-    ACTIONS(682), 1,
+    ACTIONS(987), 1,
       sym_raw_text,
-    STATE(120), 1,
+    STATE(236), 1,
       sym_end_tag,
-  [5916] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(344), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11954] = 3,
+    ACTIONS(989), 1,
+      anon_sym_RBRACE,
+    ACTIONS(991), 2,
+      sym__expression_js,
+      sym__expression_ts,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11968] = 4,
+    ACTIONS(993), 1,
       anon_sym_GT,
-    ACTIONS(684), 1,
-      anon_sym_DOT,
-    STATE(254), 1,
-      aux_sym__member_tag_name_repeat1,
-  [5929] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(995), 1,
+      sym_doctype_identifier,
+    ACTIONS(997), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [11984] = 4,
+    ACTIONS(993), 1,
+      anon_sym_GT,
+    ACTIONS(997), 1,
+      sym__bogus_doctype,
+    ACTIONS(999), 1,
+      sym_doctype_identifier,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12000] = 4,
+    ACTIONS(1001), 1,
+      anon_sym_GT,
+    ACTIONS(1003), 1,
+      sym_doctype_identifier,
+    ACTIONS(1005), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12016] = 4,
+    ACTIONS(793), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(795), 1,
+      anon_sym_DQUOTE,
+    STATE(200), 1,
+      sym_quoted_attribute_value,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12032] = 3,
+    ACTIONS(1007), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1009), 2,
+      sym__attribute_expression_js,
+      sym__attribute_expression_ts,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(686), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12046] = 3,
+    ACTIONS(1011), 1,
       anon_sym_RBRACE,
-    ACTIONS(688), 2,
+    ACTIONS(1013), 2,
       sym__attribute_expression_js,
       sym__attribute_expression_ts,
-  [5940] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(690), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12060] = 3,
+    ACTIONS(1015), 1,
       anon_sym_RBRACE,
-    ACTIONS(692), 2,
+    ACTIONS(1017), 2,
       sym__attribute_expression_js,
       sym__attribute_expression_ts,
-  [5951] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(694), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12074] = 3,
+    ACTIONS(1019), 1,
       anon_sym_RBRACE,
-    ACTIONS(696), 2,
+    ACTIONS(1021), 2,
       sym__attribute_expression_js,
       sym__attribute_expression_ts,
-  [5962] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(698), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12088] = 3,
+    ACTIONS(1023), 1,
       anon_sym_RBRACE,
-    ACTIONS(700), 2,
+    ACTIONS(1025), 2,
       sym__attribute_expression_js,
       sym__attribute_expression_ts,
-  [5973] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(702), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12102] = 3,
+    ACTIONS(1027), 1,
       anon_sym_RBRACE,
-    ACTIONS(704), 2,
+    ACTIONS(1029), 2,
       sym__attribute_expression_js,
       sym__attribute_expression_ts,
-  [5984] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12116] = 4,
+    ACTIONS(915), 1,
+      sym_erroneous_end_tag_name,
+    ACTIONS(917), 1,
+      sym__tag_namespace,
+    STATE(508), 1,
+      sym__namespaced_tag_name,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(645), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12132] = 4,
+    ACTIONS(915), 1,
       sym_erroneous_end_tag_name,
-    ACTIONS(706), 1,
+    ACTIONS(919), 1,
       sym__member_tag_object,
-    STATE(308), 1,
+    STATE(509), 1,
       sym__member_tag_name,
-  [5997] = 4,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(708), 1,
-      anon_sym_LT_SLASH,
-    ACTIONS(710), 1,
-      sym_raw_text,
-    STATE(179), 1,
-      sym_end_tag,
-  [6010] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(645), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12148] = 4,
+    ACTIONS(915), 1,
       sym_erroneous_end_tag_name,
-    ACTIONS(647), 1,
+    ACTIONS(917), 1,
       sym__tag_namespace,
-    STATE(289), 1,
+    STATE(523), 1,
       sym__namespaced_tag_name,
-  [6023] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(645), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12164] = 4,
+    ACTIONS(915), 1,
       sym_erroneous_end_tag_name,
-    ACTIONS(706), 1,
+    ACTIONS(919), 1,
       sym__member_tag_object,
-    STATE(290), 1,
+    STATE(524), 1,
       sym__member_tag_name,
-  [6036] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(712), 1,
-      anon_sym_RBRACE,
-    ACTIONS(714), 2,
-      sym__expression_js,
-      sym__expression_ts,
-  [6047] = 4,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(285), 1,
-      anon_sym_EQ,
-    ACTIONS(636), 1,
-      anon_sym_PIPE,
-    STATE(241), 1,
-      aux_sym_attribute_modifiers_repeat1,
-  [6060] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(716), 1,
-      sym__end_tag_name,
-    ACTIONS(718), 1,
-      sym_erroneous_end_tag_name,
-  [6070] = 3,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12180] = 3,
+    ACTIONS(1031), 1,
+      anon_sym_GT,
+    ACTIONS(1033), 1,
+      sym_doctype_name,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12193] = 3,
+    ACTIONS(464), 1,
+      anon_sym_DOT,
+    STATE(88), 1,
+      aux_sym__member_tag_name_repeat1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(680), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12206] = 3,
+    ACTIONS(865), 1,
       anon_sym_LT_SLASH,
-    STATE(134), 1,
+    STATE(324), 1,
       sym_end_tag,
-  [6080] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(645), 1,
-      sym_erroneous_end_tag_name,
-    ACTIONS(720), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12219] = 3,
+    ACTIONS(1035), 1,
       sym__end_tag_name,
-  [6090] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(1037), 1,
+      sym_erroneous_end_tag_name,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(680), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12232] = 3,
+    ACTIONS(865), 1,
       anon_sym_LT_SLASH,
-    STATE(137), 1,
+    STATE(327), 1,
       sym_end_tag,
-  [6100] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(708), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12245] = 3,
+    ACTIONS(865), 1,
       anon_sym_LT_SLASH,
-    STATE(171), 1,
+    STATE(332), 1,
       sym_end_tag,
-  [6110] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(722), 2,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12258] = 2,
+    ACTIONS(1039), 2,
       sym_raw_text,
       anon_sym_LT_SLASH,
-  [6118] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(724), 2,
-      sym_raw_text,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12269] = 3,
+    ACTIONS(865), 1,
       anon_sym_LT_SLASH,
-  [6126] = 3,
-    ACTIONS(3), 1,
+    STATE(347), 1,
+      sym_end_tag,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(680), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12282] = 2,
+    ACTIONS(1041), 2,
+      sym_raw_text,
       anon_sym_LT_SLASH,
-    STATE(153), 1,
-      sym_end_tag,
-  [6136] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12293] = 3,
+    ACTIONS(1043), 1,
+      anon_sym_GT,
+    ACTIONS(1045), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12306] = 3,
+    ACTIONS(1047), 1,
+      anon_sym_GT,
+    ACTIONS(1049), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12319] = 2,
+    ACTIONS(499), 2,
+      anon_sym_GT,
+      anon_sym_DOT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(280), 2,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12330] = 2,
+    ACTIONS(423), 2,
       anon_sym_EQ,
       anon_sym_PIPE,
-  [6144] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12341] = 3,
+    ACTIONS(1051), 1,
+      anon_sym_GT,
+    ACTIONS(1053), 1,
+      sym_doctype_name,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12354] = 3,
+    ACTIONS(943), 1,
+      anon_sym_DOT,
+    STATE(406), 1,
+      aux_sym__member_tag_name_repeat1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12367] = 3,
+    ACTIONS(881), 1,
+      anon_sym_LT_SLASH,
+    STATE(159), 1,
+      sym_end_tag,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(718), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12380] = 3,
+    ACTIONS(915), 1,
       sym_erroneous_end_tag_name,
-    ACTIONS(720), 1,
+    ACTIONS(1035), 1,
       sym__end_tag_name,
-  [6154] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12393] = 3,
+    ACTIONS(881), 1,
+      anon_sym_LT_SLASH,
+    STATE(162), 1,
+      sym_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12406] = 3,
+    ACTIONS(881), 1,
+      anon_sym_LT_SLASH,
+    STATE(167), 1,
+      sym_end_tag,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(708), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12419] = 3,
+    ACTIONS(881), 1,
       anon_sym_LT_SLASH,
-    STATE(197), 1,
+    STATE(182), 1,
+      sym_end_tag,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12432] = 3,
+    ACTIONS(1055), 1,
+      anon_sym_GT,
+    ACTIONS(1057), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12445] = 3,
+    ACTIONS(1059), 1,
+      anon_sym_GT,
+    ACTIONS(1061), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12458] = 3,
+    ACTIONS(1063), 1,
+      anon_sym_GT,
+    ACTIONS(1065), 1,
+      sym_doctype_name,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12471] = 3,
+    ACTIONS(895), 1,
+      anon_sym_LT_SLASH,
+    STATE(256), 1,
       sym_end_tag,
-  [6164] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(645), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12484] = 3,
+    ACTIONS(915), 1,
       sym_erroneous_end_tag_name,
-    ACTIONS(716), 1,
+    ACTIONS(1067), 1,
       sym__end_tag_name,
-  [6174] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(361), 2,
-      anon_sym_GT,
-      anon_sym_DOT,
-  [6182] = 3,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12497] = 3,
+    ACTIONS(895), 1,
+      anon_sym_LT_SLASH,
+    STATE(259), 1,
+      sym_end_tag,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(346), 1,
-      anon_sym_DOT,
-    STATE(67), 1,
-      aux_sym__member_tag_name_repeat1,
-  [6192] = 3,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12510] = 3,
+    ACTIONS(895), 1,
+      anon_sym_LT_SLASH,
+    STATE(264), 1,
+      sym_end_tag,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(708), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12523] = 3,
+    ACTIONS(895), 1,
       anon_sym_LT_SLASH,
-    STATE(194), 1,
+    STATE(279), 1,
       sym_end_tag,
-  [6202] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(684), 1,
-      anon_sym_DOT,
-    STATE(258), 1,
-      aux_sym__member_tag_name_repeat1,
-  [6212] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12536] = 3,
+    ACTIONS(1069), 1,
+      anon_sym_GT,
+    ACTIONS(1071), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12549] = 3,
+    ACTIONS(1073), 1,
+      anon_sym_GT,
+    ACTIONS(1075), 1,
+      sym__bogus_doctype,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12562] = 3,
+    ACTIONS(915), 1,
+      sym_erroneous_end_tag_name,
+    ACTIONS(1077), 1,
+      sym__end_tag_name,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12575] = 3,
+    ACTIONS(1035), 1,
+      sym__end_tag_name,
+    ACTIONS(1079), 1,
+      sym_erroneous_end_tag_name,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12588] = 3,
+    ACTIONS(1037), 1,
+      sym_erroneous_end_tag_name,
+    ACTIONS(1067), 1,
+      sym__end_tag_name,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12601] = 3,
+    ACTIONS(1067), 1,
+      sym__end_tag_name,
+    ACTIONS(1079), 1,
+      sym_erroneous_end_tag_name,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(726), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12614] = 3,
+    ACTIONS(1037), 1,
+      sym_erroneous_end_tag_name,
+    ACTIONS(1077), 1,
+      sym__end_tag_name,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12627] = 3,
+    ACTIONS(1077), 1,
+      sym__end_tag_name,
+    ACTIONS(1079), 1,
+      sym_erroneous_end_tag_name,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12640] = 2,
+    ACTIONS(1081), 1,
       sym__doctype,
-  [6219] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(728), 1,
-      anon_sym_GT,
-  [6226] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12650] = 2,
+    ACTIONS(915), 1,
+      sym_erroneous_end_tag_name,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(730), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12660] = 2,
+    ACTIONS(1083), 1,
       ts_builtin_sym_end,
-  [6233] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12670] = 2,
+    ACTIONS(1085), 1,
+      anon_sym_COLON,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12680] = 2,
+    ACTIONS(1087), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12690] = 2,
+    ACTIONS(1089), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12700] = 2,
+    ACTIONS(1035), 1,
+      sym__end_tag_name,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(732), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12710] = 2,
+    ACTIONS(1091), 1,
+      anon_sym_COLON,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12720] = 2,
+    ACTIONS(1093), 1,
+      sym__unterminated_tag_end,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12730] = 2,
+    ACTIONS(1095), 1,
+      sym__tag_local_name,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12740] = 2,
+    ACTIONS(1097), 1,
+      sym__member_tag_property,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12750] = 2,
+    ACTIONS(1099), 1,
       anon_sym_GT,
-  [6240] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(734), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12760] = 2,
+    ACTIONS(1101), 1,
       anon_sym_GT,
-  [6247] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(736), 1,
-      sym_attribute_identifier,
-  [6254] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12770] = 2,
+    ACTIONS(1103), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(738), 1,
-      anon_sym_RBRACE,
-  [6261] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12780] = 2,
+    ACTIONS(1105), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(317), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12790] = 2,
+    ACTIONS(1107), 1,
       anon_sym_EQ,
-  [6268] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(740), 1,
-      sym__tag_local_name,
-  [6275] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12800] = 2,
+    ACTIONS(1109), 1,
+      sym_attribute_identifier,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12810] = 2,
+    ACTIONS(1111), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(742), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12820] = 2,
+    ACTIONS(1113), 1,
       anon_sym_GT,
-  [6282] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(744), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12830] = 2,
+    ACTIONS(1115), 1,
       anon_sym_GT,
-  [6289] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(746), 1,
-      sym__member_tag_property,
-  [6296] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12840] = 2,
+    ACTIONS(1117), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(748), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12850] = 2,
+    ACTIONS(1119), 1,
       sym_attribute_modifier,
-  [6303] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(750), 1,
-      sym__unterminated_tag_end,
-  [6310] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12860] = 2,
+    ACTIONS(1121), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12870] = 2,
+    ACTIONS(1123), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12880] = 2,
+    ACTIONS(531), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(310), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12890] = 2,
+    ACTIONS(435), 1,
       anon_sym_EQ,
-  [6317] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(752), 1,
-      anon_sym_RBRACE,
-  [6324] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12900] = 2,
+    ACTIONS(448), 1,
+      anon_sym_EQ,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(754), 1,
-      sym_attribute_modifier,
-  [6331] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12910] = 2,
+    ACTIONS(1125), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12920] = 2,
+    ACTIONS(1127), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(756), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12930] = 2,
+    ACTIONS(1129), 1,
       sym__unterminated_tag_end,
-  [6338] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12940] = 2,
+    ACTIONS(1131), 1,
+      sym__tag_local_name,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12950] = 2,
+    ACTIONS(1133), 1,
+      sym__member_tag_property,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12960] = 2,
+    ACTIONS(1135), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12970] = 2,
+    ACTIONS(1137), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12980] = 2,
+    ACTIONS(1139), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [12990] = 2,
+    ACTIONS(1141), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(758), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13000] = 2,
+    ACTIONS(1143), 1,
       sym_attribute_identifier,
-  [6345] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(760), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13010] = 2,
+    ACTIONS(1145), 1,
       anon_sym_RBRACE,
-  [6352] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(762), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13020] = 2,
+    ACTIONS(1147), 1,
       anon_sym_GT,
-  [6359] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(764), 1,
-      sym__doctype,
-  [6366] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13030] = 2,
+    ACTIONS(1149), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(766), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13040] = 2,
+    ACTIONS(1151), 1,
       anon_sym_GT,
-  [6373] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13050] = 2,
+    ACTIONS(1153), 1,
+      sym_attribute_modifier,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(768), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13060] = 2,
+    ACTIONS(1155), 1,
       anon_sym_RBRACE,
-  [6380] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(770), 1,
-      anon_sym_COLON,
-  [6387] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13070] = 2,
+    ACTIONS(1157), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(772), 1,
-      sym_attribute_modifier,
-  [6394] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13080] = 2,
+    ACTIONS(1159), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(774), 1,
-      anon_sym_EQ,
-  [6401] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13090] = 2,
+    ACTIONS(1161), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(382), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13100] = 2,
+    ACTIONS(1163), 1,
+      sym__unterminated_tag_end,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13110] = 2,
+    ACTIONS(1165), 1,
       anon_sym_GT,
-  [6408] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(776), 1,
-      aux_sym_doctype_token1,
-  [6415] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13120] = 2,
+    ACTIONS(1167), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(778), 1,
-      sym_attribute_identifier,
-  [6422] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13130] = 2,
+    ACTIONS(1169), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(780), 1,
-      sym_attribute_identifier,
-  [6429] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13140] = 2,
+    ACTIONS(1171), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(782), 1,
-      sym_attribute_modifier,
-  [6436] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13150] = 2,
+    ACTIONS(1173), 1,
+      sym_attribute_identifier,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(784), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13160] = 2,
+    ACTIONS(1175), 1,
       anon_sym_RBRACE,
-  [6443] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(786), 1,
-      anon_sym_COLON,
-  [6450] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13170] = 2,
+    ACTIONS(1177), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13180] = 2,
+    ACTIONS(1179), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13190] = 2,
+    ACTIONS(1181), 1,
+      anon_sym_GT,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13200] = 2,
+    ACTIONS(1183), 1,
+      sym_attribute_modifier,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(788), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13210] = 2,
+    ACTIONS(1185), 1,
       anon_sym_RBRACE,
-  [6457] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(790), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13220] = 2,
+    ACTIONS(1187), 1,
       anon_sym_GT,
-  [6464] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13230] = 2,
+    ACTIONS(1189), 1,
+      sym_attribute_identifier,
+    ACTIONS(3), 4,
+      sym_comment,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13240] = 2,
+    ACTIONS(1191), 1,
+      sym_attribute_modifier,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(792), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13250] = 2,
+    ACTIONS(1193), 1,
       anon_sym_RBRACE,
-  [6471] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(794), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13260] = 2,
+    ACTIONS(1195), 1,
       anon_sym_RBRACE,
-  [6478] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(796), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13270] = 2,
+    ACTIONS(1197), 1,
       anon_sym_RBRACE,
-  [6485] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(798), 1,
-      sym__tag_local_name,
-  [6492] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13280] = 2,
+    ACTIONS(1199), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(800), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13290] = 2,
+    ACTIONS(1201), 1,
       anon_sym_RBRACE,
-  [6499] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(720), 1,
-      sym__end_tag_name,
-  [6506] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13300] = 2,
+    ACTIONS(1203), 1,
+      sym__doctype,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(718), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13310] = 2,
+    ACTIONS(1079), 1,
       sym_erroneous_end_tag_name,
-  [6513] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(802), 1,
-      aux_sym_doctype_token1,
-  [6520] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(804), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13320] = 2,
+    ACTIONS(1205), 1,
       anon_sym_COLON,
-  [6527] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(806), 1,
-      sym__member_tag_property,
-  [6534] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(716), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13330] = 2,
+    ACTIONS(1067), 1,
       sym__end_tag_name,
-  [6541] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(808), 1,
-      anon_sym_RBRACE,
-  [6548] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(810), 1,
-      anon_sym_GT,
-  [6555] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(812), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13340] = 2,
+    ACTIONS(1207), 1,
       anon_sym_COLON,
-  [6562] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(814), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13350] = 2,
+    ACTIONS(1209), 1,
       anon_sym_EQ,
-  [6569] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(816), 1,
-      anon_sym_COLON,
-  [6576] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13360] = 2,
+    ACTIONS(1211), 1,
+      sym__doctype,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(818), 1,
-      anon_sym_EQ,
-  [6583] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13370] = 2,
+    ACTIONS(1037), 1,
+      sym_erroneous_end_tag_name,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(820), 1,
-      anon_sym_COLON,
-  [6590] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13380] = 2,
+    ACTIONS(1077), 1,
+      sym__end_tag_name,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(822), 1,
-      anon_sym_RBRACE,
-  [6597] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13390] = 2,
+    ACTIONS(1213), 1,
+      anon_sym_COLON,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(645), 1,
-      sym_erroneous_end_tag_name,
-  [6604] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13400] = 2,
+    ACTIONS(1215), 1,
+      anon_sym_EQ,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(824), 1,
-      anon_sym_GT,
-  [6611] = 2,
-    ACTIONS(3), 1,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
+  [13410] = 2,
+    ACTIONS(1217), 1,
+      anon_sym_COLON,
+    ACTIONS(3), 4,
       sym_comment,
-    ACTIONS(826), 1,
-      anon_sym_RBRACE,
+      sym_processing_instruction,
+      sym_bogus_comment,
+      sym_cdata_section,
 };
 
 static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(2)] = 0,
-  [SMALL_STATE(3)] = 80,
-  [SMALL_STATE(4)] = 160,
-  [SMALL_STATE(5)] = 240,
-  [SMALL_STATE(6)] = 320,
-  [SMALL_STATE(7)] = 393,
-  [SMALL_STATE(8)] = 464,
-  [SMALL_STATE(9)] = 535,
-  [SMALL_STATE(10)] = 606,
-  [SMALL_STATE(11)] = 677,
-  [SMALL_STATE(12)] = 748,
-  [SMALL_STATE(13)] = 819,
-  [SMALL_STATE(14)] = 890,
-  [SMALL_STATE(15)] = 961,
-  [SMALL_STATE(16)] = 1032,
-  [SMALL_STATE(17)] = 1103,
-  [SMALL_STATE(18)] = 1174,
-  [SMALL_STATE(19)] = 1245,
-  [SMALL_STATE(20)] = 1316,
-  [SMALL_STATE(21)] = 1387,
-  [SMALL_STATE(22)] = 1438,
-  [SMALL_STATE(23)] = 1489,
-  [SMALL_STATE(24)] = 1531,
-  [SMALL_STATE(25)] = 1579,
-  [SMALL_STATE(26)] = 1627,
-  [SMALL_STATE(27)] = 1668,
-  [SMALL_STATE(28)] = 1713,
-  [SMALL_STATE(29)] = 1758,
-  [SMALL_STATE(30)] = 1803,
-  [SMALL_STATE(31)] = 1848,
-  [SMALL_STATE(32)] = 1893,
-  [SMALL_STATE(33)] = 1938,
-  [SMALL_STATE(34)] = 1983,
-  [SMALL_STATE(35)] = 2028,
-  [SMALL_STATE(36)] = 2070,
-  [SMALL_STATE(37)] = 2112,
-  [SMALL_STATE(38)] = 2154,
-  [SMALL_STATE(39)] = 2194,
-  [SMALL_STATE(40)] = 2222,
-  [SMALL_STATE(41)] = 2264,
-  [SMALL_STATE(42)] = 2291,
-  [SMALL_STATE(43)] = 2316,
-  [SMALL_STATE(44)] = 2341,
-  [SMALL_STATE(45)] = 2361,
-  [SMALL_STATE(46)] = 2387,
-  [SMALL_STATE(47)] = 2411,
-  [SMALL_STATE(48)] = 2435,
-  [SMALL_STATE(49)] = 2460,
-  [SMALL_STATE(50)] = 2485,
-  [SMALL_STATE(51)] = 2508,
-  [SMALL_STATE(52)] = 2527,
-  [SMALL_STATE(53)] = 2550,
-  [SMALL_STATE(54)] = 2573,
-  [SMALL_STATE(55)] = 2592,
-  [SMALL_STATE(56)] = 2617,
-  [SMALL_STATE(57)] = 2636,
-  [SMALL_STATE(58)] = 2654,
-  [SMALL_STATE(59)] = 2672,
-  [SMALL_STATE(60)] = 2696,
-  [SMALL_STATE(61)] = 2720,
-  [SMALL_STATE(62)] = 2744,
-  [SMALL_STATE(63)] = 2762,
-  [SMALL_STATE(64)] = 2784,
-  [SMALL_STATE(65)] = 2802,
-  [SMALL_STATE(66)] = 2822,
-  [SMALL_STATE(67)] = 2844,
-  [SMALL_STATE(68)] = 2866,
-  [SMALL_STATE(69)] = 2884,
-  [SMALL_STATE(70)] = 2903,
-  [SMALL_STATE(71)] = 2920,
-  [SMALL_STATE(72)] = 2937,
-  [SMALL_STATE(73)] = 2960,
-  [SMALL_STATE(74)] = 2977,
-  [SMALL_STATE(75)] = 2994,
-  [SMALL_STATE(76)] = 3017,
-  [SMALL_STATE(77)] = 3034,
-  [SMALL_STATE(78)] = 3051,
-  [SMALL_STATE(79)] = 3068,
-  [SMALL_STATE(80)] = 3085,
-  [SMALL_STATE(81)] = 3102,
-  [SMALL_STATE(82)] = 3119,
-  [SMALL_STATE(83)] = 3136,
-  [SMALL_STATE(84)] = 3153,
-  [SMALL_STATE(85)] = 3174,
-  [SMALL_STATE(86)] = 3191,
-  [SMALL_STATE(87)] = 3208,
-  [SMALL_STATE(88)] = 3225,
-  [SMALL_STATE(89)] = 3242,
-  [SMALL_STATE(90)] = 3259,
-  [SMALL_STATE(91)] = 3282,
-  [SMALL_STATE(92)] = 3298,
-  [SMALL_STATE(93)] = 3314,
-  [SMALL_STATE(94)] = 3330,
-  [SMALL_STATE(95)] = 3346,
-  [SMALL_STATE(96)] = 3362,
-  [SMALL_STATE(97)] = 3378,
-  [SMALL_STATE(98)] = 3394,
-  [SMALL_STATE(99)] = 3412,
-  [SMALL_STATE(100)] = 3428,
-  [SMALL_STATE(101)] = 3444,
-  [SMALL_STATE(102)] = 3460,
-  [SMALL_STATE(103)] = 3476,
-  [SMALL_STATE(104)] = 3492,
-  [SMALL_STATE(105)] = 3508,
-  [SMALL_STATE(106)] = 3524,
-  [SMALL_STATE(107)] = 3540,
-  [SMALL_STATE(108)] = 3557,
-  [SMALL_STATE(109)] = 3574,
-  [SMALL_STATE(110)] = 3591,
-  [SMALL_STATE(111)] = 3608,
-  [SMALL_STATE(112)] = 3625,
-  [SMALL_STATE(113)] = 3642,
-  [SMALL_STATE(114)] = 3659,
-  [SMALL_STATE(115)] = 3676,
-  [SMALL_STATE(116)] = 3693,
-  [SMALL_STATE(117)] = 3710,
-  [SMALL_STATE(118)] = 3727,
-  [SMALL_STATE(119)] = 3744,
-  [SMALL_STATE(120)] = 3761,
-  [SMALL_STATE(121)] = 3778,
-  [SMALL_STATE(122)] = 3795,
-  [SMALL_STATE(123)] = 3812,
-  [SMALL_STATE(124)] = 3829,
-  [SMALL_STATE(125)] = 3844,
-  [SMALL_STATE(126)] = 3861,
-  [SMALL_STATE(127)] = 3878,
-  [SMALL_STATE(128)] = 3893,
-  [SMALL_STATE(129)] = 3910,
-  [SMALL_STATE(130)] = 3927,
-  [SMALL_STATE(131)] = 3944,
-  [SMALL_STATE(132)] = 3961,
-  [SMALL_STATE(133)] = 3978,
-  [SMALL_STATE(134)] = 3995,
-  [SMALL_STATE(135)] = 4012,
-  [SMALL_STATE(136)] = 4029,
-  [SMALL_STATE(137)] = 4046,
-  [SMALL_STATE(138)] = 4063,
-  [SMALL_STATE(139)] = 4080,
-  [SMALL_STATE(140)] = 4097,
-  [SMALL_STATE(141)] = 4114,
-  [SMALL_STATE(142)] = 4131,
-  [SMALL_STATE(143)] = 4148,
-  [SMALL_STATE(144)] = 4163,
-  [SMALL_STATE(145)] = 4178,
-  [SMALL_STATE(146)] = 4195,
-  [SMALL_STATE(147)] = 4212,
-  [SMALL_STATE(148)] = 4229,
-  [SMALL_STATE(149)] = 4246,
-  [SMALL_STATE(150)] = 4263,
-  [SMALL_STATE(151)] = 4280,
-  [SMALL_STATE(152)] = 4297,
-  [SMALL_STATE(153)] = 4314,
-  [SMALL_STATE(154)] = 4331,
-  [SMALL_STATE(155)] = 4348,
-  [SMALL_STATE(156)] = 4365,
-  [SMALL_STATE(157)] = 4380,
-  [SMALL_STATE(158)] = 4395,
-  [SMALL_STATE(159)] = 4410,
-  [SMALL_STATE(160)] = 4427,
-  [SMALL_STATE(161)] = 4442,
-  [SMALL_STATE(162)] = 4457,
-  [SMALL_STATE(163)] = 4472,
-  [SMALL_STATE(164)] = 4487,
-  [SMALL_STATE(165)] = 4502,
-  [SMALL_STATE(166)] = 4519,
-  [SMALL_STATE(167)] = 4534,
-  [SMALL_STATE(168)] = 4549,
-  [SMALL_STATE(169)] = 4564,
-  [SMALL_STATE(170)] = 4579,
-  [SMALL_STATE(171)] = 4594,
-  [SMALL_STATE(172)] = 4609,
-  [SMALL_STATE(173)] = 4624,
-  [SMALL_STATE(174)] = 4639,
-  [SMALL_STATE(175)] = 4654,
-  [SMALL_STATE(176)] = 4669,
-  [SMALL_STATE(177)] = 4684,
-  [SMALL_STATE(178)] = 4699,
-  [SMALL_STATE(179)] = 4714,
-  [SMALL_STATE(180)] = 4729,
-  [SMALL_STATE(181)] = 4744,
-  [SMALL_STATE(182)] = 4759,
-  [SMALL_STATE(183)] = 4774,
-  [SMALL_STATE(184)] = 4789,
-  [SMALL_STATE(185)] = 4804,
-  [SMALL_STATE(186)] = 4819,
-  [SMALL_STATE(187)] = 4834,
-  [SMALL_STATE(188)] = 4849,
-  [SMALL_STATE(189)] = 4864,
-  [SMALL_STATE(190)] = 4879,
-  [SMALL_STATE(191)] = 4894,
-  [SMALL_STATE(192)] = 4909,
-  [SMALL_STATE(193)] = 4924,
-  [SMALL_STATE(194)] = 4939,
-  [SMALL_STATE(195)] = 4954,
-  [SMALL_STATE(196)] = 4969,
-  [SMALL_STATE(197)] = 4984,
-  [SMALL_STATE(198)] = 4999,
-  [SMALL_STATE(199)] = 5014,
-  [SMALL_STATE(200)] = 5029,
-  [SMALL_STATE(201)] = 5044,
-  [SMALL_STATE(202)] = 5059,
-  [SMALL_STATE(203)] = 5074,
-  [SMALL_STATE(204)] = 5095,
-  [SMALL_STATE(205)] = 5110,
-  [SMALL_STATE(206)] = 5125,
-  [SMALL_STATE(207)] = 5140,
-  [SMALL_STATE(208)] = 5155,
-  [SMALL_STATE(209)] = 5170,
-  [SMALL_STATE(210)] = 5185,
-  [SMALL_STATE(211)] = 5206,
-  [SMALL_STATE(212)] = 5227,
-  [SMALL_STATE(213)] = 5242,
-  [SMALL_STATE(214)] = 5257,
-  [SMALL_STATE(215)] = 5271,
-  [SMALL_STATE(216)] = 5289,
-  [SMALL_STATE(217)] = 5307,
-  [SMALL_STATE(218)] = 5325,
-  [SMALL_STATE(219)] = 5339,
-  [SMALL_STATE(220)] = 5361,
-  [SMALL_STATE(221)] = 5375,
-  [SMALL_STATE(222)] = 5389,
-  [SMALL_STATE(223)] = 5407,
-  [SMALL_STATE(224)] = 5425,
-  [SMALL_STATE(225)] = 5439,
-  [SMALL_STATE(226)] = 5457,
-  [SMALL_STATE(227)] = 5475,
-  [SMALL_STATE(228)] = 5493,
-  [SMALL_STATE(229)] = 5511,
-  [SMALL_STATE(230)] = 5529,
-  [SMALL_STATE(231)] = 5547,
-  [SMALL_STATE(232)] = 5565,
-  [SMALL_STATE(233)] = 5583,
-  [SMALL_STATE(234)] = 5605,
-  [SMALL_STATE(235)] = 5623,
-  [SMALL_STATE(236)] = 5637,
-  [SMALL_STATE(237)] = 5654,
-  [SMALL_STATE(238)] = 5671,
-  [SMALL_STATE(239)] = 5688,
-  [SMALL_STATE(240)] = 5704,
-  [SMALL_STATE(241)] = 5717,
-  [SMALL_STATE(242)] = 5730,
-  [SMALL_STATE(243)] = 5741,
-  [SMALL_STATE(244)] = 5754,
-  [SMALL_STATE(245)] = 5765,
-  [SMALL_STATE(246)] = 5778,
-  [SMALL_STATE(247)] = 5789,
-  [SMALL_STATE(248)] = 5800,
-  [SMALL_STATE(249)] = 5811,
-  [SMALL_STATE(250)] = 5822,
-  [SMALL_STATE(251)] = 5833,
-  [SMALL_STATE(252)] = 5844,
-  [SMALL_STATE(253)] = 5855,
-  [SMALL_STATE(254)] = 5868,
-  [SMALL_STATE(255)] = 5881,
-  [SMALL_STATE(256)] = 5892,
-  [SMALL_STATE(257)] = 5903,
-  [SMALL_STATE(258)] = 5916,
-  [SMALL_STATE(259)] = 5929,
-  [SMALL_STATE(260)] = 5940,
-  [SMALL_STATE(261)] = 5951,
-  [SMALL_STATE(262)] = 5962,
-  [SMALL_STATE(263)] = 5973,
-  [SMALL_STATE(264)] = 5984,
-  [SMALL_STATE(265)] = 5997,
-  [SMALL_STATE(266)] = 6010,
-  [SMALL_STATE(267)] = 6023,
-  [SMALL_STATE(268)] = 6036,
-  [SMALL_STATE(269)] = 6047,
-  [SMALL_STATE(270)] = 6060,
-  [SMALL_STATE(271)] = 6070,
-  [SMALL_STATE(272)] = 6080,
-  [SMALL_STATE(273)] = 6090,
-  [SMALL_STATE(274)] = 6100,
-  [SMALL_STATE(275)] = 6110,
-  [SMALL_STATE(276)] = 6118,
-  [SMALL_STATE(277)] = 6126,
-  [SMALL_STATE(278)] = 6136,
-  [SMALL_STATE(279)] = 6144,
-  [SMALL_STATE(280)] = 6154,
-  [SMALL_STATE(281)] = 6164,
-  [SMALL_STATE(282)] = 6174,
-  [SMALL_STATE(283)] = 6182,
-  [SMALL_STATE(284)] = 6192,
-  [SMALL_STATE(285)] = 6202,
-  [SMALL_STATE(286)] = 6212,
-  [SMALL_STATE(287)] = 6219,
-  [SMALL_STATE(288)] = 6226,
-  [SMALL_STATE(289)] = 6233,
-  [SMALL_STATE(290)] = 6240,
-  [SMALL_STATE(291)] = 6247,
-  [SMALL_STATE(292)] = 6254,
-  [SMALL_STATE(293)] = 6261,
-  [SMALL_STATE(294)] = 6268,
-  [SMALL_STATE(295)] = 6275,
-  [SMALL_STATE(296)] = 6282,
-  [SMALL_STATE(297)] = 6289,
-  [SMALL_STATE(298)] = 6296,
-  [SMALL_STATE(299)] = 6303,
-  [SMALL_STATE(300)] = 6310,
-  [SMALL_STATE(301)] = 6317,
-  [SMALL_STATE(302)] = 6324,
-  [SMALL_STATE(303)] = 6331,
-  [SMALL_STATE(304)] = 6338,
-  [SMALL_STATE(305)] = 6345,
-  [SMALL_STATE(306)] = 6352,
-  [SMALL_STATE(307)] = 6359,
-  [SMALL_STATE(308)] = 6366,
-  [SMALL_STATE(309)] = 6373,
-  [SMALL_STATE(310)] = 6380,
-  [SMALL_STATE(311)] = 6387,
-  [SMALL_STATE(312)] = 6394,
-  [SMALL_STATE(313)] = 6401,
-  [SMALL_STATE(314)] = 6408,
-  [SMALL_STATE(315)] = 6415,
-  [SMALL_STATE(316)] = 6422,
-  [SMALL_STATE(317)] = 6429,
-  [SMALL_STATE(318)] = 6436,
-  [SMALL_STATE(319)] = 6443,
-  [SMALL_STATE(320)] = 6450,
-  [SMALL_STATE(321)] = 6457,
-  [SMALL_STATE(322)] = 6464,
-  [SMALL_STATE(323)] = 6471,
-  [SMALL_STATE(324)] = 6478,
-  [SMALL_STATE(325)] = 6485,
-  [SMALL_STATE(326)] = 6492,
-  [SMALL_STATE(327)] = 6499,
-  [SMALL_STATE(328)] = 6506,
-  [SMALL_STATE(329)] = 6513,
-  [SMALL_STATE(330)] = 6520,
-  [SMALL_STATE(331)] = 6527,
-  [SMALL_STATE(332)] = 6534,
-  [SMALL_STATE(333)] = 6541,
-  [SMALL_STATE(334)] = 6548,
-  [SMALL_STATE(335)] = 6555,
-  [SMALL_STATE(336)] = 6562,
-  [SMALL_STATE(337)] = 6569,
-  [SMALL_STATE(338)] = 6576,
-  [SMALL_STATE(339)] = 6583,
-  [SMALL_STATE(340)] = 6590,
-  [SMALL_STATE(341)] = 6597,
-  [SMALL_STATE(342)] = 6604,
-  [SMALL_STATE(343)] = 6611,
+  [SMALL_STATE(3)] = 97,
+  [SMALL_STATE(4)] = 194,
+  [SMALL_STATE(5)] = 291,
+  [SMALL_STATE(6)] = 388,
+  [SMALL_STATE(7)] = 485,
+  [SMALL_STATE(8)] = 582,
+  [SMALL_STATE(9)] = 679,
+  [SMALL_STATE(10)] = 776,
+  [SMALL_STATE(11)] = 873,
+  [SMALL_STATE(12)] = 963,
+  [SMALL_STATE(13)] = 1054,
+  [SMALL_STATE(14)] = 1143,
+  [SMALL_STATE(15)] = 1232,
+  [SMALL_STATE(16)] = 1323,
+  [SMALL_STATE(17)] = 1414,
+  [SMALL_STATE(18)] = 1502,
+  [SMALL_STATE(19)] = 1590,
+  [SMALL_STATE(20)] = 1678,
+  [SMALL_STATE(21)] = 1766,
+  [SMALL_STATE(22)] = 1854,
+  [SMALL_STATE(23)] = 1942,
+  [SMALL_STATE(24)] = 2030,
+  [SMALL_STATE(25)] = 2118,
+  [SMALL_STATE(26)] = 2206,
+  [SMALL_STATE(27)] = 2294,
+  [SMALL_STATE(28)] = 2382,
+  [SMALL_STATE(29)] = 2470,
+  [SMALL_STATE(30)] = 2558,
+  [SMALL_STATE(31)] = 2646,
+  [SMALL_STATE(32)] = 2734,
+  [SMALL_STATE(33)] = 2822,
+  [SMALL_STATE(34)] = 2910,
+  [SMALL_STATE(35)] = 2998,
+  [SMALL_STATE(36)] = 3086,
+  [SMALL_STATE(37)] = 3174,
+  [SMALL_STATE(38)] = 3259,
+  [SMALL_STATE(39)] = 3313,
+  [SMALL_STATE(40)] = 3367,
+  [SMALL_STATE(41)] = 3421,
+  [SMALL_STATE(42)] = 3472,
+  [SMALL_STATE(43)] = 3523,
+  [SMALL_STATE(44)] = 3574,
+  [SMALL_STATE(45)] = 3619,
+  [SMALL_STATE(46)] = 3670,
+  [SMALL_STATE(47)] = 3721,
+  [SMALL_STATE(48)] = 3772,
+  [SMALL_STATE(49)] = 3823,
+  [SMALL_STATE(50)] = 3874,
+  [SMALL_STATE(51)] = 3925,
+  [SMALL_STATE(52)] = 3973,
+  [SMALL_STATE(53)] = 4021,
+  [SMALL_STATE(54)] = 4069,
+  [SMALL_STATE(55)] = 4117,
+  [SMALL_STATE(56)] = 4161,
+  [SMALL_STATE(57)] = 4209,
+  [SMALL_STATE(58)] = 4257,
+  [SMALL_STATE(59)] = 4305,
+  [SMALL_STATE(60)] = 4353,
+  [SMALL_STATE(61)] = 4401,
+  [SMALL_STATE(62)] = 4449,
+  [SMALL_STATE(63)] = 4497,
+  [SMALL_STATE(64)] = 4545,
+  [SMALL_STATE(65)] = 4590,
+  [SMALL_STATE(66)] = 4635,
+  [SMALL_STATE(67)] = 4666,
+  [SMALL_STATE(68)] = 4709,
+  [SMALL_STATE(69)] = 4754,
+  [SMALL_STATE(70)] = 4799,
+  [SMALL_STATE(71)] = 4844,
+  [SMALL_STATE(72)] = 4889,
+  [SMALL_STATE(73)] = 4917,
+  [SMALL_STATE(74)] = 4945,
+  [SMALL_STATE(75)] = 4975,
+  [SMALL_STATE(76)] = 4998,
+  [SMALL_STATE(77)] = 5027,
+  [SMALL_STATE(78)] = 5054,
+  [SMALL_STATE(79)] = 5081,
+  [SMALL_STATE(80)] = 5103,
+  [SMALL_STATE(81)] = 5129,
+  [SMALL_STATE(82)] = 5157,
+  [SMALL_STATE(83)] = 5179,
+  [SMALL_STATE(84)] = 5207,
+  [SMALL_STATE(85)] = 5235,
+  [SMALL_STATE(86)] = 5261,
+  [SMALL_STATE(87)] = 5287,
+  [SMALL_STATE(88)] = 5309,
+  [SMALL_STATE(89)] = 5334,
+  [SMALL_STATE(90)] = 5359,
+  [SMALL_STATE(91)] = 5382,
+  [SMALL_STATE(92)] = 5403,
+  [SMALL_STATE(93)] = 5424,
+  [SMALL_STATE(94)] = 5449,
+  [SMALL_STATE(95)] = 5476,
+  [SMALL_STATE(96)] = 5497,
+  [SMALL_STATE(97)] = 5524,
+  [SMALL_STATE(98)] = 5551,
+  [SMALL_STATE(99)] = 5572,
+  [SMALL_STATE(100)] = 5593,
+  [SMALL_STATE(101)] = 5613,
+  [SMALL_STATE(102)] = 5633,
+  [SMALL_STATE(103)] = 5653,
+  [SMALL_STATE(104)] = 5673,
+  [SMALL_STATE(105)] = 5693,
+  [SMALL_STATE(106)] = 5713,
+  [SMALL_STATE(107)] = 5733,
+  [SMALL_STATE(108)] = 5753,
+  [SMALL_STATE(109)] = 5773,
+  [SMALL_STATE(110)] = 5793,
+  [SMALL_STATE(111)] = 5813,
+  [SMALL_STATE(112)] = 5833,
+  [SMALL_STATE(113)] = 5853,
+  [SMALL_STATE(114)] = 5873,
+  [SMALL_STATE(115)] = 5897,
+  [SMALL_STATE(116)] = 5923,
+  [SMALL_STATE(117)] = 5943,
+  [SMALL_STATE(118)] = 5969,
+  [SMALL_STATE(119)] = 5995,
+  [SMALL_STATE(120)] = 6017,
+  [SMALL_STATE(121)] = 6037,
+  [SMALL_STATE(122)] = 6057,
+  [SMALL_STATE(123)] = 6078,
+  [SMALL_STATE(124)] = 6099,
+  [SMALL_STATE(125)] = 6118,
+  [SMALL_STATE(126)] = 6139,
+  [SMALL_STATE(127)] = 6160,
+  [SMALL_STATE(128)] = 6181,
+  [SMALL_STATE(129)] = 6202,
+  [SMALL_STATE(130)] = 6223,
+  [SMALL_STATE(131)] = 6244,
+  [SMALL_STATE(132)] = 6265,
+  [SMALL_STATE(133)] = 6286,
+  [SMALL_STATE(134)] = 6307,
+  [SMALL_STATE(135)] = 6328,
+  [SMALL_STATE(136)] = 6349,
+  [SMALL_STATE(137)] = 6370,
+  [SMALL_STATE(138)] = 6391,
+  [SMALL_STATE(139)] = 6412,
+  [SMALL_STATE(140)] = 6433,
+  [SMALL_STATE(141)] = 6454,
+  [SMALL_STATE(142)] = 6475,
+  [SMALL_STATE(143)] = 6496,
+  [SMALL_STATE(144)] = 6517,
+  [SMALL_STATE(145)] = 6538,
+  [SMALL_STATE(146)] = 6559,
+  [SMALL_STATE(147)] = 6580,
+  [SMALL_STATE(148)] = 6601,
+  [SMALL_STATE(149)] = 6622,
+  [SMALL_STATE(150)] = 6643,
+  [SMALL_STATE(151)] = 6664,
+  [SMALL_STATE(152)] = 6685,
+  [SMALL_STATE(153)] = 6706,
+  [SMALL_STATE(154)] = 6727,
+  [SMALL_STATE(155)] = 6748,
+  [SMALL_STATE(156)] = 6769,
+  [SMALL_STATE(157)] = 6790,
+  [SMALL_STATE(158)] = 6811,
+  [SMALL_STATE(159)] = 6832,
+  [SMALL_STATE(160)] = 6853,
+  [SMALL_STATE(161)] = 6874,
+  [SMALL_STATE(162)] = 6895,
+  [SMALL_STATE(163)] = 6916,
+  [SMALL_STATE(164)] = 6937,
+  [SMALL_STATE(165)] = 6958,
+  [SMALL_STATE(166)] = 6979,
+  [SMALL_STATE(167)] = 7000,
+  [SMALL_STATE(168)] = 7021,
+  [SMALL_STATE(169)] = 7042,
+  [SMALL_STATE(170)] = 7063,
+  [SMALL_STATE(171)] = 7084,
+  [SMALL_STATE(172)] = 7105,
+  [SMALL_STATE(173)] = 7126,
+  [SMALL_STATE(174)] = 7147,
+  [SMALL_STATE(175)] = 7168,
+  [SMALL_STATE(176)] = 7189,
+  [SMALL_STATE(177)] = 7210,
+  [SMALL_STATE(178)] = 7231,
+  [SMALL_STATE(179)] = 7252,
+  [SMALL_STATE(180)] = 7273,
+  [SMALL_STATE(181)] = 7294,
+  [SMALL_STATE(182)] = 7315,
+  [SMALL_STATE(183)] = 7336,
+  [SMALL_STATE(184)] = 7357,
+  [SMALL_STATE(185)] = 7378,
+  [SMALL_STATE(186)] = 7399,
+  [SMALL_STATE(187)] = 7420,
+  [SMALL_STATE(188)] = 7441,
+  [SMALL_STATE(189)] = 7462,
+  [SMALL_STATE(190)] = 7483,
+  [SMALL_STATE(191)] = 7504,
+  [SMALL_STATE(192)] = 7525,
+  [SMALL_STATE(193)] = 7546,
+  [SMALL_STATE(194)] = 7567,
+  [SMALL_STATE(195)] = 7586,
+  [SMALL_STATE(196)] = 7605,
+  [SMALL_STATE(197)] = 7624,
+  [SMALL_STATE(198)] = 7643,
+  [SMALL_STATE(199)] = 7662,
+  [SMALL_STATE(200)] = 7681,
+  [SMALL_STATE(201)] = 7700,
+  [SMALL_STATE(202)] = 7719,
+  [SMALL_STATE(203)] = 7738,
+  [SMALL_STATE(204)] = 7757,
+  [SMALL_STATE(205)] = 7776,
+  [SMALL_STATE(206)] = 7795,
+  [SMALL_STATE(207)] = 7814,
+  [SMALL_STATE(208)] = 7833,
+  [SMALL_STATE(209)] = 7853,
+  [SMALL_STATE(210)] = 7873,
+  [SMALL_STATE(211)] = 7891,
+  [SMALL_STATE(212)] = 7909,
+  [SMALL_STATE(213)] = 7927,
+  [SMALL_STATE(214)] = 7945,
+  [SMALL_STATE(215)] = 7963,
+  [SMALL_STATE(216)] = 7981,
+  [SMALL_STATE(217)] = 7999,
+  [SMALL_STATE(218)] = 8017,
+  [SMALL_STATE(219)] = 8035,
+  [SMALL_STATE(220)] = 8053,
+  [SMALL_STATE(221)] = 8071,
+  [SMALL_STATE(222)] = 8089,
+  [SMALL_STATE(223)] = 8109,
+  [SMALL_STATE(224)] = 8129,
+  [SMALL_STATE(225)] = 8149,
+  [SMALL_STATE(226)] = 8169,
+  [SMALL_STATE(227)] = 8189,
+  [SMALL_STATE(228)] = 8209,
+  [SMALL_STATE(229)] = 8229,
+  [SMALL_STATE(230)] = 8249,
+  [SMALL_STATE(231)] = 8269,
+  [SMALL_STATE(232)] = 8289,
+  [SMALL_STATE(233)] = 8309,
+  [SMALL_STATE(234)] = 8329,
+  [SMALL_STATE(235)] = 8349,
+  [SMALL_STATE(236)] = 8369,
+  [SMALL_STATE(237)] = 8389,
+  [SMALL_STATE(238)] = 8409,
+  [SMALL_STATE(239)] = 8429,
+  [SMALL_STATE(240)] = 8449,
+  [SMALL_STATE(241)] = 8469,
+  [SMALL_STATE(242)] = 8489,
+  [SMALL_STATE(243)] = 8509,
+  [SMALL_STATE(244)] = 8529,
+  [SMALL_STATE(245)] = 8549,
+  [SMALL_STATE(246)] = 8569,
+  [SMALL_STATE(247)] = 8589,
+  [SMALL_STATE(248)] = 8609,
+  [SMALL_STATE(249)] = 8629,
+  [SMALL_STATE(250)] = 8649,
+  [SMALL_STATE(251)] = 8669,
+  [SMALL_STATE(252)] = 8689,
+  [SMALL_STATE(253)] = 8709,
+  [SMALL_STATE(254)] = 8729,
+  [SMALL_STATE(255)] = 8749,
+  [SMALL_STATE(256)] = 8769,
+  [SMALL_STATE(257)] = 8789,
+  [SMALL_STATE(258)] = 8809,
+  [SMALL_STATE(259)] = 8829,
+  [SMALL_STATE(260)] = 8849,
+  [SMALL_STATE(261)] = 8869,
+  [SMALL_STATE(262)] = 8889,
+  [SMALL_STATE(263)] = 8909,
+  [SMALL_STATE(264)] = 8929,
+  [SMALL_STATE(265)] = 8949,
+  [SMALL_STATE(266)] = 8969,
+  [SMALL_STATE(267)] = 8989,
+  [SMALL_STATE(268)] = 9009,
+  [SMALL_STATE(269)] = 9029,
+  [SMALL_STATE(270)] = 9049,
+  [SMALL_STATE(271)] = 9069,
+  [SMALL_STATE(272)] = 9089,
+  [SMALL_STATE(273)] = 9109,
+  [SMALL_STATE(274)] = 9129,
+  [SMALL_STATE(275)] = 9149,
+  [SMALL_STATE(276)] = 9169,
+  [SMALL_STATE(277)] = 9189,
+  [SMALL_STATE(278)] = 9209,
+  [SMALL_STATE(279)] = 9229,
+  [SMALL_STATE(280)] = 9249,
+  [SMALL_STATE(281)] = 9269,
+  [SMALL_STATE(282)] = 9289,
+  [SMALL_STATE(283)] = 9309,
+  [SMALL_STATE(284)] = 9329,
+  [SMALL_STATE(285)] = 9349,
+  [SMALL_STATE(286)] = 9369,
+  [SMALL_STATE(287)] = 9389,
+  [SMALL_STATE(288)] = 9409,
+  [SMALL_STATE(289)] = 9429,
+  [SMALL_STATE(290)] = 9449,
+  [SMALL_STATE(291)] = 9468,
+  [SMALL_STATE(292)] = 9487,
+  [SMALL_STATE(293)] = 9506,
+  [SMALL_STATE(294)] = 9525,
+  [SMALL_STATE(295)] = 9544,
+  [SMALL_STATE(296)] = 9563,
+  [SMALL_STATE(297)] = 9582,
+  [SMALL_STATE(298)] = 9601,
+  [SMALL_STATE(299)] = 9620,
+  [SMALL_STATE(300)] = 9639,
+  [SMALL_STATE(301)] = 9658,
+  [SMALL_STATE(302)] = 9677,
+  [SMALL_STATE(303)] = 9696,
+  [SMALL_STATE(304)] = 9715,
+  [SMALL_STATE(305)] = 9734,
+  [SMALL_STATE(306)] = 9753,
+  [SMALL_STATE(307)] = 9772,
+  [SMALL_STATE(308)] = 9791,
+  [SMALL_STATE(309)] = 9810,
+  [SMALL_STATE(310)] = 9829,
+  [SMALL_STATE(311)] = 9848,
+  [SMALL_STATE(312)] = 9867,
+  [SMALL_STATE(313)] = 9886,
+  [SMALL_STATE(314)] = 9905,
+  [SMALL_STATE(315)] = 9924,
+  [SMALL_STATE(316)] = 9943,
+  [SMALL_STATE(317)] = 9962,
+  [SMALL_STATE(318)] = 9981,
+  [SMALL_STATE(319)] = 10000,
+  [SMALL_STATE(320)] = 10019,
+  [SMALL_STATE(321)] = 10038,
+  [SMALL_STATE(322)] = 10057,
+  [SMALL_STATE(323)] = 10076,
+  [SMALL_STATE(324)] = 10095,
+  [SMALL_STATE(325)] = 10114,
+  [SMALL_STATE(326)] = 10133,
+  [SMALL_STATE(327)] = 10152,
+  [SMALL_STATE(328)] = 10171,
+  [SMALL_STATE(329)] = 10190,
+  [SMALL_STATE(330)] = 10209,
+  [SMALL_STATE(331)] = 10228,
+  [SMALL_STATE(332)] = 10247,
+  [SMALL_STATE(333)] = 10266,
+  [SMALL_STATE(334)] = 10285,
+  [SMALL_STATE(335)] = 10304,
+  [SMALL_STATE(336)] = 10323,
+  [SMALL_STATE(337)] = 10342,
+  [SMALL_STATE(338)] = 10361,
+  [SMALL_STATE(339)] = 10380,
+  [SMALL_STATE(340)] = 10399,
+  [SMALL_STATE(341)] = 10418,
+  [SMALL_STATE(342)] = 10437,
+  [SMALL_STATE(343)] = 10456,
+  [SMALL_STATE(344)] = 10475,
+  [SMALL_STATE(345)] = 10494,
+  [SMALL_STATE(346)] = 10513,
+  [SMALL_STATE(347)] = 10532,
+  [SMALL_STATE(348)] = 10551,
+  [SMALL_STATE(349)] = 10570,
+  [SMALL_STATE(350)] = 10589,
+  [SMALL_STATE(351)] = 10608,
+  [SMALL_STATE(352)] = 10627,
+  [SMALL_STATE(353)] = 10646,
+  [SMALL_STATE(354)] = 10665,
+  [SMALL_STATE(355)] = 10684,
+  [SMALL_STATE(356)] = 10703,
+  [SMALL_STATE(357)] = 10722,
+  [SMALL_STATE(358)] = 10741,
+  [SMALL_STATE(359)] = 10769,
+  [SMALL_STATE(360)] = 10787,
+  [SMALL_STATE(361)] = 10805,
+  [SMALL_STATE(362)] = 10823,
+  [SMALL_STATE(363)] = 10841,
+  [SMALL_STATE(364)] = 10865,
+  [SMALL_STATE(365)] = 10883,
+  [SMALL_STATE(366)] = 10901,
+  [SMALL_STATE(367)] = 10919,
+  [SMALL_STATE(368)] = 10937,
+  [SMALL_STATE(369)] = 10961,
+  [SMALL_STATE(370)] = 10985,
+  [SMALL_STATE(371)] = 11013,
+  [SMALL_STATE(372)] = 11041,
+  [SMALL_STATE(373)] = 11062,
+  [SMALL_STATE(374)] = 11083,
+  [SMALL_STATE(375)] = 11104,
+  [SMALL_STATE(376)] = 11125,
+  [SMALL_STATE(377)] = 11146,
+  [SMALL_STATE(378)] = 11167,
+  [SMALL_STATE(379)] = 11188,
+  [SMALL_STATE(380)] = 11209,
+  [SMALL_STATE(381)] = 11230,
+  [SMALL_STATE(382)] = 11251,
+  [SMALL_STATE(383)] = 11272,
+  [SMALL_STATE(384)] = 11293,
+  [SMALL_STATE(385)] = 11314,
+  [SMALL_STATE(386)] = 11335,
+  [SMALL_STATE(387)] = 11355,
+  [SMALL_STATE(388)] = 11375,
+  [SMALL_STATE(389)] = 11395,
+  [SMALL_STATE(390)] = 11414,
+  [SMALL_STATE(391)] = 11433,
+  [SMALL_STATE(392)] = 11452,
+  [SMALL_STATE(393)] = 11471,
+  [SMALL_STATE(394)] = 11490,
+  [SMALL_STATE(395)] = 11509,
+  [SMALL_STATE(396)] = 11528,
+  [SMALL_STATE(397)] = 11542,
+  [SMALL_STATE(398)] = 11558,
+  [SMALL_STATE(399)] = 11574,
+  [SMALL_STATE(400)] = 11590,
+  [SMALL_STATE(401)] = 11604,
+  [SMALL_STATE(402)] = 11620,
+  [SMALL_STATE(403)] = 11636,
+  [SMALL_STATE(404)] = 11652,
+  [SMALL_STATE(405)] = 11668,
+  [SMALL_STATE(406)] = 11682,
+  [SMALL_STATE(407)] = 11698,
+  [SMALL_STATE(408)] = 11714,
+  [SMALL_STATE(409)] = 11730,
+  [SMALL_STATE(410)] = 11746,
+  [SMALL_STATE(411)] = 11760,
+  [SMALL_STATE(412)] = 11774,
+  [SMALL_STATE(413)] = 11788,
+  [SMALL_STATE(414)] = 11802,
+  [SMALL_STATE(415)] = 11816,
+  [SMALL_STATE(416)] = 11832,
+  [SMALL_STATE(417)] = 11846,
+  [SMALL_STATE(418)] = 11862,
+  [SMALL_STATE(419)] = 11878,
+  [SMALL_STATE(420)] = 11894,
+  [SMALL_STATE(421)] = 11910,
+  [SMALL_STATE(422)] = 11924,
+  [SMALL_STATE(423)] = 11938,
+  [SMALL_STATE(424)] = 11954,
+  [SMALL_STATE(425)] = 11968,
+  [SMALL_STATE(426)] = 11984,
+  [SMALL_STATE(427)] = 12000,
+  [SMALL_STATE(428)] = 12016,
+  [SMALL_STATE(429)] = 12032,
+  [SMALL_STATE(430)] = 12046,
+  [SMALL_STATE(431)] = 12060,
+  [SMALL_STATE(432)] = 12074,
+  [SMALL_STATE(433)] = 12088,
+  [SMALL_STATE(434)] = 12102,
+  [SMALL_STATE(435)] = 12116,
+  [SMALL_STATE(436)] = 12132,
+  [SMALL_STATE(437)] = 12148,
+  [SMALL_STATE(438)] = 12164,
+  [SMALL_STATE(439)] = 12180,
+  [SMALL_STATE(440)] = 12193,
+  [SMALL_STATE(441)] = 12206,
+  [SMALL_STATE(442)] = 12219,
+  [SMALL_STATE(443)] = 12232,
+  [SMALL_STATE(444)] = 12245,
+  [SMALL_STATE(445)] = 12258,
+  [SMALL_STATE(446)] = 12269,
+  [SMALL_STATE(447)] = 12282,
+  [SMALL_STATE(448)] = 12293,
+  [SMALL_STATE(449)] = 12306,
+  [SMALL_STATE(450)] = 12319,
+  [SMALL_STATE(451)] = 12330,
+  [SMALL_STATE(452)] = 12341,
+  [SMALL_STATE(453)] = 12354,
+  [SMALL_STATE(454)] = 12367,
+  [SMALL_STATE(455)] = 12380,
+  [SMALL_STATE(456)] = 12393,
+  [SMALL_STATE(457)] = 12406,
+  [SMALL_STATE(458)] = 12419,
+  [SMALL_STATE(459)] = 12432,
+  [SMALL_STATE(460)] = 12445,
+  [SMALL_STATE(461)] = 12458,
+  [SMALL_STATE(462)] = 12471,
+  [SMALL_STATE(463)] = 12484,
+  [SMALL_STATE(464)] = 12497,
+  [SMALL_STATE(465)] = 12510,
+  [SMALL_STATE(466)] = 12523,
+  [SMALL_STATE(467)] = 12536,
+  [SMALL_STATE(468)] = 12549,
+  [SMALL_STATE(469)] = 12562,
+  [SMALL_STATE(470)] = 12575,
+  [SMALL_STATE(471)] = 12588,
+  [SMALL_STATE(472)] = 12601,
+  [SMALL_STATE(473)] = 12614,
+  [SMALL_STATE(474)] = 12627,
+  [SMALL_STATE(475)] = 12640,
+  [SMALL_STATE(476)] = 12650,
+  [SMALL_STATE(477)] = 12660,
+  [SMALL_STATE(478)] = 12670,
+  [SMALL_STATE(479)] = 12680,
+  [SMALL_STATE(480)] = 12690,
+  [SMALL_STATE(481)] = 12700,
+  [SMALL_STATE(482)] = 12710,
+  [SMALL_STATE(483)] = 12720,
+  [SMALL_STATE(484)] = 12730,
+  [SMALL_STATE(485)] = 12740,
+  [SMALL_STATE(486)] = 12750,
+  [SMALL_STATE(487)] = 12760,
+  [SMALL_STATE(488)] = 12770,
+  [SMALL_STATE(489)] = 12780,
+  [SMALL_STATE(490)] = 12790,
+  [SMALL_STATE(491)] = 12800,
+  [SMALL_STATE(492)] = 12810,
+  [SMALL_STATE(493)] = 12820,
+  [SMALL_STATE(494)] = 12830,
+  [SMALL_STATE(495)] = 12840,
+  [SMALL_STATE(496)] = 12850,
+  [SMALL_STATE(497)] = 12860,
+  [SMALL_STATE(498)] = 12870,
+  [SMALL_STATE(499)] = 12880,
+  [SMALL_STATE(500)] = 12890,
+  [SMALL_STATE(501)] = 12900,
+  [SMALL_STATE(502)] = 12910,
+  [SMALL_STATE(503)] = 12920,
+  [SMALL_STATE(504)] = 12930,
+  [SMALL_STATE(505)] = 12940,
+  [SMALL_STATE(506)] = 12950,
+  [SMALL_STATE(507)] = 12960,
+  [SMALL_STATE(508)] = 12970,
+  [SMALL_STATE(509)] = 12980,
+  [SMALL_STATE(510)] = 12990,
+  [SMALL_STATE(511)] = 13000,
+  [SMALL_STATE(512)] = 13010,
+  [SMALL_STATE(513)] = 13020,
+  [SMALL_STATE(514)] = 13030,
+  [SMALL_STATE(515)] = 13040,
+  [SMALL_STATE(516)] = 13050,
+  [SMALL_STATE(517)] = 13060,
+  [SMALL_STATE(518)] = 13070,
+  [SMALL_STATE(519)] = 13080,
+  [SMALL_STATE(520)] = 13090,
+  [SMALL_STATE(521)] = 13100,
+  [SMALL_STATE(522)] = 13110,
+  [SMALL_STATE(523)] = 13120,
+  [SMALL_STATE(524)] = 13130,
+  [SMALL_STATE(525)] = 13140,
+  [SMALL_STATE(526)] = 13150,
+  [SMALL_STATE(527)] = 13160,
+  [SMALL_STATE(528)] = 13170,
+  [SMALL_STATE(529)] = 13180,
+  [SMALL_STATE(530)] = 13190,
+  [SMALL_STATE(531)] = 13200,
+  [SMALL_STATE(532)] = 13210,
+  [SMALL_STATE(533)] = 13220,
+  [SMALL_STATE(534)] = 13230,
+  [SMALL_STATE(535)] = 13240,
+  [SMALL_STATE(536)] = 13250,
+  [SMALL_STATE(537)] = 13260,
+  [SMALL_STATE(538)] = 13270,
+  [SMALL_STATE(539)] = 13280,
+  [SMALL_STATE(540)] = 13290,
+  [SMALL_STATE(541)] = 13300,
+  [SMALL_STATE(542)] = 13310,
+  [SMALL_STATE(543)] = 13320,
+  [SMALL_STATE(544)] = 13330,
+  [SMALL_STATE(545)] = 13340,
+  [SMALL_STATE(546)] = 13350,
+  [SMALL_STATE(547)] = 13360,
+  [SMALL_STATE(548)] = 13370,
+  [SMALL_STATE(549)] = 13380,
+  [SMALL_STATE(550)] = 13390,
+  [SMALL_STATE(551)] = 13400,
+  [SMALL_STATE(552)] = 13410,
 };
 
 static const TSParseActionEntry ts_parse_actions[] = {
@@ -7306,396 +13831,588 @@ static const TSParseActionEntry ts_parse_actions[] = {
   [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
   [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
   [5] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_document, 0, 0, 0),
-  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(307),
-  [9] = {.entry = {.count = 1, .reusable = false}}, SHIFT(219),
-  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(341),
-  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
-  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(268),
-  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(286),
-  [19] = {.entry = {.count = 1, .reusable = false}}, SHIFT(233),
-  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(279),
-  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
-  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(255),
-  [27] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
-  [29] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
-  [31] = {.entry = {.count = 1, .reusable = true}}, SHIFT(284),
-  [33] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
-  [35] = {.entry = {.count = 1, .reusable = true}}, SHIFT(195),
-  [37] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
-  [39] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
-  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(270),
-  [43] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
-  [45] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
-  [47] = {.entry = {.count = 1, .reusable = true}}, SHIFT(277),
-  [49] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
-  [51] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
-  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(118),
-  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(271),
-  [57] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(286),
-  [60] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(233),
-  [63] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(328),
-  [66] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(6),
-  [69] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(255),
-  [72] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0),
-  [74] = {.entry = {.count = 1, .reusable = true}}, SHIFT(272),
-  [76] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
-  [78] = {.entry = {.count = 1, .reusable = true}}, SHIFT(264),
-  [80] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
-  [82] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_document, 1, 0, 0),
-  [84] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
-  [86] = {.entry = {.count = 1, .reusable = true}}, SHIFT(243),
-  [88] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
-  [90] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(307),
-  [93] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(219),
-  [96] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(341),
-  [99] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(14),
-  [102] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(268),
-  [105] = {.entry = {.count = 1, .reusable = true}}, SHIFT(281),
-  [107] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
-  [109] = {.entry = {.count = 1, .reusable = true}}, SHIFT(267),
-  [111] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
-  [113] = {.entry = {.count = 1, .reusable = true}}, SHIFT(266),
-  [115] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
-  [117] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
-  [119] = {.entry = {.count = 1, .reusable = true}}, SHIFT(126),
-  [121] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
-  [123] = {.entry = {.count = 1, .reusable = true}}, SHIFT(299),
-  [125] = {.entry = {.count = 1, .reusable = true}}, SHIFT(252),
-  [127] = {.entry = {.count = 1, .reusable = true}}, SHIFT(236),
-  [129] = {.entry = {.count = 1, .reusable = true}}, SHIFT(310),
-  [131] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
-  [133] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
-  [135] = {.entry = {.count = 1, .reusable = true}}, SHIFT(235),
-  [137] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
-  [139] = {.entry = {.count = 1, .reusable = true}}, SHIFT(303),
-  [141] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
-  [143] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0),
-  [145] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(54),
-  [148] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(252),
-  [151] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(236),
-  [154] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(310),
-  [157] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(83),
-  [160] = {.entry = {.count = 1, .reusable = true}}, SHIFT(135),
-  [162] = {.entry = {.count = 1, .reusable = true}}, SHIFT(206),
-  [164] = {.entry = {.count = 1, .reusable = true}}, SHIFT(204),
-  [166] = {.entry = {.count = 1, .reusable = true}}, SHIFT(220),
-  [168] = {.entry = {.count = 1, .reusable = true}}, SHIFT(146),
-  [170] = {.entry = {.count = 1, .reusable = true}}, SHIFT(145),
-  [172] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(68),
-  [175] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(251),
-  [178] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(238),
-  [181] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(337),
-  [184] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(95),
-  [187] = {.entry = {.count = 1, .reusable = true}}, SHIFT(218),
-  [189] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
-  [191] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
-  [193] = {.entry = {.count = 1, .reusable = true}}, SHIFT(251),
-  [195] = {.entry = {.count = 1, .reusable = true}}, SHIFT(238),
-  [197] = {.entry = {.count = 1, .reusable = true}}, SHIFT(337),
-  [199] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
-  [201] = {.entry = {.count = 1, .reusable = true}}, SHIFT(186),
-  [203] = {.entry = {.count = 1, .reusable = true}}, SHIFT(214),
-  [205] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
-  [207] = {.entry = {.count = 1, .reusable = true}}, SHIFT(212),
-  [209] = {.entry = {.count = 1, .reusable = true}}, SHIFT(224),
-  [211] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
-  [213] = {.entry = {.count = 1, .reusable = true}}, SHIFT(190),
-  [215] = {.entry = {.count = 1, .reusable = true}}, SHIFT(221),
-  [217] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
-  [219] = {.entry = {.count = 1, .reusable = true}}, SHIFT(129),
-  [221] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
-  [223] = {.entry = {.count = 1, .reusable = true}}, SHIFT(188),
-  [225] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
-  [227] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
-  [229] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
-  [231] = {.entry = {.count = 1, .reusable = true}}, SHIFT(148),
-  [233] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
-  [235] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
-  [237] = {.entry = {.count = 1, .reusable = true}}, SHIFT(275),
-  [239] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
-  [241] = {.entry = {.count = 1, .reusable = true}}, SHIFT(242),
-  [243] = {.entry = {.count = 1, .reusable = true}}, SHIFT(237),
-  [245] = {.entry = {.count = 1, .reusable = true}}, SHIFT(335),
-  [247] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
-  [249] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
-  [251] = {.entry = {.count = 1, .reusable = true}}, SHIFT(276),
-  [253] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
-  [255] = {.entry = {.count = 1, .reusable = true}}, SHIFT(213),
-  [257] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(82),
-  [260] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(242),
-  [263] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(237),
-  [266] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(335),
-  [269] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(124),
-  [272] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym___attribute_directive, 3, 0, 0),
-  [274] = {.entry = {.count = 1, .reusable = true}}, SHIFT(302),
-  [276] = {.entry = {.count = 1, .reusable = true}}, SHIFT(208),
-  [278] = {.entry = {.count = 1, .reusable = true}}, SHIFT(311),
-  [280] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_attribute_modifiers_repeat1, 2, 0, 0),
-  [282] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_attribute_modifiers_repeat1, 2, 0, 0), SHIFT_REPEAT(302),
-  [285] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute_modifiers, 1, 0, 0),
-  [287] = {.entry = {.count = 1, .reusable = true}}, SHIFT(298),
-  [289] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_attribute_modifiers_repeat1, 2, 0, 0), SHIFT_REPEAT(311),
-  [292] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 3, 2, 15),
-  [294] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_attribute, 3, 2, 15), SHIFT(259),
-  [297] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unquoted_attribute_value, 2, 3, 0),
-  [299] = {.entry = {.count = 1, .reusable = true}}, SHIFT(259),
-  [301] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_attribute_modifiers_repeat1, 2, 0, 0), SHIFT_REPEAT(298),
-  [304] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 1, 2, 5),
-  [306] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
-  [308] = {.entry = {.count = 1, .reusable = true}}, SHIFT(89),
-  [310] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute_name, 1, 0, 0),
-  [312] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_unquoted_attribute_value_repeat1, 2, 0, 0),
-  [314] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_unquoted_attribute_value_repeat1, 2, 0, 0), SHIFT_REPEAT(259),
-  [317] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym___attribute_directive, 4, 0, 0),
-  [319] = {.entry = {.count = 1, .reusable = true}}, SHIFT(263),
-  [321] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_attribute, 3, 2, 15), SHIFT(263),
-  [324] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_unquoted_attribute_value_repeat1, 2, 0, 0), SHIFT_REPEAT(263),
-  [327] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute_expression, 2, 0, 0),
-  [329] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__member_tag_name_repeat1, 2, 0, 14),
-  [331] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__member_tag_name_repeat1, 2, 0, 14), SHIFT_REPEAT(297),
-  [334] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute_expression, 3, 0, 9),
-  [336] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_unquoted_attribute_value_repeat1, 1, 0, 0),
-  [338] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
-  [340] = {.entry = {.count = 1, .reusable = true}}, SHIFT(211),
-  [342] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
-  [344] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__member_tag_name, 2, 0, 6),
-  [346] = {.entry = {.count = 1, .reusable = true}}, SHIFT(297),
-  [348] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
-  [350] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 4, 0, 16),
-  [352] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_quoted_attribute_value, 2, 0, 0),
-  [354] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_attribute, 3, 2, 15), SHIFT(262),
-  [357] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_quoted_attribute_value, 3, 0, 0),
-  [359] = {.entry = {.count = 1, .reusable = true}}, SHIFT(262),
-  [361] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__member_tag_name_repeat1, 2, 0, 13),
-  [363] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tag_comment, 1, 0, 3),
-  [365] = {.entry = {.count = 1, .reusable = true}}, SHIFT(210),
-  [367] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
-  [369] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_shorthand_attribute, 3, 0, 9),
-  [371] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 1, 0, 0),
-  [373] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_shorthand_attribute, 2, 0, 0),
-  [375] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 2, 3, 11),
-  [377] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_unquoted_attribute_value_repeat1, 2, 0, 0), SHIFT_REPEAT(262),
-  [380] = {.entry = {.count = 1, .reusable = true}}, SHIFT(162),
-  [382] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__namespaced_tag_name, 3, 0, 12),
-  [384] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__member_unterminated_start_tag, 3, 0, 8),
-  [386] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__member_unterminated_start_tag, 3, 0, 8),
-  [388] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 1, 0, 0),
-  [390] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 1, 0, 0),
-  [392] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 1, 0, 1),
-  [394] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 1, 0, 1),
-  [396] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 2, 0, 0),
-  [398] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 2, 0, 0),
-  [400] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 2, 0, 0),
-  [402] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 2, 0, 0),
-  [404] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__raw_text_element, 2, 0, 1),
-  [406] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__raw_text_element, 2, 0, 1),
-  [408] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 2, 0, 1),
-  [410] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 2, 0, 1),
-  [412] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 2, 0, 2),
-  [414] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 2, 0, 2),
-  [416] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__unterminated_start_tag, 3, 0, 4),
-  [418] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__unterminated_start_tag, 3, 0, 4),
-  [420] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_self_closing_tag, 3, 0, 4),
-  [422] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_self_closing_tag, 3, 0, 4),
-  [424] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__raw_text_unterminated_start_tag, 3, 0, 4),
-  [426] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__raw_text_unterminated_start_tag, 3, 0, 4),
-  [428] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__namespaced_unterminated_start_tag, 3, 0, 7),
-  [430] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__namespaced_unterminated_start_tag, 3, 0, 7),
-  [432] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__namespaced_self_closing_tag, 3, 0, 7),
-  [434] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__namespaced_self_closing_tag, 3, 0, 7),
-  [436] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__member_self_closing_tag, 3, 0, 8),
-  [438] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__member_self_closing_tag, 3, 0, 8),
-  [440] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_erroneous_end_tag, 3, 0, 0),
-  [442] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_erroneous_end_tag, 3, 0, 0),
-  [444] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 3, 0, 9),
-  [446] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 3, 0, 9),
-  [448] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 3, 0, 0),
-  [450] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 3, 0, 0),
-  [452] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_start_tag, 4, 0, 4),
-  [454] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_start_tag, 4, 0, 4),
-  [456] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__raw_text_element, 3, 0, 1),
-  [458] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__raw_text_element, 3, 0, 1),
-  [460] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 3, 0, 1),
-  [462] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 3, 0, 1),
-  [464] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 3, 0, 10),
-  [466] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 3, 0, 10),
-  [468] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 4, 0, 0),
-  [470] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 4, 0, 0),
-  [472] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__broken_member_unterminated_start_tag, 4, 0, 4),
-  [474] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__broken_member_unterminated_start_tag, 4, 0, 4),
-  [476] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__unterminated_start_tag, 4, 0, 4),
-  [478] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__unterminated_start_tag, 4, 0, 4),
-  [480] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_self_closing_tag, 4, 0, 4),
-  [482] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_self_closing_tag, 4, 0, 4),
-  [484] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__raw_text_unterminated_start_tag, 4, 0, 4),
-  [486] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__raw_text_unterminated_start_tag, 4, 0, 4),
-  [488] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__namespaced_unterminated_start_tag, 4, 0, 7),
-  [490] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__namespaced_unterminated_start_tag, 4, 0, 7),
-  [492] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__namespaced_self_closing_tag, 4, 0, 7),
-  [494] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__namespaced_self_closing_tag, 4, 0, 7),
-  [496] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__member_unterminated_start_tag, 4, 0, 8),
-  [498] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__member_unterminated_start_tag, 4, 0, 8),
-  [500] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__member_self_closing_tag, 4, 0, 8),
-  [502] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__member_self_closing_tag, 4, 0, 8),
-  [504] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_end_tag, 3, 0, 4),
-  [506] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_end_tag, 3, 0, 4),
-  [508] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 4, 0, 0),
-  [510] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 4, 0, 0),
-  [512] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__namespaced_end_tag, 3, 0, 7),
-  [514] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__namespaced_end_tag, 3, 0, 7),
-  [516] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__member_end_tag, 3, 0, 8),
-  [518] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__member_end_tag, 3, 0, 8),
-  [520] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_start_tag, 3, 0, 4),
-  [522] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_start_tag, 3, 0, 4),
-  [524] = {.entry = {.count = 1, .reusable = true}}, SHIFT(234),
-  [526] = {.entry = {.count = 1, .reusable = true}}, SHIFT(217),
-  [528] = {.entry = {.count = 1, .reusable = true}}, SHIFT(250),
-  [530] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
-  [532] = {.entry = {.count = 1, .reusable = true}}, SHIFT(225),
-  [534] = {.entry = {.count = 1, .reusable = true}}, SHIFT(226),
-  [536] = {.entry = {.count = 1, .reusable = true}}, SHIFT(248),
-  [538] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
-  [540] = {.entry = {.count = 1, .reusable = true}}, SHIFT(229),
-  [542] = {.entry = {.count = 1, .reusable = true}}, SHIFT(230),
-  [544] = {.entry = {.count = 1, .reusable = true}}, SHIFT(256),
-  [546] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
-  [548] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__namespaced_start_tag, 4, 0, 7),
-  [550] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__namespaced_start_tag, 4, 0, 7),
-  [552] = {.entry = {.count = 1, .reusable = false}}, SHIFT(74),
-  [554] = {.entry = {.count = 1, .reusable = false}}, SHIFT(260),
-  [556] = {.entry = {.count = 1, .reusable = true}}, SHIFT(222),
-  [558] = {.entry = {.count = 1, .reusable = false}}, SHIFT(261),
-  [560] = {.entry = {.count = 1, .reusable = true}}, SHIFT(223),
-  [562] = {.entry = {.count = 1, .reusable = false}}, SHIFT(71),
-  [564] = {.entry = {.count = 1, .reusable = true}}, SHIFT(216),
-  [566] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__member_start_tag, 4, 0, 8),
-  [568] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__member_start_tag, 4, 0, 8),
-  [570] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
-  [572] = {.entry = {.count = 1, .reusable = true}}, SHIFT(37),
-  [574] = {.entry = {.count = 1, .reusable = true}}, SHIFT(319),
-  [576] = {.entry = {.count = 1, .reusable = true}}, SHIFT(283),
-  [578] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__unterminated_start_tag_with_close, 4, 0, 4),
-  [580] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__unterminated_start_tag_with_close, 4, 0, 4),
-  [582] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__namespaced_start_tag, 3, 0, 7),
-  [584] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__namespaced_start_tag, 3, 0, 7),
-  [586] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_quoted_attribute_value_repeat1, 2, 0, 0),
-  [588] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_quoted_attribute_value_repeat1, 2, 0, 0), SHIFT_REPEAT(260),
-  [591] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_quoted_attribute_value_repeat1, 2, 0, 0), SHIFT_REPEAT(222),
-  [594] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_quoted_attribute_value_repeat2, 2, 0, 0),
-  [596] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_quoted_attribute_value_repeat2, 2, 0, 0), SHIFT_REPEAT(261),
-  [599] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_quoted_attribute_value_repeat2, 2, 0, 0), SHIFT_REPEAT(223),
-  [602] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__member_start_tag, 3, 0, 8),
-  [604] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__member_start_tag, 3, 0, 8),
-  [606] = {.entry = {.count = 1, .reusable = false}}, SHIFT(160),
-  [608] = {.entry = {.count = 1, .reusable = true}}, SHIFT(227),
-  [610] = {.entry = {.count = 1, .reusable = true}}, SHIFT(228),
-  [612] = {.entry = {.count = 1, .reusable = false}}, SHIFT(163),
-  [614] = {.entry = {.count = 1, .reusable = false}}, SHIFT(92),
-  [616] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
-  [618] = {.entry = {.count = 1, .reusable = true}}, SHIFT(232),
-  [620] = {.entry = {.count = 1, .reusable = false}}, SHIFT(93),
-  [622] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
-  [624] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
-  [626] = {.entry = {.count = 1, .reusable = true}}, SHIFT(215),
-  [628] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__unterminated_start_tag_with_close, 3, 0, 4),
-  [630] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__unterminated_start_tag_with_close, 3, 0, 4),
-  [632] = {.entry = {.count = 1, .reusable = true}}, SHIFT(300),
-  [634] = {.entry = {.count = 1, .reusable = true}}, SHIFT(339),
-  [636] = {.entry = {.count = 1, .reusable = true}}, SHIFT(317),
-  [638] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_attribute_modifiers_repeat1, 2, 0, 0), SHIFT_REPEAT(317),
-  [641] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
-  [643] = {.entry = {.count = 1, .reusable = true}}, SHIFT(292),
-  [645] = {.entry = {.count = 1, .reusable = true}}, SHIFT(321),
-  [647] = {.entry = {.count = 1, .reusable = true}}, SHIFT(330),
-  [649] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute_expression, 2, 0, 0),
-  [651] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute_expression, 3, 0, 9),
-  [653] = {.entry = {.count = 1, .reusable = true}}, SHIFT(161),
-  [655] = {.entry = {.count = 1, .reusable = true}}, SHIFT(301),
-  [657] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
-  [659] = {.entry = {.count = 1, .reusable = true}}, SHIFT(309),
-  [661] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
-  [663] = {.entry = {.count = 1, .reusable = true}}, SHIFT(305),
-  [665] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
-  [667] = {.entry = {.count = 1, .reusable = true}}, SHIFT(323),
-  [669] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__member_tag_name_repeat1, 2, 0, 14), SHIFT_REPEAT(331),
-  [672] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
-  [674] = {.entry = {.count = 1, .reusable = true}}, SHIFT(340),
-  [676] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
-  [678] = {.entry = {.count = 1, .reusable = true}}, SHIFT(343),
-  [680] = {.entry = {.count = 1, .reusable = true}}, SHIFT(332),
-  [682] = {.entry = {.count = 1, .reusable = true}}, SHIFT(273),
-  [684] = {.entry = {.count = 1, .reusable = true}}, SHIFT(331),
-  [686] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
-  [688] = {.entry = {.count = 1, .reusable = true}}, SHIFT(318),
-  [690] = {.entry = {.count = 1, .reusable = true}}, SHIFT(244),
-  [692] = {.entry = {.count = 1, .reusable = true}}, SHIFT(320),
-  [694] = {.entry = {.count = 1, .reusable = true}}, SHIFT(247),
-  [696] = {.entry = {.count = 1, .reusable = true}}, SHIFT(322),
-  [698] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
-  [700] = {.entry = {.count = 1, .reusable = true}}, SHIFT(324),
-  [702] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
-  [704] = {.entry = {.count = 1, .reusable = true}}, SHIFT(326),
-  [706] = {.entry = {.count = 1, .reusable = true}}, SHIFT(285),
-  [708] = {.entry = {.count = 1, .reusable = true}}, SHIFT(327),
-  [710] = {.entry = {.count = 1, .reusable = true}}, SHIFT(280),
-  [712] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
-  [714] = {.entry = {.count = 1, .reusable = true}}, SHIFT(333),
-  [716] = {.entry = {.count = 1, .reusable = true}}, SHIFT(287),
-  [718] = {.entry = {.count = 1, .reusable = true}}, SHIFT(342),
-  [720] = {.entry = {.count = 1, .reusable = true}}, SHIFT(334),
-  [722] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__raw_text_start_tag, 3, 0, 4),
-  [724] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__raw_text_start_tag, 4, 0, 4),
-  [726] = {.entry = {.count = 1, .reusable = true}}, SHIFT(329),
-  [728] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
-  [730] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
-  [732] = {.entry = {.count = 1, .reusable = true}}, SHIFT(154),
-  [734] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
-  [736] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
-  [738] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
-  [740] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
-  [742] = {.entry = {.count = 1, .reusable = true}}, SHIFT(172),
-  [744] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
-  [746] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
-  [748] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
-  [750] = {.entry = {.count = 1, .reusable = true}}, SHIFT(142),
-  [752] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
-  [754] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
-  [756] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
-  [758] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
-  [760] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
-  [762] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
-  [764] = {.entry = {.count = 1, .reusable = true}}, SHIFT(314),
-  [766] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
-  [768] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
-  [770] = {.entry = {.count = 1, .reusable = true}}, SHIFT(316),
-  [772] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
-  [774] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
-  [776] = {.entry = {.count = 1, .reusable = true}}, SHIFT(306),
-  [778] = {.entry = {.count = 1, .reusable = true}}, SHIFT(239),
-  [780] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
-  [782] = {.entry = {.count = 1, .reusable = true}}, SHIFT(278),
-  [784] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
-  [786] = {.entry = {.count = 1, .reusable = true}}, SHIFT(294),
-  [788] = {.entry = {.count = 1, .reusable = true}}, SHIFT(246),
-  [790] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
-  [792] = {.entry = {.count = 1, .reusable = true}}, SHIFT(249),
-  [794] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
-  [796] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
-  [798] = {.entry = {.count = 1, .reusable = true}}, SHIFT(313),
-  [800] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
-  [802] = {.entry = {.count = 1, .reusable = true}}, SHIFT(296),
-  [804] = {.entry = {.count = 1, .reusable = true}}, SHIFT(325),
-  [806] = {.entry = {.count = 1, .reusable = true}}, SHIFT(282),
-  [808] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
-  [810] = {.entry = {.count = 1, .reusable = true}}, SHIFT(169),
-  [812] = {.entry = {.count = 1, .reusable = true}}, SHIFT(291),
-  [814] = {.entry = {.count = 1, .reusable = true}}, SHIFT(245),
-  [816] = {.entry = {.count = 1, .reusable = true}}, SHIFT(304),
-  [818] = {.entry = {.count = 1, .reusable = true}}, SHIFT(253),
-  [820] = {.entry = {.count = 1, .reusable = true}}, SHIFT(315),
-  [822] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
-  [824] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
-  [826] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(475),
+  [9] = {.entry = {.count = 1, .reusable = false}}, SHIFT(358),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(476),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
+  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(396),
+  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(541),
+  [19] = {.entry = {.count = 1, .reusable = false}}, SHIFT(370),
+  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(442),
+  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
+  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(414),
+  [27] = {.entry = {.count = 1, .reusable = true}}, SHIFT(301),
+  [29] = {.entry = {.count = 1, .reusable = true}}, SHIFT(302),
+  [31] = {.entry = {.count = 1, .reusable = true}}, SHIFT(441),
+  [33] = {.entry = {.count = 1, .reusable = true}}, SHIFT(547),
+  [35] = {.entry = {.count = 1, .reusable = false}}, SHIFT(371),
+  [37] = {.entry = {.count = 1, .reusable = true}}, SHIFT(470),
+  [39] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
+  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(422),
+  [43] = {.entry = {.count = 1, .reusable = true}}, SHIFT(305),
+  [45] = {.entry = {.count = 1, .reusable = true}}, SHIFT(306),
+  [47] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
+  [49] = {.entry = {.count = 1, .reusable = true}}, SHIFT(325),
+  [51] = {.entry = {.count = 1, .reusable = true}}, SHIFT(326),
+  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(446),
+  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(471),
+  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
+  [59] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
+  [61] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
+  [63] = {.entry = {.count = 1, .reusable = true}}, SHIFT(454),
+  [65] = {.entry = {.count = 1, .reusable = true}}, SHIFT(472),
+  [67] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
+  [69] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
+  [71] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
+  [73] = {.entry = {.count = 1, .reusable = true}}, SHIFT(161),
+  [75] = {.entry = {.count = 1, .reusable = true}}, SHIFT(458),
+  [77] = {.entry = {.count = 1, .reusable = true}}, SHIFT(473),
+  [79] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
+  [81] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
+  [83] = {.entry = {.count = 1, .reusable = true}}, SHIFT(234),
+  [85] = {.entry = {.count = 1, .reusable = true}}, SHIFT(462),
+  [87] = {.entry = {.count = 1, .reusable = true}}, SHIFT(474),
+  [89] = {.entry = {.count = 1, .reusable = true}}, SHIFT(237),
+  [91] = {.entry = {.count = 1, .reusable = true}}, SHIFT(238),
+  [93] = {.entry = {.count = 1, .reusable = true}}, SHIFT(257),
+  [95] = {.entry = {.count = 1, .reusable = true}}, SHIFT(258),
+  [97] = {.entry = {.count = 1, .reusable = true}}, SHIFT(466),
+  [99] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(541),
+  [102] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(370),
+  [105] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(548),
+  [108] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(11),
+  [111] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(414),
+  [114] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0),
+  [116] = {.entry = {.count = 1, .reusable = true}}, SHIFT(455),
+  [118] = {.entry = {.count = 1, .reusable = true}}, SHIFT(37),
+  [120] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_template_content, 1, 0, 0), SHIFT(542),
+  [123] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
+  [125] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_content, 1, 0, 0),
+  [127] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(547),
+  [130] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(371),
+  [133] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(542),
+  [136] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(14),
+  [139] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(422),
+  [142] = {.entry = {.count = 1, .reusable = true}}, SHIFT(463),
+  [144] = {.entry = {.count = 1, .reusable = true}}, SHIFT(469),
+  [146] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
+  [148] = {.entry = {.count = 1, .reusable = true}}, SHIFT(398),
+  [150] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
+  [152] = {.entry = {.count = 1, .reusable = true}}, SHIFT(399),
+  [154] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
+  [156] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_document, 1, 0, 0),
+  [158] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
+  [160] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(475),
+  [163] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(358),
+  [166] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(476),
+  [169] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(24),
+  [172] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(396),
+  [175] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
+  [177] = {.entry = {.count = 1, .reusable = true}}, SHIFT(435),
+  [179] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
+  [181] = {.entry = {.count = 1, .reusable = true}}, SHIFT(436),
+  [183] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
+  [185] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
+  [187] = {.entry = {.count = 1, .reusable = true}}, SHIFT(437),
+  [189] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
+  [191] = {.entry = {.count = 1, .reusable = true}}, SHIFT(438),
+  [193] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
+  [195] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_template_content, 1, 0, 0), SHIFT(476),
+  [198] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
+  [200] = {.entry = {.count = 1, .reusable = true}}, SHIFT(314),
+  [202] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
+  [204] = {.entry = {.count = 1, .reusable = true}}, SHIFT(483),
+  [206] = {.entry = {.count = 1, .reusable = true}}, SHIFT(400),
+  [208] = {.entry = {.count = 1, .reusable = true}}, SHIFT(386),
+  [210] = {.entry = {.count = 1, .reusable = true}}, SHIFT(482),
+  [212] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
+  [214] = {.entry = {.count = 1, .reusable = true}}, SHIFT(313),
+  [216] = {.entry = {.count = 1, .reusable = true}}, SHIFT(359),
+  [218] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
+  [220] = {.entry = {.count = 1, .reusable = true}}, SHIFT(504),
+  [222] = {.entry = {.count = 1, .reusable = true}}, SHIFT(148),
+  [224] = {.entry = {.count = 1, .reusable = true}}, SHIFT(246),
+  [226] = {.entry = {.count = 1, .reusable = true}}, SHIFT(521),
+  [228] = {.entry = {.count = 1, .reusable = true}}, SHIFT(245),
+  [230] = {.entry = {.count = 1, .reusable = true}}, SHIFT(208),
+  [232] = {.entry = {.count = 1, .reusable = true}}, SHIFT(317),
+  [234] = {.entry = {.count = 1, .reusable = true}}, SHIFT(316),
+  [236] = {.entry = {.count = 1, .reusable = true}}, SHIFT(360),
+  [238] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
+  [240] = {.entry = {.count = 1, .reusable = true}}, SHIFT(338),
+  [242] = {.entry = {.count = 1, .reusable = true}}, SHIFT(337),
+  [244] = {.entry = {.count = 1, .reusable = true}}, SHIFT(364),
+  [246] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
+  [248] = {.entry = {.count = 1, .reusable = true}}, SHIFT(341),
+  [250] = {.entry = {.count = 1, .reusable = true}}, SHIFT(340),
+  [252] = {.entry = {.count = 1, .reusable = true}}, SHIFT(365),
+  [254] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0),
+  [256] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(79),
+  [259] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(400),
+  [262] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(386),
+  [265] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(482),
+  [268] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(100),
+  [271] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
+  [273] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
+  [275] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
+  [277] = {.entry = {.count = 1, .reusable = true}}, SHIFT(172),
+  [279] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
+  [281] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
+  [283] = {.entry = {.count = 1, .reusable = true}}, SHIFT(249),
+  [285] = {.entry = {.count = 1, .reusable = true}}, SHIFT(248),
+  [287] = {.entry = {.count = 1, .reusable = true}}, SHIFT(270),
+  [289] = {.entry = {.count = 1, .reusable = true}}, SHIFT(269),
+  [291] = {.entry = {.count = 1, .reusable = true}}, SHIFT(273),
+  [293] = {.entry = {.count = 1, .reusable = true}}, SHIFT(272),
+  [295] = {.entry = {.count = 1, .reusable = true}}, SHIFT(361),
+  [297] = {.entry = {.count = 1, .reusable = true}}, SHIFT(319),
+  [299] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [301] = {.entry = {.count = 1, .reusable = true}}, SHIFT(424),
+  [303] = {.entry = {.count = 1, .reusable = true}}, SHIFT(388),
+  [305] = {.entry = {.count = 1, .reusable = true}}, SHIFT(550),
+  [307] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
+  [309] = {.entry = {.count = 1, .reusable = true}}, SHIFT(318),
+  [311] = {.entry = {.count = 1, .reusable = true}}, SHIFT(362),
+  [313] = {.entry = {.count = 1, .reusable = true}}, SHIFT(321),
+  [315] = {.entry = {.count = 1, .reusable = true}}, SHIFT(320),
+  [317] = {.entry = {.count = 1, .reusable = true}}, SHIFT(366),
+  [319] = {.entry = {.count = 1, .reusable = true}}, SHIFT(343),
+  [321] = {.entry = {.count = 1, .reusable = true}}, SHIFT(342),
+  [323] = {.entry = {.count = 1, .reusable = true}}, SHIFT(367),
+  [325] = {.entry = {.count = 1, .reusable = true}}, SHIFT(345),
+  [327] = {.entry = {.count = 1, .reusable = true}}, SHIFT(344),
+  [329] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(92),
+  [332] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(424),
+  [335] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(388),
+  [338] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(550),
+  [341] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(194),
+  [344] = {.entry = {.count = 1, .reusable = true}}, SHIFT(154),
+  [346] = {.entry = {.count = 1, .reusable = true}}, SHIFT(153),
+  [348] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [350] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
+  [352] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
+  [354] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
+  [356] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
+  [358] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
+  [360] = {.entry = {.count = 1, .reusable = true}}, SHIFT(251),
+  [362] = {.entry = {.count = 1, .reusable = true}}, SHIFT(250),
+  [364] = {.entry = {.count = 1, .reusable = true}}, SHIFT(253),
+  [366] = {.entry = {.count = 1, .reusable = true}}, SHIFT(252),
+  [368] = {.entry = {.count = 1, .reusable = true}}, SHIFT(275),
+  [370] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
+  [372] = {.entry = {.count = 1, .reusable = true}}, SHIFT(277),
+  [374] = {.entry = {.count = 1, .reusable = true}}, SHIFT(276),
+  [376] = {.entry = {.count = 1, .reusable = true}}, SHIFT(445),
+  [378] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
+  [380] = {.entry = {.count = 1, .reusable = true}}, SHIFT(416),
+  [382] = {.entry = {.count = 1, .reusable = true}}, SHIFT(387),
+  [384] = {.entry = {.count = 1, .reusable = true}}, SHIFT(545),
+  [386] = {.entry = {.count = 1, .reusable = true}}, SHIFT(210),
+  [388] = {.entry = {.count = 1, .reusable = true}}, SHIFT(315),
+  [390] = {.entry = {.count = 1, .reusable = true}}, SHIFT(447),
+  [392] = {.entry = {.count = 1, .reusable = true}}, SHIFT(339),
+  [394] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym___attribute_directive, 3, 0, 0),
+  [396] = {.entry = {.count = 1, .reusable = true}}, SHIFT(496),
+  [398] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(113),
+  [401] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(416),
+  [404] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(387),
+  [407] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(545),
+  [410] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(210),
+  [413] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
+  [415] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
+  [417] = {.entry = {.count = 1, .reusable = true}}, SHIFT(247),
+  [419] = {.entry = {.count = 1, .reusable = true}}, SHIFT(271),
+  [421] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute_modifiers, 1, 0, 0),
+  [423] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_attribute_modifiers_repeat1, 2, 0, 0),
+  [425] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_attribute_modifiers_repeat1, 2, 0, 0), SHIFT_REPEAT(496),
+  [428] = {.entry = {.count = 1, .reusable = true}}, SHIFT(531),
+  [430] = {.entry = {.count = 1, .reusable = true}}, SHIFT(516),
+  [432] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_attribute_modifiers_repeat1, 2, 0, 0), SHIFT_REPEAT(531),
+  [435] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute_name, 1, 0, 0),
+  [437] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 1, 2, 5),
+  [439] = {.entry = {.count = 1, .reusable = true}}, SHIFT(363),
+  [441] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [443] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 3, 2, 16),
+  [445] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_attribute, 3, 2, 16), SHIFT(430),
+  [448] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym___attribute_directive, 4, 0, 0),
+  [450] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unquoted_attribute_value, 2, 3, 0),
+  [452] = {.entry = {.count = 1, .reusable = true}}, SHIFT(430),
+  [454] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_unquoted_attribute_value_repeat1, 2, 0, 0),
+  [456] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_unquoted_attribute_value_repeat1, 2, 0, 0), SHIFT_REPEAT(430),
+  [459] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_attribute_modifiers_repeat1, 2, 0, 0), SHIFT_REPEAT(516),
+  [462] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__member_tag_name, 2, 0, 6),
+  [464] = {.entry = {.count = 1, .reusable = true}}, SHIFT(485),
+  [466] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__member_tag_name_repeat1, 2, 0, 15),
+  [468] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__member_tag_name_repeat1, 2, 0, 15), SHIFT_REPEAT(485),
+  [471] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_unquoted_attribute_value_repeat1, 1, 0, 0),
+  [473] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+  [475] = {.entry = {.count = 1, .reusable = true}}, SHIFT(369),
+  [477] = {.entry = {.count = 1, .reusable = true}}, SHIFT(197),
+  [479] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_attribute, 3, 2, 16), SHIFT(434),
+  [482] = {.entry = {.count = 1, .reusable = true}}, SHIFT(434),
+  [484] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_unquoted_attribute_value_repeat1, 2, 0, 0), SHIFT_REPEAT(434),
+  [487] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute_expression, 2, 0, 0),
+  [489] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute_expression, 3, 0, 9),
+  [491] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tag_comment, 1, 0, 3),
+  [493] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 1, 0, 0),
+  [495] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_shorthand_attribute, 2, 0, 0),
+  [497] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 2, 3, 12),
+  [499] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__member_tag_name_repeat1, 2, 0, 14),
+  [501] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_shorthand_attribute, 3, 0, 9),
+  [503] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 4, 0, 19),
+  [505] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_quoted_attribute_value, 2, 0, 0),
+  [507] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_quoted_attribute_value, 3, 0, 0),
+  [509] = {.entry = {.count = 1, .reusable = true}}, SHIFT(368),
+  [511] = {.entry = {.count = 1, .reusable = true}}, SHIFT(213),
+  [513] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_attribute, 3, 2, 16), SHIFT(433),
+  [516] = {.entry = {.count = 1, .reusable = true}}, SHIFT(433),
+  [518] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_unquoted_attribute_value_repeat1, 2, 0, 0), SHIFT_REPEAT(433),
+  [521] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
+  [523] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_start_tag, 3, 0, 4),
+  [525] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_start_tag, 3, 0, 4),
+  [527] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_start_tag, 4, 0, 4),
+  [529] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_start_tag, 4, 0, 4),
+  [531] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__namespaced_tag_name, 3, 0, 13),
+  [533] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 1, 0, 0),
+  [535] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 1, 0, 0),
+  [537] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 1, 0, 1),
+  [539] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 1, 0, 1),
+  [541] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 2, 0, 0),
+  [543] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 2, 0, 0),
+  [545] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 2, 0, 0),
+  [547] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 2, 0, 0),
+  [549] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__raw_text_element, 2, 0, 1),
+  [551] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__raw_text_element, 2, 0, 1),
+  [553] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__template_element, 2, 0, 1),
+  [555] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__template_element, 2, 0, 1),
+  [557] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 2, 0, 1),
+  [559] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 2, 0, 1),
+  [561] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 2, 0, 2),
+  [563] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 2, 0, 2),
+  [565] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 3, 0, 0),
+  [567] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 3, 0, 0),
+  [569] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__unterminated_start_tag, 3, 0, 4),
+  [571] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__unterminated_start_tag, 3, 0, 4),
+  [573] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_self_closing_tag, 3, 0, 4),
+  [575] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_self_closing_tag, 3, 0, 4),
+  [577] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__raw_text_unterminated_start_tag, 3, 0, 4),
+  [579] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__raw_text_unterminated_start_tag, 3, 0, 4),
+  [581] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__template_unterminated_start_tag, 3, 0, 4),
+  [583] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__template_unterminated_start_tag, 3, 0, 4),
+  [585] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__template_self_closing_tag, 3, 0, 4),
+  [587] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__template_self_closing_tag, 3, 0, 4),
+  [589] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__namespaced_unterminated_start_tag, 3, 0, 7),
+  [591] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__namespaced_unterminated_start_tag, 3, 0, 7),
+  [593] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__namespaced_self_closing_tag, 3, 0, 7),
+  [595] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__namespaced_self_closing_tag, 3, 0, 7),
+  [597] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__member_unterminated_start_tag, 3, 0, 8),
+  [599] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__member_unterminated_start_tag, 3, 0, 8),
+  [601] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__member_self_closing_tag, 3, 0, 8),
+  [603] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__member_self_closing_tag, 3, 0, 8),
+  [605] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_erroneous_end_tag, 3, 0, 0),
+  [607] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_erroneous_end_tag, 3, 0, 0),
+  [609] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 3, 0, 9),
+  [611] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 3, 0, 9),
+  [613] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 3, 0, 0),
+  [615] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 3, 0, 0),
+  [617] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__raw_text_element, 3, 0, 1),
+  [619] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__raw_text_element, 3, 0, 1),
+  [621] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__template_element, 3, 0, 1),
+  [623] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__template_element, 3, 0, 1),
+  [625] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 3, 0, 1),
+  [627] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 3, 0, 1),
+  [629] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 3, 0, 10),
+  [631] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 3, 0, 10),
+  [633] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 4, 0, 11),
+  [635] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 4, 0, 11),
+  [637] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__broken_member_unterminated_start_tag, 4, 0, 4),
+  [639] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__broken_member_unterminated_start_tag, 4, 0, 4),
+  [641] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__unterminated_start_tag, 4, 0, 4),
+  [643] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__unterminated_start_tag, 4, 0, 4),
+  [645] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_self_closing_tag, 4, 0, 4),
+  [647] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_self_closing_tag, 4, 0, 4),
+  [649] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__raw_text_unterminated_start_tag, 4, 0, 4),
+  [651] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__raw_text_unterminated_start_tag, 4, 0, 4),
+  [653] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__template_unterminated_start_tag, 4, 0, 4),
+  [655] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__template_unterminated_start_tag, 4, 0, 4),
+  [657] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__template_self_closing_tag, 4, 0, 4),
+  [659] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__template_self_closing_tag, 4, 0, 4),
+  [661] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__namespaced_unterminated_start_tag, 4, 0, 7),
+  [663] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__namespaced_unterminated_start_tag, 4, 0, 7),
+  [665] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__namespaced_self_closing_tag, 4, 0, 7),
+  [667] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__namespaced_self_closing_tag, 4, 0, 7),
+  [669] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__member_unterminated_start_tag, 4, 0, 8),
+  [671] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__member_unterminated_start_tag, 4, 0, 8),
+  [673] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__member_self_closing_tag, 4, 0, 8),
+  [675] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__member_self_closing_tag, 4, 0, 8),
+  [677] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_end_tag, 3, 0, 4),
+  [679] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_end_tag, 3, 0, 4),
+  [681] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 4, 0, 0),
+  [683] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 4, 0, 0),
+  [685] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__namespaced_end_tag, 3, 0, 7),
+  [687] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__namespaced_end_tag, 3, 0, 7),
+  [689] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__member_end_tag, 3, 0, 8),
+  [691] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__member_end_tag, 3, 0, 8),
+  [693] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 5, 0, 11),
+  [695] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 5, 0, 11),
+  [697] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 6, 0, 17),
+  [699] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 6, 0, 17),
+  [701] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 6, 0, 11),
+  [703] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 6, 0, 11),
+  [705] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 6, 0, 18),
+  [707] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 6, 0, 18),
+  [709] = {.entry = {.count = 1, .reusable = true}}, SHIFT(219),
+  [711] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 7, 0, 20),
+  [713] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 7, 0, 20),
+  [715] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 7, 0, 17),
+  [717] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 7, 0, 17),
+  [719] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 7, 0, 18),
+  [721] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 7, 0, 18),
+  [723] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 8, 0, 20),
+  [725] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 8, 0, 20),
+  [727] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__template_start_tag, 3, 0, 4),
+  [729] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__template_start_tag, 3, 0, 4),
+  [731] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__template_start_tag, 4, 0, 4),
+  [733] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__template_start_tag, 4, 0, 4),
+  [735] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
+  [737] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
+  [739] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
+  [741] = {.entry = {.count = 1, .reusable = true}}, SHIFT(478),
+  [743] = {.entry = {.count = 1, .reusable = true}}, SHIFT(440),
+  [745] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__unterminated_start_tag_with_close, 3, 0, 4),
+  [747] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__unterminated_start_tag_with_close, 3, 0, 4),
+  [749] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__template_unterminated_start_tag_with_close, 3, 0, 4),
+  [751] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__template_unterminated_start_tag_with_close, 3, 0, 4),
+  [753] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__namespaced_start_tag, 3, 0, 7),
+  [755] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__namespaced_start_tag, 3, 0, 7),
+  [757] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__member_start_tag, 3, 0, 8),
+  [759] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__member_start_tag, 3, 0, 8),
+  [761] = {.entry = {.count = 1, .reusable = true}}, SHIFT(372),
+  [763] = {.entry = {.count = 1, .reusable = true}}, SHIFT(373),
+  [765] = {.entry = {.count = 1, .reusable = true}}, SHIFT(405),
+  [767] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
+  [769] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__unterminated_start_tag_with_close, 4, 0, 4),
+  [771] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__unterminated_start_tag_with_close, 4, 0, 4),
+  [773] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__template_unterminated_start_tag_with_close, 4, 0, 4),
+  [775] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__template_unterminated_start_tag_with_close, 4, 0, 4),
+  [777] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__namespaced_start_tag, 4, 0, 7),
+  [779] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__namespaced_start_tag, 4, 0, 7),
+  [781] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__member_start_tag, 4, 0, 8),
+  [783] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__member_start_tag, 4, 0, 8),
+  [785] = {.entry = {.count = 1, .reusable = true}}, SHIFT(378),
+  [787] = {.entry = {.count = 1, .reusable = true}}, SHIFT(379),
+  [789] = {.entry = {.count = 1, .reusable = true}}, SHIFT(421),
+  [791] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
+  [793] = {.entry = {.count = 1, .reusable = true}}, SHIFT(382),
+  [795] = {.entry = {.count = 1, .reusable = true}}, SHIFT(383),
+  [797] = {.entry = {.count = 1, .reusable = true}}, SHIFT(429),
+  [799] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [801] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
+  [803] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
+  [805] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
+  [807] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
+  [809] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [811] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
+  [813] = {.entry = {.count = 1, .reusable = false}}, SHIFT(108),
+  [815] = {.entry = {.count = 1, .reusable = false}}, SHIFT(431),
+  [817] = {.entry = {.count = 1, .reusable = true}}, SHIFT(374),
+  [819] = {.entry = {.count = 1, .reusable = false}}, SHIFT(432),
+  [821] = {.entry = {.count = 1, .reusable = true}}, SHIFT(375),
+  [823] = {.entry = {.count = 1, .reusable = false}}, SHIFT(111),
+  [825] = {.entry = {.count = 1, .reusable = true}}, SHIFT(376),
+  [827] = {.entry = {.count = 1, .reusable = true}}, SHIFT(377),
+  [829] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_quoted_attribute_value_repeat1, 2, 0, 0),
+  [831] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_quoted_attribute_value_repeat1, 2, 0, 0), SHIFT_REPEAT(431),
+  [834] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_quoted_attribute_value_repeat1, 2, 0, 0), SHIFT_REPEAT(376),
+  [837] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_quoted_attribute_value_repeat2, 2, 0, 0),
+  [839] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_quoted_attribute_value_repeat2, 2, 0, 0), SHIFT_REPEAT(432),
+  [842] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_quoted_attribute_value_repeat2, 2, 0, 0), SHIFT_REPEAT(377),
+  [845] = {.entry = {.count = 1, .reusable = false}}, SHIFT(217),
+  [847] = {.entry = {.count = 1, .reusable = true}}, SHIFT(380),
+  [849] = {.entry = {.count = 1, .reusable = true}}, SHIFT(381),
+  [851] = {.entry = {.count = 1, .reusable = false}}, SHIFT(220),
+  [853] = {.entry = {.count = 1, .reusable = false}}, SHIFT(201),
+  [855] = {.entry = {.count = 1, .reusable = true}}, SHIFT(384),
+  [857] = {.entry = {.count = 1, .reusable = true}}, SHIFT(385),
+  [859] = {.entry = {.count = 1, .reusable = false}}, SHIFT(204),
+  [861] = {.entry = {.count = 1, .reusable = true}}, SHIFT(500),
+  [863] = {.entry = {.count = 1, .reusable = true}}, SHIFT(552),
+  [865] = {.entry = {.count = 1, .reusable = true}}, SHIFT(481),
+  [867] = {.entry = {.count = 1, .reusable = true}}, SHIFT(328),
+  [869] = {.entry = {.count = 1, .reusable = true}}, SHIFT(329),
+  [871] = {.entry = {.count = 1, .reusable = false}}, SHIFT(335),
+  [873] = {.entry = {.count = 1, .reusable = false}}, SHIFT(401),
+  [875] = {.entry = {.count = 1, .reusable = false}}, SHIFT(402),
+  [877] = {.entry = {.count = 1, .reusable = false}}, SHIFT(489),
+  [879] = {.entry = {.count = 1, .reusable = true}}, SHIFT(535),
+  [881] = {.entry = {.count = 1, .reusable = true}}, SHIFT(544),
+  [883] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
+  [885] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
+  [887] = {.entry = {.count = 1, .reusable = false}}, SHIFT(170),
+  [889] = {.entry = {.count = 1, .reusable = false}}, SHIFT(417),
+  [891] = {.entry = {.count = 1, .reusable = false}}, SHIFT(418),
+  [893] = {.entry = {.count = 1, .reusable = false}}, SHIFT(510),
+  [895] = {.entry = {.count = 1, .reusable = true}}, SHIFT(549),
+  [897] = {.entry = {.count = 1, .reusable = true}}, SHIFT(260),
+  [899] = {.entry = {.count = 1, .reusable = true}}, SHIFT(261),
+  [901] = {.entry = {.count = 1, .reusable = false}}, SHIFT(267),
+  [903] = {.entry = {.count = 1, .reusable = false}}, SHIFT(425),
+  [905] = {.entry = {.count = 1, .reusable = false}}, SHIFT(426),
+  [907] = {.entry = {.count = 1, .reusable = false}}, SHIFT(525),
+  [909] = {.entry = {.count = 1, .reusable = true}}, SHIFT(300),
+  [911] = {.entry = {.count = 1, .reusable = true}}, SHIFT(480),
+  [913] = {.entry = {.count = 1, .reusable = true}}, SHIFT(443),
+  [915] = {.entry = {.count = 1, .reusable = true}}, SHIFT(479),
+  [917] = {.entry = {.count = 1, .reusable = true}}, SHIFT(543),
+  [919] = {.entry = {.count = 1, .reusable = true}}, SHIFT(453),
+  [921] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [923] = {.entry = {.count = 1, .reusable = true}}, SHIFT(492),
+  [925] = {.entry = {.count = 1, .reusable = false}}, SHIFT(350),
+  [927] = {.entry = {.count = 1, .reusable = false}}, SHIFT(403),
+  [929] = {.entry = {.count = 1, .reusable = false}}, SHIFT(493),
+  [931] = {.entry = {.count = 1, .reusable = false}}, SHIFT(448),
+  [933] = {.entry = {.count = 1, .reusable = false}}, SHIFT(351),
+  [935] = {.entry = {.count = 1, .reusable = false}}, SHIFT(449),
+  [937] = {.entry = {.count = 1, .reusable = false}}, SHIFT(494),
+  [939] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
+  [941] = {.entry = {.count = 1, .reusable = true}}, SHIFT(497),
+  [943] = {.entry = {.count = 1, .reusable = true}}, SHIFT(506),
+  [945] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__member_tag_name_repeat1, 2, 0, 15), SHIFT_REPEAT(506),
+  [948] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_attribute_modifiers_repeat1, 2, 0, 0), SHIFT_REPEAT(535),
+  [951] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute_expression, 2, 0, 0),
+  [953] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute_expression, 3, 0, 9),
+  [955] = {.entry = {.count = 1, .reusable = true}}, SHIFT(135),
+  [957] = {.entry = {.count = 1, .reusable = true}}, SHIFT(503),
+  [959] = {.entry = {.count = 1, .reusable = true}}, SHIFT(456),
+  [961] = {.entry = {.count = 1, .reusable = true}}, SHIFT(212),
+  [963] = {.entry = {.count = 1, .reusable = true}}, SHIFT(512),
+  [965] = {.entry = {.count = 1, .reusable = false}}, SHIFT(185),
+  [967] = {.entry = {.count = 1, .reusable = false}}, SHIFT(419),
+  [969] = {.entry = {.count = 1, .reusable = false}}, SHIFT(513),
+  [971] = {.entry = {.count = 1, .reusable = false}}, SHIFT(459),
+  [973] = {.entry = {.count = 1, .reusable = false}}, SHIFT(186),
+  [975] = {.entry = {.count = 1, .reusable = false}}, SHIFT(460),
+  [977] = {.entry = {.count = 1, .reusable = false}}, SHIFT(514),
+  [979] = {.entry = {.count = 1, .reusable = true}}, SHIFT(218),
+  [981] = {.entry = {.count = 1, .reusable = true}}, SHIFT(517),
+  [983] = {.entry = {.count = 1, .reusable = true}}, SHIFT(232),
+  [985] = {.entry = {.count = 1, .reusable = true}}, SHIFT(520),
+  [987] = {.entry = {.count = 1, .reusable = true}}, SHIFT(464),
+  [989] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
+  [991] = {.entry = {.count = 1, .reusable = true}}, SHIFT(527),
+  [993] = {.entry = {.count = 1, .reusable = false}}, SHIFT(282),
+  [995] = {.entry = {.count = 1, .reusable = false}}, SHIFT(427),
+  [997] = {.entry = {.count = 1, .reusable = false}}, SHIFT(528),
+  [999] = {.entry = {.count = 1, .reusable = false}}, SHIFT(467),
+  [1001] = {.entry = {.count = 1, .reusable = false}}, SHIFT(283),
+  [1003] = {.entry = {.count = 1, .reusable = false}}, SHIFT(468),
+  [1005] = {.entry = {.count = 1, .reusable = false}}, SHIFT(529),
+  [1007] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
+  [1009] = {.entry = {.count = 1, .reusable = true}}, SHIFT(532),
+  [1011] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
+  [1013] = {.entry = {.count = 1, .reusable = true}}, SHIFT(536),
+  [1015] = {.entry = {.count = 1, .reusable = true}}, SHIFT(410),
+  [1017] = {.entry = {.count = 1, .reusable = true}}, SHIFT(537),
+  [1019] = {.entry = {.count = 1, .reusable = true}}, SHIFT(412),
+  [1021] = {.entry = {.count = 1, .reusable = true}}, SHIFT(538),
+  [1023] = {.entry = {.count = 1, .reusable = true}}, SHIFT(206),
+  [1025] = {.entry = {.count = 1, .reusable = true}}, SHIFT(539),
+  [1027] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [1029] = {.entry = {.count = 1, .reusable = true}}, SHIFT(540),
+  [1031] = {.entry = {.count = 1, .reusable = false}}, SHIFT(312),
+  [1033] = {.entry = {.count = 1, .reusable = true}}, SHIFT(390),
+  [1035] = {.entry = {.count = 1, .reusable = true}}, SHIFT(486),
+  [1037] = {.entry = {.count = 1, .reusable = true}}, SHIFT(502),
+  [1039] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__raw_text_start_tag, 3, 0, 4),
+  [1041] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__raw_text_start_tag, 4, 0, 4),
+  [1043] = {.entry = {.count = 1, .reusable = false}}, SHIFT(353),
+  [1045] = {.entry = {.count = 1, .reusable = true}}, SHIFT(495),
+  [1047] = {.entry = {.count = 1, .reusable = false}}, SHIFT(354),
+  [1049] = {.entry = {.count = 1, .reusable = true}}, SHIFT(498),
+  [1051] = {.entry = {.count = 1, .reusable = false}}, SHIFT(147),
+  [1053] = {.entry = {.count = 1, .reusable = true}}, SHIFT(393),
+  [1055] = {.entry = {.count = 1, .reusable = false}}, SHIFT(188),
+  [1057] = {.entry = {.count = 1, .reusable = true}}, SHIFT(515),
+  [1059] = {.entry = {.count = 1, .reusable = false}}, SHIFT(190),
+  [1061] = {.entry = {.count = 1, .reusable = true}}, SHIFT(518),
+  [1063] = {.entry = {.count = 1, .reusable = false}}, SHIFT(244),
+  [1065] = {.entry = {.count = 1, .reusable = true}}, SHIFT(395),
+  [1067] = {.entry = {.count = 1, .reusable = true}}, SHIFT(507),
+  [1069] = {.entry = {.count = 1, .reusable = false}}, SHIFT(285),
+  [1071] = {.entry = {.count = 1, .reusable = true}}, SHIFT(530),
+  [1073] = {.entry = {.count = 1, .reusable = false}}, SHIFT(286),
+  [1075] = {.entry = {.count = 1, .reusable = true}}, SHIFT(533),
+  [1077] = {.entry = {.count = 1, .reusable = true}}, SHIFT(522),
+  [1079] = {.entry = {.count = 1, .reusable = true}}, SHIFT(519),
+  [1081] = {.entry = {.count = 1, .reusable = true}}, SHIFT(439),
+  [1083] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [1085] = {.entry = {.count = 1, .reusable = true}}, SHIFT(484),
+  [1087] = {.entry = {.count = 1, .reusable = true}}, SHIFT(322),
+  [1089] = {.entry = {.count = 1, .reusable = true}}, SHIFT(323),
+  [1091] = {.entry = {.count = 1, .reusable = true}}, SHIFT(491),
+  [1093] = {.entry = {.count = 1, .reusable = true}}, SHIFT(336),
+  [1095] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
+  [1097] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
+  [1099] = {.entry = {.count = 1, .reusable = true}}, SHIFT(346),
+  [1101] = {.entry = {.count = 1, .reusable = true}}, SHIFT(348),
+  [1103] = {.entry = {.count = 1, .reusable = true}}, SHIFT(349),
+  [1105] = {.entry = {.count = 1, .reusable = true}}, SHIFT(350),
+  [1107] = {.entry = {.count = 1, .reusable = true}}, SHIFT(404),
+  [1109] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
+  [1111] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
+  [1113] = {.entry = {.count = 1, .reusable = true}}, SHIFT(352),
+  [1115] = {.entry = {.count = 1, .reusable = true}}, SHIFT(355),
+  [1117] = {.entry = {.count = 1, .reusable = true}}, SHIFT(356),
+  [1119] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
+  [1121] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
+  [1123] = {.entry = {.count = 1, .reusable = true}}, SHIFT(357),
+  [1125] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
+  [1127] = {.entry = {.count = 1, .reusable = true}}, SHIFT(158),
+  [1129] = {.entry = {.count = 1, .reusable = true}}, SHIFT(171),
+  [1131] = {.entry = {.count = 1, .reusable = true}}, SHIFT(499),
+  [1133] = {.entry = {.count = 1, .reusable = true}}, SHIFT(450),
+  [1135] = {.entry = {.count = 1, .reusable = true}}, SHIFT(181),
+  [1137] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
+  [1139] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
+  [1141] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
+  [1143] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
+  [1145] = {.entry = {.count = 1, .reusable = true}}, SHIFT(214),
+  [1147] = {.entry = {.count = 1, .reusable = true}}, SHIFT(187),
+  [1149] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
+  [1151] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
+  [1153] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
+  [1155] = {.entry = {.count = 1, .reusable = true}}, SHIFT(221),
+  [1157] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
+  [1159] = {.entry = {.count = 1, .reusable = true}}, SHIFT(254),
+  [1161] = {.entry = {.count = 1, .reusable = true}}, SHIFT(255),
+  [1163] = {.entry = {.count = 1, .reusable = true}}, SHIFT(268),
+  [1165] = {.entry = {.count = 1, .reusable = true}}, SHIFT(278),
+  [1167] = {.entry = {.count = 1, .reusable = true}}, SHIFT(280),
+  [1169] = {.entry = {.count = 1, .reusable = true}}, SHIFT(281),
+  [1171] = {.entry = {.count = 1, .reusable = true}}, SHIFT(282),
+  [1173] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
+  [1175] = {.entry = {.count = 1, .reusable = true}}, SHIFT(198),
+  [1177] = {.entry = {.count = 1, .reusable = true}}, SHIFT(284),
+  [1179] = {.entry = {.count = 1, .reusable = true}}, SHIFT(287),
+  [1181] = {.entry = {.count = 1, .reusable = true}}, SHIFT(288),
+  [1183] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
+  [1185] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
+  [1187] = {.entry = {.count = 1, .reusable = true}}, SHIFT(289),
+  [1189] = {.entry = {.count = 1, .reusable = true}}, SHIFT(391),
+  [1191] = {.entry = {.count = 1, .reusable = true}}, SHIFT(451),
+  [1193] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
+  [1195] = {.entry = {.count = 1, .reusable = true}}, SHIFT(411),
+  [1197] = {.entry = {.count = 1, .reusable = true}}, SHIFT(413),
+  [1199] = {.entry = {.count = 1, .reusable = true}}, SHIFT(207),
+  [1201] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
+  [1203] = {.entry = {.count = 1, .reusable = true}}, SHIFT(452),
+  [1205] = {.entry = {.count = 1, .reusable = true}}, SHIFT(505),
+  [1207] = {.entry = {.count = 1, .reusable = true}}, SHIFT(511),
+  [1209] = {.entry = {.count = 1, .reusable = true}}, SHIFT(420),
+  [1211] = {.entry = {.count = 1, .reusable = true}}, SHIFT(461),
+  [1213] = {.entry = {.count = 1, .reusable = true}}, SHIFT(526),
+  [1215] = {.entry = {.count = 1, .reusable = true}}, SHIFT(428),
+  [1217] = {.entry = {.count = 1, .reusable = true}}, SHIFT(534),
 };
 
 enum ts_external_scanner_symbol_identifiers {
@@ -7708,23 +14425,28 @@ enum ts_external_scanner_symbol_identifiers {
   ts_external_token_raw_text = 6,
   ts_external_token_comment = 7,
   ts_external_token_text = 8,
-  ts_external_token__tag_namespace = 9,
-  ts_external_token__tag_local_name = 10,
-  ts_external_token__ts_lang_marker = 11,
-  ts_external_token__expression_js = 12,
-  ts_external_token__expression_ts = 13,
-  ts_external_token__attribute_expression_js = 14,
-  ts_external_token__attribute_expression_ts = 15,
-  ts_external_token__directive_marker = 16,
-  ts_external_token__member_tag_object = 17,
-  ts_external_token__member_tag_property = 18,
-  ts_external_token__attribute_value = 19,
-  ts_external_token__pipe_attribute_name = 20,
-  ts_external_token__line_tag_comment = 21,
-  ts_external_token__block_tag_comment = 22,
-  ts_external_token__unterminated_tag_end = 23,
-  ts_external_token__textarea_end_boundary = 24,
-  ts_external_token__unterminated_tag_end_open = 25,
+  ts_external_token_processing_instruction = 9,
+  ts_external_token_bogus_comment = 10,
+  ts_external_token_cdata_section = 11,
+  ts_external_token_cdata = 12,
+  ts_external_token__template_start_tag_name = 13,
+  ts_external_token__tag_namespace = 14,
+  ts_external_token__tag_local_name = 15,
+  ts_external_token__ts_lang_marker = 16,
+  ts_external_token__expression_js = 17,
+  ts_external_token__expression_ts = 18,
+  ts_external_token__attribute_expression_js = 19,
+  ts_external_token__attribute_expression_ts = 20,
+  ts_external_token__directive_marker = 21,
+  ts_external_token__member_tag_object = 22,
+  ts_external_token__member_tag_property = 23,
+  ts_external_token__attribute_value = 24,
+  ts_external_token__pipe_attribute_name = 25,
+  ts_external_token__line_tag_comment = 26,
+  ts_external_token__block_tag_comment = 27,
+  ts_external_token__unterminated_tag_end = 28,
+  ts_external_token__textarea_end_boundary = 29,
+  ts_external_token__unterminated_tag_end_open = 30,
 };
 
 static const TSSymbol ts_external_scanner_symbol_map[EXTERNAL_TOKEN_COUNT] = {
@@ -7737,6 +14459,11 @@ static const TSSymbol ts_external_scanner_symbol_map[EXTERNAL_TOKEN_COUNT] = {
   [ts_external_token_raw_text] = sym_raw_text,
   [ts_external_token_comment] = sym_comment,
   [ts_external_token_text] = sym_text,
+  [ts_external_token_processing_instruction] = sym_processing_instruction,
+  [ts_external_token_bogus_comment] = sym_bogus_comment,
+  [ts_external_token_cdata_section] = sym_cdata_section,
+  [ts_external_token_cdata] = sym_cdata,
+  [ts_external_token__template_start_tag_name] = sym__template_start_tag_name,
   [ts_external_token__tag_namespace] = sym__tag_namespace,
   [ts_external_token__tag_local_name] = sym__tag_local_name,
   [ts_external_token__ts_lang_marker] = sym__ts_lang_marker,
@@ -7756,7 +14483,7 @@ static const TSSymbol ts_external_scanner_symbol_map[EXTERNAL_TOKEN_COUNT] = {
   [ts_external_token__unterminated_tag_end_open] = sym__unterminated_tag_end_open,
 };
 
-static const bool ts_external_scanner_states[25][EXTERNAL_TOKEN_COUNT] = {
+static const bool ts_external_scanner_states[27][EXTERNAL_TOKEN_COUNT] = {
   [1] = {
     [ts_external_token__start_tag_name] = true,
     [ts_external_token__raw_text_start_tag_name] = true,
@@ -7767,6 +14494,11 @@ static const bool ts_external_scanner_states[25][EXTERNAL_TOKEN_COUNT] = {
     [ts_external_token_raw_text] = true,
     [ts_external_token_comment] = true,
     [ts_external_token_text] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
+    [ts_external_token_cdata] = true,
+    [ts_external_token__template_start_tag_name] = true,
     [ts_external_token__tag_namespace] = true,
     [ts_external_token__tag_local_name] = true,
     [ts_external_token__ts_lang_marker] = true,
@@ -7788,17 +14520,38 @@ static const bool ts_external_scanner_states[25][EXTERNAL_TOKEN_COUNT] = {
   [2] = {
     [ts_external_token_comment] = true,
     [ts_external_token_text] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
+    [ts_external_token_cdata] = true,
   },
   [3] = {
     [ts_external_token__implicit_end_tag] = true,
     [ts_external_token_comment] = true,
     [ts_external_token_text] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
+    [ts_external_token_cdata] = true,
     [ts_external_token__unterminated_tag_end] = true,
     [ts_external_token__textarea_end_boundary] = true,
   },
   [4] = {
+    [ts_external_token__implicit_end_tag] = true,
+    [ts_external_token_comment] = true,
+    [ts_external_token_text] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
+    [ts_external_token_cdata] = true,
+    [ts_external_token__unterminated_tag_end] = true,
+  },
+  [5] = {
     [ts_external_token_SLASH_GT] = true,
     [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
     [ts_external_token__ts_lang_marker] = true,
     [ts_external_token__directive_marker] = true,
     [ts_external_token__pipe_attribute_name] = true,
@@ -7807,9 +14560,12 @@ static const bool ts_external_scanner_states[25][EXTERNAL_TOKEN_COUNT] = {
     [ts_external_token__unterminated_tag_end] = true,
     [ts_external_token__unterminated_tag_end_open] = true,
   },
-  [5] = {
+  [6] = {
     [ts_external_token_SLASH_GT] = true,
     [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
     [ts_external_token__ts_lang_marker] = true,
     [ts_external_token__directive_marker] = true,
     [ts_external_token__pipe_attribute_name] = true,
@@ -7817,8 +14573,11 @@ static const bool ts_external_scanner_states[25][EXTERNAL_TOKEN_COUNT] = {
     [ts_external_token__block_tag_comment] = true,
     [ts_external_token__unterminated_tag_end] = true,
   },
-  [6] = {
+  [7] = {
     [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
     [ts_external_token__ts_lang_marker] = true,
     [ts_external_token__directive_marker] = true,
     [ts_external_token__pipe_attribute_name] = true,
@@ -7826,9 +14585,12 @@ static const bool ts_external_scanner_states[25][EXTERNAL_TOKEN_COUNT] = {
     [ts_external_token__block_tag_comment] = true,
     [ts_external_token__unterminated_tag_end] = true,
   },
-  [7] = {
+  [8] = {
     [ts_external_token_SLASH_GT] = true,
     [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
     [ts_external_token__ts_lang_marker] = true,
     [ts_external_token__directive_marker] = true,
     [ts_external_token__attribute_value] = true,
@@ -7838,9 +14600,12 @@ static const bool ts_external_scanner_states[25][EXTERNAL_TOKEN_COUNT] = {
     [ts_external_token__unterminated_tag_end] = true,
     [ts_external_token__unterminated_tag_end_open] = true,
   },
-  [8] = {
+  [9] = {
     [ts_external_token_SLASH_GT] = true,
     [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
     [ts_external_token__ts_lang_marker] = true,
     [ts_external_token__directive_marker] = true,
     [ts_external_token__attribute_value] = true,
@@ -7849,8 +14614,11 @@ static const bool ts_external_scanner_states[25][EXTERNAL_TOKEN_COUNT] = {
     [ts_external_token__block_tag_comment] = true,
     [ts_external_token__unterminated_tag_end] = true,
   },
-  [9] = {
+  [10] = {
     [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
     [ts_external_token__ts_lang_marker] = true,
     [ts_external_token__directive_marker] = true,
     [ts_external_token__attribute_value] = true,
@@ -7859,73 +14627,127 @@ static const bool ts_external_scanner_states[25][EXTERNAL_TOKEN_COUNT] = {
     [ts_external_token__block_tag_comment] = true,
     [ts_external_token__unterminated_tag_end] = true,
   },
-  [10] = {
-    [ts_external_token_comment] = true,
-    [ts_external_token__attribute_value] = true,
-  },
   [11] = {
-    [ts_external_token_comment] = true,
-  },
-  [12] = {
     [ts_external_token__start_tag_name] = true,
     [ts_external_token__raw_text_start_tag_name] = true,
     [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
+    [ts_external_token__template_start_tag_name] = true,
     [ts_external_token__tag_namespace] = true,
     [ts_external_token__member_tag_object] = true,
   },
+  [12] = {
+    [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
+    [ts_external_token__attribute_value] = true,
+  },
   [13] = {
     [ts_external_token_comment] = true,
-    [ts_external_token__directive_marker] = true,
-    [ts_external_token__pipe_attribute_name] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
   },
   [14] = {
     [ts_external_token_comment] = true,
-    [ts_external_token__expression_js] = true,
-    [ts_external_token__expression_ts] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
+    [ts_external_token__directive_marker] = true,
+    [ts_external_token__pipe_attribute_name] = true,
   },
   [15] = {
-    [ts_external_token_erroneous_end_tag_name] = true,
+    [ts_external_token__implicit_end_tag] = true,
     [ts_external_token_comment] = true,
-    [ts_external_token__tag_namespace] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
+    [ts_external_token__unterminated_tag_end] = true,
   },
   [16] = {
     [ts_external_token_comment] = true,
-    [ts_external_token__attribute_expression_js] = true,
-    [ts_external_token__attribute_expression_ts] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
+    [ts_external_token__expression_js] = true,
+    [ts_external_token__expression_ts] = true,
   },
   [17] = {
     [ts_external_token_raw_text] = true,
     [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
   },
   [18] = {
     [ts_external_token_erroneous_end_tag_name] = true,
     [ts_external_token_comment] = true,
-    [ts_external_token__member_tag_object] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
+    [ts_external_token__tag_namespace] = true,
   },
   [19] = {
-    [ts_external_token__end_tag_name] = true,
     [ts_external_token_erroneous_end_tag_name] = true,
     [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
+    [ts_external_token__member_tag_object] = true,
   },
   [20] = {
     [ts_external_token_comment] = true,
-    [ts_external_token__tag_local_name] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
+    [ts_external_token__attribute_expression_js] = true,
+    [ts_external_token__attribute_expression_ts] = true,
   },
   [21] = {
+    [ts_external_token__end_tag_name] = true,
+    [ts_external_token_erroneous_end_tag_name] = true,
     [ts_external_token_comment] = true,
-    [ts_external_token__member_tag_property] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
   },
   [22] = {
+    [ts_external_token_erroneous_end_tag_name] = true,
     [ts_external_token_comment] = true,
-    [ts_external_token__unterminated_tag_end] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
   },
   [23] = {
     [ts_external_token__end_tag_name] = true,
     [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
   },
   [24] = {
-    [ts_external_token_erroneous_end_tag_name] = true,
     [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
+    [ts_external_token__unterminated_tag_end] = true,
+  },
+  [25] = {
+    [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
+    [ts_external_token__tag_local_name] = true,
+  },
+  [26] = {
+    [ts_external_token_comment] = true,
+    [ts_external_token_processing_instruction] = true,
+    [ts_external_token_bogus_comment] = true,
+    [ts_external_token_cdata_section] = true,
+    [ts_external_token__member_tag_property] = true,
   },
 };
 
@@ -7988,7 +14810,7 @@ TS_PUBLIC const TSLanguage *tree_sitter_htmlx(void) {
     .metadata = {
       .major_version = 0,
       .minor_version = 1,
-      .patch_version = 12,
+      .patch_version = 14,
     },
   };
   return &language;
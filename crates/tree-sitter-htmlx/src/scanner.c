@@ -2,27 +2,37 @@
 
 #define scan html_scan
 #define tree_sitter_html_external_scanner_create      html_create
+#define tree_sitter_html_external_scanner_create_scripting html_create_scripting
 #define tree_sitter_html_external_scanner_destroy     html_destroy
 #define tree_sitter_html_external_scanner_scan        html_scanner_scan
 #define tree_sitter_html_external_scanner_serialize   html_serialize
 #define tree_sitter_html_external_scanner_deserialize html_deserialize
+#define tree_sitter_html_is_html_integration_point    html_is_html_integration_point
+#define tree_sitter_html_tag_can_contain              html_tag_can_contain
+#define TREE_SITTER_HTML_VENDORED
 
 // Vendored by build.rs from tree-sitter-html crate
 #include "html/scanner.c"
 
 #undef scan
 #undef tree_sitter_html_external_scanner_create
+#undef tree_sitter_html_external_scanner_create_scripting
 #undef tree_sitter_html_external_scanner_destroy
 #undef tree_sitter_html_external_scanner_scan
 #undef tree_sitter_html_external_scanner_serialize
 #undef tree_sitter_html_external_scanner_deserialize
+#undef tree_sitter_html_is_html_integration_point
+#undef tree_sitter_html_tag_can_contain
+#undef TREE_SITTER_HTML_VENDORED
 
-// HTMLX external token indices (after HTML's 9 tokens: 0-8)
+// HTMLX external token indices (after HTML's 14 tokens: 0-13)
 // HTML tokens: START_TAG_NAME(0), RAW_TEXT_START_TAG_NAME(1), END_TAG_NAME(2),
 //              ERRONEOUS_END_TAG_NAME(3), SELF_CLOSING_TAG_DELIMITER(4),
-//              IMPLICIT_END_TAG(5), RAW_TEXT(6), COMMENT(7), TEXT(8)
+//              IMPLICIT_END_TAG(5), RAW_TEXT(6), COMMENT(7), TEXT(8),
+//              PROCESSING_INSTRUCTION(9), BOGUS_COMMENT(10), CDATA_SECTION(11),
+//              CDATA(12), TEMPLATE_START_TAG_NAME(13)
 enum {
-    TAG_NAMESPACE = 9,
+    TAG_NAMESPACE = 14,
     TAG_LOCAL_NAME,
     TS_LANG_MARKER,
     EXPRESSION_JS,
@@ -161,6 +171,22 @@ static inline Tag htmlx_tag_for_svelte_name(String name, bool saw_ascii_upper) {
     return tag_for_name(name);
 }
 
+/**
+ * Apply foreign content rules (§13.2.6.5) to a tag opened at `depth`.
+ * Svelte reads <script> and <style> as raw text wherever they appear, so they
+ * keep their HTML type inside <svg>/<math> too. Svelte has no PLAINTEXT state
+ * either, so <plaintext> is demoted to an ordinary element.
+ */
+static inline Tag htmlx_tag_in_context(State *state, Tag tag, unsigned depth) {
+    if (tag.type == PLAINTEXT) {
+        return tag_for_foreign_content(tag);
+    }
+    if (tag.type == SCRIPT || tag.type == STYLE || !in_foreign_content(state->html, depth)) {
+        return tag;
+    }
+    return tag_for_foreign_content(tag);
+}
+
 /**
  * Scan text content for HTMLX
  *
@@ -345,6 +371,7 @@ static bool scan_start_tag(State *state, TSLexer *lexer, const bool *valid) {
             lexer->mark_end(lexer);
         }
         Tag tag = htmlx_tag_for_svelte_name(name, saw_ascii_upper);
+        tag = htmlx_tag_in_context(state, tag, state->html->tags.size);
         array_push(&state->html->tags, tag);
         state->open_tag_is_namespaced = false;
 
@@ -353,6 +380,14 @@ static bool scan_start_tag(State *state, TSLexer *lexer, const bool *valid) {
             case STYLE:
                 lexer->result_symbol = RAW_TEXT_START_TAG_NAME;
                 break;
+            case NOSCRIPT:
+                lexer->result_symbol = state->html->scripting ? RAW_TEXT_START_TAG_NAME : START_TAG_NAME;
+                break;
+            case TEMPLATE:
+                lexer->result_symbol = valid[TEMPLATE_START_TAG_NAME] && !preserve_mark_end
+                    ? TEMPLATE_START_TAG_NAME
+                    : START_TAG_NAME;
+                break;
             default:
                 lexer->result_symbol = START_TAG_NAME;
                 break;
@@ -421,6 +456,7 @@ static bool scan_end_tag(State *state, TSLexer *lexer, const bool *valid) {
 
     if (valid[END_TAG_NAME] || valid[ERRONEOUS_END_TAG_NAME]) {
         Tag tag = htmlx_tag_for_svelte_name(name, saw_ascii_upper);
+        tag = htmlx_tag_in_context(state, tag, parent_depth(state->html));
         if (htmlx_has_open_tag(state) && tag_eq(htmlx_current_tag(state), &tag)) {
             if (!valid[END_TAG_NAME]) {
                 tag_free(&tag);
@@ -1134,12 +1170,21 @@ static bool scan(State *state, TSLexer *lexer, const bool *valid) {
     return html_scanner_scan(state->html, lexer, valid);
 }
 
-void *tree_sitter_htmlx_external_scanner_create(void) {
+static State *state_create(Scanner *html) {
     State *state = ts_calloc(1, sizeof(State));
-    state->html = html_create();
+    state->html = html;
     return state;
 }
 
+void *tree_sitter_htmlx_external_scanner_create(void) {
+    return state_create(html_create());
+}
+
+// <noscript> content is raw text, as in a browser with scripting enabled.
+void *tree_sitter_htmlx_external_scanner_create_scripting(void) {
+    return state_create(html_create_scripting());
+}
+
 void tree_sitter_htmlx_external_scanner_destroy(void *payload) {
     State *state = payload;
     html_destroy(state->html);
@@ -1172,3 +1217,22 @@ void tree_sitter_htmlx_external_scanner_deserialize(void *payload, const char *b
 bool tree_sitter_htmlx_external_scanner_scan(void *payload, TSLexer *lexer, const bool *valid) {
     return scan(payload, lexer, valid);
 }
+
+// Grammars that vendor this scanner build their own scripting language.
+#ifndef TREE_SITTER_HTMLX_VENDORED
+
+const TSLanguage *tree_sitter_htmlx(void);
+
+static TSLanguage scripting_language;
+
+// The HTMLX language with scripting enabled: a copy of the generated language
+// whose scanners are created with the scripting flag set. Not thread-safe;
+// the Rust crate calls it once behind a lock.
+const TSLanguage *tree_sitter_htmlx_scripting(void) {
+    scripting_language = *tree_sitter_htmlx();
+    scripting_language.external_scanner.create =
+        tree_sitter_htmlx_external_scanner_create_scripting;
+    return &scripting_language;
+}
+
+#endif
@@ -0,0 +1,67 @@
+//! Writing HTMLX trees back out as markup.
+//!
+//! [`print`] reproduces the parsed source byte for byte. [`normalize`]
+//! applies the HTML normalizations from [`tree_sitter_html::serialize`],
+//! leaving component names such as `<Card>` as written, and expands `{name}`
+//! shorthand to `name={name}`.
+//!
+//! ```rust
+//! use tree_sitter_htmlx::{serialize, LANGUAGE};
+//!
+//! let source = "<div {hidden} class=box><Card {title} /></div>";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! assert_eq!(serialize::print(tree.root_node(), source), source);
+//! assert_eq!(
+//!     serialize::normalize(tree.root_node(), source),
+//!     r#"<div hidden={hidden} class="box"><Card title={title} /></div>"#
+//! );
+//! ```
+
+use tree_sitter::Node;
+use tree_sitter_html::serialize::Extension;
+
+pub use tree_sitter_html::serialize::print;
+
+use crate::is_component_name;
+
+/// The markup of the tree at `root` in canonical form. See
+/// [`tree_sitter_html::serialize::normalize_with`].
+pub fn normalize(root: Node, source: &str) -> String {
+    tree_sitter_html::serialize::normalize_with(root, source, &Htmlx)
+}
+
+struct Htmlx;
+
+impl Extension for Htmlx {
+    fn is_component(&self, name: &str) -> bool {
+        is_component_name(name)
+    }
+
+    /// Write `{name}` as `name={name}`. Spreads stay as written.
+    fn node(&self, node: Node, source: &str, out: &mut String) -> bool {
+        if node.kind() != "shorthand_attribute" {
+            return false;
+        }
+        let name = node
+            .child_by_field_name("content")
+            .map(|content| source[content.byte_range()].trim())
+            .filter(|name| is_identifier(name));
+        if let Some(name) = name {
+            out.push_str(name);
+            out.push('=');
+        }
+        out.push_str(&source[node.byte_range()]);
+        true
+    }
+}
+
+fn is_identifier(text: &str) -> bool {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
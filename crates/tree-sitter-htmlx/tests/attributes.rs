@@ -279,3 +279,82 @@ fn test_directive_transition() {
         "(document (element (start_tag name: (tag_name) (attribute name: (attribute_name (attribute_directive) (attribute_identifier)))) (end_tag name: (tag_name))))"
     );
 }
+
+// =============================================================================
+// Attribute name checks
+// =============================================================================
+
+fn check_names(source: &str) -> Vec<String> {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&tree_sitter_htmlx::LANGUAGE.into())
+        .unwrap();
+    let tree = parser.parse(source, None).unwrap();
+    tree_sitter_htmlx::attributes::check(tree.root_node(), source)
+        .iter()
+        .map(|diagnostic| {
+            let range = diagnostic.range;
+            format!(
+                "{}: {diagnostic}",
+                &source[range.start_byte..range.end_byte]
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_attribute_names_components_are_case_sensitive() {
+    assert_eq!(
+        check_names("<Card title=a Title=b /><ui.Card x=1 X=2 x=3 /><div Title=a title=b />"),
+        [
+            "x: duplicate attribute `x`",
+            "title: duplicate attribute `title`",
+        ]
+    );
+}
+
+#[test]
+fn test_attribute_names_shorthand_and_spread() {
+    assert_eq!(
+        check_names("<input {value} value={v} {...rest} {...rest}>"),
+        ["value: duplicate attribute `value`"]
+    );
+}
+
+#[test]
+fn test_attribute_names_directives() {
+    assert_eq!(
+        check_names(
+            "<div class:a class:a={x} class={c} style:color=red style:color={y} on:click={f} on:click={g} use:tip use:tip />"
+        ),
+        [
+            "class:a: duplicate attribute `class:a`",
+            "style:color: duplicate attribute `style:color`",
+        ]
+    );
+    assert_eq!(
+        check_names("<input bind:value value={v} bind:checked|once bind:checked />"),
+        [
+            "value: `bind:value` conflicts with the `value` attribute",
+            "bind:checked: duplicate attribute `bind:checked`",
+        ]
+    );
+}
+
+#[test]
+fn test_attribute_names_class_and_style_conflict_with_directives() {
+    assert_eq!(
+        check_names(
+            r#"<div class="card active" class:active={on} style:color={c} style="color: red; top: 0" />"#
+        ),
+        [
+            "class:active: `class:active` conflicts with `active` in the `class` attribute",
+            "style: `style:color` conflicts with `color` in the `style` attribute",
+        ]
+    );
+    // Names next to an expression, and dynamic values, are not static.
+    assert_eq!(
+        check_names(r#"<div class="a{b} c" class:a class:b /><p class={k} class:k />"#),
+        Vec::<String>::new()
+    );
+}
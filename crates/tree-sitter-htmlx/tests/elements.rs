@@ -242,3 +242,86 @@ fn test_style_element() {
         "(document (element (start_tag name: (tag_name)) (raw_text) (end_tag name: (tag_name))))"
     );
 }
+
+// =============================================================================
+// Foreign content (SVG/MathML)
+// =============================================================================
+
+#[test]
+fn test_svg_elements_use_foreign_content_rules() {
+    // <image> is not void and <title> is not raw text inside <svg>.
+    assert_eq!(
+        parse(r#"<svg><image href="a"><title>Tip</title></image></svg>"#),
+        "(document (element (start_tag name: (tag_name)) (element (start_tag name: (tag_name) (attribute name: (attribute_name) value: (quoted_attribute_value (attribute_value)))) (element (start_tag name: (tag_name)) (text) (end_tag name: (tag_name))) (end_tag name: (tag_name))) (end_tag name: (tag_name))))"
+    );
+}
+
+#[test]
+fn test_svg_cdata_section_is_cdata() {
+    assert_eq!(
+        parse("<svg><text><![CDATA[a<b]]></text></svg>"),
+        "(document (element (start_tag name: (tag_name)) (element (start_tag name: (tag_name)) (cdata) (end_tag name: (tag_name))) (end_tag name: (tag_name))))"
+    );
+}
+
+#[test]
+fn test_svg_foreign_object_switches_back_to_html() {
+    assert_eq!(
+        parse("<svg><foreignObject><p>x<br></foreignObject></svg>"),
+        "(document (element (start_tag name: (tag_name)) (element (start_tag name: (tag_name)) (element (start_tag name: (tag_name)) (text) (element (start_tag name: (tag_name)))) (end_tag name: (tag_name))) (end_tag name: (tag_name))))"
+    );
+}
+
+// =============================================================================
+// Template contents
+// =============================================================================
+
+#[test]
+fn test_template_end_tags_do_not_close_outer_elements() {
+    // </dt> inside <template> has no <dt> to close in the template contents.
+    assert_eq!(
+        parse("<dl><dt>a<template></dt><dd>b</template></dl>"),
+        "(document (element (start_tag name: (tag_name)) (element (start_tag name: (tag_name)) (text) (element (start_tag name: (tag_name)) (template_content (erroneous_end_tag (erroneous_end_tag_name)) (element (start_tag name: (tag_name)) (text))) (end_tag name: (tag_name)))) (end_tag name: (tag_name))))"
+    );
+}
+
+// =============================================================================
+// Raw text elements
+// =============================================================================
+
+#[test]
+fn test_html_raw_text_elements_keep_expressions() {
+    // Only <script> and <style> are raw text in HTMLX; <xmp> and <iframe>
+    // content is markup with expressions.
+    assert_eq!(
+        parse("<xmp>{x}</xmp>"),
+        "(document (element (start_tag name: (tag_name)) (expression content: (js)) (end_tag name: (tag_name))))"
+    );
+}
+
+#[test]
+fn test_plaintext_is_an_ordinary_element() {
+    assert_eq!(
+        parse("<plaintext><b>{x}</b></plaintext><p>y</p>"),
+        "(document (element (start_tag name: (tag_name)) (element (start_tag name: (tag_name)) (expression content: (js)) (end_tag name: (tag_name))) (end_tag name: (tag_name))) (element (start_tag name: (tag_name)) (text) (end_tag name: (tag_name))))"
+    );
+}
+
+#[test]
+fn test_noscript_is_raw_text_with_scripting_enabled() {
+    let source = "<noscript><p>{x}</p></noscript>";
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&tree_sitter_htmlx::LANGUAGE_SCRIPTING.into())
+        .unwrap();
+    let tree = parser.parse(source, None).unwrap();
+
+    assert_eq!(
+        tree.root_node().to_sexp(),
+        "(document (element (start_tag name: (tag_name)) (raw_text) (end_tag name: (tag_name))))"
+    );
+    assert_eq!(
+        parse(source),
+        "(document (element (start_tag name: (tag_name)) (element (start_tag name: (tag_name)) (expression content: (js)) (end_tag name: (tag_name))) (end_tag name: (tag_name))))"
+    );
+}
@@ -0,0 +1,25 @@
+use tree_sitter_htmlx::{entities, LANGUAGE};
+
+#[test]
+fn test_text_with_references_decodes() {
+    // HTMLX keeps `&` inside `text` instead of splitting out `entity` nodes.
+    let source = "<p>Tom &amp; Jerry &copy {year} &bogus;</p>";
+    let mut parser = tree_sitter::Parser::new();
+    parser.set_language(&LANGUAGE.into()).unwrap();
+    let tree = parser.parse(source, None).unwrap();
+
+    let element = tree.root_node().child(0).unwrap();
+    let text = element.named_child(1).unwrap();
+    assert_eq!(text.kind(), "text");
+    assert_eq!(entities::decoded_text(text, source), "Tom & Jerry \u{a9} ");
+
+    let found = entities::check(tree.root_node(), source);
+    let messages: Vec<_> = found.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        messages,
+        [
+            "character reference `&copy` is missing a `;`",
+            "unknown named character reference `&bogus;`",
+        ]
+    );
+}
@@ -15,6 +15,10 @@ const HTMLX = require("../tree-sitter-htmlx/grammar");
 // back to ASCII-only `\s` handling.
 const WHITESPACE = /[ \t\n\f\r\v\u0085\u00a0\u1680\u2000-\u200b\u2028\u2029\u202f\u205f\u3000\ufeff]+/;
 
+// Keywords that open a typed block; a block end with any other block's keyword
+// becomes a `mismatched_block_end`.
+const BLOCK_KEYWORDS = ["if", "each", "await", "key", "snippet"];
+
 module.exports = grammar(HTMLX, {
   name: "svelte",
 
@@ -153,6 +157,15 @@ module.exports = grammar(HTMLX, {
     _key_block_end: ($) => seq(alias($._block_end_open, $.block_open), alias("key", $.block_keyword), $._block_close),
     _snippet_block_end: ($) => seq(alias($._block_end_open, $.block_open), alias("snippet", $.block_keyword), $._block_close),
 
+    // Mismatched block ends — {/each} closing an {#if}, etc. Each block accepts the
+    // other block keywords as a `mismatched_block_end` so the opener keeps its body
+    // and the stray end stays local instead of collapsing siblings into ERROR.
+    _if_mismatched_block_end: ($) => mismatchedBlockEnd($, "if"),
+    _each_mismatched_block_end: ($) => mismatchedBlockEnd($, "each"),
+    _await_mismatched_block_end: ($) => mismatchedBlockEnd($, "await"),
+    _key_mismatched_block_end: ($) => mismatchedBlockEnd($, "key"),
+    _snippet_mismatched_block_end: ($) => mismatchedBlockEnd($, "snippet"),
+
     // =========================================================================
     // Recovery helper
     // =========================================================================
@@ -175,6 +188,17 @@ module.exports = grammar(HTMLX, {
             alias($._if_block_end, $.block_end),
           ),
         ),
+        // Recovery: closed by another block's end, e.g. {/each}
+        prec.dynamic(
+          -1,
+          seq(
+            $._if_block_start,
+            repeat($._node),
+            repeat($.else_if_clause),
+            optional($.else_clause),
+            alias($._if_mismatched_block_end, $.mismatched_block_end),
+          ),
+        ),
         // Recovery: allow one trailing unclosed element start tag before block end
         prec.dynamic(
           1,
@@ -243,6 +267,16 @@ module.exports = grammar(HTMLX, {
           optional($.else_clause),
           alias($._each_block_end, $.block_end),
         ),
+        // Recovery: closed by another block's end, e.g. {/if}
+        prec.dynamic(
+          -1,
+          seq(
+            $._each_block_start,
+            repeat($._node),
+            optional($.else_clause),
+            alias($._each_mismatched_block_end, $.mismatched_block_end),
+          ),
+        ),
         // Recovery: allow one trailing unclosed element start tag before block end
         prec(
           -1,
@@ -328,6 +362,25 @@ module.exports = grammar(HTMLX, {
           repeat($.await_branch),
           alias($._await_block_end, $.block_end),
         ),
+        // Recovery: closed by another block's end, e.g. {/if}
+        prec.dynamic(
+          -1,
+          seq(
+            $._await_block_start_plain,
+            optional(field("pending", $.await_pending)),
+            repeat($.await_branch),
+            alias($._await_mismatched_block_end, $.mismatched_block_end),
+          ),
+        ),
+        prec.dynamic(
+          -1,
+          seq(
+            $._await_block_start_shorthand,
+            optional(field("shorthand_children", $.await_branch_children)),
+            repeat($.await_branch),
+            alias($._await_mismatched_block_end, $.mismatched_block_end),
+          ),
+        ),
         // Recovery: unclosed plain await
         prec.dynamic(
           -10,
@@ -417,6 +470,15 @@ module.exports = grammar(HTMLX, {
           repeat($._node),
           alias($._key_block_end, $.block_end),
         ),
+        // Recovery: closed by another block's end, e.g. {/if}
+        prec.dynamic(
+          -1,
+          seq(
+            $._key_block_start,
+            repeat($._node),
+            alias($._key_mismatched_block_end, $.mismatched_block_end),
+          ),
+        ),
         // Recovery: allow one trailing unclosed element start tag before block end
         prec(
           -1,
@@ -466,6 +528,15 @@ module.exports = grammar(HTMLX, {
           repeat($._node),
           alias($._snippet_block_end, $.block_end),
         ),
+        // Recovery: closed by another block's end, e.g. {/if}
+        prec.dynamic(
+          -1,
+          seq(
+            $._snippet_block_start,
+            repeat($._node),
+            alias($._snippet_mismatched_block_end, $.mismatched_block_end),
+          ),
+        ),
         prec(
           -1,
           seq(
@@ -714,6 +785,7 @@ function svelteNodeChoice($, options = {}) {
     $.doctype,
     $.entity,
     $.text,
+    $.cdata,
     $.element,
   ];
 
@@ -731,6 +803,17 @@ function svelteNodeChoice($, options = {}) {
   return choice(...nodes);
 }
 
+function mismatchedBlockEnd($, keyword) {
+  return seq(
+    alias($._block_end_open, $.block_open),
+    field(
+      "keyword",
+      alias(choice(...BLOCK_KEYWORDS.filter((other) => other !== keyword)), $.block_keyword),
+    ),
+    $._block_close,
+  );
+}
+
 function svelteBlockNodes($) {
   return [
     prec(2, $.if_block),
@@ -774,6 +857,9 @@ function svelteTag($, keyword) {
 function quotedAttributeContent($, textRule) {
   return choice(
     $.html_tag,
+    $.debug_tag,
+    $.const_tag,
+    $.render_tag,
     $.if_block,
     $.each_block,
     $.await_block,
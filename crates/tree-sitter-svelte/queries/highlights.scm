@@ -10,7 +10,12 @@
 
 (attribute_value) @string
 
-(comment) @comment
+[
+  (comment)
+  (processing_instruction)
+  (bogus_comment)
+  (cdata_section)
+] @comment
 
 (entity) @constant.character.escape
 
@@ -18,8 +18,13 @@
 ; Attribute values
 (attribute_value) @string
 
-; Comments
-(comment) @comment
+; Comments, including bogus comments
+[
+  (comment)
+  (processing_instruction)
+  (bogus_comment)
+  (cdata_section)
+] @comment
 
 ; Character entities
 (entity) @constant.character.escape
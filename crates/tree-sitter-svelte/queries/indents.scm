@@ -39,14 +39,19 @@
 
 [
   (block_end)
+  (mismatched_block_end)
   (else_if_clause)
   (else_clause)
   (await_branch)
   (orphan_branch)
 ] @indent.branch
 
-(block_end
-  (block_close) @indent.end)
+[
+  (block_end
+    (block_close) @indent.end)
+  (mismatched_block_end
+    (block_close) @indent.end)
+]
 
 [
   (else_if_clause
@@ -0,0 +1,1254 @@
+//! Matching a component's `<style>` selectors against its markup.
+//!
+//! Svelte scopes each selector in a component's `<style>` to the elements
+//! that component renders, and warns about selectors that cannot match any
+//! of them. This module rebuilds that check from a Svelte tree and a
+//! [`tree_sitter_css_svelte`] tree:
+//!
+//! - [`Template`] reads the elements of the markup, with the classes, ids, and
+//!   attributes each may have and the parents and siblings it may have
+//! - [`Selector`] reads a complex selector, including `:is()`, `:where()`,
+//!   `:not()`, `:has()`, and nested rules with or without `&`
+//! - [`Template::matches`] tells whether a selector matches some element
+//!
+//! Matching is three-valued. Markup the compiler cannot see through, such as
+//! `class={active}`, `{...props}`, `class:x` directives, component children,
+//! and snippet content rendered elsewhere, makes a selector a [`Match::Maybe`]
+//! rather than a [`Match::No`]. Only selectors that are [`Match::No`] for
+//! every element are reported. Selectors inside `:global(...)`, after a bare
+//! `:global`, or in a `:global { ... }` block match unscoped and are never
+//! reported.
+//!
+//! A `<style lang="scss">` or `lang="less"` stylesheet is read with
+//! [`tree_sitter_css_svelte::preprocessor`], and selectors the preprocessor
+//! rewrites, such as `.icon-#{$name}` or those inside `@mixin`, are not
+//! reported. `&__item` appends to the parent's last name as Sass and Less
+//! do, and a rule that only prefixes such rules is not output, so it is not
+//! reported either. Other languages, such as Sass or Stylus, are skipped.
+//!
+//! ```rust
+//! use tree_sitter_htmlx_svelte::{css_usage, LANGUAGE};
+//!
+//! let source = "<p class=\"note\">hi</p><style>.note {} .warning {}</style>";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let found = css_usage::check(tree.root_node(), source);
+//! assert_eq!(found.len(), 1);
+//! assert_eq!(found[0].to_string(), "unused CSS selector `.warning`");
+//! assert_eq!(&source[found[0].range.start_byte..found[0].range.end_byte], ".warning");
+//! ```
+
+use std::fmt;
+
+use tree_sitter::{Node, Parser, Range, Tree};
+use tree_sitter_css_svelte::preprocessor::{self, Construct, ConstructKind, Dialect};
+use tree_sitter_html::span::subrange;
+use tree_sitter_htmlx::is_component_name;
+
+/// Whether a selector matches an element.
+///
+/// Ordered from least to most certain match, so `min` is "and" and `max` is
+/// "or".
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Match {
+    /// The element can never match.
+    No,
+    /// The element matches for some values of the dynamic markup.
+    Maybe,
+    /// The element always matches.
+    Yes,
+}
+
+impl Match {
+    fn and(self, other: Match) -> Match {
+        self.min(other)
+    }
+
+    fn or(self, other: Match) -> Match {
+        self.max(other)
+    }
+
+    fn not(self) -> Match {
+        match self {
+            Match::No => Match::Yes,
+            Match::Maybe => Match::Maybe,
+            Match::Yes => Match::No,
+        }
+    }
+}
+
+/// A `<style>` selector that matches no element of the component.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    /// The span of the complex selector.
+    pub range: Range,
+}
+
+/// The typed payload of a [`Diagnostic`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// A selector no element in the markup can match.
+    UnusedSelector {
+        /// The selector as written.
+        selector: String,
+    },
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.kind, f)
+    }
+}
+
+impl fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiagnosticKind::UnusedSelector { selector } => {
+                write!(f, "unused CSS selector `{selector}`")
+            }
+        }
+    }
+}
+
+/// How two compound selectors are related.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Combinator {
+    /// `a b`.
+    Descendant,
+    /// `a > b`.
+    Child,
+    /// `a + b`.
+    NextSibling,
+    /// `a ~ b`.
+    SubsequentSibling,
+}
+
+/// A complex selector such as `nav > a.active:hover`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Selector {
+    /// The compounds left to right, each with the combinator before it. The
+    /// first combinator is [`Combinator::Descendant`] unless the selector is
+    /// relative, as in `> b` inside `:has()` or a nested rule.
+    compounds: Vec<(Combinator, Vec<Simple>)>,
+    relative: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Simple {
+    Universal,
+    Tag(String),
+    Class(String),
+    Id(String),
+    Attribute(AttributeSelector),
+    Is(Vec<Selector>),
+    Not(Vec<Selector>),
+    Has(Vec<Selector>),
+    /// `:global(...)`, which matches unscoped.
+    Global,
+    /// A bare `:global`.
+    GlobalCompound,
+    /// `&`.
+    Nesting,
+    /// A suffix appended to `&` in SCSS or Less, such as `__item` in
+    /// `&__item`.
+    Suffix(String),
+    /// Pseudo-elements and `:root`, which never rule an element out.
+    Always,
+    /// Pseudo-classes that depend on state or position.
+    Unknown,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct AttributeSelector {
+    name: String,
+    operator: Option<String>,
+    value: String,
+    ignore_case: bool,
+}
+
+impl Selector {
+    /// Read a complex selector node: a child of a rule's `selectors`.
+    pub fn from_node(node: Node, source: &str) -> Option<Selector> {
+        match node.kind() {
+            "descendant_selector"
+            | "child_selector"
+            | "sibling_selector"
+            | "adjacent_sibling_selector" => {
+                let combinator = match node.kind() {
+                    "child_selector" => Combinator::Child,
+                    "sibling_selector" => Combinator::SubsequentSibling,
+                    "adjacent_sibling_selector" => Combinator::NextSibling,
+                    _ => Combinator::Descendant,
+                };
+                let mut cursor = node.walk();
+                let children: Vec<_> = node.named_children(&mut cursor).collect();
+                match children.as_slice() {
+                    [right] => {
+                        let mut selector = Selector::from_node(*right, source)?;
+                        selector.compounds[0].0 = combinator;
+                        selector.relative = true;
+                        Some(selector)
+                    }
+                    [left, right] => {
+                        let mut selector = Selector::from_node(*left, source)?;
+                        let mut right = Selector::from_node(*right, source)?;
+                        right.compounds[0].0 = combinator;
+                        selector.compounds.extend(right.compounds);
+                        Some(selector)
+                    }
+                    _ => None,
+                }
+            }
+            "tag_name" => Some(Selector::compound(vec![Simple::Tag(
+                source[node.byte_range()].to_ascii_lowercase(),
+            )])),
+            "universal_selector" => Some(Selector::compound(vec![Simple::Universal])),
+            "nesting_selector" => Some(Selector::compound(vec![Simple::Nesting])),
+            "class_selector"
+            | "id_selector"
+            | "attribute_selector"
+            | "pseudo_class_selector"
+            | "global_selector"
+            | "pseudo_element_selector" => {
+                // Each of these wraps the whole selector before it, so
+                // `.a .b:hover` is `:hover` applied to `.a .b`.
+                let leading = node.named_child(0).filter(|child| {
+                    is_selector(child.kind())
+                        && (node.kind() != "pseudo_element_selector"
+                            || node.named_child_count() > 1)
+                });
+                let mut selector = match leading {
+                    Some(leading) => Selector::from_node(leading, source)?,
+                    None => Selector::compound(Vec::new()),
+                };
+                let simple = simple(node, source)?;
+                selector.compounds.last_mut()?.1.push(simple);
+                Some(selector)
+            }
+            _ => None,
+        }
+    }
+
+    /// Parse `text` as a comma-separated selector list.
+    pub fn parse_list(text: &str) -> Option<Vec<Selector>> {
+        // Arguments of `:is()` and friends do not parse as selectors, so
+        // they are read as the prelude of a nested rule instead.
+        let wrapped = format!("x{{{text}{{}}}}");
+        let mut parser = Parser::new();
+        parser
+            .set_language(&tree_sitter_css_svelte::LANGUAGE.into())
+            .ok()?;
+        let tree = parser.parse(&wrapped, None)?;
+        if tree.root_node().has_error() {
+            return None;
+        }
+        let selectors = tree
+            .root_node()
+            .named_child(0)?
+            .child_by_field_name("block")?
+            .named_child(0)?
+            .child_by_field_name("prelude")?;
+        let mut cursor = selectors.walk();
+        let list = selectors
+            .named_children(&mut cursor)
+            .map(|node| Selector::from_node(node, &wrapped))
+            .collect();
+        list
+    }
+
+    fn compound(simples: Vec<Simple>) -> Selector {
+        Selector {
+            compounds: vec![(Combinator::Descendant, simples)],
+            relative: false,
+        }
+    }
+
+    /// The part of the selector that must match scoped elements: everything
+    /// before a bare `:global`, and before a `:global(...)` subject. `None`
+    /// when the whole selector is global.
+    fn scoped(&self) -> Option<Selector> {
+        let mut compounds = Vec::new();
+        for (combinator, simples) in &self.compounds {
+            if simples.contains(&Simple::GlobalCompound) {
+                let rest: Vec<_> = simples
+                    .iter()
+                    .filter(|simple| **simple != Simple::GlobalCompound)
+                    .cloned()
+                    .collect();
+                if !rest.is_empty() {
+                    compounds.push((*combinator, rest));
+                }
+                break;
+            }
+            compounds.push((*combinator, simples.clone()));
+        }
+        while compounds
+            .last()
+            .is_some_and(|(_, simples)| simples.contains(&Simple::Global))
+        {
+            compounds.pop();
+        }
+        (!compounds.is_empty()).then_some(Selector {
+            compounds,
+            relative: self.relative,
+        })
+    }
+
+    /// Whether the rule's block is global: the selector ends in a bare
+    /// `:global`.
+    fn opens_global_block(&self) -> bool {
+        self.compounds
+            .last()
+            .is_some_and(|(_, simples)| simples.contains(&Simple::GlobalCompound))
+    }
+
+    /// Resolve this selector, written in a rule nested under `parents`.
+    fn nest(&self, parents: &[Selector]) -> Selector {
+        let parent = Simple::Is(parents.to_vec());
+        let mut nested = false;
+        let compounds: Vec<_> = self
+            .compounds
+            .iter()
+            .map(|(combinator, simples)| {
+                let suffix = simples.iter().find_map(|simple| match simple {
+                    Simple::Suffix(suffix) => Some(suffix),
+                    _ => None,
+                });
+                let simples = simples
+                    .iter()
+                    .filter(|simple| !matches!(simple, Simple::Suffix(_)))
+                    .map(|simple| match (simple, suffix) {
+                        (Simple::Nesting, Some(suffix)) => {
+                            nested = true;
+                            parents
+                                .iter()
+                                .map(|parent| parent.concat(suffix))
+                                .collect::<Option<_>>()
+                                .map_or(Simple::Unknown, Simple::Is)
+                        }
+                        (Simple::Nesting, None) => {
+                            nested = true;
+                            parent.clone()
+                        }
+                        _ => simple.clone(),
+                    })
+                    .collect();
+                (*combinator, simples)
+            })
+            .collect();
+        if nested {
+            return Selector {
+                compounds,
+                relative: false,
+            };
+        }
+
+        // Without `&`, a nested selector is relative to its parent, as if it
+        // started with `& `.
+        let mut resolved = vec![(Combinator::Descendant, vec![parent])];
+        resolved.extend(compounds);
+        Selector {
+            compounds: resolved,
+            relative: false,
+        }
+    }
+
+    /// This selector with `suffix` appended to the name its last compound
+    /// ends in, as `&__item` appends to `.card`. `None` when the compound
+    /// does not end in a tag, class, or id.
+    fn concat(&self, suffix: &str) -> Option<Selector> {
+        let mut selector = self.clone();
+        let last = selector.compounds.last_mut()?.1.last_mut()?;
+        *last = match last {
+            Simple::Tag(name) => Simple::Tag(format!("{name}{suffix}").to_ascii_lowercase()),
+            Simple::Class(name) => Simple::Class(format!("{name}{suffix}")),
+            Simple::Id(name) => Simple::Id(format!("{name}{suffix}")),
+            _ => return None,
+        };
+        Some(selector)
+    }
+}
+
+fn simple(node: Node, source: &str) -> Option<Simple> {
+    let text = |node: Node| source[node.byte_range()].to_string();
+    match node.kind() {
+        "class_selector" => Some(Simple::Class(text(node.child_by_field_name("name")?))),
+        "id_selector" => Some(Simple::Id(text(node.child_by_field_name("name")?))),
+        "attribute_selector" => {
+            let value = node.child_by_field_name("value").map(|value| {
+                let mut cursor = value.walk();
+                let content = value
+                    .named_children(&mut cursor)
+                    .find(|child| child.kind() == "string_content");
+                match (value.kind(), content) {
+                    ("string_value", Some(content)) => text(content),
+                    ("string_value", None) => String::new(),
+                    _ => text(value),
+                }
+            });
+            Some(Simple::Attribute(AttributeSelector {
+                name: text(node.child_by_field_name("name")?).to_ascii_lowercase(),
+                operator: node.child_by_field_name("operator").map(text),
+                value: value.unwrap_or_default(),
+                ignore_case: node
+                    .child_by_field_name("flags")
+                    .is_some_and(|flags| text(flags).eq_ignore_ascii_case("i")),
+            }))
+        }
+        "pseudo_class_selector" => {
+            // The preprocessor reads `&__item` as `&:_item`, so the colon
+            // is not a `:` in the source.
+            let mut cursor = node.walk();
+            let colon = node
+                .children(&mut cursor)
+                .find(|child| child.kind() == ":")?;
+            if &source[colon.byte_range()] != ":" {
+                return Some(Simple::Suffix(
+                    source[colon.start_byte()..node.end_byte()].to_string(),
+                ));
+            }
+            let mut cursor = node.walk();
+            let name = node
+                .children(&mut cursor)
+                .find(|child| child.kind() == "class_name")
+                .map(text)?
+                .to_ascii_lowercase();
+            let mut cursor = node.walk();
+            let arguments = node
+                .children(&mut cursor)
+                .find(|child| child.kind() == "arguments")
+                .map(|arguments| {
+                    let text = &source[arguments.byte_range()];
+                    text.strip_prefix('(')
+                        .and_then(|text| text.strip_suffix(')'))
+                        .unwrap_or(text)
+                        .to_string()
+                });
+            let list = || arguments.as_deref().and_then(Selector::parse_list);
+            let simple = match name.as_str() {
+                "is" | "where" | "matches" | "any" | "-webkit-any" | "-moz-any" => {
+                    list().map_or(Simple::Unknown, Simple::Is)
+                }
+                "not" => list().map_or(Simple::Unknown, Simple::Not),
+                "has" => list().map_or(Simple::Unknown, Simple::Has),
+                "root" | "host" => Simple::Always,
+                _ => Simple::Unknown,
+            };
+            Some(simple)
+        }
+        "global_selector" => {
+            let mut cursor = node.walk();
+            let functional = node
+                .children(&mut cursor)
+                .any(|child| child.kind() == "arguments");
+            Some(if functional {
+                Simple::Global
+            } else {
+                Simple::GlobalCompound
+            })
+        }
+        "pseudo_element_selector" => Some(Simple::Always),
+        _ => None,
+    }
+}
+
+fn is_selector(kind: &str) -> bool {
+    matches!(
+        kind,
+        "descendant_selector"
+            | "child_selector"
+            | "sibling_selector"
+            | "adjacent_sibling_selector"
+            | "attribute_selector"
+            | "class_selector"
+            | "id_selector"
+            | "nesting_selector"
+            | "pseudo_class_selector"
+            | "global_selector"
+            | "pseudo_element_selector"
+            | "tag_name"
+            | "universal_selector"
+    )
+}
+
+/// The elements a component renders, as far as its markup tells.
+#[derive(Clone, Debug)]
+pub struct Template<'tree> {
+    elements: Vec<Element<'tree>>,
+    groups: Vec<Group>,
+}
+
+#[derive(Clone, Debug)]
+struct Element<'tree> {
+    node: Node<'tree>,
+    /// Lowercase tag name; `None` for `<svelte:element this={...}>`.
+    name: Option<String>,
+    classes: Vec<String>,
+    /// Classes toggled by `class:name` directives.
+    toggled_classes: Vec<String>,
+    /// `class` is dynamic or spread.
+    any_class: bool,
+    /// Attribute names with their static values, or `None` when dynamic.
+    attributes: Vec<(String, Option<String>)>,
+    /// The element has a spread, so it may have any attribute.
+    any_attribute: bool,
+    /// The nearest enclosing element, through blocks and components.
+    parent: Option<usize>,
+    /// Whether `parent` is certainly the element's DOM parent. Components
+    /// and snippets may put other elements in between.
+    parent_known: bool,
+    /// Snippet content, which may render under any element.
+    detached: bool,
+    group: usize,
+    /// Position in the group, in source order.
+    position: usize,
+    /// Rendered whenever its group is: not inside `{#if}`, `{#each}`, or an
+    /// `{#await}` branch.
+    certain: bool,
+    /// The innermost `{#each}` block around the element within its group.
+    repeat: Option<Node<'tree>>,
+}
+
+/// Elements that may be siblings of each other.
+#[derive(Clone, Debug, Default)]
+struct Group {
+    /// The element the group's members are inside.
+    owner: Option<usize>,
+    members: Vec<usize>,
+    /// The group holds content that renders unknown elements: components,
+    /// `<slot>`, `{@render}`, or `{@html}`.
+    opaque: bool,
+}
+
+#[derive(Clone, Copy)]
+struct Context<'tree> {
+    parent: Option<usize>,
+    parent_known: bool,
+    detached: bool,
+    group: usize,
+    certain: bool,
+    repeat: Option<Node<'tree>>,
+}
+
+impl<'tree> Template<'tree> {
+    /// Read the elements under a Svelte tree's `root`. Top-level `<script>`
+    /// and `<style>` elements are left out.
+    pub fn new(root: Node<'tree>, source: &str) -> Template<'tree> {
+        let mut template = Template {
+            elements: Vec::new(),
+            groups: vec![Group::default()],
+        };
+        let context = Context {
+            parent: None,
+            parent_known: true,
+            detached: false,
+            group: 0,
+            certain: true,
+            repeat: None,
+        };
+        let mut cursor = root.walk();
+        for child in root.named_children(&mut cursor) {
+            if child.kind() == "element"
+                && matches!(tag_name(child, source).as_deref(), Some("script" | "style"))
+            {
+                continue;
+            }
+            template.visit(child, source, context);
+        }
+        template
+    }
+
+    /// The best match of `selector` against any element.
+    pub fn matches(&self, selector: &Selector) -> Match {
+        (0..self.elements.len())
+            .map(|element| self.matches_element(selector, element))
+            .max()
+            .unwrap_or(Match::No)
+    }
+
+    /// The elements `selector` may match, as `element` nodes.
+    pub fn matching_elements(&self, selector: &Selector) -> Vec<Node<'tree>> {
+        (0..self.elements.len())
+            .filter(|&element| self.matches_element(selector, element) != Match::No)
+            .map(|element| self.elements[element].node)
+            .collect()
+    }
+
+    fn new_group(&mut self, owner: Option<usize>) -> usize {
+        self.groups.push(Group {
+            owner,
+            ..Group::default()
+        });
+        self.groups.len() - 1
+    }
+
+    fn visit(&mut self, node: Node<'tree>, source: &str, context: Context<'tree>) {
+        let mut inner = context;
+        match node.kind() {
+            "element" => {
+                let name = tag_name(node, source).unwrap_or_default();
+                match name.as_str() {
+                    "svelte:head" => {
+                        inner.group = self.new_group(None);
+                        inner.parent = None;
+                        inner.certain = true;
+                        inner.repeat = None;
+                    }
+                    "svelte:fragment" | "svelte:boundary" => {}
+                    "svelte:window" | "svelte:document" | "svelte:body" | "svelte:options" => {
+                        return;
+                    }
+                    "slot" => {
+                        self.groups[context.group].opaque = true;
+                    }
+                    _ if is_component_name(&name)
+                        || matches!(name.as_str(), "svelte:component" | "svelte:self") =>
+                    {
+                        self.groups[context.group].opaque = true;
+                        inner.group = self.new_group(context.parent);
+                        self.groups[inner.group].opaque = true;
+                        inner.parent_known = false;
+                        inner.certain = false;
+                        inner.repeat = None;
+                    }
+                    _ => {
+                        let index = self.add(node, &name, source, context);
+                        inner = Context {
+                            parent: Some(index),
+                            parent_known: true,
+                            detached: false,
+                            group: self.new_group(Some(index)),
+                            certain: true,
+                            repeat: None,
+                        };
+                    }
+                }
+            }
+            "if_block" | "else_if_clause" | "else_clause" | "await_pending" | "await_branch" => {
+                inner.certain = false;
+            }
+            "each_block" => {
+                inner.certain = false;
+                inner.repeat = Some(node);
+            }
+            "snippet_block" => {
+                inner = Context {
+                    parent: None,
+                    parent_known: false,
+                    detached: true,
+                    group: self.new_group(None),
+                    certain: true,
+                    repeat: None,
+                };
+            }
+            "render_tag" | "html_tag" => {
+                self.groups[context.group].opaque = true;
+                return;
+            }
+            _ => {}
+        }
+
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            self.visit(child, source, inner);
+        }
+    }
+
+    fn add(
+        &mut self,
+        node: Node<'tree>,
+        name: &str,
+        source: &str,
+        context: Context<'tree>,
+    ) -> usize {
+        let mut element = Element {
+            node,
+            name: Some(name.to_ascii_lowercase()),
+            classes: Vec::new(),
+            toggled_classes: Vec::new(),
+            any_class: false,
+            attributes: Vec::new(),
+            any_attribute: false,
+            parent: context.parent,
+            parent_known: context.parent_known,
+            detached: context.detached,
+            group: context.group,
+            position: self.groups[context.group].members.len(),
+            certain: context.certain,
+            repeat: context.repeat,
+        };
+
+        if let Some(tag) = node.child(0) {
+            let mut cursor = tag.walk();
+            for attribute in tag.named_children(&mut cursor) {
+                if attribute.kind() == "attribute" {
+                    read_attribute(&mut element, attribute, source);
+                }
+            }
+        }
+        if name == "svelte:element" {
+            element.name = element
+                .attributes
+                .iter()
+                .find(|(name, _)| name == "this")
+                .and_then(|(_, value)| value.as_ref())
+                .map(|value| value.to_ascii_lowercase());
+            element.attributes.retain(|(name, _)| name != "this");
+        }
+
+        let index = self.elements.len();
+        self.groups[context.group].members.push(index);
+        self.elements.push(element);
+        index
+    }
+
+    fn matches_element(&self, selector: &Selector, element: usize) -> Match {
+        self.complex(&selector.compounds, element)
+    }
+
+    /// Match `compounds`, whose last compound is the subject, at `element`.
+    fn complex(&self, compounds: &[(Combinator, Vec<Simple>)], element: usize) -> Match {
+        let Some(((_, subject), rest)) = compounds.split_last() else {
+            return Match::Yes;
+        };
+        let matched = self.compound(subject, element);
+        if matched == Match::No || rest.is_empty() {
+            return matched;
+        }
+
+        let (combinator, _) = compounds[compounds.len() - 1];
+        let related = match combinator {
+            Combinator::Descendant => {
+                let mut related = Match::No;
+                let mut unknown = self.elements[element].detached;
+                let mut current = self.elements[element].parent;
+                while let Some(ancestor) = current {
+                    related = related.or(self.complex(rest, ancestor));
+                    unknown |= self.elements[ancestor].detached;
+                    current = self.elements[ancestor].parent;
+                }
+                if unknown {
+                    related = related.or(Match::Maybe);
+                }
+                related
+            }
+            Combinator::Child => {
+                let this = &self.elements[element];
+                match this.parent {
+                    _ if !this.parent_known => Match::Maybe,
+                    Some(parent) => self.complex(rest, parent),
+                    None => Match::No,
+                }
+            }
+            Combinator::NextSibling | Combinator::SubsequentSibling => {
+                let adjacent = combinator == Combinator::NextSibling;
+                let mut related = self
+                    .previous_siblings(element, adjacent)
+                    .into_iter()
+                    .map(|sibling| self.complex(rest, sibling))
+                    .max()
+                    .unwrap_or(Match::No);
+                let this = &self.elements[element];
+                if self.groups[this.group].opaque || this.detached {
+                    related = related.or(Match::Maybe);
+                }
+                related
+            }
+        };
+        matched.and(related)
+    }
+
+    /// The elements that may come right before `element` when `adjacent`,
+    /// or anywhere before it otherwise.
+    fn previous_siblings(&self, element: usize, adjacent: bool) -> Vec<usize> {
+        let this = &self.elements[element];
+        let members = &self.groups[this.group].members;
+        let mut siblings = Vec::new();
+        for &sibling in members[..this.position].iter().rev() {
+            siblings.push(sibling);
+            if adjacent && self.elements[sibling].certain {
+                break;
+            }
+        }
+        // Later elements of the same `{#each}` render before this one in
+        // the next iteration.
+        if let Some(repeat) = this.repeat {
+            siblings.extend(
+                members[this.position..]
+                    .iter()
+                    .copied()
+                    .filter(|&sibling| self.elements[sibling].repeat == Some(repeat)),
+            );
+        }
+        siblings
+    }
+
+    fn compound(&self, simples: &[Simple], element: usize) -> Match {
+        simples.iter().fold(Match::Yes, |matched, simple| {
+            if matched == Match::No {
+                matched
+            } else {
+                matched.and(self.simple(simple, element))
+            }
+        })
+    }
+
+    fn simple(&self, simple: &Simple, element: usize) -> Match {
+        let this = &self.elements[element];
+        match simple {
+            Simple::Universal | Simple::Always | Simple::Global | Simple::GlobalCompound => {
+                Match::Yes
+            }
+            Simple::Unknown | Simple::Nesting | Simple::Suffix(_) => Match::Maybe,
+            Simple::Tag(name) => match &this.name {
+                None => Match::Maybe,
+                Some(own) if own == name => Match::Yes,
+                Some(_) => Match::No,
+            },
+            Simple::Class(class) => {
+                if this.classes.contains(class) {
+                    Match::Yes
+                } else if this.any_class || this.toggled_classes.contains(class) {
+                    Match::Maybe
+                } else {
+                    Match::No
+                }
+            }
+            Simple::Id(id) => self.attribute(
+                this,
+                &AttributeSelector {
+                    name: "id".to_string(),
+                    operator: Some("=".to_string()),
+                    value: id.clone(),
+                    ignore_case: false,
+                },
+            ),
+            Simple::Attribute(selector) => self.attribute(this, selector),
+            Simple::Is(list) => list
+                .iter()
+                .map(|selector| self.matches_element(selector, element))
+                .max()
+                .unwrap_or(Match::No),
+            Simple::Not(list) => list
+                .iter()
+                .map(|selector| self.matches_element(selector, element))
+                .max()
+                .unwrap_or(Match::No)
+                .not(),
+            Simple::Has(list) => list
+                .iter()
+                .map(|selector| self.has(selector, element))
+                .max()
+                .unwrap_or(Match::No),
+        }
+    }
+
+    fn attribute(&self, element: &Element, selector: &AttributeSelector) -> Match {
+        let values: Vec<Option<String>> = if selector.name == "class" && element.any_class {
+            vec![None]
+        } else if selector.name == "class" {
+            let mut values = vec![Some(element.classes.join(" "))];
+            if !element.toggled_classes.is_empty() {
+                values.push(None);
+            }
+            values
+        } else {
+            element
+                .attributes
+                .iter()
+                .filter(|(name, _)| *name == selector.name)
+                .map(|(_, value)| value.clone())
+                .collect()
+        };
+        let spread = if element.any_attribute {
+            Match::Maybe
+        } else {
+            Match::No
+        };
+        values
+            .iter()
+            .map(|value| match value {
+                None => Match::Maybe,
+                Some(value) if attribute_value_matches(selector, value) => Match::Yes,
+                Some(_) => Match::No,
+            })
+            .fold(spread, Match::or)
+    }
+
+    /// Match the relative selector of `:has()` against the elements after
+    /// or below `element`.
+    fn has(&self, selector: &Selector, element: usize) -> Match {
+        let Some((leading, _)) = selector.compounds.first() else {
+            return Match::No;
+        };
+        let this = &self.elements[element];
+        let candidates: Vec<usize> = match leading {
+            Combinator::Descendant => (0..self.elements.len())
+                .filter(|&other| self.is_ancestor(element, other))
+                .collect(),
+            Combinator::Child => (0..self.elements.len())
+                .filter(|&other| self.elements[other].parent == Some(element))
+                .collect(),
+            Combinator::NextSibling | Combinator::SubsequentSibling => {
+                self.groups[this.group].members[this.position + 1..].to_vec()
+            }
+        };
+        let opaque = self.groups.iter().any(|group| {
+            group.opaque
+                && match leading {
+                    Combinator::Descendant => group
+                        .owner
+                        .is_some_and(|owner| owner == element || self.is_ancestor(element, owner)),
+                    Combinator::Child => group.owner == Some(element),
+                    _ => false,
+                }
+        }) || (!matches!(leading, Combinator::Descendant | Combinator::Child)
+            && self.groups[this.group].opaque);
+
+        let matched = candidates
+            .into_iter()
+            .map(|candidate| self.complex(&selector.compounds, candidate))
+            .max()
+            .unwrap_or(Match::No);
+        if opaque {
+            matched.or(Match::Maybe)
+        } else {
+            matched
+        }
+    }
+
+    fn is_ancestor(&self, ancestor: usize, element: usize) -> bool {
+        let mut current = self.elements[element].parent;
+        while let Some(parent) = current {
+            if parent == ancestor {
+                return true;
+            }
+            current = self.elements[parent].parent;
+        }
+        false
+    }
+}
+
+fn tag_name(element: Node, source: &str) -> Option<String> {
+    let name = element.child(0)?.child_by_field_name("name")?;
+    Some(source[name.byte_range()].to_string())
+}
+
+fn read_attribute(element: &mut Element, attribute: Node, source: &str) {
+    let Some(name) = attribute.child_by_field_name("name") else {
+        // `{name}` shorthand or `{...spread}`.
+        let Some(content) = attribute
+            .named_child(0)
+            .and_then(|shorthand| shorthand.child_by_field_name("content"))
+        else {
+            return;
+        };
+        let content = source[content.byte_range()].trim();
+        if content.starts_with("...") {
+            element.any_attribute = true;
+            element.any_class = true;
+        } else if content == "class" {
+            element.any_class = true;
+        } else {
+            element
+                .attributes
+                .push((content.to_ascii_lowercase(), None));
+        }
+        return;
+    };
+
+    let mut cursor = name.walk();
+    let directive = name
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "attribute_directive");
+    if let Some(directive) = directive {
+        let mut cursor = name.walk();
+        let identifier = name
+            .named_children(&mut cursor)
+            .find(|child| child.kind() == "attribute_identifier")
+            .map(|identifier| source[identifier.byte_range()].to_string());
+        match (&source[directive.byte_range()], identifier) {
+            ("class", Some(class)) => element.toggled_classes.push(class),
+            ("bind", Some(name)) => element.attributes.push((name.to_ascii_lowercase(), None)),
+            _ => {}
+        }
+        return;
+    }
+
+    let name = source[name.byte_range()].to_ascii_lowercase();
+    let value = match attribute.child_by_field_name("value") {
+        None => Some(String::new()),
+        Some(value) => static_value(value, source),
+    };
+    if name == "class" {
+        match value {
+            Some(value) => element
+                .classes
+                .extend(value.split_ascii_whitespace().map(str::to_string)),
+            None => element.any_class = true,
+        }
+    } else {
+        element.attributes.push((name, value));
+    }
+}
+
+/// The text of an attribute value without interpolation, or `None`.
+pub(crate) fn static_value(value: Node, source: &str) -> Option<String> {
+    match value.kind() {
+        "attribute_value" => Some(source[value.byte_range()].to_string()),
+        "quoted_attribute_value" => {
+            let mut cursor = value.walk();
+            let mut text = String::new();
+            for part in value.named_children(&mut cursor) {
+                if part.kind() != "attribute_value" {
+                    return None;
+                }
+                text.push_str(&source[part.byte_range()]);
+            }
+            Some(text)
+        }
+        _ => None,
+    }
+}
+
+fn attribute_value_matches(selector: &AttributeSelector, value: &str) -> bool {
+    let (value, expected) = if selector.ignore_case {
+        (value.to_lowercase(), selector.value.to_lowercase())
+    } else {
+        (value.to_string(), selector.value.clone())
+    };
+    match selector.operator.as_deref() {
+        None => true,
+        Some("=") => value == expected,
+        Some("~=") => value.split_ascii_whitespace().any(|word| word == expected),
+        Some("|=") => value == expected || value.starts_with(&format!("{expected}-")),
+        Some("^=") => !expected.is_empty() && value.starts_with(&expected),
+        Some("$=") => !expected.is_empty() && value.ends_with(&expected),
+        Some("*=") => !expected.is_empty() && value.contains(&expected),
+        Some(_) => true,
+    }
+}
+
+/// Report the selectors in the stylesheet at `css_root` that match no
+/// element of the Svelte markup at `template_root`. Ranges are in the
+/// stylesheet's source.
+pub fn unused_selectors(
+    css_root: Node,
+    css_source: &str,
+    template_root: Node,
+    template_source: &str,
+) -> Vec<Diagnostic> {
+    let template = Template::new(template_root, template_source);
+    let mut diagnostics = Vec::new();
+    visit_rules(css_root, css_source, &template, None, &mut diagnostics);
+    diagnostics
+}
+
+/// Report the selectors in each top-level `<style>` of the Svelte tree at
+/// `root` that match no element of its markup. Ranges are in the
+/// component's source.
+pub fn check(root: Node, source: &str) -> Vec<Diagnostic> {
+    let mut parser = Parser::new();
+    if parser
+        .set_language(&tree_sitter_css_svelte::LANGUAGE.into())
+        .is_err()
+    {
+        return Vec::new();
+    }
+
+    let mut diagnostics = Vec::new();
+    for text in style_sheets(root, source) {
+        let css = &source[text.byte_range()];
+        let Some((stylesheet, dialect)) = parse_style(&mut parser, text, source) else {
+            continue;
+        };
+        let constructs = dialect.map(|dialect| preprocessor::mask(css, dialect).1);
+        diagnostics.extend(
+            unused_selectors(stylesheet.root_node(), css, root, source)
+                .into_iter()
+                .filter(|diagnostic| {
+                    constructs.as_ref().is_none_or(|constructs| {
+                        !generated(stylesheet.root_node(), constructs, diagnostic)
+                    })
+                })
+                .map(|diagnostic| Diagnostic {
+                    range: subrange(
+                        text,
+                        css,
+                        diagnostic.range.start_byte,
+                        diagnostic.range.end_byte,
+                    ),
+                    kind: diagnostic.kind,
+                }),
+        );
+    }
+    diagnostics
+}
+
+/// The `raw_text` of each top-level `<style>` element.
+pub(crate) fn style_sheets<'tree>(root: Node<'tree>, source: &str) -> Vec<Node<'tree>> {
+    let mut cursor = root.walk();
+    let styles = root
+        .named_children(&mut cursor)
+        .filter(|style| {
+            style.kind() == "element" && tag_name(*style, source).as_deref() == Some("style")
+        })
+        .filter_map(|style| {
+            let mut cursor = style.walk();
+            let text = style
+                .named_children(&mut cursor)
+                .find(|child| child.kind() == "raw_text");
+            text
+        })
+        .collect();
+    styles
+}
+
+/// Parse the stylesheet in `text`, the `raw_text` of a `<style>` element,
+/// in the dialect its `lang` attribute names, along with that dialect.
+/// `None` when the stylesheet is in a language the CSS grammar cannot read,
+/// such as Sass or Stylus. Ranges in the tree are relative to `text`.
+pub(crate) fn parse_style(
+    parser: &mut Parser,
+    text: Node,
+    source: &str,
+) -> Option<(Tree, Option<Dialect>)> {
+    let css = &source[text.byte_range()];
+    let lang = text.parent().and_then(|style| {
+        let start_tag = style.child(0)?;
+        let mut cursor = start_tag.walk();
+        let lang = start_tag
+            .named_children(&mut cursor)
+            .filter(|attribute| attribute.kind() == "attribute")
+            .find(|attribute| {
+                attribute
+                    .child_by_field_name("name")
+                    .is_some_and(|name| matches!(&source[name.byte_range()], "lang" | "type"))
+            })
+            .and_then(|attribute| attribute.child_by_field_name("value"))
+            .and_then(|value| static_value(value, source));
+        lang
+    });
+    match lang.as_deref().map(str::to_ascii_lowercase).as_deref() {
+        None | Some("css" | "text/css" | "postcss" | "text/postcss") => {
+            Some((parser.parse(css, None)?, None))
+        }
+        Some(lang) => {
+            let dialect = Dialect::from_lang(lang)?;
+            Some((preprocessor::parse(parser, css, dialect)?, Some(dialect)))
+        }
+    }
+}
+
+/// Whether the selector `diagnostic` reports in a preprocessed stylesheet
+/// is not output as written: it has interpolation, is a placeholder or a
+/// Less mixin, sits in a directive such as `@mixin` or `@each`, or only
+/// prefixes rules such as `&__item` in a rule with no declarations.
+fn generated(stylesheet: Node, constructs: &[Construct], diagnostic: &Diagnostic) -> bool {
+    let range = diagnostic.range.start_byte..diagnostic.range.end_byte;
+    let rewritten = constructs.iter().any(|construct| {
+        matches!(
+            construct.kind,
+            ConstructKind::Interpolation | ConstructKind::Placeholder | ConstructKind::Parameters
+        ) && construct.range.start < range.end
+            && range.start < construct.range.end
+    });
+    if rewritten {
+        return true;
+    }
+    let node = stylesheet.descendant_for_byte_range(range.start, range.end);
+    let rule = std::iter::successors(node, Node::parent)
+        .find(|node| matches!(node.kind(), "rule_set" | "global_block"));
+    if rule
+        .and_then(|rule| rule.child_by_field_name("block"))
+        .is_some_and(|block| is_prefix_only(block, constructs))
+    {
+        return true;
+    }
+    let mut current = node;
+    while let Some(node) = current {
+        if node.kind() == "at_rule" {
+            return true;
+        }
+        current = node.parent();
+    }
+    false
+}
+
+/// Whether `block` has no declarations of its own but nests a rule that
+/// appends to `&`, so Sass and Less output none of its rule's selectors.
+fn is_prefix_only(block: Node, constructs: &[Construct]) -> bool {
+    let mut cursor = block.walk();
+    let declares = block
+        .named_children(&mut cursor)
+        .any(|child| child.kind() == "declaration");
+    !declares
+        && constructs.iter().any(|construct| {
+            construct.kind == ConstructKind::ParentSuffix
+                && block.start_byte() <= construct.range.start
+                && construct.range.end <= block.end_byte()
+        })
+}
+
+/// Check the rules under `node`. `parents` is the enclosing rule's resolved
+/// selector list, or `None` at the top level.
+fn visit_rules(
+    node: Node,
+    source: &str,
+    template: &Template,
+    parents: Option<&[Selector]>,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        if !matches!(child.kind(), "rule_set" | "global_block") {
+            if child.kind() != "keyframes_statement" {
+                visit_rules(child, source, template, parents, diagnostics);
+            }
+            continue;
+        }
+
+        let Some(prelude) = child.child_by_field_name("prelude") else {
+            continue;
+        };
+        let mut resolved = Vec::new();
+        let mut global_block = child.kind() == "global_block";
+        let mut cursor = prelude.walk();
+        for node in prelude.named_children(&mut cursor) {
+            let Some(selector) = Selector::from_node(node, source) else {
+                // Unreadable selectors are left alone, and so are the rules
+                // nested under them.
+                resolved.push(Selector::compound(vec![Simple::Unknown]));
+                continue;
+            };
+            global_block |= selector.opens_global_block();
+            let selector = match parents {
+                Some(parents) => selector.nest(parents),
+                None => selector,
+            };
+            let unused = selector
+                .scoped()
+                .is_some_and(|scoped| template.matches(&scoped) == Match::No);
+            if unused {
+                diagnostics.push(Diagnostic {
+                    kind: DiagnosticKind::UnusedSelector {
+                        selector: source[node.byte_range()].to_string(),
+                    },
+                    range: node.range(),
+                });
+            }
+            resolved.push(selector);
+        }
+
+        // Rules inside a `:global { ... }` block match unscoped.
+        if global_block {
+            continue;
+        }
+        if let Some(block) = child.child_by_field_name("block") {
+            visit_rules(block, source, template, Some(&resolved), diagnostics);
+        }
+    }
+}
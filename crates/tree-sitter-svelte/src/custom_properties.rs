@@ -0,0 +1,121 @@
+//! Custom properties across a component's `<style>` and markup.
+//!
+//! A Svelte component declares custom properties in two places: its
+//! `<style>` blocks, and `style:--name={value}` directives on elements and
+//! components. [`add_component`] adds both to a
+//! [`Graph`](tree_sitter_css_svelte::custom_properties::Graph), so `var()`
+//! references can be checked across every component of a project.
+//!
+//! ```rust
+//! use tree_sitter_css_svelte::custom_properties::Graph;
+//! use tree_sitter_htmlx_svelte::{custom_properties, LANGUAGE};
+//!
+//! let source = "<div style:--accent=\"red\"></div><style>div { color: var(--accent); }</style>";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let mut graph = Graph::default();
+//! custom_properties::add_component(&mut graph, tree.root_node(), source, 0);
+//! assert_eq!(graph.declarations[0].name, "--accent");
+//! assert_eq!(graph.declarations[0].context, ["<div>"]);
+//! assert!(graph.check().is_empty());
+//! ```
+
+use tree_sitter::{Node, Parser};
+
+use tree_sitter_css_svelte::custom_properties::{Diagnostic, Graph};
+
+use tree_sitter_html::span::subrange;
+
+use crate::css_usage::{parse_style, static_value, style_sheets};
+
+/// Add the custom properties declared and used in the Svelte tree at
+/// `root` to `graph`, recording them as coming from `document`. Ranges are
+/// in the component's source.
+pub fn add_component(graph: &mut Graph, root: Node, source: &str, document: usize) {
+    let mut parser = Parser::new();
+    if parser
+        .set_language(&tree_sitter_css_svelte::LANGUAGE.into())
+        .is_ok()
+    {
+        for text in style_sheets(root, source) {
+            let css = &source[text.byte_range()];
+            let Some((stylesheet, _)) = parse_style(&mut parser, text, source) else {
+                continue;
+            };
+            let (declarations, uses) = (graph.declarations.len(), graph.uses.len());
+            graph.add_stylesheet(stylesheet.root_node(), css, document);
+            for declaration in &mut graph.declarations[declarations..] {
+                let range = declaration.range;
+                declaration.range = subrange(text, css, range.start_byte, range.end_byte);
+            }
+            for found in &mut graph.uses[uses..] {
+                let range = found.range;
+                found.range = subrange(text, css, range.start_byte, range.end_byte);
+            }
+        }
+    }
+
+    visit_directives(root, source, graph, document);
+}
+
+/// Report custom property cycles, undefined references without fallbacks,
+/// and unused declarations within a single component. See
+/// [`Graph::check`].
+pub fn check(root: Node, source: &str) -> Vec<Diagnostic> {
+    let mut graph = Graph::default();
+    add_component(&mut graph, root, source, 0);
+    graph.check()
+}
+
+/// Add each `style:--name` directive under `node`. A quoted value is read
+/// for `var()` references; an expression value has none the stylesheet
+/// can see.
+fn visit_directives(node: Node, source: &str, graph: &mut Graph, document: usize) {
+    if node.kind() == "start_tag" || node.kind() == "self_closing_tag" {
+        let tag = node
+            .child_by_field_name("name")
+            .map_or("", |name| &source[name.byte_range()]);
+        let mut cursor = node.walk();
+        for attribute in node.named_children(&mut cursor) {
+            let Some(name) = style_directive(attribute, source) else {
+                continue;
+            };
+            let value = attribute
+                .child_by_field_name("value")
+                .and_then(|value| static_value(value, source))
+                .unwrap_or_default();
+            graph.add_inline(
+                name,
+                &value,
+                attribute.range(),
+                vec![format!("<{tag}>")],
+                document,
+            );
+        }
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        visit_directives(child, source, graph, document);
+    }
+}
+
+/// The custom property a `style:--name` directive sets.
+fn style_directive<'a>(attribute: Node, source: &'a str) -> Option<&'a str> {
+    if attribute.kind() != "attribute" {
+        return None;
+    }
+    let name = attribute.child_by_field_name("name")?;
+    let mut cursor = name.walk();
+    let children: Vec<_> = name.named_children(&mut cursor).collect();
+    let directive = children
+        .iter()
+        .find(|child| child.kind() == "attribute_directive")?;
+    let identifier = children
+        .iter()
+        .find(|child| child.kind() == "attribute_identifier")?;
+    let property = &source[identifier.byte_range()];
+    (&source[directive.byte_range()] == "style" && property.starts_with("--")).then_some(property)
+}
@@ -0,0 +1,474 @@
+//! Typed diagnostics for recovered and misplaced Svelte markup.
+//!
+//! The grammar keeps malformed input local (unclosed blocks, mismatched block
+//! ends, orphan branches, typed tails) and deliberately accepts some syntax
+//! the Svelte compiler rejects, such as blocks inside quoted attribute values.
+//! Those mistakes can only be described by looking at more than one node, so
+//! this module walks a parsed tree and reports them with precise ranges so
+//! editors do not have to rescan raw `ERROR` text.
+//!
+//! ```rust
+//! use tree_sitter_htmlx_svelte::{diagnostics, LANGUAGE};
+//!
+//! let source = "{#if ready}<p>ok</p>{/each}";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let found = diagnostics::check(&tree, source);
+//! assert_eq!(found[0].to_string(), "expected {/if}, found {/each}");
+//! ```
+
+use std::fmt;
+
+use tree_sitter::{Node, Range, Tree};
+
+use tree_sitter_htmlx::{attributes, content_model, is_component_name};
+
+use crate::{css_usage, entities};
+
+/// A problem found in a Svelte tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    /// The span the diagnostic should be reported at.
+    pub range: Range,
+}
+
+/// The typed payload of a [`Diagnostic`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// A `{/keyword}` closed a block opened with a different keyword.
+    MismatchedBlockEnd(MismatchedBlockEnd),
+    /// A block left without an end because a block nested in it took its
+    /// `{/keyword}`, as `{#if}` in `{#if x}{#each items as i}{i}{/if}`.
+    UnclosedBlock {
+        /// Block keyword (`if` for `{#if}`).
+        keyword: String,
+        /// Span of the `{/keyword}` the nested block took as its end.
+        end: Range,
+    },
+    /// A block such as `{#if}` inside a quoted attribute value.
+    BlockInAttributeValue {
+        /// Block keyword (`if` for `{#if}`).
+        keyword: String,
+    },
+    /// A tag such as `{@html}` inside a quoted attribute value.
+    TagInAttributeValue {
+        /// Tag keyword (`html` for `{@html}`).
+        keyword: String,
+    },
+    /// A block such as `{#if}` inside `<textarea>`, whose content Svelte
+    /// reads as a text sequence.
+    BlockInTextarea {
+        /// Block keyword (`if` for `{#if}`).
+        keyword: String,
+    },
+    /// A tag such as `{@debug}` inside `<textarea>`.
+    TagInTextarea {
+        /// Tag keyword (`debug` for `{@debug}`).
+        keyword: String,
+    },
+    /// A `{@const}` whose parent cannot hold declarations.
+    ConstTagInvalidPlacement,
+    /// An unknown, unterminated, or invalid character reference in text or
+    /// an attribute value.
+    CharacterReference(entities::DiagnosticKind),
+    /// A repeated or malformed attribute name, or a `bind:` directive that
+    /// clashes with an attribute.
+    Attribute(attributes::DiagnosticKind),
+    /// An element nested where the HTML content model forbids it, such as
+    /// `<div>` inside `<p>` or `<a>` inside `<button>`.
+    ContentModel(content_model::DiagnosticKind),
+    /// A `<style>` selector that no element in the markup can match.
+    Css(css_usage::DiagnosticKind),
+}
+
+/// A block end whose keyword does not match the innermost open block,
+/// e.g. `{#if x}...{/each}`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MismatchedBlockEnd {
+    /// Keyword of the innermost open block (`if` for `{#if}`).
+    pub expected: String,
+    /// Keyword that was actually written (`each` for `{/each}`).
+    pub found: String,
+    /// Span of the opener header, from `{#` through its closing `}`.
+    pub opener: Range,
+    /// Span between the opener header and the mismatched block end.
+    pub body: Range,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            DiagnosticKind::MismatchedBlockEnd(end) => {
+                write!(
+                    f,
+                    "expected {{/{}}}, found {{/{}}}",
+                    end.expected, end.found
+                )
+            }
+            DiagnosticKind::UnclosedBlock { keyword, .. } => {
+                write!(f, "{{#{keyword} ...}} block is not closed")
+            }
+            DiagnosticKind::BlockInAttributeValue { keyword } => {
+                write!(
+                    f,
+                    "{{#{keyword} ...}} block cannot be inside an attribute value"
+                )
+            }
+            DiagnosticKind::TagInAttributeValue { keyword } => {
+                write!(
+                    f,
+                    "{{@{keyword} ...}} tag cannot be inside an attribute value"
+                )
+            }
+            DiagnosticKind::BlockInTextarea { keyword } => {
+                write!(f, "{{#{keyword} ...}} block cannot be inside <textarea>")
+            }
+            DiagnosticKind::TagInTextarea { keyword } => {
+                write!(f, "{{@{keyword} ...}} tag cannot be inside <textarea>")
+            }
+            DiagnosticKind::ConstTagInvalidPlacement => {
+                f.write_str("`{@const}` must be the immediate child of ")?;
+                for syntax in CONST_TAG_BLOCKS.iter().flat_map(|(_, syntax)| *syntax) {
+                    write!(f, "`{syntax}`, ")?;
+                }
+                for name in CONST_TAG_ELEMENTS {
+                    write!(f, "`<{name}>`, ")?;
+                }
+                f.write_str("a `<Component>`, or an element with a `slot` attribute")
+            }
+            DiagnosticKind::CharacterReference(kind) => kind.fmt(f),
+            DiagnosticKind::Attribute(kind) => kind.fmt(f),
+            DiagnosticKind::ContentModel(kind) => kind.fmt(f),
+            DiagnosticKind::Css(kind) => kind.fmt(f),
+        }
+    }
+}
+
+/// Run every check in this module over `tree`, in source order.
+pub fn check(tree: &Tree, source: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = mismatched_block_ends(tree.root_node(), source);
+    diagnostics.extend(invalid_placements(tree.root_node(), source));
+    diagnostics.extend(character_references(tree.root_node(), source));
+    diagnostics.extend(attribute_names(tree.root_node(), source));
+    diagnostics.extend(element_nesting(tree.root_node(), source));
+    diagnostics.extend(unused_selectors(tree.root_node(), source));
+    diagnostics.sort_by_key(|diagnostic| diagnostic.range.start_byte);
+    diagnostics
+}
+
+/// Report every `{/keyword}` that does not close the innermost open block.
+///
+/// The grammar closes a block at another block's end, such as `{/each}`
+/// after `{#if}`, with a `mismatched_block_end` child so the body stays
+/// intact. The expected keyword comes from the block that child closes.
+/// When an enclosing block with the written keyword is left without an end
+/// of its own, as `{#if}` in `{#if x}{#each items as i}{i}{/if}`, that
+/// block is reported as unclosed too, at its opener.
+pub fn mismatched_block_ends(root: Node, source: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    visit_block_ends(root, source, &mut diagnostics);
+    diagnostics
+}
+
+/// Report blocks and tags that parse but are not allowed where they appear.
+///
+/// Quoted attribute values accept `{@html}`, `{@debug}`, `{@const}`,
+/// `{@render}` and every `{#...}` block so the surrounding tag still parses;
+/// each one is reported here, outermost only, as are blocks and tags inside
+/// `<textarea>`. `{@const}` is reported unless its parent is a block, branch,
+/// component, `<svelte:fragment>`, `<svelte:boundary>`, or an element with a
+/// `slot` attribute.
+pub fn invalid_placements(root: Node, source: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    visit_placements(root, source, &mut diagnostics);
+    diagnostics
+}
+
+/// Report character references a browser would flag: unknown names such as
+/// `&bogus;`, references missing their `;`, and numeric references to
+/// invalid code points. See [`entities::check`].
+pub fn character_references(root: Node, source: &str) -> Vec<Diagnostic> {
+    entities::check(root, source)
+        .into_iter()
+        .map(|diagnostic| Diagnostic {
+            kind: DiagnosticKind::CharacterReference(diagnostic.kind),
+            range: diagnostic.range,
+        })
+        .collect()
+}
+
+/// Report repeated attributes such as `id` twice or `class:active` twice,
+/// names with characters HTML does not allow, and `bind:name` alongside a
+/// `name` attribute. Props on components are compared case-sensitively. See
+/// [`attributes::check`].
+pub fn attribute_names(root: Node, source: &str) -> Vec<Diagnostic> {
+    attributes::check(root, source)
+        .into_iter()
+        .map(|diagnostic| Diagnostic {
+            kind: DiagnosticKind::Attribute(diagnostic.kind),
+            range: diagnostic.range,
+        })
+        .collect()
+}
+
+/// Report elements the HTML content model forbids where they appear: an
+/// end tag whose element a sibling already closed implicitly, as in
+/// `<p><div></div></p>`, `<a>` or interactive content inside `<a>` or
+/// `<button>`, and `<li>` outside a list. Components and their children are
+/// not checked. See [`content_model::check`].
+pub fn element_nesting(root: Node, source: &str) -> Vec<Diagnostic> {
+    content_model::check(root, source)
+        .into_iter()
+        .map(|diagnostic| Diagnostic {
+            kind: DiagnosticKind::ContentModel(diagnostic.kind),
+            range: diagnostic.range,
+        })
+        .collect()
+}
+
+/// Report `<style>` selectors that match no element of the component, such
+/// as `.warning` when no element has that class. Dynamic classes, spreads,
+/// and component children count as possible matches. See
+/// [`css_usage::check`].
+pub fn unused_selectors(root: Node, source: &str) -> Vec<Diagnostic> {
+    css_usage::check(root, source)
+        .into_iter()
+        .map(|diagnostic| Diagnostic {
+            kind: DiagnosticKind::Css(diagnostic.kind),
+            range: diagnostic.range,
+        })
+        .collect()
+}
+
+fn visit_placements(node: Node, source: &str, diagnostics: &mut Vec<Diagnostic>) {
+    if node.kind() == "quoted_attribute_value" {
+        report_sequence_children(
+            node,
+            |keyword| DiagnosticKind::BlockInAttributeValue { keyword },
+            |keyword| DiagnosticKind::TagInAttributeValue { keyword },
+            diagnostics,
+        );
+        return;
+    }
+
+    if node.kind() == "element" && element_name(node, source) == Some("textarea") {
+        report_sequence_children(
+            node,
+            |keyword| DiagnosticKind::BlockInTextarea { keyword },
+            |keyword| DiagnosticKind::TagInTextarea { keyword },
+            diagnostics,
+        );
+        return;
+    }
+
+    if node.kind() == "const_tag" && !accepts_const_tag(node.parent(), source) {
+        diagnostics.push(Diagnostic {
+            kind: DiagnosticKind::ConstTagInvalidPlacement,
+            range: node.range(),
+        });
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        visit_placements(child, source, diagnostics);
+    }
+}
+
+/// Report the blocks and tags directly inside `node`, whose content Svelte
+/// reads as a plain text sequence.
+fn report_sequence_children(
+    node: Node,
+    block: impl Fn(String) -> DiagnosticKind,
+    tag: impl Fn(String) -> DiagnosticKind,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        let kind = if let Some(keyword) = block_keyword(child.kind()) {
+            block(keyword.to_string())
+        } else if let Some(keyword) = tag_keyword(child.kind()) {
+            tag(keyword.to_string())
+        } else {
+            continue;
+        };
+        diagnostics.push(Diagnostic {
+            kind,
+            range: child.range(),
+        });
+    }
+}
+
+fn start_tag(element: Node) -> Option<Node> {
+    element
+        .child(0)
+        .filter(|tag| tag.kind() == "start_tag" || tag.kind() == "self_closing_tag")
+}
+
+fn element_name<'source>(element: Node, source: &'source str) -> Option<&'source str> {
+    let name = start_tag(element)?.child_by_field_name("name")?;
+    Some(&source[name.byte_range()])
+}
+
+/// The block nodes a `{@const}` may sit directly in, with the syntax that
+/// opens each one.
+const CONST_TAG_BLOCKS: &[(&str, &[&str])] = &[
+    ("snippet_block", &["{#snippet}"]),
+    ("if_block", &["{#if}"]),
+    ("else_if_clause", &["{:else if}"]),
+    ("else_clause", &["{:else}"]),
+    ("each_block", &["{#each}"]),
+    ("key_block", &["{#key}"]),
+    ("await_pending", &["{#await}"]),
+    ("await_branch_children", &["{:then}", "{:catch}"]),
+];
+
+/// The special elements a `{@const}` may sit directly in, besides components
+/// and elements with a `slot` attribute.
+const CONST_TAG_ELEMENTS: &[&str] = &[
+    "svelte:fragment",
+    "svelte:boundary",
+    "svelte:component",
+    "svelte:self",
+];
+
+fn accepts_const_tag(parent: Option<Node>, source: &str) -> bool {
+    let Some(parent) = parent else {
+        return false;
+    };
+
+    match parent.kind() {
+        kind if CONST_TAG_BLOCKS.iter().any(|(block, _)| *block == kind) => true,
+        "element" => {
+            let Some(start_tag) = start_tag(parent) else {
+                return false;
+            };
+            let Some(name) = start_tag.child_by_field_name("name") else {
+                return false;
+            };
+            let name = &source[name.byte_range()];
+
+            is_component_name(name)
+                || CONST_TAG_ELEMENTS.contains(&name)
+                || has_slot_attribute(start_tag, source)
+        }
+        _ => false,
+    }
+}
+
+fn has_slot_attribute(start_tag: Node, source: &str) -> bool {
+    let mut cursor = start_tag.walk();
+    let has_slot = start_tag.named_children(&mut cursor).any(|attribute| {
+        attribute.kind() == "attribute"
+            && attribute
+                .child_by_field_name("name")
+                .is_some_and(|name| &source[name.byte_range()] == "slot")
+    });
+    has_slot
+}
+
+fn visit_block_ends(node: Node, source: &str, diagnostics: &mut Vec<Diagnostic>) {
+    if node.kind() == "mismatched_block_end" {
+        diagnostics.extend(mismatched_block_end(node, source));
+        diagnostics.extend(unclosed_block(node, source));
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        visit_block_ends(child, source, diagnostics);
+    }
+}
+
+fn mismatched_block_end(end: Node, source: &str) -> Option<Diagnostic> {
+    let block = end.parent()?;
+    let expected = block_keyword(block.kind())?;
+    let found = end.child_by_field_name("keyword")?;
+
+    let start = block.child(0)?;
+    let mut cursor = block.walk();
+    let header_end = block
+        .children(&mut cursor)
+        .find(|child| child.kind() == "block_close")?;
+
+    Some(Diagnostic {
+        kind: DiagnosticKind::MismatchedBlockEnd(MismatchedBlockEnd {
+            expected: expected.to_string(),
+            found: source[found.byte_range()].to_string(),
+            opener: span(start.range(), header_end.range()),
+            body: Range {
+                start_byte: header_end.end_byte(),
+                end_byte: end.start_byte(),
+                start_point: header_end.end_position(),
+                end_point: end.start_position(),
+            },
+        }),
+        range: end.range(),
+    })
+}
+
+/// The nearest block around the block `end` closes that has the keyword
+/// `end` was written with and no end of its own: the block `end` was meant
+/// for.
+fn unclosed_block(end: Node, source: &str) -> Option<Diagnostic> {
+    let found = &source[end.child_by_field_name("keyword")?.byte_range()];
+    let mut ancestor = end.parent()?.parent();
+    while let Some(block) = ancestor {
+        if block_keyword(block.kind()) == Some(found) && !has_block_end(block) {
+            let start = block.child(0)?;
+            let mut cursor = block.walk();
+            let header_end = block
+                .children(&mut cursor)
+                .find(|child| child.kind() == "block_close")?;
+            return Some(Diagnostic {
+                kind: DiagnosticKind::UnclosedBlock {
+                    keyword: found.to_string(),
+                    end: end.range(),
+                },
+                range: span(start.range(), header_end.range()),
+            });
+        }
+        ancestor = block.parent();
+    }
+    None
+}
+
+fn has_block_end(block: Node) -> bool {
+    let mut cursor = block.walk();
+    let has_end = block
+        .children(&mut cursor)
+        .any(|child| matches!(child.kind(), "block_end" | "mismatched_block_end"));
+    has_end
+}
+
+fn tag_keyword(kind: &str) -> Option<&'static str> {
+    match kind {
+        "html_tag" => Some("html"),
+        "debug_tag" => Some("debug"),
+        "const_tag" => Some("const"),
+        "render_tag" => Some("render"),
+        _ => None,
+    }
+}
+
+fn block_keyword(kind: &str) -> Option<&'static str> {
+    match kind {
+        "if_block" => Some("if"),
+        "each_block" => Some("each"),
+        "await_block" => Some("await"),
+        "key_block" => Some("key"),
+        "snippet_block" => Some("snippet"),
+        _ => None,
+    }
+}
+
+fn span(start: Range, end: Range) -> Range {
+    Range {
+        start_byte: start.start_byte,
+        end_byte: end.end_byte,
+        start_point: start.start_point,
+        end_point: end.end_point,
+    }
+}
@@ -19,6 +19,25 @@
 //! ## Components
 //! - Uppercase tag names are parsed as components: `<Button>`, `<MyComponent>`
 //!
+//! ## Analysis
+//! - [`css_usage`]: `<style>` selectors matched against the markup, with the
+//!   selectors no element can match
+//! - [`custom_properties`]: custom properties from `<style>` blocks and
+//!   `style:--name` directives, added to a project-wide `var()` graph
+//! - [`diagnostics`]: typed diagnostics for recovered and misplaced markup,
+//!   such as `{#if}` closed by `{/each}`, blocks inside attribute values,
+//!   repeated attributes, or `<div>` inside `<p>`
+//! - [`entities`]: character reference decoding with the full WHATWG table,
+//!   shared with the HTML grammar
+//! - [`patterns`]: the individual names bound by each-block, await-branch,
+//!   and snippet-parameter destructuring patterns
+//! - [`scopes`]: a scope tree and symbol table built from the layered locals
+//!   queries, with definition and reference lookups by offset
+//! - [`serialize`]: lossless printing, and a normalizing printer that writes
+//!   out implied end tags and expands shorthand attributes
+//! - [`snippets`]: `{@render}` tags resolved to the `{#snippet}` blocks they
+//!   call, with implicit component snippet props and unused snippets
+//!
 //! ## Example
 //!
 //! ```rust
@@ -33,10 +52,23 @@
 //! assert!(!tree.root_node().has_error());
 //! ```
 
+use std::sync::OnceLock;
+
 use tree_sitter_language::LanguageFn;
 
+pub mod css_usage;
+pub mod custom_properties;
+pub mod diagnostics;
+pub mod patterns;
+pub mod scopes;
+pub mod serialize;
+pub mod snippets;
+
+pub use tree_sitter_html::entities;
+
 extern "C" {
     fn tree_sitter_svelte() -> *const ();
+    fn tree_sitter_svelte_scripting() -> *const ();
 }
 
 /// The tree-sitter [`LanguageFn`] for Svelte.
@@ -47,6 +79,16 @@ pub fn language() -> tree_sitter::Language {
     LANGUAGE.into()
 }
 
+/// The tree-sitter [`LanguageFn`] for Svelte with scripting enabled, so
+/// `<noscript>` content is raw text. See
+/// [`tree_sitter_html::LANGUAGE_SCRIPTING`].
+pub const LANGUAGE_SCRIPTING: LanguageFn = unsafe { LanguageFn::from_raw(scripting_language) };
+
+extern "C" fn scripting_language() -> *const () {
+    static LANGUAGE: OnceLock<usize> = OnceLock::new();
+    *LANGUAGE.get_or_init(|| unsafe { tree_sitter_svelte_scripting() } as usize) as *const ()
+}
+
 /// Scanner profiling counters exposed by the opt-in `TREE_SITTER_SVELTE_PROFILE`
 /// build flag. When profiling is disabled, the exported functions still exist
 /// and return zeroed counters.
@@ -351,7 +393,7 @@ mod tests {
             "keyword.control",
         ] {
             assert!(
-                captures.iter().any(|name| *name == capture),
+                captures.contains(&capture),
                 "missing @{capture} from Svelte highlights query"
             );
         }
@@ -401,10 +443,7 @@ mod tests {
             let query = tree_sitter::Query::new(&language, source)
                 .unwrap_or_else(|error| panic!("{name} query should compile: {error}"));
             assert!(
-                query
-                    .capture_names()
-                    .iter()
-                    .any(|capture| *capture == expected_capture),
+                query.capture_names().contains(&expected_capture),
                 "missing @{expected_capture} from Svelte {name} query"
             );
         }
@@ -0,0 +1,586 @@
+//! Destructuring pattern splitter for Svelte binding sites.
+//!
+//! The scanner captures binding patterns (`{#each items as { id, name }}`,
+//! `{:then [first, ...rest]}`, snippet parameters) as one opaque `js`/`ts`
+//! token so the grammar never has to understand JavaScript. Editor features
+//! still need the individual names, so this module walks the pattern text and
+//! yields every bound identifier with its range.
+//!
+//! Supported forms: identifiers, object patterns (shorthand, `key: pattern`,
+//! string/numeric/computed keys), array patterns with holes, defaults, rest
+//! elements, and a trailing TypeScript annotation (`value: string`,
+//! `{ a }: Props`, `label?: string`). Default values and type annotations are
+//! skipped, never reported as bindings.
+//!
+//! ```rust
+//! use tree_sitter_htmlx_svelte::{patterns, LANGUAGE};
+//!
+//! let source = "{#each items as { id, name: [first] }}{/each}";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let names: Vec<_> = patterns::definitions(tree.root_node(), source)
+//!     .into_iter()
+//!     .map(|binding| binding.name)
+//!     .collect();
+//! assert_eq!(names, ["id", "first"]);
+//! ```
+
+use tree_sitter::{Node, Range};
+
+use tree_sitter_html::span::subrange;
+
+/// One identifier introduced by a binding pattern.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PatternBinding {
+    pub name: String,
+    /// Span of the identifier itself.
+    pub range: Range,
+    /// Span of the default value after `=`, if any.
+    pub default_value: Option<Range>,
+    /// `true` for `...rest` elements.
+    pub is_rest: bool,
+    /// The syntax that introduced the pattern.
+    pub site: BindingSite,
+}
+
+/// Where a binding pattern appears.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BindingSite {
+    /// `{#each items as pattern}`
+    EachItem,
+    /// `{#each items as item, index}`
+    EachIndex,
+    /// `{:then pattern}` or `{#await promise then pattern}`
+    AwaitThen,
+    /// `{:catch pattern}` or `{#await promise catch pattern}`
+    AwaitCatch,
+    /// `{#snippet name(pattern)}`
+    SnippetParameter,
+    /// `{@const pattern = value}`
+    ConstTag,
+    /// `{let pattern = value}` or `{const pattern = value}`
+    Declaration,
+}
+
+/// Collect the bindings introduced by every pattern in the tree, in source
+/// order.
+pub fn definitions(root: Node, source: &str) -> Vec<PatternBinding> {
+    let mut bindings = Vec::new();
+    visit(root, source, &mut bindings);
+    bindings
+}
+
+/// Split one `pattern` node (or its `js`/`ts` content) into its bindings.
+///
+/// Returns `None` when the node is not a recognizable binding site.
+pub fn bindings(pattern: Node, source: &str) -> Option<Vec<PatternBinding>> {
+    let pattern = if pattern.kind() == "pattern" {
+        pattern
+    } else {
+        pattern
+            .parent()
+            .filter(|parent| parent.kind() == "pattern")?
+    };
+    let site = binding_site(pattern, source)?;
+    let content = pattern.child_by_field_name("content")?;
+    Some(split(content, source, site))
+}
+
+/// Split the `pattern = value` content of a `{@const}` or declaration tag.
+///
+/// Returns the pattern's bindings and the absolute byte offset where the
+/// initializer starts, if there is one.
+pub(crate) fn declarator(
+    content: Node,
+    source: &str,
+    site: BindingSite,
+) -> (Vec<PatternBinding>, Option<usize>) {
+    let mut splitter = Splitter {
+        text: &source[content.byte_range()],
+        offset: 0,
+        base: content,
+        site,
+        bindings: Vec::new(),
+    };
+    splitter.pattern(false);
+    splitter.skip_trivia();
+    if splitter.eat(':') {
+        splitter.skip_value(true);
+    }
+
+    let initializer = splitter
+        .eat('=')
+        .then(|| content.start_byte() + splitter.offset);
+    (splitter.bindings, initializer)
+}
+
+fn visit(node: Node, source: &str, bindings: &mut Vec<PatternBinding>) {
+    if node.kind() == "pattern" {
+        if let Some(found) = self::bindings(node, source) {
+            bindings.extend(found);
+        }
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        visit(child, source, bindings);
+    }
+}
+
+fn binding_site(pattern: Node, source: &str) -> Option<BindingSite> {
+    let parent = pattern.parent()?;
+    match parent.kind() {
+        "snippet_parameters" => Some(BindingSite::SnippetParameter),
+        "each_block" => {
+            if parent.child_by_field_name("index") == Some(pattern) {
+                Some(BindingSite::EachIndex)
+            } else {
+                Some(BindingSite::EachItem)
+            }
+        }
+        "await_block" => await_site(parent.child_by_field_name("shorthand")?, source),
+        "await_branch" | "orphan_branch" => await_site(parent.child_by_field_name("kind")?, source),
+        _ => None,
+    }
+}
+
+fn await_site(kind: Node, source: &str) -> Option<BindingSite> {
+    match &source[kind.byte_range()] {
+        "then" => Some(BindingSite::AwaitThen),
+        "catch" => Some(BindingSite::AwaitCatch),
+        _ => None,
+    }
+}
+
+fn split(content: Node, source: &str, site: BindingSite) -> Vec<PatternBinding> {
+    let mut splitter = Splitter {
+        text: &source[content.byte_range()],
+        offset: 0,
+        base: content,
+        site,
+        bindings: Vec::new(),
+    };
+    splitter.binding_element(false);
+    splitter.bindings
+}
+
+struct Splitter<'a, 'tree> {
+    text: &'a str,
+    offset: usize,
+    base: Node<'tree>,
+    site: BindingSite,
+    bindings: Vec<PatternBinding>,
+}
+
+impl Splitter<'_, '_> {
+    /// `pattern ('=' default)?`
+    fn binding_element(&mut self, is_rest: bool) {
+        let start = self.bindings.len();
+        self.pattern(is_rest);
+
+        self.skip_trivia();
+        // Optional parameter marker and type annotation: `value?: Type`.
+        self.eat('?');
+        self.skip_trivia();
+        if self.eat(':') {
+            self.skip_value(true);
+            self.skip_trivia();
+        }
+
+        if self.peek() == Some('=') && !self.rest().starts_with("=>") {
+            self.offset += 1;
+            self.skip_trivia();
+            let value_start = self.offset;
+            self.skip_value(false);
+            let value_end = self.trimmed_end(value_start);
+            if value_end > value_start {
+                let default = self.range(value_start, value_end);
+                // A default on a destructured element applies to the whole
+                // element; report it on the single binding it introduced.
+                if self.bindings.len() == start + 1 {
+                    self.bindings[start].default_value = Some(default);
+                }
+            }
+        }
+    }
+
+    fn pattern(&mut self, is_rest: bool) {
+        self.skip_trivia();
+        match self.peek() {
+            Some('{') => self.object_pattern(),
+            Some('[') => self.array_pattern(),
+            Some(c) if is_ident_start(c) => {
+                let start = self.offset;
+                self.identifier();
+                self.push(start, self.offset, is_rest);
+            }
+            _ => {}
+        }
+    }
+
+    fn object_pattern(&mut self) {
+        self.offset += 1;
+        loop {
+            self.skip_trivia();
+            match self.peek() {
+                None => return,
+                Some('}') => {
+                    self.offset += 1;
+                    return;
+                }
+                Some(',') => {
+                    self.offset += 1;
+                    continue;
+                }
+                _ => {}
+            }
+
+            if self.rest().starts_with("...") {
+                self.offset += 3;
+                self.binding_element(true);
+            } else {
+                self.property();
+            }
+
+            if !self.skip_to_separator('}') {
+                return;
+            }
+        }
+    }
+
+    /// `identifier ('=' default)?` or `key ':' binding_element`.
+    fn property(&mut self) {
+        let start = self.offset;
+        let shorthand = match self.peek() {
+            Some('[') => {
+                self.skip_balanced();
+                false
+            }
+            Some(quote @ ('"' | '\'')) => {
+                self.skip_string(quote);
+                false
+            }
+            Some(c) if is_ident_start(c) => {
+                self.identifier();
+                true
+            }
+            Some(c) if c.is_ascii_digit() => {
+                while self
+                    .peek()
+                    .is_some_and(|c| c.is_ascii_alphanumeric() || c == '.')
+                {
+                    self.offset += 1;
+                }
+                false
+            }
+            _ => return,
+        };
+        let key_end = self.offset;
+
+        self.skip_trivia();
+        if self.eat(':') {
+            self.binding_element(false);
+            return;
+        }
+
+        if shorthand {
+            self.offset = start;
+            self.binding_element(false);
+        } else {
+            self.offset = key_end;
+        }
+    }
+
+    fn array_pattern(&mut self) {
+        self.offset += 1;
+        loop {
+            self.skip_trivia();
+            match self.peek() {
+                None => return,
+                Some(']') => {
+                    self.offset += 1;
+                    return;
+                }
+                // Holes: `[, second]`
+                Some(',') => {
+                    self.offset += 1;
+                    continue;
+                }
+                _ => {}
+            }
+
+            if self.rest().starts_with("...") {
+                self.offset += 3;
+                self.binding_element(true);
+            } else {
+                self.binding_element(false);
+            }
+
+            if !self.skip_to_separator(']') {
+                return;
+            }
+        }
+    }
+
+    /// Skip anything left in the current element, then stop before `,` or
+    /// `close`. Returns `false` at the end of input.
+    fn skip_to_separator(&mut self, close: char) -> bool {
+        self.skip_trivia();
+        while let Some(c) = self.peek() {
+            if c == ',' || c == close {
+                return true;
+            }
+            let before = self.offset;
+            self.skip_value(false);
+            if self.offset == before {
+                // Stray closer or `=`: step over it so recovery always advances.
+                self.offset += c.len_utf8();
+            }
+            self.skip_trivia();
+        }
+        false
+    }
+
+    /// Skip an expression (or, with `angle_brackets`, a type) until a
+    /// depth-zero `,`, assignment `=`, or unmatched closer.
+    fn skip_value(&mut self, angle_brackets: bool) {
+        let mut depth = 0usize;
+        while let Some(c) = self.peek() {
+            match c {
+                '"' | '\'' | '`' => {
+                    self.skip_string(c);
+                    continue;
+                }
+                '/' if self.rest().starts_with("//") || self.rest().starts_with("/*") => {
+                    self.skip_trivia();
+                    continue;
+                }
+                '=' => {
+                    let run = self.rest().chars().take_while(|&c| c == '=').count();
+                    let arrow = run == 1 && self.rest()[1..].starts_with('>');
+                    let compound = self.text[..self.offset]
+                        .ends_with(['!', '<', '>', '+', '-', '*', '/', '%', '&', '|', '^', '?']);
+                    if depth == 0 && run == 1 && !arrow && !compound {
+                        return;
+                    }
+                    // `==`, `=>` and compound operators are stepped over as a
+                    // unit so `=>` never closes an angle bracket.
+                    self.offset += run + usize::from(arrow);
+                    continue;
+                }
+                '<' if angle_brackets => depth += 1,
+                '>' if angle_brackets && depth > 0 => depth -= 1,
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' if depth == 0 => return,
+                ')' | ']' | '}' => depth -= 1,
+                ',' if depth == 0 => return,
+                _ => {}
+            }
+            self.offset += c.len_utf8();
+        }
+    }
+
+    fn skip_balanced(&mut self) {
+        let Some(open) = self.peek() else {
+            return;
+        };
+        self.offset += open.len_utf8();
+        self.skip_value(false);
+        self.eat(']');
+    }
+
+    fn skip_string(&mut self, quote: char) {
+        self.offset += quote.len_utf8();
+        while let Some(c) = self.peek() {
+            self.offset += c.len_utf8();
+            if c == '\\' {
+                if let Some(escaped) = self.peek() {
+                    self.offset += escaped.len_utf8();
+                }
+            } else if c == quote {
+                return;
+            }
+        }
+    }
+
+    fn skip_trivia(&mut self) {
+        let text = self.text;
+        loop {
+            let rest = &text[self.offset..];
+            let trimmed = rest.trim_start();
+            self.offset += rest.len() - trimmed.len();
+
+            if trimmed.starts_with("//") {
+                self.offset += trimmed.find('\n').unwrap_or(trimmed.len());
+            } else if let Some(comment) = trimmed.strip_prefix("/*") {
+                self.offset += comment.find("*/").map_or(trimmed.len(), |end| end + 4);
+            } else {
+                return;
+            }
+        }
+    }
+
+    fn identifier(&mut self) {
+        while self.peek().is_some_and(is_ident_char) {
+            self.offset += self.peek().map_or(0, char::len_utf8);
+        }
+    }
+
+    fn push(&mut self, start: usize, end: usize, is_rest: bool) {
+        self.bindings.push(PatternBinding {
+            name: self.text[start..end].to_string(),
+            range: self.range(start, end),
+            default_value: None,
+            is_rest,
+            site: self.site,
+        });
+    }
+
+    fn trimmed_end(&self, start: usize) -> usize {
+        start + self.text[start..self.offset].trim_end().len()
+    }
+
+    fn range(&self, start: usize, end: usize) -> Range {
+        subrange(self.base, self.text, start, end)
+    }
+
+    fn rest(&self) -> &str {
+        &self.text[self.offset..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.offset += expected.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Identifier tokens of a JavaScript expression that are not property
+/// names or object literal keys, with their byte offsets into `text`.
+///
+/// Strings, comments, and numbers are skipped; template literals contribute
+/// the identifiers of their `${...}` substitutions only.
+pub(crate) fn identifiers(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut tokens = Vec::new();
+    // One entry per open `{`, `true` when it opened a template substitution.
+    let mut braces = Vec::new();
+    let mut previous = None;
+    let mut offset = 0;
+
+    while let Some(c) = text[offset..].chars().next() {
+        let start = offset;
+        offset += c.len_utf8();
+        let rest = &text[offset..];
+        match c {
+            '"' | '\'' => {
+                offset = skip_string(text, offset, c);
+                previous = Some(c);
+            }
+            '`' => {
+                offset = skip_template(text, offset, &mut braces);
+                previous = Some(c);
+            }
+            '/' if rest.starts_with('/') => {
+                offset = rest
+                    .find('\n')
+                    .map_or(text.len(), |newline| offset + newline);
+            }
+            '/' if rest.starts_with('*') => {
+                offset = rest[1..]
+                    .find("*/")
+                    .map_or(text.len(), |close| offset + 1 + close + 2);
+            }
+            '.' if rest.starts_with("..") => {
+                offset += 2;
+                previous = Some(',');
+            }
+            '{' => {
+                braces.push(false);
+                previous = Some(c);
+            }
+            '}' => {
+                if braces.pop() == Some(true) {
+                    offset = skip_template(text, offset, &mut braces);
+                    previous = Some('`');
+                } else {
+                    previous = Some(c);
+                }
+            }
+            c if c.is_ascii_digit() => {
+                offset += rest
+                    .find(|next: char| !(is_ident_char(next) || next == '.'))
+                    .unwrap_or(rest.len());
+                previous = Some('0');
+            }
+            c if is_ident_start(c) => {
+                offset += rest
+                    .find(|next: char| !is_ident_char(next))
+                    .unwrap_or(rest.len());
+                let is_property = previous == Some('.');
+                let is_key = matches!(previous, Some('{' | ','))
+                    && text[offset..].trim_start().starts_with(':');
+                if !is_property && !is_key {
+                    tokens.push((start, &text[start..offset]));
+                }
+                previous = Some('a');
+            }
+            c if c.is_whitespace() => {}
+            c => previous = Some(c),
+        }
+    }
+
+    tokens.into_iter()
+}
+
+/// Offset just past the closing `quote` of a string whose body starts at
+/// `offset`.
+fn skip_string(text: &str, mut offset: usize, quote: char) -> usize {
+    let mut chars = text[offset..].chars();
+    while let Some(c) = chars.next() {
+        offset += c.len_utf8();
+        if c == '\\' {
+            offset += chars.next().map_or(0, char::len_utf8);
+        } else if c == quote {
+            break;
+        }
+    }
+    offset
+}
+
+/// Offset just past the end of the template literal text starting at
+/// `offset`: the closing backtick, or the `${` of the next substitution,
+/// which is pushed onto `braces`.
+fn skip_template(text: &str, mut offset: usize, braces: &mut Vec<bool>) -> usize {
+    let mut chars = text[offset..].chars();
+    while let Some(c) = chars.next() {
+        offset += c.len_utf8();
+        match c {
+            '\\' => offset += chars.next().map_or(0, char::len_utf8),
+            '`' => break,
+            '$' if text[offset..].starts_with('{') => {
+                braces.push(true);
+                return offset + 1;
+            }
+            _ => {}
+        }
+    }
+    offset
+}
+
+pub(crate) fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == '$'
+}
+
+pub(crate) fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
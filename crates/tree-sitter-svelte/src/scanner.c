@@ -1,16 +1,18 @@
 #define tree_sitter_htmlx_external_scanner_create      htmlx_create
+#define tree_sitter_htmlx_external_scanner_create_scripting htmlx_create_scripting
 #define tree_sitter_htmlx_external_scanner_destroy     htmlx_destroy
 #define tree_sitter_htmlx_external_scanner_scan        htmlx_scanner_scan
 #define tree_sitter_htmlx_external_scanner_serialize   htmlx_serialize
 #define tree_sitter_htmlx_external_scanner_deserialize htmlx_deserialize
+#define TREE_SITTER_HTMLX_VENDORED
 
 // Vendored HTMLX scanner (committed in this crate for portability).
 #include "htmlx/scanner.c"
 
-// Svelte external token indices (after HTMLX's 26 tokens: 0-25)
-// HTML tokens (0-8) + HTMLX tokens (9-25, includes UNTERMINATED_TAG_END_OPEN at 25)
+// Svelte external token indices (after HTMLX's 31 tokens: 0-30)
+// HTML tokens (0-13) + HTMLX tokens (14-30, includes UNTERMINATED_TAG_END_OPEN at 30)
 enum {
-    ITERATOR_EXPRESSION_JS = 26,
+    ITERATOR_EXPRESSION_JS = 31,
     ITERATOR_EXPRESSION_TS,
     BINDING_PATTERN_JS,
     BINDING_PATTERN_TS,
@@ -827,6 +829,11 @@ void *tree_sitter_svelte_external_scanner_create(void) {
     return htmlx_create();
 }
 
+// <noscript> content is raw text, as in a browser with scripting enabled.
+void *tree_sitter_svelte_external_scanner_create_scripting(void) {
+    return htmlx_create_scripting();
+}
+
 void tree_sitter_svelte_external_scanner_destroy(void *payload) {
     htmlx_destroy(payload);
 }
@@ -842,3 +849,17 @@ void tree_sitter_svelte_external_scanner_deserialize(void *payload, const char *
 bool tree_sitter_svelte_external_scanner_scan(void *payload, TSLexer *lexer, const bool *valid) {
     return svelte_scan(payload, lexer, valid);
 }
+
+const TSLanguage *tree_sitter_svelte(void);
+
+static TSLanguage scripting_language;
+
+// The Svelte language with scripting enabled: a copy of the generated language
+// whose scanners are created with the scripting flag set. Not thread-safe;
+// the Rust crate calls it once behind a lock.
+const TSLanguage *tree_sitter_svelte_scripting(void) {
+    scripting_language = *tree_sitter_svelte();
+    scripting_language.external_scanner.create =
+        tree_sitter_svelte_external_scanner_create_scripting;
+    return &scripting_language;
+}
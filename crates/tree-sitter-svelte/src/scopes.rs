@@ -0,0 +1,463 @@
+//! Scope tree and symbol table built from the layered locals queries.
+//!
+//! The HTML, HTMLX, and Svelte `locals.scm` files declare scopes, definitions,
+//! and references, but captures alone only say *where* names appear.
+//! [`ScopeTree`] runs the three layers together and does the glue every
+//! consumer otherwise reimplements:
+//!
+//! - binding patterns and `{@const}`/`{let}` declarators are split into
+//!   individual names with [`patterns`](crate::patterns);
+//! - reference captures (opaque `js`/`ts` expressions) are split into
+//!   identifier tokens, skipping property names;
+//! - every reference is resolved to the nearest visible definition.
+//!
+//! A definition is visible in the whole scope that declares it, as Svelte
+//! hoists `{@const}` and snippets. Snippet names belong to the scope around the
+//! `{#snippet}` block; its parameters belong to the block itself. The header
+//! expression of `{#each}` and `{#await}` is resolved outside the block, so
+//! `{#each item.children as item}` refers to an outer `item`.
+//!
+//! Names from `<script>` are not tracked: references to props, imports, and
+//! globals stay unresolved.
+//!
+//! ```rust
+//! use tree_sitter_htmlx_svelte::{scopes::ScopeTree, LANGUAGE};
+//!
+//! let source = "{#each items as { id }}{id}{/each}";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let scopes = ScopeTree::new(&tree, source);
+//! let use_site = source.rfind("id").unwrap();
+//! let definition = scopes.definition_at(use_site).expect("id is bound");
+//! assert_eq!(scopes.definition(definition).name, "id");
+//! assert_eq!(scopes.references_to(definition).count(), 1);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use tree_sitter::{Node, Query, QueryCursor, Range, StreamingIterator, Tree};
+
+use tree_sitter_html::span::subrange;
+
+use crate::patterns::{self, identifiers, BindingSite};
+
+/// The HTML, HTMLX, and Svelte locals queries, in inheritance order.
+const LAYERED_LOCALS_QUERY: &str = concat!(
+    include_str!("../queries/htmlx/html/locals.scm"),
+    "\n",
+    include_str!("../queries/htmlx/locals.scm"),
+    "\n",
+    include_str!("../queries/locals.scm"),
+);
+
+/// Index of a scope in [`ScopeTree::scopes`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ScopeId(pub usize);
+
+/// Index of a definition in [`ScopeTree::definitions`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DefinitionId(pub usize);
+
+/// A `@local.scope` node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Scope {
+    /// Node kind of the scope, e.g. `document`, `element`, `each_block`.
+    pub kind: &'static str,
+    pub range: Range,
+    pub parent: Option<ScopeId>,
+    pub children: Vec<ScopeId>,
+    /// Names declared directly in this scope, in source order.
+    pub definitions: Vec<DefinitionId>,
+}
+
+/// What a definition declares.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DefinitionKind {
+    /// A block binding, `{@const}`, declaration tag, or `let:` directive.
+    Variable,
+    /// A `{#snippet}` name.
+    Function,
+}
+
+/// One declared name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Definition {
+    pub name: String,
+    /// Span of the name itself.
+    pub range: Range,
+    pub kind: DefinitionKind,
+    pub scope: ScopeId,
+}
+
+/// One identifier read by the template.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reference {
+    pub name: String,
+    pub range: Range,
+    /// The scope the reference is resolved from.
+    pub scope: ScopeId,
+    /// The definition it resolves to; `None` for script bindings and globals.
+    pub definition: Option<DefinitionId>,
+}
+
+/// Scopes, definitions, and resolved references of one tree.
+#[derive(Clone, Debug)]
+pub struct ScopeTree {
+    scopes: Vec<Scope>,
+    definitions: Vec<Definition>,
+    references: Vec<Reference>,
+}
+
+impl ScopeTree {
+    /// Build the scope tree for a Svelte tree.
+    pub fn new(tree: &Tree, source: &str) -> Self {
+        let root = tree.root_node();
+        let query = Query::new(&root.language(), LAYERED_LOCALS_QUERY)
+            .expect("Svelte locals queries should compile");
+
+        let mut scope_nodes = HashSet::from([root.id()]);
+        let mut definition_nodes = Vec::new();
+        let mut reference_nodes = Vec::new();
+        let mut seen = HashSet::new();
+
+        let capture_names = query.capture_names();
+        let mut cursor = QueryCursor::new();
+        let mut captures = cursor.captures(&query, root, source.as_bytes());
+        while let Some((query_match, capture_index)) = captures.next() {
+            let capture = query_match.captures[*capture_index];
+            let name = capture_names[capture.index as usize];
+            // The layers repeat each other's patterns; keep one capture each.
+            if !seen.insert((capture.node.id(), name)) {
+                continue;
+            }
+            match name {
+                "local.scope" => {
+                    scope_nodes.insert(capture.node.id());
+                }
+                "local.definition" => {
+                    definition_nodes.push((capture.node, DefinitionKind::Variable))
+                }
+                "local.definition.function" => {
+                    definition_nodes.push((capture.node, DefinitionKind::Function))
+                }
+                "local.reference" => reference_nodes.push(capture.node),
+                _ => {}
+            }
+        }
+
+        let mut builder = Builder {
+            source,
+            tree: ScopeTree {
+                scopes: Vec::new(),
+                definitions: Vec::new(),
+                references: Vec::new(),
+            },
+            scope_ids: HashMap::new(),
+        };
+        builder.collect_scopes(root, None, &scope_nodes);
+
+        let mut pending = Vec::new();
+        for (node, kind) in definition_nodes {
+            builder.define(node, kind, &mut pending);
+        }
+        for node in reference_nodes {
+            builder.reference(node, &mut pending);
+        }
+
+        let mut tree = builder.tree;
+        pending.sort_by_key(|reference| reference.range.start_byte);
+        for mut reference in pending {
+            reference.definition = tree.lookup(reference.scope, &reference.name);
+            tree.references.push(reference);
+        }
+        tree
+    }
+
+    /// The `document` scope.
+    pub fn root(&self) -> ScopeId {
+        ScopeId(0)
+    }
+
+    pub fn scope(&self, id: ScopeId) -> &Scope {
+        &self.scopes[id.0]
+    }
+
+    /// Every scope, parents before children.
+    pub fn scopes(&self) -> &[Scope] {
+        &self.scopes
+    }
+
+    pub fn definition(&self, id: DefinitionId) -> &Definition {
+        &self.definitions[id.0]
+    }
+
+    /// Every definition, in source order.
+    pub fn definitions(&self) -> &[Definition] {
+        &self.definitions
+    }
+
+    /// Every reference, in source order.
+    pub fn references(&self) -> &[Reference] {
+        &self.references
+    }
+
+    /// The innermost scope containing a byte offset.
+    pub fn scope_at(&self, offset: usize) -> ScopeId {
+        let mut current = self.root();
+        'descend: loop {
+            for &child in &self.scope(current).children {
+                let range = self.scope(child).range;
+                if range.start_byte <= offset && offset < range.end_byte {
+                    current = child;
+                    continue 'descend;
+                }
+            }
+            return current;
+        }
+    }
+
+    /// The definition named at a byte offset, either at the definition itself
+    /// or at a reference that resolves to it.
+    pub fn definition_at(&self, offset: usize) -> Option<DefinitionId> {
+        let contains = |range: &Range| range.start_byte <= offset && offset < range.end_byte;
+        if let Some(index) = self
+            .definitions
+            .iter()
+            .position(|definition| contains(&definition.range))
+        {
+            return Some(DefinitionId(index));
+        }
+        self.references
+            .iter()
+            .find(|reference| contains(&reference.range))
+            .and_then(|reference| reference.definition)
+    }
+
+    /// References that resolve to a definition, in source order.
+    pub fn references_to(&self, definition: DefinitionId) -> impl Iterator<Item = &Reference> {
+        self.references
+            .iter()
+            .filter(move |reference| reference.definition == Some(definition))
+    }
+
+    /// Resolve a name from a scope, walking outwards.
+    pub fn lookup(&self, scope: ScopeId, name: &str) -> Option<DefinitionId> {
+        let mut current = Some(scope);
+        while let Some(id) = current {
+            let scope = self.scope(id);
+            if let Some(&found) = scope
+                .definitions
+                .iter()
+                .find(|&&definition| self.definition(definition).name == name)
+            {
+                return Some(found);
+            }
+            current = scope.parent;
+        }
+        None
+    }
+
+    /// Definitions visible at a byte offset, innermost first. Shadowed names
+    /// are left out.
+    pub fn visible_names_at(&self, offset: usize) -> Vec<&Definition> {
+        let mut names = HashSet::new();
+        let mut visible = Vec::new();
+        let mut current = Some(self.scope_at(offset));
+        while let Some(id) = current {
+            let scope = self.scope(id);
+            for &definition in &scope.definitions {
+                let definition = self.definition(definition);
+                if names.insert(definition.name.as_str()) {
+                    visible.push(definition);
+                }
+            }
+            current = scope.parent;
+        }
+        visible
+    }
+}
+
+struct Builder<'a> {
+    source: &'a str,
+    tree: ScopeTree,
+    scope_ids: HashMap<usize, ScopeId>,
+}
+
+impl Builder<'_> {
+    fn collect_scopes(
+        &mut self,
+        node: Node,
+        parent: Option<ScopeId>,
+        scope_nodes: &HashSet<usize>,
+    ) {
+        let mut parent = parent;
+        if scope_nodes.contains(&node.id()) {
+            let id = ScopeId(self.tree.scopes.len());
+            self.tree.scopes.push(Scope {
+                kind: node.kind(),
+                range: node.range(),
+                parent,
+                children: Vec::new(),
+                definitions: Vec::new(),
+            });
+            if let Some(parent) = parent {
+                self.tree.scopes[parent.0].children.push(id);
+            }
+            self.scope_ids.insert(node.id(), id);
+            parent = Some(id);
+        }
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.collect_scopes(child, parent, scope_nodes);
+        }
+    }
+
+    /// The nearest scope at or above `node`.
+    fn innermost(&self, node: Node) -> ScopeId {
+        let mut current = Some(node);
+        while let Some(candidate) = current {
+            if let Some(&id) = self.scope_ids.get(&candidate.id()) {
+                return id;
+            }
+            current = candidate.parent();
+        }
+        ScopeId(0)
+    }
+
+    /// The nearest scope strictly above `node`.
+    fn enclosing(&self, node: Node) -> ScopeId {
+        node.parent()
+            .map_or(ScopeId(0), |parent| self.innermost(parent))
+    }
+
+    fn define(&mut self, node: Node, kind: DefinitionKind, pending: &mut Vec<Reference>) {
+        let parent = node.parent();
+        let owner = parent.and_then(|parent| parent.parent());
+
+        let bindings = match (
+            parent.map(|parent| parent.kind()),
+            owner.map(|owner| owner.kind()),
+        ) {
+            (Some("pattern"), _) => {
+                let scope = self.innermost(node);
+                patterns::bindings(node, self.source)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|binding| (binding.name, binding.range, scope))
+                    .collect()
+            }
+            (Some("expression_value"), Some(tag @ ("const_tag" | "declaration_tag"))) => {
+                let site = if tag == "const_tag" {
+                    BindingSite::ConstTag
+                } else {
+                    BindingSite::Declaration
+                };
+                let scope = self.innermost(node);
+                let (bindings, initializer) = patterns::declarator(node, self.source, site);
+                if let Some(initializer) = initializer {
+                    self.tokens(node, initializer - node.start_byte(), scope, pending);
+                }
+                bindings
+                    .into_iter()
+                    .map(|binding| (binding.name, binding.range, scope))
+                    .collect()
+            }
+            (Some("snippet_block"), _) => {
+                let scope = parent.map_or(ScopeId(0), |block| self.enclosing(block));
+                vec![(self.text(node).to_string(), node.range(), scope)]
+            }
+            _ => vec![(
+                self.text(node).to_string(),
+                node.range(),
+                self.innermost(node),
+            )],
+        };
+
+        for (name, range, scope) in bindings {
+            if name.is_empty() {
+                continue;
+            }
+            let id = DefinitionId(self.tree.definitions.len());
+            self.tree.definitions.push(Definition {
+                name,
+                range,
+                kind,
+                scope,
+            });
+            self.tree.scopes[scope.0].definitions.push(id);
+        }
+    }
+
+    fn reference(&mut self, node: Node, pending: &mut Vec<Reference>) {
+        let scope = self.reference_scope(node);
+        if node.kind() == "attribute_identifier" {
+            pending.push(Reference {
+                name: self.text(node).to_string(),
+                range: node.range(),
+                scope,
+                definition: None,
+            });
+        } else {
+            self.tokens(node, 0, scope, pending);
+        }
+    }
+
+    /// Block headers are evaluated outside the block they open.
+    fn reference_scope(&self, node: Node) -> ScopeId {
+        let header = node.parent().and_then(|expression| {
+            let block = expression.parent()?;
+            let is_header = matches!(block.kind(), "each_block" | "await_block")
+                && block.child_by_field_name("expression") == Some(expression);
+            is_header.then_some(block)
+        });
+        match header {
+            Some(block) => self.enclosing(block),
+            None => self.innermost(node),
+        }
+    }
+
+    fn tokens(&self, node: Node, from: usize, scope: ScopeId, pending: &mut Vec<Reference>) {
+        let text = self.text(node);
+        for (offset, name) in identifiers(&text[from..]) {
+            if is_reserved(name) {
+                continue;
+            }
+            let start = from + offset;
+            pending.push(Reference {
+                name: name.to_string(),
+                range: subrange(node, text, start, start + name.len()),
+                scope,
+                definition: None,
+            });
+        }
+    }
+
+    fn text(&self, node: Node) -> &str {
+        &self.source[node.byte_range()]
+    }
+}
+
+fn is_reserved(name: &str) -> bool {
+    matches!(
+        name,
+        "as" | "async"
+            | "await"
+            | "delete"
+            | "false"
+            | "function"
+            | "in"
+            | "instanceof"
+            | "keyof"
+            | "new"
+            | "null"
+            | "of"
+            | "satisfies"
+            | "this"
+            | "true"
+            | "typeof"
+            | "void"
+    )
+}
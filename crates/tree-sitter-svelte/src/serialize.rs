@@ -0,0 +1,24 @@
+//! Writing Svelte trees back out as markup.
+//!
+//! [`print`] reproduces the parsed source byte for byte, blocks and tags
+//! included. [`normalize`] is the HTMLX normalizer from
+//! [`tree_sitter_htmlx::serialize`]: it applies the HTML normalizations,
+//! leaves component names as written, and expands `{name}` shorthand to
+//! `name={name}`.
+//!
+//! ```rust
+//! use tree_sitter_htmlx_svelte::{serialize, LANGUAGE};
+//!
+//! let source = "{#if open}<p class=note>{text}<Icon {name} />{/if}";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! assert_eq!(serialize::print(tree.root_node(), source), source);
+//! assert_eq!(
+//!     serialize::normalize(tree.root_node(), source),
+//!     r#"{#if open}<p class="note">{text}<Icon name={name} /></p>{/if}"#
+//! );
+//! ```
+
+pub use tree_sitter_htmlx::serialize::{normalize, print};
@@ -0,0 +1,371 @@
+//! Cross-reference index between `{#snippet}` definitions and their uses.
+//!
+//! [`LOCALS_QUERY`](crate::LOCALS_QUERY) marks snippet names as function
+//! definitions and `{@render}` expressions as references, but leaves the
+//! matching to the consumer. [`SnippetIndex`] does that matching on top of
+//! [`ScopeTree`]: a snippet is visible inside the scope that contains it
+//! (including before its definition, since Svelte hoists snippets), and inner
+//! scopes shadow outer ones. Snippet parameters and block bindings also shadow
+//! snippets, so `{@render cell()}` inside `{#snippet row(cell)}` resolves to
+//! the parameter.
+//!
+//! ```rust
+//! use tree_sitter_htmlx_svelte::{snippets::SnippetIndex, LANGUAGE};
+//!
+//! let source = "{#snippet row(value)}<td>{value}</td>{/snippet}{@render row(1)}";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&LANGUAGE.into()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let index = SnippetIndex::new(&tree, source);
+//! let render = &index.renders()[0];
+//! let snippet = index.definition_for(render).expect("row is defined");
+//! assert_eq!(snippet.name, "row");
+//! assert_eq!(index.unused_snippets().count(), 0);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use tree_sitter::{Node, Range, Tree};
+use tree_sitter_htmlx::is_component_name;
+
+use crate::patterns::{identifiers, is_ident_char, is_ident_start};
+use crate::scopes::{DefinitionId, DefinitionKind, ScopeId, ScopeTree};
+
+/// Index of a snippet in [`SnippetIndex::snippets`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SnippetId(pub usize);
+
+/// A `{#snippet name(...)}` block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SnippetDefinition {
+    pub name: String,
+    /// Span of the snippet name in the block header.
+    pub name_range: Range,
+    /// Span of the whole `{#snippet}...{/snippet}` block.
+    pub range: Range,
+    /// Span of the `@local.scope` node the snippet is declared in.
+    pub scope: Range,
+}
+
+/// What a `{@render}` tag calls.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RenderTarget {
+    /// A snippet block visible from the render tag.
+    Snippet(SnippetId),
+    /// A snippet parameter or block binding visible from the render tag.
+    Binding,
+    /// A plain identifier with no visible definition in the template. These
+    /// are usually props or script bindings, which this index does not track.
+    Unresolved,
+    /// A member or computed callee such as `{@render obj.row()}`.
+    Dynamic,
+}
+
+/// A `{@render callee(...)}` tag.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RenderReference {
+    /// The callee identifier, when the callee is a plain identifier.
+    pub name: Option<String>,
+    /// Span of the callee identifier, when there is one.
+    pub name_range: Option<Range>,
+    /// Span of the whole `{@render ...}` tag.
+    pub range: Range,
+    pub target: RenderTarget,
+}
+
+/// A snippet block written as a direct child of a component, which Svelte
+/// passes to the component as a prop with the snippet's name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImplicitSnippetProp {
+    /// Tag name of the receiving component, e.g. `Table` or `ui.Table`.
+    pub component: String,
+    /// Span of the component element.
+    pub component_range: Range,
+    pub snippet: SnippetId,
+}
+
+/// Snippet definitions, render tags, and implicit snippet props of one tree.
+#[derive(Clone, Debug, Default)]
+pub struct SnippetIndex {
+    snippets: Vec<SnippetDefinition>,
+    renders: Vec<RenderReference>,
+    implicit_props: Vec<ImplicitSnippetProp>,
+    used: HashSet<SnippetId>,
+}
+
+impl SnippetIndex {
+    /// Build the index for a Svelte tree.
+    pub fn new(tree: &Tree, source: &str) -> Self {
+        let root = tree.root_node();
+        let scopes = ScopeTree::new(tree, source);
+        let mut index = SnippetIndex::default();
+
+        // Pass 1: definitions. The scope tree declares each snippet name in
+        // the scope around its block.
+        let definitions: HashMap<usize, DefinitionId> = scopes
+            .definitions()
+            .iter()
+            .enumerate()
+            .filter(|(_, definition)| definition.kind == DefinitionKind::Function)
+            .map(|(id, definition)| (definition.range.start_byte, DefinitionId(id)))
+            .collect();
+        let mut snippet_ids = HashMap::new();
+        let mut snippet_nodes = Vec::new();
+        collect_kind(root, "snippet_block", &mut snippet_nodes);
+        for snippet in snippet_nodes {
+            let Some(name) = snippet.child_by_field_name("name") else {
+                continue;
+            };
+            let Some(&definition) = definitions.get(&name.start_byte()) else {
+                continue;
+            };
+            let id = SnippetId(index.snippets.len());
+            snippet_ids.insert(definition, id);
+            index.snippets.push(SnippetDefinition {
+                name: source[name.byte_range()].to_string(),
+                name_range: name.range(),
+                range: snippet.range(),
+                scope: scopes.scope(scopes.definition(definition).scope).range,
+            });
+
+            if let Some(component) = receiving_component(snippet, source) {
+                index.implicit_props.push(ImplicitSnippetProp {
+                    component: component.0,
+                    component_range: component.1,
+                    snippet: id,
+                });
+                index.used.insert(id);
+            }
+        }
+
+        // Bindings shadow snippets declared in the same scope; between two
+        // snippets of the same name, the first one wins.
+        let resolve_in = |scope: ScopeId, name: &str| -> Option<RenderTarget> {
+            let mut found = None;
+            for &definition in &scopes.scope(scope).definitions {
+                if scopes.definition(definition).name != name {
+                    continue;
+                }
+                match snippet_ids.get(&definition) {
+                    Some(&id) => {
+                        found.get_or_insert(RenderTarget::Snippet(id));
+                    }
+                    None => return Some(RenderTarget::Binding),
+                }
+            }
+            found
+        };
+        let resolve = |from: Node, name: &str| -> Option<RenderTarget> {
+            let mut scope = Some(scopes.scope_at(from.start_byte()));
+            while let Some(current) = scope {
+                if let Some(target) = resolve_in(current, name) {
+                    return Some(target);
+                }
+                scope = scopes.scope(current).parent;
+            }
+            None
+        };
+
+        // Pass 2: render tags.
+        let mut render_nodes = Vec::new();
+        collect_kind(root, "render_tag", &mut render_nodes);
+        for render in render_nodes {
+            let content = render
+                .child_by_field_name("expression")
+                .and_then(|expression| expression.child_by_field_name("content"));
+            let callee = content.and_then(|content| callee(content, source));
+
+            let reference = match callee {
+                Some(Callee::Identifier(name, range)) => {
+                    let target = resolve(render, &name).unwrap_or(RenderTarget::Unresolved);
+                    RenderReference {
+                        name: Some(name),
+                        name_range: Some(range),
+                        range: render.range(),
+                        target,
+                    }
+                }
+                Some(Callee::Dynamic) | None => RenderReference {
+                    name: None,
+                    name_range: None,
+                    range: render.range(),
+                    target: RenderTarget::Dynamic,
+                },
+            };
+            if let RenderTarget::Snippet(id) = reference.target {
+                index.used.insert(id);
+            }
+            index.renders.push(reference);
+        }
+
+        // Pass 3: snippets passed around as values (`<Table {row} />`,
+        // `rows={[row]}`) or referenced from `<script>` at the top level.
+        let mut contents = Vec::new();
+        collect_value_contents(root, &mut contents);
+        for content in contents {
+            for (_, name) in identifiers(&source[content.byte_range()]) {
+                if let Some(RenderTarget::Snippet(id)) = resolve(content, name) {
+                    index.used.insert(id);
+                }
+            }
+        }
+        let mut scripts = Vec::new();
+        collect_script_text(root, source, &mut scripts);
+        for script in scripts {
+            for (_, name) in identifiers(&source[script.byte_range()]) {
+                if let Some(RenderTarget::Snippet(id)) = resolve_in(scopes.root(), name) {
+                    index.used.insert(id);
+                }
+            }
+        }
+
+        index
+    }
+
+    /// Every snippet definition, in source order.
+    pub fn snippets(&self) -> &[SnippetDefinition] {
+        &self.snippets
+    }
+
+    /// Every `{@render}` tag, in source order.
+    pub fn renders(&self) -> &[RenderReference] {
+        &self.renders
+    }
+
+    /// Snippets passed to components as implicit props, in source order.
+    pub fn implicit_props(&self) -> &[ImplicitSnippetProp] {
+        &self.implicit_props
+    }
+
+    pub fn snippet(&self, id: SnippetId) -> &SnippetDefinition {
+        &self.snippets[id.0]
+    }
+
+    /// The snippet a render tag resolves to, if it resolves to one.
+    pub fn definition_for(&self, render: &RenderReference) -> Option<&SnippetDefinition> {
+        match render.target {
+            RenderTarget::Snippet(id) => Some(self.snippet(id)),
+            _ => None,
+        }
+    }
+
+    /// Render tags that call the given snippet.
+    pub fn renders_of(&self, id: SnippetId) -> impl Iterator<Item = &RenderReference> {
+        self.renders
+            .iter()
+            .filter(move |render| render.target == RenderTarget::Snippet(id))
+    }
+
+    /// Render tags whose identifier callee has no visible definition.
+    pub fn unknown_render_targets(&self) -> impl Iterator<Item = &RenderReference> {
+        self.renders
+            .iter()
+            .filter(|render| render.target == RenderTarget::Unresolved)
+    }
+
+    /// Snippets that are never rendered, passed to a component, or
+    /// referenced from an expression or the top-level `<script>`.
+    pub fn unused_snippets(&self) -> impl Iterator<Item = &SnippetDefinition> {
+        self.snippets
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !self.used.contains(&SnippetId(*index)))
+            .map(|(_, snippet)| snippet)
+    }
+}
+
+enum Callee {
+    Identifier(String, Range),
+    Dynamic,
+}
+
+/// Read `name(...)` or `name?.(...)` from a render expression.
+fn callee(content: Node, source: &str) -> Option<Callee> {
+    let text = &source[content.byte_range()];
+    let length = text
+        .char_indices()
+        .find(|&(index, c)| !(is_ident_char(c) && (index > 0 || is_ident_start(c))))
+        .map_or(text.len(), |(index, _)| index);
+    if length == 0 {
+        return Some(Callee::Dynamic);
+    }
+
+    let rest = text[length..].trim_start();
+    if !(rest.starts_with('(') || rest.starts_with("?.(")) {
+        return Some(Callee::Dynamic);
+    }
+
+    let start = content.start_position();
+    let range = Range {
+        start_byte: content.start_byte(),
+        end_byte: content.start_byte() + length,
+        start_point: start,
+        end_point: tree_sitter::Point {
+            row: start.row,
+            column: start.column + length,
+        },
+    };
+    Some(Callee::Identifier(text[..length].to_string(), range))
+}
+
+/// The component a snippet is passed to when it is a direct child of one.
+fn receiving_component(snippet: Node, source: &str) -> Option<(String, Range)> {
+    let element = snippet
+        .parent()
+        .filter(|parent| parent.kind() == "element")?;
+    let start_tag = element.child(0).filter(|tag| tag.kind() == "start_tag")?;
+    let name = &source[start_tag.child_by_field_name("name")?.byte_range()];
+
+    let is_component = is_component_name(name)
+        || matches!(name, "svelte:component" | "svelte:self" | "svelte:boundary");
+    is_component.then(|| (name.to_string(), element.range()))
+}
+
+fn collect_kind<'tree>(node: Node<'tree>, kind: &str, out: &mut Vec<Node<'tree>>) {
+    if node.kind() == kind {
+        out.push(node);
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_kind(child, kind, out);
+    }
+}
+
+/// `js`/`ts` content of expressions that evaluate values, excluding binding
+/// patterns. Render tags are included so snippets passed as arguments count.
+fn collect_value_contents<'tree>(node: Node<'tree>, out: &mut Vec<Node<'tree>>) {
+    match node.kind() {
+        "pattern" | "snippet_name" => return,
+        "js" | "ts" => {
+            out.push(node);
+            return;
+        }
+        _ => {}
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_value_contents(child, out);
+    }
+}
+
+fn collect_script_text<'tree>(node: Node<'tree>, source: &str, out: &mut Vec<Node<'tree>>) {
+    if node.kind() == "element" {
+        let is_script = node
+            .child(0)
+            .and_then(|tag| tag.child_by_field_name("name"))
+            .is_some_and(|name| &source[name.byte_range()] == "script");
+        if is_script {
+            let mut cursor = node.walk();
+            out.extend(
+                node.children(&mut cursor)
+                    .filter(|child| child.kind() == "raw_text"),
+            );
+            return;
+        }
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_script_text(child, source, out);
+    }
+}
@@ -175,3 +175,11 @@ fn test_inline_block_comments_in_tag() {
         r#"(document (element (start_tag name: (tag_name) (tag_comment kind: (block_comment)) (tag_comment kind: (block_comment)) (attribute name: (attribute_name) value: (quoted_attribute_value (attribute_value)))) (end_tag name: (tag_name))))"#
     );
 }
+
+#[test]
+fn test_bogus_comments_from_legacy_markup() {
+    assert_eq!(
+        parse("<?xml version=\"1.0\"?><!ELEMENT br EMPTY><p><![CDATA[x]]>{a}</p>"),
+        "(document (processing_instruction) (bogus_comment) (element (start_tag name: (tag_name)) (cdata_section) (expression content: (js)) (end_tag name: (tag_name))))"
+    );
+}
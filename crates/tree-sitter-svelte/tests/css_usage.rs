@@ -0,0 +1,223 @@
+//! Tests for matching `<style>` selectors against Svelte markup.
+
+use tree_sitter_htmlx_svelte::css_usage::{self, Match, Selector, Template};
+use tree_sitter_htmlx_svelte::LANGUAGE;
+
+fn parse(source: &str) -> tree_sitter::Tree {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&LANGUAGE.into())
+        .expect("Failed to load Svelte grammar");
+    parser.parse(source, None).expect("Failed to parse")
+}
+
+/// The selectors of `markup`'s `<style>` that no element matches.
+fn unused(markup: &str, css: &str) -> Vec<String> {
+    let source = format!("{markup}<style>{css}</style>");
+    let tree = parse(&source);
+    css_usage::check(tree.root_node(), &source)
+        .iter()
+        .map(|diagnostic| {
+            source[diagnostic.range.start_byte..diagnostic.range.end_byte].to_string()
+        })
+        .collect()
+}
+
+fn matches(markup: &str, selector: &str) -> Match {
+    let tree = parse(markup);
+    let template = Template::new(tree.root_node(), markup);
+    let selector = Selector::parse_list(selector).expect("selector parses");
+    assert_eq!(selector.len(), 1);
+    template.matches(&selector[0])
+}
+
+#[test]
+fn test_simple_selectors() {
+    let markup = "<div class=\"card wide\" id=main data-kind=\"Large box\"><span>x</span></div>";
+    assert_eq!(matches(markup, "div"), Match::Yes);
+    assert_eq!(matches(markup, ".card.wide"), Match::Yes);
+    assert_eq!(matches(markup, "#main"), Match::Yes);
+    assert_eq!(matches(markup, "[data-kind~=box]"), Match::Yes);
+    assert_eq!(matches(markup, "[data-kind^=large i]"), Match::Yes);
+    assert_eq!(matches(markup, "[data-kind^=large]"), Match::No);
+    assert_eq!(matches(markup, "span.card"), Match::No);
+    assert_eq!(matches(markup, "#other"), Match::No);
+    assert_eq!(matches(markup, "p"), Match::No);
+    assert_eq!(matches(markup, "*"), Match::Yes);
+}
+
+#[test]
+fn test_combinators() {
+    let markup = "<ul><li class=a>1</li><li class=b>2</li><li class=c><em>3</em></li></ul>";
+    assert_eq!(matches(markup, "ul em"), Match::Yes);
+    assert_eq!(matches(markup, "ul > em"), Match::No);
+    assert_eq!(matches(markup, "li > em"), Match::Yes);
+    assert_eq!(matches(markup, ".a + .b"), Match::Yes);
+    assert_eq!(matches(markup, ".a + .c"), Match::No);
+    assert_eq!(matches(markup, ".a ~ .c"), Match::Yes);
+    assert_eq!(matches(markup, ".c ~ .a"), Match::No);
+    assert_eq!(matches(markup, "ul .b em"), Match::No);
+}
+
+#[test]
+fn test_functional_pseudo_classes() {
+    let markup = "<nav><a class=x href=/>home</a><button>go</button></nav>";
+    assert_eq!(matches(markup, ":is(a, p).x"), Match::Yes);
+    assert_eq!(matches(markup, ":where(p, span)"), Match::No);
+    assert_eq!(matches(markup, "a:not(.x)"), Match::No);
+    assert_eq!(matches(markup, "button:not(.x)"), Match::Yes);
+    assert_eq!(matches(markup, "nav:has(> button)"), Match::Yes);
+    assert_eq!(matches(markup, "nav:has(> p)"), Match::No);
+    assert_eq!(matches(markup, "a:has(+ button)"), Match::Yes);
+    assert_eq!(matches(markup, "a:hover"), Match::Maybe);
+    assert_eq!(matches(markup, "a::after"), Match::Yes);
+}
+
+#[test]
+fn test_dynamic_markup_is_a_maybe() {
+    assert_eq!(matches("<p class={kind}>x</p>", ".warning"), Match::Maybe);
+    assert_eq!(
+        matches("<p class=\"a {kind}\">x</p>", ".warning"),
+        Match::Maybe
+    );
+    assert_eq!(
+        matches("<p class:warning={bad}>x</p>", ".warning"),
+        Match::Maybe
+    );
+    assert_eq!(matches("<p class:warning={bad}>x</p>", ".error"), Match::No);
+    assert_eq!(matches("<p {...rest}>x</p>", "#id[title]"), Match::Maybe);
+    assert_eq!(matches("<p id={id}>x</p>", "#id"), Match::Maybe);
+    assert_eq!(matches("<svelte:element this={tag}/>", "h1"), Match::Maybe);
+    assert_eq!(matches("<svelte:element this=\"h2\"/>", "h1"), Match::No);
+}
+
+#[test]
+fn test_blocks_components_and_snippets() {
+    let markup = "<ul>{#each items as item}<li class=item>{item}</li>{/each}</ul>";
+    assert_eq!(matches(markup, "ul > li"), Match::Yes);
+    assert_eq!(matches(markup, ".item + .item"), Match::Yes);
+
+    let markup = "<h1>a</h1>{#if x}<h2>b</h2>{/if}<p>c</p>";
+    assert_eq!(matches(markup, "h1 + p"), Match::Yes);
+    assert_eq!(matches(markup, "h2 + p"), Match::Yes);
+
+    let markup = "<div><Card><span>x</span></Card></div>";
+    assert_eq!(matches(markup, "div span"), Match::Yes);
+    assert_eq!(matches(markup, "div > span"), Match::Maybe);
+    assert_eq!(matches(markup, "p span"), Match::No);
+
+    let markup = "{#snippet row()}<td>x</td>{/snippet}<table>{@render row()}</table>";
+    assert_eq!(matches(markup, "table td"), Match::Maybe);
+    assert_eq!(matches(markup, "table:has(td)"), Match::Maybe);
+}
+
+#[test]
+fn test_reports_unused_selectors() {
+    assert_eq!(
+        unused(
+            "<div class=a><p>x</p></div>",
+            ".a p {} .b {} .a > span, div {} p + p {}"
+        ),
+        [".b", ".a > span", "p + p"]
+    );
+    assert!(unused("<p>x</p>", "p {} p::first-line {} p:focus {}").is_empty());
+}
+
+#[test]
+fn test_global_selectors_are_not_reported() {
+    assert_eq!(
+        unused(
+            "<div>x</div>",
+            ":global(.x) {} div :global(.y) {} :global .z {} :global { .w {} } .q :global(.r) {}"
+        ),
+        [".q :global(.r)"]
+    );
+}
+
+#[test]
+fn test_nested_rules() {
+    assert_eq!(
+        unused(
+            "<div class=card><h2>t</h2><p>x</p></div>",
+            ".card { h2 {} > p {} &.wide {} span {} .x & {} }"
+        ),
+        ["&.wide", "span", ".x &"]
+    );
+    assert!(unused(
+        "<nav><a>x</a></nav>",
+        "@media (width > 1px) { nav { a {} } }"
+    )
+    .is_empty());
+}
+
+#[test]
+fn test_ranges_are_in_the_component() {
+    let source = "<p>a</p>\n<style>\n  p {}\n  .gone {}\n</style>";
+    let tree = parse(source);
+    let found = css_usage::check(tree.root_node(), source);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].range.start_point, tree_sitter::Point::new(3, 2));
+    assert_eq!(found[0].to_string(), "unused CSS selector `.gone`");
+}
+
+#[test]
+fn test_preprocessed_styles() {
+    let unused_in = |source: &str| -> Vec<String> {
+        let tree = parse(source);
+        css_usage::check(tree.root_node(), source)
+            .iter()
+            .map(|diagnostic| {
+                source[diagnostic.range.start_byte..diagnostic.range.end_byte].to_string()
+            })
+            .collect()
+    };
+    assert_eq!(
+        unused_in(
+            "<div class=card>x</div>\n<style lang=\"scss\">\n$gap: 4px !default;\n\
+             @mixin pad($n) { .inner { padding: $n; } }\n\
+             .card { margin: $gap * 2; // twice\n @include pad(1px); }\n\
+             .icon-#{$name} {}\n%base { color: red; }\n.gone { @extend %base; }\n</style>"
+        ),
+        [".gone"]
+    );
+    assert_eq!(
+        unused_in(
+            "<p>x</p><style lang=\"less\">@c: red; .m(@a) { color: @a; } p { .m(@c); } \
+             .x-@{c} {} span {}</style>"
+        ),
+        ["span"]
+    );
+    // Sass's indented syntax is not read at all.
+    assert!(unused_in("<p>x</p><style lang=\"sass\">span\n  color: red</style>").is_empty());
+}
+
+#[test]
+fn test_preprocessed_parent_suffixes() {
+    let unused_in = |source: &str| -> Vec<String> {
+        let tree = parse(source);
+        css_usage::check(tree.root_node(), source)
+            .iter()
+            .map(|diagnostic| {
+                source[diagnostic.range.start_byte..diagnostic.range.end_byte].to_string()
+            })
+            .collect()
+    };
+    assert_eq!(
+        unused_in("<div class=\"a__el\">x</div><style lang=\"scss\">.a { &__el {} }</style>"),
+        Vec::<String>::new()
+    );
+    assert_eq!(
+        unused_in(
+            "<div class=\"a__el\">x</div>\n<style lang=\"scss\">\n\
+             .a { &__el { color: red; } &--big {} }\n\
+             p { color: red; &-x {} }\n\
+             @use 'sass:math';\n$m: (k: 1);\n\
+             .a__el { width: math.div(10px, 2); }\n</style>"
+        ),
+        ["&--big", "p", "&-x"]
+    );
+    assert_eq!(
+        unused_in("<p-x>x</p-x><p>y</p><style lang=\"less\">p { color: red; &-x {} }</style>"),
+        Vec::<String>::new()
+    );
+}
@@ -0,0 +1,99 @@
+//! Tests for custom properties across Svelte `<style>` blocks and
+//! `style:--name` directives.
+
+use tree_sitter_css_svelte::custom_properties::Graph;
+use tree_sitter_htmlx_svelte::{custom_properties, LANGUAGE};
+
+fn parse(source: &str) -> tree_sitter::Tree {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&LANGUAGE.into())
+        .expect("Failed to load Svelte grammar");
+    parser.parse(source, None).expect("Failed to parse")
+}
+
+fn text(source: &str, range: tree_sitter::Range) -> &str {
+    &source[range.start_byte..range.end_byte]
+}
+
+#[test]
+fn test_style_blocks_use_component_ranges() {
+    let source = "<p>x</p>\n<style>\n  p { --unused: 1px; color: var(--nowhere); }\n</style>";
+    let tree = parse(source);
+    let found = custom_properties::check(tree.root_node(), source);
+
+    let reported: Vec<_> = found
+        .iter()
+        .map(|diagnostic| (text(source, diagnostic.range), diagnostic.to_string()))
+        .collect();
+    assert_eq!(
+        reported,
+        [
+            ("--unused", "`--unused` is never used".to_string()),
+            (
+                "--nowhere",
+                "`--nowhere` is not defined and has no fallback".to_string()
+            ),
+        ]
+    );
+    assert_eq!(found[0].range.start_point, tree_sitter::Point::new(2, 6));
+}
+
+#[test]
+fn test_style_directives_declare_properties() {
+    let source = "<div style:--gap=\"var(--base)\" style:--width={w}><Card style:--tone=\"dark\" /></div>\n<style>div { gap: var(--gap); width: var(--width); }</style>";
+    let tree = parse(source);
+    let mut graph = Graph::default();
+    custom_properties::add_component(&mut graph, tree.root_node(), source, 3);
+
+    let declared: Vec<_> = graph
+        .declarations
+        .iter()
+        .map(|declaration| {
+            (
+                declaration.name.as_str(),
+                declaration.context.clone(),
+                declaration.dependencies.clone(),
+                declaration.document,
+            )
+        })
+        .collect();
+    assert_eq!(
+        declared,
+        [
+            (
+                "--gap",
+                vec!["<div>".to_string()],
+                vec!["--base".to_string()],
+                3
+            ),
+            ("--width", vec!["<div>".to_string()], vec![], 3),
+            ("--tone", vec!["<Card>".to_string()], vec![], 3),
+        ]
+    );
+    assert_eq!(
+        text(source, graph.declarations[0].range),
+        "style:--gap=\"var(--base)\""
+    );
+
+    let messages: Vec<_> = graph.check().iter().map(ToString::to_string).collect();
+    assert_eq!(
+        messages,
+        [
+            "`--base` is not defined and has no fallback",
+            "`--tone` is never used",
+        ]
+    );
+}
+
+#[test]
+fn test_components_share_a_graph() {
+    let parent = "<Child style:--accent=\"red\" />";
+    let child = "<b>x</b><style>b { color: var(--accent); }</style>";
+    let (parent_tree, child_tree) = (parse(parent), parse(child));
+
+    let mut graph = Graph::default();
+    custom_properties::add_component(&mut graph, parent_tree.root_node(), parent, 0);
+    custom_properties::add_component(&mut graph, child_tree.root_node(), child, 1);
+    assert!(graph.check().is_empty(), "{:#?}", graph.check());
+}
@@ -0,0 +1,375 @@
+//! Tests for typed diagnostics over recovered Svelte trees.
+
+use tree_sitter_htmlx::{attributes, content_model};
+use tree_sitter_htmlx_svelte::diagnostics::{self, Diagnostic, DiagnosticKind, MismatchedBlockEnd};
+use tree_sitter_htmlx_svelte::{entities, LANGUAGE};
+
+fn check(source: &str) -> Vec<Diagnostic> {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&LANGUAGE.into())
+        .expect("Failed to load Svelte grammar");
+
+    let tree = parser.parse(source, None).expect("Failed to parse");
+    diagnostics::check(&tree, source)
+}
+
+fn mismatched(diagnostic: &Diagnostic) -> &MismatchedBlockEnd {
+    match &diagnostic.kind {
+        DiagnosticKind::MismatchedBlockEnd(end) => end,
+        other => panic!("expected a mismatched block end, got {other:?}"),
+    }
+}
+
+fn text(source: &str, range: tree_sitter::Range) -> &str {
+    &source[range.start_byte..range.end_byte]
+}
+
+#[test]
+fn test_if_closed_by_each_reports_expected_and_found() {
+    let source = "{#if x}<p>a</p>{/each}<div></div>";
+    let found = check(source);
+
+    assert_eq!(found.len(), 1, "{found:#?}");
+    assert_eq!(found[0].to_string(), "expected {/if}, found {/each}");
+    assert_eq!(text(source, found[0].range), "{/each}");
+
+    let end = mismatched(&found[0]);
+    assert_eq!(end.expected, "if");
+    assert_eq!(end.found, "each");
+    assert_eq!(text(source, end.opener), "{#if x}");
+    assert_eq!(text(source, end.body), "<p>a</p>");
+}
+
+#[test]
+fn test_stray_end_inside_closed_block_uses_enclosing_keyword() {
+    let source = "{#if x}a{/each}{/if}";
+    let found = check(source);
+
+    assert_eq!(found.len(), 1, "{found:#?}");
+    assert_eq!(found[0].to_string(), "expected {/if}, found {/each}");
+    assert_eq!(text(source, found[0].range), "{/each}");
+
+    let end = mismatched(&found[0]);
+    assert_eq!(text(source, end.opener), "{#if x}");
+    assert_eq!(text(source, end.body), "a");
+}
+
+#[test]
+fn test_inner_block_closed_by_outer_keyword() {
+    let source = "{#key k}{#if x}a{/key}";
+    let found = check(source);
+
+    assert_eq!(found.len(), 2, "{found:#?}");
+    assert_eq!(found[0].to_string(), "{#key ...} block is not closed");
+    assert_eq!(found[1].to_string(), "expected {/if}, found {/key}");
+    let end = mismatched(&found[1]);
+    assert_eq!(text(source, end.opener), "{#if x}");
+}
+
+#[test]
+fn test_outer_block_left_unclosed_by_nested_mismatch() {
+    let source = "{#if x}{#each items as i}<p>{i}</p>{/if}";
+    let found = check(source);
+
+    assert_eq!(found.len(), 2, "{found:#?}");
+    assert_eq!(found[0].to_string(), "{#if ...} block is not closed");
+    assert_eq!(text(source, found[0].range), "{#if x}");
+    let DiagnosticKind::UnclosedBlock { keyword, end } = &found[0].kind else {
+        panic!("expected an unclosed block, got {:?}", found[0].kind);
+    };
+    assert_eq!(keyword, "if");
+    assert_eq!(text(source, *end), "{/if}");
+
+    assert_eq!(found[1].to_string(), "expected {/each}, found {/if}");
+    assert_eq!(
+        text(source, mismatched(&found[1]).opener),
+        "{#each items as i}"
+    );
+}
+
+#[test]
+fn test_closed_outer_block_is_not_reported_as_unclosed() {
+    let source = "{#if a}{#if b}{#each xs as x}y{/if}{/if}";
+    let found: Vec<_> = check(source).iter().map(ToString::to_string).collect();
+
+    assert_eq!(
+        found,
+        [
+            "{#if ...} block is not closed",
+            "expected {/each}, found {/if}"
+        ]
+    );
+    assert_eq!(text(source, check(source)[0].range), "{#if a}");
+}
+
+#[test]
+fn test_mismatch_inside_element_and_before_sibling_blocks() {
+    let source = "<div>{#if x}a{/key}</div>\n{#if x}a{/each}{#if y}b{/if}";
+    let found: Vec<_> = check(source).iter().map(ToString::to_string).collect();
+
+    assert_eq!(
+        found,
+        [
+            "expected {/if}, found {/key}",
+            "expected {/if}, found {/each}"
+        ]
+    );
+}
+
+#[test]
+fn test_mismatched_end_reports_points() {
+    let source = "{#each items as item}\n\t{item}\n\t{/if}\n";
+    let found = check(source);
+
+    assert_eq!(found.len(), 1, "{found:#?}");
+    assert_eq!(found[0].to_string(), "expected {/each}, found {/if}");
+    assert_eq!(text(source, found[0].range), "{/if}");
+    assert_eq!(found[0].range.start_point, tree_sitter::Point::new(2, 1));
+    assert_eq!(found[0].range.end_point, tree_sitter::Point::new(2, 6));
+}
+
+#[test]
+fn test_matching_block_ends_report_nothing() {
+    for source in [
+        "{#if x}<p>a</p>{/if}",
+        "{#each items as item}{#if item}{item}{/if}{/each}",
+        "{#await p}{:then v}{v}{:catch e}{e}{/await}",
+        "{#if ready}<a></a><p></p>",
+        "{/each}",
+    ] {
+        assert!(check(source).is_empty(), "{source}");
+    }
+}
+
+#[test]
+fn test_blocks_and_tags_in_quoted_attribute_values_are_reported() {
+    let source = r#"<div class="a {#if x}b{/if} {@html y}" title='{#each items as item}{item}{/each}'></div>"#;
+    let found = check(source);
+
+    let kinds: Vec<_> = found.iter().map(|diagnostic| &diagnostic.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            &DiagnosticKind::BlockInAttributeValue {
+                keyword: "if".to_string()
+            },
+            &DiagnosticKind::TagInAttributeValue {
+                keyword: "html".to_string()
+            },
+            &DiagnosticKind::BlockInAttributeValue {
+                keyword: "each".to_string()
+            },
+        ]
+    );
+    assert_eq!(text(source, found[0].range), "{#if x}b{/if}");
+    assert_eq!(text(source, found[1].range), "{@html y}");
+    assert_eq!(
+        found[0].to_string(),
+        "{#if ...} block cannot be inside an attribute value"
+    );
+    assert_eq!(
+        found[1].to_string(),
+        "{@html ...} tag cannot be inside an attribute value"
+    );
+}
+
+#[test]
+fn test_nested_blocks_in_attribute_values_report_outermost_only() {
+    let source = r#"<Comp label="{#key k}{#await p}...{:then v}{v}{/await}{/key}" />"#;
+    let found = check(source);
+
+    assert_eq!(found.len(), 1, "{found:#?}");
+    assert_eq!(
+        found[0].kind,
+        DiagnosticKind::BlockInAttributeValue {
+            keyword: "key".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_expressions_in_attribute_values_are_not_reported() {
+    let source = r#"<div class="a {b} c" style="color: {color}" {hidden}></div>"#;
+
+    assert!(check(source).is_empty());
+}
+
+#[test]
+fn test_const_tag_outside_block_or_component_is_reported() {
+    for source in [
+        "{@const a = 1}",
+        "<div>{@const a = 1}</div>",
+        "<svelte:head>{@const a = 1}</svelte:head>",
+        "{#if x}<p>{@const a = 1}</p>{/if}",
+    ] {
+        let found = check(source);
+        assert_eq!(found.len(), 1, "{source}: {found:#?}");
+        assert_eq!(found[0].kind, DiagnosticKind::ConstTagInvalidPlacement);
+        assert_eq!(text(source, found[0].range), "{@const a = 1}");
+    }
+}
+
+#[test]
+fn test_const_tag_message_names_every_allowed_parent() {
+    let found = check("<div>{@const a = 1}</div>");
+    assert_eq!(
+        found[0].to_string(),
+        "`{@const}` must be the immediate child of `{#snippet}`, `{#if}`, `{:else if}`, \
+         `{:else}`, `{#each}`, `{#key}`, `{#await}`, `{:then}`, `{:catch}`, \
+         `<svelte:fragment>`, `<svelte:boundary>`, `<svelte:component>`, `<svelte:self>`, \
+         a `<Component>`, or an element with a `slot` attribute"
+    );
+}
+
+#[test]
+fn test_const_tag_in_allowed_parents_is_not_reported() {
+    for source in [
+        "{#if x}{@const a = 1}{:else if y}{@const b = 2}{:else}{@const c = 3}{/if}",
+        "{#each items as item}{@const a = item}{:else}{@const b = 2}{/each}",
+        "{#await p}{@const a = 1}{:then v}{@const b = v}{:catch e}{@const c = e}{/await}",
+        "{#await p then v}{@const b = v}{/await}",
+        "{#key k}{@const a = k}{/key}",
+        "{#snippet row(value)}{@const a = value}{/snippet}",
+        "<Comp>{@const a = 1}</Comp>",
+        "<ui.Button>{@const a = 1}</ui.Button>",
+        "<svelte:fragment slot=\"x\">{@const a = 1}</svelte:fragment>",
+        "<svelte:boundary>{@const a = 1}</svelte:boundary>",
+        "<div slot=\"x\">{@const a = 1}</div>",
+    ] {
+        assert!(check(source).is_empty(), "{source}: {:#?}", check(source));
+    }
+}
+
+#[test]
+fn test_debug_tag_in_markup_is_not_reported() {
+    for source in [
+        "{@debug}",
+        "<div>{@debug a, b}</div>",
+        "{#if x}{@debug x}{/if}",
+    ] {
+        assert!(check(source).is_empty(), "{source}");
+    }
+}
+
+#[test]
+fn test_debug_tag_in_attribute_value_or_textarea_is_reported() {
+    let source = r#"<div title="a {@debug x}"></div><textarea>{@debug y} {#if z}z{/if}</textarea>"#;
+    let found = check(source);
+
+    let messages: Vec<_> = found.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        messages,
+        [
+            "{@debug ...} tag cannot be inside an attribute value",
+            "{@debug ...} tag cannot be inside <textarea>",
+            "{#if ...} block cannot be inside <textarea>",
+        ]
+    );
+    assert_eq!(
+        found[1].kind,
+        DiagnosticKind::TagInTextarea {
+            keyword: "debug".to_string()
+        }
+    );
+    assert_eq!(text(source, found[0].range), "{@debug x}");
+    assert_eq!(text(source, found[1].range), "{@debug y}");
+}
+
+#[test]
+fn test_character_references_are_reported() {
+    let source = "<a href=\"?a=1&copy=2&bogus;\">a &amp b {x} &#x0;</a>";
+    let found = check(source);
+
+    let messages: Vec<_> = found.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        messages,
+        [
+            "unknown named character reference `&bogus;`",
+            "character reference `&amp` is missing a `;`",
+            "character reference `&#x0;` refers to U+0000, which is not allowed",
+        ]
+    );
+    assert!(matches!(
+        found[0].kind,
+        DiagnosticKind::CharacterReference(entities::DiagnosticKind::UnknownNamedReference { .. })
+    ));
+    assert_eq!(text(source, found[1].range), "&amp");
+}
+
+#[test]
+fn test_valid_character_references_are_not_reported() {
+    for source in [
+        "<p>&lt;&amp;&#65;&hearts; a && b</p>",
+        "<a href=\"?a=1&copy=2\">x</a>",
+        "<script>let a = b &bogus;</script>",
+    ] {
+        assert!(check(source).is_empty(), "{source}: {:#?}", check(source));
+    }
+}
+
+#[test]
+fn test_attribute_names_are_reported() {
+    let source = "<div id=a ID=b class:x class:x /><Comp id=a ID=b /><input {value} bind:value>";
+    let found = check(source);
+
+    let reported: Vec<_> = found
+        .iter()
+        .map(|diagnostic| (text(source, diagnostic.range), diagnostic.to_string()))
+        .collect();
+    assert_eq!(
+        reported,
+        [
+            ("ID", "duplicate attribute `ID`".to_string()),
+            ("class:x", "duplicate attribute `class:x`".to_string()),
+            (
+                "bind:value",
+                "`bind:value` conflicts with the `value` attribute".to_string()
+            ),
+        ]
+    );
+    assert!(matches!(
+        found[0].kind,
+        DiagnosticKind::Attribute(attributes::DiagnosticKind::DuplicateAttribute { .. })
+    ));
+}
+
+#[test]
+fn test_element_nesting_is_reported() {
+    let source = "<p>{#if a}x{/if}<div>y</div></p>\n<ul>{#each items as item}<li>{item}</li>{/each}</ul>\n<List><li>ok</li></List>\n<button><Link /><a href=x>no</a></button>";
+    let found = check(source);
+
+    let reported: Vec<_> = found
+        .iter()
+        .map(|diagnostic| (text(source, diagnostic.range), diagnostic.to_string()))
+        .collect();
+    assert_eq!(
+        reported,
+        [
+            (
+                "</p>",
+                "`</p>` has no open `<p>` to close: `<div>` closed it implicitly".to_string()
+            ),
+            (
+                "<a href=x>",
+                "`<a>` cannot be inside `<button>`".to_string()
+            ),
+        ]
+    );
+    assert!(matches!(
+        found[0].kind,
+        DiagnosticKind::ContentModel(content_model::DiagnosticKind::ClosedImplicitly { .. })
+    ));
+}
+
+#[test]
+fn test_unused_selectors_are_reported() {
+    let source = "<p class=\"note\">a</p>\n<style>\n  .note {}\n  p > .missing {}\n</style>";
+    let found = check(source);
+
+    assert_eq!(found.len(), 1, "{found:#?}");
+    assert_eq!(text(source, found[0].range), "p > .missing");
+    assert_eq!(found[0].range.start_point, tree_sitter::Point::new(3, 2));
+    assert_eq!(found[0].to_string(), "unused CSS selector `p > .missing`");
+    assert!(matches!(found[0].kind, DiagnosticKind::Css(_)));
+}
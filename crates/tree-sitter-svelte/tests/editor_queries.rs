@@ -2,7 +2,9 @@
 
 use std::collections::BTreeSet;
 use tree_sitter::StreamingIterator;
-use tree_sitter_htmlx_svelte::{FOLDS_QUERY, INDENTS_QUERY, LANGUAGE, LOCALS_QUERY};
+use tree_sitter_htmlx_svelte::{
+    FOLDS_QUERY, INDENTS_QUERY, INJECTIONS_QUERY, LANGUAGE, LOCALS_QUERY,
+};
 
 fn parse(source: &str) -> tree_sitter::Tree {
     let mut parser = tree_sitter::Parser::new();
@@ -413,3 +415,56 @@ fn locals_cover_recovery_branch_bindings_and_references() {
         &["orphaned"],
     );
 }
+
+#[test]
+fn injections_route_style_preprocessors_to_their_languages() {
+    let language = LANGUAGE.into();
+    let query = tree_sitter::Query::new(&language, INJECTIONS_QUERY)
+        .expect("injections query should compile");
+    let content = query
+        .capture_index_for_name("injection.content")
+        .expect("injections query has @injection.content");
+
+    for (source, expected) in [
+        (
+            "<style lang=\"scss\">.a { &__b {} }</style>",
+            &["css", "scss"][..],
+        ),
+        ("<style lang=\"less\">@c: red;</style>", &["css", "less"]),
+        (
+            "<style lang=\"sass\">a\n  color: red</style>",
+            &["css", "sass"],
+        ),
+    ] {
+        let tree = parse(source);
+        let mut cursor = tree_sitter::QueryCursor::new();
+        let mut matches = cursor.matches(&query, tree.root_node(), source.as_bytes());
+        let mut languages = BTreeSet::new();
+        while let Some(found) = matches.next() {
+            if !found
+                .captures
+                .iter()
+                .any(|capture| capture.index == content)
+            {
+                continue;
+            }
+            let language = query
+                .property_settings(found.pattern_index)
+                .iter()
+                .find(|setting| &*setting.key == "injection.language")
+                .and_then(|setting| setting.value.as_deref());
+            if found
+                .captures
+                .iter()
+                .any(|capture| capture.node.kind() == "raw_text")
+            {
+                languages.extend(language);
+            }
+        }
+        assert_eq!(
+            languages.into_iter().collect::<Vec<_>>(),
+            expected,
+            "{source}"
+        );
+    }
+}
@@ -416,3 +416,47 @@ fn test_param_is_treated_as_void_element() {
         "(document (element (start_tag name: (tag_name))) (element (start_tag name: (tag_name))))"
     );
 }
+
+#[test]
+fn test_svg_keeps_style_raw_text_and_camel_case_elements() {
+    assert_eq!(
+        parse("<svg><style>.a{}</style><linearGradient></linearGradient></svg>"),
+        "(document (element (start_tag name: (tag_name)) (element (start_tag name: (tag_name)) (raw_text) (end_tag name: (tag_name))) (element (start_tag name: (tag_name)) (end_tag name: (tag_name))) (end_tag name: (tag_name))))"
+    );
+}
+
+#[test]
+fn test_svg_cdata_section_is_cdata() {
+    assert_eq!(
+        parse("<svg><text><![CDATA[a<b]]></text></svg>"),
+        "(document (element (start_tag name: (tag_name)) (element (start_tag name: (tag_name)) (cdata) (end_tag name: (tag_name))) (end_tag name: (tag_name))))"
+    );
+}
+
+#[test]
+fn test_template_end_tags_do_not_close_outer_elements() {
+    // </li> inside <template> has no <li> to close in the template contents.
+    assert_eq!(
+        parse("<ul><li>{a}<template></li><li>{b}</template></ul>"),
+        "(document (element (start_tag name: (tag_name)) (element (start_tag name: (tag_name)) (expression content: (js)) (element (start_tag name: (tag_name)) (template_content (erroneous_end_tag (erroneous_end_tag_name)) (element (start_tag name: (tag_name)) (expression content: (js)))) (end_tag name: (tag_name)))) (end_tag name: (tag_name))))"
+    );
+}
+
+#[test]
+fn test_noscript_is_raw_text_with_scripting_enabled() {
+    let source = "<noscript><p>{x}</p></noscript>";
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&tree_sitter_htmlx_svelte::LANGUAGE_SCRIPTING.into())
+        .unwrap();
+    let tree = parser.parse(source, None).unwrap();
+
+    assert_eq!(
+        tree.root_node().to_sexp(),
+        "(document (element (start_tag name: (tag_name)) (raw_text) (end_tag name: (tag_name))))"
+    );
+    assert_eq!(
+        parse(source),
+        "(document (element (start_tag name: (tag_name)) (element (start_tag name: (tag_name)) (expression content: (js)) (end_tag name: (tag_name))) (end_tag name: (tag_name))))"
+    );
+}
@@ -0,0 +1,155 @@
+//! Tests for splitting binding patterns into individual definitions.
+
+use tree_sitter_htmlx_svelte::patterns::{self, BindingSite, PatternBinding};
+use tree_sitter_htmlx_svelte::LANGUAGE;
+
+fn definitions(source: &str) -> Vec<PatternBinding> {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&LANGUAGE.into())
+        .expect("Failed to load Svelte grammar");
+
+    let tree = parser.parse(source, None).expect("Failed to parse");
+    assert!(!tree.root_node().has_error(), "{source}");
+    patterns::definitions(tree.root_node(), source)
+}
+
+fn names(source: &str) -> Vec<String> {
+    definitions(source)
+        .into_iter()
+        .map(|binding| binding.name)
+        .collect()
+}
+
+#[test]
+fn test_each_object_pattern_with_nested_array() {
+    assert_eq!(
+        names("{#each items as { id, name: [first] }}{/each}"),
+        ["id", "first"]
+    );
+}
+
+#[test]
+fn test_each_array_pattern_with_default_and_rest() {
+    let source = "{#each rows as [a, b = 1, ...rest], i (a)}{/each}";
+    let found = definitions(source);
+
+    let summary: Vec<_> = found
+        .iter()
+        .map(|binding| (binding.name.as_str(), binding.is_rest, binding.site))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("a", false, BindingSite::EachItem),
+            ("b", false, BindingSite::EachItem),
+            ("rest", true, BindingSite::EachItem),
+            ("i", false, BindingSite::EachIndex),
+        ]
+    );
+
+    let b = &found[1];
+    let default = b.default_value.expect("b should have a default");
+    assert_eq!(&source[default.start_byte..default.end_byte], "1");
+    assert_eq!(&source[b.range.start_byte..b.range.end_byte], "b");
+}
+
+#[test]
+fn test_object_keys_and_defaults_are_not_bindings() {
+    assert_eq!(
+        names(
+            r#"{#each items as { "quoted key": a, [computed]: b, 0: c, d = fallback(e, f), ...others }}{/each}"#
+        ),
+        ["a", "b", "c", "d", "others"]
+    );
+}
+
+#[test]
+fn test_array_holes_and_nested_defaults() {
+    assert_eq!(
+        names("{#each items as [, second, { deep = [1, 2] } = {}]}{/each}"),
+        ["second", "deep"]
+    );
+}
+
+#[test]
+fn test_await_then_and_catch_bindings() {
+    let found = definitions(
+        "{#await load()}{:then { data: { user } }}{user}{:catch { message }}{message}{/await}",
+    );
+    let summary: Vec<_> = found
+        .iter()
+        .map(|binding| (binding.name.as_str(), binding.site))
+        .collect();
+
+    assert_eq!(
+        summary,
+        [
+            ("user", BindingSite::AwaitThen),
+            ("message", BindingSite::AwaitCatch),
+        ]
+    );
+}
+
+#[test]
+fn test_await_shorthand_binding() {
+    let found = definitions("{#await load() then [head, ...tail]}{head}{/await}");
+    let summary: Vec<_> = found
+        .iter()
+        .map(|binding| (binding.name.as_str(), binding.site))
+        .collect();
+
+    assert_eq!(
+        summary,
+        [
+            ("head", BindingSite::AwaitThen),
+            ("tail", BindingSite::AwaitThen)
+        ]
+    );
+}
+
+#[test]
+fn test_snippet_parameters_skip_type_annotations() {
+    let source = r#"<script lang="ts"></script>
+{#snippet row({ id, label }: Row, index?: number, format: (value: Map<string, number>) => string = (v) => `${v}`)}
+{/snippet}"#;
+    let found = definitions(source);
+
+    assert_eq!(
+        found
+            .iter()
+            .map(|binding| binding.name.as_str())
+            .collect::<Vec<_>>(),
+        ["id", "label", "index", "format"]
+    );
+    assert!(found
+        .iter()
+        .all(|binding| binding.site == BindingSite::SnippetParameter));
+
+    let format = &found[3];
+    let default = format.default_value.expect("format should have a default");
+    assert_eq!(
+        &source[default.start_byte..default.end_byte],
+        "(v) => `${v}`"
+    );
+}
+
+#[test]
+fn test_binding_ranges_track_rows_and_columns() {
+    let source = "{#each items as {\n\tid,\n\tname: { first }\n}}{/each}";
+    let found = definitions(source);
+
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[0].range.start_point, tree_sitter::Point::new(1, 1));
+    assert_eq!(found[1].name, "first");
+    assert_eq!(found[1].range.start_point, tree_sitter::Point::new(2, 9));
+    assert_eq!(found[1].range.end_point, tree_sitter::Point::new(2, 14));
+}
+
+#[test]
+fn test_comments_inside_patterns_are_skipped() {
+    assert_eq!(
+        names("{#each items as { /* id */ a, // b\n c }}{/each}"),
+        ["a", "c"]
+    );
+}
@@ -155,6 +155,41 @@ fn test_valid_if_else_does_not_expose_orphan_branch() {
     );
 }
 
+#[test]
+fn test_mismatched_block_end_closes_block_without_swallowing_siblings() {
+    let tree = parse("{#if x}<p>a</p>{/each}<div>after</div>");
+    assert!(
+        !tree.contains("(ERROR"),
+        "Mismatched block end should recover without ERROR: {tree}"
+    );
+    assert!(
+        tree.contains(
+            "(mismatched_block_end (block_open) keyword: (block_keyword) (block_close))) (element"
+        ),
+        "Expected mismatched_block_end closing the if block before the sibling: {tree}"
+    );
+}
+
+#[test]
+fn test_nested_mismatched_block_end_leaves_outer_block_open() {
+    let tree = parse("{#if x}{#each items as i}<p>{i}</p>{/if}");
+    assert_eq!(
+        tree,
+        "(document (if_block (block_open) expression: (expression content: (js)) (block_close) (each_block (block_open) expression: (expression content: (js)) binding: (pattern content: (js)) (block_close) (element (start_tag name: (tag_name)) (expression content: (js)) (end_tag name: (tag_name))) (mismatched_block_end (block_open) keyword: (block_keyword) (block_close)))))"
+    );
+}
+
+#[test]
+fn test_mismatched_block_end_keeps_await_branches() {
+    let tree = parse("{#await p}{:then v}{v}{/if}<p>x</p>");
+    assert!(
+        !tree.contains("(ERROR"),
+        "Mismatched await end should recover without ERROR: {tree}"
+    );
+    assert!(tree.contains("(await_branch"));
+    assert!(tree.contains("(mismatched_block_end"));
+}
+
 #[test]
 fn test_unclosed_if_block_has_local_recovery_shape() {
     let tree = parse("{#if ready}<a></a><p></p>");
@@ -0,0 +1,177 @@
+//! Tests for the scope tree built from the layered locals queries.
+
+use tree_sitter_htmlx_svelte::scopes::{DefinitionKind, ScopeTree};
+use tree_sitter_htmlx_svelte::LANGUAGE;
+
+fn scopes(source: &str) -> ScopeTree {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&LANGUAGE.into())
+        .expect("Failed to load Svelte grammar");
+
+    let tree = parser.parse(source, None).expect("Failed to parse");
+    assert!(!tree.root_node().has_error(), "{source}");
+    ScopeTree::new(&tree, source)
+}
+
+fn visible(scopes: &ScopeTree, offset: usize) -> Vec<&str> {
+    scopes
+        .visible_names_at(offset)
+        .into_iter()
+        .map(|definition| definition.name.as_str())
+        .collect()
+}
+
+#[test]
+fn test_scope_tree_nests_blocks_and_elements() {
+    let scopes = scopes("<ul>{#each items as item}<li>{item}</li>{/each}</ul>");
+
+    let kinds: Vec<_> = scopes.scopes().iter().map(|scope| scope.kind).collect();
+    assert_eq!(kinds, ["document", "element", "each_block", "element"]);
+    let li = scopes.scope_at(30);
+    assert_eq!(scopes.scope(li).kind, "element");
+    assert_eq!(
+        scopes.scope(scopes.scope(li).parent.unwrap()).kind,
+        "each_block"
+    );
+}
+
+#[test]
+fn test_destructured_bindings_resolve_references() {
+    let source = "{#each rows as { id, cells: [first] }, i}{id}{first.value}{i}{/each}";
+    let scopes = scopes(source);
+
+    let names: Vec<_> = scopes
+        .definitions()
+        .iter()
+        .map(|d| d.name.as_str())
+        .collect();
+    assert_eq!(names, ["id", "first", "i"]);
+
+    let first = scopes
+        .definition_at(source.find("first.").unwrap())
+        .unwrap();
+    assert_eq!(scopes.definition(first).name, "first");
+    let references: Vec<_> = scopes.references_to(first).collect();
+    assert_eq!(references.len(), 1);
+    assert_eq!(
+        references[0].range.start_byte,
+        source.find("first.").unwrap()
+    );
+}
+
+#[test]
+fn test_property_names_are_not_references() {
+    let scopes = scopes("{user.name}{typeof value}");
+    let names: Vec<_> = scopes
+        .references()
+        .iter()
+        .map(|r| r.name.as_str())
+        .collect();
+    assert_eq!(names, ["user", "value"]);
+    assert!(scopes.references().iter().all(|r| r.definition.is_none()));
+}
+
+#[test]
+fn test_each_header_resolves_outside_the_block() {
+    let source = "{#each tree as item}{#each item.children as item}{item}{/each}{/each}";
+    let scopes = scopes(source);
+
+    let inner_header = source.find("item.children").unwrap();
+    let outer = scopes.definition_at(inner_header).unwrap();
+    assert_eq!(
+        scopes.definition(outer).range.start_byte,
+        source.find("item}").unwrap()
+    );
+
+    let body = source.find("{item}").unwrap() + 1;
+    let inner = scopes.definition_at(body).unwrap();
+    assert_ne!(inner, outer);
+}
+
+#[test]
+fn test_const_tag_defines_and_references() {
+    let source = "{#if user}{@const { first, last } = user.name}{first} {last}{/if}";
+    let scopes = scopes(source);
+
+    let names: Vec<_> = scopes
+        .definitions()
+        .iter()
+        .map(|d| d.name.as_str())
+        .collect();
+    assert_eq!(names, ["first", "last"]);
+    let user = scopes
+        .references()
+        .iter()
+        .filter(|reference| reference.name == "user")
+        .count();
+    assert_eq!(user, 2, "if condition and const initializer");
+    assert!(visible(&scopes, source.find("{first}").unwrap()).contains(&"last"));
+    assert!(visible(&scopes, source.len()).is_empty());
+}
+
+#[test]
+fn test_snippet_name_belongs_to_the_outer_scope() {
+    let source = "{#snippet row(item)}{item}{/snippet}{@render row(data)}";
+    let scopes = scopes(source);
+
+    let row = scopes
+        .definitions()
+        .iter()
+        .position(|definition| definition.kind == DefinitionKind::Function)
+        .unwrap();
+    assert_eq!(scopes.definitions()[row].scope, scopes.root());
+    assert_eq!(visible(&scopes, source.len() - 3), ["row"]);
+    assert_eq!(
+        visible(&scopes, source.find("{item}").unwrap() + 1),
+        ["item", "row"]
+    );
+}
+
+#[test]
+fn test_inner_bindings_shadow_outer_names() {
+    let source = "{#each a as x}{#each b as x}{x}{/each}{/each}";
+    let scopes = scopes(source);
+
+    let offset = source.find("{x}").unwrap() + 1;
+    assert_eq!(visible(&scopes, offset), ["x"]);
+    let definition = scopes.definition_at(offset).unwrap();
+    assert_eq!(
+        scopes.definition(definition).range.start_byte,
+        source.find("b as x").unwrap() + 5
+    );
+}
+
+#[test]
+fn test_let_directive_and_action_reference() {
+    let source = "<List let:entry use:tooltip>{entry}</List>";
+    let scopes = scopes(source);
+
+    let entry = scopes
+        .definition_at(source.find("{entry}").unwrap() + 1)
+        .unwrap();
+    assert_eq!(scopes.definition(entry).name, "entry");
+    let tooltip = scopes
+        .references()
+        .iter()
+        .find(|reference| reference.name == "tooltip")
+        .unwrap();
+    assert_eq!(tooltip.definition, None);
+}
+
+#[test]
+fn test_object_keys_and_template_text_are_not_references() {
+    let source = "{#each items as item}{f({ item: 1, ...item })}{`${item.id}$ item`}{/each}";
+    let scopes = scopes(source);
+
+    let names: Vec<_> = scopes
+        .references()
+        .iter()
+        .map(|reference| reference.name.as_str())
+        .collect();
+    assert_eq!(names, ["items", "f", "item", "item"]);
+    let item = scopes
+        .definition_at(source.find("as item").unwrap() + 3)
+        .unwrap();
+    assert_eq!(scopes.references_to(item).count(), 2);
+}
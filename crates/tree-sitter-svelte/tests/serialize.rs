@@ -0,0 +1,103 @@
+//! Round-trip tests for the serializer over `tests/fixtures`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use tree_sitter_htmlx_svelte::{serialize, LANGUAGE};
+
+fn parse(source: &str) -> tree_sitter::Tree {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&LANGUAGE.into())
+        .expect("Failed to load Svelte grammar");
+    parser.parse(source, None).expect("Failed to parse")
+}
+
+fn fixtures(dir: &Path, found: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).expect("fixtures directory should exist") {
+        let path = entry.expect("directory entry").path();
+        if path.is_dir() {
+            fixtures(&path, found);
+        } else if path.extension().is_some_and(|ext| ext == "svelte") {
+            found.push(path);
+        }
+    }
+}
+
+#[test]
+fn test_fixtures_round_trip() {
+    let mut paths = Vec::new();
+    fixtures(Path::new("tests/fixtures"), &mut paths);
+    assert!(!paths.is_empty(), "no fixtures found");
+
+    for path in paths {
+        let source = fs::read_to_string(&path).unwrap();
+        let tree = parse(&source);
+        assert_eq!(
+            serialize::print(tree.root_node(), &source),
+            source,
+            "{}: print is not lossless",
+            path.display()
+        );
+
+        let normalized = serialize::normalize(tree.root_node(), &source);
+        let reparsed = parse(&normalized);
+        assert_eq!(
+            reparsed.root_node().has_error(),
+            tree.root_node().has_error(),
+            "{}: normalizing changed the parse\n{normalized}",
+            path.display()
+        );
+        assert_eq!(
+            serialize::normalize(reparsed.root_node(), &normalized),
+            normalized,
+            "{}: normalizing is not idempotent",
+            path.display()
+        );
+    }
+}
+
+#[test]
+fn test_print_keeps_recovered_markup() {
+    for source in [
+        "{#if a}<p>x{/each}",
+        "<div class=\"a\" {...rest}\n  on:click={() => go()}>\n\t{@html raw}\n</div>",
+        "  <p>leading and trailing whitespace</p>\n\n",
+    ] {
+        let tree = parse(source);
+        assert_eq!(serialize::print(tree.root_node(), source), source);
+    }
+}
+
+#[test]
+fn test_normalize_quotes_values() {
+    for (source, expected) in [
+        (
+            r#"<a href=x class=a{b} data-x={y} title='q'>go</a>"#,
+            r#"<a href="x" class="a{b}" data-x={y} title='q'>go</a>"#,
+        ),
+        // Quotes inside an expression pick the other quote, and are never
+        // escaped; with both kinds inside, the value stays unquoted.
+        (
+            r#"<p title=a{x?"b":y} lang=a{x?'b':y}>go</p>"#,
+            r#"<p title='a{x?"b":y}' lang="a{x?'b':y}">go</p>"#,
+        ),
+        (
+            r#"<p title=a{x?"'":y}>go</p>"#,
+            r#"<p title=a{x?"'":y}>go</p>"#,
+        ),
+    ] {
+        let tree = parse(source);
+        assert_eq!(serialize::normalize(tree.root_node(), source), expected);
+    }
+}
+
+#[test]
+fn test_normalize_leaves_spread_and_svg_case() {
+    let source = "<svg viewBox=\"0 0 1 1\"><linearGradient id=g></svg><Comp {...props} {a} />";
+    let tree = parse(source);
+    assert_eq!(
+        serialize::normalize(tree.root_node(), source),
+        "<svg viewBox=\"0 0 1 1\"><linearGradient id=\"g\"></linearGradient></svg><Comp {...props} a={a} />"
+    );
+}
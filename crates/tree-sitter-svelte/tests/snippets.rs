@@ -0,0 +1,119 @@
+//! Tests for resolving render tags to snippet definitions.
+
+use tree_sitter_htmlx_svelte::snippets::{RenderTarget, SnippetIndex};
+use tree_sitter_htmlx_svelte::LANGUAGE;
+
+fn index(source: &str) -> SnippetIndex {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&LANGUAGE.into())
+        .expect("Failed to load Svelte grammar");
+
+    let tree = parser.parse(source, None).expect("Failed to parse");
+    assert!(!tree.root_node().has_error(), "{source}");
+    SnippetIndex::new(&tree, source)
+}
+
+fn unused(index: &SnippetIndex) -> Vec<&str> {
+    index
+        .unused_snippets()
+        .map(|snippet| snippet.name.as_str())
+        .collect()
+}
+
+#[test]
+fn test_render_resolves_to_top_level_snippet() {
+    let source = "{#snippet row(item)}<td>{item}</td>{/snippet}\n{@render row(1)}";
+    let index = index(source);
+
+    assert_eq!(index.snippets().len(), 1);
+    let render = &index.renders()[0];
+    assert_eq!(render.name.as_deref(), Some("row"));
+    assert_eq!(render.name_range.unwrap().start_point.row, 1);
+    assert_eq!(index.definition_for(render).unwrap().name, "row");
+    assert!(unused(&index).is_empty());
+}
+
+#[test]
+fn test_snippets_are_hoisted_within_their_scope() {
+    let index = index("{@render later()}{#snippet later()}x{/snippet}");
+    assert!(matches!(
+        index.renders()[0].target,
+        RenderTarget::Snippet(_)
+    ));
+}
+
+#[test]
+fn test_nested_snippet_is_not_visible_outside_its_block() {
+    let index =
+        index("{#if ok}{#snippet inner()}x{/snippet}{@render inner()}{/if}{@render inner()}");
+
+    let targets: Vec<_> = index.renders().iter().map(|render| render.target).collect();
+    assert!(matches!(targets[0], RenderTarget::Snippet(_)));
+    assert_eq!(targets[1], RenderTarget::Unresolved);
+    assert_eq!(index.unknown_render_targets().count(), 1);
+}
+
+#[test]
+fn test_inner_snippet_shadows_outer() {
+    let index = index(
+        "{#snippet item()}outer{/snippet}{#each xs as x}{#snippet item()}inner{/snippet}{@render item()}{/each}",
+    );
+
+    let render = &index.renders()[0];
+    assert_eq!(
+        index.definition_for(render).unwrap().range,
+        index.snippets()[1].range
+    );
+    assert_eq!(unused(&index), ["item"]);
+}
+
+#[test]
+fn test_snippet_parameter_shadows_snippet() {
+    let index = index("{#snippet cell()}x{/snippet}{#snippet row(cell)}{@render cell()}{/snippet}{@render row(cell)}");
+
+    assert_eq!(index.renders()[0].target, RenderTarget::Binding);
+    assert!(unused(&index).is_empty(), "cell is passed as an argument");
+}
+
+#[test]
+fn test_optional_and_dynamic_callees() {
+    let index = index("{@render children?.()}{@render obj.snip()}");
+
+    assert_eq!(index.renders()[0].name.as_deref(), Some("children"));
+    assert_eq!(index.renders()[0].target, RenderTarget::Unresolved);
+    assert_eq!(index.renders()[1].target, RenderTarget::Dynamic);
+    assert_eq!(index.renders()[1].name, None);
+}
+
+#[test]
+fn test_component_children_are_implicit_props() {
+    let index = index("<Table data={rows}>{#snippet header()}<th>x</th>{/snippet}</Table><div>{#snippet lonely()}x{/snippet}</div>");
+
+    let props = index.implicit_props();
+    assert_eq!(props.len(), 1);
+    assert_eq!(props[0].component, "Table");
+    assert_eq!(index.snippet(props[0].snippet).name, "header");
+    assert_eq!(unused(&index), ["lonely"]);
+}
+
+#[test]
+fn test_snippet_passed_as_attribute_value_is_used() {
+    let index = index(
+        "{#snippet row(x)}{x}{/snippet}{#snippet other()}y{/snippet}<List {row} render={other} />",
+    );
+    assert!(unused(&index).is_empty());
+}
+
+#[test]
+fn test_script_reference_keeps_top_level_snippet_used() {
+    let index = index("<script>export { footer };</script>{#snippet footer()}x{/snippet}{#snippet unused()}y{/snippet}");
+    assert_eq!(unused(&index), ["unused"]);
+}
+
+#[test]
+fn test_renders_of_lists_every_call_site() {
+    let index = index("{#snippet a()}x{/snippet}{@render a()}{@render a()}{@render b()}");
+    let id = tree_sitter_htmlx_svelte::snippets::SnippetId(0);
+    assert_eq!(index.renders_of(id).count(), 2);
+}